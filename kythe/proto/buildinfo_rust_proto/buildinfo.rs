@@ -0,0 +1,287 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `kythe/proto/buildinfo.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BuildDetails {
+    // message fields
+    pub build_target: ::std::string::String,
+    pub rule_type: ::std::string::String,
+    pub build_config: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BuildDetails {
+    fn default() -> &'a BuildDetails {
+        <BuildDetails as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BuildDetails {
+    pub fn new() -> BuildDetails {
+        ::std::default::Default::default()
+    }
+
+    // string build_target = 1;
+
+
+    pub fn get_build_target(&self) -> &str {
+        &self.build_target
+    }
+    pub fn clear_build_target(&mut self) {
+        self.build_target.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_build_target(&mut self, v: ::std::string::String) {
+        self.build_target = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_build_target(&mut self) -> &mut ::std::string::String {
+        &mut self.build_target
+    }
+
+    // Take field
+    pub fn take_build_target(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.build_target, ::std::string::String::new())
+    }
+
+    // string rule_type = 2;
+
+
+    pub fn get_rule_type(&self) -> &str {
+        &self.rule_type
+    }
+    pub fn clear_rule_type(&mut self) {
+        self.rule_type.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rule_type(&mut self, v: ::std::string::String) {
+        self.rule_type = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_rule_type(&mut self) -> &mut ::std::string::String {
+        &mut self.rule_type
+    }
+
+    // Take field
+    pub fn take_rule_type(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.rule_type, ::std::string::String::new())
+    }
+
+    // string build_config = 3;
+
+
+    pub fn get_build_config(&self) -> &str {
+        &self.build_config
+    }
+    pub fn clear_build_config(&mut self) {
+        self.build_config.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_build_config(&mut self, v: ::std::string::String) {
+        self.build_config = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_build_config(&mut self) -> &mut ::std::string::String {
+        &mut self.build_config
+    }
+
+    // Take field
+    pub fn take_build_config(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.build_config, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for BuildDetails {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.build_target)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.rule_type)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.build_config)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.build_target.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.build_target);
+        }
+        if !self.rule_type.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.rule_type);
+        }
+        if !self.build_config.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.build_config);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.build_target.is_empty() {
+            os.write_string(1, &self.build_target)?;
+        }
+        if !self.rule_type.is_empty() {
+            os.write_string(2, &self.rule_type)?;
+        }
+        if !self.build_config.is_empty() {
+            os.write_string(3, &self.build_config)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BuildDetails {
+        BuildDetails::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "build_target",
+                |m: &BuildDetails| { &m.build_target },
+                |m: &mut BuildDetails| { &mut m.build_target },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "rule_type",
+                |m: &BuildDetails| { &m.rule_type },
+                |m: &mut BuildDetails| { &mut m.rule_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "build_config",
+                |m: &BuildDetails| { &m.build_config },
+                |m: &mut BuildDetails| { &mut m.build_config },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BuildDetails>(
+                "BuildDetails",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BuildDetails {
+        static instance: ::protobuf::rt::LazyV2<BuildDetails> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BuildDetails::new)
+    }
+}
+
+impl ::protobuf::Clear for BuildDetails {
+    fn clear(&mut self) {
+        self.build_target.clear();
+        self.rule_type.clear();
+        self.build_config.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BuildDetails {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BuildDetails {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x1bkythe/proto/buildinfo.proto\x12\x0bkythe.proto\"q\n\x0cBuildDetail\
+    s\x12!\n\x0cbuild_target\x18\x01\x20\x01(\tR\x0bbuildTarget\x12\x1b\n\tr\
+    ule_type\x18\x02\x20\x01(\tR\x08ruleType\x12!\n\x0cbuild_config\x18\x03\
+    \x20\x01(\tR\x0bbuildConfigBJ\n\x1fcom.google.devtools.kythe.protoZ'kyth\
+    e.io/kythe/proto/buildinfo_go_protob\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,3 @@
+extern crate protobuf;
+pub mod buildinfo;
+pub use buildinfo::*;
\ No newline at end of file
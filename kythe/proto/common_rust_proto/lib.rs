@@ -0,0 +1,5 @@
+extern crate protobuf;
+pub mod timestamp;
+pub use timestamp::*;
+pub mod common;
+pub use common::*;
\ No newline at end of file
@@ -0,0 +1,1147 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `kythe/proto/cxx.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CxxCompilationUnitDetails {
+    // message fields
+    pub header_search_info: ::protobuf::SingularPtrField<CxxCompilationUnitDetails_HeaderSearchInfo>,
+    pub system_header_prefix: ::protobuf::RepeatedField<CxxCompilationUnitDetails_SystemHeaderPrefix>,
+    pub stat_path: ::protobuf::RepeatedField<CxxCompilationUnitDetails_StatPath>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CxxCompilationUnitDetails {
+    fn default() -> &'a CxxCompilationUnitDetails {
+        <CxxCompilationUnitDetails as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CxxCompilationUnitDetails {
+    pub fn new() -> CxxCompilationUnitDetails {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.CxxCompilationUnitDetails.HeaderSearchInfo header_search_info = 1;
+
+
+    pub fn get_header_search_info(&self) -> &CxxCompilationUnitDetails_HeaderSearchInfo {
+        self.header_search_info.as_ref().unwrap_or_else(|| <CxxCompilationUnitDetails_HeaderSearchInfo as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_header_search_info(&mut self) {
+        self.header_search_info.clear();
+    }
+
+    pub fn has_header_search_info(&self) -> bool {
+        self.header_search_info.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_header_search_info(&mut self, v: CxxCompilationUnitDetails_HeaderSearchInfo) {
+        self.header_search_info = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_header_search_info(&mut self) -> &mut CxxCompilationUnitDetails_HeaderSearchInfo {
+        if self.header_search_info.is_none() {
+            self.header_search_info.set_default();
+        }
+        self.header_search_info.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_header_search_info(&mut self) -> CxxCompilationUnitDetails_HeaderSearchInfo {
+        self.header_search_info.take().unwrap_or_else(|| CxxCompilationUnitDetails_HeaderSearchInfo::new())
+    }
+
+    // repeated .kythe.proto.CxxCompilationUnitDetails.SystemHeaderPrefix system_header_prefix = 2;
+
+
+    pub fn get_system_header_prefix(&self) -> &[CxxCompilationUnitDetails_SystemHeaderPrefix] {
+        &self.system_header_prefix
+    }
+    pub fn clear_system_header_prefix(&mut self) {
+        self.system_header_prefix.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_system_header_prefix(&mut self, v: ::protobuf::RepeatedField<CxxCompilationUnitDetails_SystemHeaderPrefix>) {
+        self.system_header_prefix = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_system_header_prefix(&mut self) -> &mut ::protobuf::RepeatedField<CxxCompilationUnitDetails_SystemHeaderPrefix> {
+        &mut self.system_header_prefix
+    }
+
+    // Take field
+    pub fn take_system_header_prefix(&mut self) -> ::protobuf::RepeatedField<CxxCompilationUnitDetails_SystemHeaderPrefix> {
+        ::std::mem::replace(&mut self.system_header_prefix, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .kythe.proto.CxxCompilationUnitDetails.StatPath stat_path = 3;
+
+
+    pub fn get_stat_path(&self) -> &[CxxCompilationUnitDetails_StatPath] {
+        &self.stat_path
+    }
+    pub fn clear_stat_path(&mut self) {
+        self.stat_path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stat_path(&mut self, v: ::protobuf::RepeatedField<CxxCompilationUnitDetails_StatPath>) {
+        self.stat_path = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_stat_path(&mut self) -> &mut ::protobuf::RepeatedField<CxxCompilationUnitDetails_StatPath> {
+        &mut self.stat_path
+    }
+
+    // Take field
+    pub fn take_stat_path(&mut self) -> ::protobuf::RepeatedField<CxxCompilationUnitDetails_StatPath> {
+        ::std::mem::replace(&mut self.stat_path, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for CxxCompilationUnitDetails {
+    fn is_initialized(&self) -> bool {
+        for v in &self.header_search_info {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.system_header_prefix {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.stat_path {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.header_search_info)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.system_header_prefix)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.stat_path)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.header_search_info.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.system_header_prefix {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.stat_path {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.header_search_info.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.system_header_prefix {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.stat_path {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CxxCompilationUnitDetails {
+        CxxCompilationUnitDetails::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CxxCompilationUnitDetails_HeaderSearchInfo>>(
+                "header_search_info",
+                |m: &CxxCompilationUnitDetails| { &m.header_search_info },
+                |m: &mut CxxCompilationUnitDetails| { &mut m.header_search_info },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CxxCompilationUnitDetails_SystemHeaderPrefix>>(
+                "system_header_prefix",
+                |m: &CxxCompilationUnitDetails| { &m.system_header_prefix },
+                |m: &mut CxxCompilationUnitDetails| { &mut m.system_header_prefix },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CxxCompilationUnitDetails_StatPath>>(
+                "stat_path",
+                |m: &CxxCompilationUnitDetails| { &m.stat_path },
+                |m: &mut CxxCompilationUnitDetails| { &mut m.stat_path },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CxxCompilationUnitDetails>(
+                "CxxCompilationUnitDetails",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CxxCompilationUnitDetails {
+        static instance: ::protobuf::rt::LazyV2<CxxCompilationUnitDetails> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CxxCompilationUnitDetails::new)
+    }
+}
+
+impl ::protobuf::Clear for CxxCompilationUnitDetails {
+    fn clear(&mut self) {
+        self.header_search_info.clear();
+        self.system_header_prefix.clear();
+        self.stat_path.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CxxCompilationUnitDetails {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CxxCompilationUnitDetails {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CxxCompilationUnitDetails_HeaderSearchDir {
+    // message fields
+    pub path: ::std::string::String,
+    pub characteristic_kind: i32,
+    pub is_framework: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CxxCompilationUnitDetails_HeaderSearchDir {
+    fn default() -> &'a CxxCompilationUnitDetails_HeaderSearchDir {
+        <CxxCompilationUnitDetails_HeaderSearchDir as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CxxCompilationUnitDetails_HeaderSearchDir {
+    pub fn new() -> CxxCompilationUnitDetails_HeaderSearchDir {
+        ::std::default::Default::default()
+    }
+
+    // string path = 1;
+
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        &mut self.path
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.path, ::std::string::String::new())
+    }
+
+    // int32 characteristic_kind = 2;
+
+
+    pub fn get_characteristic_kind(&self) -> i32 {
+        self.characteristic_kind
+    }
+    pub fn clear_characteristic_kind(&mut self) {
+        self.characteristic_kind = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_characteristic_kind(&mut self, v: i32) {
+        self.characteristic_kind = v;
+    }
+
+    // bool is_framework = 3;
+
+
+    pub fn get_is_framework(&self) -> bool {
+        self.is_framework
+    }
+    pub fn clear_is_framework(&mut self) {
+        self.is_framework = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_framework(&mut self, v: bool) {
+        self.is_framework = v;
+    }
+}
+
+impl ::protobuf::Message for CxxCompilationUnitDetails_HeaderSearchDir {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.characteristic_kind = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_framework = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.path);
+        }
+        if self.characteristic_kind != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.characteristic_kind, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.is_framework != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.path.is_empty() {
+            os.write_string(1, &self.path)?;
+        }
+        if self.characteristic_kind != 0 {
+            os.write_int32(2, self.characteristic_kind)?;
+        }
+        if self.is_framework != false {
+            os.write_bool(3, self.is_framework)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CxxCompilationUnitDetails_HeaderSearchDir {
+        CxxCompilationUnitDetails_HeaderSearchDir::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "path",
+                |m: &CxxCompilationUnitDetails_HeaderSearchDir| { &m.path },
+                |m: &mut CxxCompilationUnitDetails_HeaderSearchDir| { &mut m.path },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "characteristic_kind",
+                |m: &CxxCompilationUnitDetails_HeaderSearchDir| { &m.characteristic_kind },
+                |m: &mut CxxCompilationUnitDetails_HeaderSearchDir| { &mut m.characteristic_kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_framework",
+                |m: &CxxCompilationUnitDetails_HeaderSearchDir| { &m.is_framework },
+                |m: &mut CxxCompilationUnitDetails_HeaderSearchDir| { &mut m.is_framework },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CxxCompilationUnitDetails_HeaderSearchDir>(
+                "CxxCompilationUnitDetails.HeaderSearchDir",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CxxCompilationUnitDetails_HeaderSearchDir {
+        static instance: ::protobuf::rt::LazyV2<CxxCompilationUnitDetails_HeaderSearchDir> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CxxCompilationUnitDetails_HeaderSearchDir::new)
+    }
+}
+
+impl ::protobuf::Clear for CxxCompilationUnitDetails_HeaderSearchDir {
+    fn clear(&mut self) {
+        self.path.clear();
+        self.characteristic_kind = 0;
+        self.is_framework = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CxxCompilationUnitDetails_HeaderSearchDir {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CxxCompilationUnitDetails_HeaderSearchDir {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CxxCompilationUnitDetails_HeaderSearchInfo {
+    // message fields
+    pub first_angled_dir: i32,
+    pub first_system_dir: i32,
+    pub dir: ::protobuf::RepeatedField<CxxCompilationUnitDetails_HeaderSearchDir>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CxxCompilationUnitDetails_HeaderSearchInfo {
+    fn default() -> &'a CxxCompilationUnitDetails_HeaderSearchInfo {
+        <CxxCompilationUnitDetails_HeaderSearchInfo as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CxxCompilationUnitDetails_HeaderSearchInfo {
+    pub fn new() -> CxxCompilationUnitDetails_HeaderSearchInfo {
+        ::std::default::Default::default()
+    }
+
+    // int32 first_angled_dir = 1;
+
+
+    pub fn get_first_angled_dir(&self) -> i32 {
+        self.first_angled_dir
+    }
+    pub fn clear_first_angled_dir(&mut self) {
+        self.first_angled_dir = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_first_angled_dir(&mut self, v: i32) {
+        self.first_angled_dir = v;
+    }
+
+    // int32 first_system_dir = 2;
+
+
+    pub fn get_first_system_dir(&self) -> i32 {
+        self.first_system_dir
+    }
+    pub fn clear_first_system_dir(&mut self) {
+        self.first_system_dir = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_first_system_dir(&mut self, v: i32) {
+        self.first_system_dir = v;
+    }
+
+    // repeated .kythe.proto.CxxCompilationUnitDetails.HeaderSearchDir dir = 3;
+
+
+    pub fn get_dir(&self) -> &[CxxCompilationUnitDetails_HeaderSearchDir] {
+        &self.dir
+    }
+    pub fn clear_dir(&mut self) {
+        self.dir.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_dir(&mut self, v: ::protobuf::RepeatedField<CxxCompilationUnitDetails_HeaderSearchDir>) {
+        self.dir = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_dir(&mut self) -> &mut ::protobuf::RepeatedField<CxxCompilationUnitDetails_HeaderSearchDir> {
+        &mut self.dir
+    }
+
+    // Take field
+    pub fn take_dir(&mut self) -> ::protobuf::RepeatedField<CxxCompilationUnitDetails_HeaderSearchDir> {
+        ::std::mem::replace(&mut self.dir, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for CxxCompilationUnitDetails_HeaderSearchInfo {
+    fn is_initialized(&self) -> bool {
+        for v in &self.dir {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.first_angled_dir = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.first_system_dir = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.dir)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.first_angled_dir != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.first_angled_dir, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.first_system_dir != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.first_system_dir, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.dir {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.first_angled_dir != 0 {
+            os.write_int32(1, self.first_angled_dir)?;
+        }
+        if self.first_system_dir != 0 {
+            os.write_int32(2, self.first_system_dir)?;
+        }
+        for v in &self.dir {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CxxCompilationUnitDetails_HeaderSearchInfo {
+        CxxCompilationUnitDetails_HeaderSearchInfo::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "first_angled_dir",
+                |m: &CxxCompilationUnitDetails_HeaderSearchInfo| { &m.first_angled_dir },
+                |m: &mut CxxCompilationUnitDetails_HeaderSearchInfo| { &mut m.first_angled_dir },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "first_system_dir",
+                |m: &CxxCompilationUnitDetails_HeaderSearchInfo| { &m.first_system_dir },
+                |m: &mut CxxCompilationUnitDetails_HeaderSearchInfo| { &mut m.first_system_dir },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CxxCompilationUnitDetails_HeaderSearchDir>>(
+                "dir",
+                |m: &CxxCompilationUnitDetails_HeaderSearchInfo| { &m.dir },
+                |m: &mut CxxCompilationUnitDetails_HeaderSearchInfo| { &mut m.dir },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CxxCompilationUnitDetails_HeaderSearchInfo>(
+                "CxxCompilationUnitDetails.HeaderSearchInfo",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CxxCompilationUnitDetails_HeaderSearchInfo {
+        static instance: ::protobuf::rt::LazyV2<CxxCompilationUnitDetails_HeaderSearchInfo> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CxxCompilationUnitDetails_HeaderSearchInfo::new)
+    }
+}
+
+impl ::protobuf::Clear for CxxCompilationUnitDetails_HeaderSearchInfo {
+    fn clear(&mut self) {
+        self.first_angled_dir = 0;
+        self.first_system_dir = 0;
+        self.dir.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CxxCompilationUnitDetails_HeaderSearchInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CxxCompilationUnitDetails_HeaderSearchInfo {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CxxCompilationUnitDetails_SystemHeaderPrefix {
+    // message fields
+    pub prefix: ::std::string::String,
+    pub is_system_header: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CxxCompilationUnitDetails_SystemHeaderPrefix {
+    fn default() -> &'a CxxCompilationUnitDetails_SystemHeaderPrefix {
+        <CxxCompilationUnitDetails_SystemHeaderPrefix as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CxxCompilationUnitDetails_SystemHeaderPrefix {
+    pub fn new() -> CxxCompilationUnitDetails_SystemHeaderPrefix {
+        ::std::default::Default::default()
+    }
+
+    // string prefix = 1;
+
+
+    pub fn get_prefix(&self) -> &str {
+        &self.prefix
+    }
+    pub fn clear_prefix(&mut self) {
+        self.prefix.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_prefix(&mut self, v: ::std::string::String) {
+        self.prefix = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_prefix(&mut self) -> &mut ::std::string::String {
+        &mut self.prefix
+    }
+
+    // Take field
+    pub fn take_prefix(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.prefix, ::std::string::String::new())
+    }
+
+    // bool is_system_header = 2;
+
+
+    pub fn get_is_system_header(&self) -> bool {
+        self.is_system_header
+    }
+    pub fn clear_is_system_header(&mut self) {
+        self.is_system_header = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_system_header(&mut self, v: bool) {
+        self.is_system_header = v;
+    }
+}
+
+impl ::protobuf::Message for CxxCompilationUnitDetails_SystemHeaderPrefix {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.prefix)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_system_header = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.prefix.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.prefix);
+        }
+        if self.is_system_header != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.prefix.is_empty() {
+            os.write_string(1, &self.prefix)?;
+        }
+        if self.is_system_header != false {
+            os.write_bool(2, self.is_system_header)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CxxCompilationUnitDetails_SystemHeaderPrefix {
+        CxxCompilationUnitDetails_SystemHeaderPrefix::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "prefix",
+                |m: &CxxCompilationUnitDetails_SystemHeaderPrefix| { &m.prefix },
+                |m: &mut CxxCompilationUnitDetails_SystemHeaderPrefix| { &mut m.prefix },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_system_header",
+                |m: &CxxCompilationUnitDetails_SystemHeaderPrefix| { &m.is_system_header },
+                |m: &mut CxxCompilationUnitDetails_SystemHeaderPrefix| { &mut m.is_system_header },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CxxCompilationUnitDetails_SystemHeaderPrefix>(
+                "CxxCompilationUnitDetails.SystemHeaderPrefix",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CxxCompilationUnitDetails_SystemHeaderPrefix {
+        static instance: ::protobuf::rt::LazyV2<CxxCompilationUnitDetails_SystemHeaderPrefix> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CxxCompilationUnitDetails_SystemHeaderPrefix::new)
+    }
+}
+
+impl ::protobuf::Clear for CxxCompilationUnitDetails_SystemHeaderPrefix {
+    fn clear(&mut self) {
+        self.prefix.clear();
+        self.is_system_header = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CxxCompilationUnitDetails_SystemHeaderPrefix {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CxxCompilationUnitDetails_SystemHeaderPrefix {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CxxCompilationUnitDetails_StatPath {
+    // message fields
+    pub path: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CxxCompilationUnitDetails_StatPath {
+    fn default() -> &'a CxxCompilationUnitDetails_StatPath {
+        <CxxCompilationUnitDetails_StatPath as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CxxCompilationUnitDetails_StatPath {
+    pub fn new() -> CxxCompilationUnitDetails_StatPath {
+        ::std::default::Default::default()
+    }
+
+    // string path = 1;
+
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        &mut self.path
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.path, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for CxxCompilationUnitDetails_StatPath {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.path);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.path.is_empty() {
+            os.write_string(1, &self.path)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CxxCompilationUnitDetails_StatPath {
+        CxxCompilationUnitDetails_StatPath::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "path",
+                |m: &CxxCompilationUnitDetails_StatPath| { &m.path },
+                |m: &mut CxxCompilationUnitDetails_StatPath| { &mut m.path },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CxxCompilationUnitDetails_StatPath>(
+                "CxxCompilationUnitDetails.StatPath",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CxxCompilationUnitDetails_StatPath {
+        static instance: ::protobuf::rt::LazyV2<CxxCompilationUnitDetails_StatPath> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CxxCompilationUnitDetails_StatPath::new)
+    }
+}
+
+impl ::protobuf::Clear for CxxCompilationUnitDetails_StatPath {
+    fn clear(&mut self) {
+        self.path.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CxxCompilationUnitDetails_StatPath {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CxxCompilationUnitDetails_StatPath {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x15kythe/proto/cxx.proto\x12\x0bkythe.proto\"\xe3\x05\n\x19CxxCompila\
+    tionUnitDetails\x12e\n\x12header_search_info\x18\x01\x20\x01(\x0b27.kyth\
+    e.proto.CxxCompilationUnitDetails.HeaderSearchInfoR\x10headerSearchInfo\
+    \x12k\n\x14system_header_prefix\x18\x02\x20\x03(\x0b29.kythe.proto.CxxCo\
+    mpilationUnitDetails.SystemHeaderPrefixR\x12systemHeaderPrefix\x12L\n\ts\
+    tat_path\x18\x03\x20\x03(\x0b2/.kythe.proto.CxxCompilationUnitDetails.St\
+    atPathR\x08statPath\x1ay\n\x0fHeaderSearchDir\x12\x12\n\x04path\x18\x01\
+    \x20\x01(\tR\x04path\x12/\n\x13characteristic_kind\x18\x02\x20\x01(\x05R\
+    \x12characteristicKind\x12!\n\x0cis_framework\x18\x03\x20\x01(\x08R\x0bi\
+    sFramework\x1a\xb0\x01\n\x10HeaderSearchInfo\x12(\n\x10first_angled_dir\
+    \x18\x01\x20\x01(\x05R\x0efirstAngledDir\x12(\n\x10first_system_dir\x18\
+    \x02\x20\x01(\x05R\x0efirstSystemDir\x12H\n\x03dir\x18\x03\x20\x03(\x0b2\
+    6.kythe.proto.CxxCompilationUnitDetails.HeaderSearchDirR\x03dir\x1aV\n\
+    \x12SystemHeaderPrefix\x12\x16\n\x06prefix\x18\x01\x20\x01(\tR\x06prefix\
+    \x12(\n\x10is_system_header\x18\x02\x20\x01(\x08R\x0eisSystemHeader\x1a\
+    \x1e\n\x08StatPath\x12\x12\n\x04path\x18\x01\x20\x01(\tR\x04pathBD\n\x1f\
+    com.google.devtools.kythe.protoZ!kythe.io/kythe/proto/cxx_go_protob\x06p\
+    roto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
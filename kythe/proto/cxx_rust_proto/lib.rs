@@ -0,0 +1,3 @@
+extern crate protobuf;
+pub mod cxx;
+pub use cxx::*;
\ No newline at end of file
@@ -0,0 +1,3329 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `kythe/proto/explore.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct NodeData {
+    // message fields
+    pub kind: ::std::string::String,
+    pub subkind: ::std::string::String,
+    pub locations: ::protobuf::RepeatedField<super::xref::Location>,
+    pub definition_anchor: ::std::string::String,
+    pub code: ::protobuf::SingularPtrField<super::common::MarkedSource>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a NodeData {
+    fn default() -> &'a NodeData {
+        <NodeData as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl NodeData {
+    pub fn new() -> NodeData {
+        ::std::default::Default::default()
+    }
+
+    // string kind = 1;
+
+
+    pub fn get_kind(&self) -> &str {
+        &self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: ::std::string::String) {
+        self.kind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_kind(&mut self) -> &mut ::std::string::String {
+        &mut self.kind
+    }
+
+    // Take field
+    pub fn take_kind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.kind, ::std::string::String::new())
+    }
+
+    // string subkind = 2;
+
+
+    pub fn get_subkind(&self) -> &str {
+        &self.subkind
+    }
+    pub fn clear_subkind(&mut self) {
+        self.subkind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_subkind(&mut self, v: ::std::string::String) {
+        self.subkind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_subkind(&mut self) -> &mut ::std::string::String {
+        &mut self.subkind
+    }
+
+    // Take field
+    pub fn take_subkind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.subkind, ::std::string::String::new())
+    }
+
+    // repeated .kythe.proto.Location locations = 3;
+
+
+    pub fn get_locations(&self) -> &[super::xref::Location] {
+        &self.locations
+    }
+    pub fn clear_locations(&mut self) {
+        self.locations.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_locations(&mut self, v: ::protobuf::RepeatedField<super::xref::Location>) {
+        self.locations = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_locations(&mut self) -> &mut ::protobuf::RepeatedField<super::xref::Location> {
+        &mut self.locations
+    }
+
+    // Take field
+    pub fn take_locations(&mut self) -> ::protobuf::RepeatedField<super::xref::Location> {
+        ::std::mem::replace(&mut self.locations, ::protobuf::RepeatedField::new())
+    }
+
+    // string definition_anchor = 4;
+
+
+    pub fn get_definition_anchor(&self) -> &str {
+        &self.definition_anchor
+    }
+    pub fn clear_definition_anchor(&mut self) {
+        self.definition_anchor.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_definition_anchor(&mut self, v: ::std::string::String) {
+        self.definition_anchor = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_definition_anchor(&mut self) -> &mut ::std::string::String {
+        &mut self.definition_anchor
+    }
+
+    // Take field
+    pub fn take_definition_anchor(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.definition_anchor, ::std::string::String::new())
+    }
+
+    // .kythe.proto.common.MarkedSource code = 5;
+
+
+    pub fn get_code(&self) -> &super::common::MarkedSource {
+        self.code.as_ref().unwrap_or_else(|| <super::common::MarkedSource as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_code(&mut self) {
+        self.code.clear();
+    }
+
+    pub fn has_code(&self) -> bool {
+        self.code.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_code(&mut self, v: super::common::MarkedSource) {
+        self.code = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_code(&mut self) -> &mut super::common::MarkedSource {
+        if self.code.is_none() {
+            self.code.set_default();
+        }
+        self.code.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_code(&mut self) -> super::common::MarkedSource {
+        self.code.take().unwrap_or_else(|| super::common::MarkedSource::new())
+    }
+}
+
+impl ::protobuf::Message for NodeData {
+    fn is_initialized(&self) -> bool {
+        for v in &self.locations {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.code {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.kind)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.subkind)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.locations)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.definition_anchor)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.code)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.kind.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.kind);
+        }
+        if !self.subkind.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.subkind);
+        }
+        for value in &self.locations {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.definition_anchor.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.definition_anchor);
+        }
+        if let Some(ref v) = self.code.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.kind.is_empty() {
+            os.write_string(1, &self.kind)?;
+        }
+        if !self.subkind.is_empty() {
+            os.write_string(2, &self.subkind)?;
+        }
+        for v in &self.locations {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if !self.definition_anchor.is_empty() {
+            os.write_string(4, &self.definition_anchor)?;
+        }
+        if let Some(ref v) = self.code.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> NodeData {
+        NodeData::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "kind",
+                |m: &NodeData| { &m.kind },
+                |m: &mut NodeData| { &mut m.kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "subkind",
+                |m: &NodeData| { &m.subkind },
+                |m: &mut NodeData| { &mut m.subkind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::xref::Location>>(
+                "locations",
+                |m: &NodeData| { &m.locations },
+                |m: &mut NodeData| { &mut m.locations },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "definition_anchor",
+                |m: &NodeData| { &m.definition_anchor },
+                |m: &mut NodeData| { &mut m.definition_anchor },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::MarkedSource>>(
+                "code",
+                |m: &NodeData| { &m.code },
+                |m: &mut NodeData| { &mut m.code },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<NodeData>(
+                "NodeData",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static NodeData {
+        static instance: ::protobuf::rt::LazyV2<NodeData> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(NodeData::new)
+    }
+}
+
+impl ::protobuf::Clear for NodeData {
+    fn clear(&mut self) {
+        self.kind.clear();
+        self.subkind.clear();
+        self.locations.clear();
+        self.definition_anchor.clear();
+        self.code.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for NodeData {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for NodeData {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GraphNode {
+    // message fields
+    pub node_data: ::protobuf::SingularPtrField<NodeData>,
+    pub predecessors: ::protobuf::RepeatedField<::std::string::String>,
+    pub successors: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GraphNode {
+    fn default() -> &'a GraphNode {
+        <GraphNode as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GraphNode {
+    pub fn new() -> GraphNode {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.NodeData node_data = 1;
+
+
+    pub fn get_node_data(&self) -> &NodeData {
+        self.node_data.as_ref().unwrap_or_else(|| <NodeData as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_node_data(&mut self) {
+        self.node_data.clear();
+    }
+
+    pub fn has_node_data(&self) -> bool {
+        self.node_data.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_node_data(&mut self, v: NodeData) {
+        self.node_data = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_node_data(&mut self) -> &mut NodeData {
+        if self.node_data.is_none() {
+            self.node_data.set_default();
+        }
+        self.node_data.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_node_data(&mut self) -> NodeData {
+        self.node_data.take().unwrap_or_else(|| NodeData::new())
+    }
+
+    // repeated string predecessors = 2;
+
+
+    pub fn get_predecessors(&self) -> &[::std::string::String] {
+        &self.predecessors
+    }
+    pub fn clear_predecessors(&mut self) {
+        self.predecessors.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_predecessors(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.predecessors = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_predecessors(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.predecessors
+    }
+
+    // Take field
+    pub fn take_predecessors(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.predecessors, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string successors = 3;
+
+
+    pub fn get_successors(&self) -> &[::std::string::String] {
+        &self.successors
+    }
+    pub fn clear_successors(&mut self) {
+        self.successors.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_successors(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.successors = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_successors(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.successors
+    }
+
+    // Take field
+    pub fn take_successors(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.successors, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for GraphNode {
+    fn is_initialized(&self) -> bool {
+        for v in &self.node_data {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.node_data)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.predecessors)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.successors)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.node_data.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.predecessors {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in &self.successors {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.node_data.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.predecessors {
+            os.write_string(2, &v)?;
+        };
+        for v in &self.successors {
+            os.write_string(3, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GraphNode {
+        GraphNode::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<NodeData>>(
+                "node_data",
+                |m: &GraphNode| { &m.node_data },
+                |m: &mut GraphNode| { &mut m.node_data },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "predecessors",
+                |m: &GraphNode| { &m.predecessors },
+                |m: &mut GraphNode| { &mut m.predecessors },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "successors",
+                |m: &GraphNode| { &m.successors },
+                |m: &mut GraphNode| { &mut m.successors },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GraphNode>(
+                "GraphNode",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GraphNode {
+        static instance: ::protobuf::rt::LazyV2<GraphNode> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GraphNode::new)
+    }
+}
+
+impl ::protobuf::Clear for GraphNode {
+    fn clear(&mut self) {
+        self.node_data.clear();
+        self.predecessors.clear();
+        self.successors.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GraphNode {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GraphNode {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Graph {
+    // message fields
+    pub nodes: ::std::collections::HashMap<::std::string::String, GraphNode>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Graph {
+    fn default() -> &'a Graph {
+        <Graph as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Graph {
+    pub fn new() -> Graph {
+        ::std::default::Default::default()
+    }
+
+    // repeated .kythe.proto.Graph.NodesEntry nodes = 1;
+
+
+    pub fn get_nodes(&self) -> &::std::collections::HashMap<::std::string::String, GraphNode> {
+        &self.nodes
+    }
+    pub fn clear_nodes(&mut self) {
+        self.nodes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nodes(&mut self, v: ::std::collections::HashMap<::std::string::String, GraphNode>) {
+        self.nodes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_nodes(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, GraphNode> {
+        &mut self.nodes
+    }
+
+    // Take field
+    pub fn take_nodes(&mut self) -> ::std::collections::HashMap<::std::string::String, GraphNode> {
+        ::std::mem::replace(&mut self.nodes, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for Graph {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<GraphNode>>(wire_type, is, &mut self.nodes)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<GraphNode>>(1, &self.nodes);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<GraphNode>>(1, &self.nodes, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Graph {
+        Graph::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<GraphNode>>(
+                "nodes",
+                |m: &Graph| { &m.nodes },
+                |m: &mut Graph| { &mut m.nodes },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Graph>(
+                "Graph",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Graph {
+        static instance: ::protobuf::rt::LazyV2<Graph> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Graph::new)
+    }
+}
+
+impl ::protobuf::Clear for Graph {
+    fn clear(&mut self) {
+        self.nodes.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Graph {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Graph {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct NodeFilter {
+    // message fields
+    pub included_languages: ::protobuf::RepeatedField<::std::string::String>,
+    pub included_files: ::protobuf::RepeatedField<super::storage::VName>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a NodeFilter {
+    fn default() -> &'a NodeFilter {
+        <NodeFilter as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl NodeFilter {
+    pub fn new() -> NodeFilter {
+        ::std::default::Default::default()
+    }
+
+    // repeated string included_languages = 1;
+
+
+    pub fn get_included_languages(&self) -> &[::std::string::String] {
+        &self.included_languages
+    }
+    pub fn clear_included_languages(&mut self) {
+        self.included_languages.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_included_languages(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.included_languages = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_included_languages(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.included_languages
+    }
+
+    // Take field
+    pub fn take_included_languages(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.included_languages, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .kythe.proto.VName included_files = 2;
+
+
+    pub fn get_included_files(&self) -> &[super::storage::VName] {
+        &self.included_files
+    }
+    pub fn clear_included_files(&mut self) {
+        self.included_files.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_included_files(&mut self, v: ::protobuf::RepeatedField<super::storage::VName>) {
+        self.included_files = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_included_files(&mut self) -> &mut ::protobuf::RepeatedField<super::storage::VName> {
+        &mut self.included_files
+    }
+
+    // Take field
+    pub fn take_included_files(&mut self) -> ::protobuf::RepeatedField<super::storage::VName> {
+        ::std::mem::replace(&mut self.included_files, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for NodeFilter {
+    fn is_initialized(&self) -> bool {
+        for v in &self.included_files {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.included_languages)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.included_files)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.included_languages {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in &self.included_files {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.included_languages {
+            os.write_string(1, &v)?;
+        };
+        for v in &self.included_files {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> NodeFilter {
+        NodeFilter::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "included_languages",
+                |m: &NodeFilter| { &m.included_languages },
+                |m: &mut NodeFilter| { &mut m.included_languages },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::storage::VName>>(
+                "included_files",
+                |m: &NodeFilter| { &m.included_files },
+                |m: &mut NodeFilter| { &mut m.included_files },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<NodeFilter>(
+                "NodeFilter",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static NodeFilter {
+        static instance: ::protobuf::rt::LazyV2<NodeFilter> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(NodeFilter::new)
+    }
+}
+
+impl ::protobuf::Clear for NodeFilter {
+    fn clear(&mut self) {
+        self.included_languages.clear();
+        self.included_files.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for NodeFilter {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for NodeFilter {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Tickets {
+    // message fields
+    pub tickets: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Tickets {
+    fn default() -> &'a Tickets {
+        <Tickets as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Tickets {
+    pub fn new() -> Tickets {
+        ::std::default::Default::default()
+    }
+
+    // repeated string tickets = 1;
+
+
+    pub fn get_tickets(&self) -> &[::std::string::String] {
+        &self.tickets
+    }
+    pub fn clear_tickets(&mut self) {
+        self.tickets.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tickets(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.tickets = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tickets(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.tickets
+    }
+
+    // Take field
+    pub fn take_tickets(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.tickets, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Tickets {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.tickets)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.tickets {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.tickets {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Tickets {
+        Tickets::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tickets",
+                |m: &Tickets| { &m.tickets },
+                |m: &mut Tickets| { &mut m.tickets },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Tickets>(
+                "Tickets",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Tickets {
+        static instance: ::protobuf::rt::LazyV2<Tickets> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Tickets::new)
+    }
+}
+
+impl ::protobuf::Clear for Tickets {
+    fn clear(&mut self) {
+        self.tickets.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Tickets {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Tickets {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TypeHierarchyRequest {
+    // message fields
+    pub type_ticket: ::std::string::String,
+    pub node_filter: ::protobuf::SingularPtrField<NodeFilter>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a TypeHierarchyRequest {
+    fn default() -> &'a TypeHierarchyRequest {
+        <TypeHierarchyRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TypeHierarchyRequest {
+    pub fn new() -> TypeHierarchyRequest {
+        ::std::default::Default::default()
+    }
+
+    // string type_ticket = 1;
+
+
+    pub fn get_type_ticket(&self) -> &str {
+        &self.type_ticket
+    }
+    pub fn clear_type_ticket(&mut self) {
+        self.type_ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_type_ticket(&mut self, v: ::std::string::String) {
+        self.type_ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_type_ticket(&mut self) -> &mut ::std::string::String {
+        &mut self.type_ticket
+    }
+
+    // Take field
+    pub fn take_type_ticket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.type_ticket, ::std::string::String::new())
+    }
+
+    // .kythe.proto.NodeFilter node_filter = 2;
+
+
+    pub fn get_node_filter(&self) -> &NodeFilter {
+        self.node_filter.as_ref().unwrap_or_else(|| <NodeFilter as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_node_filter(&mut self) {
+        self.node_filter.clear();
+    }
+
+    pub fn has_node_filter(&self) -> bool {
+        self.node_filter.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_node_filter(&mut self, v: NodeFilter) {
+        self.node_filter = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_node_filter(&mut self) -> &mut NodeFilter {
+        if self.node_filter.is_none() {
+            self.node_filter.set_default();
+        }
+        self.node_filter.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_node_filter(&mut self) -> NodeFilter {
+        self.node_filter.take().unwrap_or_else(|| NodeFilter::new())
+    }
+}
+
+impl ::protobuf::Message for TypeHierarchyRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.node_filter {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.type_ticket)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.node_filter)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.type_ticket.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.type_ticket);
+        }
+        if let Some(ref v) = self.node_filter.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.type_ticket.is_empty() {
+            os.write_string(1, &self.type_ticket)?;
+        }
+        if let Some(ref v) = self.node_filter.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TypeHierarchyRequest {
+        TypeHierarchyRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "type_ticket",
+                |m: &TypeHierarchyRequest| { &m.type_ticket },
+                |m: &mut TypeHierarchyRequest| { &mut m.type_ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<NodeFilter>>(
+                "node_filter",
+                |m: &TypeHierarchyRequest| { &m.node_filter },
+                |m: &mut TypeHierarchyRequest| { &mut m.node_filter },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<TypeHierarchyRequest>(
+                "TypeHierarchyRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static TypeHierarchyRequest {
+        static instance: ::protobuf::rt::LazyV2<TypeHierarchyRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(TypeHierarchyRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for TypeHierarchyRequest {
+    fn clear(&mut self) {
+        self.type_ticket.clear();
+        self.node_filter.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TypeHierarchyRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TypeHierarchyRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TypeHierarchyReply {
+    // message fields
+    pub type_ticket: ::std::string::String,
+    pub graph: ::protobuf::SingularPtrField<Graph>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a TypeHierarchyReply {
+    fn default() -> &'a TypeHierarchyReply {
+        <TypeHierarchyReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TypeHierarchyReply {
+    pub fn new() -> TypeHierarchyReply {
+        ::std::default::Default::default()
+    }
+
+    // string type_ticket = 1;
+
+
+    pub fn get_type_ticket(&self) -> &str {
+        &self.type_ticket
+    }
+    pub fn clear_type_ticket(&mut self) {
+        self.type_ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_type_ticket(&mut self, v: ::std::string::String) {
+        self.type_ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_type_ticket(&mut self) -> &mut ::std::string::String {
+        &mut self.type_ticket
+    }
+
+    // Take field
+    pub fn take_type_ticket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.type_ticket, ::std::string::String::new())
+    }
+
+    // .kythe.proto.Graph graph = 2;
+
+
+    pub fn get_graph(&self) -> &Graph {
+        self.graph.as_ref().unwrap_or_else(|| <Graph as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_graph(&mut self) {
+        self.graph.clear();
+    }
+
+    pub fn has_graph(&self) -> bool {
+        self.graph.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_graph(&mut self, v: Graph) {
+        self.graph = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_graph(&mut self) -> &mut Graph {
+        if self.graph.is_none() {
+            self.graph.set_default();
+        }
+        self.graph.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_graph(&mut self) -> Graph {
+        self.graph.take().unwrap_or_else(|| Graph::new())
+    }
+}
+
+impl ::protobuf::Message for TypeHierarchyReply {
+    fn is_initialized(&self) -> bool {
+        for v in &self.graph {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.type_ticket)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.graph)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.type_ticket.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.type_ticket);
+        }
+        if let Some(ref v) = self.graph.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.type_ticket.is_empty() {
+            os.write_string(1, &self.type_ticket)?;
+        }
+        if let Some(ref v) = self.graph.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TypeHierarchyReply {
+        TypeHierarchyReply::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "type_ticket",
+                |m: &TypeHierarchyReply| { &m.type_ticket },
+                |m: &mut TypeHierarchyReply| { &mut m.type_ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Graph>>(
+                "graph",
+                |m: &TypeHierarchyReply| { &m.graph },
+                |m: &mut TypeHierarchyReply| { &mut m.graph },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<TypeHierarchyReply>(
+                "TypeHierarchyReply",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static TypeHierarchyReply {
+        static instance: ::protobuf::rt::LazyV2<TypeHierarchyReply> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(TypeHierarchyReply::new)
+    }
+}
+
+impl ::protobuf::Clear for TypeHierarchyReply {
+    fn clear(&mut self) {
+        self.type_ticket.clear();
+        self.graph.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TypeHierarchyReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TypeHierarchyReply {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CallersRequest {
+    // message fields
+    pub tickets: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CallersRequest {
+    fn default() -> &'a CallersRequest {
+        <CallersRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CallersRequest {
+    pub fn new() -> CallersRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated string tickets = 1;
+
+
+    pub fn get_tickets(&self) -> &[::std::string::String] {
+        &self.tickets
+    }
+    pub fn clear_tickets(&mut self) {
+        self.tickets.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tickets(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.tickets = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tickets(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.tickets
+    }
+
+    // Take field
+    pub fn take_tickets(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.tickets, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for CallersRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.tickets)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.tickets {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.tickets {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CallersRequest {
+        CallersRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tickets",
+                |m: &CallersRequest| { &m.tickets },
+                |m: &mut CallersRequest| { &mut m.tickets },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CallersRequest>(
+                "CallersRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CallersRequest {
+        static instance: ::protobuf::rt::LazyV2<CallersRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CallersRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for CallersRequest {
+    fn clear(&mut self) {
+        self.tickets.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CallersRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CallersRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CallersReply {
+    // message fields
+    pub graph: ::protobuf::SingularPtrField<Graph>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CallersReply {
+    fn default() -> &'a CallersReply {
+        <CallersReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CallersReply {
+    pub fn new() -> CallersReply {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.Graph graph = 1;
+
+
+    pub fn get_graph(&self) -> &Graph {
+        self.graph.as_ref().unwrap_or_else(|| <Graph as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_graph(&mut self) {
+        self.graph.clear();
+    }
+
+    pub fn has_graph(&self) -> bool {
+        self.graph.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_graph(&mut self, v: Graph) {
+        self.graph = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_graph(&mut self) -> &mut Graph {
+        if self.graph.is_none() {
+            self.graph.set_default();
+        }
+        self.graph.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_graph(&mut self) -> Graph {
+        self.graph.take().unwrap_or_else(|| Graph::new())
+    }
+}
+
+impl ::protobuf::Message for CallersReply {
+    fn is_initialized(&self) -> bool {
+        for v in &self.graph {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.graph)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.graph.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.graph.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CallersReply {
+        CallersReply::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Graph>>(
+                "graph",
+                |m: &CallersReply| { &m.graph },
+                |m: &mut CallersReply| { &mut m.graph },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CallersReply>(
+                "CallersReply",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CallersReply {
+        static instance: ::protobuf::rt::LazyV2<CallersReply> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CallersReply::new)
+    }
+}
+
+impl ::protobuf::Clear for CallersReply {
+    fn clear(&mut self) {
+        self.graph.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CallersReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CallersReply {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CalleesRequest {
+    // message fields
+    pub tickets: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CalleesRequest {
+    fn default() -> &'a CalleesRequest {
+        <CalleesRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CalleesRequest {
+    pub fn new() -> CalleesRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated string tickets = 1;
+
+
+    pub fn get_tickets(&self) -> &[::std::string::String] {
+        &self.tickets
+    }
+    pub fn clear_tickets(&mut self) {
+        self.tickets.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tickets(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.tickets = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tickets(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.tickets
+    }
+
+    // Take field
+    pub fn take_tickets(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.tickets, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for CalleesRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.tickets)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.tickets {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.tickets {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CalleesRequest {
+        CalleesRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tickets",
+                |m: &CalleesRequest| { &m.tickets },
+                |m: &mut CalleesRequest| { &mut m.tickets },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CalleesRequest>(
+                "CalleesRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CalleesRequest {
+        static instance: ::protobuf::rt::LazyV2<CalleesRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CalleesRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for CalleesRequest {
+    fn clear(&mut self) {
+        self.tickets.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CalleesRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CalleesRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CalleesReply {
+    // message fields
+    pub graph: ::protobuf::SingularPtrField<Graph>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CalleesReply {
+    fn default() -> &'a CalleesReply {
+        <CalleesReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CalleesReply {
+    pub fn new() -> CalleesReply {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.Graph graph = 1;
+
+
+    pub fn get_graph(&self) -> &Graph {
+        self.graph.as_ref().unwrap_or_else(|| <Graph as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_graph(&mut self) {
+        self.graph.clear();
+    }
+
+    pub fn has_graph(&self) -> bool {
+        self.graph.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_graph(&mut self, v: Graph) {
+        self.graph = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_graph(&mut self) -> &mut Graph {
+        if self.graph.is_none() {
+            self.graph.set_default();
+        }
+        self.graph.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_graph(&mut self) -> Graph {
+        self.graph.take().unwrap_or_else(|| Graph::new())
+    }
+}
+
+impl ::protobuf::Message for CalleesReply {
+    fn is_initialized(&self) -> bool {
+        for v in &self.graph {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.graph)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.graph.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.graph.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CalleesReply {
+        CalleesReply::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Graph>>(
+                "graph",
+                |m: &CalleesReply| { &m.graph },
+                |m: &mut CalleesReply| { &mut m.graph },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CalleesReply>(
+                "CalleesReply",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CalleesReply {
+        static instance: ::protobuf::rt::LazyV2<CalleesReply> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CalleesReply::new)
+    }
+}
+
+impl ::protobuf::Clear for CalleesReply {
+    fn clear(&mut self) {
+        self.graph.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CalleesReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CalleesReply {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ParametersRequest {
+    // message fields
+    pub function_tickets: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ParametersRequest {
+    fn default() -> &'a ParametersRequest {
+        <ParametersRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ParametersRequest {
+    pub fn new() -> ParametersRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated string function_tickets = 1;
+
+
+    pub fn get_function_tickets(&self) -> &[::std::string::String] {
+        &self.function_tickets
+    }
+    pub fn clear_function_tickets(&mut self) {
+        self.function_tickets.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_function_tickets(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.function_tickets = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_function_tickets(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.function_tickets
+    }
+
+    // Take field
+    pub fn take_function_tickets(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.function_tickets, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for ParametersRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.function_tickets)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.function_tickets {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.function_tickets {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ParametersRequest {
+        ParametersRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "function_tickets",
+                |m: &ParametersRequest| { &m.function_tickets },
+                |m: &mut ParametersRequest| { &mut m.function_tickets },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ParametersRequest>(
+                "ParametersRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ParametersRequest {
+        static instance: ::protobuf::rt::LazyV2<ParametersRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ParametersRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ParametersRequest {
+    fn clear(&mut self) {
+        self.function_tickets.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ParametersRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ParametersRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ParametersReply {
+    // message fields
+    pub function_to_parameters: ::std::collections::HashMap<::std::string::String, Tickets>,
+    pub function_to_return_value: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    pub node_data: ::std::collections::HashMap<::std::string::String, NodeData>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ParametersReply {
+    fn default() -> &'a ParametersReply {
+        <ParametersReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ParametersReply {
+    pub fn new() -> ParametersReply {
+        ::std::default::Default::default()
+    }
+
+    // repeated .kythe.proto.ParametersReply.FunctionToParametersEntry function_to_parameters = 1;
+
+
+    pub fn get_function_to_parameters(&self) -> &::std::collections::HashMap<::std::string::String, Tickets> {
+        &self.function_to_parameters
+    }
+    pub fn clear_function_to_parameters(&mut self) {
+        self.function_to_parameters.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_function_to_parameters(&mut self, v: ::std::collections::HashMap<::std::string::String, Tickets>) {
+        self.function_to_parameters = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_function_to_parameters(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, Tickets> {
+        &mut self.function_to_parameters
+    }
+
+    // Take field
+    pub fn take_function_to_parameters(&mut self) -> ::std::collections::HashMap<::std::string::String, Tickets> {
+        ::std::mem::replace(&mut self.function_to_parameters, ::std::collections::HashMap::new())
+    }
+
+    // repeated .kythe.proto.ParametersReply.FunctionToReturnValueEntry function_to_return_value = 2;
+
+
+    pub fn get_function_to_return_value(&self) -> &::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &self.function_to_return_value
+    }
+    pub fn clear_function_to_return_value(&mut self) {
+        self.function_to_return_value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_function_to_return_value(&mut self, v: ::std::collections::HashMap<::std::string::String, ::std::string::String>) {
+        self.function_to_return_value = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_function_to_return_value(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &mut self.function_to_return_value
+    }
+
+    // Take field
+    pub fn take_function_to_return_value(&mut self) -> ::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        ::std::mem::replace(&mut self.function_to_return_value, ::std::collections::HashMap::new())
+    }
+
+    // repeated .kythe.proto.ParametersReply.NodeDataEntry node_data = 3;
+
+
+    pub fn get_node_data(&self) -> &::std::collections::HashMap<::std::string::String, NodeData> {
+        &self.node_data
+    }
+    pub fn clear_node_data(&mut self) {
+        self.node_data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_node_data(&mut self, v: ::std::collections::HashMap<::std::string::String, NodeData>) {
+        self.node_data = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_node_data(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, NodeData> {
+        &mut self.node_data
+    }
+
+    // Take field
+    pub fn take_node_data(&mut self) -> ::std::collections::HashMap<::std::string::String, NodeData> {
+        ::std::mem::replace(&mut self.node_data, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for ParametersReply {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Tickets>>(wire_type, is, &mut self.function_to_parameters)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(wire_type, is, &mut self.function_to_return_value)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<NodeData>>(wire_type, is, &mut self.node_data)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Tickets>>(1, &self.function_to_parameters);
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(2, &self.function_to_return_value);
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<NodeData>>(3, &self.node_data);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Tickets>>(1, &self.function_to_parameters, os)?;
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(2, &self.function_to_return_value, os)?;
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<NodeData>>(3, &self.node_data, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ParametersReply {
+        ParametersReply::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Tickets>>(
+                "function_to_parameters",
+                |m: &ParametersReply| { &m.function_to_parameters },
+                |m: &mut ParametersReply| { &mut m.function_to_parameters },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(
+                "function_to_return_value",
+                |m: &ParametersReply| { &m.function_to_return_value },
+                |m: &mut ParametersReply| { &mut m.function_to_return_value },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<NodeData>>(
+                "node_data",
+                |m: &ParametersReply| { &m.node_data },
+                |m: &mut ParametersReply| { &mut m.node_data },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ParametersReply>(
+                "ParametersReply",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ParametersReply {
+        static instance: ::protobuf::rt::LazyV2<ParametersReply> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ParametersReply::new)
+    }
+}
+
+impl ::protobuf::Clear for ParametersReply {
+    fn clear(&mut self) {
+        self.function_to_parameters.clear();
+        self.function_to_return_value.clear();
+        self.node_data.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ParametersReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ParametersReply {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ParentsRequest {
+    // message fields
+    pub tickets: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ParentsRequest {
+    fn default() -> &'a ParentsRequest {
+        <ParentsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ParentsRequest {
+    pub fn new() -> ParentsRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated string tickets = 1;
+
+
+    pub fn get_tickets(&self) -> &[::std::string::String] {
+        &self.tickets
+    }
+    pub fn clear_tickets(&mut self) {
+        self.tickets.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tickets(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.tickets = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tickets(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.tickets
+    }
+
+    // Take field
+    pub fn take_tickets(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.tickets, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for ParentsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.tickets)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.tickets {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.tickets {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ParentsRequest {
+        ParentsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tickets",
+                |m: &ParentsRequest| { &m.tickets },
+                |m: &mut ParentsRequest| { &mut m.tickets },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ParentsRequest>(
+                "ParentsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ParentsRequest {
+        static instance: ::protobuf::rt::LazyV2<ParentsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ParentsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ParentsRequest {
+    fn clear(&mut self) {
+        self.tickets.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ParentsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ParentsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ParentsReply {
+    // message fields
+    pub input_to_parents: ::std::collections::HashMap<::std::string::String, Tickets>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ParentsReply {
+    fn default() -> &'a ParentsReply {
+        <ParentsReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ParentsReply {
+    pub fn new() -> ParentsReply {
+        ::std::default::Default::default()
+    }
+
+    // repeated .kythe.proto.ParentsReply.InputToParentsEntry input_to_parents = 1;
+
+
+    pub fn get_input_to_parents(&self) -> &::std::collections::HashMap<::std::string::String, Tickets> {
+        &self.input_to_parents
+    }
+    pub fn clear_input_to_parents(&mut self) {
+        self.input_to_parents.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_input_to_parents(&mut self, v: ::std::collections::HashMap<::std::string::String, Tickets>) {
+        self.input_to_parents = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_input_to_parents(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, Tickets> {
+        &mut self.input_to_parents
+    }
+
+    // Take field
+    pub fn take_input_to_parents(&mut self) -> ::std::collections::HashMap<::std::string::String, Tickets> {
+        ::std::mem::replace(&mut self.input_to_parents, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for ParentsReply {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Tickets>>(wire_type, is, &mut self.input_to_parents)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Tickets>>(1, &self.input_to_parents);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Tickets>>(1, &self.input_to_parents, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ParentsReply {
+        ParentsReply::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Tickets>>(
+                "input_to_parents",
+                |m: &ParentsReply| { &m.input_to_parents },
+                |m: &mut ParentsReply| { &mut m.input_to_parents },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ParentsReply>(
+                "ParentsReply",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ParentsReply {
+        static instance: ::protobuf::rt::LazyV2<ParentsReply> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ParentsReply::new)
+    }
+}
+
+impl ::protobuf::Clear for ParentsReply {
+    fn clear(&mut self) {
+        self.input_to_parents.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ParentsReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ParentsReply {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ChildrenRequest {
+    // message fields
+    pub tickets: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ChildrenRequest {
+    fn default() -> &'a ChildrenRequest {
+        <ChildrenRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ChildrenRequest {
+    pub fn new() -> ChildrenRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated string tickets = 1;
+
+
+    pub fn get_tickets(&self) -> &[::std::string::String] {
+        &self.tickets
+    }
+    pub fn clear_tickets(&mut self) {
+        self.tickets.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tickets(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.tickets = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tickets(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.tickets
+    }
+
+    // Take field
+    pub fn take_tickets(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.tickets, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for ChildrenRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.tickets)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.tickets {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.tickets {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ChildrenRequest {
+        ChildrenRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tickets",
+                |m: &ChildrenRequest| { &m.tickets },
+                |m: &mut ChildrenRequest| { &mut m.tickets },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ChildrenRequest>(
+                "ChildrenRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ChildrenRequest {
+        static instance: ::protobuf::rt::LazyV2<ChildrenRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ChildrenRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ChildrenRequest {
+    fn clear(&mut self) {
+        self.tickets.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ChildrenRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ChildrenRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ChildrenReply {
+    // message fields
+    pub input_to_children: ::std::collections::HashMap<::std::string::String, Tickets>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ChildrenReply {
+    fn default() -> &'a ChildrenReply {
+        <ChildrenReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ChildrenReply {
+    pub fn new() -> ChildrenReply {
+        ::std::default::Default::default()
+    }
+
+    // repeated .kythe.proto.ChildrenReply.InputToChildrenEntry input_to_children = 1;
+
+
+    pub fn get_input_to_children(&self) -> &::std::collections::HashMap<::std::string::String, Tickets> {
+        &self.input_to_children
+    }
+    pub fn clear_input_to_children(&mut self) {
+        self.input_to_children.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_input_to_children(&mut self, v: ::std::collections::HashMap<::std::string::String, Tickets>) {
+        self.input_to_children = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_input_to_children(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, Tickets> {
+        &mut self.input_to_children
+    }
+
+    // Take field
+    pub fn take_input_to_children(&mut self) -> ::std::collections::HashMap<::std::string::String, Tickets> {
+        ::std::mem::replace(&mut self.input_to_children, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for ChildrenReply {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Tickets>>(wire_type, is, &mut self.input_to_children)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Tickets>>(1, &self.input_to_children);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Tickets>>(1, &self.input_to_children, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ChildrenReply {
+        ChildrenReply::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Tickets>>(
+                "input_to_children",
+                |m: &ChildrenReply| { &m.input_to_children },
+                |m: &mut ChildrenReply| { &mut m.input_to_children },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ChildrenReply>(
+                "ChildrenReply",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ChildrenReply {
+        static instance: ::protobuf::rt::LazyV2<ChildrenReply> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ChildrenReply::new)
+    }
+}
+
+impl ::protobuf::Clear for ChildrenReply {
+    fn clear(&mut self) {
+        self.input_to_children.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ChildrenReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ChildrenReply {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x19kythe/proto/explore.proto\x12\x0bkythe.proto\x1a\x18kythe/proto/co\
+    mmon.proto\x1a\x19kythe/proto/storage.proto\x1a\x16kythe/proto/xref.prot\
+    o\"\xd0\x01\n\x08NodeData\x12\x12\n\x04kind\x18\x01\x20\x01(\tR\x04kind\
+    \x12\x18\n\x07subkind\x18\x02\x20\x01(\tR\x07subkind\x123\n\tlocations\
+    \x18\x03\x20\x03(\x0b2\x15.kythe.proto.LocationR\tlocations\x12+\n\x11de\
+    finition_anchor\x18\x04\x20\x01(\tR\x10definitionAnchor\x124\n\x04code\
+    \x18\x05\x20\x01(\x0b2\x20.kythe.proto.common.MarkedSourceR\x04code\"\
+    \x83\x01\n\tGraphNode\x122\n\tnode_data\x18\x01\x20\x01(\x0b2\x15.kythe.\
+    proto.NodeDataR\x08nodeData\x12\"\n\x0cpredecessors\x18\x02\x20\x03(\tR\
+    \x0cpredecessors\x12\x1e\n\nsuccessors\x18\x03\x20\x03(\tR\nsuccessors\"\
+    \x8e\x01\n\x05Graph\x123\n\x05nodes\x18\x01\x20\x03(\x0b2\x1d.kythe.prot\
+    o.Graph.NodesEntryR\x05nodes\x1aP\n\nNodesEntry\x12\x10\n\x03key\x18\x01\
+    \x20\x01(\tR\x03key\x12,\n\x05value\x18\x02\x20\x01(\x0b2\x16.kythe.prot\
+    o.GraphNodeR\x05value:\x028\x01\"v\n\nNodeFilter\x12-\n\x12included_lang\
+    uages\x18\x01\x20\x03(\tR\x11includedLanguages\x129\n\x0eincluded_files\
+    \x18\x02\x20\x03(\x0b2\x12.kythe.proto.VNameR\rincludedFiles\"#\n\x07Tic\
+    kets\x12\x18\n\x07tickets\x18\x01\x20\x03(\tR\x07tickets\"q\n\x14TypeHie\
+    rarchyRequest\x12\x1f\n\x0btype_ticket\x18\x01\x20\x01(\tR\ntypeTicket\
+    \x128\n\x0bnode_filter\x18\x02\x20\x01(\x0b2\x17.kythe.proto.NodeFilterR\
+    \nnodeFilter\"_\n\x12TypeHierarchyReply\x12\x1f\n\x0btype_ticket\x18\x01\
+    \x20\x01(\tR\ntypeTicket\x12(\n\x05graph\x18\x02\x20\x01(\x0b2\x12.kythe\
+    .proto.GraphR\x05graph\"*\n\x0eCallersRequest\x12\x18\n\x07tickets\x18\
+    \x01\x20\x03(\tR\x07tickets\"8\n\x0cCallersReply\x12(\n\x05graph\x18\x01\
+    \x20\x01(\x0b2\x12.kythe.proto.GraphR\x05graph\"*\n\x0eCalleesRequest\
+    \x12\x18\n\x07tickets\x18\x01\x20\x03(\tR\x07tickets\"8\n\x0cCalleesRepl\
+    y\x12(\n\x05graph\x18\x01\x20\x01(\x0b2\x12.kythe.proto.GraphR\x05graph\
+    \">\n\x11ParametersRequest\x12)\n\x10function_tickets\x18\x01\x20\x03(\t\
+    R\x0ffunctionTickets\"\xb7\x04\n\x0fParametersReply\x12l\n\x16function_t\
+    o_parameters\x18\x01\x20\x03(\x0b26.kythe.proto.ParametersReply.Function\
+    ToParametersEntryR\x14functionToParameters\x12p\n\x18function_to_return_\
+    value\x18\x02\x20\x03(\x0b27.kythe.proto.ParametersReply.FunctionToRetur\
+    nValueEntryR\x15functionToReturnValue\x12G\n\tnode_data\x18\x03\x20\x03(\
+    \x0b2*.kythe.proto.ParametersReply.NodeDataEntryR\x08nodeData\x1a]\n\x19\
+    FunctionToParametersEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\
+    \x12*\n\x05value\x18\x02\x20\x01(\x0b2\x14.kythe.proto.TicketsR\x05value\
+    :\x028\x01\x1aH\n\x1aFunctionToReturnValueEntry\x12\x10\n\x03key\x18\x01\
+    \x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x02\
+    8\x01\x1aR\n\rNodeDataEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\
+    \x12+\n\x05value\x18\x02\x20\x01(\x0b2\x15.kythe.proto.NodeDataR\x05valu\
+    e:\x028\x01\"*\n\x0eParentsRequest\x12\x18\n\x07tickets\x18\x01\x20\x03(\
+    \tR\x07tickets\"\xc0\x01\n\x0cParentsReply\x12W\n\x10input_to_parents\
+    \x18\x01\x20\x03(\x0b2-.kythe.proto.ParentsReply.InputToParentsEntryR\
+    \x0einputToParents\x1aW\n\x13InputToParentsEntry\x12\x10\n\x03key\x18\
+    \x01\x20\x01(\tR\x03key\x12*\n\x05value\x18\x02\x20\x01(\x0b2\x14.kythe.\
+    proto.TicketsR\x05value:\x028\x01\"+\n\x0fChildrenRequest\x12\x18\n\x07t\
+    ickets\x18\x01\x20\x03(\tR\x07tickets\"\xc6\x01\n\rChildrenReply\x12[\n\
+    \x11input_to_children\x18\x01\x20\x03(\x0b2/.kythe.proto.ChildrenReply.I\
+    nputToChildrenEntryR\x0finputToChildren\x1aX\n\x14InputToChildrenEntry\
+    \x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12*\n\x05value\x18\x02\x20\
+    \x01(\x0b2\x14.kythe.proto.TicketsR\x05value:\x028\x012\xcc\x03\n\x0eExp\
+    loreService\x12C\n\x07Callers\x12\x1b.kythe.proto.CallersRequest\x1a\x19\
+    .kythe.proto.CallersReply\"\0\x12C\n\x07Callees\x12\x1b.kythe.proto.Call\
+    eesRequest\x1a\x19.kythe.proto.CalleesReply\"\0\x12C\n\x07Parents\x12\
+    \x1b.kythe.proto.ParentsRequest\x1a\x19.kythe.proto.ParentsReply\"\0\x12\
+    F\n\x08Children\x12\x1c.kythe.proto.ChildrenRequest\x1a\x1a.kythe.proto.\
+    ChildrenReply\"\0\x12U\n\rTypeHierarchy\x12!.kythe.proto.TypeHierarchyRe\
+    quest\x1a\x1f.kythe.proto.TypeHierarchyReply\"\0\x12L\n\nParameters\x12\
+    \x1e.kythe.proto.ParametersRequest\x1a\x1c.kythe.proto.ParametersReply\"\
+    \0BJ\n\x1fcom.google.devtools.kythe.protoP\x01Z%kythe.io/kythe/proto/exp\
+    lore_go_protob\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,11 @@
+extern crate protobuf;
+pub mod timestamp;
+pub use timestamp::*;
+pub mod common;
+pub use common::*;
+pub mod storage;
+pub use storage::*;
+pub mod xref;
+pub use xref::*;
+pub mod explore;
+pub use explore::*;
\ No newline at end of file
@@ -0,0 +1,1510 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `kythe/proto/filetree.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CorpusRootsRequest {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CorpusRootsRequest {
+    fn default() -> &'a CorpusRootsRequest {
+        <CorpusRootsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CorpusRootsRequest {
+    pub fn new() -> CorpusRootsRequest {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for CorpusRootsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CorpusRootsRequest {
+        CorpusRootsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CorpusRootsRequest>(
+                "CorpusRootsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CorpusRootsRequest {
+        static instance: ::protobuf::rt::LazyV2<CorpusRootsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CorpusRootsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for CorpusRootsRequest {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CorpusRootsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CorpusRootsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CorpusRootsReply {
+    // message fields
+    pub corpus: ::protobuf::RepeatedField<CorpusRootsReply_Corpus>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CorpusRootsReply {
+    fn default() -> &'a CorpusRootsReply {
+        <CorpusRootsReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CorpusRootsReply {
+    pub fn new() -> CorpusRootsReply {
+        ::std::default::Default::default()
+    }
+
+    // repeated .kythe.proto.CorpusRootsReply.Corpus corpus = 1;
+
+
+    pub fn get_corpus(&self) -> &[CorpusRootsReply_Corpus] {
+        &self.corpus
+    }
+    pub fn clear_corpus(&mut self) {
+        self.corpus.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_corpus(&mut self, v: ::protobuf::RepeatedField<CorpusRootsReply_Corpus>) {
+        self.corpus = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_corpus(&mut self) -> &mut ::protobuf::RepeatedField<CorpusRootsReply_Corpus> {
+        &mut self.corpus
+    }
+
+    // Take field
+    pub fn take_corpus(&mut self) -> ::protobuf::RepeatedField<CorpusRootsReply_Corpus> {
+        ::std::mem::replace(&mut self.corpus, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for CorpusRootsReply {
+    fn is_initialized(&self) -> bool {
+        for v in &self.corpus {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.corpus)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.corpus {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.corpus {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CorpusRootsReply {
+        CorpusRootsReply::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CorpusRootsReply_Corpus>>(
+                "corpus",
+                |m: &CorpusRootsReply| { &m.corpus },
+                |m: &mut CorpusRootsReply| { &mut m.corpus },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CorpusRootsReply>(
+                "CorpusRootsReply",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CorpusRootsReply {
+        static instance: ::protobuf::rt::LazyV2<CorpusRootsReply> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CorpusRootsReply::new)
+    }
+}
+
+impl ::protobuf::Clear for CorpusRootsReply {
+    fn clear(&mut self) {
+        self.corpus.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CorpusRootsReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CorpusRootsReply {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CorpusRootsReply_Corpus {
+    // message fields
+    pub name: ::std::string::String,
+    pub root: ::protobuf::RepeatedField<::std::string::String>,
+    pub build_config: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CorpusRootsReply_Corpus {
+    fn default() -> &'a CorpusRootsReply_Corpus {
+        <CorpusRootsReply_Corpus as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CorpusRootsReply_Corpus {
+    pub fn new() -> CorpusRootsReply_Corpus {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // repeated string root = 2;
+
+
+    pub fn get_root(&self) -> &[::std::string::String] {
+        &self.root
+    }
+    pub fn clear_root(&mut self) {
+        self.root.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_root(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.root = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_root(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.root
+    }
+
+    // Take field
+    pub fn take_root(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.root, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string build_config = 3;
+
+
+    pub fn get_build_config(&self) -> &[::std::string::String] {
+        &self.build_config
+    }
+    pub fn clear_build_config(&mut self) {
+        self.build_config.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_build_config(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.build_config = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_build_config(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.build_config
+    }
+
+    // Take field
+    pub fn take_build_config(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.build_config, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for CorpusRootsReply_Corpus {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.root)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.build_config)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        for value in &self.root {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in &self.build_config {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        for v in &self.root {
+            os.write_string(2, &v)?;
+        };
+        for v in &self.build_config {
+            os.write_string(3, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CorpusRootsReply_Corpus {
+        CorpusRootsReply_Corpus::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &CorpusRootsReply_Corpus| { &m.name },
+                |m: &mut CorpusRootsReply_Corpus| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "root",
+                |m: &CorpusRootsReply_Corpus| { &m.root },
+                |m: &mut CorpusRootsReply_Corpus| { &mut m.root },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "build_config",
+                |m: &CorpusRootsReply_Corpus| { &m.build_config },
+                |m: &mut CorpusRootsReply_Corpus| { &mut m.build_config },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CorpusRootsReply_Corpus>(
+                "CorpusRootsReply.Corpus",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CorpusRootsReply_Corpus {
+        static instance: ::protobuf::rt::LazyV2<CorpusRootsReply_Corpus> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CorpusRootsReply_Corpus::new)
+    }
+}
+
+impl ::protobuf::Clear for CorpusRootsReply_Corpus {
+    fn clear(&mut self) {
+        self.name.clear();
+        self.root.clear();
+        self.build_config.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CorpusRootsReply_Corpus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CorpusRootsReply_Corpus {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DirectoryRequest {
+    // message fields
+    pub corpus: ::std::string::String,
+    pub root: ::std::string::String,
+    pub path: ::std::string::String,
+    pub include_files_missing_text: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DirectoryRequest {
+    fn default() -> &'a DirectoryRequest {
+        <DirectoryRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DirectoryRequest {
+    pub fn new() -> DirectoryRequest {
+        ::std::default::Default::default()
+    }
+
+    // string corpus = 1;
+
+
+    pub fn get_corpus(&self) -> &str {
+        &self.corpus
+    }
+    pub fn clear_corpus(&mut self) {
+        self.corpus.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_corpus(&mut self, v: ::std::string::String) {
+        self.corpus = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_corpus(&mut self) -> &mut ::std::string::String {
+        &mut self.corpus
+    }
+
+    // Take field
+    pub fn take_corpus(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.corpus, ::std::string::String::new())
+    }
+
+    // string root = 2;
+
+
+    pub fn get_root(&self) -> &str {
+        &self.root
+    }
+    pub fn clear_root(&mut self) {
+        self.root.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_root(&mut self, v: ::std::string::String) {
+        self.root = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_root(&mut self) -> &mut ::std::string::String {
+        &mut self.root
+    }
+
+    // Take field
+    pub fn take_root(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.root, ::std::string::String::new())
+    }
+
+    // string path = 3;
+
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        &mut self.path
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.path, ::std::string::String::new())
+    }
+
+    // bool include_files_missing_text = 4;
+
+
+    pub fn get_include_files_missing_text(&self) -> bool {
+        self.include_files_missing_text
+    }
+    pub fn clear_include_files_missing_text(&mut self) {
+        self.include_files_missing_text = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_include_files_missing_text(&mut self, v: bool) {
+        self.include_files_missing_text = v;
+    }
+}
+
+impl ::protobuf::Message for DirectoryRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.corpus)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.root)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.include_files_missing_text = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.corpus.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.corpus);
+        }
+        if !self.root.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.root);
+        }
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.path);
+        }
+        if self.include_files_missing_text != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.corpus.is_empty() {
+            os.write_string(1, &self.corpus)?;
+        }
+        if !self.root.is_empty() {
+            os.write_string(2, &self.root)?;
+        }
+        if !self.path.is_empty() {
+            os.write_string(3, &self.path)?;
+        }
+        if self.include_files_missing_text != false {
+            os.write_bool(4, self.include_files_missing_text)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DirectoryRequest {
+        DirectoryRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "corpus",
+                |m: &DirectoryRequest| { &m.corpus },
+                |m: &mut DirectoryRequest| { &mut m.corpus },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "root",
+                |m: &DirectoryRequest| { &m.root },
+                |m: &mut DirectoryRequest| { &mut m.root },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "path",
+                |m: &DirectoryRequest| { &m.path },
+                |m: &mut DirectoryRequest| { &mut m.path },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "include_files_missing_text",
+                |m: &DirectoryRequest| { &m.include_files_missing_text },
+                |m: &mut DirectoryRequest| { &mut m.include_files_missing_text },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DirectoryRequest>(
+                "DirectoryRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DirectoryRequest {
+        static instance: ::protobuf::rt::LazyV2<DirectoryRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DirectoryRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for DirectoryRequest {
+    fn clear(&mut self) {
+        self.corpus.clear();
+        self.root.clear();
+        self.path.clear();
+        self.include_files_missing_text = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DirectoryRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DirectoryRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DirectoryReply {
+    // message fields
+    pub corpus: ::std::string::String,
+    pub root: ::std::string::String,
+    pub path: ::std::string::String,
+    pub entry: ::protobuf::RepeatedField<DirectoryReply_Entry>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DirectoryReply {
+    fn default() -> &'a DirectoryReply {
+        <DirectoryReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DirectoryReply {
+    pub fn new() -> DirectoryReply {
+        ::std::default::Default::default()
+    }
+
+    // string corpus = 3;
+
+
+    pub fn get_corpus(&self) -> &str {
+        &self.corpus
+    }
+    pub fn clear_corpus(&mut self) {
+        self.corpus.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_corpus(&mut self, v: ::std::string::String) {
+        self.corpus = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_corpus(&mut self) -> &mut ::std::string::String {
+        &mut self.corpus
+    }
+
+    // Take field
+    pub fn take_corpus(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.corpus, ::std::string::String::new())
+    }
+
+    // string root = 4;
+
+
+    pub fn get_root(&self) -> &str {
+        &self.root
+    }
+    pub fn clear_root(&mut self) {
+        self.root.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_root(&mut self, v: ::std::string::String) {
+        self.root = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_root(&mut self) -> &mut ::std::string::String {
+        &mut self.root
+    }
+
+    // Take field
+    pub fn take_root(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.root, ::std::string::String::new())
+    }
+
+    // string path = 5;
+
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        &mut self.path
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.path, ::std::string::String::new())
+    }
+
+    // repeated .kythe.proto.DirectoryReply.Entry entry = 6;
+
+
+    pub fn get_entry(&self) -> &[DirectoryReply_Entry] {
+        &self.entry
+    }
+    pub fn clear_entry(&mut self) {
+        self.entry.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_entry(&mut self, v: ::protobuf::RepeatedField<DirectoryReply_Entry>) {
+        self.entry = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_entry(&mut self) -> &mut ::protobuf::RepeatedField<DirectoryReply_Entry> {
+        &mut self.entry
+    }
+
+    // Take field
+    pub fn take_entry(&mut self) -> ::protobuf::RepeatedField<DirectoryReply_Entry> {
+        ::std::mem::replace(&mut self.entry, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for DirectoryReply {
+    fn is_initialized(&self) -> bool {
+        for v in &self.entry {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.corpus)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.root)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.entry)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.corpus.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.corpus);
+        }
+        if !self.root.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.root);
+        }
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.path);
+        }
+        for value in &self.entry {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.corpus.is_empty() {
+            os.write_string(3, &self.corpus)?;
+        }
+        if !self.root.is_empty() {
+            os.write_string(4, &self.root)?;
+        }
+        if !self.path.is_empty() {
+            os.write_string(5, &self.path)?;
+        }
+        for v in &self.entry {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DirectoryReply {
+        DirectoryReply::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "corpus",
+                |m: &DirectoryReply| { &m.corpus },
+                |m: &mut DirectoryReply| { &mut m.corpus },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "root",
+                |m: &DirectoryReply| { &m.root },
+                |m: &mut DirectoryReply| { &mut m.root },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "path",
+                |m: &DirectoryReply| { &m.path },
+                |m: &mut DirectoryReply| { &mut m.path },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DirectoryReply_Entry>>(
+                "entry",
+                |m: &DirectoryReply| { &m.entry },
+                |m: &mut DirectoryReply| { &mut m.entry },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DirectoryReply>(
+                "DirectoryReply",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DirectoryReply {
+        static instance: ::protobuf::rt::LazyV2<DirectoryReply> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DirectoryReply::new)
+    }
+}
+
+impl ::protobuf::Clear for DirectoryReply {
+    fn clear(&mut self) {
+        self.corpus.clear();
+        self.root.clear();
+        self.path.clear();
+        self.entry.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DirectoryReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DirectoryReply {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DirectoryReply_Entry {
+    // message fields
+    pub kind: DirectoryReply_Kind,
+    pub name: ::std::string::String,
+    pub build_config: ::protobuf::RepeatedField<::std::string::String>,
+    pub generated: bool,
+    pub missing_text: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DirectoryReply_Entry {
+    fn default() -> &'a DirectoryReply_Entry {
+        <DirectoryReply_Entry as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DirectoryReply_Entry {
+    pub fn new() -> DirectoryReply_Entry {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.DirectoryReply.Kind kind = 1;
+
+
+    pub fn get_kind(&self) -> DirectoryReply_Kind {
+        self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind = DirectoryReply_Kind::UNKNOWN;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: DirectoryReply_Kind) {
+        self.kind = v;
+    }
+
+    // string name = 2;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // repeated string build_config = 3;
+
+
+    pub fn get_build_config(&self) -> &[::std::string::String] {
+        &self.build_config
+    }
+    pub fn clear_build_config(&mut self) {
+        self.build_config.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_build_config(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.build_config = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_build_config(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.build_config
+    }
+
+    // Take field
+    pub fn take_build_config(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.build_config, ::protobuf::RepeatedField::new())
+    }
+
+    // bool generated = 4;
+
+
+    pub fn get_generated(&self) -> bool {
+        self.generated
+    }
+    pub fn clear_generated(&mut self) {
+        self.generated = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_generated(&mut self, v: bool) {
+        self.generated = v;
+    }
+
+    // bool missing_text = 5;
+
+
+    pub fn get_missing_text(&self) -> bool {
+        self.missing_text
+    }
+    pub fn clear_missing_text(&mut self) {
+        self.missing_text = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_missing_text(&mut self, v: bool) {
+        self.missing_text = v;
+    }
+}
+
+impl ::protobuf::Message for DirectoryReply_Entry {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.kind, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.build_config)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.generated = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.missing_text = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.kind != DirectoryReply_Kind::UNKNOWN {
+            my_size += ::protobuf::rt::enum_size(1, self.kind);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
+        }
+        for value in &self.build_config {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        if self.generated != false {
+            my_size += 2;
+        }
+        if self.missing_text != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.kind != DirectoryReply_Kind::UNKNOWN {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.kind))?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        for v in &self.build_config {
+            os.write_string(3, &v)?;
+        };
+        if self.generated != false {
+            os.write_bool(4, self.generated)?;
+        }
+        if self.missing_text != false {
+            os.write_bool(5, self.missing_text)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DirectoryReply_Entry {
+        DirectoryReply_Entry::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<DirectoryReply_Kind>>(
+                "kind",
+                |m: &DirectoryReply_Entry| { &m.kind },
+                |m: &mut DirectoryReply_Entry| { &mut m.kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &DirectoryReply_Entry| { &m.name },
+                |m: &mut DirectoryReply_Entry| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "build_config",
+                |m: &DirectoryReply_Entry| { &m.build_config },
+                |m: &mut DirectoryReply_Entry| { &mut m.build_config },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "generated",
+                |m: &DirectoryReply_Entry| { &m.generated },
+                |m: &mut DirectoryReply_Entry| { &mut m.generated },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "missing_text",
+                |m: &DirectoryReply_Entry| { &m.missing_text },
+                |m: &mut DirectoryReply_Entry| { &mut m.missing_text },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DirectoryReply_Entry>(
+                "DirectoryReply.Entry",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DirectoryReply_Entry {
+        static instance: ::protobuf::rt::LazyV2<DirectoryReply_Entry> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DirectoryReply_Entry::new)
+    }
+}
+
+impl ::protobuf::Clear for DirectoryReply_Entry {
+    fn clear(&mut self) {
+        self.kind = DirectoryReply_Kind::UNKNOWN;
+        self.name.clear();
+        self.build_config.clear();
+        self.generated = false;
+        self.missing_text = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DirectoryReply_Entry {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DirectoryReply_Entry {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum DirectoryReply_Kind {
+    UNKNOWN = 0,
+    FILE = 1,
+    DIRECTORY = 2,
+}
+
+impl ::protobuf::ProtobufEnum for DirectoryReply_Kind {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<DirectoryReply_Kind> {
+        match value {
+            0 => ::std::option::Option::Some(DirectoryReply_Kind::UNKNOWN),
+            1 => ::std::option::Option::Some(DirectoryReply_Kind::FILE),
+            2 => ::std::option::Option::Some(DirectoryReply_Kind::DIRECTORY),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [DirectoryReply_Kind] = &[
+            DirectoryReply_Kind::UNKNOWN,
+            DirectoryReply_Kind::FILE,
+            DirectoryReply_Kind::DIRECTORY,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<DirectoryReply_Kind>("DirectoryReply.Kind", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for DirectoryReply_Kind {
+}
+
+impl ::std::default::Default for DirectoryReply_Kind {
+    fn default() -> Self {
+        DirectoryReply_Kind::UNKNOWN
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DirectoryReply_Kind {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x1akythe/proto/filetree.proto\x12\x0bkythe.proto\"\x14\n\x12CorpusRoo\
+    tsRequest\"\xa5\x01\n\x10CorpusRootsReply\x12<\n\x06corpus\x18\x01\x20\
+    \x03(\x0b2$.kythe.proto.CorpusRootsReply.CorpusR\x06corpus\x1aS\n\x06Cor\
+    pus\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x12\n\x04root\x18\
+    \x02\x20\x03(\tR\x04root\x12!\n\x0cbuild_config\x18\x03\x20\x03(\tR\x0bb\
+    uildConfig\"\x8f\x01\n\x10DirectoryRequest\x12\x16\n\x06corpus\x18\x01\
+    \x20\x01(\tR\x06corpus\x12\x12\n\x04root\x18\x02\x20\x01(\tR\x04root\x12\
+    \x12\n\x04path\x18\x03\x20\x01(\tR\x04path\x12;\n\x1ainclude_files_missi\
+    ng_text\x18\x04\x20\x01(\x08R\x17includeFilesMissingText\"\xfb\x02\n\x0e\
+    DirectoryReply\x12\x16\n\x06corpus\x18\x03\x20\x01(\tR\x06corpus\x12\x12\
+    \n\x04root\x18\x04\x20\x01(\tR\x04root\x12\x12\n\x04path\x18\x05\x20\x01\
+    (\tR\x04path\x127\n\x05entry\x18\x06\x20\x03(\x0b2!.kythe.proto.Director\
+    yReply.EntryR\x05entry\x1a\xb5\x01\n\x05Entry\x124\n\x04kind\x18\x01\x20\
+    \x01(\x0e2\x20.kythe.proto.DirectoryReply.KindR\x04kind\x12\x12\n\x04nam\
+    e\x18\x02\x20\x01(\tR\x04name\x12!\n\x0cbuild_config\x18\x03\x20\x03(\tR\
+    \x0bbuildConfig\x12\x1c\n\tgenerated\x18\x04\x20\x01(\x08R\tgenerated\
+    \x12!\n\x0cmissing_text\x18\x05\x20\x01(\x08R\x0bmissingText\",\n\x04Kin\
+    d\x12\x0b\n\x07UNKNOWN\x10\0\x12\x08\n\x04FILE\x10\x01\x12\r\n\tDIRECTOR\
+    Y\x10\x02J\x04\x08\x01\x10\x02J\x04\x08\x02\x10\x032\xad\x01\n\x0fFileTr\
+    eeService\x12O\n\x0bCorpusRoots\x12\x1f.kythe.proto.CorpusRootsRequest\
+    \x1a\x1d.kythe.proto.CorpusRootsReply\"\0\x12I\n\tDirectory\x12\x1d.kyth\
+    e.proto.DirectoryRequest\x1a\x1b.kythe.proto.DirectoryReply\"\0BI\n\x1fc\
+    om.google.devtools.kythe.protoZ&kythe.io/kythe/proto/filetree_go_protob\
+    \x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,3 @@
+extern crate protobuf;
+pub mod filetree;
+pub use filetree::*;
\ No newline at end of file
@@ -0,0 +1,227 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `kythe/proto/generated_message_info.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GeneratedProtoInfo {
+    // message fields
+    pub generated_code_info: ::protobuf::SingularPtrField<::protobuf::descriptor::GeneratedCodeInfo>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GeneratedProtoInfo {
+    fn default() -> &'a GeneratedProtoInfo {
+        <GeneratedProtoInfo as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GeneratedProtoInfo {
+    pub fn new() -> GeneratedProtoInfo {
+        ::std::default::Default::default()
+    }
+
+    // .google.protobuf.GeneratedCodeInfo generated_code_info = 1;
+
+
+    pub fn get_generated_code_info(&self) -> &::protobuf::descriptor::GeneratedCodeInfo {
+        self.generated_code_info.as_ref().unwrap_or_else(|| <::protobuf::descriptor::GeneratedCodeInfo as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_generated_code_info(&mut self) {
+        self.generated_code_info.clear();
+    }
+
+    pub fn has_generated_code_info(&self) -> bool {
+        self.generated_code_info.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_generated_code_info(&mut self, v: ::protobuf::descriptor::GeneratedCodeInfo) {
+        self.generated_code_info = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_generated_code_info(&mut self) -> &mut ::protobuf::descriptor::GeneratedCodeInfo {
+        if self.generated_code_info.is_none() {
+            self.generated_code_info.set_default();
+        }
+        self.generated_code_info.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_generated_code_info(&mut self) -> ::protobuf::descriptor::GeneratedCodeInfo {
+        self.generated_code_info.take().unwrap_or_else(|| ::protobuf::descriptor::GeneratedCodeInfo::new())
+    }
+}
+
+impl ::protobuf::Message for GeneratedProtoInfo {
+    fn is_initialized(&self) -> bool {
+        for v in &self.generated_code_info {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.generated_code_info)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.generated_code_info.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.generated_code_info.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GeneratedProtoInfo {
+        GeneratedProtoInfo::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::descriptor::GeneratedCodeInfo>>(
+                "generated_code_info",
+                |m: &GeneratedProtoInfo| { &m.generated_code_info },
+                |m: &mut GeneratedProtoInfo| { &mut m.generated_code_info },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GeneratedProtoInfo>(
+                "GeneratedProtoInfo",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GeneratedProtoInfo {
+        static instance: ::protobuf::rt::LazyV2<GeneratedProtoInfo> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GeneratedProtoInfo::new)
+    }
+}
+
+impl ::protobuf::Clear for GeneratedProtoInfo {
+    fn clear(&mut self) {
+        self.generated_code_info.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GeneratedProtoInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GeneratedProtoInfo {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+/// Extension fields
+pub mod exts {
+
+    pub const generated_proto_info: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeMessage<super::GeneratedProtoInfo>> = ::protobuf::ext::ExtFieldOptional { field_number: 379882011, phantom: ::std::marker::PhantomData };
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n(kythe/proto/generated_message_info.proto\x12\x0bkythe.proto\x1a\x20go\
+    ogle/protobuf/descriptor.proto\"h\n\x12GeneratedProtoInfo\x12R\n\x13gene\
+    rated_code_info\x18\x01\x20\x01(\x0b2\".google.protobuf.GeneratedCodeInf\
+    oR\x11generatedCodeInfo:s\n\x14generated_proto_info\x18\x9b\x94\x92\xb5\
+    \x01\x20\x01(\x0b2\x1f.kythe.proto.GeneratedProtoInfo\x12\x1c.google.pro\
+    tobuf.FileOptionsR\x12generatedProtoInfoBZ\n\x1fcom.google.devtools.kyth\
+    e.protoZ4kythe.io/kythe/proto/generated_message_info_go_proto\xf8\x01\
+    \x01b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
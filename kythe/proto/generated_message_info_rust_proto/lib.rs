@@ -0,0 +1,5 @@
+extern crate protobuf;
+pub mod descriptor;
+pub use descriptor::*;
+pub mod generated_message_info;
+pub use generated_message_info::*;
\ No newline at end of file
@@ -0,0 +1,610 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `kythe/proto/go.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GoDetails {
+    // message fields
+    pub goos: ::std::string::String,
+    pub goarch: ::std::string::String,
+    pub goroot: ::std::string::String,
+    pub gopath: ::std::string::String,
+    pub compiler: ::std::string::String,
+    pub build_tags: ::protobuf::RepeatedField<::std::string::String>,
+    pub cgo_enabled: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GoDetails {
+    fn default() -> &'a GoDetails {
+        <GoDetails as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GoDetails {
+    pub fn new() -> GoDetails {
+        ::std::default::Default::default()
+    }
+
+    // string goos = 1;
+
+
+    pub fn get_goos(&self) -> &str {
+        &self.goos
+    }
+    pub fn clear_goos(&mut self) {
+        self.goos.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_goos(&mut self, v: ::std::string::String) {
+        self.goos = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_goos(&mut self) -> &mut ::std::string::String {
+        &mut self.goos
+    }
+
+    // Take field
+    pub fn take_goos(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.goos, ::std::string::String::new())
+    }
+
+    // string goarch = 2;
+
+
+    pub fn get_goarch(&self) -> &str {
+        &self.goarch
+    }
+    pub fn clear_goarch(&mut self) {
+        self.goarch.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_goarch(&mut self, v: ::std::string::String) {
+        self.goarch = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_goarch(&mut self) -> &mut ::std::string::String {
+        &mut self.goarch
+    }
+
+    // Take field
+    pub fn take_goarch(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.goarch, ::std::string::String::new())
+    }
+
+    // string goroot = 3;
+
+
+    pub fn get_goroot(&self) -> &str {
+        &self.goroot
+    }
+    pub fn clear_goroot(&mut self) {
+        self.goroot.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_goroot(&mut self, v: ::std::string::String) {
+        self.goroot = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_goroot(&mut self) -> &mut ::std::string::String {
+        &mut self.goroot
+    }
+
+    // Take field
+    pub fn take_goroot(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.goroot, ::std::string::String::new())
+    }
+
+    // string gopath = 4;
+
+
+    pub fn get_gopath(&self) -> &str {
+        &self.gopath
+    }
+    pub fn clear_gopath(&mut self) {
+        self.gopath.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gopath(&mut self, v: ::std::string::String) {
+        self.gopath = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_gopath(&mut self) -> &mut ::std::string::String {
+        &mut self.gopath
+    }
+
+    // Take field
+    pub fn take_gopath(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.gopath, ::std::string::String::new())
+    }
+
+    // string compiler = 5;
+
+
+    pub fn get_compiler(&self) -> &str {
+        &self.compiler
+    }
+    pub fn clear_compiler(&mut self) {
+        self.compiler.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_compiler(&mut self, v: ::std::string::String) {
+        self.compiler = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_compiler(&mut self) -> &mut ::std::string::String {
+        &mut self.compiler
+    }
+
+    // Take field
+    pub fn take_compiler(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.compiler, ::std::string::String::new())
+    }
+
+    // repeated string build_tags = 6;
+
+
+    pub fn get_build_tags(&self) -> &[::std::string::String] {
+        &self.build_tags
+    }
+    pub fn clear_build_tags(&mut self) {
+        self.build_tags.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_build_tags(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.build_tags = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_build_tags(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.build_tags
+    }
+
+    // Take field
+    pub fn take_build_tags(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.build_tags, ::protobuf::RepeatedField::new())
+    }
+
+    // bool cgo_enabled = 7;
+
+
+    pub fn get_cgo_enabled(&self) -> bool {
+        self.cgo_enabled
+    }
+    pub fn clear_cgo_enabled(&mut self) {
+        self.cgo_enabled = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cgo_enabled(&mut self, v: bool) {
+        self.cgo_enabled = v;
+    }
+}
+
+impl ::protobuf::Message for GoDetails {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.goos)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.goarch)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.goroot)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.gopath)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.compiler)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.build_tags)?;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.cgo_enabled = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.goos.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.goos);
+        }
+        if !self.goarch.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.goarch);
+        }
+        if !self.goroot.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.goroot);
+        }
+        if !self.gopath.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.gopath);
+        }
+        if !self.compiler.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.compiler);
+        }
+        for value in &self.build_tags {
+            my_size += ::protobuf::rt::string_size(6, &value);
+        };
+        if self.cgo_enabled != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.goos.is_empty() {
+            os.write_string(1, &self.goos)?;
+        }
+        if !self.goarch.is_empty() {
+            os.write_string(2, &self.goarch)?;
+        }
+        if !self.goroot.is_empty() {
+            os.write_string(3, &self.goroot)?;
+        }
+        if !self.gopath.is_empty() {
+            os.write_string(4, &self.gopath)?;
+        }
+        if !self.compiler.is_empty() {
+            os.write_string(5, &self.compiler)?;
+        }
+        for v in &self.build_tags {
+            os.write_string(6, &v)?;
+        };
+        if self.cgo_enabled != false {
+            os.write_bool(7, self.cgo_enabled)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GoDetails {
+        GoDetails::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "goos",
+                |m: &GoDetails| { &m.goos },
+                |m: &mut GoDetails| { &mut m.goos },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "goarch",
+                |m: &GoDetails| { &m.goarch },
+                |m: &mut GoDetails| { &mut m.goarch },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "goroot",
+                |m: &GoDetails| { &m.goroot },
+                |m: &mut GoDetails| { &mut m.goroot },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "gopath",
+                |m: &GoDetails| { &m.gopath },
+                |m: &mut GoDetails| { &mut m.gopath },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "compiler",
+                |m: &GoDetails| { &m.compiler },
+                |m: &mut GoDetails| { &mut m.compiler },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "build_tags",
+                |m: &GoDetails| { &m.build_tags },
+                |m: &mut GoDetails| { &mut m.build_tags },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "cgo_enabled",
+                |m: &GoDetails| { &m.cgo_enabled },
+                |m: &mut GoDetails| { &mut m.cgo_enabled },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GoDetails>(
+                "GoDetails",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GoDetails {
+        static instance: ::protobuf::rt::LazyV2<GoDetails> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GoDetails::new)
+    }
+}
+
+impl ::protobuf::Clear for GoDetails {
+    fn clear(&mut self) {
+        self.goos.clear();
+        self.goarch.clear();
+        self.goroot.clear();
+        self.gopath.clear();
+        self.compiler.clear();
+        self.build_tags.clear();
+        self.cgo_enabled = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GoDetails {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GoDetails {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GoPackageInfo {
+    // message fields
+    pub import_path: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GoPackageInfo {
+    fn default() -> &'a GoPackageInfo {
+        <GoPackageInfo as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GoPackageInfo {
+    pub fn new() -> GoPackageInfo {
+        ::std::default::Default::default()
+    }
+
+    // string import_path = 1;
+
+
+    pub fn get_import_path(&self) -> &str {
+        &self.import_path
+    }
+    pub fn clear_import_path(&mut self) {
+        self.import_path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_import_path(&mut self, v: ::std::string::String) {
+        self.import_path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_import_path(&mut self) -> &mut ::std::string::String {
+        &mut self.import_path
+    }
+
+    // Take field
+    pub fn take_import_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.import_path, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for GoPackageInfo {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.import_path)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.import_path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.import_path);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.import_path.is_empty() {
+            os.write_string(1, &self.import_path)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GoPackageInfo {
+        GoPackageInfo::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "import_path",
+                |m: &GoPackageInfo| { &m.import_path },
+                |m: &mut GoPackageInfo| { &mut m.import_path },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GoPackageInfo>(
+                "GoPackageInfo",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GoPackageInfo {
+        static instance: ::protobuf::rt::LazyV2<GoPackageInfo> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GoPackageInfo::new)
+    }
+}
+
+impl ::protobuf::Clear for GoPackageInfo {
+    fn clear(&mut self) {
+        self.import_path.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GoPackageInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GoPackageInfo {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x14kythe/proto/go.proto\x12\x0bkythe.proto\"\xc3\x01\n\tGoDetails\x12\
+    \x12\n\x04goos\x18\x01\x20\x01(\tR\x04goos\x12\x16\n\x06goarch\x18\x02\
+    \x20\x01(\tR\x06goarch\x12\x16\n\x06goroot\x18\x03\x20\x01(\tR\x06goroot\
+    \x12\x16\n\x06gopath\x18\x04\x20\x01(\tR\x06gopath\x12\x1a\n\x08compiler\
+    \x18\x05\x20\x01(\tR\x08compiler\x12\x1d\n\nbuild_tags\x18\x06\x20\x03(\
+    \tR\tbuildTags\x12\x1f\n\x0bcgo_enabled\x18\x07\x20\x01(\x08R\ncgoEnable\
+    d\"0\n\rGoPackageInfo\x12\x1f\n\x0bimport_path\x18\x01\x20\x01(\tR\nimpo\
+    rtPathBC\n\x1fcom.google.devtools.kythe.protoZ\x20kythe.io/kythe/proto/g\
+    o_go_protob\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
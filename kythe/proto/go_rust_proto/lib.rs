@@ -0,0 +1,3 @@
+extern crate protobuf;
+pub mod go;
+pub use go::*;
\ No newline at end of file
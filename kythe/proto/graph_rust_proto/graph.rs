@@ -0,0 +1,1527 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `kythe/proto/graph.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct NodesRequest {
+    // message fields
+    pub ticket: ::protobuf::RepeatedField<::std::string::String>,
+    pub filter: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a NodesRequest {
+    fn default() -> &'a NodesRequest {
+        <NodesRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl NodesRequest {
+    pub fn new() -> NodesRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated string ticket = 1;
+
+
+    pub fn get_ticket(&self) -> &[::std::string::String] {
+        &self.ticket
+    }
+    pub fn clear_ticket(&mut self) {
+        self.ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ticket(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_ticket(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.ticket
+    }
+
+    // Take field
+    pub fn take_ticket(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.ticket, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string filter = 2;
+
+
+    pub fn get_filter(&self) -> &[::std::string::String] {
+        &self.filter
+    }
+    pub fn clear_filter(&mut self) {
+        self.filter.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_filter(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.filter = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_filter(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.filter
+    }
+
+    // Take field
+    pub fn take_filter(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.filter, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for NodesRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.ticket)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.filter)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.ticket {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in &self.filter {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.ticket {
+            os.write_string(1, &v)?;
+        };
+        for v in &self.filter {
+            os.write_string(2, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> NodesRequest {
+        NodesRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "ticket",
+                |m: &NodesRequest| { &m.ticket },
+                |m: &mut NodesRequest| { &mut m.ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "filter",
+                |m: &NodesRequest| { &m.filter },
+                |m: &mut NodesRequest| { &mut m.filter },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<NodesRequest>(
+                "NodesRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static NodesRequest {
+        static instance: ::protobuf::rt::LazyV2<NodesRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(NodesRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for NodesRequest {
+    fn clear(&mut self) {
+        self.ticket.clear();
+        self.filter.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for NodesRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for NodesRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct NodesReply {
+    // message fields
+    pub nodes: ::std::collections::HashMap<::std::string::String, super::common::NodeInfo>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a NodesReply {
+    fn default() -> &'a NodesReply {
+        <NodesReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl NodesReply {
+    pub fn new() -> NodesReply {
+        ::std::default::Default::default()
+    }
+
+    // repeated .kythe.proto.NodesReply.NodesEntry nodes = 1;
+
+
+    pub fn get_nodes(&self) -> &::std::collections::HashMap<::std::string::String, super::common::NodeInfo> {
+        &self.nodes
+    }
+    pub fn clear_nodes(&mut self) {
+        self.nodes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nodes(&mut self, v: ::std::collections::HashMap<::std::string::String, super::common::NodeInfo>) {
+        self.nodes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_nodes(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, super::common::NodeInfo> {
+        &mut self.nodes
+    }
+
+    // Take field
+    pub fn take_nodes(&mut self) -> ::std::collections::HashMap<::std::string::String, super::common::NodeInfo> {
+        ::std::mem::replace(&mut self.nodes, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for NodesReply {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<super::common::NodeInfo>>(wire_type, is, &mut self.nodes)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<super::common::NodeInfo>>(1, &self.nodes);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<super::common::NodeInfo>>(1, &self.nodes, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> NodesReply {
+        NodesReply::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<super::common::NodeInfo>>(
+                "nodes",
+                |m: &NodesReply| { &m.nodes },
+                |m: &mut NodesReply| { &mut m.nodes },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<NodesReply>(
+                "NodesReply",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static NodesReply {
+        static instance: ::protobuf::rt::LazyV2<NodesReply> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(NodesReply::new)
+    }
+}
+
+impl ::protobuf::Clear for NodesReply {
+    fn clear(&mut self) {
+        self.nodes.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for NodesReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for NodesReply {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct EdgesRequest {
+    // message fields
+    pub ticket: ::protobuf::RepeatedField<::std::string::String>,
+    pub kind: ::protobuf::RepeatedField<::std::string::String>,
+    pub filter: ::protobuf::RepeatedField<::std::string::String>,
+    pub page_size: i32,
+    pub page_token: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a EdgesRequest {
+    fn default() -> &'a EdgesRequest {
+        <EdgesRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl EdgesRequest {
+    pub fn new() -> EdgesRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated string ticket = 1;
+
+
+    pub fn get_ticket(&self) -> &[::std::string::String] {
+        &self.ticket
+    }
+    pub fn clear_ticket(&mut self) {
+        self.ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ticket(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_ticket(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.ticket
+    }
+
+    // Take field
+    pub fn take_ticket(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.ticket, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string kind = 2;
+
+
+    pub fn get_kind(&self) -> &[::std::string::String] {
+        &self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.kind = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_kind(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.kind
+    }
+
+    // Take field
+    pub fn take_kind(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.kind, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string filter = 3;
+
+
+    pub fn get_filter(&self) -> &[::std::string::String] {
+        &self.filter
+    }
+    pub fn clear_filter(&mut self) {
+        self.filter.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_filter(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.filter = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_filter(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.filter
+    }
+
+    // Take field
+    pub fn take_filter(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.filter, ::protobuf::RepeatedField::new())
+    }
+
+    // int32 page_size = 8;
+
+
+    pub fn get_page_size(&self) -> i32 {
+        self.page_size
+    }
+    pub fn clear_page_size(&mut self) {
+        self.page_size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_page_size(&mut self, v: i32) {
+        self.page_size = v;
+    }
+
+    // string page_token = 9;
+
+
+    pub fn get_page_token(&self) -> &str {
+        &self.page_token
+    }
+    pub fn clear_page_token(&mut self) {
+        self.page_token.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_page_token(&mut self, v: ::std::string::String) {
+        self.page_token = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_page_token(&mut self) -> &mut ::std::string::String {
+        &mut self.page_token
+    }
+
+    // Take field
+    pub fn take_page_token(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.page_token, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for EdgesRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.ticket)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.kind)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.filter)?;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.page_size = tmp;
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.page_token)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.ticket {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in &self.kind {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in &self.filter {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        if self.page_size != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.page_size, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.page_token.is_empty() {
+            my_size += ::protobuf::rt::string_size(9, &self.page_token);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.ticket {
+            os.write_string(1, &v)?;
+        };
+        for v in &self.kind {
+            os.write_string(2, &v)?;
+        };
+        for v in &self.filter {
+            os.write_string(3, &v)?;
+        };
+        if self.page_size != 0 {
+            os.write_int32(8, self.page_size)?;
+        }
+        if !self.page_token.is_empty() {
+            os.write_string(9, &self.page_token)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> EdgesRequest {
+        EdgesRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "ticket",
+                |m: &EdgesRequest| { &m.ticket },
+                |m: &mut EdgesRequest| { &mut m.ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "kind",
+                |m: &EdgesRequest| { &m.kind },
+                |m: &mut EdgesRequest| { &mut m.kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "filter",
+                |m: &EdgesRequest| { &m.filter },
+                |m: &mut EdgesRequest| { &mut m.filter },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "page_size",
+                |m: &EdgesRequest| { &m.page_size },
+                |m: &mut EdgesRequest| { &mut m.page_size },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "page_token",
+                |m: &EdgesRequest| { &m.page_token },
+                |m: &mut EdgesRequest| { &mut m.page_token },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<EdgesRequest>(
+                "EdgesRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static EdgesRequest {
+        static instance: ::protobuf::rt::LazyV2<EdgesRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(EdgesRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for EdgesRequest {
+    fn clear(&mut self) {
+        self.ticket.clear();
+        self.kind.clear();
+        self.filter.clear();
+        self.page_size = 0;
+        self.page_token.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for EdgesRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EdgesRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct EdgeSet {
+    // message fields
+    pub groups: ::std::collections::HashMap<::std::string::String, EdgeSet_Group>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a EdgeSet {
+    fn default() -> &'a EdgeSet {
+        <EdgeSet as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl EdgeSet {
+    pub fn new() -> EdgeSet {
+        ::std::default::Default::default()
+    }
+
+    // repeated .kythe.proto.EdgeSet.GroupsEntry groups = 2;
+
+
+    pub fn get_groups(&self) -> &::std::collections::HashMap<::std::string::String, EdgeSet_Group> {
+        &self.groups
+    }
+    pub fn clear_groups(&mut self) {
+        self.groups.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_groups(&mut self, v: ::std::collections::HashMap<::std::string::String, EdgeSet_Group>) {
+        self.groups = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_groups(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, EdgeSet_Group> {
+        &mut self.groups
+    }
+
+    // Take field
+    pub fn take_groups(&mut self) -> ::std::collections::HashMap<::std::string::String, EdgeSet_Group> {
+        ::std::mem::replace(&mut self.groups, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for EdgeSet {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                2 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<EdgeSet_Group>>(wire_type, is, &mut self.groups)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<EdgeSet_Group>>(2, &self.groups);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<EdgeSet_Group>>(2, &self.groups, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> EdgeSet {
+        EdgeSet::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<EdgeSet_Group>>(
+                "groups",
+                |m: &EdgeSet| { &m.groups },
+                |m: &mut EdgeSet| { &mut m.groups },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<EdgeSet>(
+                "EdgeSet",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static EdgeSet {
+        static instance: ::protobuf::rt::LazyV2<EdgeSet> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(EdgeSet::new)
+    }
+}
+
+impl ::protobuf::Clear for EdgeSet {
+    fn clear(&mut self) {
+        self.groups.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for EdgeSet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EdgeSet {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct EdgeSet_Group {
+    // message fields
+    pub edge: ::protobuf::RepeatedField<EdgeSet_Group_Edge>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a EdgeSet_Group {
+    fn default() -> &'a EdgeSet_Group {
+        <EdgeSet_Group as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl EdgeSet_Group {
+    pub fn new() -> EdgeSet_Group {
+        ::std::default::Default::default()
+    }
+
+    // repeated .kythe.proto.EdgeSet.Group.Edge edge = 2;
+
+
+    pub fn get_edge(&self) -> &[EdgeSet_Group_Edge] {
+        &self.edge
+    }
+    pub fn clear_edge(&mut self) {
+        self.edge.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_edge(&mut self, v: ::protobuf::RepeatedField<EdgeSet_Group_Edge>) {
+        self.edge = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_edge(&mut self) -> &mut ::protobuf::RepeatedField<EdgeSet_Group_Edge> {
+        &mut self.edge
+    }
+
+    // Take field
+    pub fn take_edge(&mut self) -> ::protobuf::RepeatedField<EdgeSet_Group_Edge> {
+        ::std::mem::replace(&mut self.edge, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for EdgeSet_Group {
+    fn is_initialized(&self) -> bool {
+        for v in &self.edge {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.edge)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.edge {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.edge {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> EdgeSet_Group {
+        EdgeSet_Group::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<EdgeSet_Group_Edge>>(
+                "edge",
+                |m: &EdgeSet_Group| { &m.edge },
+                |m: &mut EdgeSet_Group| { &mut m.edge },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<EdgeSet_Group>(
+                "EdgeSet.Group",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static EdgeSet_Group {
+        static instance: ::protobuf::rt::LazyV2<EdgeSet_Group> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(EdgeSet_Group::new)
+    }
+}
+
+impl ::protobuf::Clear for EdgeSet_Group {
+    fn clear(&mut self) {
+        self.edge.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for EdgeSet_Group {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EdgeSet_Group {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct EdgeSet_Group_Edge {
+    // message fields
+    pub target_ticket: ::std::string::String,
+    pub ordinal: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a EdgeSet_Group_Edge {
+    fn default() -> &'a EdgeSet_Group_Edge {
+        <EdgeSet_Group_Edge as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl EdgeSet_Group_Edge {
+    pub fn new() -> EdgeSet_Group_Edge {
+        ::std::default::Default::default()
+    }
+
+    // string target_ticket = 1;
+
+
+    pub fn get_target_ticket(&self) -> &str {
+        &self.target_ticket
+    }
+    pub fn clear_target_ticket(&mut self) {
+        self.target_ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_ticket(&mut self, v: ::std::string::String) {
+        self.target_ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target_ticket(&mut self) -> &mut ::std::string::String {
+        &mut self.target_ticket
+    }
+
+    // Take field
+    pub fn take_target_ticket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.target_ticket, ::std::string::String::new())
+    }
+
+    // int32 ordinal = 2;
+
+
+    pub fn get_ordinal(&self) -> i32 {
+        self.ordinal
+    }
+    pub fn clear_ordinal(&mut self) {
+        self.ordinal = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ordinal(&mut self, v: i32) {
+        self.ordinal = v;
+    }
+}
+
+impl ::protobuf::Message for EdgeSet_Group_Edge {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.target_ticket)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.ordinal = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.target_ticket.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.target_ticket);
+        }
+        if self.ordinal != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.ordinal, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.target_ticket.is_empty() {
+            os.write_string(1, &self.target_ticket)?;
+        }
+        if self.ordinal != 0 {
+            os.write_int32(2, self.ordinal)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> EdgeSet_Group_Edge {
+        EdgeSet_Group_Edge::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "target_ticket",
+                |m: &EdgeSet_Group_Edge| { &m.target_ticket },
+                |m: &mut EdgeSet_Group_Edge| { &mut m.target_ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "ordinal",
+                |m: &EdgeSet_Group_Edge| { &m.ordinal },
+                |m: &mut EdgeSet_Group_Edge| { &mut m.ordinal },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<EdgeSet_Group_Edge>(
+                "EdgeSet.Group.Edge",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static EdgeSet_Group_Edge {
+        static instance: ::protobuf::rt::LazyV2<EdgeSet_Group_Edge> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(EdgeSet_Group_Edge::new)
+    }
+}
+
+impl ::protobuf::Clear for EdgeSet_Group_Edge {
+    fn clear(&mut self) {
+        self.target_ticket.clear();
+        self.ordinal = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for EdgeSet_Group_Edge {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EdgeSet_Group_Edge {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct EdgesReply {
+    // message fields
+    pub edge_sets: ::std::collections::HashMap<::std::string::String, EdgeSet>,
+    pub nodes: ::std::collections::HashMap<::std::string::String, super::common::NodeInfo>,
+    pub total_edges_by_kind: ::std::collections::HashMap<::std::string::String, i64>,
+    pub next_page_token: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a EdgesReply {
+    fn default() -> &'a EdgesReply {
+        <EdgesReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl EdgesReply {
+    pub fn new() -> EdgesReply {
+        ::std::default::Default::default()
+    }
+
+    // repeated .kythe.proto.EdgesReply.EdgeSetsEntry edge_sets = 1;
+
+
+    pub fn get_edge_sets(&self) -> &::std::collections::HashMap<::std::string::String, EdgeSet> {
+        &self.edge_sets
+    }
+    pub fn clear_edge_sets(&mut self) {
+        self.edge_sets.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_edge_sets(&mut self, v: ::std::collections::HashMap<::std::string::String, EdgeSet>) {
+        self.edge_sets = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_edge_sets(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, EdgeSet> {
+        &mut self.edge_sets
+    }
+
+    // Take field
+    pub fn take_edge_sets(&mut self) -> ::std::collections::HashMap<::std::string::String, EdgeSet> {
+        ::std::mem::replace(&mut self.edge_sets, ::std::collections::HashMap::new())
+    }
+
+    // repeated .kythe.proto.EdgesReply.NodesEntry nodes = 2;
+
+
+    pub fn get_nodes(&self) -> &::std::collections::HashMap<::std::string::String, super::common::NodeInfo> {
+        &self.nodes
+    }
+    pub fn clear_nodes(&mut self) {
+        self.nodes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nodes(&mut self, v: ::std::collections::HashMap<::std::string::String, super::common::NodeInfo>) {
+        self.nodes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_nodes(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, super::common::NodeInfo> {
+        &mut self.nodes
+    }
+
+    // Take field
+    pub fn take_nodes(&mut self) -> ::std::collections::HashMap<::std::string::String, super::common::NodeInfo> {
+        ::std::mem::replace(&mut self.nodes, ::std::collections::HashMap::new())
+    }
+
+    // repeated .kythe.proto.EdgesReply.TotalEdgesByKindEntry total_edges_by_kind = 5;
+
+
+    pub fn get_total_edges_by_kind(&self) -> &::std::collections::HashMap<::std::string::String, i64> {
+        &self.total_edges_by_kind
+    }
+    pub fn clear_total_edges_by_kind(&mut self) {
+        self.total_edges_by_kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_total_edges_by_kind(&mut self, v: ::std::collections::HashMap<::std::string::String, i64>) {
+        self.total_edges_by_kind = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_total_edges_by_kind(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, i64> {
+        &mut self.total_edges_by_kind
+    }
+
+    // Take field
+    pub fn take_total_edges_by_kind(&mut self) -> ::std::collections::HashMap<::std::string::String, i64> {
+        ::std::mem::replace(&mut self.total_edges_by_kind, ::std::collections::HashMap::new())
+    }
+
+    // string next_page_token = 9;
+
+
+    pub fn get_next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    pub fn clear_next_page_token(&mut self) {
+        self.next_page_token.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_next_page_token(&mut self, v: ::std::string::String) {
+        self.next_page_token = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_next_page_token(&mut self) -> &mut ::std::string::String {
+        &mut self.next_page_token
+    }
+
+    // Take field
+    pub fn take_next_page_token(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.next_page_token, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for EdgesReply {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<EdgeSet>>(wire_type, is, &mut self.edge_sets)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<super::common::NodeInfo>>(wire_type, is, &mut self.nodes)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeInt64>(wire_type, is, &mut self.total_edges_by_kind)?;
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.next_page_token)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<EdgeSet>>(1, &self.edge_sets);
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<super::common::NodeInfo>>(2, &self.nodes);
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeInt64>(5, &self.total_edges_by_kind);
+        if !self.next_page_token.is_empty() {
+            my_size += ::protobuf::rt::string_size(9, &self.next_page_token);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<EdgeSet>>(1, &self.edge_sets, os)?;
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<super::common::NodeInfo>>(2, &self.nodes, os)?;
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeInt64>(5, &self.total_edges_by_kind, os)?;
+        if !self.next_page_token.is_empty() {
+            os.write_string(9, &self.next_page_token)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> EdgesReply {
+        EdgesReply::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<EdgeSet>>(
+                "edge_sets",
+                |m: &EdgesReply| { &m.edge_sets },
+                |m: &mut EdgesReply| { &mut m.edge_sets },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<super::common::NodeInfo>>(
+                "nodes",
+                |m: &EdgesReply| { &m.nodes },
+                |m: &mut EdgesReply| { &mut m.nodes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeInt64>(
+                "total_edges_by_kind",
+                |m: &EdgesReply| { &m.total_edges_by_kind },
+                |m: &mut EdgesReply| { &mut m.total_edges_by_kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "next_page_token",
+                |m: &EdgesReply| { &m.next_page_token },
+                |m: &mut EdgesReply| { &mut m.next_page_token },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<EdgesReply>(
+                "EdgesReply",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static EdgesReply {
+        static instance: ::protobuf::rt::LazyV2<EdgesReply> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(EdgesReply::new)
+    }
+}
+
+impl ::protobuf::Clear for EdgesReply {
+    fn clear(&mut self) {
+        self.edge_sets.clear();
+        self.nodes.clear();
+        self.total_edges_by_kind.clear();
+        self.next_page_token.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for EdgesReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EdgesReply {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x17kythe/proto/graph.proto\x12\x0bkythe.proto\x1a\x18kythe/proto/comm\
+    on.proto\">\n\x0cNodesRequest\x12\x16\n\x06ticket\x18\x01\x20\x03(\tR\
+    \x06ticket\x12\x16\n\x06filter\x18\x02\x20\x03(\tR\x06filter\"\x9e\x01\n\
+    \nNodesReply\x128\n\x05nodes\x18\x01\x20\x03(\x0b2\".kythe.proto.NodesRe\
+    ply.NodesEntryR\x05nodes\x1aV\n\nNodesEntry\x12\x10\n\x03key\x18\x01\x20\
+    \x01(\tR\x03key\x122\n\x05value\x18\x02\x20\x01(\x0b2\x1c.kythe.proto.co\
+    mmon.NodeInfoR\x05value:\x028\x01\"\x8e\x01\n\x0cEdgesRequest\x12\x16\n\
+    \x06ticket\x18\x01\x20\x03(\tR\x06ticket\x12\x12\n\x04kind\x18\x02\x20\
+    \x03(\tR\x04kind\x12\x16\n\x06filter\x18\x03\x20\x03(\tR\x06filter\x12\
+    \x1b\n\tpage_size\x18\x08\x20\x01(\x05R\x08pageSize\x12\x1d\n\npage_toke\
+    n\x18\t\x20\x01(\tR\tpageToken\"\xc1\x02\n\x07EdgeSet\x128\n\x06groups\
+    \x18\x02\x20\x03(\x0b2\x20.kythe.proto.EdgeSet.GroupsEntryR\x06groups\
+    \x1a\x8f\x01\n\x05Group\x123\n\x04edge\x18\x02\x20\x03(\x0b2\x1f.kythe.p\
+    roto.EdgeSet.Group.EdgeR\x04edge\x1aE\n\x04Edge\x12#\n\rtarget_ticket\
+    \x18\x01\x20\x01(\tR\x0ctargetTicket\x12\x18\n\x07ordinal\x18\x02\x20\
+    \x01(\x05R\x07ordinalJ\x04\x08\x01\x10\x02R\x04kind\x1aU\n\x0bGroupsEntr\
+    y\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x120\n\x05value\x18\x02\
+    \x20\x01(\x0b2\x1a.kythe.proto.EdgeSet.GroupR\x05value:\x028\x01J\x04\
+    \x08\x01\x10\x02R\rsource_ticket\"\x80\x04\n\nEdgesReply\x12B\n\tedge_se\
+    ts\x18\x01\x20\x03(\x0b2%.kythe.proto.EdgesReply.EdgeSetsEntryR\x08edgeS\
+    ets\x128\n\x05nodes\x18\x02\x20\x03(\x0b2\".kythe.proto.EdgesReply.Nodes\
+    EntryR\x05nodes\x12\\\n\x13total_edges_by_kind\x18\x05\x20\x03(\x0b2-.ky\
+    the.proto.EdgesReply.TotalEdgesByKindEntryR\x10totalEdgesByKind\x12&\n\
+    \x0fnext_page_token\x18\t\x20\x01(\tR\rnextPageToken\x1aQ\n\rEdgeSetsEnt\
+    ry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12*\n\x05value\x18\x02\
+    \x20\x01(\x0b2\x14.kythe.proto.EdgeSetR\x05value:\x028\x01\x1aV\n\nNodes\
+    Entry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x122\n\x05value\x18\
+    \x02\x20\x01(\x0b2\x1c.kythe.proto.common.NodeInfoR\x05value:\x028\x01\
+    \x1aC\n\x15TotalEdgesByKindEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\
+    \x03key\x12\x14\n\x05value\x18\x02\x20\x01(\x03R\x05value:\x028\x012\x8c\
+    \x01\n\x0cGraphService\x12=\n\x05Nodes\x12\x19.kythe.proto.NodesRequest\
+    \x1a\x17.kythe.proto.NodesReply\"\0\x12=\n\x05Edges\x12\x19.kythe.proto.\
+    EdgesRequest\x1a\x17.kythe.proto.EdgesReply\"\0BF\n\x1fcom.google.devtoo\
+    ls.kythe.protoZ#kythe.io/kythe/proto/graph_go_protob\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,7 @@
+extern crate protobuf;
+pub mod timestamp;
+pub use timestamp::*;
+pub mod common;
+pub use common::*;
+pub mod graph;
+pub use graph::*;
\ No newline at end of file
@@ -0,0 +1,1190 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `kythe/proto/graph_serving.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Edges {
+    // message fields
+    pub source: ::protobuf::SingularPtrField<super::storage::VName>,
+    // message oneof groups
+    pub entry: ::std::option::Option<Edges_oneof_entry>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Edges {
+    fn default() -> &'a Edges {
+        <Edges as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum Edges_oneof_entry {
+    index(Edges_Index),
+    edge(Edges_Edge),
+    target(Edges_Target),
+}
+
+impl Edges {
+    pub fn new() -> Edges {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.VName source = 1;
+
+
+    pub fn get_source(&self) -> &super::storage::VName {
+        self.source.as_ref().unwrap_or_else(|| <super::storage::VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_source(&mut self) {
+        self.source.clear();
+    }
+
+    pub fn has_source(&self) -> bool {
+        self.source.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source(&mut self, v: super::storage::VName) {
+        self.source = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source(&mut self) -> &mut super::storage::VName {
+        if self.source.is_none() {
+            self.source.set_default();
+        }
+        self.source.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_source(&mut self) -> super::storage::VName {
+        self.source.take().unwrap_or_else(|| super::storage::VName::new())
+    }
+
+    // .kythe.proto.serving.graph.Edges.Index index = 2;
+
+
+    pub fn get_index(&self) -> &Edges_Index {
+        match self.entry {
+            ::std::option::Option::Some(Edges_oneof_entry::index(ref v)) => v,
+            _ => <Edges_Index as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_index(&mut self) {
+        self.entry = ::std::option::Option::None;
+    }
+
+    pub fn has_index(&self) -> bool {
+        match self.entry {
+            ::std::option::Option::Some(Edges_oneof_entry::index(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: Edges_Index) {
+        self.entry = ::std::option::Option::Some(Edges_oneof_entry::index(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_index(&mut self) -> &mut Edges_Index {
+        if let ::std::option::Option::Some(Edges_oneof_entry::index(_)) = self.entry {
+        } else {
+            self.entry = ::std::option::Option::Some(Edges_oneof_entry::index(Edges_Index::new()));
+        }
+        match self.entry {
+            ::std::option::Option::Some(Edges_oneof_entry::index(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_index(&mut self) -> Edges_Index {
+        if self.has_index() {
+            match self.entry.take() {
+                ::std::option::Option::Some(Edges_oneof_entry::index(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Edges_Index::new()
+        }
+    }
+
+    // .kythe.proto.serving.graph.Edges.Edge edge = 3;
+
+
+    pub fn get_edge(&self) -> &Edges_Edge {
+        match self.entry {
+            ::std::option::Option::Some(Edges_oneof_entry::edge(ref v)) => v,
+            _ => <Edges_Edge as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_edge(&mut self) {
+        self.entry = ::std::option::Option::None;
+    }
+
+    pub fn has_edge(&self) -> bool {
+        match self.entry {
+            ::std::option::Option::Some(Edges_oneof_entry::edge(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_edge(&mut self, v: Edges_Edge) {
+        self.entry = ::std::option::Option::Some(Edges_oneof_entry::edge(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_edge(&mut self) -> &mut Edges_Edge {
+        if let ::std::option::Option::Some(Edges_oneof_entry::edge(_)) = self.entry {
+        } else {
+            self.entry = ::std::option::Option::Some(Edges_oneof_entry::edge(Edges_Edge::new()));
+        }
+        match self.entry {
+            ::std::option::Option::Some(Edges_oneof_entry::edge(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_edge(&mut self) -> Edges_Edge {
+        if self.has_edge() {
+            match self.entry.take() {
+                ::std::option::Option::Some(Edges_oneof_entry::edge(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Edges_Edge::new()
+        }
+    }
+
+    // .kythe.proto.serving.graph.Edges.Target target = 4;
+
+
+    pub fn get_target(&self) -> &Edges_Target {
+        match self.entry {
+            ::std::option::Option::Some(Edges_oneof_entry::target(ref v)) => v,
+            _ => <Edges_Target as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_target(&mut self) {
+        self.entry = ::std::option::Option::None;
+    }
+
+    pub fn has_target(&self) -> bool {
+        match self.entry {
+            ::std::option::Option::Some(Edges_oneof_entry::target(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: Edges_Target) {
+        self.entry = ::std::option::Option::Some(Edges_oneof_entry::target(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_target(&mut self) -> &mut Edges_Target {
+        if let ::std::option::Option::Some(Edges_oneof_entry::target(_)) = self.entry {
+        } else {
+            self.entry = ::std::option::Option::Some(Edges_oneof_entry::target(Edges_Target::new()));
+        }
+        match self.entry {
+            ::std::option::Option::Some(Edges_oneof_entry::target(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> Edges_Target {
+        if self.has_target() {
+            match self.entry.take() {
+                ::std::option::Option::Some(Edges_oneof_entry::target(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Edges_Target::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for Edges {
+    fn is_initialized(&self) -> bool {
+        for v in &self.source {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        if let Some(Edges_oneof_entry::index(ref v)) = self.entry {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Edges_oneof_entry::edge(ref v)) = self.entry {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Edges_oneof_entry::target(ref v)) = self.entry {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.source)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.entry = ::std::option::Option::Some(Edges_oneof_entry::index(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.entry = ::std::option::Option::Some(Edges_oneof_entry::edge(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.entry = ::std::option::Option::Some(Edges_oneof_entry::target(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.source.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let ::std::option::Option::Some(ref v) = self.entry {
+            match v {
+                &Edges_oneof_entry::index(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Edges_oneof_entry::edge(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Edges_oneof_entry::target(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.source.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.entry {
+            match v {
+                &Edges_oneof_entry::index(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Edges_oneof_entry::edge(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Edges_oneof_entry::target(ref v) => {
+                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Edges {
+        Edges::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::storage::VName>>(
+                "source",
+                |m: &Edges| { &m.source },
+                |m: &mut Edges| { &mut m.source },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Edges_Index>(
+                "index",
+                Edges::has_index,
+                Edges::get_index,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Edges_Edge>(
+                "edge",
+                Edges::has_edge,
+                Edges::get_edge,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Edges_Target>(
+                "target",
+                Edges::has_target,
+                Edges::get_target,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Edges>(
+                "Edges",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Edges {
+        static instance: ::protobuf::rt::LazyV2<Edges> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Edges::new)
+    }
+}
+
+impl ::protobuf::Clear for Edges {
+    fn clear(&mut self) {
+        self.source.clear();
+        self.entry = ::std::option::Option::None;
+        self.entry = ::std::option::Option::None;
+        self.entry = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Edges {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Edges {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Edges_Index {
+    // message fields
+    pub node: ::protobuf::SingularPtrField<super::schema::Node>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Edges_Index {
+    fn default() -> &'a Edges_Index {
+        <Edges_Index as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Edges_Index {
+    pub fn new() -> Edges_Index {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.schema.Node node = 1;
+
+
+    pub fn get_node(&self) -> &super::schema::Node {
+        self.node.as_ref().unwrap_or_else(|| <super::schema::Node as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_node(&mut self) {
+        self.node.clear();
+    }
+
+    pub fn has_node(&self) -> bool {
+        self.node.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_node(&mut self, v: super::schema::Node) {
+        self.node = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_node(&mut self) -> &mut super::schema::Node {
+        if self.node.is_none() {
+            self.node.set_default();
+        }
+        self.node.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_node(&mut self) -> super::schema::Node {
+        self.node.take().unwrap_or_else(|| super::schema::Node::new())
+    }
+}
+
+impl ::protobuf::Message for Edges_Index {
+    fn is_initialized(&self) -> bool {
+        for v in &self.node {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.node)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.node.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.node.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Edges_Index {
+        Edges_Index::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::schema::Node>>(
+                "node",
+                |m: &Edges_Index| { &m.node },
+                |m: &mut Edges_Index| { &mut m.node },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Edges_Index>(
+                "Edges.Index",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Edges_Index {
+        static instance: ::protobuf::rt::LazyV2<Edges_Index> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Edges_Index::new)
+    }
+}
+
+impl ::protobuf::Clear for Edges_Index {
+    fn clear(&mut self) {
+        self.node.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Edges_Index {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Edges_Index {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Edges_Edge {
+    // message fields
+    pub ordinal: i32,
+    pub reverse: bool,
+    pub target: ::protobuf::SingularPtrField<super::storage::VName>,
+    // message oneof groups
+    pub kind: ::std::option::Option<Edges_Edge_oneof_kind>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Edges_Edge {
+    fn default() -> &'a Edges_Edge {
+        <Edges_Edge as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum Edges_Edge_oneof_kind {
+    kythe_kind(super::schema::EdgeKind),
+    generic_kind(::std::string::String),
+}
+
+impl Edges_Edge {
+    pub fn new() -> Edges_Edge {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.schema.EdgeKind kythe_kind = 1;
+
+
+    pub fn get_kythe_kind(&self) -> super::schema::EdgeKind {
+        match self.kind {
+            ::std::option::Option::Some(Edges_Edge_oneof_kind::kythe_kind(v)) => v,
+            _ => super::schema::EdgeKind::UNKNOWN_EDGE_KIND,
+        }
+    }
+    pub fn clear_kythe_kind(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_kythe_kind(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(Edges_Edge_oneof_kind::kythe_kind(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kythe_kind(&mut self, v: super::schema::EdgeKind) {
+        self.kind = ::std::option::Option::Some(Edges_Edge_oneof_kind::kythe_kind(v))
+    }
+
+    // string generic_kind = 2;
+
+
+    pub fn get_generic_kind(&self) -> &str {
+        match self.kind {
+            ::std::option::Option::Some(Edges_Edge_oneof_kind::generic_kind(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_generic_kind(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_generic_kind(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(Edges_Edge_oneof_kind::generic_kind(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_generic_kind(&mut self, v: ::std::string::String) {
+        self.kind = ::std::option::Option::Some(Edges_Edge_oneof_kind::generic_kind(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_generic_kind(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(Edges_Edge_oneof_kind::generic_kind(_)) = self.kind {
+        } else {
+            self.kind = ::std::option::Option::Some(Edges_Edge_oneof_kind::generic_kind(::std::string::String::new()));
+        }
+        match self.kind {
+            ::std::option::Option::Some(Edges_Edge_oneof_kind::generic_kind(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_generic_kind(&mut self) -> ::std::string::String {
+        if self.has_generic_kind() {
+            match self.kind.take() {
+                ::std::option::Option::Some(Edges_Edge_oneof_kind::generic_kind(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // int32 ordinal = 3;
+
+
+    pub fn get_ordinal(&self) -> i32 {
+        self.ordinal
+    }
+    pub fn clear_ordinal(&mut self) {
+        self.ordinal = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ordinal(&mut self, v: i32) {
+        self.ordinal = v;
+    }
+
+    // bool reverse = 4;
+
+
+    pub fn get_reverse(&self) -> bool {
+        self.reverse
+    }
+    pub fn clear_reverse(&mut self) {
+        self.reverse = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reverse(&mut self, v: bool) {
+        self.reverse = v;
+    }
+
+    // .kythe.proto.VName target = 5;
+
+
+    pub fn get_target(&self) -> &super::storage::VName {
+        self.target.as_ref().unwrap_or_else(|| <super::storage::VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: super::storage::VName) {
+        self.target = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut super::storage::VName {
+        if self.target.is_none() {
+            self.target.set_default();
+        }
+        self.target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> super::storage::VName {
+        self.target.take().unwrap_or_else(|| super::storage::VName::new())
+    }
+}
+
+impl ::protobuf::Message for Edges_Edge {
+    fn is_initialized(&self) -> bool {
+        for v in &self.target {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.kind = ::std::option::Option::Some(Edges_Edge_oneof_kind::kythe_kind(is.read_enum()?));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.kind = ::std::option::Option::Some(Edges_Edge_oneof_kind::generic_kind(is.read_string()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.ordinal = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.reverse = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.target)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.ordinal != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.ordinal, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.reverse != false {
+            my_size += 2;
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let ::std::option::Option::Some(ref v) = self.kind {
+            match v {
+                &Edges_Edge_oneof_kind::kythe_kind(v) => {
+                    my_size += ::protobuf::rt::enum_size(1, v);
+                },
+                &Edges_Edge_oneof_kind::generic_kind(ref v) => {
+                    my_size += ::protobuf::rt::string_size(2, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.ordinal != 0 {
+            os.write_int32(3, self.ordinal)?;
+        }
+        if self.reverse != false {
+            os.write_bool(4, self.reverse)?;
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.kind {
+            match v {
+                &Edges_Edge_oneof_kind::kythe_kind(v) => {
+                    os.write_enum(1, ::protobuf::ProtobufEnum::value(&v))?;
+                },
+                &Edges_Edge_oneof_kind::generic_kind(ref v) => {
+                    os.write_string(2, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Edges_Edge {
+        Edges_Edge::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor::<_, super::schema::EdgeKind>(
+                "kythe_kind",
+                Edges_Edge::has_kythe_kind,
+                Edges_Edge::get_kythe_kind,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "generic_kind",
+                Edges_Edge::has_generic_kind,
+                Edges_Edge::get_generic_kind,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "ordinal",
+                |m: &Edges_Edge| { &m.ordinal },
+                |m: &mut Edges_Edge| { &mut m.ordinal },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "reverse",
+                |m: &Edges_Edge| { &m.reverse },
+                |m: &mut Edges_Edge| { &mut m.reverse },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::storage::VName>>(
+                "target",
+                |m: &Edges_Edge| { &m.target },
+                |m: &mut Edges_Edge| { &mut m.target },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Edges_Edge>(
+                "Edges.Edge",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Edges_Edge {
+        static instance: ::protobuf::rt::LazyV2<Edges_Edge> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Edges_Edge::new)
+    }
+}
+
+impl ::protobuf::Clear for Edges_Edge {
+    fn clear(&mut self) {
+        self.kind = ::std::option::Option::None;
+        self.kind = ::std::option::Option::None;
+        self.ordinal = 0;
+        self.reverse = false;
+        self.target.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Edges_Edge {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Edges_Edge {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Edges_Target {
+    // message fields
+    pub node: ::protobuf::SingularPtrField<super::schema::Node>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Edges_Target {
+    fn default() -> &'a Edges_Target {
+        <Edges_Target as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Edges_Target {
+    pub fn new() -> Edges_Target {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.schema.Node node = 1;
+
+
+    pub fn get_node(&self) -> &super::schema::Node {
+        self.node.as_ref().unwrap_or_else(|| <super::schema::Node as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_node(&mut self) {
+        self.node.clear();
+    }
+
+    pub fn has_node(&self) -> bool {
+        self.node.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_node(&mut self, v: super::schema::Node) {
+        self.node = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_node(&mut self) -> &mut super::schema::Node {
+        if self.node.is_none() {
+            self.node.set_default();
+        }
+        self.node.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_node(&mut self) -> super::schema::Node {
+        self.node.take().unwrap_or_else(|| super::schema::Node::new())
+    }
+}
+
+impl ::protobuf::Message for Edges_Target {
+    fn is_initialized(&self) -> bool {
+        for v in &self.node {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.node)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.node.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.node.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Edges_Target {
+        Edges_Target::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::schema::Node>>(
+                "node",
+                |m: &Edges_Target| { &m.node },
+                |m: &mut Edges_Target| { &mut m.node },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Edges_Target>(
+                "Edges.Target",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Edges_Target {
+        static instance: ::protobuf::rt::LazyV2<Edges_Target> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Edges_Target::new)
+    }
+}
+
+impl ::protobuf::Clear for Edges_Target {
+    fn clear(&mut self) {
+        self.node.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Edges_Target {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Edges_Target {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x1fkythe/proto/graph_serving.proto\x12\x19kythe.proto.serving.graph\
+    \x1a\x18kythe/proto/schema.proto\x1a\x19kythe/proto/storage.proto\"\xc0\
+    \x04\n\x05Edges\x12*\n\x06source\x18\x01\x20\x01(\x0b2\x12.kythe.proto.V\
+    NameR\x06source\x12>\n\x05index\x18\x02\x20\x01(\x0b2&.kythe.proto.servi\
+    ng.graph.Edges.IndexH\0R\x05index\x12;\n\x04edge\x18\x03\x20\x01(\x0b2%.\
+    kythe.proto.serving.graph.Edges.EdgeH\0R\x04edge\x12A\n\x06target\x18\
+    \x04\x20\x01(\x0b2'.kythe.proto.serving.graph.Edges.TargetH\0R\x06target\
+    \x1a5\n\x05Index\x12,\n\x04node\x18\x01\x20\x01(\x0b2\x18.kythe.proto.sc\
+    hema.NodeR\x04node\x1a\xd2\x01\n\x04Edge\x12=\n\nkythe_kind\x18\x01\x20\
+    \x01(\x0e2\x1c.kythe.proto.schema.EdgeKindH\0R\tkytheKind\x12#\n\x0cgene\
+    ric_kind\x18\x02\x20\x01(\tH\0R\x0bgenericKind\x12\x18\n\x07ordinal\x18\
+    \x03\x20\x01(\x05R\x07ordinal\x12\x18\n\x07reverse\x18\x04\x20\x01(\x08R\
+    \x07reverse\x12*\n\x06target\x18\x05\x20\x01(\x0b2\x12.kythe.proto.VName\
+    R\x06targetB\x06\n\x04kind\x1a6\n\x06Target\x12,\n\x04node\x18\x01\x20\
+    \x01(\x0b2\x18.kythe.proto.schema.NodeR\x04nodeB\x07\n\x05entryBN\n\x1fc\
+    om.google.devtools.kythe.protoZ+kythe.io/kythe/proto/graph_serving_go_pr\
+    otob\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
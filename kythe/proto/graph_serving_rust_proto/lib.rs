@@ -0,0 +1,9 @@
+extern crate protobuf;
+pub mod storage;
+pub use storage::*;
+pub mod descriptor;
+pub use descriptor::*;
+pub mod schema;
+pub use schema::*;
+pub mod graph_serving;
+pub use graph_serving::*;
\ No newline at end of file
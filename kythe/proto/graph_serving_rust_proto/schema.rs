@@ -0,0 +1,2518 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `kythe/proto/schema.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Metadata {
+    // message fields
+    pub label: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Metadata {
+    fn default() -> &'a Metadata {
+        <Metadata as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Metadata {
+    pub fn new() -> Metadata {
+        ::std::default::Default::default()
+    }
+
+    // string label = 1;
+
+
+    pub fn get_label(&self) -> &str {
+        &self.label
+    }
+    pub fn clear_label(&mut self) {
+        self.label.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_label(&mut self, v: ::std::string::String) {
+        self.label = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_label(&mut self) -> &mut ::std::string::String {
+        &mut self.label
+    }
+
+    // Take field
+    pub fn take_label(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.label, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Metadata {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.label)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.label.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.label);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.label.is_empty() {
+            os.write_string(1, &self.label)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Metadata {
+        Metadata::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "label",
+                |m: &Metadata| { &m.label },
+                |m: &mut Metadata| { &mut m.label },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Metadata>(
+                "Metadata",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Metadata {
+        static instance: ::protobuf::rt::LazyV2<Metadata> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Metadata::new)
+    }
+}
+
+impl ::protobuf::Clear for Metadata {
+    fn clear(&mut self) {
+        self.label.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Metadata {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Metadata {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Node {
+    // message fields
+    pub source: ::protobuf::SingularPtrField<super::storage::VName>,
+    pub fact: ::protobuf::RepeatedField<Fact>,
+    pub edge: ::protobuf::RepeatedField<Edge>,
+    // message oneof groups
+    pub kind: ::std::option::Option<Node_oneof_kind>,
+    pub subkind: ::std::option::Option<Node_oneof_subkind>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Node {
+    fn default() -> &'a Node {
+        <Node as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum Node_oneof_kind {
+    kythe_kind(NodeKind),
+    generic_kind(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum Node_oneof_subkind {
+    kythe_subkind(Subkind),
+    generic_subkind(::std::string::String),
+}
+
+impl Node {
+    pub fn new() -> Node {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.VName source = 1;
+
+
+    pub fn get_source(&self) -> &super::storage::VName {
+        self.source.as_ref().unwrap_or_else(|| <super::storage::VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_source(&mut self) {
+        self.source.clear();
+    }
+
+    pub fn has_source(&self) -> bool {
+        self.source.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source(&mut self, v: super::storage::VName) {
+        self.source = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source(&mut self) -> &mut super::storage::VName {
+        if self.source.is_none() {
+            self.source.set_default();
+        }
+        self.source.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_source(&mut self) -> super::storage::VName {
+        self.source.take().unwrap_or_else(|| super::storage::VName::new())
+    }
+
+    // repeated .kythe.proto.schema.Fact fact = 2;
+
+
+    pub fn get_fact(&self) -> &[Fact] {
+        &self.fact
+    }
+    pub fn clear_fact(&mut self) {
+        self.fact.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fact(&mut self, v: ::protobuf::RepeatedField<Fact>) {
+        self.fact = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_fact(&mut self) -> &mut ::protobuf::RepeatedField<Fact> {
+        &mut self.fact
+    }
+
+    // Take field
+    pub fn take_fact(&mut self) -> ::protobuf::RepeatedField<Fact> {
+        ::std::mem::replace(&mut self.fact, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .kythe.proto.schema.Edge edge = 3;
+
+
+    pub fn get_edge(&self) -> &[Edge] {
+        &self.edge
+    }
+    pub fn clear_edge(&mut self) {
+        self.edge.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_edge(&mut self, v: ::protobuf::RepeatedField<Edge>) {
+        self.edge = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_edge(&mut self) -> &mut ::protobuf::RepeatedField<Edge> {
+        &mut self.edge
+    }
+
+    // Take field
+    pub fn take_edge(&mut self) -> ::protobuf::RepeatedField<Edge> {
+        ::std::mem::replace(&mut self.edge, ::protobuf::RepeatedField::new())
+    }
+
+    // .kythe.proto.schema.NodeKind kythe_kind = 4;
+
+
+    pub fn get_kythe_kind(&self) -> NodeKind {
+        match self.kind {
+            ::std::option::Option::Some(Node_oneof_kind::kythe_kind(v)) => v,
+            _ => NodeKind::UNKNOWN_NODE_KIND,
+        }
+    }
+    pub fn clear_kythe_kind(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_kythe_kind(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(Node_oneof_kind::kythe_kind(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kythe_kind(&mut self, v: NodeKind) {
+        self.kind = ::std::option::Option::Some(Node_oneof_kind::kythe_kind(v))
+    }
+
+    // string generic_kind = 5;
+
+
+    pub fn get_generic_kind(&self) -> &str {
+        match self.kind {
+            ::std::option::Option::Some(Node_oneof_kind::generic_kind(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_generic_kind(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_generic_kind(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(Node_oneof_kind::generic_kind(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_generic_kind(&mut self, v: ::std::string::String) {
+        self.kind = ::std::option::Option::Some(Node_oneof_kind::generic_kind(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_generic_kind(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(Node_oneof_kind::generic_kind(_)) = self.kind {
+        } else {
+            self.kind = ::std::option::Option::Some(Node_oneof_kind::generic_kind(::std::string::String::new()));
+        }
+        match self.kind {
+            ::std::option::Option::Some(Node_oneof_kind::generic_kind(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_generic_kind(&mut self) -> ::std::string::String {
+        if self.has_generic_kind() {
+            match self.kind.take() {
+                ::std::option::Option::Some(Node_oneof_kind::generic_kind(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // .kythe.proto.schema.Subkind kythe_subkind = 6;
+
+
+    pub fn get_kythe_subkind(&self) -> Subkind {
+        match self.subkind {
+            ::std::option::Option::Some(Node_oneof_subkind::kythe_subkind(v)) => v,
+            _ => Subkind::UNKNOWN_SUBKIND,
+        }
+    }
+    pub fn clear_kythe_subkind(&mut self) {
+        self.subkind = ::std::option::Option::None;
+    }
+
+    pub fn has_kythe_subkind(&self) -> bool {
+        match self.subkind {
+            ::std::option::Option::Some(Node_oneof_subkind::kythe_subkind(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kythe_subkind(&mut self, v: Subkind) {
+        self.subkind = ::std::option::Option::Some(Node_oneof_subkind::kythe_subkind(v))
+    }
+
+    // string generic_subkind = 7;
+
+
+    pub fn get_generic_subkind(&self) -> &str {
+        match self.subkind {
+            ::std::option::Option::Some(Node_oneof_subkind::generic_subkind(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_generic_subkind(&mut self) {
+        self.subkind = ::std::option::Option::None;
+    }
+
+    pub fn has_generic_subkind(&self) -> bool {
+        match self.subkind {
+            ::std::option::Option::Some(Node_oneof_subkind::generic_subkind(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_generic_subkind(&mut self, v: ::std::string::String) {
+        self.subkind = ::std::option::Option::Some(Node_oneof_subkind::generic_subkind(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_generic_subkind(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(Node_oneof_subkind::generic_subkind(_)) = self.subkind {
+        } else {
+            self.subkind = ::std::option::Option::Some(Node_oneof_subkind::generic_subkind(::std::string::String::new()));
+        }
+        match self.subkind {
+            ::std::option::Option::Some(Node_oneof_subkind::generic_subkind(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_generic_subkind(&mut self) -> ::std::string::String {
+        if self.has_generic_subkind() {
+            match self.subkind.take() {
+                ::std::option::Option::Some(Node_oneof_subkind::generic_subkind(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for Node {
+    fn is_initialized(&self) -> bool {
+        for v in &self.source {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.fact {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.edge {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.source)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.fact)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.edge)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.kind = ::std::option::Option::Some(Node_oneof_kind::kythe_kind(is.read_enum()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.kind = ::std::option::Option::Some(Node_oneof_kind::generic_kind(is.read_string()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.subkind = ::std::option::Option::Some(Node_oneof_subkind::kythe_subkind(is.read_enum()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.subkind = ::std::option::Option::Some(Node_oneof_subkind::generic_subkind(is.read_string()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.source.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.fact {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.edge {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let ::std::option::Option::Some(ref v) = self.kind {
+            match v {
+                &Node_oneof_kind::kythe_kind(v) => {
+                    my_size += ::protobuf::rt::enum_size(4, v);
+                },
+                &Node_oneof_kind::generic_kind(ref v) => {
+                    my_size += ::protobuf::rt::string_size(5, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.subkind {
+            match v {
+                &Node_oneof_subkind::kythe_subkind(v) => {
+                    my_size += ::protobuf::rt::enum_size(6, v);
+                },
+                &Node_oneof_subkind::generic_subkind(ref v) => {
+                    my_size += ::protobuf::rt::string_size(7, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.source.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.fact {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.edge {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let ::std::option::Option::Some(ref v) = self.kind {
+            match v {
+                &Node_oneof_kind::kythe_kind(v) => {
+                    os.write_enum(4, ::protobuf::ProtobufEnum::value(&v))?;
+                },
+                &Node_oneof_kind::generic_kind(ref v) => {
+                    os.write_string(5, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.subkind {
+            match v {
+                &Node_oneof_subkind::kythe_subkind(v) => {
+                    os.write_enum(6, ::protobuf::ProtobufEnum::value(&v))?;
+                },
+                &Node_oneof_subkind::generic_subkind(ref v) => {
+                    os.write_string(7, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Node {
+        Node::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::storage::VName>>(
+                "source",
+                |m: &Node| { &m.source },
+                |m: &mut Node| { &mut m.source },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Fact>>(
+                "fact",
+                |m: &Node| { &m.fact },
+                |m: &mut Node| { &mut m.fact },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Edge>>(
+                "edge",
+                |m: &Node| { &m.edge },
+                |m: &mut Node| { &mut m.edge },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor::<_, NodeKind>(
+                "kythe_kind",
+                Node::has_kythe_kind,
+                Node::get_kythe_kind,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "generic_kind",
+                Node::has_generic_kind,
+                Node::get_generic_kind,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor::<_, Subkind>(
+                "kythe_subkind",
+                Node::has_kythe_subkind,
+                Node::get_kythe_subkind,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "generic_subkind",
+                Node::has_generic_subkind,
+                Node::get_generic_subkind,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Node>(
+                "Node",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Node {
+        static instance: ::protobuf::rt::LazyV2<Node> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Node::new)
+    }
+}
+
+impl ::protobuf::Clear for Node {
+    fn clear(&mut self) {
+        self.source.clear();
+        self.fact.clear();
+        self.edge.clear();
+        self.kind = ::std::option::Option::None;
+        self.kind = ::std::option::Option::None;
+        self.subkind = ::std::option::Option::None;
+        self.subkind = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Node {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Node {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Fact {
+    // message fields
+    pub source: ::protobuf::SingularPtrField<super::storage::VName>,
+    pub value: ::std::vec::Vec<u8>,
+    // message oneof groups
+    pub name: ::std::option::Option<Fact_oneof_name>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Fact {
+    fn default() -> &'a Fact {
+        <Fact as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum Fact_oneof_name {
+    kythe_name(FactName),
+    generic_name(::std::string::String),
+}
+
+impl Fact {
+    pub fn new() -> Fact {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.VName source = 1;
+
+
+    pub fn get_source(&self) -> &super::storage::VName {
+        self.source.as_ref().unwrap_or_else(|| <super::storage::VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_source(&mut self) {
+        self.source.clear();
+    }
+
+    pub fn has_source(&self) -> bool {
+        self.source.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source(&mut self, v: super::storage::VName) {
+        self.source = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source(&mut self) -> &mut super::storage::VName {
+        if self.source.is_none() {
+            self.source.set_default();
+        }
+        self.source.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_source(&mut self) -> super::storage::VName {
+        self.source.take().unwrap_or_else(|| super::storage::VName::new())
+    }
+
+    // .kythe.proto.schema.FactName kythe_name = 2;
+
+
+    pub fn get_kythe_name(&self) -> FactName {
+        match self.name {
+            ::std::option::Option::Some(Fact_oneof_name::kythe_name(v)) => v,
+            _ => FactName::UNKNOWN_FACT_NAME,
+        }
+    }
+    pub fn clear_kythe_name(&mut self) {
+        self.name = ::std::option::Option::None;
+    }
+
+    pub fn has_kythe_name(&self) -> bool {
+        match self.name {
+            ::std::option::Option::Some(Fact_oneof_name::kythe_name(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kythe_name(&mut self, v: FactName) {
+        self.name = ::std::option::Option::Some(Fact_oneof_name::kythe_name(v))
+    }
+
+    // string generic_name = 3;
+
+
+    pub fn get_generic_name(&self) -> &str {
+        match self.name {
+            ::std::option::Option::Some(Fact_oneof_name::generic_name(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_generic_name(&mut self) {
+        self.name = ::std::option::Option::None;
+    }
+
+    pub fn has_generic_name(&self) -> bool {
+        match self.name {
+            ::std::option::Option::Some(Fact_oneof_name::generic_name(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_generic_name(&mut self, v: ::std::string::String) {
+        self.name = ::std::option::Option::Some(Fact_oneof_name::generic_name(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_generic_name(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(Fact_oneof_name::generic_name(_)) = self.name {
+        } else {
+            self.name = ::std::option::Option::Some(Fact_oneof_name::generic_name(::std::string::String::new()));
+        }
+        match self.name {
+            ::std::option::Option::Some(Fact_oneof_name::generic_name(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_generic_name(&mut self) -> ::std::string::String {
+        if self.has_generic_name() {
+            match self.name.take() {
+                ::std::option::Option::Some(Fact_oneof_name::generic_name(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // bytes value = 4;
+
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for Fact {
+    fn is_initialized(&self) -> bool {
+        for v in &self.source {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.source)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.name = ::std::option::Option::Some(Fact_oneof_name::kythe_name(is.read_enum()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.name = ::std::option::Option::Some(Fact_oneof_name::generic_name(is.read_string()?));
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.source.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.value);
+        }
+        if let ::std::option::Option::Some(ref v) = self.name {
+            match v {
+                &Fact_oneof_name::kythe_name(v) => {
+                    my_size += ::protobuf::rt::enum_size(2, v);
+                },
+                &Fact_oneof_name::generic_name(ref v) => {
+                    my_size += ::protobuf::rt::string_size(3, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.source.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(4, &self.value)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.name {
+            match v {
+                &Fact_oneof_name::kythe_name(v) => {
+                    os.write_enum(2, ::protobuf::ProtobufEnum::value(&v))?;
+                },
+                &Fact_oneof_name::generic_name(ref v) => {
+                    os.write_string(3, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Fact {
+        Fact::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::storage::VName>>(
+                "source",
+                |m: &Fact| { &m.source },
+                |m: &mut Fact| { &mut m.source },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor::<_, FactName>(
+                "kythe_name",
+                Fact::has_kythe_name,
+                Fact::get_kythe_name,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "generic_name",
+                Fact::has_generic_name,
+                Fact::get_generic_name,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "value",
+                |m: &Fact| { &m.value },
+                |m: &mut Fact| { &mut m.value },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Fact>(
+                "Fact",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Fact {
+        static instance: ::protobuf::rt::LazyV2<Fact> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Fact::new)
+    }
+}
+
+impl ::protobuf::Clear for Fact {
+    fn clear(&mut self) {
+        self.source.clear();
+        self.name = ::std::option::Option::None;
+        self.name = ::std::option::Option::None;
+        self.value.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Fact {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Fact {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Edge {
+    // message fields
+    pub source: ::protobuf::SingularPtrField<super::storage::VName>,
+    pub target: ::protobuf::SingularPtrField<super::storage::VName>,
+    pub ordinal: i32,
+    pub source_node: ::protobuf::SingularPtrField<Node>,
+    pub target_node: ::protobuf::SingularPtrField<Node>,
+    // message oneof groups
+    pub kind: ::std::option::Option<Edge_oneof_kind>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Edge {
+    fn default() -> &'a Edge {
+        <Edge as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum Edge_oneof_kind {
+    kythe_kind(EdgeKind),
+    generic_kind(::std::string::String),
+}
+
+impl Edge {
+    pub fn new() -> Edge {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.VName source = 1;
+
+
+    pub fn get_source(&self) -> &super::storage::VName {
+        self.source.as_ref().unwrap_or_else(|| <super::storage::VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_source(&mut self) {
+        self.source.clear();
+    }
+
+    pub fn has_source(&self) -> bool {
+        self.source.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source(&mut self, v: super::storage::VName) {
+        self.source = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source(&mut self) -> &mut super::storage::VName {
+        if self.source.is_none() {
+            self.source.set_default();
+        }
+        self.source.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_source(&mut self) -> super::storage::VName {
+        self.source.take().unwrap_or_else(|| super::storage::VName::new())
+    }
+
+    // .kythe.proto.VName target = 2;
+
+
+    pub fn get_target(&self) -> &super::storage::VName {
+        self.target.as_ref().unwrap_or_else(|| <super::storage::VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: super::storage::VName) {
+        self.target = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut super::storage::VName {
+        if self.target.is_none() {
+            self.target.set_default();
+        }
+        self.target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> super::storage::VName {
+        self.target.take().unwrap_or_else(|| super::storage::VName::new())
+    }
+
+    // .kythe.proto.schema.EdgeKind kythe_kind = 3;
+
+
+    pub fn get_kythe_kind(&self) -> EdgeKind {
+        match self.kind {
+            ::std::option::Option::Some(Edge_oneof_kind::kythe_kind(v)) => v,
+            _ => EdgeKind::UNKNOWN_EDGE_KIND,
+        }
+    }
+    pub fn clear_kythe_kind(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_kythe_kind(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(Edge_oneof_kind::kythe_kind(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kythe_kind(&mut self, v: EdgeKind) {
+        self.kind = ::std::option::Option::Some(Edge_oneof_kind::kythe_kind(v))
+    }
+
+    // string generic_kind = 4;
+
+
+    pub fn get_generic_kind(&self) -> &str {
+        match self.kind {
+            ::std::option::Option::Some(Edge_oneof_kind::generic_kind(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_generic_kind(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_generic_kind(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(Edge_oneof_kind::generic_kind(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_generic_kind(&mut self, v: ::std::string::String) {
+        self.kind = ::std::option::Option::Some(Edge_oneof_kind::generic_kind(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_generic_kind(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(Edge_oneof_kind::generic_kind(_)) = self.kind {
+        } else {
+            self.kind = ::std::option::Option::Some(Edge_oneof_kind::generic_kind(::std::string::String::new()));
+        }
+        match self.kind {
+            ::std::option::Option::Some(Edge_oneof_kind::generic_kind(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_generic_kind(&mut self) -> ::std::string::String {
+        if self.has_generic_kind() {
+            match self.kind.take() {
+                ::std::option::Option::Some(Edge_oneof_kind::generic_kind(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // int32 ordinal = 5;
+
+
+    pub fn get_ordinal(&self) -> i32 {
+        self.ordinal
+    }
+    pub fn clear_ordinal(&mut self) {
+        self.ordinal = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ordinal(&mut self, v: i32) {
+        self.ordinal = v;
+    }
+
+    // .kythe.proto.schema.Node source_node = 6;
+
+
+    pub fn get_source_node(&self) -> &Node {
+        self.source_node.as_ref().unwrap_or_else(|| <Node as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_source_node(&mut self) {
+        self.source_node.clear();
+    }
+
+    pub fn has_source_node(&self) -> bool {
+        self.source_node.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source_node(&mut self, v: Node) {
+        self.source_node = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source_node(&mut self) -> &mut Node {
+        if self.source_node.is_none() {
+            self.source_node.set_default();
+        }
+        self.source_node.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_source_node(&mut self) -> Node {
+        self.source_node.take().unwrap_or_else(|| Node::new())
+    }
+
+    // .kythe.proto.schema.Node target_node = 7;
+
+
+    pub fn get_target_node(&self) -> &Node {
+        self.target_node.as_ref().unwrap_or_else(|| <Node as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_target_node(&mut self) {
+        self.target_node.clear();
+    }
+
+    pub fn has_target_node(&self) -> bool {
+        self.target_node.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_node(&mut self, v: Node) {
+        self.target_node = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target_node(&mut self) -> &mut Node {
+        if self.target_node.is_none() {
+            self.target_node.set_default();
+        }
+        self.target_node.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target_node(&mut self) -> Node {
+        self.target_node.take().unwrap_or_else(|| Node::new())
+    }
+}
+
+impl ::protobuf::Message for Edge {
+    fn is_initialized(&self) -> bool {
+        for v in &self.source {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.target {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.source_node {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.target_node {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.source)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.target)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.kind = ::std::option::Option::Some(Edge_oneof_kind::kythe_kind(is.read_enum()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.kind = ::std::option::Option::Some(Edge_oneof_kind::generic_kind(is.read_string()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.ordinal = tmp;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.source_node)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.target_node)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.source.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.ordinal != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.ordinal, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.source_node.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.target_node.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let ::std::option::Option::Some(ref v) = self.kind {
+            match v {
+                &Edge_oneof_kind::kythe_kind(v) => {
+                    my_size += ::protobuf::rt::enum_size(3, v);
+                },
+                &Edge_oneof_kind::generic_kind(ref v) => {
+                    my_size += ::protobuf::rt::string_size(4, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.source.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.ordinal != 0 {
+            os.write_int32(5, self.ordinal)?;
+        }
+        if let Some(ref v) = self.source_node.as_ref() {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.target_node.as_ref() {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.kind {
+            match v {
+                &Edge_oneof_kind::kythe_kind(v) => {
+                    os.write_enum(3, ::protobuf::ProtobufEnum::value(&v))?;
+                },
+                &Edge_oneof_kind::generic_kind(ref v) => {
+                    os.write_string(4, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Edge {
+        Edge::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::storage::VName>>(
+                "source",
+                |m: &Edge| { &m.source },
+                |m: &mut Edge| { &mut m.source },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::storage::VName>>(
+                "target",
+                |m: &Edge| { &m.target },
+                |m: &mut Edge| { &mut m.target },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor::<_, EdgeKind>(
+                "kythe_kind",
+                Edge::has_kythe_kind,
+                Edge::get_kythe_kind,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "generic_kind",
+                Edge::has_generic_kind,
+                Edge::get_generic_kind,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "ordinal",
+                |m: &Edge| { &m.ordinal },
+                |m: &mut Edge| { &mut m.ordinal },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                "source_node",
+                |m: &Edge| { &m.source_node },
+                |m: &mut Edge| { &mut m.source_node },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                "target_node",
+                |m: &Edge| { &m.target_node },
+                |m: &mut Edge| { &mut m.target_node },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Edge>(
+                "Edge",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Edge {
+        static instance: ::protobuf::rt::LazyV2<Edge> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Edge::new)
+    }
+}
+
+impl ::protobuf::Clear for Edge {
+    fn clear(&mut self) {
+        self.source.clear();
+        self.target.clear();
+        self.kind = ::std::option::Option::None;
+        self.kind = ::std::option::Option::None;
+        self.ordinal = 0;
+        self.source_node.clear();
+        self.target_node.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Edge {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Edge {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Entry {
+    // message oneof groups
+    pub entry: ::std::option::Option<Entry_oneof_entry>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Entry {
+    fn default() -> &'a Entry {
+        <Entry as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum Entry_oneof_entry {
+    fact(Fact),
+    edge(Edge),
+}
+
+impl Entry {
+    pub fn new() -> Entry {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.schema.Fact fact = 1;
+
+
+    pub fn get_fact(&self) -> &Fact {
+        match self.entry {
+            ::std::option::Option::Some(Entry_oneof_entry::fact(ref v)) => v,
+            _ => <Fact as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_fact(&mut self) {
+        self.entry = ::std::option::Option::None;
+    }
+
+    pub fn has_fact(&self) -> bool {
+        match self.entry {
+            ::std::option::Option::Some(Entry_oneof_entry::fact(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fact(&mut self, v: Fact) {
+        self.entry = ::std::option::Option::Some(Entry_oneof_entry::fact(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_fact(&mut self) -> &mut Fact {
+        if let ::std::option::Option::Some(Entry_oneof_entry::fact(_)) = self.entry {
+        } else {
+            self.entry = ::std::option::Option::Some(Entry_oneof_entry::fact(Fact::new()));
+        }
+        match self.entry {
+            ::std::option::Option::Some(Entry_oneof_entry::fact(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_fact(&mut self) -> Fact {
+        if self.has_fact() {
+            match self.entry.take() {
+                ::std::option::Option::Some(Entry_oneof_entry::fact(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Fact::new()
+        }
+    }
+
+    // .kythe.proto.schema.Edge edge = 2;
+
+
+    pub fn get_edge(&self) -> &Edge {
+        match self.entry {
+            ::std::option::Option::Some(Entry_oneof_entry::edge(ref v)) => v,
+            _ => <Edge as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_edge(&mut self) {
+        self.entry = ::std::option::Option::None;
+    }
+
+    pub fn has_edge(&self) -> bool {
+        match self.entry {
+            ::std::option::Option::Some(Entry_oneof_entry::edge(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_edge(&mut self, v: Edge) {
+        self.entry = ::std::option::Option::Some(Entry_oneof_entry::edge(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_edge(&mut self) -> &mut Edge {
+        if let ::std::option::Option::Some(Entry_oneof_entry::edge(_)) = self.entry {
+        } else {
+            self.entry = ::std::option::Option::Some(Entry_oneof_entry::edge(Edge::new()));
+        }
+        match self.entry {
+            ::std::option::Option::Some(Entry_oneof_entry::edge(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_edge(&mut self) -> Edge {
+        if self.has_edge() {
+            match self.entry.take() {
+                ::std::option::Option::Some(Entry_oneof_entry::edge(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Edge::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for Entry {
+    fn is_initialized(&self) -> bool {
+        if let Some(Entry_oneof_entry::fact(ref v)) = self.entry {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Entry_oneof_entry::edge(ref v)) = self.entry {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.entry = ::std::option::Option::Some(Entry_oneof_entry::fact(is.read_message()?));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.entry = ::std::option::Option::Some(Entry_oneof_entry::edge(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.entry {
+            match v {
+                &Entry_oneof_entry::fact(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Entry_oneof_entry::edge(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let ::std::option::Option::Some(ref v) = self.entry {
+            match v {
+                &Entry_oneof_entry::fact(ref v) => {
+                    os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Entry_oneof_entry::edge(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Entry {
+        Entry::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Fact>(
+                "fact",
+                Entry::has_fact,
+                Entry::get_fact,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Edge>(
+                "edge",
+                Entry::has_edge,
+                Entry::get_edge,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Entry>(
+                "Entry",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Entry {
+        static instance: ::protobuf::rt::LazyV2<Entry> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Entry::new)
+    }
+}
+
+impl ::protobuf::Clear for Entry {
+    fn clear(&mut self) {
+        self.entry = ::std::option::Option::None;
+        self.entry = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Entry {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Entry {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum NodeKind {
+    UNKNOWN_NODE_KIND = 0,
+    ANCHOR = 3,
+    CONSTANT = 4,
+    DIAGNOSTIC = 5,
+    DOC = 6,
+    FILE = 7,
+    GFLAG = 27,
+    INTERFACE = 8,
+    FUNCTION = 9,
+    LOOKUP = 10,
+    MACRO = 11,
+    META = 12,
+    NAME = 13,
+    PACKAGE = 14,
+    PROCESS = 15,
+    RECORD = 16,
+    SUM = 17,
+    SYMBOL = 18,
+    TALIAS = 19,
+    TAPP = 20,
+    TVAR = 26,
+    TBUILTIN = 21,
+    TNOMINAL = 22,
+    TSIGMA = 23,
+    VARIABLE = 24,
+    VCS = 25,
+}
+
+impl ::protobuf::ProtobufEnum for NodeKind {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<NodeKind> {
+        match value {
+            0 => ::std::option::Option::Some(NodeKind::UNKNOWN_NODE_KIND),
+            3 => ::std::option::Option::Some(NodeKind::ANCHOR),
+            4 => ::std::option::Option::Some(NodeKind::CONSTANT),
+            5 => ::std::option::Option::Some(NodeKind::DIAGNOSTIC),
+            6 => ::std::option::Option::Some(NodeKind::DOC),
+            7 => ::std::option::Option::Some(NodeKind::FILE),
+            27 => ::std::option::Option::Some(NodeKind::GFLAG),
+            8 => ::std::option::Option::Some(NodeKind::INTERFACE),
+            9 => ::std::option::Option::Some(NodeKind::FUNCTION),
+            10 => ::std::option::Option::Some(NodeKind::LOOKUP),
+            11 => ::std::option::Option::Some(NodeKind::MACRO),
+            12 => ::std::option::Option::Some(NodeKind::META),
+            13 => ::std::option::Option::Some(NodeKind::NAME),
+            14 => ::std::option::Option::Some(NodeKind::PACKAGE),
+            15 => ::std::option::Option::Some(NodeKind::PROCESS),
+            16 => ::std::option::Option::Some(NodeKind::RECORD),
+            17 => ::std::option::Option::Some(NodeKind::SUM),
+            18 => ::std::option::Option::Some(NodeKind::SYMBOL),
+            19 => ::std::option::Option::Some(NodeKind::TALIAS),
+            20 => ::std::option::Option::Some(NodeKind::TAPP),
+            26 => ::std::option::Option::Some(NodeKind::TVAR),
+            21 => ::std::option::Option::Some(NodeKind::TBUILTIN),
+            22 => ::std::option::Option::Some(NodeKind::TNOMINAL),
+            23 => ::std::option::Option::Some(NodeKind::TSIGMA),
+            24 => ::std::option::Option::Some(NodeKind::VARIABLE),
+            25 => ::std::option::Option::Some(NodeKind::VCS),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [NodeKind] = &[
+            NodeKind::UNKNOWN_NODE_KIND,
+            NodeKind::ANCHOR,
+            NodeKind::CONSTANT,
+            NodeKind::DIAGNOSTIC,
+            NodeKind::DOC,
+            NodeKind::FILE,
+            NodeKind::GFLAG,
+            NodeKind::INTERFACE,
+            NodeKind::FUNCTION,
+            NodeKind::LOOKUP,
+            NodeKind::MACRO,
+            NodeKind::META,
+            NodeKind::NAME,
+            NodeKind::PACKAGE,
+            NodeKind::PROCESS,
+            NodeKind::RECORD,
+            NodeKind::SUM,
+            NodeKind::SYMBOL,
+            NodeKind::TALIAS,
+            NodeKind::TAPP,
+            NodeKind::TVAR,
+            NodeKind::TBUILTIN,
+            NodeKind::TNOMINAL,
+            NodeKind::TSIGMA,
+            NodeKind::VARIABLE,
+            NodeKind::VCS,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<NodeKind>("NodeKind", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for NodeKind {
+}
+
+impl ::std::default::Default for NodeKind {
+    fn default() -> Self {
+        NodeKind::UNKNOWN_NODE_KIND
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for NodeKind {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Subkind {
+    UNKNOWN_SUBKIND = 0,
+    CATEGORY = 1,
+    CLASS = 2,
+    CONSTRUCTOR = 3,
+    DESTRUCTOR = 4,
+    ENUM = 5,
+    ENUM_CLASS = 6,
+    FIELD = 7,
+    IMPLICIT = 8,
+    IMPORT = 9,
+    INITIALIZER = 10,
+    LOCAL = 11,
+    LOCAL_PARAMETER = 12,
+    METHOD = 13,
+    NAMESPACE = 14,
+    STRUCT = 15,
+    TYPE = 16,
+    UNION = 17,
+}
+
+impl ::protobuf::ProtobufEnum for Subkind {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Subkind> {
+        match value {
+            0 => ::std::option::Option::Some(Subkind::UNKNOWN_SUBKIND),
+            1 => ::std::option::Option::Some(Subkind::CATEGORY),
+            2 => ::std::option::Option::Some(Subkind::CLASS),
+            3 => ::std::option::Option::Some(Subkind::CONSTRUCTOR),
+            4 => ::std::option::Option::Some(Subkind::DESTRUCTOR),
+            5 => ::std::option::Option::Some(Subkind::ENUM),
+            6 => ::std::option::Option::Some(Subkind::ENUM_CLASS),
+            7 => ::std::option::Option::Some(Subkind::FIELD),
+            8 => ::std::option::Option::Some(Subkind::IMPLICIT),
+            9 => ::std::option::Option::Some(Subkind::IMPORT),
+            10 => ::std::option::Option::Some(Subkind::INITIALIZER),
+            11 => ::std::option::Option::Some(Subkind::LOCAL),
+            12 => ::std::option::Option::Some(Subkind::LOCAL_PARAMETER),
+            13 => ::std::option::Option::Some(Subkind::METHOD),
+            14 => ::std::option::Option::Some(Subkind::NAMESPACE),
+            15 => ::std::option::Option::Some(Subkind::STRUCT),
+            16 => ::std::option::Option::Some(Subkind::TYPE),
+            17 => ::std::option::Option::Some(Subkind::UNION),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Subkind] = &[
+            Subkind::UNKNOWN_SUBKIND,
+            Subkind::CATEGORY,
+            Subkind::CLASS,
+            Subkind::CONSTRUCTOR,
+            Subkind::DESTRUCTOR,
+            Subkind::ENUM,
+            Subkind::ENUM_CLASS,
+            Subkind::FIELD,
+            Subkind::IMPLICIT,
+            Subkind::IMPORT,
+            Subkind::INITIALIZER,
+            Subkind::LOCAL,
+            Subkind::LOCAL_PARAMETER,
+            Subkind::METHOD,
+            Subkind::NAMESPACE,
+            Subkind::STRUCT,
+            Subkind::TYPE,
+            Subkind::UNION,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<Subkind>("Subkind", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for Subkind {
+}
+
+impl ::std::default::Default for Subkind {
+    fn default() -> Self {
+        Subkind::UNKNOWN_SUBKIND
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Subkind {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum FactName {
+    UNKNOWN_FACT_NAME = 0,
+    CODE = 1,
+    COMPLETE = 2,
+    CONTEXT_URL = 3,
+    DETAILS = 4,
+    DOC_URI = 5,
+    LABEL = 6,
+    LOC_END = 7,
+    LOC_START = 8,
+    MESSAGE = 9,
+    NODE_KIND = 10,
+    PARAM_DEFAULT = 11,
+    RULE_CLASS = 12,
+    SNIPPET_END = 13,
+    SNIPPET_START = 14,
+    SUBKIND = 15,
+    TEXT = 16,
+    TEXT_ENCODING = 17,
+    VISIBILITY = 18,
+    BUILD_CONFIG = 19,
+    TAG_DEPRECATED = 20,
+}
+
+impl ::protobuf::ProtobufEnum for FactName {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<FactName> {
+        match value {
+            0 => ::std::option::Option::Some(FactName::UNKNOWN_FACT_NAME),
+            1 => ::std::option::Option::Some(FactName::CODE),
+            2 => ::std::option::Option::Some(FactName::COMPLETE),
+            3 => ::std::option::Option::Some(FactName::CONTEXT_URL),
+            4 => ::std::option::Option::Some(FactName::DETAILS),
+            5 => ::std::option::Option::Some(FactName::DOC_URI),
+            6 => ::std::option::Option::Some(FactName::LABEL),
+            7 => ::std::option::Option::Some(FactName::LOC_END),
+            8 => ::std::option::Option::Some(FactName::LOC_START),
+            9 => ::std::option::Option::Some(FactName::MESSAGE),
+            10 => ::std::option::Option::Some(FactName::NODE_KIND),
+            11 => ::std::option::Option::Some(FactName::PARAM_DEFAULT),
+            12 => ::std::option::Option::Some(FactName::RULE_CLASS),
+            13 => ::std::option::Option::Some(FactName::SNIPPET_END),
+            14 => ::std::option::Option::Some(FactName::SNIPPET_START),
+            15 => ::std::option::Option::Some(FactName::SUBKIND),
+            16 => ::std::option::Option::Some(FactName::TEXT),
+            17 => ::std::option::Option::Some(FactName::TEXT_ENCODING),
+            18 => ::std::option::Option::Some(FactName::VISIBILITY),
+            19 => ::std::option::Option::Some(FactName::BUILD_CONFIG),
+            20 => ::std::option::Option::Some(FactName::TAG_DEPRECATED),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [FactName] = &[
+            FactName::UNKNOWN_FACT_NAME,
+            FactName::CODE,
+            FactName::COMPLETE,
+            FactName::CONTEXT_URL,
+            FactName::DETAILS,
+            FactName::DOC_URI,
+            FactName::LABEL,
+            FactName::LOC_END,
+            FactName::LOC_START,
+            FactName::MESSAGE,
+            FactName::NODE_KIND,
+            FactName::PARAM_DEFAULT,
+            FactName::RULE_CLASS,
+            FactName::SNIPPET_END,
+            FactName::SNIPPET_START,
+            FactName::SUBKIND,
+            FactName::TEXT,
+            FactName::TEXT_ENCODING,
+            FactName::VISIBILITY,
+            FactName::BUILD_CONFIG,
+            FactName::TAG_DEPRECATED,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<FactName>("FactName", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for FactName {
+}
+
+impl ::std::default::Default for FactName {
+    fn default() -> Self {
+        FactName::UNKNOWN_FACT_NAME
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FactName {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum EdgeKind {
+    UNKNOWN_EDGE_KIND = 0,
+    ALIASES = 1,
+    ALIASES_ROOT = 2,
+    ANNOTATED_BY = 3,
+    BOUNDED_LOWER = 4,
+    BOUNDED_UPPER = 5,
+    CHILD_OF = 6,
+    CHILD_OF_CONTEXT = 7,
+    COMPLETED_BY = 49,
+    DEFINES = 10,
+    DEFINES_BINDING = 11,
+    DEFINES_IMPLICIT = 50,
+    DEPENDS = 12,
+    DOCUMENTS = 13,
+    EXPORTS = 14,
+    EXTENDS = 15,
+    GENERATES = 16,
+    IMPUTES = 17,
+    INSTANTIATES = 18,
+    INSTANTIATES_SPECULATIVE = 19,
+    NAMED = 20,
+    OVERRIDES = 21,
+    OVERRIDES_ROOT = 22,
+    OVERRIDES_TRANSITIVE = 23,
+    PARAM = 24,
+    PROPERTY_READS = 44,
+    PROPERTY_WRITES = 45,
+    REF = 25,
+    REF_CALL = 26,
+    REF_CALL_IMPLICIT = 27,
+    REF_DOC = 28,
+    REF_EXPANDS = 29,
+    REF_EXPANDS_TRANSITIVE = 30,
+    REF_FILE = 31,
+    REF_ID = 46,
+    REF_IMPLICIT = 32,
+    REF_IMPORTS = 33,
+    REF_INCLUDES = 34,
+    REF_INIT = 35,
+    REF_INIT_IMPLICIT = 36,
+    REF_QUERIES = 37,
+    REF_WRITES = 47,
+    SATISFIES = 38,
+    SPECIALIZES = 39,
+    SPECIALIZES_SPECULATIVE = 40,
+    TAGGED = 41,
+    TPARAM = 48,
+    TYPED = 42,
+    UNDEFINES = 43,
+}
+
+impl ::protobuf::ProtobufEnum for EdgeKind {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<EdgeKind> {
+        match value {
+            0 => ::std::option::Option::Some(EdgeKind::UNKNOWN_EDGE_KIND),
+            1 => ::std::option::Option::Some(EdgeKind::ALIASES),
+            2 => ::std::option::Option::Some(EdgeKind::ALIASES_ROOT),
+            3 => ::std::option::Option::Some(EdgeKind::ANNOTATED_BY),
+            4 => ::std::option::Option::Some(EdgeKind::BOUNDED_LOWER),
+            5 => ::std::option::Option::Some(EdgeKind::BOUNDED_UPPER),
+            6 => ::std::option::Option::Some(EdgeKind::CHILD_OF),
+            7 => ::std::option::Option::Some(EdgeKind::CHILD_OF_CONTEXT),
+            49 => ::std::option::Option::Some(EdgeKind::COMPLETED_BY),
+            10 => ::std::option::Option::Some(EdgeKind::DEFINES),
+            11 => ::std::option::Option::Some(EdgeKind::DEFINES_BINDING),
+            50 => ::std::option::Option::Some(EdgeKind::DEFINES_IMPLICIT),
+            12 => ::std::option::Option::Some(EdgeKind::DEPENDS),
+            13 => ::std::option::Option::Some(EdgeKind::DOCUMENTS),
+            14 => ::std::option::Option::Some(EdgeKind::EXPORTS),
+            15 => ::std::option::Option::Some(EdgeKind::EXTENDS),
+            16 => ::std::option::Option::Some(EdgeKind::GENERATES),
+            17 => ::std::option::Option::Some(EdgeKind::IMPUTES),
+            18 => ::std::option::Option::Some(EdgeKind::INSTANTIATES),
+            19 => ::std::option::Option::Some(EdgeKind::INSTANTIATES_SPECULATIVE),
+            20 => ::std::option::Option::Some(EdgeKind::NAMED),
+            21 => ::std::option::Option::Some(EdgeKind::OVERRIDES),
+            22 => ::std::option::Option::Some(EdgeKind::OVERRIDES_ROOT),
+            23 => ::std::option::Option::Some(EdgeKind::OVERRIDES_TRANSITIVE),
+            24 => ::std::option::Option::Some(EdgeKind::PARAM),
+            44 => ::std::option::Option::Some(EdgeKind::PROPERTY_READS),
+            45 => ::std::option::Option::Some(EdgeKind::PROPERTY_WRITES),
+            25 => ::std::option::Option::Some(EdgeKind::REF),
+            26 => ::std::option::Option::Some(EdgeKind::REF_CALL),
+            27 => ::std::option::Option::Some(EdgeKind::REF_CALL_IMPLICIT),
+            28 => ::std::option::Option::Some(EdgeKind::REF_DOC),
+            29 => ::std::option::Option::Some(EdgeKind::REF_EXPANDS),
+            30 => ::std::option::Option::Some(EdgeKind::REF_EXPANDS_TRANSITIVE),
+            31 => ::std::option::Option::Some(EdgeKind::REF_FILE),
+            46 => ::std::option::Option::Some(EdgeKind::REF_ID),
+            32 => ::std::option::Option::Some(EdgeKind::REF_IMPLICIT),
+            33 => ::std::option::Option::Some(EdgeKind::REF_IMPORTS),
+            34 => ::std::option::Option::Some(EdgeKind::REF_INCLUDES),
+            35 => ::std::option::Option::Some(EdgeKind::REF_INIT),
+            36 => ::std::option::Option::Some(EdgeKind::REF_INIT_IMPLICIT),
+            37 => ::std::option::Option::Some(EdgeKind::REF_QUERIES),
+            47 => ::std::option::Option::Some(EdgeKind::REF_WRITES),
+            38 => ::std::option::Option::Some(EdgeKind::SATISFIES),
+            39 => ::std::option::Option::Some(EdgeKind::SPECIALIZES),
+            40 => ::std::option::Option::Some(EdgeKind::SPECIALIZES_SPECULATIVE),
+            41 => ::std::option::Option::Some(EdgeKind::TAGGED),
+            48 => ::std::option::Option::Some(EdgeKind::TPARAM),
+            42 => ::std::option::Option::Some(EdgeKind::TYPED),
+            43 => ::std::option::Option::Some(EdgeKind::UNDEFINES),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [EdgeKind] = &[
+            EdgeKind::UNKNOWN_EDGE_KIND,
+            EdgeKind::ALIASES,
+            EdgeKind::ALIASES_ROOT,
+            EdgeKind::ANNOTATED_BY,
+            EdgeKind::BOUNDED_LOWER,
+            EdgeKind::BOUNDED_UPPER,
+            EdgeKind::CHILD_OF,
+            EdgeKind::CHILD_OF_CONTEXT,
+            EdgeKind::COMPLETED_BY,
+            EdgeKind::DEFINES,
+            EdgeKind::DEFINES_BINDING,
+            EdgeKind::DEFINES_IMPLICIT,
+            EdgeKind::DEPENDS,
+            EdgeKind::DOCUMENTS,
+            EdgeKind::EXPORTS,
+            EdgeKind::EXTENDS,
+            EdgeKind::GENERATES,
+            EdgeKind::IMPUTES,
+            EdgeKind::INSTANTIATES,
+            EdgeKind::INSTANTIATES_SPECULATIVE,
+            EdgeKind::NAMED,
+            EdgeKind::OVERRIDES,
+            EdgeKind::OVERRIDES_ROOT,
+            EdgeKind::OVERRIDES_TRANSITIVE,
+            EdgeKind::PARAM,
+            EdgeKind::PROPERTY_READS,
+            EdgeKind::PROPERTY_WRITES,
+            EdgeKind::REF,
+            EdgeKind::REF_CALL,
+            EdgeKind::REF_CALL_IMPLICIT,
+            EdgeKind::REF_DOC,
+            EdgeKind::REF_EXPANDS,
+            EdgeKind::REF_EXPANDS_TRANSITIVE,
+            EdgeKind::REF_FILE,
+            EdgeKind::REF_ID,
+            EdgeKind::REF_IMPLICIT,
+            EdgeKind::REF_IMPORTS,
+            EdgeKind::REF_INCLUDES,
+            EdgeKind::REF_INIT,
+            EdgeKind::REF_INIT_IMPLICIT,
+            EdgeKind::REF_QUERIES,
+            EdgeKind::REF_WRITES,
+            EdgeKind::SATISFIES,
+            EdgeKind::SPECIALIZES,
+            EdgeKind::SPECIALIZES_SPECULATIVE,
+            EdgeKind::TAGGED,
+            EdgeKind::TPARAM,
+            EdgeKind::TYPED,
+            EdgeKind::UNDEFINES,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<EdgeKind>("EdgeKind", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for EdgeKind {
+}
+
+impl ::std::default::Default for EdgeKind {
+    fn default() -> Self {
+        EdgeKind::UNKNOWN_EDGE_KIND
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EdgeKind {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+/// Extension fields
+pub mod exts {
+
+    pub const metadata: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::EnumValueOptions, ::protobuf::types::ProtobufTypeMessage<super::Metadata>> = ::protobuf::ext::ExtFieldOptional { field_number: 69945, phantom: ::std::marker::PhantomData };
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x18kythe/proto/schema.proto\x12\x12kythe.proto.schema\x1a\x20google/p\
+    rotobuf/descriptor.proto\x1a\x19kythe/proto/storage.proto\"\x20\n\x08Met\
+    adata\x12\x14\n\x05label\x18\x01\x20\x01(\tR\x05label\"\xf4\x02\n\x04Nod\
+    e\x12*\n\x06source\x18\x01\x20\x01(\x0b2\x12.kythe.proto.VNameR\x06sourc\
+    e\x12,\n\x04fact\x18\x02\x20\x03(\x0b2\x18.kythe.proto.schema.FactR\x04f\
+    act\x12,\n\x04edge\x18\x03\x20\x03(\x0b2\x18.kythe.proto.schema.EdgeR\
+    \x04edge\x12=\n\nkythe_kind\x18\x04\x20\x01(\x0e2\x1c.kythe.proto.schema\
+    .NodeKindH\0R\tkytheKind\x12#\n\x0cgeneric_kind\x18\x05\x20\x01(\tH\0R\
+    \x0bgenericKind\x12B\n\rkythe_subkind\x18\x06\x20\x01(\x0e2\x1b.kythe.pr\
+    oto.schema.SubkindH\x01R\x0ckytheSubkind\x12)\n\x0fgeneric_subkind\x18\
+    \x07\x20\x01(\tH\x01R\x0egenericSubkindB\x06\n\x04kindB\t\n\x07subkind\"\
+    \xb4\x01\n\x04Fact\x12*\n\x06source\x18\x01\x20\x01(\x0b2\x12.kythe.prot\
+    o.VNameR\x06source\x12=\n\nkythe_name\x18\x02\x20\x01(\x0e2\x1c.kythe.pr\
+    oto.schema.FactNameH\0R\tkytheName\x12#\n\x0cgeneric_name\x18\x03\x20\
+    \x01(\tH\0R\x0bgenericName\x12\x14\n\x05value\x18\x04\x20\x01(\x0cR\x05v\
+    alueB\x06\n\x04name\"\xda\x02\n\x04Edge\x12*\n\x06source\x18\x01\x20\x01\
+    (\x0b2\x12.kythe.proto.VNameR\x06source\x12*\n\x06target\x18\x02\x20\x01\
+    (\x0b2\x12.kythe.proto.VNameR\x06target\x12=\n\nkythe_kind\x18\x03\x20\
+    \x01(\x0e2\x1c.kythe.proto.schema.EdgeKindH\0R\tkytheKind\x12#\n\x0cgene\
+    ric_kind\x18\x04\x20\x01(\tH\0R\x0bgenericKind\x12\x18\n\x07ordinal\x18\
+    \x05\x20\x01(\x05R\x07ordinal\x129\n\x0bsource_node\x18\x06\x20\x01(\x0b\
+    2\x18.kythe.proto.schema.NodeR\nsourceNode\x129\n\x0btarget_node\x18\x07\
+    \x20\x01(\x0b2\x18.kythe.proto.schema.NodeR\ntargetNodeB\x06\n\x04kind\"\
+    p\n\x05Entry\x12.\n\x04fact\x18\x01\x20\x01(\x0b2\x18.kythe.proto.schema\
+    .FactH\0R\x04fact\x12.\n\x04edge\x18\x02\x20\x01(\x0b2\x18.kythe.proto.s\
+    chema.EdgeH\0R\x04edgeB\x07\n\x05entry*\xba\x05\n\x08NodeKind\x12\x15\n\
+    \x11UNKNOWN_NODE_KIND\x10\0\x12\x18\n\x06ANCHOR\x10\x03\x1a\x0c\xca\x93\
+    \"\x08\n\x06anchor\x12\x1c\n\x08CONSTANT\x10\x04\x1a\x0e\xca\x93\"\n\n\
+    \x08constant\x12\x20\n\nDIAGNOSTIC\x10\x05\x1a\x10\xca\x93\"\x0c\n\ndiag\
+    nostic\x12\x12\n\x03DOC\x10\x06\x1a\t\xca\x93\"\x05\n\x03doc\x12\x14\n\
+    \x04FILE\x10\x07\x1a\n\xca\x93\"\x06\n\x04file\x12\x1d\n\x05GFLAG\x10\
+    \x1b\x1a\x12\xca\x93\"\x0e\n\x0cgoogle/gflag\x12\x1e\n\tINTERFACE\x10\
+    \x08\x1a\x0f\xca\x93\"\x0b\n\tinterface\x12\x1c\n\x08FUNCTION\x10\t\x1a\
+    \x0e\xca\x93\"\n\n\x08function\x12\x18\n\x06LOOKUP\x10\n\x1a\x0c\xca\x93\
+    \"\x08\n\x06lookup\x12\x16\n\x05MACRO\x10\x0b\x1a\x0b\xca\x93\"\x07\n\
+    \x05macro\x12\x14\n\x04META\x10\x0c\x1a\n\xca\x93\"\x06\n\x04meta\x12\
+    \x14\n\x04NAME\x10\r\x1a\n\xca\x93\"\x06\n\x04name\x12\x1a\n\x07PACKAGE\
+    \x10\x0e\x1a\r\xca\x93\"\t\n\x07package\x12\x1a\n\x07PROCESS\x10\x0f\x1a\
+    \r\xca\x93\"\t\n\x07process\x12\x18\n\x06RECORD\x10\x10\x1a\x0c\xca\x93\
+    \"\x08\n\x06record\x12\x12\n\x03SUM\x10\x11\x1a\t\xca\x93\"\x05\n\x03sum\
+    \x12\x18\n\x06SYMBOL\x10\x12\x1a\x0c\xca\x93\"\x08\n\x06symbol\x12\x18\n\
+    \x06TALIAS\x10\x13\x1a\x0c\xca\x93\"\x08\n\x06talias\x12\x14\n\x04TAPP\
+    \x10\x14\x1a\n\xca\x93\"\x06\n\x04tapp\x12\x14\n\x04TVAR\x10\x1a\x1a\n\
+    \xca\x93\"\x06\n\x04tvar\x12\x1c\n\x08TBUILTIN\x10\x15\x1a\x0e\xca\x93\"\
+    \n\n\x08tbuiltin\x12\x1c\n\x08TNOMINAL\x10\x16\x1a\x0e\xca\x93\"\n\n\x08\
+    tnominal\x12\x18\n\x06TSIGMA\x10\x17\x1a\x0c\xca\x93\"\x08\n\x06tsigma\
+    \x12\x1c\n\x08VARIABLE\x10\x18\x1a\x0e\xca\x93\"\n\n\x08variable\x12\x12\
+    \n\x03VCS\x10\x19\x1a\t\xca\x93\"\x05\n\x03vcs\"\x04\x08\x01\x10\x01\"\
+    \x04\x08\x02\x10\x02*\x8b\x04\n\x07Subkind\x12\x13\n\x0fUNKNOWN_SUBKIND\
+    \x10\0\x12\x1c\n\x08CATEGORY\x10\x01\x1a\x0e\xca\x93\"\n\n\x08category\
+    \x12\x16\n\x05CLASS\x10\x02\x1a\x0b\xca\x93\"\x07\n\x05class\x12\"\n\x0b\
+    CONSTRUCTOR\x10\x03\x1a\x11\xca\x93\"\r\n\x0bconstructor\x12\x20\n\nDEST\
+    RUCTOR\x10\x04\x1a\x10\xca\x93\"\x0c\n\ndestructor\x12\x14\n\x04ENUM\x10\
+    \x05\x1a\n\xca\x93\"\x06\n\x04enum\x12\x1f\n\nENUM_CLASS\x10\x06\x1a\x0f\
+    \xca\x93\"\x0b\n\tenumClass\x12\x16\n\x05FIELD\x10\x07\x1a\x0b\xca\x93\"\
+    \x07\n\x05field\x12\x1c\n\x08IMPLICIT\x10\x08\x1a\x0e\xca\x93\"\n\n\x08i\
+    mplicit\x12\x18\n\x06IMPORT\x10\t\x1a\x0c\xca\x93\"\x08\n\x06import\x12\
+    \"\n\x0bINITIALIZER\x10\n\x1a\x11\xca\x93\"\r\n\x0binitializer\x12\x16\n\
+    \x05LOCAL\x10\x0b\x1a\x0b\xca\x93\"\x07\n\x05local\x12*\n\x0fLOCAL_PARAM\
+    ETER\x10\x0c\x1a\x15\xca\x93\"\x11\n\x0flocal/parameter\x12\x18\n\x06MET\
+    HOD\x10\r\x1a\x0c\xca\x93\"\x08\n\x06method\x12\x1e\n\tNAMESPACE\x10\x0e\
+    \x1a\x0f\xca\x93\"\x0b\n\tnamespace\x12\x18\n\x06STRUCT\x10\x0f\x1a\x0c\
+    \xca\x93\"\x08\n\x06struct\x12\x14\n\x04TYPE\x10\x10\x1a\n\xca\x93\"\x06\
+    \n\x04type\x12\x16\n\x05UNION\x10\x11\x1a\x0b\xca\x93\"\x07\n\x05union*\
+    \xae\x06\n\x08FactName\x12\x15\n\x11UNKNOWN_FACT_NAME\x10\0\x12\x1b\n\
+    \x04CODE\x10\x01\x1a\x11\xca\x93\"\r\n\x0b/kythe/code\x12#\n\x08COMPLETE\
+    \x10\x02\x1a\x15\xca\x93\"\x11\n\x0f/kythe/complete\x12)\n\x0bCONTEXT_UR\
+    L\x10\x03\x1a\x18\xca\x93\"\x14\n\x12/kythe/context/url\x12!\n\x07DETAIL\
+    S\x10\x04\x1a\x14\xca\x93\"\x10\n\x0e/kythe/details\x12!\n\x07DOC_URI\
+    \x10\x05\x1a\x14\xca\x93\"\x10\n\x0e/kythe/doc/uri\x12\x1d\n\x05LABEL\
+    \x10\x06\x1a\x12\xca\x93\"\x0e\n\x0c/kythe/label\x12!\n\x07LOC_END\x10\
+    \x07\x1a\x14\xca\x93\"\x10\n\x0e/kythe/loc/end\x12%\n\tLOC_START\x10\x08\
+    \x1a\x16\xca\x93\"\x12\n\x10/kythe/loc/start\x12!\n\x07MESSAGE\x10\t\x1a\
+    \x14\xca\x93\"\x10\n\x0e/kythe/message\x12%\n\tNODE_KIND\x10\n\x1a\x16\
+    \xca\x93\"\x12\n\x10/kythe/node/kind\x12-\n\rPARAM_DEFAULT\x10\x0b\x1a\
+    \x1a\xca\x93\"\x16\n\x14/kythe/param/default\x12&\n\nRULE_CLASS\x10\x0c\
+    \x1a\x16\xca\x93\"\x12\n\x10/kythe/ruleclass\x12)\n\x0bSNIPPET_END\x10\r\
+    \x1a\x18\xca\x93\"\x14\n\x12/kythe/snippet/end\x12-\n\rSNIPPET_START\x10\
+    \x0e\x1a\x1a\xca\x93\"\x16\n\x14/kythe/snippet/start\x12!\n\x07SUBKIND\
+    \x10\x0f\x1a\x14\xca\x93\"\x10\n\x0e/kythe/subkind\x12\x1b\n\x04TEXT\x10\
+    \x10\x1a\x11\xca\x93\"\r\n\x0b/kythe/text\x12-\n\rTEXT_ENCODING\x10\x11\
+    \x1a\x1a\xca\x93\"\x16\n\x14/kythe/text/encoding\x12'\n\nVISIBILITY\x10\
+    \x12\x1a\x17\xca\x93\"\x13\n\x11/kythe/visibility\x12+\n\x0cBUILD_CONFIG\
+    \x10\x13\x1a\x19\xca\x93\"\x15\n\x13/kythe/build/config\x12/\n\x0eTAG_DE\
+    PRECATED\x10\x14\x1a\x1b\xca\x93\"\x17\n\x15/kythe/tag/deprecated*\xa7\
+    \x12\n\x08EdgeKind\x12\x15\n\x11UNKNOWN_EDGE_KIND\x10\0\x12&\n\x07ALIASE\
+    S\x10\x01\x1a\x19\xca\x93\"\x15\n\x13/kythe/edge/aliases\x120\n\x0cALIAS\
+    ES_ROOT\x10\x02\x1a\x1e\xca\x93\"\x1a\n\x18/kythe/edge/aliases/root\x12/\
+    \n\x0cANNOTATED_BY\x10\x03\x1a\x1d\xca\x93\"\x19\n\x17/kythe/edge/annota\
+    tedby\x122\n\rBOUNDED_LOWER\x10\x04\x1a\x1f\xca\x93\"\x1b\n\x19/kythe/ed\
+    ge/bounded/lower\x122\n\rBOUNDED_UPPER\x10\x05\x1a\x1f\xca\x93\"\x1b\n\
+    \x19/kythe/edge/bounded/upper\x12'\n\x08CHILD_OF\x10\x06\x1a\x19\xca\x93\
+    \"\x15\n\x13/kythe/edge/childof\x127\n\x10CHILD_OF_CONTEXT\x10\x07\x1a!\
+    \xca\x93\"\x1d\n\x1b/kythe/edge/childof/context\x12/\n\x0cCOMPLETED_BY\
+    \x101\x1a\x1d\xca\x93\"\x19\n\x17/kythe/edge/completedby\x12&\n\x07DEFIN\
+    ES\x10\n\x1a\x19\xca\x93\"\x15\n\x13/kythe/edge/defines\x126\n\x0fDEFINE\
+    S_BINDING\x10\x0b\x1a!\xca\x93\"\x1d\n\x1b/kythe/edge/defines/binding\
+    \x128\n\x10DEFINES_IMPLICIT\x102\x1a\"\xca\x93\"\x1e\n\x1c/kythe/edge/de\
+    fines/implicit\x12&\n\x07DEPENDS\x10\x0c\x1a\x19\xca\x93\"\x15\n\x13/kyt\
+    he/edge/depends\x12*\n\tDOCUMENTS\x10\r\x1a\x1b\xca\x93\"\x17\n\x15/kyth\
+    e/edge/documents\x12&\n\x07EXPORTS\x10\x0e\x1a\x19\xca\x93\"\x15\n\x13/k\
+    ythe/edge/exports\x12&\n\x07EXTENDS\x10\x0f\x1a\x19\xca\x93\"\x15\n\x13/\
+    kythe/edge/extends\x12*\n\tGENERATES\x10\x10\x1a\x1b\xca\x93\"\x17\n\x15\
+    /kythe/edge/generates\x12&\n\x07IMPUTES\x10\x11\x1a\x19\xca\x93\"\x15\n\
+    \x13/kythe/edge/imputes\x120\n\x0cINSTANTIATES\x10\x12\x1a\x1e\xca\x93\"\
+    \x1a\n\x18/kythe/edge/instantiates\x12H\n\x18INSTANTIATES_SPECULATIVE\
+    \x10\x13\x1a*\xca\x93\"&\n$/kythe/edge/instantiates/speculative\x12\"\n\
+    \x05NAMED\x10\x14\x1a\x17\xca\x93\"\x13\n\x11/kythe/edge/named\x12*\n\tO\
+    VERRIDES\x10\x15\x1a\x1b\xca\x93\"\x17\n\x15/kythe/edge/overrides\x124\n\
+    \x0eOVERRIDES_ROOT\x10\x16\x1a\x20\xca\x93\"\x1c\n\x1a/kythe/edge/overri\
+    des/root\x12@\n\x14OVERRIDES_TRANSITIVE\x10\x17\x1a&\xca\x93\"\"\n\x20/k\
+    ythe/edge/overrides/transitive\x12\"\n\x05PARAM\x10\x18\x1a\x17\xca\x93\
+    \"\x13\n\x11/kythe/edge/param\x124\n\x0ePROPERTY_READS\x10,\x1a\x20\xca\
+    \x93\"\x1c\n\x1a/kythe/edge/property/reads\x126\n\x0fPROPERTY_WRITES\x10\
+    -\x1a!\xca\x93\"\x1d\n\x1b/kythe/edge/property/writes\x12\x1e\n\x03REF\
+    \x10\x19\x1a\x15\xca\x93\"\x11\n\x0f/kythe/edge/ref\x12(\n\x08REF_CALL\
+    \x10\x1a\x1a\x1a\xca\x93\"\x16\n\x14/kythe/edge/ref/call\x12:\n\x11REF_C\
+    ALL_IMPLICIT\x10\x1b\x1a#\xca\x93\"\x1f\n\x1d/kythe/edge/ref/call/implic\
+    it\x12&\n\x07REF_DOC\x10\x1c\x1a\x19\xca\x93\"\x15\n\x13/kythe/edge/ref/\
+    doc\x12.\n\x0bREF_EXPANDS\x10\x1d\x1a\x1d\xca\x93\"\x19\n\x17/kythe/edge\
+    /ref/expands\x12D\n\x16REF_EXPANDS_TRANSITIVE\x10\x1e\x1a(\xca\x93\"$\n\
+    \"/kythe/edge/ref/expands/transitive\x12(\n\x08REF_FILE\x10\x1f\x1a\x1a\
+    \xca\x93\"\x16\n\x14/kythe/edge/ref/file\x12$\n\x06REF_ID\x10.\x1a\x18\
+    \xca\x93\"\x14\n\x12/kythe/edge/ref/id\x120\n\x0cREF_IMPLICIT\x10\x20\
+    \x1a\x1e\xca\x93\"\x1a\n\x18/kythe/edge/ref/implicit\x12.\n\x0bREF_IMPOR\
+    TS\x10!\x1a\x1d\xca\x93\"\x19\n\x17/kythe/edge/ref/imports\x120\n\x0cREF\
+    _INCLUDES\x10\"\x1a\x1e\xca\x93\"\x1a\n\x18/kythe/edge/ref/includes\x12(\
+    \n\x08REF_INIT\x10#\x1a\x1a\xca\x93\"\x16\n\x14/kythe/edge/ref/init\x12:\
+    \n\x11REF_INIT_IMPLICIT\x10$\x1a#\xca\x93\"\x1f\n\x1d/kythe/edge/ref/ini\
+    t/implicit\x12.\n\x0bREF_QUERIES\x10%\x1a\x1d\xca\x93\"\x19\n\x17/kythe/\
+    edge/ref/queries\x12,\n\nREF_WRITES\x10/\x1a\x1c\xca\x93\"\x18\n\x16/kyt\
+    he/edge/ref/writes\x12*\n\tSATISFIES\x10&\x1a\x1b\xca\x93\"\x17\n\x15/ky\
+    the/edge/satisfies\x12.\n\x0bSPECIALIZES\x10'\x1a\x1d\xca\x93\"\x19\n\
+    \x17/kythe/edge/specializes\x12F\n\x17SPECIALIZES_SPECULATIVE\x10(\x1a)\
+    \xca\x93\"%\n#/kythe/edge/specializes/speculative\x12$\n\x06TAGGED\x10)\
+    \x1a\x18\xca\x93\"\x14\n\x12/kythe/edge/tagged\x12$\n\x06TPARAM\x100\x1a\
+    \x18\xca\x93\"\x14\n\x12/kythe/edge/tparam\x12\"\n\x05TYPED\x10*\x1a\x17\
+    \xca\x93\"\x13\n\x11/kythe/edge/typed\x12*\n\tUNDEFINES\x10+\x1a\x1b\xca\
+    \x93\"\x17\n\x15/kythe/edge/undefines\"\x04\x08\x08\x10\x08\"\x04\x08\t\
+    \x10\t:]\n\x08metadata\x18\xb9\xa2\x04\x20\x01(\x0b2\x1c.kythe.proto.sch\
+    ema.Metadata\x12!.google.protobuf.EnumValueOptionsR\x08metadataBG\n\x1fc\
+    om.google.devtools.kythe.protoZ$kythe.io/kythe/proto/schema_go_protob\
+    \x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,821 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `kythe/proto/identifier.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FindRequest {
+    // message fields
+    pub identifier: ::std::string::String,
+    pub corpus: ::protobuf::RepeatedField<::std::string::String>,
+    pub languages: ::protobuf::RepeatedField<::std::string::String>,
+    pub pick_canonical_nodes: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FindRequest {
+    fn default() -> &'a FindRequest {
+        <FindRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FindRequest {
+    pub fn new() -> FindRequest {
+        ::std::default::Default::default()
+    }
+
+    // string identifier = 1;
+
+
+    pub fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    pub fn clear_identifier(&mut self) {
+        self.identifier.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_identifier(&mut self, v: ::std::string::String) {
+        self.identifier = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_identifier(&mut self) -> &mut ::std::string::String {
+        &mut self.identifier
+    }
+
+    // Take field
+    pub fn take_identifier(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.identifier, ::std::string::String::new())
+    }
+
+    // repeated string corpus = 2;
+
+
+    pub fn get_corpus(&self) -> &[::std::string::String] {
+        &self.corpus
+    }
+    pub fn clear_corpus(&mut self) {
+        self.corpus.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_corpus(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.corpus = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_corpus(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.corpus
+    }
+
+    // Take field
+    pub fn take_corpus(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.corpus, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string languages = 3;
+
+
+    pub fn get_languages(&self) -> &[::std::string::String] {
+        &self.languages
+    }
+    pub fn clear_languages(&mut self) {
+        self.languages.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_languages(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.languages = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_languages(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.languages
+    }
+
+    // Take field
+    pub fn take_languages(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.languages, ::protobuf::RepeatedField::new())
+    }
+
+    // bool pick_canonical_nodes = 4;
+
+
+    pub fn get_pick_canonical_nodes(&self) -> bool {
+        self.pick_canonical_nodes
+    }
+    pub fn clear_pick_canonical_nodes(&mut self) {
+        self.pick_canonical_nodes = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pick_canonical_nodes(&mut self, v: bool) {
+        self.pick_canonical_nodes = v;
+    }
+}
+
+impl ::protobuf::Message for FindRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.identifier)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.corpus)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.languages)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.pick_canonical_nodes = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.identifier.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.identifier);
+        }
+        for value in &self.corpus {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in &self.languages {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        if self.pick_canonical_nodes != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.identifier.is_empty() {
+            os.write_string(1, &self.identifier)?;
+        }
+        for v in &self.corpus {
+            os.write_string(2, &v)?;
+        };
+        for v in &self.languages {
+            os.write_string(3, &v)?;
+        };
+        if self.pick_canonical_nodes != false {
+            os.write_bool(4, self.pick_canonical_nodes)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FindRequest {
+        FindRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "identifier",
+                |m: &FindRequest| { &m.identifier },
+                |m: &mut FindRequest| { &mut m.identifier },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "corpus",
+                |m: &FindRequest| { &m.corpus },
+                |m: &mut FindRequest| { &mut m.corpus },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "languages",
+                |m: &FindRequest| { &m.languages },
+                |m: &mut FindRequest| { &mut m.languages },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "pick_canonical_nodes",
+                |m: &FindRequest| { &m.pick_canonical_nodes },
+                |m: &mut FindRequest| { &mut m.pick_canonical_nodes },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FindRequest>(
+                "FindRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FindRequest {
+        static instance: ::protobuf::rt::LazyV2<FindRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FindRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for FindRequest {
+    fn clear(&mut self) {
+        self.identifier.clear();
+        self.corpus.clear();
+        self.languages.clear();
+        self.pick_canonical_nodes = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FindRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FindRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FindReply {
+    // message fields
+    pub matches: ::protobuf::RepeatedField<FindReply_Match>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FindReply {
+    fn default() -> &'a FindReply {
+        <FindReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FindReply {
+    pub fn new() -> FindReply {
+        ::std::default::Default::default()
+    }
+
+    // repeated .kythe.proto.FindReply.Match matches = 1;
+
+
+    pub fn get_matches(&self) -> &[FindReply_Match] {
+        &self.matches
+    }
+    pub fn clear_matches(&mut self) {
+        self.matches.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_matches(&mut self, v: ::protobuf::RepeatedField<FindReply_Match>) {
+        self.matches = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_matches(&mut self) -> &mut ::protobuf::RepeatedField<FindReply_Match> {
+        &mut self.matches
+    }
+
+    // Take field
+    pub fn take_matches(&mut self) -> ::protobuf::RepeatedField<FindReply_Match> {
+        ::std::mem::replace(&mut self.matches, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for FindReply {
+    fn is_initialized(&self) -> bool {
+        for v in &self.matches {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.matches)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.matches {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.matches {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FindReply {
+        FindReply::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FindReply_Match>>(
+                "matches",
+                |m: &FindReply| { &m.matches },
+                |m: &mut FindReply| { &mut m.matches },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FindReply>(
+                "FindReply",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FindReply {
+        static instance: ::protobuf::rt::LazyV2<FindReply> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FindReply::new)
+    }
+}
+
+impl ::protobuf::Clear for FindReply {
+    fn clear(&mut self) {
+        self.matches.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FindReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FindReply {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FindReply_Match {
+    // message fields
+    pub ticket: ::std::string::String,
+    pub node_kind: ::std::string::String,
+    pub node_subkind: ::std::string::String,
+    pub base_name: ::std::string::String,
+    pub qualified_name: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FindReply_Match {
+    fn default() -> &'a FindReply_Match {
+        <FindReply_Match as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FindReply_Match {
+    pub fn new() -> FindReply_Match {
+        ::std::default::Default::default()
+    }
+
+    // string ticket = 1;
+
+
+    pub fn get_ticket(&self) -> &str {
+        &self.ticket
+    }
+    pub fn clear_ticket(&mut self) {
+        self.ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ticket(&mut self, v: ::std::string::String) {
+        self.ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ticket(&mut self) -> &mut ::std::string::String {
+        &mut self.ticket
+    }
+
+    // Take field
+    pub fn take_ticket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.ticket, ::std::string::String::new())
+    }
+
+    // string node_kind = 2;
+
+
+    pub fn get_node_kind(&self) -> &str {
+        &self.node_kind
+    }
+    pub fn clear_node_kind(&mut self) {
+        self.node_kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_node_kind(&mut self, v: ::std::string::String) {
+        self.node_kind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_node_kind(&mut self) -> &mut ::std::string::String {
+        &mut self.node_kind
+    }
+
+    // Take field
+    pub fn take_node_kind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.node_kind, ::std::string::String::new())
+    }
+
+    // string node_subkind = 3;
+
+
+    pub fn get_node_subkind(&self) -> &str {
+        &self.node_subkind
+    }
+    pub fn clear_node_subkind(&mut self) {
+        self.node_subkind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_node_subkind(&mut self, v: ::std::string::String) {
+        self.node_subkind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_node_subkind(&mut self) -> &mut ::std::string::String {
+        &mut self.node_subkind
+    }
+
+    // Take field
+    pub fn take_node_subkind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.node_subkind, ::std::string::String::new())
+    }
+
+    // string base_name = 4;
+
+
+    pub fn get_base_name(&self) -> &str {
+        &self.base_name
+    }
+    pub fn clear_base_name(&mut self) {
+        self.base_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_base_name(&mut self, v: ::std::string::String) {
+        self.base_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_base_name(&mut self) -> &mut ::std::string::String {
+        &mut self.base_name
+    }
+
+    // Take field
+    pub fn take_base_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.base_name, ::std::string::String::new())
+    }
+
+    // string qualified_name = 5;
+
+
+    pub fn get_qualified_name(&self) -> &str {
+        &self.qualified_name
+    }
+    pub fn clear_qualified_name(&mut self) {
+        self.qualified_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_qualified_name(&mut self, v: ::std::string::String) {
+        self.qualified_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_qualified_name(&mut self) -> &mut ::std::string::String {
+        &mut self.qualified_name
+    }
+
+    // Take field
+    pub fn take_qualified_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.qualified_name, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for FindReply_Match {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.ticket)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.node_kind)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.node_subkind)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.base_name)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.qualified_name)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.ticket.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.ticket);
+        }
+        if !self.node_kind.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.node_kind);
+        }
+        if !self.node_subkind.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.node_subkind);
+        }
+        if !self.base_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.base_name);
+        }
+        if !self.qualified_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.qualified_name);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.ticket.is_empty() {
+            os.write_string(1, &self.ticket)?;
+        }
+        if !self.node_kind.is_empty() {
+            os.write_string(2, &self.node_kind)?;
+        }
+        if !self.node_subkind.is_empty() {
+            os.write_string(3, &self.node_subkind)?;
+        }
+        if !self.base_name.is_empty() {
+            os.write_string(4, &self.base_name)?;
+        }
+        if !self.qualified_name.is_empty() {
+            os.write_string(5, &self.qualified_name)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FindReply_Match {
+        FindReply_Match::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "ticket",
+                |m: &FindReply_Match| { &m.ticket },
+                |m: &mut FindReply_Match| { &mut m.ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "node_kind",
+                |m: &FindReply_Match| { &m.node_kind },
+                |m: &mut FindReply_Match| { &mut m.node_kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "node_subkind",
+                |m: &FindReply_Match| { &m.node_subkind },
+                |m: &mut FindReply_Match| { &mut m.node_subkind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "base_name",
+                |m: &FindReply_Match| { &m.base_name },
+                |m: &mut FindReply_Match| { &mut m.base_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "qualified_name",
+                |m: &FindReply_Match| { &m.qualified_name },
+                |m: &mut FindReply_Match| { &mut m.qualified_name },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FindReply_Match>(
+                "FindReply.Match",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FindReply_Match {
+        static instance: ::protobuf::rt::LazyV2<FindReply_Match> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FindReply_Match::new)
+    }
+}
+
+impl ::protobuf::Clear for FindReply_Match {
+    fn clear(&mut self) {
+        self.ticket.clear();
+        self.node_kind.clear();
+        self.node_subkind.clear();
+        self.base_name.clear();
+        self.qualified_name.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FindReply_Match {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FindReply_Match {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x1ckythe/proto/identifier.proto\x12\x0bkythe.proto\"\x95\x01\n\x0bFin\
+    dRequest\x12\x1e\n\nidentifier\x18\x01\x20\x01(\tR\nidentifier\x12\x16\n\
+    \x06corpus\x18\x02\x20\x03(\tR\x06corpus\x12\x1c\n\tlanguages\x18\x03\
+    \x20\x03(\tR\tlanguages\x120\n\x14pick_canonical_nodes\x18\x04\x20\x01(\
+    \x08R\x12pickCanonicalNodes\"\xe9\x01\n\tFindReply\x126\n\x07matches\x18\
+    \x01\x20\x03(\x0b2\x1c.kythe.proto.FindReply.MatchR\x07matches\x1a\xa3\
+    \x01\n\x05Match\x12\x16\n\x06ticket\x18\x01\x20\x01(\tR\x06ticket\x12\
+    \x1b\n\tnode_kind\x18\x02\x20\x01(\tR\x08nodeKind\x12!\n\x0cnode_subkind\
+    \x18\x03\x20\x01(\tR\x0bnodeSubkind\x12\x1b\n\tbase_name\x18\x04\x20\x01\
+    (\tR\x08baseName\x12%\n\x0equalified_name\x18\x05\x20\x01(\tR\rqualified\
+    Name2M\n\x11IdentifierService\x128\n\x04Find\x12\x18.kythe.proto.FindReq\
+    uest\x1a\x16.kythe.proto.FindReplyBK\n\x1fcom.google.devtools.kythe.prot\
+    oZ(kythe.io/kythe/proto/identifier_go_protob\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,3 @@
+extern crate protobuf;
+pub mod identifier;
+pub use identifier::*;
\ No newline at end of file
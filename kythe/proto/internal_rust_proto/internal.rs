@@ -0,0 +1,2839 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `kythe/proto/internal.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Source {
+    // message fields
+    pub ticket: ::std::string::String,
+    pub facts: ::std::collections::HashMap<::std::string::String, ::std::vec::Vec<u8>>,
+    pub edge_groups: ::std::collections::HashMap<::std::string::String, Source_EdgeGroup>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Source {
+    fn default() -> &'a Source {
+        <Source as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Source {
+    pub fn new() -> Source {
+        ::std::default::Default::default()
+    }
+
+    // string ticket = 1;
+
+
+    pub fn get_ticket(&self) -> &str {
+        &self.ticket
+    }
+    pub fn clear_ticket(&mut self) {
+        self.ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ticket(&mut self, v: ::std::string::String) {
+        self.ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ticket(&mut self) -> &mut ::std::string::String {
+        &mut self.ticket
+    }
+
+    // Take field
+    pub fn take_ticket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.ticket, ::std::string::String::new())
+    }
+
+    // repeated .kythe.proto.internal.Source.FactsEntry facts = 2;
+
+
+    pub fn get_facts(&self) -> &::std::collections::HashMap<::std::string::String, ::std::vec::Vec<u8>> {
+        &self.facts
+    }
+    pub fn clear_facts(&mut self) {
+        self.facts.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_facts(&mut self, v: ::std::collections::HashMap<::std::string::String, ::std::vec::Vec<u8>>) {
+        self.facts = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_facts(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, ::std::vec::Vec<u8>> {
+        &mut self.facts
+    }
+
+    // Take field
+    pub fn take_facts(&mut self) -> ::std::collections::HashMap<::std::string::String, ::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.facts, ::std::collections::HashMap::new())
+    }
+
+    // repeated .kythe.proto.internal.Source.EdgeGroupsEntry edge_groups = 3;
+
+
+    pub fn get_edge_groups(&self) -> &::std::collections::HashMap<::std::string::String, Source_EdgeGroup> {
+        &self.edge_groups
+    }
+    pub fn clear_edge_groups(&mut self) {
+        self.edge_groups.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_edge_groups(&mut self, v: ::std::collections::HashMap<::std::string::String, Source_EdgeGroup>) {
+        self.edge_groups = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_edge_groups(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, Source_EdgeGroup> {
+        &mut self.edge_groups
+    }
+
+    // Take field
+    pub fn take_edge_groups(&mut self) -> ::std::collections::HashMap<::std::string::String, Source_EdgeGroup> {
+        ::std::mem::replace(&mut self.edge_groups, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for Source {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.ticket)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeBytes>(wire_type, is, &mut self.facts)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Source_EdgeGroup>>(wire_type, is, &mut self.edge_groups)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.ticket.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.ticket);
+        }
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeBytes>(2, &self.facts);
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Source_EdgeGroup>>(3, &self.edge_groups);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.ticket.is_empty() {
+            os.write_string(1, &self.ticket)?;
+        }
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeBytes>(2, &self.facts, os)?;
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Source_EdgeGroup>>(3, &self.edge_groups, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Source {
+        Source::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "ticket",
+                |m: &Source| { &m.ticket },
+                |m: &mut Source| { &mut m.ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeBytes>(
+                "facts",
+                |m: &Source| { &m.facts },
+                |m: &mut Source| { &mut m.facts },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Source_EdgeGroup>>(
+                "edge_groups",
+                |m: &Source| { &m.edge_groups },
+                |m: &mut Source| { &mut m.edge_groups },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Source>(
+                "Source",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Source {
+        static instance: ::protobuf::rt::LazyV2<Source> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Source::new)
+    }
+}
+
+impl ::protobuf::Clear for Source {
+    fn clear(&mut self) {
+        self.ticket.clear();
+        self.facts.clear();
+        self.edge_groups.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Source {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Source {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Source_Edge {
+    // message fields
+    pub ticket: ::std::string::String,
+    pub ordinal: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Source_Edge {
+    fn default() -> &'a Source_Edge {
+        <Source_Edge as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Source_Edge {
+    pub fn new() -> Source_Edge {
+        ::std::default::Default::default()
+    }
+
+    // string ticket = 1;
+
+
+    pub fn get_ticket(&self) -> &str {
+        &self.ticket
+    }
+    pub fn clear_ticket(&mut self) {
+        self.ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ticket(&mut self, v: ::std::string::String) {
+        self.ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ticket(&mut self) -> &mut ::std::string::String {
+        &mut self.ticket
+    }
+
+    // Take field
+    pub fn take_ticket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.ticket, ::std::string::String::new())
+    }
+
+    // int32 ordinal = 2;
+
+
+    pub fn get_ordinal(&self) -> i32 {
+        self.ordinal
+    }
+    pub fn clear_ordinal(&mut self) {
+        self.ordinal = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ordinal(&mut self, v: i32) {
+        self.ordinal = v;
+    }
+}
+
+impl ::protobuf::Message for Source_Edge {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.ticket)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.ordinal = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.ticket.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.ticket);
+        }
+        if self.ordinal != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.ordinal, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.ticket.is_empty() {
+            os.write_string(1, &self.ticket)?;
+        }
+        if self.ordinal != 0 {
+            os.write_int32(2, self.ordinal)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Source_Edge {
+        Source_Edge::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "ticket",
+                |m: &Source_Edge| { &m.ticket },
+                |m: &mut Source_Edge| { &mut m.ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "ordinal",
+                |m: &Source_Edge| { &m.ordinal },
+                |m: &mut Source_Edge| { &mut m.ordinal },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Source_Edge>(
+                "Source.Edge",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Source_Edge {
+        static instance: ::protobuf::rt::LazyV2<Source_Edge> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Source_Edge::new)
+    }
+}
+
+impl ::protobuf::Clear for Source_Edge {
+    fn clear(&mut self) {
+        self.ticket.clear();
+        self.ordinal = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Source_Edge {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Source_Edge {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Source_EdgeGroup {
+    // message fields
+    pub edges: ::protobuf::RepeatedField<Source_Edge>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Source_EdgeGroup {
+    fn default() -> &'a Source_EdgeGroup {
+        <Source_EdgeGroup as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Source_EdgeGroup {
+    pub fn new() -> Source_EdgeGroup {
+        ::std::default::Default::default()
+    }
+
+    // repeated .kythe.proto.internal.Source.Edge edges = 1;
+
+
+    pub fn get_edges(&self) -> &[Source_Edge] {
+        &self.edges
+    }
+    pub fn clear_edges(&mut self) {
+        self.edges.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_edges(&mut self, v: ::protobuf::RepeatedField<Source_Edge>) {
+        self.edges = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_edges(&mut self) -> &mut ::protobuf::RepeatedField<Source_Edge> {
+        &mut self.edges
+    }
+
+    // Take field
+    pub fn take_edges(&mut self) -> ::protobuf::RepeatedField<Source_Edge> {
+        ::std::mem::replace(&mut self.edges, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Source_EdgeGroup {
+    fn is_initialized(&self) -> bool {
+        for v in &self.edges {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.edges)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.edges {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.edges {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Source_EdgeGroup {
+        Source_EdgeGroup::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Source_Edge>>(
+                "edges",
+                |m: &Source_EdgeGroup| { &m.edges },
+                |m: &mut Source_EdgeGroup| { &mut m.edges },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Source_EdgeGroup>(
+                "Source.EdgeGroup",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Source_EdgeGroup {
+        static instance: ::protobuf::rt::LazyV2<Source_EdgeGroup> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Source_EdgeGroup::new)
+    }
+}
+
+impl ::protobuf::Clear for Source_EdgeGroup {
+    fn clear(&mut self) {
+        self.edges.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Source_EdgeGroup {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Source_EdgeGroup {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PageToken {
+    // message fields
+    pub index: i32,
+    pub secondary_token: ::protobuf::RepeatedField<::std::string::String>,
+    pub sub_tokens: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    pub indices: ::std::collections::HashMap<::std::string::String, i32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PageToken {
+    fn default() -> &'a PageToken {
+        <PageToken as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PageToken {
+    pub fn new() -> PageToken {
+        ::std::default::Default::default()
+    }
+
+    // int32 index = 1;
+
+
+    pub fn get_index(&self) -> i32 {
+        self.index
+    }
+    pub fn clear_index(&mut self) {
+        self.index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: i32) {
+        self.index = v;
+    }
+
+    // repeated string secondary_token = 2;
+
+
+    pub fn get_secondary_token(&self) -> &[::std::string::String] {
+        &self.secondary_token
+    }
+    pub fn clear_secondary_token(&mut self) {
+        self.secondary_token.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_secondary_token(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.secondary_token = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_secondary_token(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.secondary_token
+    }
+
+    // Take field
+    pub fn take_secondary_token(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.secondary_token, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .kythe.proto.internal.PageToken.SubTokensEntry sub_tokens = 3;
+
+
+    pub fn get_sub_tokens(&self) -> &::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &self.sub_tokens
+    }
+    pub fn clear_sub_tokens(&mut self) {
+        self.sub_tokens.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sub_tokens(&mut self, v: ::std::collections::HashMap<::std::string::String, ::std::string::String>) {
+        self.sub_tokens = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_sub_tokens(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        &mut self.sub_tokens
+    }
+
+    // Take field
+    pub fn take_sub_tokens(&mut self) -> ::std::collections::HashMap<::std::string::String, ::std::string::String> {
+        ::std::mem::replace(&mut self.sub_tokens, ::std::collections::HashMap::new())
+    }
+
+    // repeated .kythe.proto.internal.PageToken.IndicesEntry indices = 4;
+
+
+    pub fn get_indices(&self) -> &::std::collections::HashMap<::std::string::String, i32> {
+        &self.indices
+    }
+    pub fn clear_indices(&mut self) {
+        self.indices.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_indices(&mut self, v: ::std::collections::HashMap<::std::string::String, i32>) {
+        self.indices = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_indices(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, i32> {
+        &mut self.indices
+    }
+
+    // Take field
+    pub fn take_indices(&mut self) -> ::std::collections::HashMap<::std::string::String, i32> {
+        ::std::mem::replace(&mut self.indices, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for PageToken {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.index = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.secondary_token)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(wire_type, is, &mut self.sub_tokens)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeInt32>(wire_type, is, &mut self.indices)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.index != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.secondary_token {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(3, &self.sub_tokens);
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeInt32>(4, &self.indices);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.index != 0 {
+            os.write_int32(1, self.index)?;
+        }
+        for v in &self.secondary_token {
+            os.write_string(2, &v)?;
+        };
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(3, &self.sub_tokens, os)?;
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeInt32>(4, &self.indices, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PageToken {
+        PageToken::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "index",
+                |m: &PageToken| { &m.index },
+                |m: &mut PageToken| { &mut m.index },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "secondary_token",
+                |m: &PageToken| { &m.secondary_token },
+                |m: &mut PageToken| { &mut m.secondary_token },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeString>(
+                "sub_tokens",
+                |m: &PageToken| { &m.sub_tokens },
+                |m: &mut PageToken| { &mut m.sub_tokens },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeInt32>(
+                "indices",
+                |m: &PageToken| { &m.indices },
+                |m: &mut PageToken| { &mut m.indices },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PageToken>(
+                "PageToken",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PageToken {
+        static instance: ::protobuf::rt::LazyV2<PageToken> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PageToken::new)
+    }
+}
+
+impl ::protobuf::Clear for PageToken {
+    fn clear(&mut self) {
+        self.index = 0;
+        self.secondary_token.clear();
+        self.sub_tokens.clear();
+        self.indices.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PageToken {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PageToken {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CrossReference {
+    // message fields
+    pub source_decoration: ::protobuf::SingularPtrField<CrossReference_Decoration>,
+    pub referent: ::protobuf::SingularPtrField<super::serving::Node>,
+    pub target_decoration: ::protobuf::SingularPtrField<CrossReference_Decoration>,
+    pub source_anchor: ::protobuf::SingularPtrField<super::serving::ExpandedAnchor>,
+    pub target_anchor: ::protobuf::SingularPtrField<super::serving::ExpandedAnchor>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CrossReference {
+    fn default() -> &'a CrossReference {
+        <CrossReference as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CrossReference {
+    pub fn new() -> CrossReference {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.internal.CrossReference.Decoration source_decoration = 1;
+
+
+    pub fn get_source_decoration(&self) -> &CrossReference_Decoration {
+        self.source_decoration.as_ref().unwrap_or_else(|| <CrossReference_Decoration as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_source_decoration(&mut self) {
+        self.source_decoration.clear();
+    }
+
+    pub fn has_source_decoration(&self) -> bool {
+        self.source_decoration.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source_decoration(&mut self, v: CrossReference_Decoration) {
+        self.source_decoration = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source_decoration(&mut self) -> &mut CrossReference_Decoration {
+        if self.source_decoration.is_none() {
+            self.source_decoration.set_default();
+        }
+        self.source_decoration.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_source_decoration(&mut self) -> CrossReference_Decoration {
+        self.source_decoration.take().unwrap_or_else(|| CrossReference_Decoration::new())
+    }
+
+    // .kythe.proto.serving.Node referent = 2;
+
+
+    pub fn get_referent(&self) -> &super::serving::Node {
+        self.referent.as_ref().unwrap_or_else(|| <super::serving::Node as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_referent(&mut self) {
+        self.referent.clear();
+    }
+
+    pub fn has_referent(&self) -> bool {
+        self.referent.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_referent(&mut self, v: super::serving::Node) {
+        self.referent = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_referent(&mut self) -> &mut super::serving::Node {
+        if self.referent.is_none() {
+            self.referent.set_default();
+        }
+        self.referent.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_referent(&mut self) -> super::serving::Node {
+        self.referent.take().unwrap_or_else(|| super::serving::Node::new())
+    }
+
+    // .kythe.proto.internal.CrossReference.Decoration target_decoration = 3;
+
+
+    pub fn get_target_decoration(&self) -> &CrossReference_Decoration {
+        self.target_decoration.as_ref().unwrap_or_else(|| <CrossReference_Decoration as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_target_decoration(&mut self) {
+        self.target_decoration.clear();
+    }
+
+    pub fn has_target_decoration(&self) -> bool {
+        self.target_decoration.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_decoration(&mut self, v: CrossReference_Decoration) {
+        self.target_decoration = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target_decoration(&mut self) -> &mut CrossReference_Decoration {
+        if self.target_decoration.is_none() {
+            self.target_decoration.set_default();
+        }
+        self.target_decoration.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target_decoration(&mut self) -> CrossReference_Decoration {
+        self.target_decoration.take().unwrap_or_else(|| CrossReference_Decoration::new())
+    }
+
+    // .kythe.proto.serving.ExpandedAnchor source_anchor = 4;
+
+
+    pub fn get_source_anchor(&self) -> &super::serving::ExpandedAnchor {
+        self.source_anchor.as_ref().unwrap_or_else(|| <super::serving::ExpandedAnchor as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_source_anchor(&mut self) {
+        self.source_anchor.clear();
+    }
+
+    pub fn has_source_anchor(&self) -> bool {
+        self.source_anchor.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source_anchor(&mut self, v: super::serving::ExpandedAnchor) {
+        self.source_anchor = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source_anchor(&mut self) -> &mut super::serving::ExpandedAnchor {
+        if self.source_anchor.is_none() {
+            self.source_anchor.set_default();
+        }
+        self.source_anchor.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_source_anchor(&mut self) -> super::serving::ExpandedAnchor {
+        self.source_anchor.take().unwrap_or_else(|| super::serving::ExpandedAnchor::new())
+    }
+
+    // .kythe.proto.serving.ExpandedAnchor target_anchor = 5;
+
+
+    pub fn get_target_anchor(&self) -> &super::serving::ExpandedAnchor {
+        self.target_anchor.as_ref().unwrap_or_else(|| <super::serving::ExpandedAnchor as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_target_anchor(&mut self) {
+        self.target_anchor.clear();
+    }
+
+    pub fn has_target_anchor(&self) -> bool {
+        self.target_anchor.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_anchor(&mut self, v: super::serving::ExpandedAnchor) {
+        self.target_anchor = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target_anchor(&mut self) -> &mut super::serving::ExpandedAnchor {
+        if self.target_anchor.is_none() {
+            self.target_anchor.set_default();
+        }
+        self.target_anchor.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target_anchor(&mut self) -> super::serving::ExpandedAnchor {
+        self.target_anchor.take().unwrap_or_else(|| super::serving::ExpandedAnchor::new())
+    }
+}
+
+impl ::protobuf::Message for CrossReference {
+    fn is_initialized(&self) -> bool {
+        for v in &self.source_decoration {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.referent {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.target_decoration {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.source_anchor {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.target_anchor {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.source_decoration)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.referent)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.target_decoration)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.source_anchor)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.target_anchor)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.source_decoration.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.referent.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.target_decoration.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.source_anchor.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.target_anchor.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.source_decoration.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.referent.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.target_decoration.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.source_anchor.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.target_anchor.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CrossReference {
+        CrossReference::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CrossReference_Decoration>>(
+                "source_decoration",
+                |m: &CrossReference| { &m.source_decoration },
+                |m: &mut CrossReference| { &mut m.source_decoration },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::serving::Node>>(
+                "referent",
+                |m: &CrossReference| { &m.referent },
+                |m: &mut CrossReference| { &mut m.referent },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CrossReference_Decoration>>(
+                "target_decoration",
+                |m: &CrossReference| { &m.target_decoration },
+                |m: &mut CrossReference| { &mut m.target_decoration },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::serving::ExpandedAnchor>>(
+                "source_anchor",
+                |m: &CrossReference| { &m.source_anchor },
+                |m: &mut CrossReference| { &mut m.source_anchor },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::serving::ExpandedAnchor>>(
+                "target_anchor",
+                |m: &CrossReference| { &m.target_anchor },
+                |m: &mut CrossReference| { &mut m.target_anchor },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CrossReference>(
+                "CrossReference",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CrossReference {
+        static instance: ::protobuf::rt::LazyV2<CrossReference> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CrossReference::new)
+    }
+}
+
+impl ::protobuf::Clear for CrossReference {
+    fn clear(&mut self) {
+        self.source_decoration.clear();
+        self.referent.clear();
+        self.target_decoration.clear();
+        self.source_anchor.clear();
+        self.target_anchor.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CrossReference {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CrossReference {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CrossReference_Decoration {
+    // message fields
+    pub file: ::protobuf::SingularPtrField<super::serving::File>,
+    pub anchor: ::protobuf::SingularPtrField<super::serving::RawAnchor>,
+    pub kind: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CrossReference_Decoration {
+    fn default() -> &'a CrossReference_Decoration {
+        <CrossReference_Decoration as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CrossReference_Decoration {
+    pub fn new() -> CrossReference_Decoration {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.serving.File file = 1;
+
+
+    pub fn get_file(&self) -> &super::serving::File {
+        self.file.as_ref().unwrap_or_else(|| <super::serving::File as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_file(&mut self) {
+        self.file.clear();
+    }
+
+    pub fn has_file(&self) -> bool {
+        self.file.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file(&mut self, v: super::serving::File) {
+        self.file = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_file(&mut self) -> &mut super::serving::File {
+        if self.file.is_none() {
+            self.file.set_default();
+        }
+        self.file.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_file(&mut self) -> super::serving::File {
+        self.file.take().unwrap_or_else(|| super::serving::File::new())
+    }
+
+    // .kythe.proto.serving.RawAnchor anchor = 2;
+
+
+    pub fn get_anchor(&self) -> &super::serving::RawAnchor {
+        self.anchor.as_ref().unwrap_or_else(|| <super::serving::RawAnchor as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_anchor(&mut self) {
+        self.anchor.clear();
+    }
+
+    pub fn has_anchor(&self) -> bool {
+        self.anchor.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_anchor(&mut self, v: super::serving::RawAnchor) {
+        self.anchor = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_anchor(&mut self) -> &mut super::serving::RawAnchor {
+        if self.anchor.is_none() {
+            self.anchor.set_default();
+        }
+        self.anchor.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_anchor(&mut self) -> super::serving::RawAnchor {
+        self.anchor.take().unwrap_or_else(|| super::serving::RawAnchor::new())
+    }
+
+    // string kind = 3;
+
+
+    pub fn get_kind(&self) -> &str {
+        &self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: ::std::string::String) {
+        self.kind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_kind(&mut self) -> &mut ::std::string::String {
+        &mut self.kind
+    }
+
+    // Take field
+    pub fn take_kind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.kind, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for CrossReference_Decoration {
+    fn is_initialized(&self) -> bool {
+        for v in &self.file {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.anchor {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.file)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.anchor)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.kind)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.file.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.anchor.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.kind.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.kind);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.file.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.anchor.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.kind.is_empty() {
+            os.write_string(3, &self.kind)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CrossReference_Decoration {
+        CrossReference_Decoration::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::serving::File>>(
+                "file",
+                |m: &CrossReference_Decoration| { &m.file },
+                |m: &mut CrossReference_Decoration| { &mut m.file },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::serving::RawAnchor>>(
+                "anchor",
+                |m: &CrossReference_Decoration| { &m.anchor },
+                |m: &mut CrossReference_Decoration| { &mut m.anchor },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "kind",
+                |m: &CrossReference_Decoration| { &m.kind },
+                |m: &mut CrossReference_Decoration| { &mut m.kind },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CrossReference_Decoration>(
+                "CrossReference.Decoration",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CrossReference_Decoration {
+        static instance: ::protobuf::rt::LazyV2<CrossReference_Decoration> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CrossReference_Decoration::new)
+    }
+}
+
+impl ::protobuf::Clear for CrossReference_Decoration {
+    fn clear(&mut self) {
+        self.file.clear();
+        self.anchor.clear();
+        self.kind.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CrossReference_Decoration {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CrossReference_Decoration {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SortedKeyValue {
+    // message fields
+    pub key: ::std::string::String,
+    pub sort_key: ::std::string::String,
+    pub value: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SortedKeyValue {
+    fn default() -> &'a SortedKeyValue {
+        <SortedKeyValue as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SortedKeyValue {
+    pub fn new() -> SortedKeyValue {
+        ::std::default::Default::default()
+    }
+
+    // string key = 1;
+
+
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key, ::std::string::String::new())
+    }
+
+    // string sort_key = 2;
+
+
+    pub fn get_sort_key(&self) -> &str {
+        &self.sort_key
+    }
+    pub fn clear_sort_key(&mut self) {
+        self.sort_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sort_key(&mut self, v: ::std::string::String) {
+        self.sort_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_sort_key(&mut self) -> &mut ::std::string::String {
+        &mut self.sort_key
+    }
+
+    // Take field
+    pub fn take_sort_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.sort_key, ::std::string::String::new())
+    }
+
+    // bytes value = 3;
+
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for SortedKeyValue {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.sort_key)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.key);
+        }
+        if !self.sort_key.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.sort_key);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_string(1, &self.key)?;
+        }
+        if !self.sort_key.is_empty() {
+            os.write_string(2, &self.sort_key)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(3, &self.value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SortedKeyValue {
+        SortedKeyValue::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "key",
+                |m: &SortedKeyValue| { &m.key },
+                |m: &mut SortedKeyValue| { &mut m.key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "sort_key",
+                |m: &SortedKeyValue| { &m.sort_key },
+                |m: &mut SortedKeyValue| { &mut m.sort_key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "value",
+                |m: &SortedKeyValue| { &m.value },
+                |m: &mut SortedKeyValue| { &mut m.value },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SortedKeyValue>(
+                "SortedKeyValue",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SortedKeyValue {
+        static instance: ::protobuf::rt::LazyV2<SortedKeyValue> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SortedKeyValue::new)
+    }
+}
+
+impl ::protobuf::Clear for SortedKeyValue {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.sort_key.clear();
+        self.value.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SortedKeyValue {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SortedKeyValue {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Path {
+    // message fields
+    pub pivot: ::protobuf::SingularPtrField<Path_Node>,
+    pub edges: ::protobuf::RepeatedField<Path_Edge>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Path {
+    fn default() -> &'a Path {
+        <Path as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Path {
+    pub fn new() -> Path {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.internal.Path.Node pivot = 1;
+
+
+    pub fn get_pivot(&self) -> &Path_Node {
+        self.pivot.as_ref().unwrap_or_else(|| <Path_Node as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_pivot(&mut self) {
+        self.pivot.clear();
+    }
+
+    pub fn has_pivot(&self) -> bool {
+        self.pivot.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pivot(&mut self, v: Path_Node) {
+        self.pivot = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_pivot(&mut self) -> &mut Path_Node {
+        if self.pivot.is_none() {
+            self.pivot.set_default();
+        }
+        self.pivot.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_pivot(&mut self) -> Path_Node {
+        self.pivot.take().unwrap_or_else(|| Path_Node::new())
+    }
+
+    // repeated .kythe.proto.internal.Path.Edge edges = 2;
+
+
+    pub fn get_edges(&self) -> &[Path_Edge] {
+        &self.edges
+    }
+    pub fn clear_edges(&mut self) {
+        self.edges.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_edges(&mut self, v: ::protobuf::RepeatedField<Path_Edge>) {
+        self.edges = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_edges(&mut self) -> &mut ::protobuf::RepeatedField<Path_Edge> {
+        &mut self.edges
+    }
+
+    // Take field
+    pub fn take_edges(&mut self) -> ::protobuf::RepeatedField<Path_Edge> {
+        ::std::mem::replace(&mut self.edges, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Path {
+    fn is_initialized(&self) -> bool {
+        for v in &self.pivot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.edges {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.pivot)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.edges)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.pivot.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.edges {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.pivot.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.edges {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Path {
+        Path::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Path_Node>>(
+                "pivot",
+                |m: &Path| { &m.pivot },
+                |m: &mut Path| { &mut m.pivot },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Path_Edge>>(
+                "edges",
+                |m: &Path| { &m.edges },
+                |m: &mut Path| { &mut m.edges },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Path>(
+                "Path",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Path {
+        static instance: ::protobuf::rt::LazyV2<Path> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Path::new)
+    }
+}
+
+impl ::protobuf::Clear for Path {
+    fn clear(&mut self) {
+        self.pivot.clear();
+        self.edges.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Path {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Path {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Path_Node {
+    // message fields
+    pub ticket: ::std::string::String,
+    pub node_kind: ::std::string::String,
+    pub original: ::protobuf::SingularPtrField<super::serving::Node>,
+    // message oneof groups
+    pub specialization: ::std::option::Option<Path_Node_oneof_specialization>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Path_Node {
+    fn default() -> &'a Path_Node {
+        <Path_Node as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum Path_Node_oneof_specialization {
+    raw_anchor(super::serving::RawAnchor),
+    expanded_anchor(super::serving::ExpandedAnchor),
+    file(super::serving::File),
+}
+
+impl Path_Node {
+    pub fn new() -> Path_Node {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.serving.RawAnchor raw_anchor = 10;
+
+
+    pub fn get_raw_anchor(&self) -> &super::serving::RawAnchor {
+        match self.specialization {
+            ::std::option::Option::Some(Path_Node_oneof_specialization::raw_anchor(ref v)) => v,
+            _ => <super::serving::RawAnchor as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_raw_anchor(&mut self) {
+        self.specialization = ::std::option::Option::None;
+    }
+
+    pub fn has_raw_anchor(&self) -> bool {
+        match self.specialization {
+            ::std::option::Option::Some(Path_Node_oneof_specialization::raw_anchor(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_raw_anchor(&mut self, v: super::serving::RawAnchor) {
+        self.specialization = ::std::option::Option::Some(Path_Node_oneof_specialization::raw_anchor(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_raw_anchor(&mut self) -> &mut super::serving::RawAnchor {
+        if let ::std::option::Option::Some(Path_Node_oneof_specialization::raw_anchor(_)) = self.specialization {
+        } else {
+            self.specialization = ::std::option::Option::Some(Path_Node_oneof_specialization::raw_anchor(super::serving::RawAnchor::new()));
+        }
+        match self.specialization {
+            ::std::option::Option::Some(Path_Node_oneof_specialization::raw_anchor(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_raw_anchor(&mut self) -> super::serving::RawAnchor {
+        if self.has_raw_anchor() {
+            match self.specialization.take() {
+                ::std::option::Option::Some(Path_Node_oneof_specialization::raw_anchor(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            super::serving::RawAnchor::new()
+        }
+    }
+
+    // .kythe.proto.serving.ExpandedAnchor expanded_anchor = 11;
+
+
+    pub fn get_expanded_anchor(&self) -> &super::serving::ExpandedAnchor {
+        match self.specialization {
+            ::std::option::Option::Some(Path_Node_oneof_specialization::expanded_anchor(ref v)) => v,
+            _ => <super::serving::ExpandedAnchor as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_expanded_anchor(&mut self) {
+        self.specialization = ::std::option::Option::None;
+    }
+
+    pub fn has_expanded_anchor(&self) -> bool {
+        match self.specialization {
+            ::std::option::Option::Some(Path_Node_oneof_specialization::expanded_anchor(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_expanded_anchor(&mut self, v: super::serving::ExpandedAnchor) {
+        self.specialization = ::std::option::Option::Some(Path_Node_oneof_specialization::expanded_anchor(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_expanded_anchor(&mut self) -> &mut super::serving::ExpandedAnchor {
+        if let ::std::option::Option::Some(Path_Node_oneof_specialization::expanded_anchor(_)) = self.specialization {
+        } else {
+            self.specialization = ::std::option::Option::Some(Path_Node_oneof_specialization::expanded_anchor(super::serving::ExpandedAnchor::new()));
+        }
+        match self.specialization {
+            ::std::option::Option::Some(Path_Node_oneof_specialization::expanded_anchor(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_expanded_anchor(&mut self) -> super::serving::ExpandedAnchor {
+        if self.has_expanded_anchor() {
+            match self.specialization.take() {
+                ::std::option::Option::Some(Path_Node_oneof_specialization::expanded_anchor(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            super::serving::ExpandedAnchor::new()
+        }
+    }
+
+    // .kythe.proto.serving.File file = 12;
+
+
+    pub fn get_file(&self) -> &super::serving::File {
+        match self.specialization {
+            ::std::option::Option::Some(Path_Node_oneof_specialization::file(ref v)) => v,
+            _ => <super::serving::File as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_file(&mut self) {
+        self.specialization = ::std::option::Option::None;
+    }
+
+    pub fn has_file(&self) -> bool {
+        match self.specialization {
+            ::std::option::Option::Some(Path_Node_oneof_specialization::file(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file(&mut self, v: super::serving::File) {
+        self.specialization = ::std::option::Option::Some(Path_Node_oneof_specialization::file(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_file(&mut self) -> &mut super::serving::File {
+        if let ::std::option::Option::Some(Path_Node_oneof_specialization::file(_)) = self.specialization {
+        } else {
+            self.specialization = ::std::option::Option::Some(Path_Node_oneof_specialization::file(super::serving::File::new()));
+        }
+        match self.specialization {
+            ::std::option::Option::Some(Path_Node_oneof_specialization::file(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_file(&mut self) -> super::serving::File {
+        if self.has_file() {
+            match self.specialization.take() {
+                ::std::option::Option::Some(Path_Node_oneof_specialization::file(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            super::serving::File::new()
+        }
+    }
+
+    // string ticket = 1;
+
+
+    pub fn get_ticket(&self) -> &str {
+        &self.ticket
+    }
+    pub fn clear_ticket(&mut self) {
+        self.ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ticket(&mut self, v: ::std::string::String) {
+        self.ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ticket(&mut self) -> &mut ::std::string::String {
+        &mut self.ticket
+    }
+
+    // Take field
+    pub fn take_ticket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.ticket, ::std::string::String::new())
+    }
+
+    // string node_kind = 2;
+
+
+    pub fn get_node_kind(&self) -> &str {
+        &self.node_kind
+    }
+    pub fn clear_node_kind(&mut self) {
+        self.node_kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_node_kind(&mut self, v: ::std::string::String) {
+        self.node_kind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_node_kind(&mut self) -> &mut ::std::string::String {
+        &mut self.node_kind
+    }
+
+    // Take field
+    pub fn take_node_kind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.node_kind, ::std::string::String::new())
+    }
+
+    // .kythe.proto.serving.Node original = 3;
+
+
+    pub fn get_original(&self) -> &super::serving::Node {
+        self.original.as_ref().unwrap_or_else(|| <super::serving::Node as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_original(&mut self) {
+        self.original.clear();
+    }
+
+    pub fn has_original(&self) -> bool {
+        self.original.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_original(&mut self, v: super::serving::Node) {
+        self.original = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_original(&mut self) -> &mut super::serving::Node {
+        if self.original.is_none() {
+            self.original.set_default();
+        }
+        self.original.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_original(&mut self) -> super::serving::Node {
+        self.original.take().unwrap_or_else(|| super::serving::Node::new())
+    }
+}
+
+impl ::protobuf::Message for Path_Node {
+    fn is_initialized(&self) -> bool {
+        if let Some(Path_Node_oneof_specialization::raw_anchor(ref v)) = self.specialization {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Path_Node_oneof_specialization::expanded_anchor(ref v)) = self.specialization {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Path_Node_oneof_specialization::file(ref v)) = self.specialization {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        for v in &self.original {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.specialization = ::std::option::Option::Some(Path_Node_oneof_specialization::raw_anchor(is.read_message()?));
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.specialization = ::std::option::Option::Some(Path_Node_oneof_specialization::expanded_anchor(is.read_message()?));
+                },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.specialization = ::std::option::Option::Some(Path_Node_oneof_specialization::file(is.read_message()?));
+                },
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.ticket)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.node_kind)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.original)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.ticket.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.ticket);
+        }
+        if !self.node_kind.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.node_kind);
+        }
+        if let Some(ref v) = self.original.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let ::std::option::Option::Some(ref v) = self.specialization {
+            match v {
+                &Path_Node_oneof_specialization::raw_anchor(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Path_Node_oneof_specialization::expanded_anchor(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Path_Node_oneof_specialization::file(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.ticket.is_empty() {
+            os.write_string(1, &self.ticket)?;
+        }
+        if !self.node_kind.is_empty() {
+            os.write_string(2, &self.node_kind)?;
+        }
+        if let Some(ref v) = self.original.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.specialization {
+            match v {
+                &Path_Node_oneof_specialization::raw_anchor(ref v) => {
+                    os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Path_Node_oneof_specialization::expanded_anchor(ref v) => {
+                    os.write_tag(11, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Path_Node_oneof_specialization::file(ref v) => {
+                    os.write_tag(12, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Path_Node {
+        Path_Node::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, super::serving::RawAnchor>(
+                "raw_anchor",
+                Path_Node::has_raw_anchor,
+                Path_Node::get_raw_anchor,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, super::serving::ExpandedAnchor>(
+                "expanded_anchor",
+                Path_Node::has_expanded_anchor,
+                Path_Node::get_expanded_anchor,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, super::serving::File>(
+                "file",
+                Path_Node::has_file,
+                Path_Node::get_file,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "ticket",
+                |m: &Path_Node| { &m.ticket },
+                |m: &mut Path_Node| { &mut m.ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "node_kind",
+                |m: &Path_Node| { &m.node_kind },
+                |m: &mut Path_Node| { &mut m.node_kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::serving::Node>>(
+                "original",
+                |m: &Path_Node| { &m.original },
+                |m: &mut Path_Node| { &mut m.original },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Path_Node>(
+                "Path.Node",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Path_Node {
+        static instance: ::protobuf::rt::LazyV2<Path_Node> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Path_Node::new)
+    }
+}
+
+impl ::protobuf::Clear for Path_Node {
+    fn clear(&mut self) {
+        self.specialization = ::std::option::Option::None;
+        self.specialization = ::std::option::Option::None;
+        self.specialization = ::std::option::Option::None;
+        self.ticket.clear();
+        self.node_kind.clear();
+        self.original.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Path_Node {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Path_Node {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Path_Edge {
+    // message fields
+    pub kind: ::std::string::String,
+    pub ordinal: i32,
+    pub target: ::protobuf::SingularPtrField<Path_Node>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Path_Edge {
+    fn default() -> &'a Path_Edge {
+        <Path_Edge as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Path_Edge {
+    pub fn new() -> Path_Edge {
+        ::std::default::Default::default()
+    }
+
+    // string kind = 1;
+
+
+    pub fn get_kind(&self) -> &str {
+        &self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: ::std::string::String) {
+        self.kind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_kind(&mut self) -> &mut ::std::string::String {
+        &mut self.kind
+    }
+
+    // Take field
+    pub fn take_kind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.kind, ::std::string::String::new())
+    }
+
+    // int32 ordinal = 2;
+
+
+    pub fn get_ordinal(&self) -> i32 {
+        self.ordinal
+    }
+    pub fn clear_ordinal(&mut self) {
+        self.ordinal = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ordinal(&mut self, v: i32) {
+        self.ordinal = v;
+    }
+
+    // .kythe.proto.internal.Path.Node target = 3;
+
+
+    pub fn get_target(&self) -> &Path_Node {
+        self.target.as_ref().unwrap_or_else(|| <Path_Node as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: Path_Node) {
+        self.target = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut Path_Node {
+        if self.target.is_none() {
+            self.target.set_default();
+        }
+        self.target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> Path_Node {
+        self.target.take().unwrap_or_else(|| Path_Node::new())
+    }
+}
+
+impl ::protobuf::Message for Path_Edge {
+    fn is_initialized(&self) -> bool {
+        for v in &self.target {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.kind)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.ordinal = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.target)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.kind.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.kind);
+        }
+        if self.ordinal != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.ordinal, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.kind.is_empty() {
+            os.write_string(1, &self.kind)?;
+        }
+        if self.ordinal != 0 {
+            os.write_int32(2, self.ordinal)?;
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Path_Edge {
+        Path_Edge::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "kind",
+                |m: &Path_Edge| { &m.kind },
+                |m: &mut Path_Edge| { &mut m.kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "ordinal",
+                |m: &Path_Edge| { &m.ordinal },
+                |m: &mut Path_Edge| { &mut m.ordinal },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Path_Node>>(
+                "target",
+                |m: &Path_Edge| { &m.target },
+                |m: &mut Path_Edge| { &mut m.target },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Path_Edge>(
+                "Path.Edge",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Path_Edge {
+        static instance: ::protobuf::rt::LazyV2<Path_Edge> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Path_Edge::new)
+    }
+}
+
+impl ::protobuf::Clear for Path_Edge {
+    fn clear(&mut self) {
+        self.kind.clear();
+        self.ordinal = 0;
+        self.target.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Path_Edge {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Path_Edge {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x1akythe/proto/internal.proto\x12\x14kythe.proto.internal\x1a\x19kyth\
+    e/proto/serving.proto\"\xcf\x03\n\x06Source\x12\x16\n\x06ticket\x18\x01\
+    \x20\x01(\tR\x06ticket\x12=\n\x05facts\x18\x02\x20\x03(\x0b2'.kythe.prot\
+    o.internal.Source.FactsEntryR\x05facts\x12M\n\x0bedge_groups\x18\x03\x20\
+    \x03(\x0b2,.kythe.proto.internal.Source.EdgeGroupsEntryR\nedgeGroups\x1a\
+    8\n\x04Edge\x12\x16\n\x06ticket\x18\x01\x20\x01(\tR\x06ticket\x12\x18\n\
+    \x07ordinal\x18\x02\x20\x01(\x05R\x07ordinal\x1aD\n\tEdgeGroup\x127\n\
+    \x05edges\x18\x01\x20\x03(\x0b2!.kythe.proto.internal.Source.EdgeR\x05ed\
+    ges\x1a8\n\nFactsEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\
+    \x14\n\x05value\x18\x02\x20\x01(\x0cR\x05value:\x028\x01\x1ae\n\x0fEdgeG\
+    roupsEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12<\n\x05value\
+    \x18\x02\x20\x01(\x0b2&.kythe.proto.internal.Source.EdgeGroupR\x05value:\
+    \x028\x01\"\xdb\x02\n\tPageToken\x12\x14\n\x05index\x18\x01\x20\x01(\x05\
+    R\x05index\x12'\n\x0fsecondary_token\x18\x02\x20\x03(\tR\x0esecondaryTok\
+    en\x12M\n\nsub_tokens\x18\x03\x20\x03(\x0b2..kythe.proto.internal.PageTo\
+    ken.SubTokensEntryR\tsubTokens\x12F\n\x07indices\x18\x04\x20\x03(\x0b2,.\
+    kythe.proto.internal.PageToken.IndicesEntryR\x07indices\x1a<\n\x0eSubTok\
+    ensEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\
+    \x18\x02\x20\x01(\tR\x05value:\x028\x01\x1a:\n\x0cIndicesEntry\x12\x10\n\
+    \x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\
+    \x05R\x05value:\x028\x01\"\xa1\x04\n\x0eCrossReference\x12\\\n\x11source\
+    _decoration\x18\x01\x20\x01(\x0b2/.kythe.proto.internal.CrossReference.D\
+    ecorationR\x10sourceDecoration\x125\n\x08referent\x18\x02\x20\x01(\x0b2\
+    \x19.kythe.proto.serving.NodeR\x08referent\x12\\\n\x11target_decoration\
+    \x18\x03\x20\x01(\x0b2/.kythe.proto.internal.CrossReference.DecorationR\
+    \x10targetDecoration\x12H\n\rsource_anchor\x18\x04\x20\x01(\x0b2#.kythe.\
+    proto.serving.ExpandedAnchorR\x0csourceAnchor\x12H\n\rtarget_anchor\x18\
+    \x05\x20\x01(\x0b2#.kythe.proto.serving.ExpandedAnchorR\x0ctargetAnchor\
+    \x1a\x87\x01\n\nDecoration\x12-\n\x04file\x18\x01\x20\x01(\x0b2\x19.kyth\
+    e.proto.serving.FileR\x04file\x126\n\x06anchor\x18\x02\x20\x01(\x0b2\x1e\
+    .kythe.proto.serving.RawAnchorR\x06anchor\x12\x12\n\x04kind\x18\x03\x20\
+    \x01(\tR\x04kind\"S\n\x0eSortedKeyValue\x12\x10\n\x03key\x18\x01\x20\x01\
+    (\tR\x03key\x12\x19\n\x08sort_key\x18\x02\x20\x01(\tR\x07sortKey\x12\x14\
+    \n\x05value\x18\x03\x20\x01(\x0cR\x05value\"\xac\x04\n\x04Path\x125\n\
+    \x05pivot\x18\x01\x20\x01(\x0b2\x1f.kythe.proto.internal.Path.NodeR\x05p\
+    ivot\x125\n\x05edges\x18\x02\x20\x03(\x0b2\x1f.kythe.proto.internal.Path\
+    .EdgeR\x05edges\x1a\xc6\x02\n\x04Node\x12?\n\nraw_anchor\x18\n\x20\x01(\
+    \x0b2\x1e.kythe.proto.serving.RawAnchorH\0R\trawAnchor\x12N\n\x0fexpande\
+    d_anchor\x18\x0b\x20\x01(\x0b2#.kythe.proto.serving.ExpandedAnchorH\0R\
+    \x0eexpandedAnchor\x12/\n\x04file\x18\x0c\x20\x01(\x0b2\x19.kythe.proto.\
+    serving.FileH\0R\x04file\x12\x16\n\x06ticket\x18\x01\x20\x01(\tR\x06tick\
+    et\x12\x1b\n\tnode_kind\x18\x02\x20\x01(\tR\x08nodeKind\x125\n\x08origin\
+    al\x18\x03\x20\x01(\x0b2\x19.kythe.proto.serving.NodeR\x08originalB\x10\
+    \n\x0especialization\x1am\n\x04Edge\x12\x12\n\x04kind\x18\x01\x20\x01(\t\
+    R\x04kind\x12\x18\n\x07ordinal\x18\x02\x20\x01(\x05R\x07ordinal\x127\n\
+    \x06target\x18\x03\x20\x01(\x0b2\x1f.kythe.proto.internal.Path.NodeR\x06\
+    targetBI\n\x1fcom.google.devtools.kythe.protoZ&kythe.io/kythe/proto/inte\
+    rnal_go_protob\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,9 @@
+extern crate protobuf;
+pub mod timestamp;
+pub use timestamp::*;
+pub mod common;
+pub use common::*;
+pub mod serving;
+pub use serving::*;
+pub mod internal;
+pub use internal::*;
\ No newline at end of file
@@ -0,0 +1,822 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `kythe/proto/java.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JarDetails {
+    // message fields
+    pub jar: ::protobuf::RepeatedField<JarDetails_Jar>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a JarDetails {
+    fn default() -> &'a JarDetails {
+        <JarDetails as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl JarDetails {
+    pub fn new() -> JarDetails {
+        ::std::default::Default::default()
+    }
+
+    // repeated .kythe.proto.JarDetails.Jar jar = 1;
+
+
+    pub fn get_jar(&self) -> &[JarDetails_Jar] {
+        &self.jar
+    }
+    pub fn clear_jar(&mut self) {
+        self.jar.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_jar(&mut self, v: ::protobuf::RepeatedField<JarDetails_Jar>) {
+        self.jar = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_jar(&mut self) -> &mut ::protobuf::RepeatedField<JarDetails_Jar> {
+        &mut self.jar
+    }
+
+    // Take field
+    pub fn take_jar(&mut self) -> ::protobuf::RepeatedField<JarDetails_Jar> {
+        ::std::mem::replace(&mut self.jar, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for JarDetails {
+    fn is_initialized(&self) -> bool {
+        for v in &self.jar {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.jar)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.jar {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.jar {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> JarDetails {
+        JarDetails::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<JarDetails_Jar>>(
+                "jar",
+                |m: &JarDetails| { &m.jar },
+                |m: &mut JarDetails| { &mut m.jar },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<JarDetails>(
+                "JarDetails",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static JarDetails {
+        static instance: ::protobuf::rt::LazyV2<JarDetails> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(JarDetails::new)
+    }
+}
+
+impl ::protobuf::Clear for JarDetails {
+    fn clear(&mut self) {
+        self.jar.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JarDetails {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JarDetails {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JarDetails_Jar {
+    // message fields
+    pub v_name: ::protobuf::SingularPtrField<super::storage::VName>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a JarDetails_Jar {
+    fn default() -> &'a JarDetails_Jar {
+        <JarDetails_Jar as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl JarDetails_Jar {
+    pub fn new() -> JarDetails_Jar {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.VName v_name = 1;
+
+
+    pub fn get_v_name(&self) -> &super::storage::VName {
+        self.v_name.as_ref().unwrap_or_else(|| <super::storage::VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_v_name(&mut self) {
+        self.v_name.clear();
+    }
+
+    pub fn has_v_name(&self) -> bool {
+        self.v_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_v_name(&mut self, v: super::storage::VName) {
+        self.v_name = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_v_name(&mut self) -> &mut super::storage::VName {
+        if self.v_name.is_none() {
+            self.v_name.set_default();
+        }
+        self.v_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_v_name(&mut self) -> super::storage::VName {
+        self.v_name.take().unwrap_or_else(|| super::storage::VName::new())
+    }
+}
+
+impl ::protobuf::Message for JarDetails_Jar {
+    fn is_initialized(&self) -> bool {
+        for v in &self.v_name {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.v_name)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.v_name.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.v_name.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> JarDetails_Jar {
+        JarDetails_Jar::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::storage::VName>>(
+                "v_name",
+                |m: &JarDetails_Jar| { &m.v_name },
+                |m: &mut JarDetails_Jar| { &mut m.v_name },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<JarDetails_Jar>(
+                "JarDetails.Jar",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static JarDetails_Jar {
+        static instance: ::protobuf::rt::LazyV2<JarDetails_Jar> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(JarDetails_Jar::new)
+    }
+}
+
+impl ::protobuf::Clear for JarDetails_Jar {
+    fn clear(&mut self) {
+        self.v_name.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JarDetails_Jar {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JarDetails_Jar {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JarEntryDetails {
+    // message fields
+    pub jar_container: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a JarEntryDetails {
+    fn default() -> &'a JarEntryDetails {
+        <JarEntryDetails as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl JarEntryDetails {
+    pub fn new() -> JarEntryDetails {
+        ::std::default::Default::default()
+    }
+
+    // int32 jar_container = 1;
+
+
+    pub fn get_jar_container(&self) -> i32 {
+        self.jar_container
+    }
+    pub fn clear_jar_container(&mut self) {
+        self.jar_container = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_jar_container(&mut self, v: i32) {
+        self.jar_container = v;
+    }
+}
+
+impl ::protobuf::Message for JarEntryDetails {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.jar_container = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.jar_container != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.jar_container, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.jar_container != 0 {
+            os.write_int32(1, self.jar_container)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> JarEntryDetails {
+        JarEntryDetails::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "jar_container",
+                |m: &JarEntryDetails| { &m.jar_container },
+                |m: &mut JarEntryDetails| { &mut m.jar_container },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<JarEntryDetails>(
+                "JarEntryDetails",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static JarEntryDetails {
+        static instance: ::protobuf::rt::LazyV2<JarEntryDetails> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(JarEntryDetails::new)
+    }
+}
+
+impl ::protobuf::Clear for JarEntryDetails {
+    fn clear(&mut self) {
+        self.jar_container = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JarEntryDetails {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JarEntryDetails {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JavaDetails {
+    // message fields
+    pub classpath: ::protobuf::RepeatedField<::std::string::String>,
+    pub sourcepath: ::protobuf::RepeatedField<::std::string::String>,
+    pub bootclasspath: ::protobuf::RepeatedField<::std::string::String>,
+    pub extra_javacopts: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a JavaDetails {
+    fn default() -> &'a JavaDetails {
+        <JavaDetails as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl JavaDetails {
+    pub fn new() -> JavaDetails {
+        ::std::default::Default::default()
+    }
+
+    // repeated string classpath = 1;
+
+
+    pub fn get_classpath(&self) -> &[::std::string::String] {
+        &self.classpath
+    }
+    pub fn clear_classpath(&mut self) {
+        self.classpath.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_classpath(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.classpath = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_classpath(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.classpath
+    }
+
+    // Take field
+    pub fn take_classpath(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.classpath, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string sourcepath = 2;
+
+
+    pub fn get_sourcepath(&self) -> &[::std::string::String] {
+        &self.sourcepath
+    }
+    pub fn clear_sourcepath(&mut self) {
+        self.sourcepath.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sourcepath(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.sourcepath = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_sourcepath(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.sourcepath
+    }
+
+    // Take field
+    pub fn take_sourcepath(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.sourcepath, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string bootclasspath = 3;
+
+
+    pub fn get_bootclasspath(&self) -> &[::std::string::String] {
+        &self.bootclasspath
+    }
+    pub fn clear_bootclasspath(&mut self) {
+        self.bootclasspath.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bootclasspath(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.bootclasspath = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_bootclasspath(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.bootclasspath
+    }
+
+    // Take field
+    pub fn take_bootclasspath(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.bootclasspath, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string extra_javacopts = 10;
+
+
+    pub fn get_extra_javacopts(&self) -> &[::std::string::String] {
+        &self.extra_javacopts
+    }
+    pub fn clear_extra_javacopts(&mut self) {
+        self.extra_javacopts.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_extra_javacopts(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.extra_javacopts = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_extra_javacopts(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.extra_javacopts
+    }
+
+    // Take field
+    pub fn take_extra_javacopts(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.extra_javacopts, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for JavaDetails {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.classpath)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.sourcepath)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.bootclasspath)?;
+                },
+                10 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.extra_javacopts)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.classpath {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in &self.sourcepath {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in &self.bootclasspath {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in &self.extra_javacopts {
+            my_size += ::protobuf::rt::string_size(10, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.classpath {
+            os.write_string(1, &v)?;
+        };
+        for v in &self.sourcepath {
+            os.write_string(2, &v)?;
+        };
+        for v in &self.bootclasspath {
+            os.write_string(3, &v)?;
+        };
+        for v in &self.extra_javacopts {
+            os.write_string(10, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> JavaDetails {
+        JavaDetails::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "classpath",
+                |m: &JavaDetails| { &m.classpath },
+                |m: &mut JavaDetails| { &mut m.classpath },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "sourcepath",
+                |m: &JavaDetails| { &m.sourcepath },
+                |m: &mut JavaDetails| { &mut m.sourcepath },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "bootclasspath",
+                |m: &JavaDetails| { &m.bootclasspath },
+                |m: &mut JavaDetails| { &mut m.bootclasspath },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "extra_javacopts",
+                |m: &JavaDetails| { &m.extra_javacopts },
+                |m: &mut JavaDetails| { &mut m.extra_javacopts },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<JavaDetails>(
+                "JavaDetails",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static JavaDetails {
+        static instance: ::protobuf::rt::LazyV2<JavaDetails> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(JavaDetails::new)
+    }
+}
+
+impl ::protobuf::Clear for JavaDetails {
+    fn clear(&mut self) {
+        self.classpath.clear();
+        self.sourcepath.clear();
+        self.bootclasspath.clear();
+        self.extra_javacopts.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JavaDetails {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JavaDetails {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x16kythe/proto/java.proto\x12\x0bkythe.proto\x1a\x19kythe/proto/stora\
+    ge.proto\"s\n\nJarDetails\x12-\n\x03jar\x18\x01\x20\x03(\x0b2\x1b.kythe.\
+    proto.JarDetails.JarR\x03jar\x1a6\n\x03Jar\x12)\n\x06v_name\x18\x01\x20\
+    \x01(\x0b2\x12.kythe.proto.VNameR\x05vNameJ\x04\x08\x02\x10\x03\"6\n\x0f\
+    JarEntryDetails\x12#\n\rjar_container\x18\x01\x20\x01(\x05R\x0cjarContai\
+    ner\"\x9a\x01\n\x0bJavaDetails\x12\x1c\n\tclasspath\x18\x01\x20\x03(\tR\
+    \tclasspath\x12\x1e\n\nsourcepath\x18\x02\x20\x03(\tR\nsourcepath\x12$\n\
+    \rbootclasspath\x18\x03\x20\x03(\tR\rbootclasspath\x12'\n\x0fextra_javac\
+    opts\x18\n\x20\x03(\tR\x0eextraJavacoptsBE\n\x1fcom.google.devtools.kyth\
+    e.protoZ\"kythe.io/kythe/proto/java_go_protob\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,5 @@
+extern crate protobuf;
+pub mod storage;
+pub use storage::*;
+pub mod java;
+pub use java::*;
\ No newline at end of file
@@ -0,0 +1,7 @@
+extern crate protobuf;
+pub mod timestamp;
+pub use timestamp::*;
+pub mod common;
+pub use common::*;
+pub mod link;
+pub use link::*;
\ No newline at end of file
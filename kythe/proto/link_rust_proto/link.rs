@@ -0,0 +1,1689 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `kythe/proto/link.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LinkRequest {
+    // message fields
+    pub identifier: ::std::string::String,
+    pub corpus: ::protobuf::RepeatedField<::std::string::String>,
+    pub language: ::protobuf::RepeatedField<::std::string::String>,
+    pub node_kind: ::protobuf::RepeatedField<::std::string::String>,
+    pub include: ::protobuf::RepeatedField<LinkRequest_Location>,
+    pub exclude: ::protobuf::RepeatedField<LinkRequest_Location>,
+    pub params: ::protobuf::SingularPtrField<LinkRequest_Params>,
+    pub definition_kind: LinkRequest_DefinitionKind,
+    pub include_nodes: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a LinkRequest {
+    fn default() -> &'a LinkRequest {
+        <LinkRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LinkRequest {
+    pub fn new() -> LinkRequest {
+        ::std::default::Default::default()
+    }
+
+    // string identifier = 1;
+
+
+    pub fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    pub fn clear_identifier(&mut self) {
+        self.identifier.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_identifier(&mut self, v: ::std::string::String) {
+        self.identifier = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_identifier(&mut self) -> &mut ::std::string::String {
+        &mut self.identifier
+    }
+
+    // Take field
+    pub fn take_identifier(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.identifier, ::std::string::String::new())
+    }
+
+    // repeated string corpus = 2;
+
+
+    pub fn get_corpus(&self) -> &[::std::string::String] {
+        &self.corpus
+    }
+    pub fn clear_corpus(&mut self) {
+        self.corpus.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_corpus(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.corpus = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_corpus(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.corpus
+    }
+
+    // Take field
+    pub fn take_corpus(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.corpus, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string language = 3;
+
+
+    pub fn get_language(&self) -> &[::std::string::String] {
+        &self.language
+    }
+    pub fn clear_language(&mut self) {
+        self.language.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_language(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.language = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_language(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.language
+    }
+
+    // Take field
+    pub fn take_language(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.language, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string node_kind = 4;
+
+
+    pub fn get_node_kind(&self) -> &[::std::string::String] {
+        &self.node_kind
+    }
+    pub fn clear_node_kind(&mut self) {
+        self.node_kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_node_kind(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.node_kind = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_node_kind(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.node_kind
+    }
+
+    // Take field
+    pub fn take_node_kind(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.node_kind, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .kythe.proto.LinkRequest.Location include = 5;
+
+
+    pub fn get_include(&self) -> &[LinkRequest_Location] {
+        &self.include
+    }
+    pub fn clear_include(&mut self) {
+        self.include.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_include(&mut self, v: ::protobuf::RepeatedField<LinkRequest_Location>) {
+        self.include = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_include(&mut self) -> &mut ::protobuf::RepeatedField<LinkRequest_Location> {
+        &mut self.include
+    }
+
+    // Take field
+    pub fn take_include(&mut self) -> ::protobuf::RepeatedField<LinkRequest_Location> {
+        ::std::mem::replace(&mut self.include, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .kythe.proto.LinkRequest.Location exclude = 6;
+
+
+    pub fn get_exclude(&self) -> &[LinkRequest_Location] {
+        &self.exclude
+    }
+    pub fn clear_exclude(&mut self) {
+        self.exclude.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exclude(&mut self, v: ::protobuf::RepeatedField<LinkRequest_Location>) {
+        self.exclude = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_exclude(&mut self) -> &mut ::protobuf::RepeatedField<LinkRequest_Location> {
+        &mut self.exclude
+    }
+
+    // Take field
+    pub fn take_exclude(&mut self) -> ::protobuf::RepeatedField<LinkRequest_Location> {
+        ::std::mem::replace(&mut self.exclude, ::protobuf::RepeatedField::new())
+    }
+
+    // .kythe.proto.LinkRequest.Params params = 7;
+
+
+    pub fn get_params(&self) -> &LinkRequest_Params {
+        self.params.as_ref().unwrap_or_else(|| <LinkRequest_Params as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_params(&mut self) {
+        self.params.clear();
+    }
+
+    pub fn has_params(&self) -> bool {
+        self.params.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_params(&mut self, v: LinkRequest_Params) {
+        self.params = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_params(&mut self) -> &mut LinkRequest_Params {
+        if self.params.is_none() {
+            self.params.set_default();
+        }
+        self.params.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_params(&mut self) -> LinkRequest_Params {
+        self.params.take().unwrap_or_else(|| LinkRequest_Params::new())
+    }
+
+    // .kythe.proto.LinkRequest.DefinitionKind definition_kind = 8;
+
+
+    pub fn get_definition_kind(&self) -> LinkRequest_DefinitionKind {
+        self.definition_kind
+    }
+    pub fn clear_definition_kind(&mut self) {
+        self.definition_kind = LinkRequest_DefinitionKind::BINDING;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_definition_kind(&mut self, v: LinkRequest_DefinitionKind) {
+        self.definition_kind = v;
+    }
+
+    // bool include_nodes = 9;
+
+
+    pub fn get_include_nodes(&self) -> bool {
+        self.include_nodes
+    }
+    pub fn clear_include_nodes(&mut self) {
+        self.include_nodes = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_include_nodes(&mut self, v: bool) {
+        self.include_nodes = v;
+    }
+}
+
+impl ::protobuf::Message for LinkRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.include {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.exclude {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.params {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.identifier)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.corpus)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.language)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.node_kind)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.include)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.exclude)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.params)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.definition_kind, 8, &mut self.unknown_fields)?
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.include_nodes = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.identifier.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.identifier);
+        }
+        for value in &self.corpus {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in &self.language {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in &self.node_kind {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        for value in &self.include {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.exclude {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.params.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.definition_kind != LinkRequest_DefinitionKind::BINDING {
+            my_size += ::protobuf::rt::enum_size(8, self.definition_kind);
+        }
+        if self.include_nodes != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.identifier.is_empty() {
+            os.write_string(1, &self.identifier)?;
+        }
+        for v in &self.corpus {
+            os.write_string(2, &v)?;
+        };
+        for v in &self.language {
+            os.write_string(3, &v)?;
+        };
+        for v in &self.node_kind {
+            os.write_string(4, &v)?;
+        };
+        for v in &self.include {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.exclude {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.params.as_ref() {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.definition_kind != LinkRequest_DefinitionKind::BINDING {
+            os.write_enum(8, ::protobuf::ProtobufEnum::value(&self.definition_kind))?;
+        }
+        if self.include_nodes != false {
+            os.write_bool(9, self.include_nodes)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LinkRequest {
+        LinkRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "identifier",
+                |m: &LinkRequest| { &m.identifier },
+                |m: &mut LinkRequest| { &mut m.identifier },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "corpus",
+                |m: &LinkRequest| { &m.corpus },
+                |m: &mut LinkRequest| { &mut m.corpus },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "language",
+                |m: &LinkRequest| { &m.language },
+                |m: &mut LinkRequest| { &mut m.language },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "node_kind",
+                |m: &LinkRequest| { &m.node_kind },
+                |m: &mut LinkRequest| { &mut m.node_kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LinkRequest_Location>>(
+                "include",
+                |m: &LinkRequest| { &m.include },
+                |m: &mut LinkRequest| { &mut m.include },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LinkRequest_Location>>(
+                "exclude",
+                |m: &LinkRequest| { &m.exclude },
+                |m: &mut LinkRequest| { &mut m.exclude },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LinkRequest_Params>>(
+                "params",
+                |m: &LinkRequest| { &m.params },
+                |m: &mut LinkRequest| { &mut m.params },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<LinkRequest_DefinitionKind>>(
+                "definition_kind",
+                |m: &LinkRequest| { &m.definition_kind },
+                |m: &mut LinkRequest| { &mut m.definition_kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "include_nodes",
+                |m: &LinkRequest| { &m.include_nodes },
+                |m: &mut LinkRequest| { &mut m.include_nodes },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<LinkRequest>(
+                "LinkRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static LinkRequest {
+        static instance: ::protobuf::rt::LazyV2<LinkRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(LinkRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for LinkRequest {
+    fn clear(&mut self) {
+        self.identifier.clear();
+        self.corpus.clear();
+        self.language.clear();
+        self.node_kind.clear();
+        self.include.clear();
+        self.exclude.clear();
+        self.params.clear();
+        self.definition_kind = LinkRequest_DefinitionKind::BINDING;
+        self.include_nodes = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LinkRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LinkRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LinkRequest_Location {
+    // message fields
+    pub path: ::std::string::String,
+    pub root: ::std::string::String,
+    pub corpus: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a LinkRequest_Location {
+    fn default() -> &'a LinkRequest_Location {
+        <LinkRequest_Location as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LinkRequest_Location {
+    pub fn new() -> LinkRequest_Location {
+        ::std::default::Default::default()
+    }
+
+    // string path = 1;
+
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        &mut self.path
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.path, ::std::string::String::new())
+    }
+
+    // string root = 2;
+
+
+    pub fn get_root(&self) -> &str {
+        &self.root
+    }
+    pub fn clear_root(&mut self) {
+        self.root.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_root(&mut self, v: ::std::string::String) {
+        self.root = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_root(&mut self) -> &mut ::std::string::String {
+        &mut self.root
+    }
+
+    // Take field
+    pub fn take_root(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.root, ::std::string::String::new())
+    }
+
+    // string corpus = 3;
+
+
+    pub fn get_corpus(&self) -> &str {
+        &self.corpus
+    }
+    pub fn clear_corpus(&mut self) {
+        self.corpus.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_corpus(&mut self, v: ::std::string::String) {
+        self.corpus = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_corpus(&mut self) -> &mut ::std::string::String {
+        &mut self.corpus
+    }
+
+    // Take field
+    pub fn take_corpus(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.corpus, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for LinkRequest_Location {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.root)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.corpus)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.path);
+        }
+        if !self.root.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.root);
+        }
+        if !self.corpus.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.corpus);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.path.is_empty() {
+            os.write_string(1, &self.path)?;
+        }
+        if !self.root.is_empty() {
+            os.write_string(2, &self.root)?;
+        }
+        if !self.corpus.is_empty() {
+            os.write_string(3, &self.corpus)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LinkRequest_Location {
+        LinkRequest_Location::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "path",
+                |m: &LinkRequest_Location| { &m.path },
+                |m: &mut LinkRequest_Location| { &mut m.path },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "root",
+                |m: &LinkRequest_Location| { &m.root },
+                |m: &mut LinkRequest_Location| { &mut m.root },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "corpus",
+                |m: &LinkRequest_Location| { &m.corpus },
+                |m: &mut LinkRequest_Location| { &mut m.corpus },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<LinkRequest_Location>(
+                "LinkRequest.Location",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static LinkRequest_Location {
+        static instance: ::protobuf::rt::LazyV2<LinkRequest_Location> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(LinkRequest_Location::new)
+    }
+}
+
+impl ::protobuf::Clear for LinkRequest_Location {
+    fn clear(&mut self) {
+        self.path.clear();
+        self.root.clear();
+        self.corpus.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LinkRequest_Location {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LinkRequest_Location {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LinkRequest_Params {
+    // message fields
+    pub count: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a LinkRequest_Params {
+    fn default() -> &'a LinkRequest_Params {
+        <LinkRequest_Params as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LinkRequest_Params {
+    pub fn new() -> LinkRequest_Params {
+        ::std::default::Default::default()
+    }
+
+    // int32 count = 1;
+
+
+    pub fn get_count(&self) -> i32 {
+        self.count
+    }
+    pub fn clear_count(&mut self) {
+        self.count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: i32) {
+        self.count = v;
+    }
+}
+
+impl ::protobuf::Message for LinkRequest_Params {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.count = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.count != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.count != 0 {
+            os.write_int32(1, self.count)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LinkRequest_Params {
+        LinkRequest_Params::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "count",
+                |m: &LinkRequest_Params| { &m.count },
+                |m: &mut LinkRequest_Params| { &mut m.count },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<LinkRequest_Params>(
+                "LinkRequest.Params",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static LinkRequest_Params {
+        static instance: ::protobuf::rt::LazyV2<LinkRequest_Params> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(LinkRequest_Params::new)
+    }
+}
+
+impl ::protobuf::Clear for LinkRequest_Params {
+    fn clear(&mut self) {
+        self.count = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LinkRequest_Params {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LinkRequest_Params {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum LinkRequest_DefinitionKind {
+    BINDING = 0,
+    FULL = 1,
+    ANY = 2,
+}
+
+impl ::protobuf::ProtobufEnum for LinkRequest_DefinitionKind {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<LinkRequest_DefinitionKind> {
+        match value {
+            0 => ::std::option::Option::Some(LinkRequest_DefinitionKind::BINDING),
+            1 => ::std::option::Option::Some(LinkRequest_DefinitionKind::FULL),
+            2 => ::std::option::Option::Some(LinkRequest_DefinitionKind::ANY),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [LinkRequest_DefinitionKind] = &[
+            LinkRequest_DefinitionKind::BINDING,
+            LinkRequest_DefinitionKind::FULL,
+            LinkRequest_DefinitionKind::ANY,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<LinkRequest_DefinitionKind>("LinkRequest.DefinitionKind", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for LinkRequest_DefinitionKind {
+}
+
+impl ::std::default::Default for LinkRequest_DefinitionKind {
+    fn default() -> Self {
+        LinkRequest_DefinitionKind::BINDING
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LinkRequest_DefinitionKind {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Link {
+    // message fields
+    pub file_ticket: ::std::string::String,
+    pub span: ::protobuf::SingularPtrField<super::common::Span>,
+    pub nodes: ::protobuf::RepeatedField<Link_Node>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Link {
+    fn default() -> &'a Link {
+        <Link as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Link {
+    pub fn new() -> Link {
+        ::std::default::Default::default()
+    }
+
+    // string file_ticket = 1;
+
+
+    pub fn get_file_ticket(&self) -> &str {
+        &self.file_ticket
+    }
+    pub fn clear_file_ticket(&mut self) {
+        self.file_ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_ticket(&mut self, v: ::std::string::String) {
+        self.file_ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_file_ticket(&mut self) -> &mut ::std::string::String {
+        &mut self.file_ticket
+    }
+
+    // Take field
+    pub fn take_file_ticket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.file_ticket, ::std::string::String::new())
+    }
+
+    // .kythe.proto.common.Span span = 2;
+
+
+    pub fn get_span(&self) -> &super::common::Span {
+        self.span.as_ref().unwrap_or_else(|| <super::common::Span as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_span(&mut self) {
+        self.span.clear();
+    }
+
+    pub fn has_span(&self) -> bool {
+        self.span.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_span(&mut self, v: super::common::Span) {
+        self.span = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_span(&mut self) -> &mut super::common::Span {
+        if self.span.is_none() {
+            self.span.set_default();
+        }
+        self.span.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_span(&mut self) -> super::common::Span {
+        self.span.take().unwrap_or_else(|| super::common::Span::new())
+    }
+
+    // repeated .kythe.proto.Link.Node nodes = 3;
+
+
+    pub fn get_nodes(&self) -> &[Link_Node] {
+        &self.nodes
+    }
+    pub fn clear_nodes(&mut self) {
+        self.nodes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nodes(&mut self, v: ::protobuf::RepeatedField<Link_Node>) {
+        self.nodes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_nodes(&mut self) -> &mut ::protobuf::RepeatedField<Link_Node> {
+        &mut self.nodes
+    }
+
+    // Take field
+    pub fn take_nodes(&mut self) -> ::protobuf::RepeatedField<Link_Node> {
+        ::std::mem::replace(&mut self.nodes, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Link {
+    fn is_initialized(&self) -> bool {
+        for v in &self.span {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.nodes {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.file_ticket)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.span)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.nodes)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.file_ticket.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.file_ticket);
+        }
+        if let Some(ref v) = self.span.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.nodes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.file_ticket.is_empty() {
+            os.write_string(1, &self.file_ticket)?;
+        }
+        if let Some(ref v) = self.span.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.nodes {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Link {
+        Link::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "file_ticket",
+                |m: &Link| { &m.file_ticket },
+                |m: &mut Link| { &mut m.file_ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::Span>>(
+                "span",
+                |m: &Link| { &m.span },
+                |m: &mut Link| { &mut m.span },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Link_Node>>(
+                "nodes",
+                |m: &Link| { &m.nodes },
+                |m: &mut Link| { &mut m.nodes },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Link>(
+                "Link",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Link {
+        static instance: ::protobuf::rt::LazyV2<Link> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Link::new)
+    }
+}
+
+impl ::protobuf::Clear for Link {
+    fn clear(&mut self) {
+        self.file_ticket.clear();
+        self.span.clear();
+        self.nodes.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Link {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Link {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Link_Node {
+    // message fields
+    pub ticket: ::std::string::String,
+    pub base_name: ::std::string::String,
+    pub identifier: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Link_Node {
+    fn default() -> &'a Link_Node {
+        <Link_Node as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Link_Node {
+    pub fn new() -> Link_Node {
+        ::std::default::Default::default()
+    }
+
+    // string ticket = 1;
+
+
+    pub fn get_ticket(&self) -> &str {
+        &self.ticket
+    }
+    pub fn clear_ticket(&mut self) {
+        self.ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ticket(&mut self, v: ::std::string::String) {
+        self.ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ticket(&mut self) -> &mut ::std::string::String {
+        &mut self.ticket
+    }
+
+    // Take field
+    pub fn take_ticket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.ticket, ::std::string::String::new())
+    }
+
+    // string base_name = 2;
+
+
+    pub fn get_base_name(&self) -> &str {
+        &self.base_name
+    }
+    pub fn clear_base_name(&mut self) {
+        self.base_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_base_name(&mut self, v: ::std::string::String) {
+        self.base_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_base_name(&mut self) -> &mut ::std::string::String {
+        &mut self.base_name
+    }
+
+    // Take field
+    pub fn take_base_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.base_name, ::std::string::String::new())
+    }
+
+    // string identifier = 3;
+
+
+    pub fn get_identifier(&self) -> &str {
+        &self.identifier
+    }
+    pub fn clear_identifier(&mut self) {
+        self.identifier.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_identifier(&mut self, v: ::std::string::String) {
+        self.identifier = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_identifier(&mut self) -> &mut ::std::string::String {
+        &mut self.identifier
+    }
+
+    // Take field
+    pub fn take_identifier(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.identifier, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Link_Node {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.ticket)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.base_name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.identifier)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.ticket.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.ticket);
+        }
+        if !self.base_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.base_name);
+        }
+        if !self.identifier.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.identifier);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.ticket.is_empty() {
+            os.write_string(1, &self.ticket)?;
+        }
+        if !self.base_name.is_empty() {
+            os.write_string(2, &self.base_name)?;
+        }
+        if !self.identifier.is_empty() {
+            os.write_string(3, &self.identifier)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Link_Node {
+        Link_Node::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "ticket",
+                |m: &Link_Node| { &m.ticket },
+                |m: &mut Link_Node| { &mut m.ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "base_name",
+                |m: &Link_Node| { &m.base_name },
+                |m: &mut Link_Node| { &mut m.base_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "identifier",
+                |m: &Link_Node| { &m.identifier },
+                |m: &mut Link_Node| { &mut m.identifier },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Link_Node>(
+                "Link.Node",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Link_Node {
+        static instance: ::protobuf::rt::LazyV2<Link_Node> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Link_Node::new)
+    }
+}
+
+impl ::protobuf::Clear for Link_Node {
+    fn clear(&mut self) {
+        self.ticket.clear();
+        self.base_name.clear();
+        self.identifier.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Link_Node {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Link_Node {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LinkReply {
+    // message fields
+    pub links: ::protobuf::RepeatedField<Link>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a LinkReply {
+    fn default() -> &'a LinkReply {
+        <LinkReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LinkReply {
+    pub fn new() -> LinkReply {
+        ::std::default::Default::default()
+    }
+
+    // repeated .kythe.proto.Link links = 1;
+
+
+    pub fn get_links(&self) -> &[Link] {
+        &self.links
+    }
+    pub fn clear_links(&mut self) {
+        self.links.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_links(&mut self, v: ::protobuf::RepeatedField<Link>) {
+        self.links = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_links(&mut self) -> &mut ::protobuf::RepeatedField<Link> {
+        &mut self.links
+    }
+
+    // Take field
+    pub fn take_links(&mut self) -> ::protobuf::RepeatedField<Link> {
+        ::std::mem::replace(&mut self.links, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for LinkReply {
+    fn is_initialized(&self) -> bool {
+        for v in &self.links {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.links)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.links {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.links {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LinkReply {
+        LinkReply::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Link>>(
+                "links",
+                |m: &LinkReply| { &m.links },
+                |m: &mut LinkReply| { &mut m.links },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<LinkReply>(
+                "LinkReply",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static LinkReply {
+        static instance: ::protobuf::rt::LazyV2<LinkReply> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(LinkReply::new)
+    }
+}
+
+impl ::protobuf::Clear for LinkReply {
+    fn clear(&mut self) {
+        self.links.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LinkReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LinkReply {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x16kythe/proto/link.proto\x12\x0bkythe.proto\x1a\x18kythe/proto/commo\
+    n.proto\"\xc6\x04\n\x0bLinkRequest\x12\x1e\n\nidentifier\x18\x01\x20\x01\
+    (\tR\nidentifier\x12\x16\n\x06corpus\x18\x02\x20\x03(\tR\x06corpus\x12\
+    \x1a\n\x08language\x18\x03\x20\x03(\tR\x08language\x12\x1b\n\tnode_kind\
+    \x18\x04\x20\x03(\tR\x08nodeKind\x12;\n\x07include\x18\x05\x20\x03(\x0b2\
+    !.kythe.proto.LinkRequest.LocationR\x07include\x12;\n\x07exclude\x18\x06\
+    \x20\x03(\x0b2!.kythe.proto.LinkRequest.LocationR\x07exclude\x127\n\x06p\
+    arams\x18\x07\x20\x01(\x0b2\x1f.kythe.proto.LinkRequest.ParamsR\x06param\
+    s\x12P\n\x0fdefinition_kind\x18\x08\x20\x01(\x0e2'.kythe.proto.LinkReque\
+    st.DefinitionKindR\x0edefinitionKind\x12#\n\rinclude_nodes\x18\t\x20\x01\
+    (\x08R\x0cincludeNodes\x1aJ\n\x08Location\x12\x12\n\x04path\x18\x01\x20\
+    \x01(\tR\x04path\x12\x12\n\x04root\x18\x02\x20\x01(\tR\x04root\x12\x16\n\
+    \x06corpus\x18\x03\x20\x01(\tR\x06corpus\x1a\x1e\n\x06Params\x12\x14\n\
+    \x05count\x18\x01\x20\x01(\x05R\x05count\"0\n\x0eDefinitionKind\x12\x0b\
+    \n\x07BINDING\x10\0\x12\x08\n\x04FULL\x10\x01\x12\x07\n\x03ANY\x10\x02\"\
+    \xe0\x01\n\x04Link\x12\x1f\n\x0bfile_ticket\x18\x01\x20\x01(\tR\nfileTic\
+    ket\x12,\n\x04span\x18\x02\x20\x01(\x0b2\x18.kythe.proto.common.SpanR\
+    \x04span\x12,\n\x05nodes\x18\x03\x20\x03(\x0b2\x16.kythe.proto.Link.Node\
+    R\x05nodes\x1a[\n\x04Node\x12\x16\n\x06ticket\x18\x01\x20\x01(\tR\x06tic\
+    ket\x12\x1b\n\tbase_name\x18\x02\x20\x01(\tR\x08baseName\x12\x1e\n\niden\
+    tifier\x18\x03\x20\x01(\tR\nidentifier\"4\n\tLinkReply\x12'\n\x05links\
+    \x18\x01\x20\x03(\x0b2\x11.kythe.proto.LinkR\x05links2L\n\x0bLinkService\
+    \x12=\n\x07Resolve\x12\x18.kythe.proto.LinkRequest\x1a\x16.kythe.proto.L\
+    inkReply\"\0BE\n\x1fcom.google.devtools.kythe.protoZ\"kythe.io/kythe/pro\
+    to/link_go_protob\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
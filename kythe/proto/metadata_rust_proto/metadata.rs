@@ -0,0 +1,964 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `kythe/proto/metadata.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GeneratedCodeInfo {
+    // message fields
+    pub field_type: GeneratedCodeInfo_Type,
+    pub meta: ::protobuf::RepeatedField<MappingRule>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GeneratedCodeInfo {
+    fn default() -> &'a GeneratedCodeInfo {
+        <GeneratedCodeInfo as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GeneratedCodeInfo {
+    pub fn new() -> GeneratedCodeInfo {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.metadata.GeneratedCodeInfo.Type type = 1;
+
+
+    pub fn get_field_type(&self) -> GeneratedCodeInfo_Type {
+        self.field_type
+    }
+    pub fn clear_field_type(&mut self) {
+        self.field_type = GeneratedCodeInfo_Type::NONE;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: GeneratedCodeInfo_Type) {
+        self.field_type = v;
+    }
+
+    // repeated .kythe.proto.metadata.MappingRule meta = 2;
+
+
+    pub fn get_meta(&self) -> &[MappingRule] {
+        &self.meta
+    }
+    pub fn clear_meta(&mut self) {
+        self.meta.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_meta(&mut self, v: ::protobuf::RepeatedField<MappingRule>) {
+        self.meta = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_meta(&mut self) -> &mut ::protobuf::RepeatedField<MappingRule> {
+        &mut self.meta
+    }
+
+    // Take field
+    pub fn take_meta(&mut self) -> ::protobuf::RepeatedField<MappingRule> {
+        ::std::mem::replace(&mut self.meta, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for GeneratedCodeInfo {
+    fn is_initialized(&self) -> bool {
+        for v in &self.meta {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.field_type, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.meta)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.field_type != GeneratedCodeInfo_Type::NONE {
+            my_size += ::protobuf::rt::enum_size(1, self.field_type);
+        }
+        for value in &self.meta {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.field_type != GeneratedCodeInfo_Type::NONE {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.field_type))?;
+        }
+        for v in &self.meta {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GeneratedCodeInfo {
+        GeneratedCodeInfo::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<GeneratedCodeInfo_Type>>(
+                "type",
+                |m: &GeneratedCodeInfo| { &m.field_type },
+                |m: &mut GeneratedCodeInfo| { &mut m.field_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<MappingRule>>(
+                "meta",
+                |m: &GeneratedCodeInfo| { &m.meta },
+                |m: &mut GeneratedCodeInfo| { &mut m.meta },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GeneratedCodeInfo>(
+                "GeneratedCodeInfo",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GeneratedCodeInfo {
+        static instance: ::protobuf::rt::LazyV2<GeneratedCodeInfo> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GeneratedCodeInfo::new)
+    }
+}
+
+impl ::protobuf::Clear for GeneratedCodeInfo {
+    fn clear(&mut self) {
+        self.field_type = GeneratedCodeInfo_Type::NONE;
+        self.meta.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GeneratedCodeInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GeneratedCodeInfo {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum GeneratedCodeInfo_Type {
+    NONE = 0,
+    KYTHE0 = 1,
+}
+
+impl ::protobuf::ProtobufEnum for GeneratedCodeInfo_Type {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<GeneratedCodeInfo_Type> {
+        match value {
+            0 => ::std::option::Option::Some(GeneratedCodeInfo_Type::NONE),
+            1 => ::std::option::Option::Some(GeneratedCodeInfo_Type::KYTHE0),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [GeneratedCodeInfo_Type] = &[
+            GeneratedCodeInfo_Type::NONE,
+            GeneratedCodeInfo_Type::KYTHE0,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<GeneratedCodeInfo_Type>("GeneratedCodeInfo.Type", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for GeneratedCodeInfo_Type {
+}
+
+impl ::std::default::Default for GeneratedCodeInfo_Type {
+    fn default() -> Self {
+        GeneratedCodeInfo_Type::NONE
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GeneratedCodeInfo_Type {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MappingRule {
+    // message fields
+    pub field_type: MappingRule_Type,
+    pub edge: ::std::string::String,
+    pub vname: ::protobuf::SingularPtrField<super::storage::VName>,
+    pub begin: u32,
+    pub end: u32,
+    pub semantic: MappingRule_Semantic,
+    pub source_vname: ::protobuf::SingularPtrField<super::storage::VName>,
+    pub source_begin: u32,
+    pub source_end: u32,
+    pub target_begin: u32,
+    pub target_end: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MappingRule {
+    fn default() -> &'a MappingRule {
+        <MappingRule as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MappingRule {
+    pub fn new() -> MappingRule {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.metadata.MappingRule.Type type = 1;
+
+
+    pub fn get_field_type(&self) -> MappingRule_Type {
+        self.field_type
+    }
+    pub fn clear_field_type(&mut self) {
+        self.field_type = MappingRule_Type::NONE;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: MappingRule_Type) {
+        self.field_type = v;
+    }
+
+    // string edge = 2;
+
+
+    pub fn get_edge(&self) -> &str {
+        &self.edge
+    }
+    pub fn clear_edge(&mut self) {
+        self.edge.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_edge(&mut self, v: ::std::string::String) {
+        self.edge = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_edge(&mut self) -> &mut ::std::string::String {
+        &mut self.edge
+    }
+
+    // Take field
+    pub fn take_edge(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.edge, ::std::string::String::new())
+    }
+
+    // .kythe.proto.VName vname = 3;
+
+
+    pub fn get_vname(&self) -> &super::storage::VName {
+        self.vname.as_ref().unwrap_or_else(|| <super::storage::VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_vname(&mut self) {
+        self.vname.clear();
+    }
+
+    pub fn has_vname(&self) -> bool {
+        self.vname.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vname(&mut self, v: super::storage::VName) {
+        self.vname = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_vname(&mut self) -> &mut super::storage::VName {
+        if self.vname.is_none() {
+            self.vname.set_default();
+        }
+        self.vname.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_vname(&mut self) -> super::storage::VName {
+        self.vname.take().unwrap_or_else(|| super::storage::VName::new())
+    }
+
+    // uint32 begin = 4;
+
+
+    pub fn get_begin(&self) -> u32 {
+        self.begin
+    }
+    pub fn clear_begin(&mut self) {
+        self.begin = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_begin(&mut self, v: u32) {
+        self.begin = v;
+    }
+
+    // uint32 end = 5;
+
+
+    pub fn get_end(&self) -> u32 {
+        self.end
+    }
+    pub fn clear_end(&mut self) {
+        self.end = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end(&mut self, v: u32) {
+        self.end = v;
+    }
+
+    // .kythe.proto.metadata.MappingRule.Semantic semantic = 11;
+
+
+    pub fn get_semantic(&self) -> MappingRule_Semantic {
+        self.semantic
+    }
+    pub fn clear_semantic(&mut self) {
+        self.semantic = MappingRule_Semantic::SEMA_NONE;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_semantic(&mut self, v: MappingRule_Semantic) {
+        self.semantic = v;
+    }
+
+    // .kythe.proto.VName source_vname = 6;
+
+
+    pub fn get_source_vname(&self) -> &super::storage::VName {
+        self.source_vname.as_ref().unwrap_or_else(|| <super::storage::VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_source_vname(&mut self) {
+        self.source_vname.clear();
+    }
+
+    pub fn has_source_vname(&self) -> bool {
+        self.source_vname.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source_vname(&mut self, v: super::storage::VName) {
+        self.source_vname = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source_vname(&mut self) -> &mut super::storage::VName {
+        if self.source_vname.is_none() {
+            self.source_vname.set_default();
+        }
+        self.source_vname.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_source_vname(&mut self) -> super::storage::VName {
+        self.source_vname.take().unwrap_or_else(|| super::storage::VName::new())
+    }
+
+    // uint32 source_begin = 7;
+
+
+    pub fn get_source_begin(&self) -> u32 {
+        self.source_begin
+    }
+    pub fn clear_source_begin(&mut self) {
+        self.source_begin = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source_begin(&mut self, v: u32) {
+        self.source_begin = v;
+    }
+
+    // uint32 source_end = 8;
+
+
+    pub fn get_source_end(&self) -> u32 {
+        self.source_end
+    }
+    pub fn clear_source_end(&mut self) {
+        self.source_end = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source_end(&mut self, v: u32) {
+        self.source_end = v;
+    }
+
+    // uint32 target_begin = 9;
+
+
+    pub fn get_target_begin(&self) -> u32 {
+        self.target_begin
+    }
+    pub fn clear_target_begin(&mut self) {
+        self.target_begin = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_begin(&mut self, v: u32) {
+        self.target_begin = v;
+    }
+
+    // uint32 target_end = 10;
+
+
+    pub fn get_target_end(&self) -> u32 {
+        self.target_end
+    }
+    pub fn clear_target_end(&mut self) {
+        self.target_end = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_end(&mut self, v: u32) {
+        self.target_end = v;
+    }
+}
+
+impl ::protobuf::Message for MappingRule {
+    fn is_initialized(&self) -> bool {
+        for v in &self.vname {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.source_vname {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.field_type, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.edge)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.vname)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.begin = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.end = tmp;
+                },
+                11 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.semantic, 11, &mut self.unknown_fields)?
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.source_vname)?;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.source_begin = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.source_end = tmp;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.target_begin = tmp;
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.target_end = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.field_type != MappingRule_Type::NONE {
+            my_size += ::protobuf::rt::enum_size(1, self.field_type);
+        }
+        if !self.edge.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.edge);
+        }
+        if let Some(ref v) = self.vname.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.begin != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.begin, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.end != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.end, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.semantic != MappingRule_Semantic::SEMA_NONE {
+            my_size += ::protobuf::rt::enum_size(11, self.semantic);
+        }
+        if let Some(ref v) = self.source_vname.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.source_begin != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.source_begin, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.source_end != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.source_end, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.target_begin != 0 {
+            my_size += ::protobuf::rt::value_size(9, self.target_begin, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.target_end != 0 {
+            my_size += ::protobuf::rt::value_size(10, self.target_end, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.field_type != MappingRule_Type::NONE {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.field_type))?;
+        }
+        if !self.edge.is_empty() {
+            os.write_string(2, &self.edge)?;
+        }
+        if let Some(ref v) = self.vname.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.begin != 0 {
+            os.write_uint32(4, self.begin)?;
+        }
+        if self.end != 0 {
+            os.write_uint32(5, self.end)?;
+        }
+        if self.semantic != MappingRule_Semantic::SEMA_NONE {
+            os.write_enum(11, ::protobuf::ProtobufEnum::value(&self.semantic))?;
+        }
+        if let Some(ref v) = self.source_vname.as_ref() {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.source_begin != 0 {
+            os.write_uint32(7, self.source_begin)?;
+        }
+        if self.source_end != 0 {
+            os.write_uint32(8, self.source_end)?;
+        }
+        if self.target_begin != 0 {
+            os.write_uint32(9, self.target_begin)?;
+        }
+        if self.target_end != 0 {
+            os.write_uint32(10, self.target_end)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MappingRule {
+        MappingRule::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<MappingRule_Type>>(
+                "type",
+                |m: &MappingRule| { &m.field_type },
+                |m: &mut MappingRule| { &mut m.field_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "edge",
+                |m: &MappingRule| { &m.edge },
+                |m: &mut MappingRule| { &mut m.edge },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::storage::VName>>(
+                "vname",
+                |m: &MappingRule| { &m.vname },
+                |m: &mut MappingRule| { &mut m.vname },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "begin",
+                |m: &MappingRule| { &m.begin },
+                |m: &mut MappingRule| { &mut m.begin },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "end",
+                |m: &MappingRule| { &m.end },
+                |m: &mut MappingRule| { &mut m.end },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<MappingRule_Semantic>>(
+                "semantic",
+                |m: &MappingRule| { &m.semantic },
+                |m: &mut MappingRule| { &mut m.semantic },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::storage::VName>>(
+                "source_vname",
+                |m: &MappingRule| { &m.source_vname },
+                |m: &mut MappingRule| { &mut m.source_vname },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "source_begin",
+                |m: &MappingRule| { &m.source_begin },
+                |m: &mut MappingRule| { &mut m.source_begin },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "source_end",
+                |m: &MappingRule| { &m.source_end },
+                |m: &mut MappingRule| { &mut m.source_end },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "target_begin",
+                |m: &MappingRule| { &m.target_begin },
+                |m: &mut MappingRule| { &mut m.target_begin },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "target_end",
+                |m: &MappingRule| { &m.target_end },
+                |m: &mut MappingRule| { &mut m.target_end },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MappingRule>(
+                "MappingRule",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MappingRule {
+        static instance: ::protobuf::rt::LazyV2<MappingRule> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MappingRule::new)
+    }
+}
+
+impl ::protobuf::Clear for MappingRule {
+    fn clear(&mut self) {
+        self.field_type = MappingRule_Type::NONE;
+        self.edge.clear();
+        self.vname.clear();
+        self.begin = 0;
+        self.end = 0;
+        self.semantic = MappingRule_Semantic::SEMA_NONE;
+        self.source_vname.clear();
+        self.source_begin = 0;
+        self.source_end = 0;
+        self.target_begin = 0;
+        self.target_end = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MappingRule {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MappingRule {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum MappingRule_Type {
+    NONE = 0,
+    NOP = 1,
+    ANCHOR_DEFINES = 2,
+    ANCHOR_ANCHOR = 3,
+}
+
+impl ::protobuf::ProtobufEnum for MappingRule_Type {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<MappingRule_Type> {
+        match value {
+            0 => ::std::option::Option::Some(MappingRule_Type::NONE),
+            1 => ::std::option::Option::Some(MappingRule_Type::NOP),
+            2 => ::std::option::Option::Some(MappingRule_Type::ANCHOR_DEFINES),
+            3 => ::std::option::Option::Some(MappingRule_Type::ANCHOR_ANCHOR),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [MappingRule_Type] = &[
+            MappingRule_Type::NONE,
+            MappingRule_Type::NOP,
+            MappingRule_Type::ANCHOR_DEFINES,
+            MappingRule_Type::ANCHOR_ANCHOR,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<MappingRule_Type>("MappingRule.Type", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for MappingRule_Type {
+}
+
+impl ::std::default::Default for MappingRule_Type {
+    fn default() -> Self {
+        MappingRule_Type::NONE
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MappingRule_Type {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum MappingRule_Semantic {
+    SEMA_NONE = 0,
+    SEMA_WRITE = 1,
+    SEMA_READ_WRITE = 2,
+    SEMA_TAKE_ALIAS = 3,
+}
+
+impl ::protobuf::ProtobufEnum for MappingRule_Semantic {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<MappingRule_Semantic> {
+        match value {
+            0 => ::std::option::Option::Some(MappingRule_Semantic::SEMA_NONE),
+            1 => ::std::option::Option::Some(MappingRule_Semantic::SEMA_WRITE),
+            2 => ::std::option::Option::Some(MappingRule_Semantic::SEMA_READ_WRITE),
+            3 => ::std::option::Option::Some(MappingRule_Semantic::SEMA_TAKE_ALIAS),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [MappingRule_Semantic] = &[
+            MappingRule_Semantic::SEMA_NONE,
+            MappingRule_Semantic::SEMA_WRITE,
+            MappingRule_Semantic::SEMA_READ_WRITE,
+            MappingRule_Semantic::SEMA_TAKE_ALIAS,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<MappingRule_Semantic>("MappingRule.Semantic", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for MappingRule_Semantic {
+}
+
+impl ::std::default::Default for MappingRule_Semantic {
+    fn default() -> Self {
+        MappingRule_Semantic::SEMA_NONE
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MappingRule_Semantic {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x1akythe/proto/metadata.proto\x12\x14kythe.proto.metadata\x1a\x19kyth\
+    e/proto/storage.proto\"\xaa\x01\n\x11GeneratedCodeInfo\x12@\n\x04type\
+    \x18\x01\x20\x01(\x0e2,.kythe.proto.metadata.GeneratedCodeInfo.TypeR\x04\
+    type\x125\n\x04meta\x18\x02\x20\x03(\x0b2!.kythe.proto.metadata.MappingR\
+    uleR\x04meta\"\x1c\n\x04Type\x12\x08\n\x04NONE\x10\0\x12\n\n\x06KYTHE0\
+    \x10\x01\"\xc9\x04\n\x0bMappingRule\x12:\n\x04type\x18\x01\x20\x01(\x0e2\
+    &.kythe.proto.metadata.MappingRule.TypeR\x04type\x12\x12\n\x04edge\x18\
+    \x02\x20\x01(\tR\x04edge\x12(\n\x05vname\x18\x03\x20\x01(\x0b2\x12.kythe\
+    .proto.VNameR\x05vname\x12\x14\n\x05begin\x18\x04\x20\x01(\rR\x05begin\
+    \x12\x10\n\x03end\x18\x05\x20\x01(\rR\x03end\x12F\n\x08semantic\x18\x0b\
+    \x20\x01(\x0e2*.kythe.proto.metadata.MappingRule.SemanticR\x08semantic\
+    \x125\n\x0csource_vname\x18\x06\x20\x01(\x0b2\x12.kythe.proto.VNameR\x0b\
+    sourceVname\x12!\n\x0csource_begin\x18\x07\x20\x01(\rR\x0bsourceBegin\
+    \x12\x1d\n\nsource_end\x18\x08\x20\x01(\rR\tsourceEnd\x12!\n\x0ctarget_b\
+    egin\x18\t\x20\x01(\rR\x0btargetBegin\x12\x1d\n\ntarget_end\x18\n\x20\
+    \x01(\rR\ttargetEnd\"@\n\x04Type\x12\x08\n\x04NONE\x10\0\x12\x07\n\x03NO\
+    P\x10\x01\x12\x12\n\x0eANCHOR_DEFINES\x10\x02\x12\x11\n\rANCHOR_ANCHOR\
+    \x10\x03\"S\n\x08Semantic\x12\r\n\tSEMA_NONE\x10\0\x12\x0e\n\nSEMA_WRITE\
+    \x10\x01\x12\x13\n\x0fSEMA_READ_WRITE\x10\x02\x12\x13\n\x0fSEMA_TAKE_ALI\
+    AS\x10\x03BI\n\x1fcom.google.devtools.kythe.protoZ&kythe.io/kythe/proto/\
+    metadata_go_protob\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
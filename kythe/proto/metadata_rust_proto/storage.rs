@@ -0,0 +1,3237 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `kythe/proto/storage.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct VName {
+    // message fields
+    pub signature: ::std::string::String,
+    pub corpus: ::std::string::String,
+    pub root: ::std::string::String,
+    pub path: ::std::string::String,
+    pub language: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a VName {
+    fn default() -> &'a VName {
+        <VName as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl VName {
+    pub fn new() -> VName {
+        ::std::default::Default::default()
+    }
+
+    // string signature = 1;
+
+
+    pub fn get_signature(&self) -> &str {
+        &self.signature
+    }
+    pub fn clear_signature(&mut self) {
+        self.signature.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signature(&mut self, v: ::std::string::String) {
+        self.signature = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_signature(&mut self) -> &mut ::std::string::String {
+        &mut self.signature
+    }
+
+    // Take field
+    pub fn take_signature(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.signature, ::std::string::String::new())
+    }
+
+    // string corpus = 2;
+
+
+    pub fn get_corpus(&self) -> &str {
+        &self.corpus
+    }
+    pub fn clear_corpus(&mut self) {
+        self.corpus.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_corpus(&mut self, v: ::std::string::String) {
+        self.corpus = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_corpus(&mut self) -> &mut ::std::string::String {
+        &mut self.corpus
+    }
+
+    // Take field
+    pub fn take_corpus(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.corpus, ::std::string::String::new())
+    }
+
+    // string root = 3;
+
+
+    pub fn get_root(&self) -> &str {
+        &self.root
+    }
+    pub fn clear_root(&mut self) {
+        self.root.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_root(&mut self, v: ::std::string::String) {
+        self.root = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_root(&mut self) -> &mut ::std::string::String {
+        &mut self.root
+    }
+
+    // Take field
+    pub fn take_root(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.root, ::std::string::String::new())
+    }
+
+    // string path = 4;
+
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        &mut self.path
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.path, ::std::string::String::new())
+    }
+
+    // string language = 5;
+
+
+    pub fn get_language(&self) -> &str {
+        &self.language
+    }
+    pub fn clear_language(&mut self) {
+        self.language.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_language(&mut self, v: ::std::string::String) {
+        self.language = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_language(&mut self) -> &mut ::std::string::String {
+        &mut self.language
+    }
+
+    // Take field
+    pub fn take_language(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.language, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for VName {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.signature)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.corpus)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.root)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.language)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.signature.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.signature);
+        }
+        if !self.corpus.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.corpus);
+        }
+        if !self.root.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.root);
+        }
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.path);
+        }
+        if !self.language.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.language);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.signature.is_empty() {
+            os.write_string(1, &self.signature)?;
+        }
+        if !self.corpus.is_empty() {
+            os.write_string(2, &self.corpus)?;
+        }
+        if !self.root.is_empty() {
+            os.write_string(3, &self.root)?;
+        }
+        if !self.path.is_empty() {
+            os.write_string(4, &self.path)?;
+        }
+        if !self.language.is_empty() {
+            os.write_string(5, &self.language)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> VName {
+        VName::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "signature",
+                |m: &VName| { &m.signature },
+                |m: &mut VName| { &mut m.signature },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "corpus",
+                |m: &VName| { &m.corpus },
+                |m: &mut VName| { &mut m.corpus },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "root",
+                |m: &VName| { &m.root },
+                |m: &mut VName| { &mut m.root },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "path",
+                |m: &VName| { &m.path },
+                |m: &mut VName| { &mut m.path },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "language",
+                |m: &VName| { &m.language },
+                |m: &mut VName| { &mut m.language },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<VName>(
+                "VName",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static VName {
+        static instance: ::protobuf::rt::LazyV2<VName> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(VName::new)
+    }
+}
+
+impl ::protobuf::Clear for VName {
+    fn clear(&mut self) {
+        self.signature.clear();
+        self.corpus.clear();
+        self.root.clear();
+        self.path.clear();
+        self.language.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for VName {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for VName {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct VNameMask {
+    // message fields
+    pub signature: bool,
+    pub corpus: bool,
+    pub root: bool,
+    pub path: bool,
+    pub language: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a VNameMask {
+    fn default() -> &'a VNameMask {
+        <VNameMask as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl VNameMask {
+    pub fn new() -> VNameMask {
+        ::std::default::Default::default()
+    }
+
+    // bool signature = 1;
+
+
+    pub fn get_signature(&self) -> bool {
+        self.signature
+    }
+    pub fn clear_signature(&mut self) {
+        self.signature = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signature(&mut self, v: bool) {
+        self.signature = v;
+    }
+
+    // bool corpus = 2;
+
+
+    pub fn get_corpus(&self) -> bool {
+        self.corpus
+    }
+    pub fn clear_corpus(&mut self) {
+        self.corpus = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_corpus(&mut self, v: bool) {
+        self.corpus = v;
+    }
+
+    // bool root = 3;
+
+
+    pub fn get_root(&self) -> bool {
+        self.root
+    }
+    pub fn clear_root(&mut self) {
+        self.root = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_root(&mut self, v: bool) {
+        self.root = v;
+    }
+
+    // bool path = 4;
+
+
+    pub fn get_path(&self) -> bool {
+        self.path
+    }
+    pub fn clear_path(&mut self) {
+        self.path = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: bool) {
+        self.path = v;
+    }
+
+    // bool language = 5;
+
+
+    pub fn get_language(&self) -> bool {
+        self.language
+    }
+    pub fn clear_language(&mut self) {
+        self.language = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_language(&mut self, v: bool) {
+        self.language = v;
+    }
+}
+
+impl ::protobuf::Message for VNameMask {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.signature = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.corpus = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.root = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.path = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.language = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.signature != false {
+            my_size += 2;
+        }
+        if self.corpus != false {
+            my_size += 2;
+        }
+        if self.root != false {
+            my_size += 2;
+        }
+        if self.path != false {
+            my_size += 2;
+        }
+        if self.language != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.signature != false {
+            os.write_bool(1, self.signature)?;
+        }
+        if self.corpus != false {
+            os.write_bool(2, self.corpus)?;
+        }
+        if self.root != false {
+            os.write_bool(3, self.root)?;
+        }
+        if self.path != false {
+            os.write_bool(4, self.path)?;
+        }
+        if self.language != false {
+            os.write_bool(5, self.language)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> VNameMask {
+        VNameMask::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "signature",
+                |m: &VNameMask| { &m.signature },
+                |m: &mut VNameMask| { &mut m.signature },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "corpus",
+                |m: &VNameMask| { &m.corpus },
+                |m: &mut VNameMask| { &mut m.corpus },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "root",
+                |m: &VNameMask| { &m.root },
+                |m: &mut VNameMask| { &mut m.root },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "path",
+                |m: &VNameMask| { &m.path },
+                |m: &mut VNameMask| { &mut m.path },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "language",
+                |m: &VNameMask| { &m.language },
+                |m: &mut VNameMask| { &mut m.language },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<VNameMask>(
+                "VNameMask",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static VNameMask {
+        static instance: ::protobuf::rt::LazyV2<VNameMask> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(VNameMask::new)
+    }
+}
+
+impl ::protobuf::Clear for VNameMask {
+    fn clear(&mut self) {
+        self.signature = false;
+        self.corpus = false;
+        self.root = false;
+        self.path = false;
+        self.language = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for VNameMask {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for VNameMask {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Entry {
+    // message fields
+    pub source: ::protobuf::SingularPtrField<VName>,
+    pub edge_kind: ::std::string::String,
+    pub target: ::protobuf::SingularPtrField<VName>,
+    pub fact_name: ::std::string::String,
+    pub fact_value: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Entry {
+    fn default() -> &'a Entry {
+        <Entry as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Entry {
+    pub fn new() -> Entry {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.VName source = 1;
+
+
+    pub fn get_source(&self) -> &VName {
+        self.source.as_ref().unwrap_or_else(|| <VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_source(&mut self) {
+        self.source.clear();
+    }
+
+    pub fn has_source(&self) -> bool {
+        self.source.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source(&mut self, v: VName) {
+        self.source = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source(&mut self) -> &mut VName {
+        if self.source.is_none() {
+            self.source.set_default();
+        }
+        self.source.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_source(&mut self) -> VName {
+        self.source.take().unwrap_or_else(|| VName::new())
+    }
+
+    // string edge_kind = 2;
+
+
+    pub fn get_edge_kind(&self) -> &str {
+        &self.edge_kind
+    }
+    pub fn clear_edge_kind(&mut self) {
+        self.edge_kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_edge_kind(&mut self, v: ::std::string::String) {
+        self.edge_kind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_edge_kind(&mut self) -> &mut ::std::string::String {
+        &mut self.edge_kind
+    }
+
+    // Take field
+    pub fn take_edge_kind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.edge_kind, ::std::string::String::new())
+    }
+
+    // .kythe.proto.VName target = 3;
+
+
+    pub fn get_target(&self) -> &VName {
+        self.target.as_ref().unwrap_or_else(|| <VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: VName) {
+        self.target = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut VName {
+        if self.target.is_none() {
+            self.target.set_default();
+        }
+        self.target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> VName {
+        self.target.take().unwrap_or_else(|| VName::new())
+    }
+
+    // string fact_name = 4;
+
+
+    pub fn get_fact_name(&self) -> &str {
+        &self.fact_name
+    }
+    pub fn clear_fact_name(&mut self) {
+        self.fact_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fact_name(&mut self, v: ::std::string::String) {
+        self.fact_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_fact_name(&mut self) -> &mut ::std::string::String {
+        &mut self.fact_name
+    }
+
+    // Take field
+    pub fn take_fact_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.fact_name, ::std::string::String::new())
+    }
+
+    // bytes fact_value = 5;
+
+
+    pub fn get_fact_value(&self) -> &[u8] {
+        &self.fact_value
+    }
+    pub fn clear_fact_value(&mut self) {
+        self.fact_value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fact_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.fact_value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_fact_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.fact_value
+    }
+
+    // Take field
+    pub fn take_fact_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.fact_value, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for Entry {
+    fn is_initialized(&self) -> bool {
+        for v in &self.source {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.target {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.source)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.edge_kind)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.target)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.fact_name)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.fact_value)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.source.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.edge_kind.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.edge_kind);
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.fact_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.fact_name);
+        }
+        if !self.fact_value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(5, &self.fact_value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.source.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.edge_kind.is_empty() {
+            os.write_string(2, &self.edge_kind)?;
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.fact_name.is_empty() {
+            os.write_string(4, &self.fact_name)?;
+        }
+        if !self.fact_value.is_empty() {
+            os.write_bytes(5, &self.fact_value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Entry {
+        Entry::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<VName>>(
+                "source",
+                |m: &Entry| { &m.source },
+                |m: &mut Entry| { &mut m.source },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "edge_kind",
+                |m: &Entry| { &m.edge_kind },
+                |m: &mut Entry| { &mut m.edge_kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<VName>>(
+                "target",
+                |m: &Entry| { &m.target },
+                |m: &mut Entry| { &mut m.target },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "fact_name",
+                |m: &Entry| { &m.fact_name },
+                |m: &mut Entry| { &mut m.fact_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "fact_value",
+                |m: &Entry| { &m.fact_value },
+                |m: &mut Entry| { &mut m.fact_value },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Entry>(
+                "Entry",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Entry {
+        static instance: ::protobuf::rt::LazyV2<Entry> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Entry::new)
+    }
+}
+
+impl ::protobuf::Clear for Entry {
+    fn clear(&mut self) {
+        self.source.clear();
+        self.edge_kind.clear();
+        self.target.clear();
+        self.fact_name.clear();
+        self.fact_value.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Entry {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Entry {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Entries {
+    // message fields
+    pub entries: ::protobuf::RepeatedField<Entry>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Entries {
+    fn default() -> &'a Entries {
+        <Entries as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Entries {
+    pub fn new() -> Entries {
+        ::std::default::Default::default()
+    }
+
+    // repeated .kythe.proto.Entry entries = 1;
+
+
+    pub fn get_entries(&self) -> &[Entry] {
+        &self.entries
+    }
+    pub fn clear_entries(&mut self) {
+        self.entries.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_entries(&mut self, v: ::protobuf::RepeatedField<Entry>) {
+        self.entries = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_entries(&mut self) -> &mut ::protobuf::RepeatedField<Entry> {
+        &mut self.entries
+    }
+
+    // Take field
+    pub fn take_entries(&mut self) -> ::protobuf::RepeatedField<Entry> {
+        ::std::mem::replace(&mut self.entries, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Entries {
+    fn is_initialized(&self) -> bool {
+        for v in &self.entries {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.entries)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.entries {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.entries {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Entries {
+        Entries::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Entry>>(
+                "entries",
+                |m: &Entries| { &m.entries },
+                |m: &mut Entries| { &mut m.entries },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Entries>(
+                "Entries",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Entries {
+        static instance: ::protobuf::rt::LazyV2<Entries> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Entries::new)
+    }
+}
+
+impl ::protobuf::Clear for Entries {
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Entries {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Entries {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ReadRequest {
+    // message fields
+    pub source: ::protobuf::SingularPtrField<VName>,
+    pub edge_kind: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ReadRequest {
+    fn default() -> &'a ReadRequest {
+        <ReadRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReadRequest {
+    pub fn new() -> ReadRequest {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.VName source = 1;
+
+
+    pub fn get_source(&self) -> &VName {
+        self.source.as_ref().unwrap_or_else(|| <VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_source(&mut self) {
+        self.source.clear();
+    }
+
+    pub fn has_source(&self) -> bool {
+        self.source.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source(&mut self, v: VName) {
+        self.source = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source(&mut self) -> &mut VName {
+        if self.source.is_none() {
+            self.source.set_default();
+        }
+        self.source.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_source(&mut self) -> VName {
+        self.source.take().unwrap_or_else(|| VName::new())
+    }
+
+    // string edge_kind = 2;
+
+
+    pub fn get_edge_kind(&self) -> &str {
+        &self.edge_kind
+    }
+    pub fn clear_edge_kind(&mut self) {
+        self.edge_kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_edge_kind(&mut self, v: ::std::string::String) {
+        self.edge_kind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_edge_kind(&mut self) -> &mut ::std::string::String {
+        &mut self.edge_kind
+    }
+
+    // Take field
+    pub fn take_edge_kind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.edge_kind, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ReadRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.source {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.source)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.edge_kind)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.source.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.edge_kind.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.edge_kind);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.source.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.edge_kind.is_empty() {
+            os.write_string(2, &self.edge_kind)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ReadRequest {
+        ReadRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<VName>>(
+                "source",
+                |m: &ReadRequest| { &m.source },
+                |m: &mut ReadRequest| { &mut m.source },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "edge_kind",
+                |m: &ReadRequest| { &m.edge_kind },
+                |m: &mut ReadRequest| { &mut m.edge_kind },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ReadRequest>(
+                "ReadRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ReadRequest {
+        static instance: ::protobuf::rt::LazyV2<ReadRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ReadRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ReadRequest {
+    fn clear(&mut self) {
+        self.source.clear();
+        self.edge_kind.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ReadRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReadRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WriteRequest {
+    // message fields
+    pub source: ::protobuf::SingularPtrField<VName>,
+    pub update: ::protobuf::RepeatedField<WriteRequest_Update>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a WriteRequest {
+    fn default() -> &'a WriteRequest {
+        <WriteRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WriteRequest {
+    pub fn new() -> WriteRequest {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.VName source = 1;
+
+
+    pub fn get_source(&self) -> &VName {
+        self.source.as_ref().unwrap_or_else(|| <VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_source(&mut self) {
+        self.source.clear();
+    }
+
+    pub fn has_source(&self) -> bool {
+        self.source.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source(&mut self, v: VName) {
+        self.source = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source(&mut self) -> &mut VName {
+        if self.source.is_none() {
+            self.source.set_default();
+        }
+        self.source.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_source(&mut self) -> VName {
+        self.source.take().unwrap_or_else(|| VName::new())
+    }
+
+    // repeated .kythe.proto.WriteRequest.Update update = 2;
+
+
+    pub fn get_update(&self) -> &[WriteRequest_Update] {
+        &self.update
+    }
+    pub fn clear_update(&mut self) {
+        self.update.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_update(&mut self, v: ::protobuf::RepeatedField<WriteRequest_Update>) {
+        self.update = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_update(&mut self) -> &mut ::protobuf::RepeatedField<WriteRequest_Update> {
+        &mut self.update
+    }
+
+    // Take field
+    pub fn take_update(&mut self) -> ::protobuf::RepeatedField<WriteRequest_Update> {
+        ::std::mem::replace(&mut self.update, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for WriteRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.source {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.update {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.source)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.update)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.source.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.update {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.source.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.update {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WriteRequest {
+        WriteRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<VName>>(
+                "source",
+                |m: &WriteRequest| { &m.source },
+                |m: &mut WriteRequest| { &mut m.source },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<WriteRequest_Update>>(
+                "update",
+                |m: &WriteRequest| { &m.update },
+                |m: &mut WriteRequest| { &mut m.update },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WriteRequest>(
+                "WriteRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WriteRequest {
+        static instance: ::protobuf::rt::LazyV2<WriteRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WriteRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for WriteRequest {
+    fn clear(&mut self) {
+        self.source.clear();
+        self.update.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WriteRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WriteRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WriteRequest_Update {
+    // message fields
+    pub edge_kind: ::std::string::String,
+    pub target: ::protobuf::SingularPtrField<VName>,
+    pub fact_name: ::std::string::String,
+    pub fact_value: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a WriteRequest_Update {
+    fn default() -> &'a WriteRequest_Update {
+        <WriteRequest_Update as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WriteRequest_Update {
+    pub fn new() -> WriteRequest_Update {
+        ::std::default::Default::default()
+    }
+
+    // string edge_kind = 1;
+
+
+    pub fn get_edge_kind(&self) -> &str {
+        &self.edge_kind
+    }
+    pub fn clear_edge_kind(&mut self) {
+        self.edge_kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_edge_kind(&mut self, v: ::std::string::String) {
+        self.edge_kind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_edge_kind(&mut self) -> &mut ::std::string::String {
+        &mut self.edge_kind
+    }
+
+    // Take field
+    pub fn take_edge_kind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.edge_kind, ::std::string::String::new())
+    }
+
+    // .kythe.proto.VName target = 2;
+
+
+    pub fn get_target(&self) -> &VName {
+        self.target.as_ref().unwrap_or_else(|| <VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: VName) {
+        self.target = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut VName {
+        if self.target.is_none() {
+            self.target.set_default();
+        }
+        self.target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> VName {
+        self.target.take().unwrap_or_else(|| VName::new())
+    }
+
+    // string fact_name = 3;
+
+
+    pub fn get_fact_name(&self) -> &str {
+        &self.fact_name
+    }
+    pub fn clear_fact_name(&mut self) {
+        self.fact_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fact_name(&mut self, v: ::std::string::String) {
+        self.fact_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_fact_name(&mut self) -> &mut ::std::string::String {
+        &mut self.fact_name
+    }
+
+    // Take field
+    pub fn take_fact_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.fact_name, ::std::string::String::new())
+    }
+
+    // bytes fact_value = 4;
+
+
+    pub fn get_fact_value(&self) -> &[u8] {
+        &self.fact_value
+    }
+    pub fn clear_fact_value(&mut self) {
+        self.fact_value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fact_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.fact_value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_fact_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.fact_value
+    }
+
+    // Take field
+    pub fn take_fact_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.fact_value, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for WriteRequest_Update {
+    fn is_initialized(&self) -> bool {
+        for v in &self.target {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.edge_kind)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.target)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.fact_name)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.fact_value)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.edge_kind.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.edge_kind);
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.fact_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.fact_name);
+        }
+        if !self.fact_value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.fact_value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.edge_kind.is_empty() {
+            os.write_string(1, &self.edge_kind)?;
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.fact_name.is_empty() {
+            os.write_string(3, &self.fact_name)?;
+        }
+        if !self.fact_value.is_empty() {
+            os.write_bytes(4, &self.fact_value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WriteRequest_Update {
+        WriteRequest_Update::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "edge_kind",
+                |m: &WriteRequest_Update| { &m.edge_kind },
+                |m: &mut WriteRequest_Update| { &mut m.edge_kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<VName>>(
+                "target",
+                |m: &WriteRequest_Update| { &m.target },
+                |m: &mut WriteRequest_Update| { &mut m.target },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "fact_name",
+                |m: &WriteRequest_Update| { &m.fact_name },
+                |m: &mut WriteRequest_Update| { &mut m.fact_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "fact_value",
+                |m: &WriteRequest_Update| { &m.fact_value },
+                |m: &mut WriteRequest_Update| { &mut m.fact_value },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WriteRequest_Update>(
+                "WriteRequest.Update",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WriteRequest_Update {
+        static instance: ::protobuf::rt::LazyV2<WriteRequest_Update> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WriteRequest_Update::new)
+    }
+}
+
+impl ::protobuf::Clear for WriteRequest_Update {
+    fn clear(&mut self) {
+        self.edge_kind.clear();
+        self.target.clear();
+        self.fact_name.clear();
+        self.fact_value.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WriteRequest_Update {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WriteRequest_Update {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WriteReply {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a WriteReply {
+    fn default() -> &'a WriteReply {
+        <WriteReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WriteReply {
+    pub fn new() -> WriteReply {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for WriteReply {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WriteReply {
+        WriteReply::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WriteReply>(
+                "WriteReply",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WriteReply {
+        static instance: ::protobuf::rt::LazyV2<WriteReply> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WriteReply::new)
+    }
+}
+
+impl ::protobuf::Clear for WriteReply {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WriteReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WriteReply {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ScanRequest {
+    // message fields
+    pub target: ::protobuf::SingularPtrField<VName>,
+    pub edge_kind: ::std::string::String,
+    pub fact_prefix: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ScanRequest {
+    fn default() -> &'a ScanRequest {
+        <ScanRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ScanRequest {
+    pub fn new() -> ScanRequest {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.VName target = 1;
+
+
+    pub fn get_target(&self) -> &VName {
+        self.target.as_ref().unwrap_or_else(|| <VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: VName) {
+        self.target = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut VName {
+        if self.target.is_none() {
+            self.target.set_default();
+        }
+        self.target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> VName {
+        self.target.take().unwrap_or_else(|| VName::new())
+    }
+
+    // string edge_kind = 2;
+
+
+    pub fn get_edge_kind(&self) -> &str {
+        &self.edge_kind
+    }
+    pub fn clear_edge_kind(&mut self) {
+        self.edge_kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_edge_kind(&mut self, v: ::std::string::String) {
+        self.edge_kind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_edge_kind(&mut self) -> &mut ::std::string::String {
+        &mut self.edge_kind
+    }
+
+    // Take field
+    pub fn take_edge_kind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.edge_kind, ::std::string::String::new())
+    }
+
+    // string fact_prefix = 3;
+
+
+    pub fn get_fact_prefix(&self) -> &str {
+        &self.fact_prefix
+    }
+    pub fn clear_fact_prefix(&mut self) {
+        self.fact_prefix.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fact_prefix(&mut self, v: ::std::string::String) {
+        self.fact_prefix = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_fact_prefix(&mut self) -> &mut ::std::string::String {
+        &mut self.fact_prefix
+    }
+
+    // Take field
+    pub fn take_fact_prefix(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.fact_prefix, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ScanRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.target {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.target)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.edge_kind)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.fact_prefix)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.target.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.edge_kind.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.edge_kind);
+        }
+        if !self.fact_prefix.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.fact_prefix);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.target.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.edge_kind.is_empty() {
+            os.write_string(2, &self.edge_kind)?;
+        }
+        if !self.fact_prefix.is_empty() {
+            os.write_string(3, &self.fact_prefix)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ScanRequest {
+        ScanRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<VName>>(
+                "target",
+                |m: &ScanRequest| { &m.target },
+                |m: &mut ScanRequest| { &mut m.target },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "edge_kind",
+                |m: &ScanRequest| { &m.edge_kind },
+                |m: &mut ScanRequest| { &mut m.edge_kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "fact_prefix",
+                |m: &ScanRequest| { &m.fact_prefix },
+                |m: &mut ScanRequest| { &mut m.fact_prefix },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ScanRequest>(
+                "ScanRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ScanRequest {
+        static instance: ::protobuf::rt::LazyV2<ScanRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ScanRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ScanRequest {
+    fn clear(&mut self) {
+        self.target.clear();
+        self.edge_kind.clear();
+        self.fact_prefix.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ScanRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ScanRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CountRequest {
+    // message fields
+    pub index: i64,
+    pub shards: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CountRequest {
+    fn default() -> &'a CountRequest {
+        <CountRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CountRequest {
+    pub fn new() -> CountRequest {
+        ::std::default::Default::default()
+    }
+
+    // int64 index = 1;
+
+
+    pub fn get_index(&self) -> i64 {
+        self.index
+    }
+    pub fn clear_index(&mut self) {
+        self.index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: i64) {
+        self.index = v;
+    }
+
+    // int64 shards = 2;
+
+
+    pub fn get_shards(&self) -> i64 {
+        self.shards
+    }
+    pub fn clear_shards(&mut self) {
+        self.shards = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_shards(&mut self, v: i64) {
+        self.shards = v;
+    }
+}
+
+impl ::protobuf::Message for CountRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.index = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.shards = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.index != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.shards != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.shards, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.index != 0 {
+            os.write_int64(1, self.index)?;
+        }
+        if self.shards != 0 {
+            os.write_int64(2, self.shards)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CountRequest {
+        CountRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "index",
+                |m: &CountRequest| { &m.index },
+                |m: &mut CountRequest| { &mut m.index },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "shards",
+                |m: &CountRequest| { &m.shards },
+                |m: &mut CountRequest| { &mut m.shards },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CountRequest>(
+                "CountRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CountRequest {
+        static instance: ::protobuf::rt::LazyV2<CountRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CountRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for CountRequest {
+    fn clear(&mut self) {
+        self.index = 0;
+        self.shards = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CountRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CountRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CountReply {
+    // message fields
+    pub entries: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CountReply {
+    fn default() -> &'a CountReply {
+        <CountReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CountReply {
+    pub fn new() -> CountReply {
+        ::std::default::Default::default()
+    }
+
+    // int64 entries = 1;
+
+
+    pub fn get_entries(&self) -> i64 {
+        self.entries
+    }
+    pub fn clear_entries(&mut self) {
+        self.entries = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_entries(&mut self, v: i64) {
+        self.entries = v;
+    }
+}
+
+impl ::protobuf::Message for CountReply {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.entries = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.entries != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.entries, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.entries != 0 {
+            os.write_int64(1, self.entries)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CountReply {
+        CountReply::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "entries",
+                |m: &CountReply| { &m.entries },
+                |m: &mut CountReply| { &mut m.entries },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CountReply>(
+                "CountReply",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CountReply {
+        static instance: ::protobuf::rt::LazyV2<CountReply> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CountReply::new)
+    }
+}
+
+impl ::protobuf::Clear for CountReply {
+    fn clear(&mut self) {
+        self.entries = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CountReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CountReply {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ShardRequest {
+    // message fields
+    pub index: i64,
+    pub shards: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ShardRequest {
+    fn default() -> &'a ShardRequest {
+        <ShardRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ShardRequest {
+    pub fn new() -> ShardRequest {
+        ::std::default::Default::default()
+    }
+
+    // int64 index = 1;
+
+
+    pub fn get_index(&self) -> i64 {
+        self.index
+    }
+    pub fn clear_index(&mut self) {
+        self.index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: i64) {
+        self.index = v;
+    }
+
+    // int64 shards = 2;
+
+
+    pub fn get_shards(&self) -> i64 {
+        self.shards
+    }
+    pub fn clear_shards(&mut self) {
+        self.shards = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_shards(&mut self, v: i64) {
+        self.shards = v;
+    }
+}
+
+impl ::protobuf::Message for ShardRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.index = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.shards = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.index != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.shards != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.shards, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.index != 0 {
+            os.write_int64(1, self.index)?;
+        }
+        if self.shards != 0 {
+            os.write_int64(2, self.shards)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ShardRequest {
+        ShardRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "index",
+                |m: &ShardRequest| { &m.index },
+                |m: &mut ShardRequest| { &mut m.index },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "shards",
+                |m: &ShardRequest| { &m.shards },
+                |m: &mut ShardRequest| { &mut m.shards },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ShardRequest>(
+                "ShardRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ShardRequest {
+        static instance: ::protobuf::rt::LazyV2<ShardRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ShardRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ShardRequest {
+    fn clear(&mut self) {
+        self.index = 0;
+        self.shards = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ShardRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ShardRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct VNameRewriteRule {
+    // message fields
+    pub pattern: ::std::string::String,
+    pub v_name: ::protobuf::SingularPtrField<VName>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a VNameRewriteRule {
+    fn default() -> &'a VNameRewriteRule {
+        <VNameRewriteRule as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl VNameRewriteRule {
+    pub fn new() -> VNameRewriteRule {
+        ::std::default::Default::default()
+    }
+
+    // string pattern = 1;
+
+
+    pub fn get_pattern(&self) -> &str {
+        &self.pattern
+    }
+    pub fn clear_pattern(&mut self) {
+        self.pattern.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pattern(&mut self, v: ::std::string::String) {
+        self.pattern = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_pattern(&mut self) -> &mut ::std::string::String {
+        &mut self.pattern
+    }
+
+    // Take field
+    pub fn take_pattern(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.pattern, ::std::string::String::new())
+    }
+
+    // .kythe.proto.VName v_name = 2;
+
+
+    pub fn get_v_name(&self) -> &VName {
+        self.v_name.as_ref().unwrap_or_else(|| <VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_v_name(&mut self) {
+        self.v_name.clear();
+    }
+
+    pub fn has_v_name(&self) -> bool {
+        self.v_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_v_name(&mut self, v: VName) {
+        self.v_name = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_v_name(&mut self) -> &mut VName {
+        if self.v_name.is_none() {
+            self.v_name.set_default();
+        }
+        self.v_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_v_name(&mut self) -> VName {
+        self.v_name.take().unwrap_or_else(|| VName::new())
+    }
+}
+
+impl ::protobuf::Message for VNameRewriteRule {
+    fn is_initialized(&self) -> bool {
+        for v in &self.v_name {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.pattern)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.v_name)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.pattern.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.pattern);
+        }
+        if let Some(ref v) = self.v_name.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.pattern.is_empty() {
+            os.write_string(1, &self.pattern)?;
+        }
+        if let Some(ref v) = self.v_name.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> VNameRewriteRule {
+        VNameRewriteRule::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "pattern",
+                |m: &VNameRewriteRule| { &m.pattern },
+                |m: &mut VNameRewriteRule| { &mut m.pattern },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<VName>>(
+                "v_name",
+                |m: &VNameRewriteRule| { &m.v_name },
+                |m: &mut VNameRewriteRule| { &mut m.v_name },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<VNameRewriteRule>(
+                "VNameRewriteRule",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static VNameRewriteRule {
+        static instance: ::protobuf::rt::LazyV2<VNameRewriteRule> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(VNameRewriteRule::new)
+    }
+}
+
+impl ::protobuf::Clear for VNameRewriteRule {
+    fn clear(&mut self) {
+        self.pattern.clear();
+        self.v_name.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for VNameRewriteRule {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for VNameRewriteRule {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct VNameRewriteRules {
+    // message fields
+    pub rule: ::protobuf::RepeatedField<VNameRewriteRule>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a VNameRewriteRules {
+    fn default() -> &'a VNameRewriteRules {
+        <VNameRewriteRules as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl VNameRewriteRules {
+    pub fn new() -> VNameRewriteRules {
+        ::std::default::Default::default()
+    }
+
+    // repeated .kythe.proto.VNameRewriteRule rule = 1;
+
+
+    pub fn get_rule(&self) -> &[VNameRewriteRule] {
+        &self.rule
+    }
+    pub fn clear_rule(&mut self) {
+        self.rule.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rule(&mut self, v: ::protobuf::RepeatedField<VNameRewriteRule>) {
+        self.rule = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_rule(&mut self) -> &mut ::protobuf::RepeatedField<VNameRewriteRule> {
+        &mut self.rule
+    }
+
+    // Take field
+    pub fn take_rule(&mut self) -> ::protobuf::RepeatedField<VNameRewriteRule> {
+        ::std::mem::replace(&mut self.rule, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for VNameRewriteRules {
+    fn is_initialized(&self) -> bool {
+        for v in &self.rule {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.rule)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.rule {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.rule {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> VNameRewriteRules {
+        VNameRewriteRules::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<VNameRewriteRule>>(
+                "rule",
+                |m: &VNameRewriteRules| { &m.rule },
+                |m: &mut VNameRewriteRules| { &mut m.rule },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<VNameRewriteRules>(
+                "VNameRewriteRules",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static VNameRewriteRules {
+        static instance: ::protobuf::rt::LazyV2<VNameRewriteRules> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(VNameRewriteRules::new)
+    }
+}
+
+impl ::protobuf::Clear for VNameRewriteRules {
+    fn clear(&mut self) {
+        self.rule.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for VNameRewriteRules {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for VNameRewriteRules {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x19kythe/proto/storage.proto\x12\x0bkythe.proto\"\x81\x01\n\x05VName\
+    \x12\x1c\n\tsignature\x18\x01\x20\x01(\tR\tsignature\x12\x16\n\x06corpus\
+    \x18\x02\x20\x01(\tR\x06corpus\x12\x12\n\x04root\x18\x03\x20\x01(\tR\x04\
+    root\x12\x12\n\x04path\x18\x04\x20\x01(\tR\x04path\x12\x1a\n\x08language\
+    \x18\x05\x20\x01(\tR\x08language\"\x85\x01\n\tVNameMask\x12\x1c\n\tsigna\
+    ture\x18\x01\x20\x01(\x08R\tsignature\x12\x16\n\x06corpus\x18\x02\x20\
+    \x01(\x08R\x06corpus\x12\x12\n\x04root\x18\x03\x20\x01(\x08R\x04root\x12\
+    \x12\n\x04path\x18\x04\x20\x01(\x08R\x04path\x12\x1a\n\x08language\x18\
+    \x05\x20\x01(\x08R\x08language\"\xb8\x01\n\x05Entry\x12*\n\x06source\x18\
+    \x01\x20\x01(\x0b2\x12.kythe.proto.VNameR\x06source\x12\x1b\n\tedge_kind\
+    \x18\x02\x20\x01(\tR\x08edgeKind\x12*\n\x06target\x18\x03\x20\x01(\x0b2\
+    \x12.kythe.proto.VNameR\x06target\x12\x1b\n\tfact_name\x18\x04\x20\x01(\
+    \tR\x08factName\x12\x1d\n\nfact_value\x18\x05\x20\x01(\x0cR\tfactValue\"\
+    7\n\x07Entries\x12,\n\x07entries\x18\x01\x20\x03(\x0b2\x12.kythe.proto.E\
+    ntryR\x07entries\"V\n\x0bReadRequest\x12*\n\x06source\x18\x01\x20\x01(\
+    \x0b2\x12.kythe.proto.VNameR\x06source\x12\x1b\n\tedge_kind\x18\x02\x20\
+    \x01(\tR\x08edgeKind\"\x84\x02\n\x0cWriteRequest\x12*\n\x06source\x18\
+    \x01\x20\x01(\x0b2\x12.kythe.proto.VNameR\x06source\x128\n\x06update\x18\
+    \x02\x20\x03(\x0b2\x20.kythe.proto.WriteRequest.UpdateR\x06update\x1a\
+    \x8d\x01\n\x06Update\x12\x1b\n\tedge_kind\x18\x01\x20\x01(\tR\x08edgeKin\
+    d\x12*\n\x06target\x18\x02\x20\x01(\x0b2\x12.kythe.proto.VNameR\x06targe\
+    t\x12\x1b\n\tfact_name\x18\x03\x20\x01(\tR\x08factName\x12\x1d\n\nfact_v\
+    alue\x18\x04\x20\x01(\x0cR\tfactValue\"\x0c\n\nWriteReply\"w\n\x0bScanRe\
+    quest\x12*\n\x06target\x18\x01\x20\x01(\x0b2\x12.kythe.proto.VNameR\x06t\
+    arget\x12\x1b\n\tedge_kind\x18\x02\x20\x01(\tR\x08edgeKind\x12\x1f\n\x0b\
+    fact_prefix\x18\x03\x20\x01(\tR\nfactPrefix\"<\n\x0cCountRequest\x12\x14\
+    \n\x05index\x18\x01\x20\x01(\x03R\x05index\x12\x16\n\x06shards\x18\x02\
+    \x20\x01(\x03R\x06shards\"&\n\nCountReply\x12\x18\n\x07entries\x18\x01\
+    \x20\x01(\x03R\x07entries\"<\n\x0cShardRequest\x12\x14\n\x05index\x18\
+    \x01\x20\x01(\x03R\x05index\x12\x16\n\x06shards\x18\x02\x20\x01(\x03R\
+    \x06shards\"W\n\x10VNameRewriteRule\x12\x18\n\x07pattern\x18\x01\x20\x01\
+    (\tR\x07pattern\x12)\n\x06v_name\x18\x02\x20\x01(\x0b2\x12.kythe.proto.V\
+    NameR\x05vname\"F\n\x11VNameRewriteRules\x121\n\x04rule\x18\x01\x20\x03(\
+    \x0b2\x1d.kythe.proto.VNameRewriteRuleR\x04ruleBH\n\x1fcom.google.devtoo\
+    ls.kythe.protoZ%kythe.io/kythe/proto/storage_go_protob\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
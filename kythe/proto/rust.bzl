@@ -1,8 +1,18 @@
 load("@aspect_bazel_lib//lib:write_source_files.bzl", "write_source_file")
 load("@aspect_bazel_lib//lib:copy_to_directory.bzl", "copy_to_directory")
 load("@rules_rust//proto/protobuf:defs.bzl", _rust_proto_library = "rust_proto_library")
+load("@rules_rust//rust:defs.bzl", "rust_library")
 load("@rules_rust//rust:rust_common.bzl", "CrateInfo")
 
+# Runtime dependencies of the crates generated by //kythe/rust/protogen.
+_PROST_DEPS = [
+    "@crate_index//:pbjson",
+    "@crate_index//:pbjson-types",
+    "@crate_index//:prost",
+    "@crate_index//:serde",
+    "@crate_index//:tonic",
+]
+
 def _rust_proto_sources_impl(ctx):
     return [DefaultInfo(files = ctx.attr.crate[CrateInfo].srcs)]
 
@@ -17,15 +27,43 @@ _rust_proto_sources = rule(
     },
 )
 
-def rust_proto_library(name, **kwargs):
-    _rust_proto_library(name = name, **kwargs)
-    _rust_proto_sources(
-        name = name + "_src",
-        crate = name,
+def _rust_prost_sources_impl(ctx):
+    out = ctx.actions.declare_file(ctx.label.name + "/lib.rs")
+    direct = [dep[ProtoInfo].direct_descriptor_set for dep in ctx.attr.deps]
+    transitive = depset(transitive = [dep[ProtoInfo].transitive_descriptor_sets for dep in ctx.attr.deps])
+    args = ctx.actions.args()
+    args.add("--output", out)
+    args.add_all(transitive, before_each = "--descriptor_set")
+    args.add_all(direct, before_each = "--direct_descriptor_set")
+    ctx.actions.run(
+        outputs = [out],
+        inputs = depset(direct, transitive = [transitive]),
+        executable = ctx.executable._protogen,
+        arguments = [args],
+        mnemonic = "RustProtogen",
+        progress_message = "Generating Rust protos for %{label}",
     )
+    return [DefaultInfo(files = depset([out]))]
+
+_rust_prost_sources = rule(
+    implementation = _rust_prost_sources_impl,
+    attrs = {
+        "deps": attr.label_list(
+            mandatory = True,
+            providers = [ProtoInfo],
+        ),
+        "_protogen": attr.label(
+            default = "//kythe/rust/protogen:protogen_tool",
+            executable = True,
+            cfg = "exec",
+        ),
+    },
+)
+
+def _sync_sources(name, srcs):
     copy_to_directory(
         name = name + "_dir",
-        srcs = [name + "_src"],
+        srcs = srcs,
         out = name,
         replace_prefixes = {"*/": ""},
     )
@@ -37,3 +75,43 @@ def rust_proto_library(name, **kwargs):
         # and rust_proto_library rule target precludes automated testing.
         diff_test = False,
     )
+
+def rust_proto_library(name, codegen = "protobuf", **kwargs):
+    """Generates a Rust crate for the proto_library targets in deps.
+
+    Args:
+      name: the name of the target, which is also the crate name.
+      codegen: "protobuf" to generate the crate with rust-protobuf 2, or
+        "prost" to generate it with //kythe/rust/protogen, whose messages have
+        plain Option and Vec fields, implement serde traits and come with
+        tonic gRPC stubs. A prost crate is a separate target next to the
+        rust-protobuf one (e.g. storage_rust_prost_proto beside
+        storage_rust_proto), so that users can adopt it one at a time;
+        //kythe/rust/protocompat converts messages between the two.
+      **kwargs: passed on to the underlying crate rules.
+    """
+    if codegen == "prost":
+        _rust_prost_library(name, **kwargs)
+        return
+    if codegen != "protobuf":
+        fail("unknown codegen for %s: %s" % (name, codegen))
+    _rust_proto_library(name = name, **kwargs)
+    _rust_proto_sources(
+        name = name + "_src",
+        crate = name,
+    )
+    _sync_sources(name, [name + "_src"])
+
+def _rust_prost_library(name, deps, **kwargs):
+    _rust_prost_sources(
+        name = name + "_src",
+        deps = deps,
+    )
+    rust_library(
+        name = name,
+        srcs = [name + "_src"],
+        edition = "2021",
+        deps = _PROST_DEPS,
+        **kwargs
+    )
+    _sync_sources(name, [name + "_src"])
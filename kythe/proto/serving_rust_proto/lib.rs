@@ -0,0 +1,7 @@
+extern crate protobuf;
+pub mod timestamp;
+pub use timestamp::*;
+pub mod common;
+pub use common::*;
+pub mod serving;
+pub use serving::*;
\ No newline at end of file
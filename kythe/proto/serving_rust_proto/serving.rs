@@ -0,0 +1,10735 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `kythe/proto/serving.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Node {
+    // message fields
+    pub ticket: ::std::string::String,
+    pub fact: ::protobuf::RepeatedField<super::common::Fact>,
+    pub definition_location: ::protobuf::SingularPtrField<ExpandedAnchor>,
+    pub rank: f64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Node {
+    fn default() -> &'a Node {
+        <Node as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Node {
+    pub fn new() -> Node {
+        ::std::default::Default::default()
+    }
+
+    // string ticket = 1;
+
+
+    pub fn get_ticket(&self) -> &str {
+        &self.ticket
+    }
+    pub fn clear_ticket(&mut self) {
+        self.ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ticket(&mut self, v: ::std::string::String) {
+        self.ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ticket(&mut self) -> &mut ::std::string::String {
+        &mut self.ticket
+    }
+
+    // Take field
+    pub fn take_ticket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.ticket, ::std::string::String::new())
+    }
+
+    // repeated .kythe.proto.common.Fact fact = 2;
+
+
+    pub fn get_fact(&self) -> &[super::common::Fact] {
+        &self.fact
+    }
+    pub fn clear_fact(&mut self) {
+        self.fact.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fact(&mut self, v: ::protobuf::RepeatedField<super::common::Fact>) {
+        self.fact = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_fact(&mut self) -> &mut ::protobuf::RepeatedField<super::common::Fact> {
+        &mut self.fact
+    }
+
+    // Take field
+    pub fn take_fact(&mut self) -> ::protobuf::RepeatedField<super::common::Fact> {
+        ::std::mem::replace(&mut self.fact, ::protobuf::RepeatedField::new())
+    }
+
+    // .kythe.proto.serving.ExpandedAnchor definition_location = 3;
+
+
+    pub fn get_definition_location(&self) -> &ExpandedAnchor {
+        self.definition_location.as_ref().unwrap_or_else(|| <ExpandedAnchor as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_definition_location(&mut self) {
+        self.definition_location.clear();
+    }
+
+    pub fn has_definition_location(&self) -> bool {
+        self.definition_location.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_definition_location(&mut self, v: ExpandedAnchor) {
+        self.definition_location = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_definition_location(&mut self) -> &mut ExpandedAnchor {
+        if self.definition_location.is_none() {
+            self.definition_location.set_default();
+        }
+        self.definition_location.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_definition_location(&mut self) -> ExpandedAnchor {
+        self.definition_location.take().unwrap_or_else(|| ExpandedAnchor::new())
+    }
+
+    // double rank = 4;
+
+
+    pub fn get_rank(&self) -> f64 {
+        self.rank
+    }
+    pub fn clear_rank(&mut self) {
+        self.rank = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rank(&mut self, v: f64) {
+        self.rank = v;
+    }
+}
+
+impl ::protobuf::Message for Node {
+    fn is_initialized(&self) -> bool {
+        for v in &self.fact {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.definition_location {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.ticket)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.fact)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.definition_location)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.rank = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.ticket.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.ticket);
+        }
+        for value in &self.fact {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.definition_location.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.rank != 0. {
+            my_size += 9;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.ticket.is_empty() {
+            os.write_string(1, &self.ticket)?;
+        }
+        for v in &self.fact {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.definition_location.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.rank != 0. {
+            os.write_double(4, self.rank)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Node {
+        Node::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "ticket",
+                |m: &Node| { &m.ticket },
+                |m: &mut Node| { &mut m.ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::Fact>>(
+                "fact",
+                |m: &Node| { &m.fact },
+                |m: &mut Node| { &mut m.fact },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ExpandedAnchor>>(
+                "definition_location",
+                |m: &Node| { &m.definition_location },
+                |m: &mut Node| { &mut m.definition_location },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "rank",
+                |m: &Node| { &m.rank },
+                |m: &mut Node| { &mut m.rank },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Node>(
+                "Node",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Node {
+        static instance: ::protobuf::rt::LazyV2<Node> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Node::new)
+    }
+}
+
+impl ::protobuf::Clear for Node {
+    fn clear(&mut self) {
+        self.ticket.clear();
+        self.fact.clear();
+        self.definition_location.clear();
+        self.rank = 0.;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Node {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Node {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Edge {
+    // message fields
+    pub source: ::protobuf::SingularPtrField<Node>,
+    pub kind: ::std::string::String,
+    pub ordinal: i32,
+    pub target: ::protobuf::SingularPtrField<Node>,
+    pub fact: ::protobuf::RepeatedField<super::common::Fact>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Edge {
+    fn default() -> &'a Edge {
+        <Edge as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Edge {
+    pub fn new() -> Edge {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.serving.Node source = 1;
+
+
+    pub fn get_source(&self) -> &Node {
+        self.source.as_ref().unwrap_or_else(|| <Node as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_source(&mut self) {
+        self.source.clear();
+    }
+
+    pub fn has_source(&self) -> bool {
+        self.source.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source(&mut self, v: Node) {
+        self.source = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source(&mut self) -> &mut Node {
+        if self.source.is_none() {
+            self.source.set_default();
+        }
+        self.source.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_source(&mut self) -> Node {
+        self.source.take().unwrap_or_else(|| Node::new())
+    }
+
+    // string kind = 2;
+
+
+    pub fn get_kind(&self) -> &str {
+        &self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: ::std::string::String) {
+        self.kind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_kind(&mut self) -> &mut ::std::string::String {
+        &mut self.kind
+    }
+
+    // Take field
+    pub fn take_kind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.kind, ::std::string::String::new())
+    }
+
+    // int32 ordinal = 5;
+
+
+    pub fn get_ordinal(&self) -> i32 {
+        self.ordinal
+    }
+    pub fn clear_ordinal(&mut self) {
+        self.ordinal = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ordinal(&mut self, v: i32) {
+        self.ordinal = v;
+    }
+
+    // .kythe.proto.serving.Node target = 3;
+
+
+    pub fn get_target(&self) -> &Node {
+        self.target.as_ref().unwrap_or_else(|| <Node as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: Node) {
+        self.target = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut Node {
+        if self.target.is_none() {
+            self.target.set_default();
+        }
+        self.target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> Node {
+        self.target.take().unwrap_or_else(|| Node::new())
+    }
+
+    // repeated .kythe.proto.common.Fact fact = 4;
+
+
+    pub fn get_fact(&self) -> &[super::common::Fact] {
+        &self.fact
+    }
+    pub fn clear_fact(&mut self) {
+        self.fact.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fact(&mut self, v: ::protobuf::RepeatedField<super::common::Fact>) {
+        self.fact = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_fact(&mut self) -> &mut ::protobuf::RepeatedField<super::common::Fact> {
+        &mut self.fact
+    }
+
+    // Take field
+    pub fn take_fact(&mut self) -> ::protobuf::RepeatedField<super::common::Fact> {
+        ::std::mem::replace(&mut self.fact, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Edge {
+    fn is_initialized(&self) -> bool {
+        for v in &self.source {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.target {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.fact {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.source)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.kind)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.ordinal = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.target)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.fact)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.source.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.kind.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.kind);
+        }
+        if self.ordinal != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.ordinal, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.fact {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.source.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.kind.is_empty() {
+            os.write_string(2, &self.kind)?;
+        }
+        if self.ordinal != 0 {
+            os.write_int32(5, self.ordinal)?;
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.fact {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Edge {
+        Edge::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                "source",
+                |m: &Edge| { &m.source },
+                |m: &mut Edge| { &mut m.source },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "kind",
+                |m: &Edge| { &m.kind },
+                |m: &mut Edge| { &mut m.kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "ordinal",
+                |m: &Edge| { &m.ordinal },
+                |m: &mut Edge| { &mut m.ordinal },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                "target",
+                |m: &Edge| { &m.target },
+                |m: &mut Edge| { &mut m.target },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::Fact>>(
+                "fact",
+                |m: &Edge| { &m.fact },
+                |m: &mut Edge| { &mut m.fact },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Edge>(
+                "Edge",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Edge {
+        static instance: ::protobuf::rt::LazyV2<Edge> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Edge::new)
+    }
+}
+
+impl ::protobuf::Clear for Edge {
+    fn clear(&mut self) {
+        self.source.clear();
+        self.kind.clear();
+        self.ordinal = 0;
+        self.target.clear();
+        self.fact.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Edge {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Edge {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct EdgeGroup {
+    // message fields
+    pub kind: ::std::string::String,
+    pub edge: ::protobuf::RepeatedField<EdgeGroup_Edge>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a EdgeGroup {
+    fn default() -> &'a EdgeGroup {
+        <EdgeGroup as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl EdgeGroup {
+    pub fn new() -> EdgeGroup {
+        ::std::default::Default::default()
+    }
+
+    // string kind = 1;
+
+
+    pub fn get_kind(&self) -> &str {
+        &self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: ::std::string::String) {
+        self.kind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_kind(&mut self) -> &mut ::std::string::String {
+        &mut self.kind
+    }
+
+    // Take field
+    pub fn take_kind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.kind, ::std::string::String::new())
+    }
+
+    // repeated .kythe.proto.serving.EdgeGroup.Edge edge = 2;
+
+
+    pub fn get_edge(&self) -> &[EdgeGroup_Edge] {
+        &self.edge
+    }
+    pub fn clear_edge(&mut self) {
+        self.edge.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_edge(&mut self, v: ::protobuf::RepeatedField<EdgeGroup_Edge>) {
+        self.edge = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_edge(&mut self) -> &mut ::protobuf::RepeatedField<EdgeGroup_Edge> {
+        &mut self.edge
+    }
+
+    // Take field
+    pub fn take_edge(&mut self) -> ::protobuf::RepeatedField<EdgeGroup_Edge> {
+        ::std::mem::replace(&mut self.edge, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for EdgeGroup {
+    fn is_initialized(&self) -> bool {
+        for v in &self.edge {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.kind)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.edge)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.kind.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.kind);
+        }
+        for value in &self.edge {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.kind.is_empty() {
+            os.write_string(1, &self.kind)?;
+        }
+        for v in &self.edge {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> EdgeGroup {
+        EdgeGroup::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "kind",
+                |m: &EdgeGroup| { &m.kind },
+                |m: &mut EdgeGroup| { &mut m.kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<EdgeGroup_Edge>>(
+                "edge",
+                |m: &EdgeGroup| { &m.edge },
+                |m: &mut EdgeGroup| { &mut m.edge },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<EdgeGroup>(
+                "EdgeGroup",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static EdgeGroup {
+        static instance: ::protobuf::rt::LazyV2<EdgeGroup> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(EdgeGroup::new)
+    }
+}
+
+impl ::protobuf::Clear for EdgeGroup {
+    fn clear(&mut self) {
+        self.kind.clear();
+        self.edge.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for EdgeGroup {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EdgeGroup {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct EdgeGroup_Edge {
+    // message fields
+    pub target: ::protobuf::SingularPtrField<Node>,
+    pub ordinal: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a EdgeGroup_Edge {
+    fn default() -> &'a EdgeGroup_Edge {
+        <EdgeGroup_Edge as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl EdgeGroup_Edge {
+    pub fn new() -> EdgeGroup_Edge {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.serving.Node target = 1;
+
+
+    pub fn get_target(&self) -> &Node {
+        self.target.as_ref().unwrap_or_else(|| <Node as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: Node) {
+        self.target = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut Node {
+        if self.target.is_none() {
+            self.target.set_default();
+        }
+        self.target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> Node {
+        self.target.take().unwrap_or_else(|| Node::new())
+    }
+
+    // int32 ordinal = 2;
+
+
+    pub fn get_ordinal(&self) -> i32 {
+        self.ordinal
+    }
+    pub fn clear_ordinal(&mut self) {
+        self.ordinal = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ordinal(&mut self, v: i32) {
+        self.ordinal = v;
+    }
+}
+
+impl ::protobuf::Message for EdgeGroup_Edge {
+    fn is_initialized(&self) -> bool {
+        for v in &self.target {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.target)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.ordinal = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.target.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.ordinal != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.ordinal, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.target.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.ordinal != 0 {
+            os.write_int32(2, self.ordinal)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> EdgeGroup_Edge {
+        EdgeGroup_Edge::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                "target",
+                |m: &EdgeGroup_Edge| { &m.target },
+                |m: &mut EdgeGroup_Edge| { &mut m.target },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "ordinal",
+                |m: &EdgeGroup_Edge| { &m.ordinal },
+                |m: &mut EdgeGroup_Edge| { &mut m.ordinal },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<EdgeGroup_Edge>(
+                "EdgeGroup.Edge",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static EdgeGroup_Edge {
+        static instance: ::protobuf::rt::LazyV2<EdgeGroup_Edge> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(EdgeGroup_Edge::new)
+    }
+}
+
+impl ::protobuf::Clear for EdgeGroup_Edge {
+    fn clear(&mut self) {
+        self.target.clear();
+        self.ordinal = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for EdgeGroup_Edge {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EdgeGroup_Edge {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PagedEdgeSet {
+    // message fields
+    pub source: ::protobuf::SingularPtrField<Node>,
+    pub group: ::protobuf::RepeatedField<EdgeGroup>,
+    pub total_edges: i32,
+    pub page_index: ::protobuf::RepeatedField<PageIndex>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PagedEdgeSet {
+    fn default() -> &'a PagedEdgeSet {
+        <PagedEdgeSet as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PagedEdgeSet {
+    pub fn new() -> PagedEdgeSet {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.serving.Node source = 1;
+
+
+    pub fn get_source(&self) -> &Node {
+        self.source.as_ref().unwrap_or_else(|| <Node as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_source(&mut self) {
+        self.source.clear();
+    }
+
+    pub fn has_source(&self) -> bool {
+        self.source.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source(&mut self, v: Node) {
+        self.source = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source(&mut self) -> &mut Node {
+        if self.source.is_none() {
+            self.source.set_default();
+        }
+        self.source.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_source(&mut self) -> Node {
+        self.source.take().unwrap_or_else(|| Node::new())
+    }
+
+    // repeated .kythe.proto.serving.EdgeGroup group = 2;
+
+
+    pub fn get_group(&self) -> &[EdgeGroup] {
+        &self.group
+    }
+    pub fn clear_group(&mut self) {
+        self.group.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_group(&mut self, v: ::protobuf::RepeatedField<EdgeGroup>) {
+        self.group = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_group(&mut self) -> &mut ::protobuf::RepeatedField<EdgeGroup> {
+        &mut self.group
+    }
+
+    // Take field
+    pub fn take_group(&mut self) -> ::protobuf::RepeatedField<EdgeGroup> {
+        ::std::mem::replace(&mut self.group, ::protobuf::RepeatedField::new())
+    }
+
+    // int32 total_edges = 3;
+
+
+    pub fn get_total_edges(&self) -> i32 {
+        self.total_edges
+    }
+    pub fn clear_total_edges(&mut self) {
+        self.total_edges = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_total_edges(&mut self, v: i32) {
+        self.total_edges = v;
+    }
+
+    // repeated .kythe.proto.serving.PageIndex page_index = 4;
+
+
+    pub fn get_page_index(&self) -> &[PageIndex] {
+        &self.page_index
+    }
+    pub fn clear_page_index(&mut self) {
+        self.page_index.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_page_index(&mut self, v: ::protobuf::RepeatedField<PageIndex>) {
+        self.page_index = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_page_index(&mut self) -> &mut ::protobuf::RepeatedField<PageIndex> {
+        &mut self.page_index
+    }
+
+    // Take field
+    pub fn take_page_index(&mut self) -> ::protobuf::RepeatedField<PageIndex> {
+        ::std::mem::replace(&mut self.page_index, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for PagedEdgeSet {
+    fn is_initialized(&self) -> bool {
+        for v in &self.source {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.group {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.page_index {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.source)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.group)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.total_edges = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.page_index)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.source.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.group {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.total_edges != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.total_edges, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.page_index {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.source.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.group {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.total_edges != 0 {
+            os.write_int32(3, self.total_edges)?;
+        }
+        for v in &self.page_index {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PagedEdgeSet {
+        PagedEdgeSet::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                "source",
+                |m: &PagedEdgeSet| { &m.source },
+                |m: &mut PagedEdgeSet| { &mut m.source },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<EdgeGroup>>(
+                "group",
+                |m: &PagedEdgeSet| { &m.group },
+                |m: &mut PagedEdgeSet| { &mut m.group },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "total_edges",
+                |m: &PagedEdgeSet| { &m.total_edges },
+                |m: &mut PagedEdgeSet| { &mut m.total_edges },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PageIndex>>(
+                "page_index",
+                |m: &PagedEdgeSet| { &m.page_index },
+                |m: &mut PagedEdgeSet| { &mut m.page_index },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PagedEdgeSet>(
+                "PagedEdgeSet",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PagedEdgeSet {
+        static instance: ::protobuf::rt::LazyV2<PagedEdgeSet> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PagedEdgeSet::new)
+    }
+}
+
+impl ::protobuf::Clear for PagedEdgeSet {
+    fn clear(&mut self) {
+        self.source.clear();
+        self.group.clear();
+        self.total_edges = 0;
+        self.page_index.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PagedEdgeSet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PagedEdgeSet {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PageIndex {
+    // message fields
+    pub edge_kind: ::std::string::String,
+    pub edge_count: i32,
+    pub page_key: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PageIndex {
+    fn default() -> &'a PageIndex {
+        <PageIndex as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PageIndex {
+    pub fn new() -> PageIndex {
+        ::std::default::Default::default()
+    }
+
+    // string edge_kind = 1;
+
+
+    pub fn get_edge_kind(&self) -> &str {
+        &self.edge_kind
+    }
+    pub fn clear_edge_kind(&mut self) {
+        self.edge_kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_edge_kind(&mut self, v: ::std::string::String) {
+        self.edge_kind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_edge_kind(&mut self) -> &mut ::std::string::String {
+        &mut self.edge_kind
+    }
+
+    // Take field
+    pub fn take_edge_kind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.edge_kind, ::std::string::String::new())
+    }
+
+    // int32 edge_count = 2;
+
+
+    pub fn get_edge_count(&self) -> i32 {
+        self.edge_count
+    }
+    pub fn clear_edge_count(&mut self) {
+        self.edge_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_edge_count(&mut self, v: i32) {
+        self.edge_count = v;
+    }
+
+    // string page_key = 3;
+
+
+    pub fn get_page_key(&self) -> &str {
+        &self.page_key
+    }
+    pub fn clear_page_key(&mut self) {
+        self.page_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_page_key(&mut self, v: ::std::string::String) {
+        self.page_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_page_key(&mut self) -> &mut ::std::string::String {
+        &mut self.page_key
+    }
+
+    // Take field
+    pub fn take_page_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.page_key, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for PageIndex {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.edge_kind)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.edge_count = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.page_key)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.edge_kind.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.edge_kind);
+        }
+        if self.edge_count != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.edge_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.page_key.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.page_key);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.edge_kind.is_empty() {
+            os.write_string(1, &self.edge_kind)?;
+        }
+        if self.edge_count != 0 {
+            os.write_int32(2, self.edge_count)?;
+        }
+        if !self.page_key.is_empty() {
+            os.write_string(3, &self.page_key)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PageIndex {
+        PageIndex::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "edge_kind",
+                |m: &PageIndex| { &m.edge_kind },
+                |m: &mut PageIndex| { &mut m.edge_kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "edge_count",
+                |m: &PageIndex| { &m.edge_count },
+                |m: &mut PageIndex| { &mut m.edge_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "page_key",
+                |m: &PageIndex| { &m.page_key },
+                |m: &mut PageIndex| { &mut m.page_key },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PageIndex>(
+                "PageIndex",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PageIndex {
+        static instance: ::protobuf::rt::LazyV2<PageIndex> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PageIndex::new)
+    }
+}
+
+impl ::protobuf::Clear for PageIndex {
+    fn clear(&mut self) {
+        self.edge_kind.clear();
+        self.edge_count = 0;
+        self.page_key.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PageIndex {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PageIndex {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct EdgePage {
+    // message fields
+    pub page_key: ::std::string::String,
+    pub source_ticket: ::std::string::String,
+    pub edges_group: ::protobuf::SingularPtrField<EdgeGroup>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a EdgePage {
+    fn default() -> &'a EdgePage {
+        <EdgePage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl EdgePage {
+    pub fn new() -> EdgePage {
+        ::std::default::Default::default()
+    }
+
+    // string page_key = 1;
+
+
+    pub fn get_page_key(&self) -> &str {
+        &self.page_key
+    }
+    pub fn clear_page_key(&mut self) {
+        self.page_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_page_key(&mut self, v: ::std::string::String) {
+        self.page_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_page_key(&mut self) -> &mut ::std::string::String {
+        &mut self.page_key
+    }
+
+    // Take field
+    pub fn take_page_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.page_key, ::std::string::String::new())
+    }
+
+    // string source_ticket = 2;
+
+
+    pub fn get_source_ticket(&self) -> &str {
+        &self.source_ticket
+    }
+    pub fn clear_source_ticket(&mut self) {
+        self.source_ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source_ticket(&mut self, v: ::std::string::String) {
+        self.source_ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source_ticket(&mut self) -> &mut ::std::string::String {
+        &mut self.source_ticket
+    }
+
+    // Take field
+    pub fn take_source_ticket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.source_ticket, ::std::string::String::new())
+    }
+
+    // .kythe.proto.serving.EdgeGroup edges_group = 3;
+
+
+    pub fn get_edges_group(&self) -> &EdgeGroup {
+        self.edges_group.as_ref().unwrap_or_else(|| <EdgeGroup as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_edges_group(&mut self) {
+        self.edges_group.clear();
+    }
+
+    pub fn has_edges_group(&self) -> bool {
+        self.edges_group.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_edges_group(&mut self, v: EdgeGroup) {
+        self.edges_group = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_edges_group(&mut self) -> &mut EdgeGroup {
+        if self.edges_group.is_none() {
+            self.edges_group.set_default();
+        }
+        self.edges_group.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_edges_group(&mut self) -> EdgeGroup {
+        self.edges_group.take().unwrap_or_else(|| EdgeGroup::new())
+    }
+}
+
+impl ::protobuf::Message for EdgePage {
+    fn is_initialized(&self) -> bool {
+        for v in &self.edges_group {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.page_key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.source_ticket)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.edges_group)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.page_key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.page_key);
+        }
+        if !self.source_ticket.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.source_ticket);
+        }
+        if let Some(ref v) = self.edges_group.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.page_key.is_empty() {
+            os.write_string(1, &self.page_key)?;
+        }
+        if !self.source_ticket.is_empty() {
+            os.write_string(2, &self.source_ticket)?;
+        }
+        if let Some(ref v) = self.edges_group.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> EdgePage {
+        EdgePage::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "page_key",
+                |m: &EdgePage| { &m.page_key },
+                |m: &mut EdgePage| { &mut m.page_key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "source_ticket",
+                |m: &EdgePage| { &m.source_ticket },
+                |m: &mut EdgePage| { &mut m.source_ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<EdgeGroup>>(
+                "edges_group",
+                |m: &EdgePage| { &m.edges_group },
+                |m: &mut EdgePage| { &mut m.edges_group },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<EdgePage>(
+                "EdgePage",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static EdgePage {
+        static instance: ::protobuf::rt::LazyV2<EdgePage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(EdgePage::new)
+    }
+}
+
+impl ::protobuf::Clear for EdgePage {
+    fn clear(&mut self) {
+        self.page_key.clear();
+        self.source_ticket.clear();
+        self.edges_group.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for EdgePage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EdgePage {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileDirectory {
+    // message fields
+    pub entry: ::protobuf::RepeatedField<FileDirectory_Entry>,
+    pub subdirectory: ::protobuf::RepeatedField<::std::string::String>,
+    pub file_ticket: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileDirectory {
+    fn default() -> &'a FileDirectory {
+        <FileDirectory as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FileDirectory {
+    pub fn new() -> FileDirectory {
+        ::std::default::Default::default()
+    }
+
+    // repeated .kythe.proto.serving.FileDirectory.Entry entry = 3;
+
+
+    pub fn get_entry(&self) -> &[FileDirectory_Entry] {
+        &self.entry
+    }
+    pub fn clear_entry(&mut self) {
+        self.entry.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_entry(&mut self, v: ::protobuf::RepeatedField<FileDirectory_Entry>) {
+        self.entry = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_entry(&mut self) -> &mut ::protobuf::RepeatedField<FileDirectory_Entry> {
+        &mut self.entry
+    }
+
+    // Take field
+    pub fn take_entry(&mut self) -> ::protobuf::RepeatedField<FileDirectory_Entry> {
+        ::std::mem::replace(&mut self.entry, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string subdirectory = 1;
+
+
+    pub fn get_subdirectory(&self) -> &[::std::string::String] {
+        &self.subdirectory
+    }
+    pub fn clear_subdirectory(&mut self) {
+        self.subdirectory.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_subdirectory(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.subdirectory = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_subdirectory(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.subdirectory
+    }
+
+    // Take field
+    pub fn take_subdirectory(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.subdirectory, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string file_ticket = 2;
+
+
+    pub fn get_file_ticket(&self) -> &[::std::string::String] {
+        &self.file_ticket
+    }
+    pub fn clear_file_ticket(&mut self) {
+        self.file_ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_ticket(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.file_ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_file_ticket(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.file_ticket
+    }
+
+    // Take field
+    pub fn take_file_ticket(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.file_ticket, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for FileDirectory {
+    fn is_initialized(&self) -> bool {
+        for v in &self.entry {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.entry)?;
+                },
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.subdirectory)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.file_ticket)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.entry {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.subdirectory {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in &self.file_ticket {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.entry {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.subdirectory {
+            os.write_string(1, &v)?;
+        };
+        for v in &self.file_ticket {
+            os.write_string(2, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FileDirectory {
+        FileDirectory::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FileDirectory_Entry>>(
+                "entry",
+                |m: &FileDirectory| { &m.entry },
+                |m: &mut FileDirectory| { &mut m.entry },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "subdirectory",
+                |m: &FileDirectory| { &m.subdirectory },
+                |m: &mut FileDirectory| { &mut m.subdirectory },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "file_ticket",
+                |m: &FileDirectory| { &m.file_ticket },
+                |m: &mut FileDirectory| { &mut m.file_ticket },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FileDirectory>(
+                "FileDirectory",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FileDirectory {
+        static instance: ::protobuf::rt::LazyV2<FileDirectory> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FileDirectory::new)
+    }
+}
+
+impl ::protobuf::Clear for FileDirectory {
+    fn clear(&mut self) {
+        self.entry.clear();
+        self.subdirectory.clear();
+        self.file_ticket.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileDirectory {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileDirectory {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileDirectory_Entry {
+    // message fields
+    pub kind: FileDirectory_Kind,
+    pub name: ::std::string::String,
+    pub build_config: ::protobuf::RepeatedField<::std::string::String>,
+    pub generated: bool,
+    pub missing_text: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileDirectory_Entry {
+    fn default() -> &'a FileDirectory_Entry {
+        <FileDirectory_Entry as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FileDirectory_Entry {
+    pub fn new() -> FileDirectory_Entry {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.serving.FileDirectory.Kind kind = 1;
+
+
+    pub fn get_kind(&self) -> FileDirectory_Kind {
+        self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind = FileDirectory_Kind::UNKNOWN;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: FileDirectory_Kind) {
+        self.kind = v;
+    }
+
+    // string name = 2;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // repeated string build_config = 3;
+
+
+    pub fn get_build_config(&self) -> &[::std::string::String] {
+        &self.build_config
+    }
+    pub fn clear_build_config(&mut self) {
+        self.build_config.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_build_config(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.build_config = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_build_config(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.build_config
+    }
+
+    // Take field
+    pub fn take_build_config(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.build_config, ::protobuf::RepeatedField::new())
+    }
+
+    // bool generated = 4;
+
+
+    pub fn get_generated(&self) -> bool {
+        self.generated
+    }
+    pub fn clear_generated(&mut self) {
+        self.generated = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_generated(&mut self, v: bool) {
+        self.generated = v;
+    }
+
+    // bool missing_text = 5;
+
+
+    pub fn get_missing_text(&self) -> bool {
+        self.missing_text
+    }
+    pub fn clear_missing_text(&mut self) {
+        self.missing_text = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_missing_text(&mut self, v: bool) {
+        self.missing_text = v;
+    }
+}
+
+impl ::protobuf::Message for FileDirectory_Entry {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.kind, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.build_config)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.generated = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.missing_text = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.kind != FileDirectory_Kind::UNKNOWN {
+            my_size += ::protobuf::rt::enum_size(1, self.kind);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
+        }
+        for value in &self.build_config {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        if self.generated != false {
+            my_size += 2;
+        }
+        if self.missing_text != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.kind != FileDirectory_Kind::UNKNOWN {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.kind))?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        for v in &self.build_config {
+            os.write_string(3, &v)?;
+        };
+        if self.generated != false {
+            os.write_bool(4, self.generated)?;
+        }
+        if self.missing_text != false {
+            os.write_bool(5, self.missing_text)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FileDirectory_Entry {
+        FileDirectory_Entry::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<FileDirectory_Kind>>(
+                "kind",
+                |m: &FileDirectory_Entry| { &m.kind },
+                |m: &mut FileDirectory_Entry| { &mut m.kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &FileDirectory_Entry| { &m.name },
+                |m: &mut FileDirectory_Entry| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "build_config",
+                |m: &FileDirectory_Entry| { &m.build_config },
+                |m: &mut FileDirectory_Entry| { &mut m.build_config },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "generated",
+                |m: &FileDirectory_Entry| { &m.generated },
+                |m: &mut FileDirectory_Entry| { &mut m.generated },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "missing_text",
+                |m: &FileDirectory_Entry| { &m.missing_text },
+                |m: &mut FileDirectory_Entry| { &mut m.missing_text },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FileDirectory_Entry>(
+                "FileDirectory.Entry",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FileDirectory_Entry {
+        static instance: ::protobuf::rt::LazyV2<FileDirectory_Entry> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FileDirectory_Entry::new)
+    }
+}
+
+impl ::protobuf::Clear for FileDirectory_Entry {
+    fn clear(&mut self) {
+        self.kind = FileDirectory_Kind::UNKNOWN;
+        self.name.clear();
+        self.build_config.clear();
+        self.generated = false;
+        self.missing_text = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileDirectory_Entry {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileDirectory_Entry {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum FileDirectory_Kind {
+    UNKNOWN = 0,
+    FILE = 1,
+    DIRECTORY = 2,
+}
+
+impl ::protobuf::ProtobufEnum for FileDirectory_Kind {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<FileDirectory_Kind> {
+        match value {
+            0 => ::std::option::Option::Some(FileDirectory_Kind::UNKNOWN),
+            1 => ::std::option::Option::Some(FileDirectory_Kind::FILE),
+            2 => ::std::option::Option::Some(FileDirectory_Kind::DIRECTORY),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [FileDirectory_Kind] = &[
+            FileDirectory_Kind::UNKNOWN,
+            FileDirectory_Kind::FILE,
+            FileDirectory_Kind::DIRECTORY,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<FileDirectory_Kind>("FileDirectory.Kind", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for FileDirectory_Kind {
+}
+
+impl ::std::default::Default for FileDirectory_Kind {
+    fn default() -> Self {
+        FileDirectory_Kind::UNKNOWN
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileDirectory_Kind {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CorpusRoots {
+    // message fields
+    pub corpus: ::protobuf::RepeatedField<CorpusRoots_Corpus>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CorpusRoots {
+    fn default() -> &'a CorpusRoots {
+        <CorpusRoots as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CorpusRoots {
+    pub fn new() -> CorpusRoots {
+        ::std::default::Default::default()
+    }
+
+    // repeated .kythe.proto.serving.CorpusRoots.Corpus corpus = 1;
+
+
+    pub fn get_corpus(&self) -> &[CorpusRoots_Corpus] {
+        &self.corpus
+    }
+    pub fn clear_corpus(&mut self) {
+        self.corpus.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_corpus(&mut self, v: ::protobuf::RepeatedField<CorpusRoots_Corpus>) {
+        self.corpus = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_corpus(&mut self) -> &mut ::protobuf::RepeatedField<CorpusRoots_Corpus> {
+        &mut self.corpus
+    }
+
+    // Take field
+    pub fn take_corpus(&mut self) -> ::protobuf::RepeatedField<CorpusRoots_Corpus> {
+        ::std::mem::replace(&mut self.corpus, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for CorpusRoots {
+    fn is_initialized(&self) -> bool {
+        for v in &self.corpus {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.corpus)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.corpus {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.corpus {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CorpusRoots {
+        CorpusRoots::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CorpusRoots_Corpus>>(
+                "corpus",
+                |m: &CorpusRoots| { &m.corpus },
+                |m: &mut CorpusRoots| { &mut m.corpus },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CorpusRoots>(
+                "CorpusRoots",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CorpusRoots {
+        static instance: ::protobuf::rt::LazyV2<CorpusRoots> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CorpusRoots::new)
+    }
+}
+
+impl ::protobuf::Clear for CorpusRoots {
+    fn clear(&mut self) {
+        self.corpus.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CorpusRoots {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CorpusRoots {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CorpusRoots_Corpus {
+    // message fields
+    pub corpus: ::std::string::String,
+    pub root: ::protobuf::RepeatedField<::std::string::String>,
+    pub build_config: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CorpusRoots_Corpus {
+    fn default() -> &'a CorpusRoots_Corpus {
+        <CorpusRoots_Corpus as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CorpusRoots_Corpus {
+    pub fn new() -> CorpusRoots_Corpus {
+        ::std::default::Default::default()
+    }
+
+    // string corpus = 1;
+
+
+    pub fn get_corpus(&self) -> &str {
+        &self.corpus
+    }
+    pub fn clear_corpus(&mut self) {
+        self.corpus.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_corpus(&mut self, v: ::std::string::String) {
+        self.corpus = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_corpus(&mut self) -> &mut ::std::string::String {
+        &mut self.corpus
+    }
+
+    // Take field
+    pub fn take_corpus(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.corpus, ::std::string::String::new())
+    }
+
+    // repeated string root = 2;
+
+
+    pub fn get_root(&self) -> &[::std::string::String] {
+        &self.root
+    }
+    pub fn clear_root(&mut self) {
+        self.root.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_root(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.root = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_root(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.root
+    }
+
+    // Take field
+    pub fn take_root(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.root, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string build_config = 3;
+
+
+    pub fn get_build_config(&self) -> &[::std::string::String] {
+        &self.build_config
+    }
+    pub fn clear_build_config(&mut self) {
+        self.build_config.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_build_config(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.build_config = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_build_config(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.build_config
+    }
+
+    // Take field
+    pub fn take_build_config(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.build_config, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for CorpusRoots_Corpus {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.corpus)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.root)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.build_config)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.corpus.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.corpus);
+        }
+        for value in &self.root {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in &self.build_config {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.corpus.is_empty() {
+            os.write_string(1, &self.corpus)?;
+        }
+        for v in &self.root {
+            os.write_string(2, &v)?;
+        };
+        for v in &self.build_config {
+            os.write_string(3, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CorpusRoots_Corpus {
+        CorpusRoots_Corpus::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "corpus",
+                |m: &CorpusRoots_Corpus| { &m.corpus },
+                |m: &mut CorpusRoots_Corpus| { &mut m.corpus },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "root",
+                |m: &CorpusRoots_Corpus| { &m.root },
+                |m: &mut CorpusRoots_Corpus| { &mut m.root },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "build_config",
+                |m: &CorpusRoots_Corpus| { &m.build_config },
+                |m: &mut CorpusRoots_Corpus| { &mut m.build_config },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CorpusRoots_Corpus>(
+                "CorpusRoots.Corpus",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CorpusRoots_Corpus {
+        static instance: ::protobuf::rt::LazyV2<CorpusRoots_Corpus> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CorpusRoots_Corpus::new)
+    }
+}
+
+impl ::protobuf::Clear for CorpusRoots_Corpus {
+    fn clear(&mut self) {
+        self.corpus.clear();
+        self.root.clear();
+        self.build_config.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CorpusRoots_Corpus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CorpusRoots_Corpus {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct File {
+    // message fields
+    pub ticket: ::std::string::String,
+    pub encoding: ::std::string::String,
+    pub info: ::protobuf::SingularPtrField<FileInfo>,
+    // message oneof groups
+    pub _text: ::std::option::Option<File_oneof__text>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a File {
+    fn default() -> &'a File {
+        <File as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum File_oneof__text {
+    text(::std::vec::Vec<u8>),
+}
+
+impl File {
+    pub fn new() -> File {
+        ::std::default::Default::default()
+    }
+
+    // string ticket = 1;
+
+
+    pub fn get_ticket(&self) -> &str {
+        &self.ticket
+    }
+    pub fn clear_ticket(&mut self) {
+        self.ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ticket(&mut self, v: ::std::string::String) {
+        self.ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ticket(&mut self) -> &mut ::std::string::String {
+        &mut self.ticket
+    }
+
+    // Take field
+    pub fn take_ticket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.ticket, ::std::string::String::new())
+    }
+
+    // bytes text = 2;
+
+
+    pub fn get_text(&self) -> &[u8] {
+        match self._text {
+            ::std::option::Option::Some(File_oneof__text::text(ref v)) => v,
+            _ => &[],
+        }
+    }
+    pub fn clear_text(&mut self) {
+        self._text = ::std::option::Option::None;
+    }
+
+    pub fn has_text(&self) -> bool {
+        match self._text {
+            ::std::option::Option::Some(File_oneof__text::text(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_text(&mut self, v: ::std::vec::Vec<u8>) {
+        self._text = ::std::option::Option::Some(File_oneof__text::text(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_text(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if let ::std::option::Option::Some(File_oneof__text::text(_)) = self._text {
+        } else {
+            self._text = ::std::option::Option::Some(File_oneof__text::text(::std::vec::Vec::new()));
+        }
+        match self._text {
+            ::std::option::Option::Some(File_oneof__text::text(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_text(&mut self) -> ::std::vec::Vec<u8> {
+        if self.has_text() {
+            match self._text.take() {
+                ::std::option::Option::Some(File_oneof__text::text(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::vec::Vec::new()
+        }
+    }
+
+    // string encoding = 3;
+
+
+    pub fn get_encoding(&self) -> &str {
+        &self.encoding
+    }
+    pub fn clear_encoding(&mut self) {
+        self.encoding.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_encoding(&mut self, v: ::std::string::String) {
+        self.encoding = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_encoding(&mut self) -> &mut ::std::string::String {
+        &mut self.encoding
+    }
+
+    // Take field
+    pub fn take_encoding(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.encoding, ::std::string::String::new())
+    }
+
+    // .kythe.proto.serving.FileInfo info = 4;
+
+
+    pub fn get_info(&self) -> &FileInfo {
+        self.info.as_ref().unwrap_or_else(|| <FileInfo as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_info(&mut self) {
+        self.info.clear();
+    }
+
+    pub fn has_info(&self) -> bool {
+        self.info.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_info(&mut self, v: FileInfo) {
+        self.info = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_info(&mut self) -> &mut FileInfo {
+        if self.info.is_none() {
+            self.info.set_default();
+        }
+        self.info.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_info(&mut self) -> FileInfo {
+        self.info.take().unwrap_or_else(|| FileInfo::new())
+    }
+}
+
+impl ::protobuf::Message for File {
+    fn is_initialized(&self) -> bool {
+        for v in &self.info {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.ticket)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self._text = ::std::option::Option::Some(File_oneof__text::text(is.read_bytes()?));
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.encoding)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.info)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.ticket.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.ticket);
+        }
+        if !self.encoding.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.encoding);
+        }
+        if let Some(ref v) = self.info.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let ::std::option::Option::Some(ref v) = self._text {
+            match v {
+                &File_oneof__text::text(ref v) => {
+                    my_size += ::protobuf::rt::bytes_size(2, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.ticket.is_empty() {
+            os.write_string(1, &self.ticket)?;
+        }
+        if !self.encoding.is_empty() {
+            os.write_string(3, &self.encoding)?;
+        }
+        if let Some(ref v) = self.info.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self._text {
+            match v {
+                &File_oneof__text::text(ref v) => {
+                    os.write_bytes(2, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> File {
+        File::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "ticket",
+                |m: &File| { &m.ticket },
+                |m: &mut File| { &mut m.ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor::<_>(
+                "text",
+                File::has_text,
+                File::get_text,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "encoding",
+                |m: &File| { &m.encoding },
+                |m: &mut File| { &mut m.encoding },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FileInfo>>(
+                "info",
+                |m: &File| { &m.info },
+                |m: &mut File| { &mut m.info },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<File>(
+                "File",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static File {
+        static instance: ::protobuf::rt::LazyV2<File> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(File::new)
+    }
+}
+
+impl ::protobuf::Clear for File {
+    fn clear(&mut self) {
+        self.ticket.clear();
+        self._text = ::std::option::Option::None;
+        self.encoding.clear();
+        self.info.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for File {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for File {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RawAnchor {
+    // message fields
+    pub ticket: ::std::string::String,
+    pub start_offset: i32,
+    pub end_offset: i32,
+    pub snippet_start: i32,
+    pub snippet_end: i32,
+    pub build_configuration: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RawAnchor {
+    fn default() -> &'a RawAnchor {
+        <RawAnchor as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RawAnchor {
+    pub fn new() -> RawAnchor {
+        ::std::default::Default::default()
+    }
+
+    // string ticket = 1;
+
+
+    pub fn get_ticket(&self) -> &str {
+        &self.ticket
+    }
+    pub fn clear_ticket(&mut self) {
+        self.ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ticket(&mut self, v: ::std::string::String) {
+        self.ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ticket(&mut self) -> &mut ::std::string::String {
+        &mut self.ticket
+    }
+
+    // Take field
+    pub fn take_ticket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.ticket, ::std::string::String::new())
+    }
+
+    // int32 start_offset = 2;
+
+
+    pub fn get_start_offset(&self) -> i32 {
+        self.start_offset
+    }
+    pub fn clear_start_offset(&mut self) {
+        self.start_offset = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start_offset(&mut self, v: i32) {
+        self.start_offset = v;
+    }
+
+    // int32 end_offset = 3;
+
+
+    pub fn get_end_offset(&self) -> i32 {
+        self.end_offset
+    }
+    pub fn clear_end_offset(&mut self) {
+        self.end_offset = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end_offset(&mut self, v: i32) {
+        self.end_offset = v;
+    }
+
+    // int32 snippet_start = 4;
+
+
+    pub fn get_snippet_start(&self) -> i32 {
+        self.snippet_start
+    }
+    pub fn clear_snippet_start(&mut self) {
+        self.snippet_start = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_snippet_start(&mut self, v: i32) {
+        self.snippet_start = v;
+    }
+
+    // int32 snippet_end = 5;
+
+
+    pub fn get_snippet_end(&self) -> i32 {
+        self.snippet_end
+    }
+    pub fn clear_snippet_end(&mut self) {
+        self.snippet_end = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_snippet_end(&mut self, v: i32) {
+        self.snippet_end = v;
+    }
+
+    // string build_configuration = 6;
+
+
+    pub fn get_build_configuration(&self) -> &str {
+        &self.build_configuration
+    }
+    pub fn clear_build_configuration(&mut self) {
+        self.build_configuration.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_build_configuration(&mut self, v: ::std::string::String) {
+        self.build_configuration = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_build_configuration(&mut self) -> &mut ::std::string::String {
+        &mut self.build_configuration
+    }
+
+    // Take field
+    pub fn take_build_configuration(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.build_configuration, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for RawAnchor {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.ticket)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.start_offset = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.end_offset = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.snippet_start = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.snippet_end = tmp;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.build_configuration)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.ticket.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.ticket);
+        }
+        if self.start_offset != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.start_offset, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.end_offset != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.end_offset, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.snippet_start != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.snippet_start, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.snippet_end != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.snippet_end, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.build_configuration.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.build_configuration);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.ticket.is_empty() {
+            os.write_string(1, &self.ticket)?;
+        }
+        if self.start_offset != 0 {
+            os.write_int32(2, self.start_offset)?;
+        }
+        if self.end_offset != 0 {
+            os.write_int32(3, self.end_offset)?;
+        }
+        if self.snippet_start != 0 {
+            os.write_int32(4, self.snippet_start)?;
+        }
+        if self.snippet_end != 0 {
+            os.write_int32(5, self.snippet_end)?;
+        }
+        if !self.build_configuration.is_empty() {
+            os.write_string(6, &self.build_configuration)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RawAnchor {
+        RawAnchor::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "ticket",
+                |m: &RawAnchor| { &m.ticket },
+                |m: &mut RawAnchor| { &mut m.ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "start_offset",
+                |m: &RawAnchor| { &m.start_offset },
+                |m: &mut RawAnchor| { &mut m.start_offset },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "end_offset",
+                |m: &RawAnchor| { &m.end_offset },
+                |m: &mut RawAnchor| { &mut m.end_offset },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "snippet_start",
+                |m: &RawAnchor| { &m.snippet_start },
+                |m: &mut RawAnchor| { &mut m.snippet_start },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "snippet_end",
+                |m: &RawAnchor| { &m.snippet_end },
+                |m: &mut RawAnchor| { &mut m.snippet_end },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "build_configuration",
+                |m: &RawAnchor| { &m.build_configuration },
+                |m: &mut RawAnchor| { &mut m.build_configuration },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RawAnchor>(
+                "RawAnchor",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RawAnchor {
+        static instance: ::protobuf::rt::LazyV2<RawAnchor> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RawAnchor::new)
+    }
+}
+
+impl ::protobuf::Clear for RawAnchor {
+    fn clear(&mut self) {
+        self.ticket.clear();
+        self.start_offset = 0;
+        self.end_offset = 0;
+        self.snippet_start = 0;
+        self.snippet_end = 0;
+        self.build_configuration.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RawAnchor {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RawAnchor {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ExpandedAnchor {
+    // message fields
+    pub ticket: ::std::string::String,
+    pub kind: ::std::string::String,
+    pub text: ::std::string::String,
+    pub span: ::protobuf::SingularPtrField<super::common::Span>,
+    pub snippet: ::std::string::String,
+    pub snippet_span: ::protobuf::SingularPtrField<super::common::Span>,
+    pub build_configuration: ::std::string::String,
+    pub rank: f64,
+    pub file_info: ::protobuf::SingularPtrField<FileInfo>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ExpandedAnchor {
+    fn default() -> &'a ExpandedAnchor {
+        <ExpandedAnchor as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ExpandedAnchor {
+    pub fn new() -> ExpandedAnchor {
+        ::std::default::Default::default()
+    }
+
+    // string ticket = 1;
+
+
+    pub fn get_ticket(&self) -> &str {
+        &self.ticket
+    }
+    pub fn clear_ticket(&mut self) {
+        self.ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ticket(&mut self, v: ::std::string::String) {
+        self.ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ticket(&mut self) -> &mut ::std::string::String {
+        &mut self.ticket
+    }
+
+    // Take field
+    pub fn take_ticket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.ticket, ::std::string::String::new())
+    }
+
+    // string kind = 2;
+
+
+    pub fn get_kind(&self) -> &str {
+        &self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: ::std::string::String) {
+        self.kind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_kind(&mut self) -> &mut ::std::string::String {
+        &mut self.kind
+    }
+
+    // Take field
+    pub fn take_kind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.kind, ::std::string::String::new())
+    }
+
+    // string text = 4;
+
+
+    pub fn get_text(&self) -> &str {
+        &self.text
+    }
+    pub fn clear_text(&mut self) {
+        self.text.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_text(&mut self, v: ::std::string::String) {
+        self.text = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_text(&mut self) -> &mut ::std::string::String {
+        &mut self.text
+    }
+
+    // Take field
+    pub fn take_text(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.text, ::std::string::String::new())
+    }
+
+    // .kythe.proto.common.Span span = 5;
+
+
+    pub fn get_span(&self) -> &super::common::Span {
+        self.span.as_ref().unwrap_or_else(|| <super::common::Span as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_span(&mut self) {
+        self.span.clear();
+    }
+
+    pub fn has_span(&self) -> bool {
+        self.span.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_span(&mut self, v: super::common::Span) {
+        self.span = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_span(&mut self) -> &mut super::common::Span {
+        if self.span.is_none() {
+            self.span.set_default();
+        }
+        self.span.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_span(&mut self) -> super::common::Span {
+        self.span.take().unwrap_or_else(|| super::common::Span::new())
+    }
+
+    // string snippet = 6;
+
+
+    pub fn get_snippet(&self) -> &str {
+        &self.snippet
+    }
+    pub fn clear_snippet(&mut self) {
+        self.snippet.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_snippet(&mut self, v: ::std::string::String) {
+        self.snippet = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_snippet(&mut self) -> &mut ::std::string::String {
+        &mut self.snippet
+    }
+
+    // Take field
+    pub fn take_snippet(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.snippet, ::std::string::String::new())
+    }
+
+    // .kythe.proto.common.Span snippet_span = 7;
+
+
+    pub fn get_snippet_span(&self) -> &super::common::Span {
+        self.snippet_span.as_ref().unwrap_or_else(|| <super::common::Span as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_snippet_span(&mut self) {
+        self.snippet_span.clear();
+    }
+
+    pub fn has_snippet_span(&self) -> bool {
+        self.snippet_span.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_snippet_span(&mut self, v: super::common::Span) {
+        self.snippet_span = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_snippet_span(&mut self) -> &mut super::common::Span {
+        if self.snippet_span.is_none() {
+            self.snippet_span.set_default();
+        }
+        self.snippet_span.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_snippet_span(&mut self) -> super::common::Span {
+        self.snippet_span.take().unwrap_or_else(|| super::common::Span::new())
+    }
+
+    // string build_configuration = 8;
+
+
+    pub fn get_build_configuration(&self) -> &str {
+        &self.build_configuration
+    }
+    pub fn clear_build_configuration(&mut self) {
+        self.build_configuration.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_build_configuration(&mut self, v: ::std::string::String) {
+        self.build_configuration = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_build_configuration(&mut self) -> &mut ::std::string::String {
+        &mut self.build_configuration
+    }
+
+    // Take field
+    pub fn take_build_configuration(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.build_configuration, ::std::string::String::new())
+    }
+
+    // double rank = 9;
+
+
+    pub fn get_rank(&self) -> f64 {
+        self.rank
+    }
+    pub fn clear_rank(&mut self) {
+        self.rank = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rank(&mut self, v: f64) {
+        self.rank = v;
+    }
+
+    // .kythe.proto.serving.FileInfo file_info = 10;
+
+
+    pub fn get_file_info(&self) -> &FileInfo {
+        self.file_info.as_ref().unwrap_or_else(|| <FileInfo as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_file_info(&mut self) {
+        self.file_info.clear();
+    }
+
+    pub fn has_file_info(&self) -> bool {
+        self.file_info.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_info(&mut self, v: FileInfo) {
+        self.file_info = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_file_info(&mut self) -> &mut FileInfo {
+        if self.file_info.is_none() {
+            self.file_info.set_default();
+        }
+        self.file_info.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_file_info(&mut self) -> FileInfo {
+        self.file_info.take().unwrap_or_else(|| FileInfo::new())
+    }
+}
+
+impl ::protobuf::Message for ExpandedAnchor {
+    fn is_initialized(&self) -> bool {
+        for v in &self.span {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.snippet_span {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.file_info {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.ticket)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.kind)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.text)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.span)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.snippet)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.snippet_span)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.build_configuration)?;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.rank = tmp;
+                },
+                10 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.file_info)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.ticket.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.ticket);
+        }
+        if !self.kind.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.kind);
+        }
+        if !self.text.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.text);
+        }
+        if let Some(ref v) = self.span.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.snippet.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.snippet);
+        }
+        if let Some(ref v) = self.snippet_span.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.build_configuration.is_empty() {
+            my_size += ::protobuf::rt::string_size(8, &self.build_configuration);
+        }
+        if self.rank != 0. {
+            my_size += 9;
+        }
+        if let Some(ref v) = self.file_info.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.ticket.is_empty() {
+            os.write_string(1, &self.ticket)?;
+        }
+        if !self.kind.is_empty() {
+            os.write_string(2, &self.kind)?;
+        }
+        if !self.text.is_empty() {
+            os.write_string(4, &self.text)?;
+        }
+        if let Some(ref v) = self.span.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.snippet.is_empty() {
+            os.write_string(6, &self.snippet)?;
+        }
+        if let Some(ref v) = self.snippet_span.as_ref() {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.build_configuration.is_empty() {
+            os.write_string(8, &self.build_configuration)?;
+        }
+        if self.rank != 0. {
+            os.write_double(9, self.rank)?;
+        }
+        if let Some(ref v) = self.file_info.as_ref() {
+            os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ExpandedAnchor {
+        ExpandedAnchor::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "ticket",
+                |m: &ExpandedAnchor| { &m.ticket },
+                |m: &mut ExpandedAnchor| { &mut m.ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "kind",
+                |m: &ExpandedAnchor| { &m.kind },
+                |m: &mut ExpandedAnchor| { &mut m.kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "text",
+                |m: &ExpandedAnchor| { &m.text },
+                |m: &mut ExpandedAnchor| { &mut m.text },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::Span>>(
+                "span",
+                |m: &ExpandedAnchor| { &m.span },
+                |m: &mut ExpandedAnchor| { &mut m.span },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "snippet",
+                |m: &ExpandedAnchor| { &m.snippet },
+                |m: &mut ExpandedAnchor| { &mut m.snippet },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::Span>>(
+                "snippet_span",
+                |m: &ExpandedAnchor| { &m.snippet_span },
+                |m: &mut ExpandedAnchor| { &mut m.snippet_span },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "build_configuration",
+                |m: &ExpandedAnchor| { &m.build_configuration },
+                |m: &mut ExpandedAnchor| { &mut m.build_configuration },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "rank",
+                |m: &ExpandedAnchor| { &m.rank },
+                |m: &mut ExpandedAnchor| { &mut m.rank },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FileInfo>>(
+                "file_info",
+                |m: &ExpandedAnchor| { &m.file_info },
+                |m: &mut ExpandedAnchor| { &mut m.file_info },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ExpandedAnchor>(
+                "ExpandedAnchor",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ExpandedAnchor {
+        static instance: ::protobuf::rt::LazyV2<ExpandedAnchor> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ExpandedAnchor::new)
+    }
+}
+
+impl ::protobuf::Clear for ExpandedAnchor {
+    fn clear(&mut self) {
+        self.ticket.clear();
+        self.kind.clear();
+        self.text.clear();
+        self.span.clear();
+        self.snippet.clear();
+        self.snippet_span.clear();
+        self.build_configuration.clear();
+        self.rank = 0.;
+        self.file_info.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExpandedAnchor {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExpandedAnchor {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileInfo {
+    // message fields
+    pub corpus_path: ::protobuf::SingularPtrField<super::common::CorpusPath>,
+    pub revision: ::std::string::String,
+    pub hash: ::protobuf::SingularPtrField<super::common::Hash>,
+    pub view_restricted: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileInfo {
+    fn default() -> &'a FileInfo {
+        <FileInfo as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FileInfo {
+    pub fn new() -> FileInfo {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.common.CorpusPath corpus_path = 1;
+
+
+    pub fn get_corpus_path(&self) -> &super::common::CorpusPath {
+        self.corpus_path.as_ref().unwrap_or_else(|| <super::common::CorpusPath as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_corpus_path(&mut self) {
+        self.corpus_path.clear();
+    }
+
+    pub fn has_corpus_path(&self) -> bool {
+        self.corpus_path.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_corpus_path(&mut self, v: super::common::CorpusPath) {
+        self.corpus_path = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_corpus_path(&mut self) -> &mut super::common::CorpusPath {
+        if self.corpus_path.is_none() {
+            self.corpus_path.set_default();
+        }
+        self.corpus_path.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_corpus_path(&mut self) -> super::common::CorpusPath {
+        self.corpus_path.take().unwrap_or_else(|| super::common::CorpusPath::new())
+    }
+
+    // string revision = 2;
+
+
+    pub fn get_revision(&self) -> &str {
+        &self.revision
+    }
+    pub fn clear_revision(&mut self) {
+        self.revision.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_revision(&mut self, v: ::std::string::String) {
+        self.revision = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_revision(&mut self) -> &mut ::std::string::String {
+        &mut self.revision
+    }
+
+    // Take field
+    pub fn take_revision(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.revision, ::std::string::String::new())
+    }
+
+    // .kythe.proto.common.Hash hash = 3;
+
+
+    pub fn get_hash(&self) -> &super::common::Hash {
+        self.hash.as_ref().unwrap_or_else(|| <super::common::Hash as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_hash(&mut self) {
+        self.hash.clear();
+    }
+
+    pub fn has_hash(&self) -> bool {
+        self.hash.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hash(&mut self, v: super::common::Hash) {
+        self.hash = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_hash(&mut self) -> &mut super::common::Hash {
+        if self.hash.is_none() {
+            self.hash.set_default();
+        }
+        self.hash.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_hash(&mut self) -> super::common::Hash {
+        self.hash.take().unwrap_or_else(|| super::common::Hash::new())
+    }
+
+    // bool view_restricted = 4;
+
+
+    pub fn get_view_restricted(&self) -> bool {
+        self.view_restricted
+    }
+    pub fn clear_view_restricted(&mut self) {
+        self.view_restricted = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_restricted(&mut self, v: bool) {
+        self.view_restricted = v;
+    }
+}
+
+impl ::protobuf::Message for FileInfo {
+    fn is_initialized(&self) -> bool {
+        for v in &self.corpus_path {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.hash {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.corpus_path)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.revision)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.hash)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.view_restricted = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.corpus_path.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.revision.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.revision);
+        }
+        if let Some(ref v) = self.hash.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.view_restricted != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.corpus_path.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.revision.is_empty() {
+            os.write_string(2, &self.revision)?;
+        }
+        if let Some(ref v) = self.hash.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.view_restricted != false {
+            os.write_bool(4, self.view_restricted)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FileInfo {
+        FileInfo::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::CorpusPath>>(
+                "corpus_path",
+                |m: &FileInfo| { &m.corpus_path },
+                |m: &mut FileInfo| { &mut m.corpus_path },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "revision",
+                |m: &FileInfo| { &m.revision },
+                |m: &mut FileInfo| { &mut m.revision },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::Hash>>(
+                "hash",
+                |m: &FileInfo| { &m.hash },
+                |m: &mut FileInfo| { &mut m.hash },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "view_restricted",
+                |m: &FileInfo| { &m.view_restricted },
+                |m: &mut FileInfo| { &mut m.view_restricted },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FileInfo>(
+                "FileInfo",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FileInfo {
+        static instance: ::protobuf::rt::LazyV2<FileInfo> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FileInfo::new)
+    }
+}
+
+impl ::protobuf::Clear for FileInfo {
+    fn clear(&mut self) {
+        self.corpus_path.clear();
+        self.revision.clear();
+        self.hash.clear();
+        self.view_restricted = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileInfo {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileDecorations {
+    // message fields
+    pub file: ::protobuf::SingularPtrField<File>,
+    pub decoration: ::protobuf::RepeatedField<FileDecorations_Decoration>,
+    pub target: ::protobuf::RepeatedField<Node>,
+    pub target_definitions: ::protobuf::RepeatedField<ExpandedAnchor>,
+    pub target_override: ::protobuf::RepeatedField<FileDecorations_Override>,
+    pub diagnostic: ::protobuf::RepeatedField<super::common::Diagnostic>,
+    pub generated_by: ::protobuf::RepeatedField<::std::string::String>,
+    pub file_info: ::protobuf::RepeatedField<FileInfo>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileDecorations {
+    fn default() -> &'a FileDecorations {
+        <FileDecorations as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FileDecorations {
+    pub fn new() -> FileDecorations {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.serving.File file = 1;
+
+
+    pub fn get_file(&self) -> &File {
+        self.file.as_ref().unwrap_or_else(|| <File as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_file(&mut self) {
+        self.file.clear();
+    }
+
+    pub fn has_file(&self) -> bool {
+        self.file.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file(&mut self, v: File) {
+        self.file = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_file(&mut self) -> &mut File {
+        if self.file.is_none() {
+            self.file.set_default();
+        }
+        self.file.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_file(&mut self) -> File {
+        self.file.take().unwrap_or_else(|| File::new())
+    }
+
+    // repeated .kythe.proto.serving.FileDecorations.Decoration decoration = 2;
+
+
+    pub fn get_decoration(&self) -> &[FileDecorations_Decoration] {
+        &self.decoration
+    }
+    pub fn clear_decoration(&mut self) {
+        self.decoration.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_decoration(&mut self, v: ::protobuf::RepeatedField<FileDecorations_Decoration>) {
+        self.decoration = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_decoration(&mut self) -> &mut ::protobuf::RepeatedField<FileDecorations_Decoration> {
+        &mut self.decoration
+    }
+
+    // Take field
+    pub fn take_decoration(&mut self) -> ::protobuf::RepeatedField<FileDecorations_Decoration> {
+        ::std::mem::replace(&mut self.decoration, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .kythe.proto.serving.Node target = 4;
+
+
+    pub fn get_target(&self) -> &[Node] {
+        &self.target
+    }
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: ::protobuf::RepeatedField<Node>) {
+        self.target = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_target(&mut self) -> &mut ::protobuf::RepeatedField<Node> {
+        &mut self.target
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> ::protobuf::RepeatedField<Node> {
+        ::std::mem::replace(&mut self.target, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .kythe.proto.serving.ExpandedAnchor target_definitions = 3;
+
+
+    pub fn get_target_definitions(&self) -> &[ExpandedAnchor] {
+        &self.target_definitions
+    }
+    pub fn clear_target_definitions(&mut self) {
+        self.target_definitions.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_definitions(&mut self, v: ::protobuf::RepeatedField<ExpandedAnchor>) {
+        self.target_definitions = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_target_definitions(&mut self) -> &mut ::protobuf::RepeatedField<ExpandedAnchor> {
+        &mut self.target_definitions
+    }
+
+    // Take field
+    pub fn take_target_definitions(&mut self) -> ::protobuf::RepeatedField<ExpandedAnchor> {
+        ::std::mem::replace(&mut self.target_definitions, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .kythe.proto.serving.FileDecorations.Override target_override = 5;
+
+
+    pub fn get_target_override(&self) -> &[FileDecorations_Override] {
+        &self.target_override
+    }
+    pub fn clear_target_override(&mut self) {
+        self.target_override.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_override(&mut self, v: ::protobuf::RepeatedField<FileDecorations_Override>) {
+        self.target_override = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_target_override(&mut self) -> &mut ::protobuf::RepeatedField<FileDecorations_Override> {
+        &mut self.target_override
+    }
+
+    // Take field
+    pub fn take_target_override(&mut self) -> ::protobuf::RepeatedField<FileDecorations_Override> {
+        ::std::mem::replace(&mut self.target_override, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .kythe.proto.common.Diagnostic diagnostic = 6;
+
+
+    pub fn get_diagnostic(&self) -> &[super::common::Diagnostic] {
+        &self.diagnostic
+    }
+    pub fn clear_diagnostic(&mut self) {
+        self.diagnostic.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_diagnostic(&mut self, v: ::protobuf::RepeatedField<super::common::Diagnostic>) {
+        self.diagnostic = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_diagnostic(&mut self) -> &mut ::protobuf::RepeatedField<super::common::Diagnostic> {
+        &mut self.diagnostic
+    }
+
+    // Take field
+    pub fn take_diagnostic(&mut self) -> ::protobuf::RepeatedField<super::common::Diagnostic> {
+        ::std::mem::replace(&mut self.diagnostic, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string generated_by = 7;
+
+
+    pub fn get_generated_by(&self) -> &[::std::string::String] {
+        &self.generated_by
+    }
+    pub fn clear_generated_by(&mut self) {
+        self.generated_by.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_generated_by(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.generated_by = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_generated_by(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.generated_by
+    }
+
+    // Take field
+    pub fn take_generated_by(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.generated_by, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .kythe.proto.serving.FileInfo file_info = 8;
+
+
+    pub fn get_file_info(&self) -> &[FileInfo] {
+        &self.file_info
+    }
+    pub fn clear_file_info(&mut self) {
+        self.file_info.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_info(&mut self, v: ::protobuf::RepeatedField<FileInfo>) {
+        self.file_info = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_file_info(&mut self) -> &mut ::protobuf::RepeatedField<FileInfo> {
+        &mut self.file_info
+    }
+
+    // Take field
+    pub fn take_file_info(&mut self) -> ::protobuf::RepeatedField<FileInfo> {
+        ::std::mem::replace(&mut self.file_info, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for FileDecorations {
+    fn is_initialized(&self) -> bool {
+        for v in &self.file {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.decoration {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.target {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.target_definitions {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.target_override {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.diagnostic {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.file_info {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.file)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.decoration)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.target)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.target_definitions)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.target_override)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.diagnostic)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.generated_by)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.file_info)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.file.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.decoration {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.target {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.target_definitions {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.target_override {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.diagnostic {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.generated_by {
+            my_size += ::protobuf::rt::string_size(7, &value);
+        };
+        for value in &self.file_info {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.file.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.decoration {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.target {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.target_definitions {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.target_override {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.diagnostic {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.generated_by {
+            os.write_string(7, &v)?;
+        };
+        for v in &self.file_info {
+            os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FileDecorations {
+        FileDecorations::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<File>>(
+                "file",
+                |m: &FileDecorations| { &m.file },
+                |m: &mut FileDecorations| { &mut m.file },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FileDecorations_Decoration>>(
+                "decoration",
+                |m: &FileDecorations| { &m.decoration },
+                |m: &mut FileDecorations| { &mut m.decoration },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                "target",
+                |m: &FileDecorations| { &m.target },
+                |m: &mut FileDecorations| { &mut m.target },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ExpandedAnchor>>(
+                "target_definitions",
+                |m: &FileDecorations| { &m.target_definitions },
+                |m: &mut FileDecorations| { &mut m.target_definitions },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FileDecorations_Override>>(
+                "target_override",
+                |m: &FileDecorations| { &m.target_override },
+                |m: &mut FileDecorations| { &mut m.target_override },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::Diagnostic>>(
+                "diagnostic",
+                |m: &FileDecorations| { &m.diagnostic },
+                |m: &mut FileDecorations| { &mut m.diagnostic },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "generated_by",
+                |m: &FileDecorations| { &m.generated_by },
+                |m: &mut FileDecorations| { &mut m.generated_by },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FileInfo>>(
+                "file_info",
+                |m: &FileDecorations| { &m.file_info },
+                |m: &mut FileDecorations| { &mut m.file_info },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FileDecorations>(
+                "FileDecorations",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FileDecorations {
+        static instance: ::protobuf::rt::LazyV2<FileDecorations> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FileDecorations::new)
+    }
+}
+
+impl ::protobuf::Clear for FileDecorations {
+    fn clear(&mut self) {
+        self.file.clear();
+        self.decoration.clear();
+        self.target.clear();
+        self.target_definitions.clear();
+        self.target_override.clear();
+        self.diagnostic.clear();
+        self.generated_by.clear();
+        self.file_info.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileDecorations {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileDecorations {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileDecorations_Decoration {
+    // message fields
+    pub anchor: ::protobuf::SingularPtrField<RawAnchor>,
+    pub kind: ::std::string::String,
+    pub target: ::std::string::String,
+    pub target_definition: ::std::string::String,
+    pub semantic_scope: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileDecorations_Decoration {
+    fn default() -> &'a FileDecorations_Decoration {
+        <FileDecorations_Decoration as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FileDecorations_Decoration {
+    pub fn new() -> FileDecorations_Decoration {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.serving.RawAnchor anchor = 1;
+
+
+    pub fn get_anchor(&self) -> &RawAnchor {
+        self.anchor.as_ref().unwrap_or_else(|| <RawAnchor as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_anchor(&mut self) {
+        self.anchor.clear();
+    }
+
+    pub fn has_anchor(&self) -> bool {
+        self.anchor.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_anchor(&mut self, v: RawAnchor) {
+        self.anchor = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_anchor(&mut self) -> &mut RawAnchor {
+        if self.anchor.is_none() {
+            self.anchor.set_default();
+        }
+        self.anchor.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_anchor(&mut self) -> RawAnchor {
+        self.anchor.take().unwrap_or_else(|| RawAnchor::new())
+    }
+
+    // string kind = 2;
+
+
+    pub fn get_kind(&self) -> &str {
+        &self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: ::std::string::String) {
+        self.kind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_kind(&mut self) -> &mut ::std::string::String {
+        &mut self.kind
+    }
+
+    // Take field
+    pub fn take_kind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.kind, ::std::string::String::new())
+    }
+
+    // string target = 5;
+
+
+    pub fn get_target(&self) -> &str {
+        &self.target
+    }
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: ::std::string::String) {
+        self.target = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut ::std::string::String {
+        &mut self.target
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.target, ::std::string::String::new())
+    }
+
+    // string target_definition = 4;
+
+
+    pub fn get_target_definition(&self) -> &str {
+        &self.target_definition
+    }
+    pub fn clear_target_definition(&mut self) {
+        self.target_definition.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_definition(&mut self, v: ::std::string::String) {
+        self.target_definition = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target_definition(&mut self) -> &mut ::std::string::String {
+        &mut self.target_definition
+    }
+
+    // Take field
+    pub fn take_target_definition(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.target_definition, ::std::string::String::new())
+    }
+
+    // string semantic_scope = 6;
+
+
+    pub fn get_semantic_scope(&self) -> &str {
+        &self.semantic_scope
+    }
+    pub fn clear_semantic_scope(&mut self) {
+        self.semantic_scope.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_semantic_scope(&mut self, v: ::std::string::String) {
+        self.semantic_scope = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_semantic_scope(&mut self) -> &mut ::std::string::String {
+        &mut self.semantic_scope
+    }
+
+    // Take field
+    pub fn take_semantic_scope(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.semantic_scope, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for FileDecorations_Decoration {
+    fn is_initialized(&self) -> bool {
+        for v in &self.anchor {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.anchor)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.kind)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.target)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.target_definition)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.semantic_scope)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.anchor.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.kind.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.kind);
+        }
+        if !self.target.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.target);
+        }
+        if !self.target_definition.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.target_definition);
+        }
+        if !self.semantic_scope.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.semantic_scope);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.anchor.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.kind.is_empty() {
+            os.write_string(2, &self.kind)?;
+        }
+        if !self.target.is_empty() {
+            os.write_string(5, &self.target)?;
+        }
+        if !self.target_definition.is_empty() {
+            os.write_string(4, &self.target_definition)?;
+        }
+        if !self.semantic_scope.is_empty() {
+            os.write_string(6, &self.semantic_scope)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FileDecorations_Decoration {
+        FileDecorations_Decoration::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RawAnchor>>(
+                "anchor",
+                |m: &FileDecorations_Decoration| { &m.anchor },
+                |m: &mut FileDecorations_Decoration| { &mut m.anchor },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "kind",
+                |m: &FileDecorations_Decoration| { &m.kind },
+                |m: &mut FileDecorations_Decoration| { &mut m.kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "target",
+                |m: &FileDecorations_Decoration| { &m.target },
+                |m: &mut FileDecorations_Decoration| { &mut m.target },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "target_definition",
+                |m: &FileDecorations_Decoration| { &m.target_definition },
+                |m: &mut FileDecorations_Decoration| { &mut m.target_definition },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "semantic_scope",
+                |m: &FileDecorations_Decoration| { &m.semantic_scope },
+                |m: &mut FileDecorations_Decoration| { &mut m.semantic_scope },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FileDecorations_Decoration>(
+                "FileDecorations.Decoration",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FileDecorations_Decoration {
+        static instance: ::protobuf::rt::LazyV2<FileDecorations_Decoration> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FileDecorations_Decoration::new)
+    }
+}
+
+impl ::protobuf::Clear for FileDecorations_Decoration {
+    fn clear(&mut self) {
+        self.anchor.clear();
+        self.kind.clear();
+        self.target.clear();
+        self.target_definition.clear();
+        self.semantic_scope.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileDecorations_Decoration {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileDecorations_Decoration {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileDecorations_Override {
+    // message fields
+    pub overriding: ::std::string::String,
+    pub overridden: ::std::string::String,
+    pub overridden_definition: ::std::string::String,
+    pub kind: FileDecorations_Override_Kind,
+    pub marked_source: ::protobuf::SingularPtrField<super::common::MarkedSource>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileDecorations_Override {
+    fn default() -> &'a FileDecorations_Override {
+        <FileDecorations_Override as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FileDecorations_Override {
+    pub fn new() -> FileDecorations_Override {
+        ::std::default::Default::default()
+    }
+
+    // string overriding = 1;
+
+
+    pub fn get_overriding(&self) -> &str {
+        &self.overriding
+    }
+    pub fn clear_overriding(&mut self) {
+        self.overriding.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_overriding(&mut self, v: ::std::string::String) {
+        self.overriding = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_overriding(&mut self) -> &mut ::std::string::String {
+        &mut self.overriding
+    }
+
+    // Take field
+    pub fn take_overriding(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.overriding, ::std::string::String::new())
+    }
+
+    // string overridden = 2;
+
+
+    pub fn get_overridden(&self) -> &str {
+        &self.overridden
+    }
+    pub fn clear_overridden(&mut self) {
+        self.overridden.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_overridden(&mut self, v: ::std::string::String) {
+        self.overridden = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_overridden(&mut self) -> &mut ::std::string::String {
+        &mut self.overridden
+    }
+
+    // Take field
+    pub fn take_overridden(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.overridden, ::std::string::String::new())
+    }
+
+    // string overridden_definition = 5;
+
+
+    pub fn get_overridden_definition(&self) -> &str {
+        &self.overridden_definition
+    }
+    pub fn clear_overridden_definition(&mut self) {
+        self.overridden_definition.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_overridden_definition(&mut self, v: ::std::string::String) {
+        self.overridden_definition = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_overridden_definition(&mut self) -> &mut ::std::string::String {
+        &mut self.overridden_definition
+    }
+
+    // Take field
+    pub fn take_overridden_definition(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.overridden_definition, ::std::string::String::new())
+    }
+
+    // .kythe.proto.serving.FileDecorations.Override.Kind kind = 3;
+
+
+    pub fn get_kind(&self) -> FileDecorations_Override_Kind {
+        self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind = FileDecorations_Override_Kind::OVERRIDES;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: FileDecorations_Override_Kind) {
+        self.kind = v;
+    }
+
+    // .kythe.proto.common.MarkedSource marked_source = 4;
+
+
+    pub fn get_marked_source(&self) -> &super::common::MarkedSource {
+        self.marked_source.as_ref().unwrap_or_else(|| <super::common::MarkedSource as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_marked_source(&mut self) {
+        self.marked_source.clear();
+    }
+
+    pub fn has_marked_source(&self) -> bool {
+        self.marked_source.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_marked_source(&mut self, v: super::common::MarkedSource) {
+        self.marked_source = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_marked_source(&mut self) -> &mut super::common::MarkedSource {
+        if self.marked_source.is_none() {
+            self.marked_source.set_default();
+        }
+        self.marked_source.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_marked_source(&mut self) -> super::common::MarkedSource {
+        self.marked_source.take().unwrap_or_else(|| super::common::MarkedSource::new())
+    }
+}
+
+impl ::protobuf::Message for FileDecorations_Override {
+    fn is_initialized(&self) -> bool {
+        for v in &self.marked_source {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.overriding)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.overridden)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.overridden_definition)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.kind, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.marked_source)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.overriding.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.overriding);
+        }
+        if !self.overridden.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.overridden);
+        }
+        if !self.overridden_definition.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.overridden_definition);
+        }
+        if self.kind != FileDecorations_Override_Kind::OVERRIDES {
+            my_size += ::protobuf::rt::enum_size(3, self.kind);
+        }
+        if let Some(ref v) = self.marked_source.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.overriding.is_empty() {
+            os.write_string(1, &self.overriding)?;
+        }
+        if !self.overridden.is_empty() {
+            os.write_string(2, &self.overridden)?;
+        }
+        if !self.overridden_definition.is_empty() {
+            os.write_string(5, &self.overridden_definition)?;
+        }
+        if self.kind != FileDecorations_Override_Kind::OVERRIDES {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.kind))?;
+        }
+        if let Some(ref v) = self.marked_source.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FileDecorations_Override {
+        FileDecorations_Override::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "overriding",
+                |m: &FileDecorations_Override| { &m.overriding },
+                |m: &mut FileDecorations_Override| { &mut m.overriding },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "overridden",
+                |m: &FileDecorations_Override| { &m.overridden },
+                |m: &mut FileDecorations_Override| { &mut m.overridden },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "overridden_definition",
+                |m: &FileDecorations_Override| { &m.overridden_definition },
+                |m: &mut FileDecorations_Override| { &mut m.overridden_definition },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<FileDecorations_Override_Kind>>(
+                "kind",
+                |m: &FileDecorations_Override| { &m.kind },
+                |m: &mut FileDecorations_Override| { &mut m.kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::MarkedSource>>(
+                "marked_source",
+                |m: &FileDecorations_Override| { &m.marked_source },
+                |m: &mut FileDecorations_Override| { &mut m.marked_source },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FileDecorations_Override>(
+                "FileDecorations.Override",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FileDecorations_Override {
+        static instance: ::protobuf::rt::LazyV2<FileDecorations_Override> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FileDecorations_Override::new)
+    }
+}
+
+impl ::protobuf::Clear for FileDecorations_Override {
+    fn clear(&mut self) {
+        self.overriding.clear();
+        self.overridden.clear();
+        self.overridden_definition.clear();
+        self.kind = FileDecorations_Override_Kind::OVERRIDES;
+        self.marked_source.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileDecorations_Override {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileDecorations_Override {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum FileDecorations_Override_Kind {
+    OVERRIDES = 0,
+    EXTENDS = 1,
+}
+
+impl ::protobuf::ProtobufEnum for FileDecorations_Override_Kind {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<FileDecorations_Override_Kind> {
+        match value {
+            0 => ::std::option::Option::Some(FileDecorations_Override_Kind::OVERRIDES),
+            1 => ::std::option::Option::Some(FileDecorations_Override_Kind::EXTENDS),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [FileDecorations_Override_Kind] = &[
+            FileDecorations_Override_Kind::OVERRIDES,
+            FileDecorations_Override_Kind::EXTENDS,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<FileDecorations_Override_Kind>("FileDecorations.Override.Kind", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for FileDecorations_Override_Kind {
+}
+
+impl ::std::default::Default for FileDecorations_Override_Kind {
+    fn default() -> Self {
+        FileDecorations_Override_Kind::OVERRIDES
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileDecorations_Override_Kind {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PagedCrossReferences {
+    // message fields
+    pub merge_with: ::protobuf::RepeatedField<::std::string::String>,
+    pub source_ticket: ::std::string::String,
+    pub source_node: ::protobuf::SingularPtrField<Node>,
+    pub group: ::protobuf::RepeatedField<PagedCrossReferences_Group>,
+    pub page_index: ::protobuf::RepeatedField<PagedCrossReferences_PageIndex>,
+    pub total_references: i32,
+    pub incomplete: bool,
+    pub marked_source: ::protobuf::SingularPtrField<super::common::MarkedSource>,
+    pub page_search_index: ::protobuf::SingularPtrField<PagedCrossReferences_PageSearchIndex>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PagedCrossReferences {
+    fn default() -> &'a PagedCrossReferences {
+        <PagedCrossReferences as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PagedCrossReferences {
+    pub fn new() -> PagedCrossReferences {
+        ::std::default::Default::default()
+    }
+
+    // repeated string merge_with = 7;
+
+
+    pub fn get_merge_with(&self) -> &[::std::string::String] {
+        &self.merge_with
+    }
+    pub fn clear_merge_with(&mut self) {
+        self.merge_with.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_merge_with(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.merge_with = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_merge_with(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.merge_with
+    }
+
+    // Take field
+    pub fn take_merge_with(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.merge_with, ::protobuf::RepeatedField::new())
+    }
+
+    // string source_ticket = 1;
+
+
+    pub fn get_source_ticket(&self) -> &str {
+        &self.source_ticket
+    }
+    pub fn clear_source_ticket(&mut self) {
+        self.source_ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source_ticket(&mut self, v: ::std::string::String) {
+        self.source_ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source_ticket(&mut self) -> &mut ::std::string::String {
+        &mut self.source_ticket
+    }
+
+    // Take field
+    pub fn take_source_ticket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.source_ticket, ::std::string::String::new())
+    }
+
+    // .kythe.proto.serving.Node source_node = 8;
+
+
+    pub fn get_source_node(&self) -> &Node {
+        self.source_node.as_ref().unwrap_or_else(|| <Node as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_source_node(&mut self) {
+        self.source_node.clear();
+    }
+
+    pub fn has_source_node(&self) -> bool {
+        self.source_node.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source_node(&mut self, v: Node) {
+        self.source_node = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source_node(&mut self) -> &mut Node {
+        if self.source_node.is_none() {
+            self.source_node.set_default();
+        }
+        self.source_node.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_source_node(&mut self) -> Node {
+        self.source_node.take().unwrap_or_else(|| Node::new())
+    }
+
+    // repeated .kythe.proto.serving.PagedCrossReferences.Group group = 2;
+
+
+    pub fn get_group(&self) -> &[PagedCrossReferences_Group] {
+        &self.group
+    }
+    pub fn clear_group(&mut self) {
+        self.group.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_group(&mut self, v: ::protobuf::RepeatedField<PagedCrossReferences_Group>) {
+        self.group = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_group(&mut self) -> &mut ::protobuf::RepeatedField<PagedCrossReferences_Group> {
+        &mut self.group
+    }
+
+    // Take field
+    pub fn take_group(&mut self) -> ::protobuf::RepeatedField<PagedCrossReferences_Group> {
+        ::std::mem::replace(&mut self.group, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .kythe.proto.serving.PagedCrossReferences.PageIndex page_index = 3;
+
+
+    pub fn get_page_index(&self) -> &[PagedCrossReferences_PageIndex] {
+        &self.page_index
+    }
+    pub fn clear_page_index(&mut self) {
+        self.page_index.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_page_index(&mut self, v: ::protobuf::RepeatedField<PagedCrossReferences_PageIndex>) {
+        self.page_index = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_page_index(&mut self) -> &mut ::protobuf::RepeatedField<PagedCrossReferences_PageIndex> {
+        &mut self.page_index
+    }
+
+    // Take field
+    pub fn take_page_index(&mut self) -> ::protobuf::RepeatedField<PagedCrossReferences_PageIndex> {
+        ::std::mem::replace(&mut self.page_index, ::protobuf::RepeatedField::new())
+    }
+
+    // int32 total_references = 4;
+
+
+    pub fn get_total_references(&self) -> i32 {
+        self.total_references
+    }
+    pub fn clear_total_references(&mut self) {
+        self.total_references = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_total_references(&mut self, v: i32) {
+        self.total_references = v;
+    }
+
+    // bool incomplete = 5;
+
+
+    pub fn get_incomplete(&self) -> bool {
+        self.incomplete
+    }
+    pub fn clear_incomplete(&mut self) {
+        self.incomplete = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_incomplete(&mut self, v: bool) {
+        self.incomplete = v;
+    }
+
+    // .kythe.proto.common.MarkedSource marked_source = 6;
+
+
+    pub fn get_marked_source(&self) -> &super::common::MarkedSource {
+        self.marked_source.as_ref().unwrap_or_else(|| <super::common::MarkedSource as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_marked_source(&mut self) {
+        self.marked_source.clear();
+    }
+
+    pub fn has_marked_source(&self) -> bool {
+        self.marked_source.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_marked_source(&mut self, v: super::common::MarkedSource) {
+        self.marked_source = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_marked_source(&mut self) -> &mut super::common::MarkedSource {
+        if self.marked_source.is_none() {
+            self.marked_source.set_default();
+        }
+        self.marked_source.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_marked_source(&mut self) -> super::common::MarkedSource {
+        self.marked_source.take().unwrap_or_else(|| super::common::MarkedSource::new())
+    }
+
+    // .kythe.proto.serving.PagedCrossReferences.PageSearchIndex page_search_index = 11;
+
+
+    pub fn get_page_search_index(&self) -> &PagedCrossReferences_PageSearchIndex {
+        self.page_search_index.as_ref().unwrap_or_else(|| <PagedCrossReferences_PageSearchIndex as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_page_search_index(&mut self) {
+        self.page_search_index.clear();
+    }
+
+    pub fn has_page_search_index(&self) -> bool {
+        self.page_search_index.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_page_search_index(&mut self, v: PagedCrossReferences_PageSearchIndex) {
+        self.page_search_index = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_page_search_index(&mut self) -> &mut PagedCrossReferences_PageSearchIndex {
+        if self.page_search_index.is_none() {
+            self.page_search_index.set_default();
+        }
+        self.page_search_index.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_page_search_index(&mut self) -> PagedCrossReferences_PageSearchIndex {
+        self.page_search_index.take().unwrap_or_else(|| PagedCrossReferences_PageSearchIndex::new())
+    }
+}
+
+impl ::protobuf::Message for PagedCrossReferences {
+    fn is_initialized(&self) -> bool {
+        for v in &self.source_node {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.group {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.page_index {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.marked_source {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.page_search_index {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                7 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.merge_with)?;
+                },
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.source_ticket)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.source_node)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.group)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.page_index)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.total_references = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.incomplete = tmp;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.marked_source)?;
+                },
+                11 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.page_search_index)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.merge_with {
+            my_size += ::protobuf::rt::string_size(7, &value);
+        };
+        if !self.source_ticket.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.source_ticket);
+        }
+        if let Some(ref v) = self.source_node.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.group {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.page_index {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.total_references != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.total_references, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.incomplete != false {
+            my_size += 2;
+        }
+        if let Some(ref v) = self.marked_source.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.page_search_index.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.merge_with {
+            os.write_string(7, &v)?;
+        };
+        if !self.source_ticket.is_empty() {
+            os.write_string(1, &self.source_ticket)?;
+        }
+        if let Some(ref v) = self.source_node.as_ref() {
+            os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.group {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.page_index {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.total_references != 0 {
+            os.write_int32(4, self.total_references)?;
+        }
+        if self.incomplete != false {
+            os.write_bool(5, self.incomplete)?;
+        }
+        if let Some(ref v) = self.marked_source.as_ref() {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.page_search_index.as_ref() {
+            os.write_tag(11, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PagedCrossReferences {
+        PagedCrossReferences::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "merge_with",
+                |m: &PagedCrossReferences| { &m.merge_with },
+                |m: &mut PagedCrossReferences| { &mut m.merge_with },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "source_ticket",
+                |m: &PagedCrossReferences| { &m.source_ticket },
+                |m: &mut PagedCrossReferences| { &mut m.source_ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                "source_node",
+                |m: &PagedCrossReferences| { &m.source_node },
+                |m: &mut PagedCrossReferences| { &mut m.source_node },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PagedCrossReferences_Group>>(
+                "group",
+                |m: &PagedCrossReferences| { &m.group },
+                |m: &mut PagedCrossReferences| { &mut m.group },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PagedCrossReferences_PageIndex>>(
+                "page_index",
+                |m: &PagedCrossReferences| { &m.page_index },
+                |m: &mut PagedCrossReferences| { &mut m.page_index },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "total_references",
+                |m: &PagedCrossReferences| { &m.total_references },
+                |m: &mut PagedCrossReferences| { &mut m.total_references },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "incomplete",
+                |m: &PagedCrossReferences| { &m.incomplete },
+                |m: &mut PagedCrossReferences| { &mut m.incomplete },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::MarkedSource>>(
+                "marked_source",
+                |m: &PagedCrossReferences| { &m.marked_source },
+                |m: &mut PagedCrossReferences| { &mut m.marked_source },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PagedCrossReferences_PageSearchIndex>>(
+                "page_search_index",
+                |m: &PagedCrossReferences| { &m.page_search_index },
+                |m: &mut PagedCrossReferences| { &mut m.page_search_index },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PagedCrossReferences>(
+                "PagedCrossReferences",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PagedCrossReferences {
+        static instance: ::protobuf::rt::LazyV2<PagedCrossReferences> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PagedCrossReferences::new)
+    }
+}
+
+impl ::protobuf::Clear for PagedCrossReferences {
+    fn clear(&mut self) {
+        self.merge_with.clear();
+        self.source_ticket.clear();
+        self.source_node.clear();
+        self.group.clear();
+        self.page_index.clear();
+        self.total_references = 0;
+        self.incomplete = false;
+        self.marked_source.clear();
+        self.page_search_index.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PagedCrossReferences {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PagedCrossReferences {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PagedCrossReferences_RelatedNode {
+    // message fields
+    pub node: ::protobuf::SingularPtrField<Node>,
+    pub ordinal: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PagedCrossReferences_RelatedNode {
+    fn default() -> &'a PagedCrossReferences_RelatedNode {
+        <PagedCrossReferences_RelatedNode as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PagedCrossReferences_RelatedNode {
+    pub fn new() -> PagedCrossReferences_RelatedNode {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.serving.Node node = 1;
+
+
+    pub fn get_node(&self) -> &Node {
+        self.node.as_ref().unwrap_or_else(|| <Node as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_node(&mut self) {
+        self.node.clear();
+    }
+
+    pub fn has_node(&self) -> bool {
+        self.node.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_node(&mut self, v: Node) {
+        self.node = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_node(&mut self) -> &mut Node {
+        if self.node.is_none() {
+            self.node.set_default();
+        }
+        self.node.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_node(&mut self) -> Node {
+        self.node.take().unwrap_or_else(|| Node::new())
+    }
+
+    // int32 ordinal = 2;
+
+
+    pub fn get_ordinal(&self) -> i32 {
+        self.ordinal
+    }
+    pub fn clear_ordinal(&mut self) {
+        self.ordinal = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ordinal(&mut self, v: i32) {
+        self.ordinal = v;
+    }
+}
+
+impl ::protobuf::Message for PagedCrossReferences_RelatedNode {
+    fn is_initialized(&self) -> bool {
+        for v in &self.node {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.node)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.ordinal = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.node.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.ordinal != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.ordinal, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.node.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.ordinal != 0 {
+            os.write_int32(2, self.ordinal)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PagedCrossReferences_RelatedNode {
+        PagedCrossReferences_RelatedNode::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                "node",
+                |m: &PagedCrossReferences_RelatedNode| { &m.node },
+                |m: &mut PagedCrossReferences_RelatedNode| { &mut m.node },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "ordinal",
+                |m: &PagedCrossReferences_RelatedNode| { &m.ordinal },
+                |m: &mut PagedCrossReferences_RelatedNode| { &mut m.ordinal },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PagedCrossReferences_RelatedNode>(
+                "PagedCrossReferences.RelatedNode",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PagedCrossReferences_RelatedNode {
+        static instance: ::protobuf::rt::LazyV2<PagedCrossReferences_RelatedNode> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PagedCrossReferences_RelatedNode::new)
+    }
+}
+
+impl ::protobuf::Clear for PagedCrossReferences_RelatedNode {
+    fn clear(&mut self) {
+        self.node.clear();
+        self.ordinal = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PagedCrossReferences_RelatedNode {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PagedCrossReferences_RelatedNode {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PagedCrossReferences_ScopedReference {
+    // message fields
+    pub scope: ::protobuf::SingularPtrField<ExpandedAnchor>,
+    pub semantic_scope: ::std::string::String,
+    pub marked_source: ::protobuf::SingularPtrField<super::common::MarkedSource>,
+    pub reference: ::protobuf::RepeatedField<ExpandedAnchor>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PagedCrossReferences_ScopedReference {
+    fn default() -> &'a PagedCrossReferences_ScopedReference {
+        <PagedCrossReferences_ScopedReference as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PagedCrossReferences_ScopedReference {
+    pub fn new() -> PagedCrossReferences_ScopedReference {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.serving.ExpandedAnchor scope = 1;
+
+
+    pub fn get_scope(&self) -> &ExpandedAnchor {
+        self.scope.as_ref().unwrap_or_else(|| <ExpandedAnchor as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_scope(&mut self) {
+        self.scope.clear();
+    }
+
+    pub fn has_scope(&self) -> bool {
+        self.scope.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scope(&mut self, v: ExpandedAnchor) {
+        self.scope = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_scope(&mut self) -> &mut ExpandedAnchor {
+        if self.scope.is_none() {
+            self.scope.set_default();
+        }
+        self.scope.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_scope(&mut self) -> ExpandedAnchor {
+        self.scope.take().unwrap_or_else(|| ExpandedAnchor::new())
+    }
+
+    // string semantic_scope = 2;
+
+
+    pub fn get_semantic_scope(&self) -> &str {
+        &self.semantic_scope
+    }
+    pub fn clear_semantic_scope(&mut self) {
+        self.semantic_scope.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_semantic_scope(&mut self, v: ::std::string::String) {
+        self.semantic_scope = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_semantic_scope(&mut self) -> &mut ::std::string::String {
+        &mut self.semantic_scope
+    }
+
+    // Take field
+    pub fn take_semantic_scope(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.semantic_scope, ::std::string::String::new())
+    }
+
+    // .kythe.proto.common.MarkedSource marked_source = 3;
+
+
+    pub fn get_marked_source(&self) -> &super::common::MarkedSource {
+        self.marked_source.as_ref().unwrap_or_else(|| <super::common::MarkedSource as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_marked_source(&mut self) {
+        self.marked_source.clear();
+    }
+
+    pub fn has_marked_source(&self) -> bool {
+        self.marked_source.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_marked_source(&mut self, v: super::common::MarkedSource) {
+        self.marked_source = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_marked_source(&mut self) -> &mut super::common::MarkedSource {
+        if self.marked_source.is_none() {
+            self.marked_source.set_default();
+        }
+        self.marked_source.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_marked_source(&mut self) -> super::common::MarkedSource {
+        self.marked_source.take().unwrap_or_else(|| super::common::MarkedSource::new())
+    }
+
+    // repeated .kythe.proto.serving.ExpandedAnchor reference = 4;
+
+
+    pub fn get_reference(&self) -> &[ExpandedAnchor] {
+        &self.reference
+    }
+    pub fn clear_reference(&mut self) {
+        self.reference.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reference(&mut self, v: ::protobuf::RepeatedField<ExpandedAnchor>) {
+        self.reference = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_reference(&mut self) -> &mut ::protobuf::RepeatedField<ExpandedAnchor> {
+        &mut self.reference
+    }
+
+    // Take field
+    pub fn take_reference(&mut self) -> ::protobuf::RepeatedField<ExpandedAnchor> {
+        ::std::mem::replace(&mut self.reference, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for PagedCrossReferences_ScopedReference {
+    fn is_initialized(&self) -> bool {
+        for v in &self.scope {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.marked_source {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.reference {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.scope)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.semantic_scope)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.marked_source)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.reference)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.scope.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.semantic_scope.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.semantic_scope);
+        }
+        if let Some(ref v) = self.marked_source.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.reference {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.scope.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.semantic_scope.is_empty() {
+            os.write_string(2, &self.semantic_scope)?;
+        }
+        if let Some(ref v) = self.marked_source.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.reference {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PagedCrossReferences_ScopedReference {
+        PagedCrossReferences_ScopedReference::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ExpandedAnchor>>(
+                "scope",
+                |m: &PagedCrossReferences_ScopedReference| { &m.scope },
+                |m: &mut PagedCrossReferences_ScopedReference| { &mut m.scope },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "semantic_scope",
+                |m: &PagedCrossReferences_ScopedReference| { &m.semantic_scope },
+                |m: &mut PagedCrossReferences_ScopedReference| { &mut m.semantic_scope },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::MarkedSource>>(
+                "marked_source",
+                |m: &PagedCrossReferences_ScopedReference| { &m.marked_source },
+                |m: &mut PagedCrossReferences_ScopedReference| { &mut m.marked_source },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ExpandedAnchor>>(
+                "reference",
+                |m: &PagedCrossReferences_ScopedReference| { &m.reference },
+                |m: &mut PagedCrossReferences_ScopedReference| { &mut m.reference },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PagedCrossReferences_ScopedReference>(
+                "PagedCrossReferences.ScopedReference",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PagedCrossReferences_ScopedReference {
+        static instance: ::protobuf::rt::LazyV2<PagedCrossReferences_ScopedReference> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PagedCrossReferences_ScopedReference::new)
+    }
+}
+
+impl ::protobuf::Clear for PagedCrossReferences_ScopedReference {
+    fn clear(&mut self) {
+        self.scope.clear();
+        self.semantic_scope.clear();
+        self.marked_source.clear();
+        self.reference.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PagedCrossReferences_ScopedReference {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PagedCrossReferences_ScopedReference {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PagedCrossReferences_Caller {
+    // message fields
+    pub caller: ::protobuf::SingularPtrField<ExpandedAnchor>,
+    pub semantic_caller: ::std::string::String,
+    pub marked_source: ::protobuf::SingularPtrField<super::common::MarkedSource>,
+    pub callsite: ::protobuf::RepeatedField<ExpandedAnchor>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PagedCrossReferences_Caller {
+    fn default() -> &'a PagedCrossReferences_Caller {
+        <PagedCrossReferences_Caller as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PagedCrossReferences_Caller {
+    pub fn new() -> PagedCrossReferences_Caller {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.serving.ExpandedAnchor caller = 1;
+
+
+    pub fn get_caller(&self) -> &ExpandedAnchor {
+        self.caller.as_ref().unwrap_or_else(|| <ExpandedAnchor as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_caller(&mut self) {
+        self.caller.clear();
+    }
+
+    pub fn has_caller(&self) -> bool {
+        self.caller.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_caller(&mut self, v: ExpandedAnchor) {
+        self.caller = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_caller(&mut self) -> &mut ExpandedAnchor {
+        if self.caller.is_none() {
+            self.caller.set_default();
+        }
+        self.caller.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_caller(&mut self) -> ExpandedAnchor {
+        self.caller.take().unwrap_or_else(|| ExpandedAnchor::new())
+    }
+
+    // string semantic_caller = 2;
+
+
+    pub fn get_semantic_caller(&self) -> &str {
+        &self.semantic_caller
+    }
+    pub fn clear_semantic_caller(&mut self) {
+        self.semantic_caller.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_semantic_caller(&mut self, v: ::std::string::String) {
+        self.semantic_caller = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_semantic_caller(&mut self) -> &mut ::std::string::String {
+        &mut self.semantic_caller
+    }
+
+    // Take field
+    pub fn take_semantic_caller(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.semantic_caller, ::std::string::String::new())
+    }
+
+    // .kythe.proto.common.MarkedSource marked_source = 3;
+
+
+    pub fn get_marked_source(&self) -> &super::common::MarkedSource {
+        self.marked_source.as_ref().unwrap_or_else(|| <super::common::MarkedSource as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_marked_source(&mut self) {
+        self.marked_source.clear();
+    }
+
+    pub fn has_marked_source(&self) -> bool {
+        self.marked_source.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_marked_source(&mut self, v: super::common::MarkedSource) {
+        self.marked_source = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_marked_source(&mut self) -> &mut super::common::MarkedSource {
+        if self.marked_source.is_none() {
+            self.marked_source.set_default();
+        }
+        self.marked_source.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_marked_source(&mut self) -> super::common::MarkedSource {
+        self.marked_source.take().unwrap_or_else(|| super::common::MarkedSource::new())
+    }
+
+    // repeated .kythe.proto.serving.ExpandedAnchor callsite = 4;
+
+
+    pub fn get_callsite(&self) -> &[ExpandedAnchor] {
+        &self.callsite
+    }
+    pub fn clear_callsite(&mut self) {
+        self.callsite.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_callsite(&mut self, v: ::protobuf::RepeatedField<ExpandedAnchor>) {
+        self.callsite = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_callsite(&mut self) -> &mut ::protobuf::RepeatedField<ExpandedAnchor> {
+        &mut self.callsite
+    }
+
+    // Take field
+    pub fn take_callsite(&mut self) -> ::protobuf::RepeatedField<ExpandedAnchor> {
+        ::std::mem::replace(&mut self.callsite, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for PagedCrossReferences_Caller {
+    fn is_initialized(&self) -> bool {
+        for v in &self.caller {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.marked_source {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.callsite {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.caller)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.semantic_caller)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.marked_source)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.callsite)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.caller.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.semantic_caller.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.semantic_caller);
+        }
+        if let Some(ref v) = self.marked_source.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.callsite {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.caller.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.semantic_caller.is_empty() {
+            os.write_string(2, &self.semantic_caller)?;
+        }
+        if let Some(ref v) = self.marked_source.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.callsite {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PagedCrossReferences_Caller {
+        PagedCrossReferences_Caller::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ExpandedAnchor>>(
+                "caller",
+                |m: &PagedCrossReferences_Caller| { &m.caller },
+                |m: &mut PagedCrossReferences_Caller| { &mut m.caller },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "semantic_caller",
+                |m: &PagedCrossReferences_Caller| { &m.semantic_caller },
+                |m: &mut PagedCrossReferences_Caller| { &mut m.semantic_caller },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::MarkedSource>>(
+                "marked_source",
+                |m: &PagedCrossReferences_Caller| { &m.marked_source },
+                |m: &mut PagedCrossReferences_Caller| { &mut m.marked_source },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ExpandedAnchor>>(
+                "callsite",
+                |m: &PagedCrossReferences_Caller| { &m.callsite },
+                |m: &mut PagedCrossReferences_Caller| { &mut m.callsite },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PagedCrossReferences_Caller>(
+                "PagedCrossReferences.Caller",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PagedCrossReferences_Caller {
+        static instance: ::protobuf::rt::LazyV2<PagedCrossReferences_Caller> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PagedCrossReferences_Caller::new)
+    }
+}
+
+impl ::protobuf::Clear for PagedCrossReferences_Caller {
+    fn clear(&mut self) {
+        self.caller.clear();
+        self.semantic_caller.clear();
+        self.marked_source.clear();
+        self.callsite.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PagedCrossReferences_Caller {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PagedCrossReferences_Caller {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PagedCrossReferences_Group {
+    // message fields
+    pub kind: ::std::string::String,
+    pub build_config: ::std::string::String,
+    pub anchor: ::protobuf::RepeatedField<ExpandedAnchor>,
+    pub related_node: ::protobuf::RepeatedField<PagedCrossReferences_RelatedNode>,
+    pub caller: ::protobuf::RepeatedField<PagedCrossReferences_Caller>,
+    pub scoped_reference: ::protobuf::RepeatedField<PagedCrossReferences_ScopedReference>,
+    pub file_info: ::protobuf::RepeatedField<FileInfo>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PagedCrossReferences_Group {
+    fn default() -> &'a PagedCrossReferences_Group {
+        <PagedCrossReferences_Group as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PagedCrossReferences_Group {
+    pub fn new() -> PagedCrossReferences_Group {
+        ::std::default::Default::default()
+    }
+
+    // string kind = 1;
+
+
+    pub fn get_kind(&self) -> &str {
+        &self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: ::std::string::String) {
+        self.kind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_kind(&mut self) -> &mut ::std::string::String {
+        &mut self.kind
+    }
+
+    // Take field
+    pub fn take_kind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.kind, ::std::string::String::new())
+    }
+
+    // string build_config = 5;
+
+
+    pub fn get_build_config(&self) -> &str {
+        &self.build_config
+    }
+    pub fn clear_build_config(&mut self) {
+        self.build_config.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_build_config(&mut self, v: ::std::string::String) {
+        self.build_config = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_build_config(&mut self) -> &mut ::std::string::String {
+        &mut self.build_config
+    }
+
+    // Take field
+    pub fn take_build_config(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.build_config, ::std::string::String::new())
+    }
+
+    // repeated .kythe.proto.serving.ExpandedAnchor anchor = 2;
+
+
+    pub fn get_anchor(&self) -> &[ExpandedAnchor] {
+        &self.anchor
+    }
+    pub fn clear_anchor(&mut self) {
+        self.anchor.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_anchor(&mut self, v: ::protobuf::RepeatedField<ExpandedAnchor>) {
+        self.anchor = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_anchor(&mut self) -> &mut ::protobuf::RepeatedField<ExpandedAnchor> {
+        &mut self.anchor
+    }
+
+    // Take field
+    pub fn take_anchor(&mut self) -> ::protobuf::RepeatedField<ExpandedAnchor> {
+        ::std::mem::replace(&mut self.anchor, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .kythe.proto.serving.PagedCrossReferences.RelatedNode related_node = 3;
+
+
+    pub fn get_related_node(&self) -> &[PagedCrossReferences_RelatedNode] {
+        &self.related_node
+    }
+    pub fn clear_related_node(&mut self) {
+        self.related_node.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_related_node(&mut self, v: ::protobuf::RepeatedField<PagedCrossReferences_RelatedNode>) {
+        self.related_node = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_related_node(&mut self) -> &mut ::protobuf::RepeatedField<PagedCrossReferences_RelatedNode> {
+        &mut self.related_node
+    }
+
+    // Take field
+    pub fn take_related_node(&mut self) -> ::protobuf::RepeatedField<PagedCrossReferences_RelatedNode> {
+        ::std::mem::replace(&mut self.related_node, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .kythe.proto.serving.PagedCrossReferences.Caller caller = 4;
+
+
+    pub fn get_caller(&self) -> &[PagedCrossReferences_Caller] {
+        &self.caller
+    }
+    pub fn clear_caller(&mut self) {
+        self.caller.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_caller(&mut self, v: ::protobuf::RepeatedField<PagedCrossReferences_Caller>) {
+        self.caller = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_caller(&mut self) -> &mut ::protobuf::RepeatedField<PagedCrossReferences_Caller> {
+        &mut self.caller
+    }
+
+    // Take field
+    pub fn take_caller(&mut self) -> ::protobuf::RepeatedField<PagedCrossReferences_Caller> {
+        ::std::mem::replace(&mut self.caller, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .kythe.proto.serving.PagedCrossReferences.ScopedReference scoped_reference = 7;
+
+
+    pub fn get_scoped_reference(&self) -> &[PagedCrossReferences_ScopedReference] {
+        &self.scoped_reference
+    }
+    pub fn clear_scoped_reference(&mut self) {
+        self.scoped_reference.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scoped_reference(&mut self, v: ::protobuf::RepeatedField<PagedCrossReferences_ScopedReference>) {
+        self.scoped_reference = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_scoped_reference(&mut self) -> &mut ::protobuf::RepeatedField<PagedCrossReferences_ScopedReference> {
+        &mut self.scoped_reference
+    }
+
+    // Take field
+    pub fn take_scoped_reference(&mut self) -> ::protobuf::RepeatedField<PagedCrossReferences_ScopedReference> {
+        ::std::mem::replace(&mut self.scoped_reference, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .kythe.proto.serving.FileInfo file_info = 6;
+
+
+    pub fn get_file_info(&self) -> &[FileInfo] {
+        &self.file_info
+    }
+    pub fn clear_file_info(&mut self) {
+        self.file_info.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_info(&mut self, v: ::protobuf::RepeatedField<FileInfo>) {
+        self.file_info = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_file_info(&mut self) -> &mut ::protobuf::RepeatedField<FileInfo> {
+        &mut self.file_info
+    }
+
+    // Take field
+    pub fn take_file_info(&mut self) -> ::protobuf::RepeatedField<FileInfo> {
+        ::std::mem::replace(&mut self.file_info, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for PagedCrossReferences_Group {
+    fn is_initialized(&self) -> bool {
+        for v in &self.anchor {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.related_node {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.caller {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.scoped_reference {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.file_info {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.kind)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.build_config)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.anchor)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.related_node)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.caller)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.scoped_reference)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.file_info)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.kind.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.kind);
+        }
+        if !self.build_config.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.build_config);
+        }
+        for value in &self.anchor {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.related_node {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.caller {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.scoped_reference {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.file_info {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.kind.is_empty() {
+            os.write_string(1, &self.kind)?;
+        }
+        if !self.build_config.is_empty() {
+            os.write_string(5, &self.build_config)?;
+        }
+        for v in &self.anchor {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.related_node {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.caller {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.scoped_reference {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.file_info {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PagedCrossReferences_Group {
+        PagedCrossReferences_Group::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "kind",
+                |m: &PagedCrossReferences_Group| { &m.kind },
+                |m: &mut PagedCrossReferences_Group| { &mut m.kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "build_config",
+                |m: &PagedCrossReferences_Group| { &m.build_config },
+                |m: &mut PagedCrossReferences_Group| { &mut m.build_config },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ExpandedAnchor>>(
+                "anchor",
+                |m: &PagedCrossReferences_Group| { &m.anchor },
+                |m: &mut PagedCrossReferences_Group| { &mut m.anchor },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PagedCrossReferences_RelatedNode>>(
+                "related_node",
+                |m: &PagedCrossReferences_Group| { &m.related_node },
+                |m: &mut PagedCrossReferences_Group| { &mut m.related_node },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PagedCrossReferences_Caller>>(
+                "caller",
+                |m: &PagedCrossReferences_Group| { &m.caller },
+                |m: &mut PagedCrossReferences_Group| { &mut m.caller },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PagedCrossReferences_ScopedReference>>(
+                "scoped_reference",
+                |m: &PagedCrossReferences_Group| { &m.scoped_reference },
+                |m: &mut PagedCrossReferences_Group| { &mut m.scoped_reference },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FileInfo>>(
+                "file_info",
+                |m: &PagedCrossReferences_Group| { &m.file_info },
+                |m: &mut PagedCrossReferences_Group| { &mut m.file_info },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PagedCrossReferences_Group>(
+                "PagedCrossReferences.Group",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PagedCrossReferences_Group {
+        static instance: ::protobuf::rt::LazyV2<PagedCrossReferences_Group> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PagedCrossReferences_Group::new)
+    }
+}
+
+impl ::protobuf::Clear for PagedCrossReferences_Group {
+    fn clear(&mut self) {
+        self.kind.clear();
+        self.build_config.clear();
+        self.anchor.clear();
+        self.related_node.clear();
+        self.caller.clear();
+        self.scoped_reference.clear();
+        self.file_info.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PagedCrossReferences_Group {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PagedCrossReferences_Group {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PagedCrossReferences_Page {
+    // message fields
+    pub page_key: ::std::string::String,
+    pub source_ticket: ::std::string::String,
+    pub group: ::protobuf::SingularPtrField<PagedCrossReferences_Group>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PagedCrossReferences_Page {
+    fn default() -> &'a PagedCrossReferences_Page {
+        <PagedCrossReferences_Page as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PagedCrossReferences_Page {
+    pub fn new() -> PagedCrossReferences_Page {
+        ::std::default::Default::default()
+    }
+
+    // string page_key = 1;
+
+
+    pub fn get_page_key(&self) -> &str {
+        &self.page_key
+    }
+    pub fn clear_page_key(&mut self) {
+        self.page_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_page_key(&mut self, v: ::std::string::String) {
+        self.page_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_page_key(&mut self) -> &mut ::std::string::String {
+        &mut self.page_key
+    }
+
+    // Take field
+    pub fn take_page_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.page_key, ::std::string::String::new())
+    }
+
+    // string source_ticket = 2;
+
+
+    pub fn get_source_ticket(&self) -> &str {
+        &self.source_ticket
+    }
+    pub fn clear_source_ticket(&mut self) {
+        self.source_ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source_ticket(&mut self, v: ::std::string::String) {
+        self.source_ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source_ticket(&mut self) -> &mut ::std::string::String {
+        &mut self.source_ticket
+    }
+
+    // Take field
+    pub fn take_source_ticket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.source_ticket, ::std::string::String::new())
+    }
+
+    // .kythe.proto.serving.PagedCrossReferences.Group group = 3;
+
+
+    pub fn get_group(&self) -> &PagedCrossReferences_Group {
+        self.group.as_ref().unwrap_or_else(|| <PagedCrossReferences_Group as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_group(&mut self) {
+        self.group.clear();
+    }
+
+    pub fn has_group(&self) -> bool {
+        self.group.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_group(&mut self, v: PagedCrossReferences_Group) {
+        self.group = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_group(&mut self) -> &mut PagedCrossReferences_Group {
+        if self.group.is_none() {
+            self.group.set_default();
+        }
+        self.group.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_group(&mut self) -> PagedCrossReferences_Group {
+        self.group.take().unwrap_or_else(|| PagedCrossReferences_Group::new())
+    }
+}
+
+impl ::protobuf::Message for PagedCrossReferences_Page {
+    fn is_initialized(&self) -> bool {
+        for v in &self.group {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.page_key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.source_ticket)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.group)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.page_key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.page_key);
+        }
+        if !self.source_ticket.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.source_ticket);
+        }
+        if let Some(ref v) = self.group.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.page_key.is_empty() {
+            os.write_string(1, &self.page_key)?;
+        }
+        if !self.source_ticket.is_empty() {
+            os.write_string(2, &self.source_ticket)?;
+        }
+        if let Some(ref v) = self.group.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PagedCrossReferences_Page {
+        PagedCrossReferences_Page::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "page_key",
+                |m: &PagedCrossReferences_Page| { &m.page_key },
+                |m: &mut PagedCrossReferences_Page| { &mut m.page_key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "source_ticket",
+                |m: &PagedCrossReferences_Page| { &m.source_ticket },
+                |m: &mut PagedCrossReferences_Page| { &mut m.source_ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PagedCrossReferences_Group>>(
+                "group",
+                |m: &PagedCrossReferences_Page| { &m.group },
+                |m: &mut PagedCrossReferences_Page| { &mut m.group },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PagedCrossReferences_Page>(
+                "PagedCrossReferences.Page",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PagedCrossReferences_Page {
+        static instance: ::protobuf::rt::LazyV2<PagedCrossReferences_Page> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PagedCrossReferences_Page::new)
+    }
+}
+
+impl ::protobuf::Clear for PagedCrossReferences_Page {
+    fn clear(&mut self) {
+        self.page_key.clear();
+        self.source_ticket.clear();
+        self.group.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PagedCrossReferences_Page {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PagedCrossReferences_Page {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PagedCrossReferences_PageIndex {
+    // message fields
+    pub kind: ::std::string::String,
+    pub count: i32,
+    pub page_key: ::std::string::String,
+    pub build_config: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PagedCrossReferences_PageIndex {
+    fn default() -> &'a PagedCrossReferences_PageIndex {
+        <PagedCrossReferences_PageIndex as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PagedCrossReferences_PageIndex {
+    pub fn new() -> PagedCrossReferences_PageIndex {
+        ::std::default::Default::default()
+    }
+
+    // string kind = 1;
+
+
+    pub fn get_kind(&self) -> &str {
+        &self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: ::std::string::String) {
+        self.kind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_kind(&mut self) -> &mut ::std::string::String {
+        &mut self.kind
+    }
+
+    // Take field
+    pub fn take_kind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.kind, ::std::string::String::new())
+    }
+
+    // int32 count = 2;
+
+
+    pub fn get_count(&self) -> i32 {
+        self.count
+    }
+    pub fn clear_count(&mut self) {
+        self.count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: i32) {
+        self.count = v;
+    }
+
+    // string page_key = 3;
+
+
+    pub fn get_page_key(&self) -> &str {
+        &self.page_key
+    }
+    pub fn clear_page_key(&mut self) {
+        self.page_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_page_key(&mut self, v: ::std::string::String) {
+        self.page_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_page_key(&mut self) -> &mut ::std::string::String {
+        &mut self.page_key
+    }
+
+    // Take field
+    pub fn take_page_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.page_key, ::std::string::String::new())
+    }
+
+    // string build_config = 4;
+
+
+    pub fn get_build_config(&self) -> &str {
+        &self.build_config
+    }
+    pub fn clear_build_config(&mut self) {
+        self.build_config.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_build_config(&mut self, v: ::std::string::String) {
+        self.build_config = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_build_config(&mut self) -> &mut ::std::string::String {
+        &mut self.build_config
+    }
+
+    // Take field
+    pub fn take_build_config(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.build_config, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for PagedCrossReferences_PageIndex {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.kind)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.count = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.page_key)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.build_config)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.kind.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.kind);
+        }
+        if self.count != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.page_key.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.page_key);
+        }
+        if !self.build_config.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.build_config);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.kind.is_empty() {
+            os.write_string(1, &self.kind)?;
+        }
+        if self.count != 0 {
+            os.write_int32(2, self.count)?;
+        }
+        if !self.page_key.is_empty() {
+            os.write_string(3, &self.page_key)?;
+        }
+        if !self.build_config.is_empty() {
+            os.write_string(4, &self.build_config)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PagedCrossReferences_PageIndex {
+        PagedCrossReferences_PageIndex::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "kind",
+                |m: &PagedCrossReferences_PageIndex| { &m.kind },
+                |m: &mut PagedCrossReferences_PageIndex| { &mut m.kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "count",
+                |m: &PagedCrossReferences_PageIndex| { &m.count },
+                |m: &mut PagedCrossReferences_PageIndex| { &mut m.count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "page_key",
+                |m: &PagedCrossReferences_PageIndex| { &m.page_key },
+                |m: &mut PagedCrossReferences_PageIndex| { &mut m.page_key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "build_config",
+                |m: &PagedCrossReferences_PageIndex| { &m.build_config },
+                |m: &mut PagedCrossReferences_PageIndex| { &mut m.build_config },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PagedCrossReferences_PageIndex>(
+                "PagedCrossReferences.PageIndex",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PagedCrossReferences_PageIndex {
+        static instance: ::protobuf::rt::LazyV2<PagedCrossReferences_PageIndex> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PagedCrossReferences_PageIndex::new)
+    }
+}
+
+impl ::protobuf::Clear for PagedCrossReferences_PageIndex {
+    fn clear(&mut self) {
+        self.kind.clear();
+        self.count = 0;
+        self.page_key.clear();
+        self.build_config.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PagedCrossReferences_PageIndex {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PagedCrossReferences_PageIndex {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PagedCrossReferences_PageSearchIndex {
+    // message fields
+    pub by_corpus: ::protobuf::SingularPtrField<PagedCrossReferences_PageSearchIndex_Postings>,
+    pub by_root: ::protobuf::SingularPtrField<PagedCrossReferences_PageSearchIndex_Postings>,
+    pub by_path: ::protobuf::SingularPtrField<PagedCrossReferences_PageSearchIndex_Postings>,
+    pub by_resolved_path: ::protobuf::SingularPtrField<PagedCrossReferences_PageSearchIndex_Postings>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PagedCrossReferences_PageSearchIndex {
+    fn default() -> &'a PagedCrossReferences_PageSearchIndex {
+        <PagedCrossReferences_PageSearchIndex as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PagedCrossReferences_PageSearchIndex {
+    pub fn new() -> PagedCrossReferences_PageSearchIndex {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.serving.PagedCrossReferences.PageSearchIndex.Postings by_corpus = 1;
+
+
+    pub fn get_by_corpus(&self) -> &PagedCrossReferences_PageSearchIndex_Postings {
+        self.by_corpus.as_ref().unwrap_or_else(|| <PagedCrossReferences_PageSearchIndex_Postings as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_by_corpus(&mut self) {
+        self.by_corpus.clear();
+    }
+
+    pub fn has_by_corpus(&self) -> bool {
+        self.by_corpus.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_by_corpus(&mut self, v: PagedCrossReferences_PageSearchIndex_Postings) {
+        self.by_corpus = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_by_corpus(&mut self) -> &mut PagedCrossReferences_PageSearchIndex_Postings {
+        if self.by_corpus.is_none() {
+            self.by_corpus.set_default();
+        }
+        self.by_corpus.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_by_corpus(&mut self) -> PagedCrossReferences_PageSearchIndex_Postings {
+        self.by_corpus.take().unwrap_or_else(|| PagedCrossReferences_PageSearchIndex_Postings::new())
+    }
+
+    // .kythe.proto.serving.PagedCrossReferences.PageSearchIndex.Postings by_root = 2;
+
+
+    pub fn get_by_root(&self) -> &PagedCrossReferences_PageSearchIndex_Postings {
+        self.by_root.as_ref().unwrap_or_else(|| <PagedCrossReferences_PageSearchIndex_Postings as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_by_root(&mut self) {
+        self.by_root.clear();
+    }
+
+    pub fn has_by_root(&self) -> bool {
+        self.by_root.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_by_root(&mut self, v: PagedCrossReferences_PageSearchIndex_Postings) {
+        self.by_root = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_by_root(&mut self) -> &mut PagedCrossReferences_PageSearchIndex_Postings {
+        if self.by_root.is_none() {
+            self.by_root.set_default();
+        }
+        self.by_root.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_by_root(&mut self) -> PagedCrossReferences_PageSearchIndex_Postings {
+        self.by_root.take().unwrap_or_else(|| PagedCrossReferences_PageSearchIndex_Postings::new())
+    }
+
+    // .kythe.proto.serving.PagedCrossReferences.PageSearchIndex.Postings by_path = 3;
+
+
+    pub fn get_by_path(&self) -> &PagedCrossReferences_PageSearchIndex_Postings {
+        self.by_path.as_ref().unwrap_or_else(|| <PagedCrossReferences_PageSearchIndex_Postings as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_by_path(&mut self) {
+        self.by_path.clear();
+    }
+
+    pub fn has_by_path(&self) -> bool {
+        self.by_path.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_by_path(&mut self, v: PagedCrossReferences_PageSearchIndex_Postings) {
+        self.by_path = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_by_path(&mut self) -> &mut PagedCrossReferences_PageSearchIndex_Postings {
+        if self.by_path.is_none() {
+            self.by_path.set_default();
+        }
+        self.by_path.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_by_path(&mut self) -> PagedCrossReferences_PageSearchIndex_Postings {
+        self.by_path.take().unwrap_or_else(|| PagedCrossReferences_PageSearchIndex_Postings::new())
+    }
+
+    // .kythe.proto.serving.PagedCrossReferences.PageSearchIndex.Postings by_resolved_path = 4;
+
+
+    pub fn get_by_resolved_path(&self) -> &PagedCrossReferences_PageSearchIndex_Postings {
+        self.by_resolved_path.as_ref().unwrap_or_else(|| <PagedCrossReferences_PageSearchIndex_Postings as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_by_resolved_path(&mut self) {
+        self.by_resolved_path.clear();
+    }
+
+    pub fn has_by_resolved_path(&self) -> bool {
+        self.by_resolved_path.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_by_resolved_path(&mut self, v: PagedCrossReferences_PageSearchIndex_Postings) {
+        self.by_resolved_path = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_by_resolved_path(&mut self) -> &mut PagedCrossReferences_PageSearchIndex_Postings {
+        if self.by_resolved_path.is_none() {
+            self.by_resolved_path.set_default();
+        }
+        self.by_resolved_path.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_by_resolved_path(&mut self) -> PagedCrossReferences_PageSearchIndex_Postings {
+        self.by_resolved_path.take().unwrap_or_else(|| PagedCrossReferences_PageSearchIndex_Postings::new())
+    }
+}
+
+impl ::protobuf::Message for PagedCrossReferences_PageSearchIndex {
+    fn is_initialized(&self) -> bool {
+        for v in &self.by_corpus {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.by_root {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.by_path {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.by_resolved_path {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.by_corpus)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.by_root)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.by_path)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.by_resolved_path)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.by_corpus.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.by_root.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.by_path.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.by_resolved_path.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.by_corpus.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.by_root.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.by_path.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.by_resolved_path.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PagedCrossReferences_PageSearchIndex {
+        PagedCrossReferences_PageSearchIndex::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PagedCrossReferences_PageSearchIndex_Postings>>(
+                "by_corpus",
+                |m: &PagedCrossReferences_PageSearchIndex| { &m.by_corpus },
+                |m: &mut PagedCrossReferences_PageSearchIndex| { &mut m.by_corpus },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PagedCrossReferences_PageSearchIndex_Postings>>(
+                "by_root",
+                |m: &PagedCrossReferences_PageSearchIndex| { &m.by_root },
+                |m: &mut PagedCrossReferences_PageSearchIndex| { &mut m.by_root },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PagedCrossReferences_PageSearchIndex_Postings>>(
+                "by_path",
+                |m: &PagedCrossReferences_PageSearchIndex| { &m.by_path },
+                |m: &mut PagedCrossReferences_PageSearchIndex| { &mut m.by_path },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PagedCrossReferences_PageSearchIndex_Postings>>(
+                "by_resolved_path",
+                |m: &PagedCrossReferences_PageSearchIndex| { &m.by_resolved_path },
+                |m: &mut PagedCrossReferences_PageSearchIndex| { &mut m.by_resolved_path },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PagedCrossReferences_PageSearchIndex>(
+                "PagedCrossReferences.PageSearchIndex",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PagedCrossReferences_PageSearchIndex {
+        static instance: ::protobuf::rt::LazyV2<PagedCrossReferences_PageSearchIndex> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PagedCrossReferences_PageSearchIndex::new)
+    }
+}
+
+impl ::protobuf::Clear for PagedCrossReferences_PageSearchIndex {
+    fn clear(&mut self) {
+        self.by_corpus.clear();
+        self.by_root.clear();
+        self.by_path.clear();
+        self.by_resolved_path.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PagedCrossReferences_PageSearchIndex {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PagedCrossReferences_PageSearchIndex {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PagedCrossReferences_PageSearchIndex_Pages {
+    // message fields
+    pub page_index: ::std::vec::Vec<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PagedCrossReferences_PageSearchIndex_Pages {
+    fn default() -> &'a PagedCrossReferences_PageSearchIndex_Pages {
+        <PagedCrossReferences_PageSearchIndex_Pages as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PagedCrossReferences_PageSearchIndex_Pages {
+    pub fn new() -> PagedCrossReferences_PageSearchIndex_Pages {
+        ::std::default::Default::default()
+    }
+
+    // repeated uint32 page_index = 1;
+
+
+    pub fn get_page_index(&self) -> &[u32] {
+        &self.page_index
+    }
+    pub fn clear_page_index(&mut self) {
+        self.page_index.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_page_index(&mut self, v: ::std::vec::Vec<u32>) {
+        self.page_index = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_page_index(&mut self) -> &mut ::std::vec::Vec<u32> {
+        &mut self.page_index
+    }
+
+    // Take field
+    pub fn take_page_index(&mut self) -> ::std::vec::Vec<u32> {
+        ::std::mem::replace(&mut self.page_index, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for PagedCrossReferences_PageSearchIndex_Pages {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_uint32_into(wire_type, is, &mut self.page_index)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.page_index.is_empty() {
+            my_size += ::protobuf::rt::vec_packed_varint_size(1, &self.page_index);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.page_index.is_empty() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            // TODO: Data size is computed again, it should be cached
+            os.write_raw_varint32(::protobuf::rt::vec_packed_varint_data_size(&self.page_index))?;
+            for v in &self.page_index {
+                os.write_uint32_no_tag(*v)?;
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PagedCrossReferences_PageSearchIndex_Pages {
+        PagedCrossReferences_PageSearchIndex_Pages::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "page_index",
+                |m: &PagedCrossReferences_PageSearchIndex_Pages| { &m.page_index },
+                |m: &mut PagedCrossReferences_PageSearchIndex_Pages| { &mut m.page_index },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PagedCrossReferences_PageSearchIndex_Pages>(
+                "PagedCrossReferences.PageSearchIndex.Pages",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PagedCrossReferences_PageSearchIndex_Pages {
+        static instance: ::protobuf::rt::LazyV2<PagedCrossReferences_PageSearchIndex_Pages> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PagedCrossReferences_PageSearchIndex_Pages::new)
+    }
+}
+
+impl ::protobuf::Clear for PagedCrossReferences_PageSearchIndex_Pages {
+    fn clear(&mut self) {
+        self.page_index.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PagedCrossReferences_PageSearchIndex_Pages {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PagedCrossReferences_PageSearchIndex_Pages {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PagedCrossReferences_PageSearchIndex_Postings {
+    // message fields
+    pub index: ::std::collections::HashMap<u32, PagedCrossReferences_PageSearchIndex_Pages>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PagedCrossReferences_PageSearchIndex_Postings {
+    fn default() -> &'a PagedCrossReferences_PageSearchIndex_Postings {
+        <PagedCrossReferences_PageSearchIndex_Postings as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PagedCrossReferences_PageSearchIndex_Postings {
+    pub fn new() -> PagedCrossReferences_PageSearchIndex_Postings {
+        ::std::default::Default::default()
+    }
+
+    // repeated .kythe.proto.serving.PagedCrossReferences.PageSearchIndex.Postings.IndexEntry index = 1;
+
+
+    pub fn get_index(&self) -> &::std::collections::HashMap<u32, PagedCrossReferences_PageSearchIndex_Pages> {
+        &self.index
+    }
+    pub fn clear_index(&mut self) {
+        self.index.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: ::std::collections::HashMap<u32, PagedCrossReferences_PageSearchIndex_Pages>) {
+        self.index = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_index(&mut self) -> &mut ::std::collections::HashMap<u32, PagedCrossReferences_PageSearchIndex_Pages> {
+        &mut self.index
+    }
+
+    // Take field
+    pub fn take_index(&mut self) -> ::std::collections::HashMap<u32, PagedCrossReferences_PageSearchIndex_Pages> {
+        ::std::mem::replace(&mut self.index, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for PagedCrossReferences_PageSearchIndex_Postings {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<PagedCrossReferences_PageSearchIndex_Pages>>(wire_type, is, &mut self.index)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<PagedCrossReferences_PageSearchIndex_Pages>>(1, &self.index);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<PagedCrossReferences_PageSearchIndex_Pages>>(1, &self.index, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PagedCrossReferences_PageSearchIndex_Postings {
+        PagedCrossReferences_PageSearchIndex_Postings::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeMessage<PagedCrossReferences_PageSearchIndex_Pages>>(
+                "index",
+                |m: &PagedCrossReferences_PageSearchIndex_Postings| { &m.index },
+                |m: &mut PagedCrossReferences_PageSearchIndex_Postings| { &mut m.index },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PagedCrossReferences_PageSearchIndex_Postings>(
+                "PagedCrossReferences.PageSearchIndex.Postings",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PagedCrossReferences_PageSearchIndex_Postings {
+        static instance: ::protobuf::rt::LazyV2<PagedCrossReferences_PageSearchIndex_Postings> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PagedCrossReferences_PageSearchIndex_Postings::new)
+    }
+}
+
+impl ::protobuf::Clear for PagedCrossReferences_PageSearchIndex_Postings {
+    fn clear(&mut self) {
+        self.index.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PagedCrossReferences_PageSearchIndex_Postings {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PagedCrossReferences_PageSearchIndex_Postings {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Document {
+    // message fields
+    pub ticket: ::std::string::String,
+    pub marked_source: ::protobuf::SingularPtrField<super::common::MarkedSource>,
+    pub raw_text: ::std::string::String,
+    pub link: ::protobuf::RepeatedField<super::common::Link>,
+    pub child_ticket: ::protobuf::RepeatedField<::std::string::String>,
+    pub node: ::protobuf::RepeatedField<Node>,
+    pub documented_by: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Document {
+    fn default() -> &'a Document {
+        <Document as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Document {
+    pub fn new() -> Document {
+        ::std::default::Default::default()
+    }
+
+    // string ticket = 1;
+
+
+    pub fn get_ticket(&self) -> &str {
+        &self.ticket
+    }
+    pub fn clear_ticket(&mut self) {
+        self.ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ticket(&mut self, v: ::std::string::String) {
+        self.ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ticket(&mut self) -> &mut ::std::string::String {
+        &mut self.ticket
+    }
+
+    // Take field
+    pub fn take_ticket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.ticket, ::std::string::String::new())
+    }
+
+    // .kythe.proto.common.MarkedSource marked_source = 2;
+
+
+    pub fn get_marked_source(&self) -> &super::common::MarkedSource {
+        self.marked_source.as_ref().unwrap_or_else(|| <super::common::MarkedSource as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_marked_source(&mut self) {
+        self.marked_source.clear();
+    }
+
+    pub fn has_marked_source(&self) -> bool {
+        self.marked_source.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_marked_source(&mut self, v: super::common::MarkedSource) {
+        self.marked_source = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_marked_source(&mut self) -> &mut super::common::MarkedSource {
+        if self.marked_source.is_none() {
+            self.marked_source.set_default();
+        }
+        self.marked_source.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_marked_source(&mut self) -> super::common::MarkedSource {
+        self.marked_source.take().unwrap_or_else(|| super::common::MarkedSource::new())
+    }
+
+    // string raw_text = 3;
+
+
+    pub fn get_raw_text(&self) -> &str {
+        &self.raw_text
+    }
+    pub fn clear_raw_text(&mut self) {
+        self.raw_text.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_raw_text(&mut self, v: ::std::string::String) {
+        self.raw_text = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_raw_text(&mut self) -> &mut ::std::string::String {
+        &mut self.raw_text
+    }
+
+    // Take field
+    pub fn take_raw_text(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.raw_text, ::std::string::String::new())
+    }
+
+    // repeated .kythe.proto.common.Link link = 4;
+
+
+    pub fn get_link(&self) -> &[super::common::Link] {
+        &self.link
+    }
+    pub fn clear_link(&mut self) {
+        self.link.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_link(&mut self, v: ::protobuf::RepeatedField<super::common::Link>) {
+        self.link = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_link(&mut self) -> &mut ::protobuf::RepeatedField<super::common::Link> {
+        &mut self.link
+    }
+
+    // Take field
+    pub fn take_link(&mut self) -> ::protobuf::RepeatedField<super::common::Link> {
+        ::std::mem::replace(&mut self.link, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string child_ticket = 5;
+
+
+    pub fn get_child_ticket(&self) -> &[::std::string::String] {
+        &self.child_ticket
+    }
+    pub fn clear_child_ticket(&mut self) {
+        self.child_ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_child_ticket(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.child_ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_child_ticket(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.child_ticket
+    }
+
+    // Take field
+    pub fn take_child_ticket(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.child_ticket, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .kythe.proto.serving.Node node = 6;
+
+
+    pub fn get_node(&self) -> &[Node] {
+        &self.node
+    }
+    pub fn clear_node(&mut self) {
+        self.node.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_node(&mut self, v: ::protobuf::RepeatedField<Node>) {
+        self.node = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_node(&mut self) -> &mut ::protobuf::RepeatedField<Node> {
+        &mut self.node
+    }
+
+    // Take field
+    pub fn take_node(&mut self) -> ::protobuf::RepeatedField<Node> {
+        ::std::mem::replace(&mut self.node, ::protobuf::RepeatedField::new())
+    }
+
+    // string documented_by = 7;
+
+
+    pub fn get_documented_by(&self) -> &str {
+        &self.documented_by
+    }
+    pub fn clear_documented_by(&mut self) {
+        self.documented_by.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_documented_by(&mut self, v: ::std::string::String) {
+        self.documented_by = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_documented_by(&mut self) -> &mut ::std::string::String {
+        &mut self.documented_by
+    }
+
+    // Take field
+    pub fn take_documented_by(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.documented_by, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Document {
+    fn is_initialized(&self) -> bool {
+        for v in &self.marked_source {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.link {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.node {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.ticket)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.marked_source)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.raw_text)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.link)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.child_ticket)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.node)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.documented_by)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.ticket.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.ticket);
+        }
+        if let Some(ref v) = self.marked_source.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.raw_text.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.raw_text);
+        }
+        for value in &self.link {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.child_ticket {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        for value in &self.node {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.documented_by.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.documented_by);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.ticket.is_empty() {
+            os.write_string(1, &self.ticket)?;
+        }
+        if let Some(ref v) = self.marked_source.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.raw_text.is_empty() {
+            os.write_string(3, &self.raw_text)?;
+        }
+        for v in &self.link {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.child_ticket {
+            os.write_string(5, &v)?;
+        };
+        for v in &self.node {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if !self.documented_by.is_empty() {
+            os.write_string(7, &self.documented_by)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Document {
+        Document::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "ticket",
+                |m: &Document| { &m.ticket },
+                |m: &mut Document| { &mut m.ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::MarkedSource>>(
+                "marked_source",
+                |m: &Document| { &m.marked_source },
+                |m: &mut Document| { &mut m.marked_source },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "raw_text",
+                |m: &Document| { &m.raw_text },
+                |m: &mut Document| { &mut m.raw_text },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::Link>>(
+                "link",
+                |m: &Document| { &m.link },
+                |m: &mut Document| { &mut m.link },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "child_ticket",
+                |m: &Document| { &m.child_ticket },
+                |m: &mut Document| { &mut m.child_ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Node>>(
+                "node",
+                |m: &Document| { &m.node },
+                |m: &mut Document| { &mut m.node },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "documented_by",
+                |m: &Document| { &m.documented_by },
+                |m: &mut Document| { &mut m.documented_by },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Document>(
+                "Document",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Document {
+        static instance: ::protobuf::rt::LazyV2<Document> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Document::new)
+    }
+}
+
+impl ::protobuf::Clear for Document {
+    fn clear(&mut self) {
+        self.ticket.clear();
+        self.marked_source.clear();
+        self.raw_text.clear();
+        self.link.clear();
+        self.child_ticket.clear();
+        self.node.clear();
+        self.documented_by.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Document {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Document {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct IdentifierMatch {
+    // message fields
+    pub qualified_name: ::std::string::String,
+    pub base_name: ::std::string::String,
+    pub node: ::protobuf::RepeatedField<IdentifierMatch_Node>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a IdentifierMatch {
+    fn default() -> &'a IdentifierMatch {
+        <IdentifierMatch as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl IdentifierMatch {
+    pub fn new() -> IdentifierMatch {
+        ::std::default::Default::default()
+    }
+
+    // string qualified_name = 1;
+
+
+    pub fn get_qualified_name(&self) -> &str {
+        &self.qualified_name
+    }
+    pub fn clear_qualified_name(&mut self) {
+        self.qualified_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_qualified_name(&mut self, v: ::std::string::String) {
+        self.qualified_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_qualified_name(&mut self) -> &mut ::std::string::String {
+        &mut self.qualified_name
+    }
+
+    // Take field
+    pub fn take_qualified_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.qualified_name, ::std::string::String::new())
+    }
+
+    // string base_name = 2;
+
+
+    pub fn get_base_name(&self) -> &str {
+        &self.base_name
+    }
+    pub fn clear_base_name(&mut self) {
+        self.base_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_base_name(&mut self, v: ::std::string::String) {
+        self.base_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_base_name(&mut self) -> &mut ::std::string::String {
+        &mut self.base_name
+    }
+
+    // Take field
+    pub fn take_base_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.base_name, ::std::string::String::new())
+    }
+
+    // repeated .kythe.proto.serving.IdentifierMatch.Node node = 3;
+
+
+    pub fn get_node(&self) -> &[IdentifierMatch_Node] {
+        &self.node
+    }
+    pub fn clear_node(&mut self) {
+        self.node.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_node(&mut self, v: ::protobuf::RepeatedField<IdentifierMatch_Node>) {
+        self.node = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_node(&mut self) -> &mut ::protobuf::RepeatedField<IdentifierMatch_Node> {
+        &mut self.node
+    }
+
+    // Take field
+    pub fn take_node(&mut self) -> ::protobuf::RepeatedField<IdentifierMatch_Node> {
+        ::std::mem::replace(&mut self.node, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for IdentifierMatch {
+    fn is_initialized(&self) -> bool {
+        for v in &self.node {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.qualified_name)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.base_name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.node)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.qualified_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.qualified_name);
+        }
+        if !self.base_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.base_name);
+        }
+        for value in &self.node {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.qualified_name.is_empty() {
+            os.write_string(1, &self.qualified_name)?;
+        }
+        if !self.base_name.is_empty() {
+            os.write_string(2, &self.base_name)?;
+        }
+        for v in &self.node {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> IdentifierMatch {
+        IdentifierMatch::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "qualified_name",
+                |m: &IdentifierMatch| { &m.qualified_name },
+                |m: &mut IdentifierMatch| { &mut m.qualified_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "base_name",
+                |m: &IdentifierMatch| { &m.base_name },
+                |m: &mut IdentifierMatch| { &mut m.base_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<IdentifierMatch_Node>>(
+                "node",
+                |m: &IdentifierMatch| { &m.node },
+                |m: &mut IdentifierMatch| { &mut m.node },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<IdentifierMatch>(
+                "IdentifierMatch",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static IdentifierMatch {
+        static instance: ::protobuf::rt::LazyV2<IdentifierMatch> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(IdentifierMatch::new)
+    }
+}
+
+impl ::protobuf::Clear for IdentifierMatch {
+    fn clear(&mut self) {
+        self.qualified_name.clear();
+        self.base_name.clear();
+        self.node.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for IdentifierMatch {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for IdentifierMatch {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct IdentifierMatch_Node {
+    // message fields
+    pub ticket: ::std::string::String,
+    pub canonical_node_ticket: ::std::string::String,
+    pub node_kind: ::std::string::String,
+    pub node_subkind: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a IdentifierMatch_Node {
+    fn default() -> &'a IdentifierMatch_Node {
+        <IdentifierMatch_Node as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl IdentifierMatch_Node {
+    pub fn new() -> IdentifierMatch_Node {
+        ::std::default::Default::default()
+    }
+
+    // string ticket = 1;
+
+
+    pub fn get_ticket(&self) -> &str {
+        &self.ticket
+    }
+    pub fn clear_ticket(&mut self) {
+        self.ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ticket(&mut self, v: ::std::string::String) {
+        self.ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ticket(&mut self) -> &mut ::std::string::String {
+        &mut self.ticket
+    }
+
+    // Take field
+    pub fn take_ticket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.ticket, ::std::string::String::new())
+    }
+
+    // string canonical_node_ticket = 4;
+
+
+    pub fn get_canonical_node_ticket(&self) -> &str {
+        &self.canonical_node_ticket
+    }
+    pub fn clear_canonical_node_ticket(&mut self) {
+        self.canonical_node_ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_canonical_node_ticket(&mut self, v: ::std::string::String) {
+        self.canonical_node_ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_canonical_node_ticket(&mut self) -> &mut ::std::string::String {
+        &mut self.canonical_node_ticket
+    }
+
+    // Take field
+    pub fn take_canonical_node_ticket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.canonical_node_ticket, ::std::string::String::new())
+    }
+
+    // string node_kind = 2;
+
+
+    pub fn get_node_kind(&self) -> &str {
+        &self.node_kind
+    }
+    pub fn clear_node_kind(&mut self) {
+        self.node_kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_node_kind(&mut self, v: ::std::string::String) {
+        self.node_kind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_node_kind(&mut self) -> &mut ::std::string::String {
+        &mut self.node_kind
+    }
+
+    // Take field
+    pub fn take_node_kind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.node_kind, ::std::string::String::new())
+    }
+
+    // string node_subkind = 3;
+
+
+    pub fn get_node_subkind(&self) -> &str {
+        &self.node_subkind
+    }
+    pub fn clear_node_subkind(&mut self) {
+        self.node_subkind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_node_subkind(&mut self, v: ::std::string::String) {
+        self.node_subkind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_node_subkind(&mut self) -> &mut ::std::string::String {
+        &mut self.node_subkind
+    }
+
+    // Take field
+    pub fn take_node_subkind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.node_subkind, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for IdentifierMatch_Node {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.ticket)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.canonical_node_ticket)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.node_kind)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.node_subkind)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.ticket.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.ticket);
+        }
+        if !self.canonical_node_ticket.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.canonical_node_ticket);
+        }
+        if !self.node_kind.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.node_kind);
+        }
+        if !self.node_subkind.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.node_subkind);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.ticket.is_empty() {
+            os.write_string(1, &self.ticket)?;
+        }
+        if !self.canonical_node_ticket.is_empty() {
+            os.write_string(4, &self.canonical_node_ticket)?;
+        }
+        if !self.node_kind.is_empty() {
+            os.write_string(2, &self.node_kind)?;
+        }
+        if !self.node_subkind.is_empty() {
+            os.write_string(3, &self.node_subkind)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> IdentifierMatch_Node {
+        IdentifierMatch_Node::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "ticket",
+                |m: &IdentifierMatch_Node| { &m.ticket },
+                |m: &mut IdentifierMatch_Node| { &mut m.ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "canonical_node_ticket",
+                |m: &IdentifierMatch_Node| { &m.canonical_node_ticket },
+                |m: &mut IdentifierMatch_Node| { &mut m.canonical_node_ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "node_kind",
+                |m: &IdentifierMatch_Node| { &m.node_kind },
+                |m: &mut IdentifierMatch_Node| { &mut m.node_kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "node_subkind",
+                |m: &IdentifierMatch_Node| { &m.node_subkind },
+                |m: &mut IdentifierMatch_Node| { &mut m.node_subkind },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<IdentifierMatch_Node>(
+                "IdentifierMatch.Node",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static IdentifierMatch_Node {
+        static instance: ::protobuf::rt::LazyV2<IdentifierMatch_Node> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(IdentifierMatch_Node::new)
+    }
+}
+
+impl ::protobuf::Clear for IdentifierMatch_Node {
+    fn clear(&mut self) {
+        self.ticket.clear();
+        self.canonical_node_ticket.clear();
+        self.node_kind.clear();
+        self.node_subkind.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for IdentifierMatch_Node {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for IdentifierMatch_Node {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Relatives {
+    // message fields
+    pub tickets: ::protobuf::RepeatedField<::std::string::String>,
+    pub field_type: Relatives_Type,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Relatives {
+    fn default() -> &'a Relatives {
+        <Relatives as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Relatives {
+    pub fn new() -> Relatives {
+        ::std::default::Default::default()
+    }
+
+    // repeated string tickets = 1;
+
+
+    pub fn get_tickets(&self) -> &[::std::string::String] {
+        &self.tickets
+    }
+    pub fn clear_tickets(&mut self) {
+        self.tickets.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tickets(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.tickets = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tickets(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.tickets
+    }
+
+    // Take field
+    pub fn take_tickets(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.tickets, ::protobuf::RepeatedField::new())
+    }
+
+    // .kythe.proto.serving.Relatives.Type type = 2;
+
+
+    pub fn get_field_type(&self) -> Relatives_Type {
+        self.field_type
+    }
+    pub fn clear_field_type(&mut self) {
+        self.field_type = Relatives_Type::UNKNOWN;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: Relatives_Type) {
+        self.field_type = v;
+    }
+}
+
+impl ::protobuf::Message for Relatives {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.tickets)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.field_type, 2, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.tickets {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        if self.field_type != Relatives_Type::UNKNOWN {
+            my_size += ::protobuf::rt::enum_size(2, self.field_type);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.tickets {
+            os.write_string(1, &v)?;
+        };
+        if self.field_type != Relatives_Type::UNKNOWN {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.field_type))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Relatives {
+        Relatives::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tickets",
+                |m: &Relatives| { &m.tickets },
+                |m: &mut Relatives| { &mut m.tickets },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Relatives_Type>>(
+                "type",
+                |m: &Relatives| { &m.field_type },
+                |m: &mut Relatives| { &mut m.field_type },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Relatives>(
+                "Relatives",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Relatives {
+        static instance: ::protobuf::rt::LazyV2<Relatives> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Relatives::new)
+    }
+}
+
+impl ::protobuf::Clear for Relatives {
+    fn clear(&mut self) {
+        self.tickets.clear();
+        self.field_type = Relatives_Type::UNKNOWN;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Relatives {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Relatives {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Relatives_Type {
+    UNKNOWN = 0,
+    PARENTS = 1,
+    CHILDREN = 2,
+}
+
+impl ::protobuf::ProtobufEnum for Relatives_Type {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Relatives_Type> {
+        match value {
+            0 => ::std::option::Option::Some(Relatives_Type::UNKNOWN),
+            1 => ::std::option::Option::Some(Relatives_Type::PARENTS),
+            2 => ::std::option::Option::Some(Relatives_Type::CHILDREN),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Relatives_Type] = &[
+            Relatives_Type::UNKNOWN,
+            Relatives_Type::PARENTS,
+            Relatives_Type::CHILDREN,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<Relatives_Type>("Relatives.Type", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for Relatives_Type {
+}
+
+impl ::std::default::Default for Relatives_Type {
+    fn default() -> Self {
+        Relatives_Type::UNKNOWN
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Relatives_Type {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Callgraph {
+    // message fields
+    pub tickets: ::protobuf::RepeatedField<::std::string::String>,
+    pub field_type: Callgraph_Type,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Callgraph {
+    fn default() -> &'a Callgraph {
+        <Callgraph as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Callgraph {
+    pub fn new() -> Callgraph {
+        ::std::default::Default::default()
+    }
+
+    // repeated string tickets = 1;
+
+
+    pub fn get_tickets(&self) -> &[::std::string::String] {
+        &self.tickets
+    }
+    pub fn clear_tickets(&mut self) {
+        self.tickets.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tickets(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.tickets = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tickets(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.tickets
+    }
+
+    // Take field
+    pub fn take_tickets(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.tickets, ::protobuf::RepeatedField::new())
+    }
+
+    // .kythe.proto.serving.Callgraph.Type type = 2;
+
+
+    pub fn get_field_type(&self) -> Callgraph_Type {
+        self.field_type
+    }
+    pub fn clear_field_type(&mut self) {
+        self.field_type = Callgraph_Type::UNKNOWN;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: Callgraph_Type) {
+        self.field_type = v;
+    }
+}
+
+impl ::protobuf::Message for Callgraph {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.tickets)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.field_type, 2, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.tickets {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        if self.field_type != Callgraph_Type::UNKNOWN {
+            my_size += ::protobuf::rt::enum_size(2, self.field_type);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.tickets {
+            os.write_string(1, &v)?;
+        };
+        if self.field_type != Callgraph_Type::UNKNOWN {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.field_type))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Callgraph {
+        Callgraph::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "tickets",
+                |m: &Callgraph| { &m.tickets },
+                |m: &mut Callgraph| { &mut m.tickets },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Callgraph_Type>>(
+                "type",
+                |m: &Callgraph| { &m.field_type },
+                |m: &mut Callgraph| { &mut m.field_type },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Callgraph>(
+                "Callgraph",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Callgraph {
+        static instance: ::protobuf::rt::LazyV2<Callgraph> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Callgraph::new)
+    }
+}
+
+impl ::protobuf::Clear for Callgraph {
+    fn clear(&mut self) {
+        self.tickets.clear();
+        self.field_type = Callgraph_Type::UNKNOWN;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Callgraph {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Callgraph {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Callgraph_Type {
+    UNKNOWN = 0,
+    CALLER = 1,
+    CALLEE = 2,
+}
+
+impl ::protobuf::ProtobufEnum for Callgraph_Type {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Callgraph_Type> {
+        match value {
+            0 => ::std::option::Option::Some(Callgraph_Type::UNKNOWN),
+            1 => ::std::option::Option::Some(Callgraph_Type::CALLER),
+            2 => ::std::option::Option::Some(Callgraph_Type::CALLEE),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Callgraph_Type] = &[
+            Callgraph_Type::UNKNOWN,
+            Callgraph_Type::CALLER,
+            Callgraph_Type::CALLEE,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<Callgraph_Type>("Callgraph.Type", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for Callgraph_Type {
+}
+
+impl ::std::default::Default for Callgraph_Type {
+    fn default() -> Self {
+        Callgraph_Type::UNKNOWN
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Callgraph_Type {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Diff {
+    // message fields
+    pub span_length: ::std::vec::Vec<i32>,
+    pub span_type: ::std::vec::Vec<Diff_Type>,
+    pub span_newlines: ::std::vec::Vec<i32>,
+    pub span_first_newline: ::std::vec::Vec<i32>,
+    pub span_last_newline: ::std::vec::Vec<i32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Diff {
+    fn default() -> &'a Diff {
+        <Diff as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Diff {
+    pub fn new() -> Diff {
+        ::std::default::Default::default()
+    }
+
+    // repeated int32 span_length = 1;
+
+
+    pub fn get_span_length(&self) -> &[i32] {
+        &self.span_length
+    }
+    pub fn clear_span_length(&mut self) {
+        self.span_length.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_span_length(&mut self, v: ::std::vec::Vec<i32>) {
+        self.span_length = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_span_length(&mut self) -> &mut ::std::vec::Vec<i32> {
+        &mut self.span_length
+    }
+
+    // Take field
+    pub fn take_span_length(&mut self) -> ::std::vec::Vec<i32> {
+        ::std::mem::replace(&mut self.span_length, ::std::vec::Vec::new())
+    }
+
+    // repeated .kythe.proto.serving.Diff.Type span_type = 2;
+
+
+    pub fn get_span_type(&self) -> &[Diff_Type] {
+        &self.span_type
+    }
+    pub fn clear_span_type(&mut self) {
+        self.span_type.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_span_type(&mut self, v: ::std::vec::Vec<Diff_Type>) {
+        self.span_type = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_span_type(&mut self) -> &mut ::std::vec::Vec<Diff_Type> {
+        &mut self.span_type
+    }
+
+    // Take field
+    pub fn take_span_type(&mut self) -> ::std::vec::Vec<Diff_Type> {
+        ::std::mem::replace(&mut self.span_type, ::std::vec::Vec::new())
+    }
+
+    // repeated int32 span_newlines = 3;
+
+
+    pub fn get_span_newlines(&self) -> &[i32] {
+        &self.span_newlines
+    }
+    pub fn clear_span_newlines(&mut self) {
+        self.span_newlines.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_span_newlines(&mut self, v: ::std::vec::Vec<i32>) {
+        self.span_newlines = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_span_newlines(&mut self) -> &mut ::std::vec::Vec<i32> {
+        &mut self.span_newlines
+    }
+
+    // Take field
+    pub fn take_span_newlines(&mut self) -> ::std::vec::Vec<i32> {
+        ::std::mem::replace(&mut self.span_newlines, ::std::vec::Vec::new())
+    }
+
+    // repeated int32 span_first_newline = 4;
+
+
+    pub fn get_span_first_newline(&self) -> &[i32] {
+        &self.span_first_newline
+    }
+    pub fn clear_span_first_newline(&mut self) {
+        self.span_first_newline.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_span_first_newline(&mut self, v: ::std::vec::Vec<i32>) {
+        self.span_first_newline = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_span_first_newline(&mut self) -> &mut ::std::vec::Vec<i32> {
+        &mut self.span_first_newline
+    }
+
+    // Take field
+    pub fn take_span_first_newline(&mut self) -> ::std::vec::Vec<i32> {
+        ::std::mem::replace(&mut self.span_first_newline, ::std::vec::Vec::new())
+    }
+
+    // repeated int32 span_last_newline = 5;
+
+
+    pub fn get_span_last_newline(&self) -> &[i32] {
+        &self.span_last_newline
+    }
+    pub fn clear_span_last_newline(&mut self) {
+        self.span_last_newline.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_span_last_newline(&mut self, v: ::std::vec::Vec<i32>) {
+        self.span_last_newline = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_span_last_newline(&mut self) -> &mut ::std::vec::Vec<i32> {
+        &mut self.span_last_newline
+    }
+
+    // Take field
+    pub fn take_span_last_newline(&mut self) -> ::std::vec::Vec<i32> {
+        ::std::mem::replace(&mut self.span_last_newline, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for Diff {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_int32_into(wire_type, is, &mut self.span_length)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_enum_with_unknown_fields_into(wire_type, is, &mut self.span_type, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_int32_into(wire_type, is, &mut self.span_newlines)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_int32_into(wire_type, is, &mut self.span_first_newline)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_int32_into(wire_type, is, &mut self.span_last_newline)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.span_length.is_empty() {
+            my_size += ::protobuf::rt::vec_packed_varint_size(1, &self.span_length);
+        }
+        if !self.span_type.is_empty() {
+            my_size += ::protobuf::rt::vec_packed_enum_size(2, &self.span_type);
+        }
+        if !self.span_newlines.is_empty() {
+            my_size += ::protobuf::rt::vec_packed_varint_size(3, &self.span_newlines);
+        }
+        if !self.span_first_newline.is_empty() {
+            my_size += ::protobuf::rt::vec_packed_varint_size(4, &self.span_first_newline);
+        }
+        if !self.span_last_newline.is_empty() {
+            my_size += ::protobuf::rt::vec_packed_varint_size(5, &self.span_last_newline);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.span_length.is_empty() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            // TODO: Data size is computed again, it should be cached
+            os.write_raw_varint32(::protobuf::rt::vec_packed_varint_data_size(&self.span_length))?;
+            for v in &self.span_length {
+                os.write_int32_no_tag(*v)?;
+            };
+        }
+        if !self.span_type.is_empty() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            // TODO: Data size is computed again, it should be cached
+            os.write_raw_varint32(::protobuf::rt::vec_packed_enum_data_size(&self.span_type))?;
+            for v in &self.span_type {
+                os.write_enum_no_tag(::protobuf::ProtobufEnum::value(v))?;
+            };
+        }
+        if !self.span_newlines.is_empty() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            // TODO: Data size is computed again, it should be cached
+            os.write_raw_varint32(::protobuf::rt::vec_packed_varint_data_size(&self.span_newlines))?;
+            for v in &self.span_newlines {
+                os.write_int32_no_tag(*v)?;
+            };
+        }
+        if !self.span_first_newline.is_empty() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            // TODO: Data size is computed again, it should be cached
+            os.write_raw_varint32(::protobuf::rt::vec_packed_varint_data_size(&self.span_first_newline))?;
+            for v in &self.span_first_newline {
+                os.write_int32_no_tag(*v)?;
+            };
+        }
+        if !self.span_last_newline.is_empty() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            // TODO: Data size is computed again, it should be cached
+            os.write_raw_varint32(::protobuf::rt::vec_packed_varint_data_size(&self.span_last_newline))?;
+            for v in &self.span_last_newline {
+                os.write_int32_no_tag(*v)?;
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Diff {
+        Diff::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "span_length",
+                |m: &Diff| { &m.span_length },
+                |m: &mut Diff| { &mut m.span_length },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Diff_Type>>(
+                "span_type",
+                |m: &Diff| { &m.span_type },
+                |m: &mut Diff| { &mut m.span_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "span_newlines",
+                |m: &Diff| { &m.span_newlines },
+                |m: &mut Diff| { &mut m.span_newlines },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "span_first_newline",
+                |m: &Diff| { &m.span_first_newline },
+                |m: &mut Diff| { &mut m.span_first_newline },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "span_last_newline",
+                |m: &Diff| { &m.span_last_newline },
+                |m: &mut Diff| { &mut m.span_last_newline },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Diff>(
+                "Diff",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Diff {
+        static instance: ::protobuf::rt::LazyV2<Diff> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Diff::new)
+    }
+}
+
+impl ::protobuf::Clear for Diff {
+    fn clear(&mut self) {
+        self.span_length.clear();
+        self.span_type.clear();
+        self.span_newlines.clear();
+        self.span_first_newline.clear();
+        self.span_last_newline.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Diff {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Diff {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Diff_Type {
+    EQUAL = 0,
+    INSERT = 1,
+    DELETE = 2,
+}
+
+impl ::protobuf::ProtobufEnum for Diff_Type {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Diff_Type> {
+        match value {
+            0 => ::std::option::Option::Some(Diff_Type::EQUAL),
+            1 => ::std::option::Option::Some(Diff_Type::INSERT),
+            2 => ::std::option::Option::Some(Diff_Type::DELETE),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Diff_Type] = &[
+            Diff_Type::EQUAL,
+            Diff_Type::INSERT,
+            Diff_Type::DELETE,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<Diff_Type>("Diff.Type", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for Diff_Type {
+}
+
+impl ::std::default::Default for Diff_Type {
+    fn default() -> Self {
+        Diff_Type::EQUAL
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Diff_Type {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x19kythe/proto/serving.proto\x12\x13kythe.proto.serving\x1a\x18kythe/\
+    proto/common.proto\"\xb6\x01\n\x04Node\x12\x16\n\x06ticket\x18\x01\x20\
+    \x01(\tR\x06ticket\x12,\n\x04fact\x18\x02\x20\x03(\x0b2\x18.kythe.proto.\
+    common.FactR\x04fact\x12T\n\x13definition_location\x18\x03\x20\x01(\x0b2\
+    #.kythe.proto.serving.ExpandedAnchorR\x12definitionLocation\x12\x12\n\
+    \x04rank\x18\x04\x20\x01(\x01R\x04rank\"\xc8\x01\n\x04Edge\x121\n\x06sou\
+    rce\x18\x01\x20\x01(\x0b2\x19.kythe.proto.serving.NodeR\x06source\x12\
+    \x12\n\x04kind\x18\x02\x20\x01(\tR\x04kind\x12\x18\n\x07ordinal\x18\x05\
+    \x20\x01(\x05R\x07ordinal\x121\n\x06target\x18\x03\x20\x01(\x0b2\x19.kyt\
+    he.proto.serving.NodeR\x06target\x12,\n\x04fact\x18\x04\x20\x03(\x0b2\
+    \x18.kythe.proto.common.FactR\x04fact\"\xad\x01\n\tEdgeGroup\x12\x12\n\
+    \x04kind\x18\x01\x20\x01(\tR\x04kind\x127\n\x04edge\x18\x02\x20\x03(\x0b\
+    2#.kythe.proto.serving.EdgeGroup.EdgeR\x04edge\x1aS\n\x04Edge\x121\n\x06\
+    target\x18\x01\x20\x01(\x0b2\x19.kythe.proto.serving.NodeR\x06target\x12\
+    \x18\n\x07ordinal\x18\x02\x20\x01(\x05R\x07ordinal\"\xdb\x01\n\x0cPagedE\
+    dgeSet\x121\n\x06source\x18\x01\x20\x01(\x0b2\x19.kythe.proto.serving.No\
+    deR\x06source\x124\n\x05group\x18\x02\x20\x03(\x0b2\x1e.kythe.proto.serv\
+    ing.EdgeGroupR\x05group\x12#\n\x0btotal_edges\x18\x03\x20\x01(\x05R\ntot\
+    alEdgesB\x02\x18\x01\x12=\n\npage_index\x18\x04\x20\x03(\x0b2\x1e.kythe.\
+    proto.serving.PageIndexR\tpageIndex\"b\n\tPageIndex\x12\x1b\n\tedge_kind\
+    \x18\x01\x20\x01(\tR\x08edgeKind\x12\x1d\n\nedge_count\x18\x02\x20\x01(\
+    \x05R\tedgeCount\x12\x19\n\x08page_key\x18\x03\x20\x01(\tR\x07pageKey\"\
+    \x8b\x01\n\x08EdgePage\x12\x19\n\x08page_key\x18\x01\x20\x01(\tR\x07page\
+    Key\x12#\n\rsource_ticket\x18\x02\x20\x01(\tR\x0csourceTicket\x12?\n\x0b\
+    edges_group\x18\x03\x20\x01(\x0b2\x1e.kythe.proto.serving.EdgeGroupR\ned\
+    gesGroup\"\x89\x03\n\rFileDirectory\x12>\n\x05entry\x18\x03\x20\x03(\x0b\
+    2(.kythe.proto.serving.FileDirectory.EntryR\x05entry\x12&\n\x0csubdirect\
+    ory\x18\x01\x20\x03(\tR\x0csubdirectoryB\x02\x18\x01\x12#\n\x0bfile_tick\
+    et\x18\x02\x20\x03(\tR\nfileTicketB\x02\x18\x01\x1a\xbc\x01\n\x05Entry\
+    \x12;\n\x04kind\x18\x01\x20\x01(\x0e2'.kythe.proto.serving.FileDirectory\
+    .KindR\x04kind\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12!\n\x0cb\
+    uild_config\x18\x03\x20\x03(\tR\x0bbuildConfig\x12\x1c\n\tgenerated\x18\
+    \x04\x20\x01(\x08R\tgenerated\x12!\n\x0cmissing_text\x18\x05\x20\x01(\
+    \x08R\x0bmissingText\",\n\x04Kind\x12\x0b\n\x07UNKNOWN\x10\0\x12\x08\n\
+    \x04FILE\x10\x01\x12\r\n\tDIRECTORY\x10\x02\"\xa7\x01\n\x0bCorpusRoots\
+    \x12?\n\x06corpus\x18\x01\x20\x03(\x0b2'.kythe.proto.serving.CorpusRoots\
+    .CorpusR\x06corpus\x1aW\n\x06Corpus\x12\x16\n\x06corpus\x18\x01\x20\x01(\
+    \tR\x06corpus\x12\x12\n\x04root\x18\x02\x20\x03(\tR\x04root\x12!\n\x0cbu\
+    ild_config\x18\x03\x20\x03(\tR\x0bbuildConfig\"\x8f\x01\n\x04File\x12\
+    \x16\n\x06ticket\x18\x01\x20\x01(\tR\x06ticket\x12\x17\n\x04text\x18\x02\
+    \x20\x01(\x0cH\0R\x04text\x88\x01\x01\x12\x1a\n\x08encoding\x18\x03\x20\
+    \x01(\tR\x08encoding\x121\n\x04info\x18\x04\x20\x01(\x0b2\x1d.kythe.prot\
+    o.serving.FileInfoR\x04infoB\x07\n\x05_text\"\xdc\x01\n\tRawAnchor\x12\
+    \x16\n\x06ticket\x18\x01\x20\x01(\tR\x06ticket\x12!\n\x0cstart_offset\
+    \x18\x02\x20\x01(\x05R\x0bstartOffset\x12\x1d\n\nend_offset\x18\x03\x20\
+    \x01(\x05R\tendOffset\x12#\n\rsnippet_start\x18\x04\x20\x01(\x05R\x0csni\
+    ppetStart\x12\x1f\n\x0bsnippet_end\x18\x05\x20\x01(\x05R\nsnippetEnd\x12\
+    /\n\x13build_configuration\x18\x06\x20\x01(\tR\x12buildConfiguration\"\
+    \xdc\x02\n\x0eExpandedAnchor\x12\x16\n\x06ticket\x18\x01\x20\x01(\tR\x06\
+    ticket\x12\x12\n\x04kind\x18\x02\x20\x01(\tR\x04kind\x12\x12\n\x04text\
+    \x18\x04\x20\x01(\tR\x04text\x12,\n\x04span\x18\x05\x20\x01(\x0b2\x18.ky\
+    the.proto.common.SpanR\x04span\x12\x18\n\x07snippet\x18\x06\x20\x01(\tR\
+    \x07snippet\x12;\n\x0csnippet_span\x18\x07\x20\x01(\x0b2\x18.kythe.proto\
+    .common.SpanR\x0bsnippetSpan\x12/\n\x13build_configuration\x18\x08\x20\
+    \x01(\tR\x12buildConfiguration\x12\x12\n\x04rank\x18\t\x20\x01(\x01R\x04\
+    rank\x12:\n\tfile_info\x18\n\x20\x01(\x0b2\x1d.kythe.proto.serving.FileI\
+    nfoR\x08fileInfoJ\x04\x08\x03\x10\x04\"\xbe\x01\n\x08FileInfo\x12?\n\x0b\
+    corpus_path\x18\x01\x20\x01(\x0b2\x1e.kythe.proto.common.CorpusPathR\nco\
+    rpusPath\x12\x1a\n\x08revision\x18\x02\x20\x01(\tR\x08revision\x12,\n\
+    \x04hash\x18\x03\x20\x01(\x0b2\x18.kythe.proto.common.HashR\x04hash\x12'\
+    \n\x0fview_restricted\x18\x04\x20\x01(\x08R\x0eviewRestricted\"\x8b\x08\
+    \n\x0fFileDecorations\x12-\n\x04file\x18\x01\x20\x01(\x0b2\x19.kythe.pro\
+    to.serving.FileR\x04file\x12O\n\ndecoration\x18\x02\x20\x03(\x0b2/.kythe\
+    .proto.serving.FileDecorations.DecorationR\ndecoration\x121\n\x06target\
+    \x18\x04\x20\x03(\x0b2\x19.kythe.proto.serving.NodeR\x06target\x12R\n\
+    \x12target_definitions\x18\x03\x20\x03(\x0b2#.kythe.proto.serving.Expand\
+    edAnchorR\x11targetDefinitions\x12V\n\x0ftarget_override\x18\x05\x20\x03\
+    (\x0b2-.kythe.proto.serving.FileDecorations.OverrideR\x0etargetOverride\
+    \x12>\n\ndiagnostic\x18\x06\x20\x03(\x0b2\x1e.kythe.proto.common.Diagnos\
+    ticR\ndiagnostic\x12!\n\x0cgenerated_by\x18\x07\x20\x03(\tR\x0bgenerated\
+    By\x12:\n\tfile_info\x18\x08\x20\x03(\x0b2\x1d.kythe.proto.serving.FileI\
+    nfoR\x08fileInfo\x1a\xc4\x01\n\nDecoration\x126\n\x06anchor\x18\x01\x20\
+    \x01(\x0b2\x1e.kythe.proto.serving.RawAnchorR\x06anchor\x12\x12\n\x04kin\
+    d\x18\x02\x20\x01(\tR\x04kind\x12\x16\n\x06target\x18\x05\x20\x01(\tR\
+    \x06target\x12+\n\x11target_definition\x18\x04\x20\x01(\tR\x10targetDefi\
+    nition\x12%\n\x0esemantic_scope\x18\x06\x20\x01(\tR\rsemanticScope\x1a\
+    \xb2\x02\n\x08Override\x12\x1e\n\noverriding\x18\x01\x20\x01(\tR\noverri\
+    ding\x12\x1e\n\noverridden\x18\x02\x20\x01(\tR\noverridden\x123\n\x15ove\
+    rridden_definition\x18\x05\x20\x01(\tR\x14overriddenDefinition\x12F\n\
+    \x04kind\x18\x03\x20\x01(\x0e22.kythe.proto.serving.FileDecorations.Over\
+    ride.KindR\x04kind\x12E\n\rmarked_source\x18\x04\x20\x01(\x0b2\x20.kythe\
+    .proto.common.MarkedSourceR\x0cmarkedSource\"\"\n\x04Kind\x12\r\n\tOVERR\
+    IDES\x10\0\x12\x0b\n\x07EXTENDS\x10\x01\"\x84\x14\n\x14PagedCrossReferen\
+    ces\x12\x1d\n\nmerge_with\x18\x07\x20\x03(\tR\tmergeWith\x12#\n\rsource_\
+    ticket\x18\x01\x20\x01(\tR\x0csourceTicket\x12:\n\x0bsource_node\x18\x08\
+    \x20\x01(\x0b2\x19.kythe.proto.serving.NodeR\nsourceNode\x12E\n\x05group\
+    \x18\x02\x20\x03(\x0b2/.kythe.proto.serving.PagedCrossReferences.GroupR\
+    \x05group\x12R\n\npage_index\x18\x03\x20\x03(\x0b23.kythe.proto.serving.\
+    PagedCrossReferences.PageIndexR\tpageIndex\x12-\n\x10total_references\
+    \x18\x04\x20\x01(\x05R\x0ftotalReferencesB\x02\x18\x01\x12\x1e\n\nincomp\
+    lete\x18\x05\x20\x01(\x08R\nincomplete\x12E\n\rmarked_source\x18\x06\x20\
+    \x01(\x0b2\x20.kythe.proto.common.MarkedSourceR\x0cmarkedSource\x12e\n\
+    \x11page_search_index\x18\x0b\x20\x01(\x0b29.kythe.proto.serving.PagedCr\
+    ossReferences.PageSearchIndexR\x0fpageSearchIndex\x1aV\n\x0bRelatedNode\
+    \x12-\n\x04node\x18\x01\x20\x01(\x0b2\x19.kythe.proto.serving.NodeR\x04n\
+    ode\x12\x18\n\x07ordinal\x18\x02\x20\x01(\x05R\x07ordinal\x1a\xfd\x01\n\
+    \x0fScopedReference\x129\n\x05scope\x18\x01\x20\x01(\x0b2#.kythe.proto.s\
+    erving.ExpandedAnchorR\x05scope\x12%\n\x0esemantic_scope\x18\x02\x20\x01\
+    (\tR\rsemanticScope\x12E\n\rmarked_source\x18\x03\x20\x01(\x0b2\x20.kyth\
+    e.proto.common.MarkedSourceR\x0cmarkedSource\x12A\n\treference\x18\x04\
+    \x20\x03(\x0b2#.kythe.proto.serving.ExpandedAnchorR\treference\x1a\xf6\
+    \x01\n\x06Caller\x12;\n\x06caller\x18\x01\x20\x01(\x0b2#.kythe.proto.ser\
+    ving.ExpandedAnchorR\x06caller\x12'\n\x0fsemantic_caller\x18\x02\x20\x01\
+    (\tR\x0esemanticCaller\x12E\n\rmarked_source\x18\x03\x20\x01(\x0b2\x20.k\
+    ythe.proto.common.MarkedSourceR\x0cmarkedSource\x12?\n\x08callsite\x18\
+    \x04\x20\x03(\x0b2#.kythe.proto.serving.ExpandedAnchorR\x08callsite\x1a\
+    \xc1\x03\n\x05Group\x12\x12\n\x04kind\x18\x01\x20\x01(\tR\x04kind\x12!\n\
+    \x0cbuild_config\x18\x05\x20\x01(\tR\x0bbuildConfig\x12;\n\x06anchor\x18\
+    \x02\x20\x03(\x0b2#.kythe.proto.serving.ExpandedAnchorR\x06anchor\x12X\n\
+    \x0crelated_node\x18\x03\x20\x03(\x0b25.kythe.proto.serving.PagedCrossRe\
+    ferences.RelatedNodeR\x0brelatedNode\x12H\n\x06caller\x18\x04\x20\x03(\
+    \x0b20.kythe.proto.serving.PagedCrossReferences.CallerR\x06caller\x12d\n\
+    \x10scoped_reference\x18\x07\x20\x03(\x0b29.kythe.proto.serving.PagedCro\
+    ssReferences.ScopedReferenceR\x0fscopedReference\x12:\n\tfile_info\x18\
+    \x06\x20\x03(\x0b2\x1d.kythe.proto.serving.FileInfoR\x08fileInfo\x1a\x8d\
+    \x01\n\x04Page\x12\x19\n\x08page_key\x18\x01\x20\x01(\tR\x07pageKey\x12#\
+    \n\rsource_ticket\x18\x02\x20\x01(\tR\x0csourceTicket\x12E\n\x05group\
+    \x18\x03\x20\x01(\x0b2/.kythe.proto.serving.PagedCrossReferences.GroupR\
+    \x05group\x1as\n\tPageIndex\x12\x12\n\x04kind\x18\x01\x20\x01(\tR\x04kin\
+    d\x12\x14\n\x05count\x18\x02\x20\x01(\x05R\x05count\x12\x19\n\x08page_ke\
+    y\x18\x03\x20\x01(\tR\x07pageKey\x12!\n\x0cbuild_config\x18\x04\x20\x01(\
+    \tR\x0bbuildConfig\x1a\xb3\x05\n\x0fPageSearchIndex\x12_\n\tby_corpus\
+    \x18\x01\x20\x01(\x0b2B.kythe.proto.serving.PagedCrossReferences.PageSea\
+    rchIndex.PostingsR\x08byCorpus\x12[\n\x07by_root\x18\x02\x20\x01(\x0b2B.\
+    kythe.proto.serving.PagedCrossReferences.PageSearchIndex.PostingsR\x06by\
+    Root\x12[\n\x07by_path\x18\x03\x20\x01(\x0b2B.kythe.proto.serving.PagedC\
+    rossReferences.PageSearchIndex.PostingsR\x06byPath\x12l\n\x10by_resolved\
+    _path\x18\x04\x20\x01(\x0b2B.kythe.proto.serving.PagedCrossReferences.Pa\
+    geSearchIndex.PostingsR\x0ebyResolvedPath\x1a*\n\x05Pages\x12!\n\npage_i\
+    ndex\x18\x01\x20\x03(\rR\tpageIndexB\x02\x10\x01\x1a\xea\x01\n\x08Postin\
+    gs\x12c\n\x05index\x18\x01\x20\x03(\x0b2M.kythe.proto.serving.PagedCross\
+    References.PageSearchIndex.Postings.IndexEntryR\x05index\x1ay\n\nIndexEn\
+    try\x12\x10\n\x03key\x18\x01\x20\x01(\rR\x03key\x12U\n\x05value\x18\x02\
+    \x20\x01(\x0b2?.kythe.proto.serving.PagedCrossReferences.PageSearchIndex\
+    .PagesR\x05value:\x028\x01J\x04\x08\n\x10\x0b\"\xa9\x02\n\x08Document\
+    \x12\x16\n\x06ticket\x18\x01\x20\x01(\tR\x06ticket\x12E\n\rmarked_source\
+    \x18\x02\x20\x01(\x0b2\x20.kythe.proto.common.MarkedSourceR\x0cmarkedSou\
+    rce\x12\x19\n\x08raw_text\x18\x03\x20\x01(\tR\x07rawText\x12,\n\x04link\
+    \x18\x04\x20\x03(\x0b2\x18.kythe.proto.common.LinkR\x04link\x12!\n\x0cch\
+    ild_ticket\x18\x05\x20\x03(\tR\x0bchildTicket\x12-\n\x04node\x18\x06\x20\
+    \x03(\x0b2\x19.kythe.proto.serving.NodeR\x04node\x12#\n\rdocumented_by\
+    \x18\x07\x20\x01(\tR\x0cdocumentedBy\"\xa9\x02\n\x0fIdentifierMatch\x12%\
+    \n\x0equalified_name\x18\x01\x20\x01(\tR\rqualifiedName\x12\x1b\n\tbase_\
+    name\x18\x02\x20\x01(\tR\x08baseName\x12=\n\x04node\x18\x03\x20\x03(\x0b\
+    2).kythe.proto.serving.IdentifierMatch.NodeR\x04node\x1a\x92\x01\n\x04No\
+    de\x12\x16\n\x06ticket\x18\x01\x20\x01(\tR\x06ticket\x122\n\x15canonical\
+    _node_ticket\x18\x04\x20\x01(\tR\x13canonicalNodeTicket\x12\x1b\n\tnode_\
+    kind\x18\x02\x20\x01(\tR\x08nodeKind\x12!\n\x0cnode_subkind\x18\x03\x20\
+    \x01(\tR\x0bnodeSubkind\"\x8e\x01\n\tRelatives\x12\x18\n\x07tickets\x18\
+    \x01\x20\x03(\tR\x07tickets\x127\n\x04type\x18\x02\x20\x01(\x0e2#.kythe.\
+    proto.serving.Relatives.TypeR\x04type\".\n\x04Type\x12\x0b\n\x07UNKNOWN\
+    \x10\0\x12\x0b\n\x07PARENTS\x10\x01\x12\x0c\n\x08CHILDREN\x10\x02\"\x8b\
+    \x01\n\tCallgraph\x12\x18\n\x07tickets\x18\x01\x20\x03(\tR\x07tickets\
+    \x127\n\x04type\x18\x02\x20\x01(\x0e2#.kythe.proto.serving.Callgraph.Typ\
+    eR\x04type\"+\n\x04Type\x12\x0b\n\x07UNKNOWN\x10\0\x12\n\n\x06CALLER\x10\
+    \x01\x12\n\n\x06CALLEE\x10\x02\"\xa2\x02\n\x04Diff\x12#\n\x0bspan_length\
+    \x18\x01\x20\x03(\x05R\nspanLengthB\x02\x10\x01\x12?\n\tspan_type\x18\
+    \x02\x20\x03(\x0e2\x1e.kythe.proto.serving.Diff.TypeR\x08spanTypeB\x02\
+    \x10\x01\x12'\n\rspan_newlines\x18\x03\x20\x03(\x05R\x0cspanNewlinesB\
+    \x02\x10\x01\x120\n\x12span_first_newline\x18\x04\x20\x03(\x05R\x10spanF\
+    irstNewlineB\x02\x10\x01\x12.\n\x11span_last_newline\x18\x05\x20\x03(\
+    \x05R\x0fspanLastNewlineB\x02\x10\x01\")\n\x04Type\x12\t\n\x05EQUAL\x10\
+    \0\x12\n\n\x06INSERT\x10\x01\x12\n\n\x06DELETE\x10\x02BH\n\x1fcom.google\
+    .devtools.kythe.protoZ%kythe.io/kythe/proto/serving_go_protob\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
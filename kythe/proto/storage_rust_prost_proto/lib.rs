@@ -0,0 +1,1997 @@
+// This file is generated by //kythe/rust/protogen. DO NOT EDIT.
+
+#![allow(clippy::all)]
+pub mod kythe {
+    pub mod proto {
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct VName {
+            #[prost(string, tag = "1")]
+            pub signature: ::prost::alloc::string::String,
+            #[prost(string, tag = "2")]
+            pub corpus: ::prost::alloc::string::String,
+            #[prost(string, tag = "3")]
+            pub root: ::prost::alloc::string::String,
+            #[prost(string, tag = "4")]
+            pub path: ::prost::alloc::string::String,
+            #[prost(string, tag = "5")]
+            pub language: ::prost::alloc::string::String,
+        }
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct VNameMask {
+            #[prost(bool, tag = "1")]
+            pub signature: bool,
+            #[prost(bool, tag = "2")]
+            pub corpus: bool,
+            #[prost(bool, tag = "3")]
+            pub root: bool,
+            #[prost(bool, tag = "4")]
+            pub path: bool,
+            #[prost(bool, tag = "5")]
+            pub language: bool,
+        }
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct Entry {
+            #[prost(message, optional, tag = "1")]
+            pub source: ::core::option::Option<VName>,
+            #[prost(string, tag = "2")]
+            pub edge_kind: ::prost::alloc::string::String,
+            #[prost(message, optional, tag = "3")]
+            pub target: ::core::option::Option<VName>,
+            #[prost(string, tag = "4")]
+            pub fact_name: ::prost::alloc::string::String,
+            #[prost(bytes = "vec", tag = "5")]
+            pub fact_value: ::prost::alloc::vec::Vec<u8>,
+        }
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct Entries {
+            #[prost(message, repeated, tag = "1")]
+            pub entries: ::prost::alloc::vec::Vec<Entry>,
+        }
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct ReadRequest {
+            #[prost(message, optional, tag = "1")]
+            pub source: ::core::option::Option<VName>,
+            #[prost(string, tag = "2")]
+            pub edge_kind: ::prost::alloc::string::String,
+        }
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct WriteRequest {
+            #[prost(message, optional, tag = "1")]
+            pub source: ::core::option::Option<VName>,
+            #[prost(message, repeated, tag = "2")]
+            pub update: ::prost::alloc::vec::Vec<write_request::Update>,
+        }
+        /// Nested message and enum types in `WriteRequest`.
+        pub mod write_request {
+            #[allow(clippy::derive_partial_eq_without_eq)]
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct Update {
+                #[prost(string, tag = "1")]
+                pub edge_kind: ::prost::alloc::string::String,
+                #[prost(message, optional, tag = "2")]
+                pub target: ::core::option::Option<super::VName>,
+                #[prost(string, tag = "3")]
+                pub fact_name: ::prost::alloc::string::String,
+                #[prost(bytes = "vec", tag = "4")]
+                pub fact_value: ::prost::alloc::vec::Vec<u8>,
+            }
+        }
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct WriteReply {}
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct ScanRequest {
+            #[prost(message, optional, tag = "1")]
+            pub target: ::core::option::Option<VName>,
+            #[prost(string, tag = "2")]
+            pub edge_kind: ::prost::alloc::string::String,
+            #[prost(string, tag = "3")]
+            pub fact_prefix: ::prost::alloc::string::String,
+        }
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct CountRequest {
+            #[prost(int64, tag = "1")]
+            pub index: i64,
+            #[prost(int64, tag = "2")]
+            pub shards: i64,
+        }
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct CountReply {
+            #[prost(int64, tag = "1")]
+            pub entries: i64,
+        }
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct ShardRequest {
+            #[prost(int64, tag = "1")]
+            pub index: i64,
+            #[prost(int64, tag = "2")]
+            pub shards: i64,
+        }
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct VNameRewriteRule {
+            #[prost(string, tag = "1")]
+            pub pattern: ::prost::alloc::string::String,
+            #[prost(message, optional, tag = "2")]
+            pub v_name: ::core::option::Option<VName>,
+        }
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct VNameRewriteRules {
+            #[prost(message, repeated, tag = "1")]
+            pub rule: ::prost::alloc::vec::Vec<VNameRewriteRule>,
+        }
+        impl serde::Serialize for CountReply {
+            #[allow(deprecated)]
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                use serde::ser::SerializeStruct;
+                let mut len = 0;
+                if self.entries != 0 {
+                    len += 1;
+                }
+                let mut struct_ser = serializer
+                    .serialize_struct("kythe.proto.CountReply", len)?;
+                if self.entries != 0 {
+                    #[allow(clippy::needless_borrow)]
+                    struct_ser
+                        .serialize_field(
+                            "entries",
+                            ToString::to_string(&self.entries).as_str(),
+                        )?;
+                }
+                struct_ser.end()
+            }
+        }
+        impl<'de> serde::Deserialize<'de> for CountReply {
+            #[allow(deprecated)]
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                const FIELDS: &[&str] = &["entries"];
+                #[allow(clippy::enum_variant_names)]
+                enum GeneratedField {
+                    Entries,
+                }
+                impl<'de> serde::Deserialize<'de> for GeneratedField {
+                    fn deserialize<D>(
+                        deserializer: D,
+                    ) -> std::result::Result<GeneratedField, D::Error>
+                    where
+                        D: serde::Deserializer<'de>,
+                    {
+                        struct GeneratedVisitor;
+                        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                            type Value = GeneratedField;
+                            fn expecting(
+                                &self,
+                                formatter: &mut std::fmt::Formatter<'_>,
+                            ) -> std::fmt::Result {
+                                write!(formatter, "expected one of: {:?}", & FIELDS)
+                            }
+                            #[allow(unused_variables)]
+                            fn visit_str<E>(
+                                self,
+                                value: &str,
+                            ) -> std::result::Result<GeneratedField, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                match value {
+                                    "entries" => Ok(GeneratedField::Entries),
+                                    _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                                }
+                            }
+                        }
+                        deserializer.deserialize_identifier(GeneratedVisitor)
+                    }
+                }
+                struct GeneratedVisitor;
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = CountReply;
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
+                        formatter.write_str("struct kythe.proto.CountReply")
+                    }
+                    fn visit_map<V>(
+                        self,
+                        mut map_: V,
+                    ) -> std::result::Result<CountReply, V::Error>
+                    where
+                        V: serde::de::MapAccess<'de>,
+                    {
+                        let mut entries__ = None;
+                        while let Some(k) = map_.next_key()? {
+                            match k {
+                                GeneratedField::Entries => {
+                                    if entries__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("entries"));
+                                    }
+                                    entries__ = Some(
+                                        map_
+                                            .next_value::<::pbjson::private::NumberDeserialize<_>>()?
+                                            .0,
+                                    );
+                                }
+                            }
+                        }
+                        Ok(CountReply {
+                            entries: entries__.unwrap_or_default(),
+                        })
+                    }
+                }
+                deserializer
+                    .deserialize_struct(
+                        "kythe.proto.CountReply",
+                        FIELDS,
+                        GeneratedVisitor,
+                    )
+            }
+        }
+        impl serde::Serialize for CountRequest {
+            #[allow(deprecated)]
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                use serde::ser::SerializeStruct;
+                let mut len = 0;
+                if self.index != 0 {
+                    len += 1;
+                }
+                if self.shards != 0 {
+                    len += 1;
+                }
+                let mut struct_ser = serializer
+                    .serialize_struct("kythe.proto.CountRequest", len)?;
+                if self.index != 0 {
+                    #[allow(clippy::needless_borrow)]
+                    struct_ser
+                        .serialize_field(
+                            "index",
+                            ToString::to_string(&self.index).as_str(),
+                        )?;
+                }
+                if self.shards != 0 {
+                    #[allow(clippy::needless_borrow)]
+                    struct_ser
+                        .serialize_field(
+                            "shards",
+                            ToString::to_string(&self.shards).as_str(),
+                        )?;
+                }
+                struct_ser.end()
+            }
+        }
+        impl<'de> serde::Deserialize<'de> for CountRequest {
+            #[allow(deprecated)]
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                const FIELDS: &[&str] = &["index", "shards"];
+                #[allow(clippy::enum_variant_names)]
+                enum GeneratedField {
+                    Index,
+                    Shards,
+                }
+                impl<'de> serde::Deserialize<'de> for GeneratedField {
+                    fn deserialize<D>(
+                        deserializer: D,
+                    ) -> std::result::Result<GeneratedField, D::Error>
+                    where
+                        D: serde::Deserializer<'de>,
+                    {
+                        struct GeneratedVisitor;
+                        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                            type Value = GeneratedField;
+                            fn expecting(
+                                &self,
+                                formatter: &mut std::fmt::Formatter<'_>,
+                            ) -> std::fmt::Result {
+                                write!(formatter, "expected one of: {:?}", & FIELDS)
+                            }
+                            #[allow(unused_variables)]
+                            fn visit_str<E>(
+                                self,
+                                value: &str,
+                            ) -> std::result::Result<GeneratedField, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                match value {
+                                    "index" => Ok(GeneratedField::Index),
+                                    "shards" => Ok(GeneratedField::Shards),
+                                    _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                                }
+                            }
+                        }
+                        deserializer.deserialize_identifier(GeneratedVisitor)
+                    }
+                }
+                struct GeneratedVisitor;
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = CountRequest;
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
+                        formatter.write_str("struct kythe.proto.CountRequest")
+                    }
+                    fn visit_map<V>(
+                        self,
+                        mut map_: V,
+                    ) -> std::result::Result<CountRequest, V::Error>
+                    where
+                        V: serde::de::MapAccess<'de>,
+                    {
+                        let mut index__ = None;
+                        let mut shards__ = None;
+                        while let Some(k) = map_.next_key()? {
+                            match k {
+                                GeneratedField::Index => {
+                                    if index__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("index"));
+                                    }
+                                    index__ = Some(
+                                        map_
+                                            .next_value::<::pbjson::private::NumberDeserialize<_>>()?
+                                            .0,
+                                    );
+                                }
+                                GeneratedField::Shards => {
+                                    if shards__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("shards"));
+                                    }
+                                    shards__ = Some(
+                                        map_
+                                            .next_value::<::pbjson::private::NumberDeserialize<_>>()?
+                                            .0,
+                                    );
+                                }
+                            }
+                        }
+                        Ok(CountRequest {
+                            index: index__.unwrap_or_default(),
+                            shards: shards__.unwrap_or_default(),
+                        })
+                    }
+                }
+                deserializer
+                    .deserialize_struct(
+                        "kythe.proto.CountRequest",
+                        FIELDS,
+                        GeneratedVisitor,
+                    )
+            }
+        }
+        impl serde::Serialize for Entries {
+            #[allow(deprecated)]
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                use serde::ser::SerializeStruct;
+                let mut len = 0;
+                if !self.entries.is_empty() {
+                    len += 1;
+                }
+                let mut struct_ser = serializer
+                    .serialize_struct("kythe.proto.Entries", len)?;
+                if !self.entries.is_empty() {
+                    struct_ser.serialize_field("entries", &self.entries)?;
+                }
+                struct_ser.end()
+            }
+        }
+        impl<'de> serde::Deserialize<'de> for Entries {
+            #[allow(deprecated)]
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                const FIELDS: &[&str] = &["entries"];
+                #[allow(clippy::enum_variant_names)]
+                enum GeneratedField {
+                    Entries,
+                }
+                impl<'de> serde::Deserialize<'de> for GeneratedField {
+                    fn deserialize<D>(
+                        deserializer: D,
+                    ) -> std::result::Result<GeneratedField, D::Error>
+                    where
+                        D: serde::Deserializer<'de>,
+                    {
+                        struct GeneratedVisitor;
+                        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                            type Value = GeneratedField;
+                            fn expecting(
+                                &self,
+                                formatter: &mut std::fmt::Formatter<'_>,
+                            ) -> std::fmt::Result {
+                                write!(formatter, "expected one of: {:?}", & FIELDS)
+                            }
+                            #[allow(unused_variables)]
+                            fn visit_str<E>(
+                                self,
+                                value: &str,
+                            ) -> std::result::Result<GeneratedField, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                match value {
+                                    "entries" => Ok(GeneratedField::Entries),
+                                    _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                                }
+                            }
+                        }
+                        deserializer.deserialize_identifier(GeneratedVisitor)
+                    }
+                }
+                struct GeneratedVisitor;
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = Entries;
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
+                        formatter.write_str("struct kythe.proto.Entries")
+                    }
+                    fn visit_map<V>(
+                        self,
+                        mut map_: V,
+                    ) -> std::result::Result<Entries, V::Error>
+                    where
+                        V: serde::de::MapAccess<'de>,
+                    {
+                        let mut entries__ = None;
+                        while let Some(k) = map_.next_key()? {
+                            match k {
+                                GeneratedField::Entries => {
+                                    if entries__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("entries"));
+                                    }
+                                    entries__ = Some(map_.next_value()?);
+                                }
+                            }
+                        }
+                        Ok(Entries {
+                            entries: entries__.unwrap_or_default(),
+                        })
+                    }
+                }
+                deserializer
+                    .deserialize_struct("kythe.proto.Entries", FIELDS, GeneratedVisitor)
+            }
+        }
+        impl serde::Serialize for Entry {
+            #[allow(deprecated)]
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                use serde::ser::SerializeStruct;
+                let mut len = 0;
+                if self.source.is_some() {
+                    len += 1;
+                }
+                if !self.edge_kind.is_empty() {
+                    len += 1;
+                }
+                if self.target.is_some() {
+                    len += 1;
+                }
+                if !self.fact_name.is_empty() {
+                    len += 1;
+                }
+                if !self.fact_value.is_empty() {
+                    len += 1;
+                }
+                let mut struct_ser = serializer
+                    .serialize_struct("kythe.proto.Entry", len)?;
+                if let Some(v) = self.source.as_ref() {
+                    struct_ser.serialize_field("source", v)?;
+                }
+                if !self.edge_kind.is_empty() {
+                    struct_ser.serialize_field("edge_kind", &self.edge_kind)?;
+                }
+                if let Some(v) = self.target.as_ref() {
+                    struct_ser.serialize_field("target", v)?;
+                }
+                if !self.fact_name.is_empty() {
+                    struct_ser.serialize_field("fact_name", &self.fact_name)?;
+                }
+                if !self.fact_value.is_empty() {
+                    #[allow(clippy::needless_borrow)]
+                    struct_ser
+                        .serialize_field(
+                            "fact_value",
+                            pbjson::private::base64::encode(&self.fact_value).as_str(),
+                        )?;
+                }
+                struct_ser.end()
+            }
+        }
+        impl<'de> serde::Deserialize<'de> for Entry {
+            #[allow(deprecated)]
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                const FIELDS: &[&str] = &[
+                    "source",
+                    "edge_kind",
+                    "edgeKind",
+                    "target",
+                    "fact_name",
+                    "factName",
+                    "fact_value",
+                    "factValue",
+                ];
+                #[allow(clippy::enum_variant_names)]
+                enum GeneratedField {
+                    Source,
+                    EdgeKind,
+                    Target,
+                    FactName,
+                    FactValue,
+                }
+                impl<'de> serde::Deserialize<'de> for GeneratedField {
+                    fn deserialize<D>(
+                        deserializer: D,
+                    ) -> std::result::Result<GeneratedField, D::Error>
+                    where
+                        D: serde::Deserializer<'de>,
+                    {
+                        struct GeneratedVisitor;
+                        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                            type Value = GeneratedField;
+                            fn expecting(
+                                &self,
+                                formatter: &mut std::fmt::Formatter<'_>,
+                            ) -> std::fmt::Result {
+                                write!(formatter, "expected one of: {:?}", & FIELDS)
+                            }
+                            #[allow(unused_variables)]
+                            fn visit_str<E>(
+                                self,
+                                value: &str,
+                            ) -> std::result::Result<GeneratedField, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                match value {
+                                    "source" => Ok(GeneratedField::Source),
+                                    "edgeKind" | "edge_kind" => Ok(GeneratedField::EdgeKind),
+                                    "target" => Ok(GeneratedField::Target),
+                                    "factName" | "fact_name" => Ok(GeneratedField::FactName),
+                                    "factValue" | "fact_value" => Ok(GeneratedField::FactValue),
+                                    _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                                }
+                            }
+                        }
+                        deserializer.deserialize_identifier(GeneratedVisitor)
+                    }
+                }
+                struct GeneratedVisitor;
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = Entry;
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
+                        formatter.write_str("struct kythe.proto.Entry")
+                    }
+                    fn visit_map<V>(
+                        self,
+                        mut map_: V,
+                    ) -> std::result::Result<Entry, V::Error>
+                    where
+                        V: serde::de::MapAccess<'de>,
+                    {
+                        let mut source__ = None;
+                        let mut edge_kind__ = None;
+                        let mut target__ = None;
+                        let mut fact_name__ = None;
+                        let mut fact_value__ = None;
+                        while let Some(k) = map_.next_key()? {
+                            match k {
+                                GeneratedField::Source => {
+                                    if source__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("source"));
+                                    }
+                                    source__ = map_.next_value()?;
+                                }
+                                GeneratedField::EdgeKind => {
+                                    if edge_kind__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("edgeKind"));
+                                    }
+                                    edge_kind__ = Some(map_.next_value()?);
+                                }
+                                GeneratedField::Target => {
+                                    if target__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("target"));
+                                    }
+                                    target__ = map_.next_value()?;
+                                }
+                                GeneratedField::FactName => {
+                                    if fact_name__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("factName"));
+                                    }
+                                    fact_name__ = Some(map_.next_value()?);
+                                }
+                                GeneratedField::FactValue => {
+                                    if fact_value__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("factValue"));
+                                    }
+                                    fact_value__ = Some(
+                                        map_
+                                            .next_value::<::pbjson::private::BytesDeserialize<_>>()?
+                                            .0,
+                                    );
+                                }
+                            }
+                        }
+                        Ok(Entry {
+                            source: source__,
+                            edge_kind: edge_kind__.unwrap_or_default(),
+                            target: target__,
+                            fact_name: fact_name__.unwrap_or_default(),
+                            fact_value: fact_value__.unwrap_or_default(),
+                        })
+                    }
+                }
+                deserializer
+                    .deserialize_struct("kythe.proto.Entry", FIELDS, GeneratedVisitor)
+            }
+        }
+        impl serde::Serialize for ReadRequest {
+            #[allow(deprecated)]
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                use serde::ser::SerializeStruct;
+                let mut len = 0;
+                if self.source.is_some() {
+                    len += 1;
+                }
+                if !self.edge_kind.is_empty() {
+                    len += 1;
+                }
+                let mut struct_ser = serializer
+                    .serialize_struct("kythe.proto.ReadRequest", len)?;
+                if let Some(v) = self.source.as_ref() {
+                    struct_ser.serialize_field("source", v)?;
+                }
+                if !self.edge_kind.is_empty() {
+                    struct_ser.serialize_field("edge_kind", &self.edge_kind)?;
+                }
+                struct_ser.end()
+            }
+        }
+        impl<'de> serde::Deserialize<'de> for ReadRequest {
+            #[allow(deprecated)]
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                const FIELDS: &[&str] = &["source", "edge_kind", "edgeKind"];
+                #[allow(clippy::enum_variant_names)]
+                enum GeneratedField {
+                    Source,
+                    EdgeKind,
+                }
+                impl<'de> serde::Deserialize<'de> for GeneratedField {
+                    fn deserialize<D>(
+                        deserializer: D,
+                    ) -> std::result::Result<GeneratedField, D::Error>
+                    where
+                        D: serde::Deserializer<'de>,
+                    {
+                        struct GeneratedVisitor;
+                        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                            type Value = GeneratedField;
+                            fn expecting(
+                                &self,
+                                formatter: &mut std::fmt::Formatter<'_>,
+                            ) -> std::fmt::Result {
+                                write!(formatter, "expected one of: {:?}", & FIELDS)
+                            }
+                            #[allow(unused_variables)]
+                            fn visit_str<E>(
+                                self,
+                                value: &str,
+                            ) -> std::result::Result<GeneratedField, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                match value {
+                                    "source" => Ok(GeneratedField::Source),
+                                    "edgeKind" | "edge_kind" => Ok(GeneratedField::EdgeKind),
+                                    _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                                }
+                            }
+                        }
+                        deserializer.deserialize_identifier(GeneratedVisitor)
+                    }
+                }
+                struct GeneratedVisitor;
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = ReadRequest;
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
+                        formatter.write_str("struct kythe.proto.ReadRequest")
+                    }
+                    fn visit_map<V>(
+                        self,
+                        mut map_: V,
+                    ) -> std::result::Result<ReadRequest, V::Error>
+                    where
+                        V: serde::de::MapAccess<'de>,
+                    {
+                        let mut source__ = None;
+                        let mut edge_kind__ = None;
+                        while let Some(k) = map_.next_key()? {
+                            match k {
+                                GeneratedField::Source => {
+                                    if source__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("source"));
+                                    }
+                                    source__ = map_.next_value()?;
+                                }
+                                GeneratedField::EdgeKind => {
+                                    if edge_kind__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("edgeKind"));
+                                    }
+                                    edge_kind__ = Some(map_.next_value()?);
+                                }
+                            }
+                        }
+                        Ok(ReadRequest {
+                            source: source__,
+                            edge_kind: edge_kind__.unwrap_or_default(),
+                        })
+                    }
+                }
+                deserializer
+                    .deserialize_struct(
+                        "kythe.proto.ReadRequest",
+                        FIELDS,
+                        GeneratedVisitor,
+                    )
+            }
+        }
+        impl serde::Serialize for ScanRequest {
+            #[allow(deprecated)]
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                use serde::ser::SerializeStruct;
+                let mut len = 0;
+                if self.target.is_some() {
+                    len += 1;
+                }
+                if !self.edge_kind.is_empty() {
+                    len += 1;
+                }
+                if !self.fact_prefix.is_empty() {
+                    len += 1;
+                }
+                let mut struct_ser = serializer
+                    .serialize_struct("kythe.proto.ScanRequest", len)?;
+                if let Some(v) = self.target.as_ref() {
+                    struct_ser.serialize_field("target", v)?;
+                }
+                if !self.edge_kind.is_empty() {
+                    struct_ser.serialize_field("edge_kind", &self.edge_kind)?;
+                }
+                if !self.fact_prefix.is_empty() {
+                    struct_ser.serialize_field("fact_prefix", &self.fact_prefix)?;
+                }
+                struct_ser.end()
+            }
+        }
+        impl<'de> serde::Deserialize<'de> for ScanRequest {
+            #[allow(deprecated)]
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                const FIELDS: &[&str] = &[
+                    "target",
+                    "edge_kind",
+                    "edgeKind",
+                    "fact_prefix",
+                    "factPrefix",
+                ];
+                #[allow(clippy::enum_variant_names)]
+                enum GeneratedField {
+                    Target,
+                    EdgeKind,
+                    FactPrefix,
+                }
+                impl<'de> serde::Deserialize<'de> for GeneratedField {
+                    fn deserialize<D>(
+                        deserializer: D,
+                    ) -> std::result::Result<GeneratedField, D::Error>
+                    where
+                        D: serde::Deserializer<'de>,
+                    {
+                        struct GeneratedVisitor;
+                        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                            type Value = GeneratedField;
+                            fn expecting(
+                                &self,
+                                formatter: &mut std::fmt::Formatter<'_>,
+                            ) -> std::fmt::Result {
+                                write!(formatter, "expected one of: {:?}", & FIELDS)
+                            }
+                            #[allow(unused_variables)]
+                            fn visit_str<E>(
+                                self,
+                                value: &str,
+                            ) -> std::result::Result<GeneratedField, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                match value {
+                                    "target" => Ok(GeneratedField::Target),
+                                    "edgeKind" | "edge_kind" => Ok(GeneratedField::EdgeKind),
+                                    "factPrefix" | "fact_prefix" => {
+                                        Ok(GeneratedField::FactPrefix)
+                                    }
+                                    _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                                }
+                            }
+                        }
+                        deserializer.deserialize_identifier(GeneratedVisitor)
+                    }
+                }
+                struct GeneratedVisitor;
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = ScanRequest;
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
+                        formatter.write_str("struct kythe.proto.ScanRequest")
+                    }
+                    fn visit_map<V>(
+                        self,
+                        mut map_: V,
+                    ) -> std::result::Result<ScanRequest, V::Error>
+                    where
+                        V: serde::de::MapAccess<'de>,
+                    {
+                        let mut target__ = None;
+                        let mut edge_kind__ = None;
+                        let mut fact_prefix__ = None;
+                        while let Some(k) = map_.next_key()? {
+                            match k {
+                                GeneratedField::Target => {
+                                    if target__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("target"));
+                                    }
+                                    target__ = map_.next_value()?;
+                                }
+                                GeneratedField::EdgeKind => {
+                                    if edge_kind__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("edgeKind"));
+                                    }
+                                    edge_kind__ = Some(map_.next_value()?);
+                                }
+                                GeneratedField::FactPrefix => {
+                                    if fact_prefix__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("factPrefix"));
+                                    }
+                                    fact_prefix__ = Some(map_.next_value()?);
+                                }
+                            }
+                        }
+                        Ok(ScanRequest {
+                            target: target__,
+                            edge_kind: edge_kind__.unwrap_or_default(),
+                            fact_prefix: fact_prefix__.unwrap_or_default(),
+                        })
+                    }
+                }
+                deserializer
+                    .deserialize_struct(
+                        "kythe.proto.ScanRequest",
+                        FIELDS,
+                        GeneratedVisitor,
+                    )
+            }
+        }
+        impl serde::Serialize for ShardRequest {
+            #[allow(deprecated)]
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                use serde::ser::SerializeStruct;
+                let mut len = 0;
+                if self.index != 0 {
+                    len += 1;
+                }
+                if self.shards != 0 {
+                    len += 1;
+                }
+                let mut struct_ser = serializer
+                    .serialize_struct("kythe.proto.ShardRequest", len)?;
+                if self.index != 0 {
+                    #[allow(clippy::needless_borrow)]
+                    struct_ser
+                        .serialize_field(
+                            "index",
+                            ToString::to_string(&self.index).as_str(),
+                        )?;
+                }
+                if self.shards != 0 {
+                    #[allow(clippy::needless_borrow)]
+                    struct_ser
+                        .serialize_field(
+                            "shards",
+                            ToString::to_string(&self.shards).as_str(),
+                        )?;
+                }
+                struct_ser.end()
+            }
+        }
+        impl<'de> serde::Deserialize<'de> for ShardRequest {
+            #[allow(deprecated)]
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                const FIELDS: &[&str] = &["index", "shards"];
+                #[allow(clippy::enum_variant_names)]
+                enum GeneratedField {
+                    Index,
+                    Shards,
+                }
+                impl<'de> serde::Deserialize<'de> for GeneratedField {
+                    fn deserialize<D>(
+                        deserializer: D,
+                    ) -> std::result::Result<GeneratedField, D::Error>
+                    where
+                        D: serde::Deserializer<'de>,
+                    {
+                        struct GeneratedVisitor;
+                        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                            type Value = GeneratedField;
+                            fn expecting(
+                                &self,
+                                formatter: &mut std::fmt::Formatter<'_>,
+                            ) -> std::fmt::Result {
+                                write!(formatter, "expected one of: {:?}", & FIELDS)
+                            }
+                            #[allow(unused_variables)]
+                            fn visit_str<E>(
+                                self,
+                                value: &str,
+                            ) -> std::result::Result<GeneratedField, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                match value {
+                                    "index" => Ok(GeneratedField::Index),
+                                    "shards" => Ok(GeneratedField::Shards),
+                                    _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                                }
+                            }
+                        }
+                        deserializer.deserialize_identifier(GeneratedVisitor)
+                    }
+                }
+                struct GeneratedVisitor;
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = ShardRequest;
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
+                        formatter.write_str("struct kythe.proto.ShardRequest")
+                    }
+                    fn visit_map<V>(
+                        self,
+                        mut map_: V,
+                    ) -> std::result::Result<ShardRequest, V::Error>
+                    where
+                        V: serde::de::MapAccess<'de>,
+                    {
+                        let mut index__ = None;
+                        let mut shards__ = None;
+                        while let Some(k) = map_.next_key()? {
+                            match k {
+                                GeneratedField::Index => {
+                                    if index__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("index"));
+                                    }
+                                    index__ = Some(
+                                        map_
+                                            .next_value::<::pbjson::private::NumberDeserialize<_>>()?
+                                            .0,
+                                    );
+                                }
+                                GeneratedField::Shards => {
+                                    if shards__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("shards"));
+                                    }
+                                    shards__ = Some(
+                                        map_
+                                            .next_value::<::pbjson::private::NumberDeserialize<_>>()?
+                                            .0,
+                                    );
+                                }
+                            }
+                        }
+                        Ok(ShardRequest {
+                            index: index__.unwrap_or_default(),
+                            shards: shards__.unwrap_or_default(),
+                        })
+                    }
+                }
+                deserializer
+                    .deserialize_struct(
+                        "kythe.proto.ShardRequest",
+                        FIELDS,
+                        GeneratedVisitor,
+                    )
+            }
+        }
+        impl serde::Serialize for VName {
+            #[allow(deprecated)]
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                use serde::ser::SerializeStruct;
+                let mut len = 0;
+                if !self.signature.is_empty() {
+                    len += 1;
+                }
+                if !self.corpus.is_empty() {
+                    len += 1;
+                }
+                if !self.root.is_empty() {
+                    len += 1;
+                }
+                if !self.path.is_empty() {
+                    len += 1;
+                }
+                if !self.language.is_empty() {
+                    len += 1;
+                }
+                let mut struct_ser = serializer
+                    .serialize_struct("kythe.proto.VName", len)?;
+                if !self.signature.is_empty() {
+                    struct_ser.serialize_field("signature", &self.signature)?;
+                }
+                if !self.corpus.is_empty() {
+                    struct_ser.serialize_field("corpus", &self.corpus)?;
+                }
+                if !self.root.is_empty() {
+                    struct_ser.serialize_field("root", &self.root)?;
+                }
+                if !self.path.is_empty() {
+                    struct_ser.serialize_field("path", &self.path)?;
+                }
+                if !self.language.is_empty() {
+                    struct_ser.serialize_field("language", &self.language)?;
+                }
+                struct_ser.end()
+            }
+        }
+        impl<'de> serde::Deserialize<'de> for VName {
+            #[allow(deprecated)]
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                const FIELDS: &[&str] = &[
+                    "signature",
+                    "corpus",
+                    "root",
+                    "path",
+                    "language",
+                ];
+                #[allow(clippy::enum_variant_names)]
+                enum GeneratedField {
+                    Signature,
+                    Corpus,
+                    Root,
+                    Path,
+                    Language,
+                }
+                impl<'de> serde::Deserialize<'de> for GeneratedField {
+                    fn deserialize<D>(
+                        deserializer: D,
+                    ) -> std::result::Result<GeneratedField, D::Error>
+                    where
+                        D: serde::Deserializer<'de>,
+                    {
+                        struct GeneratedVisitor;
+                        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                            type Value = GeneratedField;
+                            fn expecting(
+                                &self,
+                                formatter: &mut std::fmt::Formatter<'_>,
+                            ) -> std::fmt::Result {
+                                write!(formatter, "expected one of: {:?}", & FIELDS)
+                            }
+                            #[allow(unused_variables)]
+                            fn visit_str<E>(
+                                self,
+                                value: &str,
+                            ) -> std::result::Result<GeneratedField, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                match value {
+                                    "signature" => Ok(GeneratedField::Signature),
+                                    "corpus" => Ok(GeneratedField::Corpus),
+                                    "root" => Ok(GeneratedField::Root),
+                                    "path" => Ok(GeneratedField::Path),
+                                    "language" => Ok(GeneratedField::Language),
+                                    _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                                }
+                            }
+                        }
+                        deserializer.deserialize_identifier(GeneratedVisitor)
+                    }
+                }
+                struct GeneratedVisitor;
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = VName;
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
+                        formatter.write_str("struct kythe.proto.VName")
+                    }
+                    fn visit_map<V>(
+                        self,
+                        mut map_: V,
+                    ) -> std::result::Result<VName, V::Error>
+                    where
+                        V: serde::de::MapAccess<'de>,
+                    {
+                        let mut signature__ = None;
+                        let mut corpus__ = None;
+                        let mut root__ = None;
+                        let mut path__ = None;
+                        let mut language__ = None;
+                        while let Some(k) = map_.next_key()? {
+                            match k {
+                                GeneratedField::Signature => {
+                                    if signature__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("signature"));
+                                    }
+                                    signature__ = Some(map_.next_value()?);
+                                }
+                                GeneratedField::Corpus => {
+                                    if corpus__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("corpus"));
+                                    }
+                                    corpus__ = Some(map_.next_value()?);
+                                }
+                                GeneratedField::Root => {
+                                    if root__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("root"));
+                                    }
+                                    root__ = Some(map_.next_value()?);
+                                }
+                                GeneratedField::Path => {
+                                    if path__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("path"));
+                                    }
+                                    path__ = Some(map_.next_value()?);
+                                }
+                                GeneratedField::Language => {
+                                    if language__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("language"));
+                                    }
+                                    language__ = Some(map_.next_value()?);
+                                }
+                            }
+                        }
+                        Ok(VName {
+                            signature: signature__.unwrap_or_default(),
+                            corpus: corpus__.unwrap_or_default(),
+                            root: root__.unwrap_or_default(),
+                            path: path__.unwrap_or_default(),
+                            language: language__.unwrap_or_default(),
+                        })
+                    }
+                }
+                deserializer
+                    .deserialize_struct("kythe.proto.VName", FIELDS, GeneratedVisitor)
+            }
+        }
+        impl serde::Serialize for VNameMask {
+            #[allow(deprecated)]
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                use serde::ser::SerializeStruct;
+                let mut len = 0;
+                if self.signature {
+                    len += 1;
+                }
+                if self.corpus {
+                    len += 1;
+                }
+                if self.root {
+                    len += 1;
+                }
+                if self.path {
+                    len += 1;
+                }
+                if self.language {
+                    len += 1;
+                }
+                let mut struct_ser = serializer
+                    .serialize_struct("kythe.proto.VNameMask", len)?;
+                if self.signature {
+                    struct_ser.serialize_field("signature", &self.signature)?;
+                }
+                if self.corpus {
+                    struct_ser.serialize_field("corpus", &self.corpus)?;
+                }
+                if self.root {
+                    struct_ser.serialize_field("root", &self.root)?;
+                }
+                if self.path {
+                    struct_ser.serialize_field("path", &self.path)?;
+                }
+                if self.language {
+                    struct_ser.serialize_field("language", &self.language)?;
+                }
+                struct_ser.end()
+            }
+        }
+        impl<'de> serde::Deserialize<'de> for VNameMask {
+            #[allow(deprecated)]
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                const FIELDS: &[&str] = &[
+                    "signature",
+                    "corpus",
+                    "root",
+                    "path",
+                    "language",
+                ];
+                #[allow(clippy::enum_variant_names)]
+                enum GeneratedField {
+                    Signature,
+                    Corpus,
+                    Root,
+                    Path,
+                    Language,
+                }
+                impl<'de> serde::Deserialize<'de> for GeneratedField {
+                    fn deserialize<D>(
+                        deserializer: D,
+                    ) -> std::result::Result<GeneratedField, D::Error>
+                    where
+                        D: serde::Deserializer<'de>,
+                    {
+                        struct GeneratedVisitor;
+                        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                            type Value = GeneratedField;
+                            fn expecting(
+                                &self,
+                                formatter: &mut std::fmt::Formatter<'_>,
+                            ) -> std::fmt::Result {
+                                write!(formatter, "expected one of: {:?}", & FIELDS)
+                            }
+                            #[allow(unused_variables)]
+                            fn visit_str<E>(
+                                self,
+                                value: &str,
+                            ) -> std::result::Result<GeneratedField, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                match value {
+                                    "signature" => Ok(GeneratedField::Signature),
+                                    "corpus" => Ok(GeneratedField::Corpus),
+                                    "root" => Ok(GeneratedField::Root),
+                                    "path" => Ok(GeneratedField::Path),
+                                    "language" => Ok(GeneratedField::Language),
+                                    _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                                }
+                            }
+                        }
+                        deserializer.deserialize_identifier(GeneratedVisitor)
+                    }
+                }
+                struct GeneratedVisitor;
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = VNameMask;
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
+                        formatter.write_str("struct kythe.proto.VNameMask")
+                    }
+                    fn visit_map<V>(
+                        self,
+                        mut map_: V,
+                    ) -> std::result::Result<VNameMask, V::Error>
+                    where
+                        V: serde::de::MapAccess<'de>,
+                    {
+                        let mut signature__ = None;
+                        let mut corpus__ = None;
+                        let mut root__ = None;
+                        let mut path__ = None;
+                        let mut language__ = None;
+                        while let Some(k) = map_.next_key()? {
+                            match k {
+                                GeneratedField::Signature => {
+                                    if signature__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("signature"));
+                                    }
+                                    signature__ = Some(map_.next_value()?);
+                                }
+                                GeneratedField::Corpus => {
+                                    if corpus__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("corpus"));
+                                    }
+                                    corpus__ = Some(map_.next_value()?);
+                                }
+                                GeneratedField::Root => {
+                                    if root__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("root"));
+                                    }
+                                    root__ = Some(map_.next_value()?);
+                                }
+                                GeneratedField::Path => {
+                                    if path__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("path"));
+                                    }
+                                    path__ = Some(map_.next_value()?);
+                                }
+                                GeneratedField::Language => {
+                                    if language__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("language"));
+                                    }
+                                    language__ = Some(map_.next_value()?);
+                                }
+                            }
+                        }
+                        Ok(VNameMask {
+                            signature: signature__.unwrap_or_default(),
+                            corpus: corpus__.unwrap_or_default(),
+                            root: root__.unwrap_or_default(),
+                            path: path__.unwrap_or_default(),
+                            language: language__.unwrap_or_default(),
+                        })
+                    }
+                }
+                deserializer
+                    .deserialize_struct(
+                        "kythe.proto.VNameMask",
+                        FIELDS,
+                        GeneratedVisitor,
+                    )
+            }
+        }
+        impl serde::Serialize for VNameRewriteRule {
+            #[allow(deprecated)]
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                use serde::ser::SerializeStruct;
+                let mut len = 0;
+                if !self.pattern.is_empty() {
+                    len += 1;
+                }
+                if self.v_name.is_some() {
+                    len += 1;
+                }
+                let mut struct_ser = serializer
+                    .serialize_struct("kythe.proto.VNameRewriteRule", len)?;
+                if !self.pattern.is_empty() {
+                    struct_ser.serialize_field("pattern", &self.pattern)?;
+                }
+                if let Some(v) = self.v_name.as_ref() {
+                    struct_ser.serialize_field("v_name", v)?;
+                }
+                struct_ser.end()
+            }
+        }
+        impl<'de> serde::Deserialize<'de> for VNameRewriteRule {
+            #[allow(deprecated)]
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                const FIELDS: &[&str] = &["pattern", "v_name", "vname"];
+                #[allow(clippy::enum_variant_names)]
+                enum GeneratedField {
+                    Pattern,
+                    VName,
+                }
+                impl<'de> serde::Deserialize<'de> for GeneratedField {
+                    fn deserialize<D>(
+                        deserializer: D,
+                    ) -> std::result::Result<GeneratedField, D::Error>
+                    where
+                        D: serde::Deserializer<'de>,
+                    {
+                        struct GeneratedVisitor;
+                        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                            type Value = GeneratedField;
+                            fn expecting(
+                                &self,
+                                formatter: &mut std::fmt::Formatter<'_>,
+                            ) -> std::fmt::Result {
+                                write!(formatter, "expected one of: {:?}", & FIELDS)
+                            }
+                            #[allow(unused_variables)]
+                            fn visit_str<E>(
+                                self,
+                                value: &str,
+                            ) -> std::result::Result<GeneratedField, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                match value {
+                                    "pattern" => Ok(GeneratedField::Pattern),
+                                    "vname" | "v_name" => Ok(GeneratedField::VName),
+                                    _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                                }
+                            }
+                        }
+                        deserializer.deserialize_identifier(GeneratedVisitor)
+                    }
+                }
+                struct GeneratedVisitor;
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = VNameRewriteRule;
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
+                        formatter.write_str("struct kythe.proto.VNameRewriteRule")
+                    }
+                    fn visit_map<V>(
+                        self,
+                        mut map_: V,
+                    ) -> std::result::Result<VNameRewriteRule, V::Error>
+                    where
+                        V: serde::de::MapAccess<'de>,
+                    {
+                        let mut pattern__ = None;
+                        let mut v_name__ = None;
+                        while let Some(k) = map_.next_key()? {
+                            match k {
+                                GeneratedField::Pattern => {
+                                    if pattern__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("pattern"));
+                                    }
+                                    pattern__ = Some(map_.next_value()?);
+                                }
+                                GeneratedField::VName => {
+                                    if v_name__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("vname"));
+                                    }
+                                    v_name__ = map_.next_value()?;
+                                }
+                            }
+                        }
+                        Ok(VNameRewriteRule {
+                            pattern: pattern__.unwrap_or_default(),
+                            v_name: v_name__,
+                        })
+                    }
+                }
+                deserializer
+                    .deserialize_struct(
+                        "kythe.proto.VNameRewriteRule",
+                        FIELDS,
+                        GeneratedVisitor,
+                    )
+            }
+        }
+        impl serde::Serialize for VNameRewriteRules {
+            #[allow(deprecated)]
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                use serde::ser::SerializeStruct;
+                let mut len = 0;
+                if !self.rule.is_empty() {
+                    len += 1;
+                }
+                let mut struct_ser = serializer
+                    .serialize_struct("kythe.proto.VNameRewriteRules", len)?;
+                if !self.rule.is_empty() {
+                    struct_ser.serialize_field("rule", &self.rule)?;
+                }
+                struct_ser.end()
+            }
+        }
+        impl<'de> serde::Deserialize<'de> for VNameRewriteRules {
+            #[allow(deprecated)]
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                const FIELDS: &[&str] = &["rule"];
+                #[allow(clippy::enum_variant_names)]
+                enum GeneratedField {
+                    Rule,
+                }
+                impl<'de> serde::Deserialize<'de> for GeneratedField {
+                    fn deserialize<D>(
+                        deserializer: D,
+                    ) -> std::result::Result<GeneratedField, D::Error>
+                    where
+                        D: serde::Deserializer<'de>,
+                    {
+                        struct GeneratedVisitor;
+                        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                            type Value = GeneratedField;
+                            fn expecting(
+                                &self,
+                                formatter: &mut std::fmt::Formatter<'_>,
+                            ) -> std::fmt::Result {
+                                write!(formatter, "expected one of: {:?}", & FIELDS)
+                            }
+                            #[allow(unused_variables)]
+                            fn visit_str<E>(
+                                self,
+                                value: &str,
+                            ) -> std::result::Result<GeneratedField, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                match value {
+                                    "rule" => Ok(GeneratedField::Rule),
+                                    _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                                }
+                            }
+                        }
+                        deserializer.deserialize_identifier(GeneratedVisitor)
+                    }
+                }
+                struct GeneratedVisitor;
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = VNameRewriteRules;
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
+                        formatter.write_str("struct kythe.proto.VNameRewriteRules")
+                    }
+                    fn visit_map<V>(
+                        self,
+                        mut map_: V,
+                    ) -> std::result::Result<VNameRewriteRules, V::Error>
+                    where
+                        V: serde::de::MapAccess<'de>,
+                    {
+                        let mut rule__ = None;
+                        while let Some(k) = map_.next_key()? {
+                            match k {
+                                GeneratedField::Rule => {
+                                    if rule__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("rule"));
+                                    }
+                                    rule__ = Some(map_.next_value()?);
+                                }
+                            }
+                        }
+                        Ok(VNameRewriteRules {
+                            rule: rule__.unwrap_or_default(),
+                        })
+                    }
+                }
+                deserializer
+                    .deserialize_struct(
+                        "kythe.proto.VNameRewriteRules",
+                        FIELDS,
+                        GeneratedVisitor,
+                    )
+            }
+        }
+        impl serde::Serialize for WriteReply {
+            #[allow(deprecated)]
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                use serde::ser::SerializeStruct;
+                let len = 0;
+                let struct_ser = serializer
+                    .serialize_struct("kythe.proto.WriteReply", len)?;
+                struct_ser.end()
+            }
+        }
+        impl<'de> serde::Deserialize<'de> for WriteReply {
+            #[allow(deprecated)]
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                const FIELDS: &[&str] = &[];
+                #[allow(clippy::enum_variant_names)]
+                enum GeneratedField {}
+                impl<'de> serde::Deserialize<'de> for GeneratedField {
+                    fn deserialize<D>(
+                        deserializer: D,
+                    ) -> std::result::Result<GeneratedField, D::Error>
+                    where
+                        D: serde::Deserializer<'de>,
+                    {
+                        struct GeneratedVisitor;
+                        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                            type Value = GeneratedField;
+                            fn expecting(
+                                &self,
+                                formatter: &mut std::fmt::Formatter<'_>,
+                            ) -> std::fmt::Result {
+                                write!(formatter, "expected one of: {:?}", & FIELDS)
+                            }
+                            #[allow(unused_variables)]
+                            fn visit_str<E>(
+                                self,
+                                value: &str,
+                            ) -> std::result::Result<GeneratedField, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                Err(serde::de::Error::unknown_field(value, FIELDS))
+                            }
+                        }
+                        deserializer.deserialize_identifier(GeneratedVisitor)
+                    }
+                }
+                struct GeneratedVisitor;
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = WriteReply;
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
+                        formatter.write_str("struct kythe.proto.WriteReply")
+                    }
+                    fn visit_map<V>(
+                        self,
+                        mut map_: V,
+                    ) -> std::result::Result<WriteReply, V::Error>
+                    where
+                        V: serde::de::MapAccess<'de>,
+                    {
+                        while map_.next_key::<GeneratedField>()?.is_some() {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                        Ok(WriteReply {})
+                    }
+                }
+                deserializer
+                    .deserialize_struct(
+                        "kythe.proto.WriteReply",
+                        FIELDS,
+                        GeneratedVisitor,
+                    )
+            }
+        }
+        impl serde::Serialize for WriteRequest {
+            #[allow(deprecated)]
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                use serde::ser::SerializeStruct;
+                let mut len = 0;
+                if self.source.is_some() {
+                    len += 1;
+                }
+                if !self.update.is_empty() {
+                    len += 1;
+                }
+                let mut struct_ser = serializer
+                    .serialize_struct("kythe.proto.WriteRequest", len)?;
+                if let Some(v) = self.source.as_ref() {
+                    struct_ser.serialize_field("source", v)?;
+                }
+                if !self.update.is_empty() {
+                    struct_ser.serialize_field("update", &self.update)?;
+                }
+                struct_ser.end()
+            }
+        }
+        impl<'de> serde::Deserialize<'de> for WriteRequest {
+            #[allow(deprecated)]
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                const FIELDS: &[&str] = &["source", "update"];
+                #[allow(clippy::enum_variant_names)]
+                enum GeneratedField {
+                    Source,
+                    Update,
+                }
+                impl<'de> serde::Deserialize<'de> for GeneratedField {
+                    fn deserialize<D>(
+                        deserializer: D,
+                    ) -> std::result::Result<GeneratedField, D::Error>
+                    where
+                        D: serde::Deserializer<'de>,
+                    {
+                        struct GeneratedVisitor;
+                        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                            type Value = GeneratedField;
+                            fn expecting(
+                                &self,
+                                formatter: &mut std::fmt::Formatter<'_>,
+                            ) -> std::fmt::Result {
+                                write!(formatter, "expected one of: {:?}", & FIELDS)
+                            }
+                            #[allow(unused_variables)]
+                            fn visit_str<E>(
+                                self,
+                                value: &str,
+                            ) -> std::result::Result<GeneratedField, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                match value {
+                                    "source" => Ok(GeneratedField::Source),
+                                    "update" => Ok(GeneratedField::Update),
+                                    _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                                }
+                            }
+                        }
+                        deserializer.deserialize_identifier(GeneratedVisitor)
+                    }
+                }
+                struct GeneratedVisitor;
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = WriteRequest;
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
+                        formatter.write_str("struct kythe.proto.WriteRequest")
+                    }
+                    fn visit_map<V>(
+                        self,
+                        mut map_: V,
+                    ) -> std::result::Result<WriteRequest, V::Error>
+                    where
+                        V: serde::de::MapAccess<'de>,
+                    {
+                        let mut source__ = None;
+                        let mut update__ = None;
+                        while let Some(k) = map_.next_key()? {
+                            match k {
+                                GeneratedField::Source => {
+                                    if source__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("source"));
+                                    }
+                                    source__ = map_.next_value()?;
+                                }
+                                GeneratedField::Update => {
+                                    if update__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("update"));
+                                    }
+                                    update__ = Some(map_.next_value()?);
+                                }
+                            }
+                        }
+                        Ok(WriteRequest {
+                            source: source__,
+                            update: update__.unwrap_or_default(),
+                        })
+                    }
+                }
+                deserializer
+                    .deserialize_struct(
+                        "kythe.proto.WriteRequest",
+                        FIELDS,
+                        GeneratedVisitor,
+                    )
+            }
+        }
+        impl serde::Serialize for write_request::Update {
+            #[allow(deprecated)]
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                use serde::ser::SerializeStruct;
+                let mut len = 0;
+                if !self.edge_kind.is_empty() {
+                    len += 1;
+                }
+                if self.target.is_some() {
+                    len += 1;
+                }
+                if !self.fact_name.is_empty() {
+                    len += 1;
+                }
+                if !self.fact_value.is_empty() {
+                    len += 1;
+                }
+                let mut struct_ser = serializer
+                    .serialize_struct("kythe.proto.WriteRequest.Update", len)?;
+                if !self.edge_kind.is_empty() {
+                    struct_ser.serialize_field("edge_kind", &self.edge_kind)?;
+                }
+                if let Some(v) = self.target.as_ref() {
+                    struct_ser.serialize_field("target", v)?;
+                }
+                if !self.fact_name.is_empty() {
+                    struct_ser.serialize_field("fact_name", &self.fact_name)?;
+                }
+                if !self.fact_value.is_empty() {
+                    #[allow(clippy::needless_borrow)]
+                    struct_ser
+                        .serialize_field(
+                            "fact_value",
+                            pbjson::private::base64::encode(&self.fact_value).as_str(),
+                        )?;
+                }
+                struct_ser.end()
+            }
+        }
+        impl<'de> serde::Deserialize<'de> for write_request::Update {
+            #[allow(deprecated)]
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                const FIELDS: &[&str] = &[
+                    "edge_kind",
+                    "edgeKind",
+                    "target",
+                    "fact_name",
+                    "factName",
+                    "fact_value",
+                    "factValue",
+                ];
+                #[allow(clippy::enum_variant_names)]
+                enum GeneratedField {
+                    EdgeKind,
+                    Target,
+                    FactName,
+                    FactValue,
+                }
+                impl<'de> serde::Deserialize<'de> for GeneratedField {
+                    fn deserialize<D>(
+                        deserializer: D,
+                    ) -> std::result::Result<GeneratedField, D::Error>
+                    where
+                        D: serde::Deserializer<'de>,
+                    {
+                        struct GeneratedVisitor;
+                        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                            type Value = GeneratedField;
+                            fn expecting(
+                                &self,
+                                formatter: &mut std::fmt::Formatter<'_>,
+                            ) -> std::fmt::Result {
+                                write!(formatter, "expected one of: {:?}", & FIELDS)
+                            }
+                            #[allow(unused_variables)]
+                            fn visit_str<E>(
+                                self,
+                                value: &str,
+                            ) -> std::result::Result<GeneratedField, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                match value {
+                                    "edgeKind" | "edge_kind" => Ok(GeneratedField::EdgeKind),
+                                    "target" => Ok(GeneratedField::Target),
+                                    "factName" | "fact_name" => Ok(GeneratedField::FactName),
+                                    "factValue" | "fact_value" => Ok(GeneratedField::FactValue),
+                                    _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                                }
+                            }
+                        }
+                        deserializer.deserialize_identifier(GeneratedVisitor)
+                    }
+                }
+                struct GeneratedVisitor;
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = write_request::Update;
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
+                        formatter.write_str("struct kythe.proto.WriteRequest.Update")
+                    }
+                    fn visit_map<V>(
+                        self,
+                        mut map_: V,
+                    ) -> std::result::Result<write_request::Update, V::Error>
+                    where
+                        V: serde::de::MapAccess<'de>,
+                    {
+                        let mut edge_kind__ = None;
+                        let mut target__ = None;
+                        let mut fact_name__ = None;
+                        let mut fact_value__ = None;
+                        while let Some(k) = map_.next_key()? {
+                            match k {
+                                GeneratedField::EdgeKind => {
+                                    if edge_kind__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("edgeKind"));
+                                    }
+                                    edge_kind__ = Some(map_.next_value()?);
+                                }
+                                GeneratedField::Target => {
+                                    if target__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("target"));
+                                    }
+                                    target__ = map_.next_value()?;
+                                }
+                                GeneratedField::FactName => {
+                                    if fact_name__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("factName"));
+                                    }
+                                    fact_name__ = Some(map_.next_value()?);
+                                }
+                                GeneratedField::FactValue => {
+                                    if fact_value__.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("factValue"));
+                                    }
+                                    fact_value__ = Some(
+                                        map_
+                                            .next_value::<::pbjson::private::BytesDeserialize<_>>()?
+                                            .0,
+                                    );
+                                }
+                            }
+                        }
+                        Ok(write_request::Update {
+                            edge_kind: edge_kind__.unwrap_or_default(),
+                            target: target__,
+                            fact_name: fact_name__.unwrap_or_default(),
+                            fact_value: fact_value__.unwrap_or_default(),
+                        })
+                    }
+                }
+                deserializer
+                    .deserialize_struct(
+                        "kythe.proto.WriteRequest.Update",
+                        FIELDS,
+                        GeneratedVisitor,
+                    )
+            }
+        }
+    }
+}
+pub use self::kythe::proto::*;
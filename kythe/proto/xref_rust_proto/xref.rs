@@ -0,0 +1,8375 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `kythe/proto/xref.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Location {
+    // message fields
+    pub ticket: ::std::string::String,
+    pub kind: Location_Kind,
+    pub span: ::protobuf::SingularPtrField<super::common::Span>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Location {
+    fn default() -> &'a Location {
+        <Location as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Location {
+    pub fn new() -> Location {
+        ::std::default::Default::default()
+    }
+
+    // string ticket = 1;
+
+
+    pub fn get_ticket(&self) -> &str {
+        &self.ticket
+    }
+    pub fn clear_ticket(&mut self) {
+        self.ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ticket(&mut self, v: ::std::string::String) {
+        self.ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ticket(&mut self) -> &mut ::std::string::String {
+        &mut self.ticket
+    }
+
+    // Take field
+    pub fn take_ticket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.ticket, ::std::string::String::new())
+    }
+
+    // .kythe.proto.Location.Kind kind = 2;
+
+
+    pub fn get_kind(&self) -> Location_Kind {
+        self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind = Location_Kind::FILE;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: Location_Kind) {
+        self.kind = v;
+    }
+
+    // .kythe.proto.common.Span span = 5;
+
+
+    pub fn get_span(&self) -> &super::common::Span {
+        self.span.as_ref().unwrap_or_else(|| <super::common::Span as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_span(&mut self) {
+        self.span.clear();
+    }
+
+    pub fn has_span(&self) -> bool {
+        self.span.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_span(&mut self, v: super::common::Span) {
+        self.span = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_span(&mut self) -> &mut super::common::Span {
+        if self.span.is_none() {
+            self.span.set_default();
+        }
+        self.span.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_span(&mut self) -> super::common::Span {
+        self.span.take().unwrap_or_else(|| super::common::Span::new())
+    }
+}
+
+impl ::protobuf::Message for Location {
+    fn is_initialized(&self) -> bool {
+        for v in &self.span {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.ticket)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.kind, 2, &mut self.unknown_fields)?
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.span)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.ticket.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.ticket);
+        }
+        if self.kind != Location_Kind::FILE {
+            my_size += ::protobuf::rt::enum_size(2, self.kind);
+        }
+        if let Some(ref v) = self.span.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.ticket.is_empty() {
+            os.write_string(1, &self.ticket)?;
+        }
+        if self.kind != Location_Kind::FILE {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.kind))?;
+        }
+        if let Some(ref v) = self.span.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Location {
+        Location::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "ticket",
+                |m: &Location| { &m.ticket },
+                |m: &mut Location| { &mut m.ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Location_Kind>>(
+                "kind",
+                |m: &Location| { &m.kind },
+                |m: &mut Location| { &mut m.kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::Span>>(
+                "span",
+                |m: &Location| { &m.span },
+                |m: &mut Location| { &mut m.span },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Location>(
+                "Location",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Location {
+        static instance: ::protobuf::rt::LazyV2<Location> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Location::new)
+    }
+}
+
+impl ::protobuf::Clear for Location {
+    fn clear(&mut self) {
+        self.ticket.clear();
+        self.kind = Location_Kind::FILE;
+        self.span.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Location {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Location {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Location_Kind {
+    FILE = 0,
+    SPAN = 1,
+}
+
+impl ::protobuf::ProtobufEnum for Location_Kind {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Location_Kind> {
+        match value {
+            0 => ::std::option::Option::Some(Location_Kind::FILE),
+            1 => ::std::option::Option::Some(Location_Kind::SPAN),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Location_Kind] = &[
+            Location_Kind::FILE,
+            Location_Kind::SPAN,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<Location_Kind>("Location.Kind", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for Location_Kind {
+}
+
+impl ::std::default::Default for Location_Kind {
+    fn default() -> Self {
+        Location_Kind::FILE
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Location_Kind {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DecorationsRequest {
+    // message fields
+    pub location: ::protobuf::SingularPtrField<Location>,
+    pub span_kind: DecorationsRequest_SpanKind,
+    pub dirty_buffer: ::std::vec::Vec<u8>,
+    pub source_text: bool,
+    pub references: bool,
+    pub target_definitions: bool,
+    pub filter: ::protobuf::RepeatedField<::std::string::String>,
+    pub extends_overrides: bool,
+    pub diagnostics: bool,
+    pub snippets: SnippetsKind,
+    pub build_config: ::protobuf::RepeatedField<::std::string::String>,
+    pub semantic_scopes: bool,
+    pub workspace: ::protobuf::SingularPtrField<Workspace>,
+    pub patch_against_workspace: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DecorationsRequest {
+    fn default() -> &'a DecorationsRequest {
+        <DecorationsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DecorationsRequest {
+    pub fn new() -> DecorationsRequest {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.Location location = 1;
+
+
+    pub fn get_location(&self) -> &Location {
+        self.location.as_ref().unwrap_or_else(|| <Location as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_location(&mut self) {
+        self.location.clear();
+    }
+
+    pub fn has_location(&self) -> bool {
+        self.location.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_location(&mut self, v: Location) {
+        self.location = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_location(&mut self) -> &mut Location {
+        if self.location.is_none() {
+            self.location.set_default();
+        }
+        self.location.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_location(&mut self) -> Location {
+        self.location.take().unwrap_or_else(|| Location::new())
+    }
+
+    // .kythe.proto.DecorationsRequest.SpanKind span_kind = 10;
+
+
+    pub fn get_span_kind(&self) -> DecorationsRequest_SpanKind {
+        self.span_kind
+    }
+    pub fn clear_span_kind(&mut self) {
+        self.span_kind = DecorationsRequest_SpanKind::WITHIN_SPAN;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_span_kind(&mut self, v: DecorationsRequest_SpanKind) {
+        self.span_kind = v;
+    }
+
+    // bytes dirty_buffer = 2;
+
+
+    pub fn get_dirty_buffer(&self) -> &[u8] {
+        &self.dirty_buffer
+    }
+    pub fn clear_dirty_buffer(&mut self) {
+        self.dirty_buffer.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_dirty_buffer(&mut self, v: ::std::vec::Vec<u8>) {
+        self.dirty_buffer = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_dirty_buffer(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.dirty_buffer
+    }
+
+    // Take field
+    pub fn take_dirty_buffer(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.dirty_buffer, ::std::vec::Vec::new())
+    }
+
+    // bool source_text = 3;
+
+
+    pub fn get_source_text(&self) -> bool {
+        self.source_text
+    }
+    pub fn clear_source_text(&mut self) {
+        self.source_text = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source_text(&mut self, v: bool) {
+        self.source_text = v;
+    }
+
+    // bool references = 4;
+
+
+    pub fn get_references(&self) -> bool {
+        self.references
+    }
+    pub fn clear_references(&mut self) {
+        self.references = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_references(&mut self, v: bool) {
+        self.references = v;
+    }
+
+    // bool target_definitions = 6;
+
+
+    pub fn get_target_definitions(&self) -> bool {
+        self.target_definitions
+    }
+    pub fn clear_target_definitions(&mut self) {
+        self.target_definitions = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_definitions(&mut self, v: bool) {
+        self.target_definitions = v;
+    }
+
+    // repeated string filter = 5;
+
+
+    pub fn get_filter(&self) -> &[::std::string::String] {
+        &self.filter
+    }
+    pub fn clear_filter(&mut self) {
+        self.filter.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_filter(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.filter = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_filter(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.filter
+    }
+
+    // Take field
+    pub fn take_filter(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.filter, ::protobuf::RepeatedField::new())
+    }
+
+    // bool extends_overrides = 7;
+
+
+    pub fn get_extends_overrides(&self) -> bool {
+        self.extends_overrides
+    }
+    pub fn clear_extends_overrides(&mut self) {
+        self.extends_overrides = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_extends_overrides(&mut self, v: bool) {
+        self.extends_overrides = v;
+    }
+
+    // bool diagnostics = 8;
+
+
+    pub fn get_diagnostics(&self) -> bool {
+        self.diagnostics
+    }
+    pub fn clear_diagnostics(&mut self) {
+        self.diagnostics = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_diagnostics(&mut self, v: bool) {
+        self.diagnostics = v;
+    }
+
+    // .kythe.proto.SnippetsKind snippets = 9;
+
+
+    pub fn get_snippets(&self) -> SnippetsKind {
+        self.snippets
+    }
+    pub fn clear_snippets(&mut self) {
+        self.snippets = SnippetsKind::NONE;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_snippets(&mut self, v: SnippetsKind) {
+        self.snippets = v;
+    }
+
+    // repeated string build_config = 11;
+
+
+    pub fn get_build_config(&self) -> &[::std::string::String] {
+        &self.build_config
+    }
+    pub fn clear_build_config(&mut self) {
+        self.build_config.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_build_config(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.build_config = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_build_config(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.build_config
+    }
+
+    // Take field
+    pub fn take_build_config(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.build_config, ::protobuf::RepeatedField::new())
+    }
+
+    // bool semantic_scopes = 12;
+
+
+    pub fn get_semantic_scopes(&self) -> bool {
+        self.semantic_scopes
+    }
+    pub fn clear_semantic_scopes(&mut self) {
+        self.semantic_scopes = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_semantic_scopes(&mut self, v: bool) {
+        self.semantic_scopes = v;
+    }
+
+    // .kythe.proto.Workspace workspace = 13;
+
+
+    pub fn get_workspace(&self) -> &Workspace {
+        self.workspace.as_ref().unwrap_or_else(|| <Workspace as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_workspace(&mut self) {
+        self.workspace.clear();
+    }
+
+    pub fn has_workspace(&self) -> bool {
+        self.workspace.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace(&mut self, v: Workspace) {
+        self.workspace = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace(&mut self) -> &mut Workspace {
+        if self.workspace.is_none() {
+            self.workspace.set_default();
+        }
+        self.workspace.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_workspace(&mut self) -> Workspace {
+        self.workspace.take().unwrap_or_else(|| Workspace::new())
+    }
+
+    // bool patch_against_workspace = 14;
+
+
+    pub fn get_patch_against_workspace(&self) -> bool {
+        self.patch_against_workspace
+    }
+    pub fn clear_patch_against_workspace(&mut self) {
+        self.patch_against_workspace = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_patch_against_workspace(&mut self, v: bool) {
+        self.patch_against_workspace = v;
+    }
+}
+
+impl ::protobuf::Message for DecorationsRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.location {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.workspace {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.location)?;
+                },
+                10 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.span_kind, 10, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.dirty_buffer)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.source_text = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.references = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.target_definitions = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.filter)?;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.extends_overrides = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.diagnostics = tmp;
+                },
+                9 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.snippets, 9, &mut self.unknown_fields)?
+                },
+                11 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.build_config)?;
+                },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.semantic_scopes = tmp;
+                },
+                13 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.workspace)?;
+                },
+                14 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.patch_against_workspace = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.location.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.span_kind != DecorationsRequest_SpanKind::WITHIN_SPAN {
+            my_size += ::protobuf::rt::enum_size(10, self.span_kind);
+        }
+        if !self.dirty_buffer.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.dirty_buffer);
+        }
+        if self.source_text != false {
+            my_size += 2;
+        }
+        if self.references != false {
+            my_size += 2;
+        }
+        if self.target_definitions != false {
+            my_size += 2;
+        }
+        for value in &self.filter {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        if self.extends_overrides != false {
+            my_size += 2;
+        }
+        if self.diagnostics != false {
+            my_size += 2;
+        }
+        if self.snippets != SnippetsKind::NONE {
+            my_size += ::protobuf::rt::enum_size(9, self.snippets);
+        }
+        for value in &self.build_config {
+            my_size += ::protobuf::rt::string_size(11, &value);
+        };
+        if self.semantic_scopes != false {
+            my_size += 2;
+        }
+        if let Some(ref v) = self.workspace.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.patch_against_workspace != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.location.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.span_kind != DecorationsRequest_SpanKind::WITHIN_SPAN {
+            os.write_enum(10, ::protobuf::ProtobufEnum::value(&self.span_kind))?;
+        }
+        if !self.dirty_buffer.is_empty() {
+            os.write_bytes(2, &self.dirty_buffer)?;
+        }
+        if self.source_text != false {
+            os.write_bool(3, self.source_text)?;
+        }
+        if self.references != false {
+            os.write_bool(4, self.references)?;
+        }
+        if self.target_definitions != false {
+            os.write_bool(6, self.target_definitions)?;
+        }
+        for v in &self.filter {
+            os.write_string(5, &v)?;
+        };
+        if self.extends_overrides != false {
+            os.write_bool(7, self.extends_overrides)?;
+        }
+        if self.diagnostics != false {
+            os.write_bool(8, self.diagnostics)?;
+        }
+        if self.snippets != SnippetsKind::NONE {
+            os.write_enum(9, ::protobuf::ProtobufEnum::value(&self.snippets))?;
+        }
+        for v in &self.build_config {
+            os.write_string(11, &v)?;
+        };
+        if self.semantic_scopes != false {
+            os.write_bool(12, self.semantic_scopes)?;
+        }
+        if let Some(ref v) = self.workspace.as_ref() {
+            os.write_tag(13, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.patch_against_workspace != false {
+            os.write_bool(14, self.patch_against_workspace)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DecorationsRequest {
+        DecorationsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Location>>(
+                "location",
+                |m: &DecorationsRequest| { &m.location },
+                |m: &mut DecorationsRequest| { &mut m.location },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<DecorationsRequest_SpanKind>>(
+                "span_kind",
+                |m: &DecorationsRequest| { &m.span_kind },
+                |m: &mut DecorationsRequest| { &mut m.span_kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "dirty_buffer",
+                |m: &DecorationsRequest| { &m.dirty_buffer },
+                |m: &mut DecorationsRequest| { &mut m.dirty_buffer },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "source_text",
+                |m: &DecorationsRequest| { &m.source_text },
+                |m: &mut DecorationsRequest| { &mut m.source_text },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "references",
+                |m: &DecorationsRequest| { &m.references },
+                |m: &mut DecorationsRequest| { &mut m.references },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "target_definitions",
+                |m: &DecorationsRequest| { &m.target_definitions },
+                |m: &mut DecorationsRequest| { &mut m.target_definitions },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "filter",
+                |m: &DecorationsRequest| { &m.filter },
+                |m: &mut DecorationsRequest| { &mut m.filter },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "extends_overrides",
+                |m: &DecorationsRequest| { &m.extends_overrides },
+                |m: &mut DecorationsRequest| { &mut m.extends_overrides },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "diagnostics",
+                |m: &DecorationsRequest| { &m.diagnostics },
+                |m: &mut DecorationsRequest| { &mut m.diagnostics },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<SnippetsKind>>(
+                "snippets",
+                |m: &DecorationsRequest| { &m.snippets },
+                |m: &mut DecorationsRequest| { &mut m.snippets },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "build_config",
+                |m: &DecorationsRequest| { &m.build_config },
+                |m: &mut DecorationsRequest| { &mut m.build_config },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "semantic_scopes",
+                |m: &DecorationsRequest| { &m.semantic_scopes },
+                |m: &mut DecorationsRequest| { &mut m.semantic_scopes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Workspace>>(
+                "workspace",
+                |m: &DecorationsRequest| { &m.workspace },
+                |m: &mut DecorationsRequest| { &mut m.workspace },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "patch_against_workspace",
+                |m: &DecorationsRequest| { &m.patch_against_workspace },
+                |m: &mut DecorationsRequest| { &mut m.patch_against_workspace },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DecorationsRequest>(
+                "DecorationsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DecorationsRequest {
+        static instance: ::protobuf::rt::LazyV2<DecorationsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DecorationsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for DecorationsRequest {
+    fn clear(&mut self) {
+        self.location.clear();
+        self.span_kind = DecorationsRequest_SpanKind::WITHIN_SPAN;
+        self.dirty_buffer.clear();
+        self.source_text = false;
+        self.references = false;
+        self.target_definitions = false;
+        self.filter.clear();
+        self.extends_overrides = false;
+        self.diagnostics = false;
+        self.snippets = SnippetsKind::NONE;
+        self.build_config.clear();
+        self.semantic_scopes = false;
+        self.workspace.clear();
+        self.patch_against_workspace = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DecorationsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DecorationsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum DecorationsRequest_SpanKind {
+    WITHIN_SPAN = 0,
+    AROUND_SPAN = 1,
+}
+
+impl ::protobuf::ProtobufEnum for DecorationsRequest_SpanKind {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<DecorationsRequest_SpanKind> {
+        match value {
+            0 => ::std::option::Option::Some(DecorationsRequest_SpanKind::WITHIN_SPAN),
+            1 => ::std::option::Option::Some(DecorationsRequest_SpanKind::AROUND_SPAN),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [DecorationsRequest_SpanKind] = &[
+            DecorationsRequest_SpanKind::WITHIN_SPAN,
+            DecorationsRequest_SpanKind::AROUND_SPAN,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<DecorationsRequest_SpanKind>("DecorationsRequest.SpanKind", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for DecorationsRequest_SpanKind {
+}
+
+impl ::std::default::Default for DecorationsRequest_SpanKind {
+    fn default() -> Self {
+        DecorationsRequest_SpanKind::WITHIN_SPAN
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DecorationsRequest_SpanKind {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct File {
+    // message fields
+    pub corpus_path: ::protobuf::SingularPtrField<super::common::CorpusPath>,
+    pub revision: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a File {
+    fn default() -> &'a File {
+        <File as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl File {
+    pub fn new() -> File {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.common.CorpusPath corpus_path = 1;
+
+
+    pub fn get_corpus_path(&self) -> &super::common::CorpusPath {
+        self.corpus_path.as_ref().unwrap_or_else(|| <super::common::CorpusPath as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_corpus_path(&mut self) {
+        self.corpus_path.clear();
+    }
+
+    pub fn has_corpus_path(&self) -> bool {
+        self.corpus_path.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_corpus_path(&mut self, v: super::common::CorpusPath) {
+        self.corpus_path = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_corpus_path(&mut self) -> &mut super::common::CorpusPath {
+        if self.corpus_path.is_none() {
+            self.corpus_path.set_default();
+        }
+        self.corpus_path.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_corpus_path(&mut self) -> super::common::CorpusPath {
+        self.corpus_path.take().unwrap_or_else(|| super::common::CorpusPath::new())
+    }
+
+    // string revision = 2;
+
+
+    pub fn get_revision(&self) -> &str {
+        &self.revision
+    }
+    pub fn clear_revision(&mut self) {
+        self.revision.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_revision(&mut self, v: ::std::string::String) {
+        self.revision = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_revision(&mut self) -> &mut ::std::string::String {
+        &mut self.revision
+    }
+
+    // Take field
+    pub fn take_revision(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.revision, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for File {
+    fn is_initialized(&self) -> bool {
+        for v in &self.corpus_path {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.corpus_path)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.revision)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.corpus_path.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.revision.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.revision);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.corpus_path.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.revision.is_empty() {
+            os.write_string(2, &self.revision)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> File {
+        File::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::CorpusPath>>(
+                "corpus_path",
+                |m: &File| { &m.corpus_path },
+                |m: &mut File| { &mut m.corpus_path },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "revision",
+                |m: &File| { &m.revision },
+                |m: &mut File| { &mut m.revision },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<File>(
+                "File",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static File {
+        static instance: ::protobuf::rt::LazyV2<File> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(File::new)
+    }
+}
+
+impl ::protobuf::Clear for File {
+    fn clear(&mut self) {
+        self.corpus_path.clear();
+        self.revision.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for File {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for File {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DecorationsReply {
+    // message fields
+    pub location: ::protobuf::SingularPtrField<Location>,
+    pub revision: ::std::string::String,
+    pub encoding: ::std::string::String,
+    pub reference: ::protobuf::RepeatedField<DecorationsReply_Reference>,
+    pub diagnostic: ::protobuf::RepeatedField<super::common::Diagnostic>,
+    pub generated_by_file: ::protobuf::RepeatedField<File>,
+    pub nodes: ::std::collections::HashMap<::std::string::String, super::common::NodeInfo>,
+    pub definition_locations: ::std::collections::HashMap<::std::string::String, Anchor>,
+    pub extends_overrides: ::std::collections::HashMap<::std::string::String, DecorationsReply_Overrides>,
+    pub build_id: ::std::string::String,
+    // message oneof groups
+    pub _source_text: ::std::option::Option<DecorationsReply_oneof__source_text>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DecorationsReply {
+    fn default() -> &'a DecorationsReply {
+        <DecorationsReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum DecorationsReply_oneof__source_text {
+    source_text(::std::vec::Vec<u8>),
+}
+
+impl DecorationsReply {
+    pub fn new() -> DecorationsReply {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.Location location = 1;
+
+
+    pub fn get_location(&self) -> &Location {
+        self.location.as_ref().unwrap_or_else(|| <Location as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_location(&mut self) {
+        self.location.clear();
+    }
+
+    pub fn has_location(&self) -> bool {
+        self.location.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_location(&mut self, v: Location) {
+        self.location = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_location(&mut self) -> &mut Location {
+        if self.location.is_none() {
+            self.location.set_default();
+        }
+        self.location.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_location(&mut self) -> Location {
+        self.location.take().unwrap_or_else(|| Location::new())
+    }
+
+    // string revision = 18;
+
+
+    pub fn get_revision(&self) -> &str {
+        &self.revision
+    }
+    pub fn clear_revision(&mut self) {
+        self.revision.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_revision(&mut self, v: ::std::string::String) {
+        self.revision = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_revision(&mut self) -> &mut ::std::string::String {
+        &mut self.revision
+    }
+
+    // Take field
+    pub fn take_revision(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.revision, ::std::string::String::new())
+    }
+
+    // bytes source_text = 2;
+
+
+    pub fn get_source_text(&self) -> &[u8] {
+        match self._source_text {
+            ::std::option::Option::Some(DecorationsReply_oneof__source_text::source_text(ref v)) => v,
+            _ => &[],
+        }
+    }
+    pub fn clear_source_text(&mut self) {
+        self._source_text = ::std::option::Option::None;
+    }
+
+    pub fn has_source_text(&self) -> bool {
+        match self._source_text {
+            ::std::option::Option::Some(DecorationsReply_oneof__source_text::source_text(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source_text(&mut self, v: ::std::vec::Vec<u8>) {
+        self._source_text = ::std::option::Option::Some(DecorationsReply_oneof__source_text::source_text(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_source_text(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if let ::std::option::Option::Some(DecorationsReply_oneof__source_text::source_text(_)) = self._source_text {
+        } else {
+            self._source_text = ::std::option::Option::Some(DecorationsReply_oneof__source_text::source_text(::std::vec::Vec::new()));
+        }
+        match self._source_text {
+            ::std::option::Option::Some(DecorationsReply_oneof__source_text::source_text(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_source_text(&mut self) -> ::std::vec::Vec<u8> {
+        if self.has_source_text() {
+            match self._source_text.take() {
+                ::std::option::Option::Some(DecorationsReply_oneof__source_text::source_text(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::vec::Vec::new()
+        }
+    }
+
+    // string encoding = 3;
+
+
+    pub fn get_encoding(&self) -> &str {
+        &self.encoding
+    }
+    pub fn clear_encoding(&mut self) {
+        self.encoding.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_encoding(&mut self, v: ::std::string::String) {
+        self.encoding = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_encoding(&mut self) -> &mut ::std::string::String {
+        &mut self.encoding
+    }
+
+    // Take field
+    pub fn take_encoding(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.encoding, ::std::string::String::new())
+    }
+
+    // repeated .kythe.proto.DecorationsReply.Reference reference = 4;
+
+
+    pub fn get_reference(&self) -> &[DecorationsReply_Reference] {
+        &self.reference
+    }
+    pub fn clear_reference(&mut self) {
+        self.reference.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reference(&mut self, v: ::protobuf::RepeatedField<DecorationsReply_Reference>) {
+        self.reference = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_reference(&mut self) -> &mut ::protobuf::RepeatedField<DecorationsReply_Reference> {
+        &mut self.reference
+    }
+
+    // Take field
+    pub fn take_reference(&mut self) -> ::protobuf::RepeatedField<DecorationsReply_Reference> {
+        ::std::mem::replace(&mut self.reference, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .kythe.proto.common.Diagnostic diagnostic = 5;
+
+
+    pub fn get_diagnostic(&self) -> &[super::common::Diagnostic] {
+        &self.diagnostic
+    }
+    pub fn clear_diagnostic(&mut self) {
+        self.diagnostic.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_diagnostic(&mut self, v: ::protobuf::RepeatedField<super::common::Diagnostic>) {
+        self.diagnostic = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_diagnostic(&mut self) -> &mut ::protobuf::RepeatedField<super::common::Diagnostic> {
+        &mut self.diagnostic
+    }
+
+    // Take field
+    pub fn take_diagnostic(&mut self) -> ::protobuf::RepeatedField<super::common::Diagnostic> {
+        ::std::mem::replace(&mut self.diagnostic, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .kythe.proto.File generated_by_file = 19;
+
+
+    pub fn get_generated_by_file(&self) -> &[File] {
+        &self.generated_by_file
+    }
+    pub fn clear_generated_by_file(&mut self) {
+        self.generated_by_file.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_generated_by_file(&mut self, v: ::protobuf::RepeatedField<File>) {
+        self.generated_by_file = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_generated_by_file(&mut self) -> &mut ::protobuf::RepeatedField<File> {
+        &mut self.generated_by_file
+    }
+
+    // Take field
+    pub fn take_generated_by_file(&mut self) -> ::protobuf::RepeatedField<File> {
+        ::std::mem::replace(&mut self.generated_by_file, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .kythe.proto.DecorationsReply.NodesEntry nodes = 15;
+
+
+    pub fn get_nodes(&self) -> &::std::collections::HashMap<::std::string::String, super::common::NodeInfo> {
+        &self.nodes
+    }
+    pub fn clear_nodes(&mut self) {
+        self.nodes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nodes(&mut self, v: ::std::collections::HashMap<::std::string::String, super::common::NodeInfo>) {
+        self.nodes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_nodes(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, super::common::NodeInfo> {
+        &mut self.nodes
+    }
+
+    // Take field
+    pub fn take_nodes(&mut self) -> ::std::collections::HashMap<::std::string::String, super::common::NodeInfo> {
+        ::std::mem::replace(&mut self.nodes, ::std::collections::HashMap::new())
+    }
+
+    // repeated .kythe.proto.DecorationsReply.DefinitionLocationsEntry definition_locations = 16;
+
+
+    pub fn get_definition_locations(&self) -> &::std::collections::HashMap<::std::string::String, Anchor> {
+        &self.definition_locations
+    }
+    pub fn clear_definition_locations(&mut self) {
+        self.definition_locations.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_definition_locations(&mut self, v: ::std::collections::HashMap<::std::string::String, Anchor>) {
+        self.definition_locations = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_definition_locations(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, Anchor> {
+        &mut self.definition_locations
+    }
+
+    // Take field
+    pub fn take_definition_locations(&mut self) -> ::std::collections::HashMap<::std::string::String, Anchor> {
+        ::std::mem::replace(&mut self.definition_locations, ::std::collections::HashMap::new())
+    }
+
+    // repeated .kythe.proto.DecorationsReply.ExtendsOverridesEntry extends_overrides = 17;
+
+
+    pub fn get_extends_overrides(&self) -> &::std::collections::HashMap<::std::string::String, DecorationsReply_Overrides> {
+        &self.extends_overrides
+    }
+    pub fn clear_extends_overrides(&mut self) {
+        self.extends_overrides.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_extends_overrides(&mut self, v: ::std::collections::HashMap<::std::string::String, DecorationsReply_Overrides>) {
+        self.extends_overrides = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_extends_overrides(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, DecorationsReply_Overrides> {
+        &mut self.extends_overrides
+    }
+
+    // Take field
+    pub fn take_extends_overrides(&mut self) -> ::std::collections::HashMap<::std::string::String, DecorationsReply_Overrides> {
+        ::std::mem::replace(&mut self.extends_overrides, ::std::collections::HashMap::new())
+    }
+
+    // string build_id = 20;
+
+
+    pub fn get_build_id(&self) -> &str {
+        &self.build_id
+    }
+    pub fn clear_build_id(&mut self) {
+        self.build_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_build_id(&mut self, v: ::std::string::String) {
+        self.build_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_build_id(&mut self) -> &mut ::std::string::String {
+        &mut self.build_id
+    }
+
+    // Take field
+    pub fn take_build_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.build_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for DecorationsReply {
+    fn is_initialized(&self) -> bool {
+        for v in &self.location {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.reference {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.diagnostic {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.generated_by_file {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.location)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.revision)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self._source_text = ::std::option::Option::Some(DecorationsReply_oneof__source_text::source_text(is.read_bytes()?));
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.encoding)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.reference)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.diagnostic)?;
+                },
+                19 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.generated_by_file)?;
+                },
+                15 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<super::common::NodeInfo>>(wire_type, is, &mut self.nodes)?;
+                },
+                16 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Anchor>>(wire_type, is, &mut self.definition_locations)?;
+                },
+                17 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<DecorationsReply_Overrides>>(wire_type, is, &mut self.extends_overrides)?;
+                },
+                20 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.build_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.location.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.revision.is_empty() {
+            my_size += ::protobuf::rt::string_size(18, &self.revision);
+        }
+        if !self.encoding.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.encoding);
+        }
+        for value in &self.reference {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.diagnostic {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.generated_by_file {
+            let len = value.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<super::common::NodeInfo>>(15, &self.nodes);
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Anchor>>(16, &self.definition_locations);
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<DecorationsReply_Overrides>>(17, &self.extends_overrides);
+        if !self.build_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(20, &self.build_id);
+        }
+        if let ::std::option::Option::Some(ref v) = self._source_text {
+            match v {
+                &DecorationsReply_oneof__source_text::source_text(ref v) => {
+                    my_size += ::protobuf::rt::bytes_size(2, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.location.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.revision.is_empty() {
+            os.write_string(18, &self.revision)?;
+        }
+        if !self.encoding.is_empty() {
+            os.write_string(3, &self.encoding)?;
+        }
+        for v in &self.reference {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.diagnostic {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.generated_by_file {
+            os.write_tag(19, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<super::common::NodeInfo>>(15, &self.nodes, os)?;
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Anchor>>(16, &self.definition_locations, os)?;
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<DecorationsReply_Overrides>>(17, &self.extends_overrides, os)?;
+        if !self.build_id.is_empty() {
+            os.write_string(20, &self.build_id)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self._source_text {
+            match v {
+                &DecorationsReply_oneof__source_text::source_text(ref v) => {
+                    os.write_bytes(2, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DecorationsReply {
+        DecorationsReply::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Location>>(
+                "location",
+                |m: &DecorationsReply| { &m.location },
+                |m: &mut DecorationsReply| { &mut m.location },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "revision",
+                |m: &DecorationsReply| { &m.revision },
+                |m: &mut DecorationsReply| { &mut m.revision },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor::<_>(
+                "source_text",
+                DecorationsReply::has_source_text,
+                DecorationsReply::get_source_text,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "encoding",
+                |m: &DecorationsReply| { &m.encoding },
+                |m: &mut DecorationsReply| { &mut m.encoding },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DecorationsReply_Reference>>(
+                "reference",
+                |m: &DecorationsReply| { &m.reference },
+                |m: &mut DecorationsReply| { &mut m.reference },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::Diagnostic>>(
+                "diagnostic",
+                |m: &DecorationsReply| { &m.diagnostic },
+                |m: &mut DecorationsReply| { &mut m.diagnostic },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<File>>(
+                "generated_by_file",
+                |m: &DecorationsReply| { &m.generated_by_file },
+                |m: &mut DecorationsReply| { &mut m.generated_by_file },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<super::common::NodeInfo>>(
+                "nodes",
+                |m: &DecorationsReply| { &m.nodes },
+                |m: &mut DecorationsReply| { &mut m.nodes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Anchor>>(
+                "definition_locations",
+                |m: &DecorationsReply| { &m.definition_locations },
+                |m: &mut DecorationsReply| { &mut m.definition_locations },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<DecorationsReply_Overrides>>(
+                "extends_overrides",
+                |m: &DecorationsReply| { &m.extends_overrides },
+                |m: &mut DecorationsReply| { &mut m.extends_overrides },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "build_id",
+                |m: &DecorationsReply| { &m.build_id },
+                |m: &mut DecorationsReply| { &mut m.build_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DecorationsReply>(
+                "DecorationsReply",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DecorationsReply {
+        static instance: ::protobuf::rt::LazyV2<DecorationsReply> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DecorationsReply::new)
+    }
+}
+
+impl ::protobuf::Clear for DecorationsReply {
+    fn clear(&mut self) {
+        self.location.clear();
+        self.revision.clear();
+        self._source_text = ::std::option::Option::None;
+        self.encoding.clear();
+        self.reference.clear();
+        self.diagnostic.clear();
+        self.generated_by_file.clear();
+        self.nodes.clear();
+        self.definition_locations.clear();
+        self.extends_overrides.clear();
+        self.build_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DecorationsReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DecorationsReply {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DecorationsReply_Reference {
+    // message fields
+    pub target_ticket: ::std::string::String,
+    pub kind: ::std::string::String,
+    pub target_definition: ::std::string::String,
+    pub span: ::protobuf::SingularPtrField<super::common::Span>,
+    pub build_config: ::std::string::String,
+    pub semantic_scope: ::std::string::String,
+    pub target_revision: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DecorationsReply_Reference {
+    fn default() -> &'a DecorationsReply_Reference {
+        <DecorationsReply_Reference as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DecorationsReply_Reference {
+    pub fn new() -> DecorationsReply_Reference {
+        ::std::default::Default::default()
+    }
+
+    // string target_ticket = 2;
+
+
+    pub fn get_target_ticket(&self) -> &str {
+        &self.target_ticket
+    }
+    pub fn clear_target_ticket(&mut self) {
+        self.target_ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_ticket(&mut self, v: ::std::string::String) {
+        self.target_ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target_ticket(&mut self) -> &mut ::std::string::String {
+        &mut self.target_ticket
+    }
+
+    // Take field
+    pub fn take_target_ticket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.target_ticket, ::std::string::String::new())
+    }
+
+    // string kind = 3;
+
+
+    pub fn get_kind(&self) -> &str {
+        &self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: ::std::string::String) {
+        self.kind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_kind(&mut self) -> &mut ::std::string::String {
+        &mut self.kind
+    }
+
+    // Take field
+    pub fn take_kind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.kind, ::std::string::String::new())
+    }
+
+    // string target_definition = 4;
+
+
+    pub fn get_target_definition(&self) -> &str {
+        &self.target_definition
+    }
+    pub fn clear_target_definition(&mut self) {
+        self.target_definition.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_definition(&mut self, v: ::std::string::String) {
+        self.target_definition = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target_definition(&mut self) -> &mut ::std::string::String {
+        &mut self.target_definition
+    }
+
+    // Take field
+    pub fn take_target_definition(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.target_definition, ::std::string::String::new())
+    }
+
+    // .kythe.proto.common.Span span = 5;
+
+
+    pub fn get_span(&self) -> &super::common::Span {
+        self.span.as_ref().unwrap_or_else(|| <super::common::Span as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_span(&mut self) {
+        self.span.clear();
+    }
+
+    pub fn has_span(&self) -> bool {
+        self.span.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_span(&mut self, v: super::common::Span) {
+        self.span = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_span(&mut self) -> &mut super::common::Span {
+        if self.span.is_none() {
+            self.span.set_default();
+        }
+        self.span.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_span(&mut self) -> super::common::Span {
+        self.span.take().unwrap_or_else(|| super::common::Span::new())
+    }
+
+    // string build_config = 6;
+
+
+    pub fn get_build_config(&self) -> &str {
+        &self.build_config
+    }
+    pub fn clear_build_config(&mut self) {
+        self.build_config.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_build_config(&mut self, v: ::std::string::String) {
+        self.build_config = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_build_config(&mut self) -> &mut ::std::string::String {
+        &mut self.build_config
+    }
+
+    // Take field
+    pub fn take_build_config(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.build_config, ::std::string::String::new())
+    }
+
+    // string semantic_scope = 7;
+
+
+    pub fn get_semantic_scope(&self) -> &str {
+        &self.semantic_scope
+    }
+    pub fn clear_semantic_scope(&mut self) {
+        self.semantic_scope.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_semantic_scope(&mut self, v: ::std::string::String) {
+        self.semantic_scope = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_semantic_scope(&mut self) -> &mut ::std::string::String {
+        &mut self.semantic_scope
+    }
+
+    // Take field
+    pub fn take_semantic_scope(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.semantic_scope, ::std::string::String::new())
+    }
+
+    // string target_revision = 8;
+
+
+    pub fn get_target_revision(&self) -> &str {
+        &self.target_revision
+    }
+    pub fn clear_target_revision(&mut self) {
+        self.target_revision.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_revision(&mut self, v: ::std::string::String) {
+        self.target_revision = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target_revision(&mut self) -> &mut ::std::string::String {
+        &mut self.target_revision
+    }
+
+    // Take field
+    pub fn take_target_revision(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.target_revision, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for DecorationsReply_Reference {
+    fn is_initialized(&self) -> bool {
+        for v in &self.span {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.target_ticket)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.kind)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.target_definition)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.span)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.build_config)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.semantic_scope)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.target_revision)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.target_ticket.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.target_ticket);
+        }
+        if !self.kind.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.kind);
+        }
+        if !self.target_definition.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.target_definition);
+        }
+        if let Some(ref v) = self.span.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.build_config.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.build_config);
+        }
+        if !self.semantic_scope.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.semantic_scope);
+        }
+        if !self.target_revision.is_empty() {
+            my_size += ::protobuf::rt::string_size(8, &self.target_revision);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.target_ticket.is_empty() {
+            os.write_string(2, &self.target_ticket)?;
+        }
+        if !self.kind.is_empty() {
+            os.write_string(3, &self.kind)?;
+        }
+        if !self.target_definition.is_empty() {
+            os.write_string(4, &self.target_definition)?;
+        }
+        if let Some(ref v) = self.span.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.build_config.is_empty() {
+            os.write_string(6, &self.build_config)?;
+        }
+        if !self.semantic_scope.is_empty() {
+            os.write_string(7, &self.semantic_scope)?;
+        }
+        if !self.target_revision.is_empty() {
+            os.write_string(8, &self.target_revision)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DecorationsReply_Reference {
+        DecorationsReply_Reference::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "target_ticket",
+                |m: &DecorationsReply_Reference| { &m.target_ticket },
+                |m: &mut DecorationsReply_Reference| { &mut m.target_ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "kind",
+                |m: &DecorationsReply_Reference| { &m.kind },
+                |m: &mut DecorationsReply_Reference| { &mut m.kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "target_definition",
+                |m: &DecorationsReply_Reference| { &m.target_definition },
+                |m: &mut DecorationsReply_Reference| { &mut m.target_definition },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::Span>>(
+                "span",
+                |m: &DecorationsReply_Reference| { &m.span },
+                |m: &mut DecorationsReply_Reference| { &mut m.span },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "build_config",
+                |m: &DecorationsReply_Reference| { &m.build_config },
+                |m: &mut DecorationsReply_Reference| { &mut m.build_config },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "semantic_scope",
+                |m: &DecorationsReply_Reference| { &m.semantic_scope },
+                |m: &mut DecorationsReply_Reference| { &mut m.semantic_scope },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "target_revision",
+                |m: &DecorationsReply_Reference| { &m.target_revision },
+                |m: &mut DecorationsReply_Reference| { &mut m.target_revision },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DecorationsReply_Reference>(
+                "DecorationsReply.Reference",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DecorationsReply_Reference {
+        static instance: ::protobuf::rt::LazyV2<DecorationsReply_Reference> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DecorationsReply_Reference::new)
+    }
+}
+
+impl ::protobuf::Clear for DecorationsReply_Reference {
+    fn clear(&mut self) {
+        self.target_ticket.clear();
+        self.kind.clear();
+        self.target_definition.clear();
+        self.span.clear();
+        self.build_config.clear();
+        self.semantic_scope.clear();
+        self.target_revision.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DecorationsReply_Reference {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DecorationsReply_Reference {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DecorationsReply_Override {
+    // message fields
+    pub target: ::std::string::String,
+    pub target_definition: ::std::string::String,
+    pub kind: DecorationsReply_Override_Kind,
+    pub marked_source: ::protobuf::SingularPtrField<super::common::MarkedSource>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DecorationsReply_Override {
+    fn default() -> &'a DecorationsReply_Override {
+        <DecorationsReply_Override as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DecorationsReply_Override {
+    pub fn new() -> DecorationsReply_Override {
+        ::std::default::Default::default()
+    }
+
+    // string target = 1;
+
+
+    pub fn get_target(&self) -> &str {
+        &self.target
+    }
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: ::std::string::String) {
+        self.target = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut ::std::string::String {
+        &mut self.target
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.target, ::std::string::String::new())
+    }
+
+    // string target_definition = 5;
+
+
+    pub fn get_target_definition(&self) -> &str {
+        &self.target_definition
+    }
+    pub fn clear_target_definition(&mut self) {
+        self.target_definition.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_definition(&mut self, v: ::std::string::String) {
+        self.target_definition = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target_definition(&mut self) -> &mut ::std::string::String {
+        &mut self.target_definition
+    }
+
+    // Take field
+    pub fn take_target_definition(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.target_definition, ::std::string::String::new())
+    }
+
+    // .kythe.proto.DecorationsReply.Override.Kind kind = 2;
+
+
+    pub fn get_kind(&self) -> DecorationsReply_Override_Kind {
+        self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind = DecorationsReply_Override_Kind::OVERRIDES;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: DecorationsReply_Override_Kind) {
+        self.kind = v;
+    }
+
+    // .kythe.proto.common.MarkedSource marked_source = 4;
+
+
+    pub fn get_marked_source(&self) -> &super::common::MarkedSource {
+        self.marked_source.as_ref().unwrap_or_else(|| <super::common::MarkedSource as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_marked_source(&mut self) {
+        self.marked_source.clear();
+    }
+
+    pub fn has_marked_source(&self) -> bool {
+        self.marked_source.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_marked_source(&mut self, v: super::common::MarkedSource) {
+        self.marked_source = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_marked_source(&mut self) -> &mut super::common::MarkedSource {
+        if self.marked_source.is_none() {
+            self.marked_source.set_default();
+        }
+        self.marked_source.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_marked_source(&mut self) -> super::common::MarkedSource {
+        self.marked_source.take().unwrap_or_else(|| super::common::MarkedSource::new())
+    }
+}
+
+impl ::protobuf::Message for DecorationsReply_Override {
+    fn is_initialized(&self) -> bool {
+        for v in &self.marked_source {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.target)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.target_definition)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.kind, 2, &mut self.unknown_fields)?
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.marked_source)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.target.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.target);
+        }
+        if !self.target_definition.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.target_definition);
+        }
+        if self.kind != DecorationsReply_Override_Kind::OVERRIDES {
+            my_size += ::protobuf::rt::enum_size(2, self.kind);
+        }
+        if let Some(ref v) = self.marked_source.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.target.is_empty() {
+            os.write_string(1, &self.target)?;
+        }
+        if !self.target_definition.is_empty() {
+            os.write_string(5, &self.target_definition)?;
+        }
+        if self.kind != DecorationsReply_Override_Kind::OVERRIDES {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.kind))?;
+        }
+        if let Some(ref v) = self.marked_source.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DecorationsReply_Override {
+        DecorationsReply_Override::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "target",
+                |m: &DecorationsReply_Override| { &m.target },
+                |m: &mut DecorationsReply_Override| { &mut m.target },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "target_definition",
+                |m: &DecorationsReply_Override| { &m.target_definition },
+                |m: &mut DecorationsReply_Override| { &mut m.target_definition },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<DecorationsReply_Override_Kind>>(
+                "kind",
+                |m: &DecorationsReply_Override| { &m.kind },
+                |m: &mut DecorationsReply_Override| { &mut m.kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::MarkedSource>>(
+                "marked_source",
+                |m: &DecorationsReply_Override| { &m.marked_source },
+                |m: &mut DecorationsReply_Override| { &mut m.marked_source },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DecorationsReply_Override>(
+                "DecorationsReply.Override",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DecorationsReply_Override {
+        static instance: ::protobuf::rt::LazyV2<DecorationsReply_Override> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DecorationsReply_Override::new)
+    }
+}
+
+impl ::protobuf::Clear for DecorationsReply_Override {
+    fn clear(&mut self) {
+        self.target.clear();
+        self.target_definition.clear();
+        self.kind = DecorationsReply_Override_Kind::OVERRIDES;
+        self.marked_source.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DecorationsReply_Override {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DecorationsReply_Override {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum DecorationsReply_Override_Kind {
+    OVERRIDES = 0,
+    EXTENDS = 1,
+}
+
+impl ::protobuf::ProtobufEnum for DecorationsReply_Override_Kind {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<DecorationsReply_Override_Kind> {
+        match value {
+            0 => ::std::option::Option::Some(DecorationsReply_Override_Kind::OVERRIDES),
+            1 => ::std::option::Option::Some(DecorationsReply_Override_Kind::EXTENDS),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [DecorationsReply_Override_Kind] = &[
+            DecorationsReply_Override_Kind::OVERRIDES,
+            DecorationsReply_Override_Kind::EXTENDS,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<DecorationsReply_Override_Kind>("DecorationsReply.Override.Kind", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for DecorationsReply_Override_Kind {
+}
+
+impl ::std::default::Default for DecorationsReply_Override_Kind {
+    fn default() -> Self {
+        DecorationsReply_Override_Kind::OVERRIDES
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DecorationsReply_Override_Kind {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DecorationsReply_Overrides {
+    // message fields
+    pub field_override: ::protobuf::RepeatedField<DecorationsReply_Override>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DecorationsReply_Overrides {
+    fn default() -> &'a DecorationsReply_Overrides {
+        <DecorationsReply_Overrides as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DecorationsReply_Overrides {
+    pub fn new() -> DecorationsReply_Overrides {
+        ::std::default::Default::default()
+    }
+
+    // repeated .kythe.proto.DecorationsReply.Override override = 1;
+
+
+    pub fn get_field_override(&self) -> &[DecorationsReply_Override] {
+        &self.field_override
+    }
+    pub fn clear_field_override(&mut self) {
+        self.field_override.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_override(&mut self, v: ::protobuf::RepeatedField<DecorationsReply_Override>) {
+        self.field_override = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_field_override(&mut self) -> &mut ::protobuf::RepeatedField<DecorationsReply_Override> {
+        &mut self.field_override
+    }
+
+    // Take field
+    pub fn take_field_override(&mut self) -> ::protobuf::RepeatedField<DecorationsReply_Override> {
+        ::std::mem::replace(&mut self.field_override, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for DecorationsReply_Overrides {
+    fn is_initialized(&self) -> bool {
+        for v in &self.field_override {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.field_override)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.field_override {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.field_override {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DecorationsReply_Overrides {
+        DecorationsReply_Overrides::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DecorationsReply_Override>>(
+                "override",
+                |m: &DecorationsReply_Overrides| { &m.field_override },
+                |m: &mut DecorationsReply_Overrides| { &mut m.field_override },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DecorationsReply_Overrides>(
+                "DecorationsReply.Overrides",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DecorationsReply_Overrides {
+        static instance: ::protobuf::rt::LazyV2<DecorationsReply_Overrides> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DecorationsReply_Overrides::new)
+    }
+}
+
+impl ::protobuf::Clear for DecorationsReply_Overrides {
+    fn clear(&mut self) {
+        self.field_override.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DecorationsReply_Overrides {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DecorationsReply_Overrides {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CrossReferencesRequest {
+    // message fields
+    pub ticket: ::protobuf::RepeatedField<::std::string::String>,
+    pub definition_kind: CrossReferencesRequest_DefinitionKind,
+    pub declaration_kind: CrossReferencesRequest_DeclarationKind,
+    pub reference_kind: CrossReferencesRequest_ReferenceKind,
+    pub caller_kind: CrossReferencesRequest_CallerKind,
+    pub semantic_scopes: bool,
+    pub filter: ::protobuf::RepeatedField<::std::string::String>,
+    pub related_node_kind: ::protobuf::RepeatedField<::std::string::String>,
+    pub anchor_text: bool,
+    pub node_definitions: bool,
+    pub totals_quality: CrossReferencesRequest_TotalsQuality,
+    pub page_size: i32,
+    pub page_token: ::std::string::String,
+    pub snippets: SnippetsKind,
+    pub build_config: ::protobuf::RepeatedField<::std::string::String>,
+    pub workspace: ::protobuf::SingularPtrField<Workspace>,
+    pub patch_against_workspace: bool,
+    pub corpus_path_filters: ::protobuf::SingularPtrField<CorpusPathFilters>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CrossReferencesRequest {
+    fn default() -> &'a CrossReferencesRequest {
+        <CrossReferencesRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CrossReferencesRequest {
+    pub fn new() -> CrossReferencesRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated string ticket = 1;
+
+
+    pub fn get_ticket(&self) -> &[::std::string::String] {
+        &self.ticket
+    }
+    pub fn clear_ticket(&mut self) {
+        self.ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ticket(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_ticket(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.ticket
+    }
+
+    // Take field
+    pub fn take_ticket(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.ticket, ::protobuf::RepeatedField::new())
+    }
+
+    // .kythe.proto.CrossReferencesRequest.DefinitionKind definition_kind = 2;
+
+
+    pub fn get_definition_kind(&self) -> CrossReferencesRequest_DefinitionKind {
+        self.definition_kind
+    }
+    pub fn clear_definition_kind(&mut self) {
+        self.definition_kind = CrossReferencesRequest_DefinitionKind::NO_DEFINITIONS;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_definition_kind(&mut self, v: CrossReferencesRequest_DefinitionKind) {
+        self.definition_kind = v;
+    }
+
+    // .kythe.proto.CrossReferencesRequest.DeclarationKind declaration_kind = 7;
+
+
+    pub fn get_declaration_kind(&self) -> CrossReferencesRequest_DeclarationKind {
+        self.declaration_kind
+    }
+    pub fn clear_declaration_kind(&mut self) {
+        self.declaration_kind = CrossReferencesRequest_DeclarationKind::NO_DECLARATIONS;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_declaration_kind(&mut self, v: CrossReferencesRequest_DeclarationKind) {
+        self.declaration_kind = v;
+    }
+
+    // .kythe.proto.CrossReferencesRequest.ReferenceKind reference_kind = 3;
+
+
+    pub fn get_reference_kind(&self) -> CrossReferencesRequest_ReferenceKind {
+        self.reference_kind
+    }
+    pub fn clear_reference_kind(&mut self) {
+        self.reference_kind = CrossReferencesRequest_ReferenceKind::NO_REFERENCES;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reference_kind(&mut self, v: CrossReferencesRequest_ReferenceKind) {
+        self.reference_kind = v;
+    }
+
+    // .kythe.proto.CrossReferencesRequest.CallerKind caller_kind = 12;
+
+
+    pub fn get_caller_kind(&self) -> CrossReferencesRequest_CallerKind {
+        self.caller_kind
+    }
+    pub fn clear_caller_kind(&mut self) {
+        self.caller_kind = CrossReferencesRequest_CallerKind::NO_CALLERS;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_caller_kind(&mut self, v: CrossReferencesRequest_CallerKind) {
+        self.caller_kind = v;
+    }
+
+    // bool semantic_scopes = 20;
+
+
+    pub fn get_semantic_scopes(&self) -> bool {
+        self.semantic_scopes
+    }
+    pub fn clear_semantic_scopes(&mut self) {
+        self.semantic_scopes = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_semantic_scopes(&mut self, v: bool) {
+        self.semantic_scopes = v;
+    }
+
+    // repeated string filter = 5;
+
+
+    pub fn get_filter(&self) -> &[::std::string::String] {
+        &self.filter
+    }
+    pub fn clear_filter(&mut self) {
+        self.filter.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_filter(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.filter = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_filter(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.filter
+    }
+
+    // Take field
+    pub fn take_filter(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.filter, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string related_node_kind = 14;
+
+
+    pub fn get_related_node_kind(&self) -> &[::std::string::String] {
+        &self.related_node_kind
+    }
+    pub fn clear_related_node_kind(&mut self) {
+        self.related_node_kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_related_node_kind(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.related_node_kind = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_related_node_kind(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.related_node_kind
+    }
+
+    // Take field
+    pub fn take_related_node_kind(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.related_node_kind, ::protobuf::RepeatedField::new())
+    }
+
+    // bool anchor_text = 6;
+
+
+    pub fn get_anchor_text(&self) -> bool {
+        self.anchor_text
+    }
+    pub fn clear_anchor_text(&mut self) {
+        self.anchor_text = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_anchor_text(&mut self, v: bool) {
+        self.anchor_text = v;
+    }
+
+    // bool node_definitions = 8;
+
+
+    pub fn get_node_definitions(&self) -> bool {
+        self.node_definitions
+    }
+    pub fn clear_node_definitions(&mut self) {
+        self.node_definitions = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_node_definitions(&mut self, v: bool) {
+        self.node_definitions = v;
+    }
+
+    // .kythe.proto.CrossReferencesRequest.TotalsQuality totals_quality = 16;
+
+
+    pub fn get_totals_quality(&self) -> CrossReferencesRequest_TotalsQuality {
+        self.totals_quality
+    }
+    pub fn clear_totals_quality(&mut self) {
+        self.totals_quality = CrossReferencesRequest_TotalsQuality::UNSPECIFIED_TOTALS;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_totals_quality(&mut self, v: CrossReferencesRequest_TotalsQuality) {
+        self.totals_quality = v;
+    }
+
+    // int32 page_size = 10;
+
+
+    pub fn get_page_size(&self) -> i32 {
+        self.page_size
+    }
+    pub fn clear_page_size(&mut self) {
+        self.page_size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_page_size(&mut self, v: i32) {
+        self.page_size = v;
+    }
+
+    // string page_token = 11;
+
+
+    pub fn get_page_token(&self) -> &str {
+        &self.page_token
+    }
+    pub fn clear_page_token(&mut self) {
+        self.page_token.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_page_token(&mut self, v: ::std::string::String) {
+        self.page_token = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_page_token(&mut self) -> &mut ::std::string::String {
+        &mut self.page_token
+    }
+
+    // Take field
+    pub fn take_page_token(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.page_token, ::std::string::String::new())
+    }
+
+    // .kythe.proto.SnippetsKind snippets = 13;
+
+
+    pub fn get_snippets(&self) -> SnippetsKind {
+        self.snippets
+    }
+    pub fn clear_snippets(&mut self) {
+        self.snippets = SnippetsKind::NONE;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_snippets(&mut self, v: SnippetsKind) {
+        self.snippets = v;
+    }
+
+    // repeated string build_config = 15;
+
+
+    pub fn get_build_config(&self) -> &[::std::string::String] {
+        &self.build_config
+    }
+    pub fn clear_build_config(&mut self) {
+        self.build_config.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_build_config(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.build_config = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_build_config(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.build_config
+    }
+
+    // Take field
+    pub fn take_build_config(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.build_config, ::protobuf::RepeatedField::new())
+    }
+
+    // .kythe.proto.Workspace workspace = 17;
+
+
+    pub fn get_workspace(&self) -> &Workspace {
+        self.workspace.as_ref().unwrap_or_else(|| <Workspace as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_workspace(&mut self) {
+        self.workspace.clear();
+    }
+
+    pub fn has_workspace(&self) -> bool {
+        self.workspace.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace(&mut self, v: Workspace) {
+        self.workspace = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace(&mut self) -> &mut Workspace {
+        if self.workspace.is_none() {
+            self.workspace.set_default();
+        }
+        self.workspace.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_workspace(&mut self) -> Workspace {
+        self.workspace.take().unwrap_or_else(|| Workspace::new())
+    }
+
+    // bool patch_against_workspace = 18;
+
+
+    pub fn get_patch_against_workspace(&self) -> bool {
+        self.patch_against_workspace
+    }
+    pub fn clear_patch_against_workspace(&mut self) {
+        self.patch_against_workspace = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_patch_against_workspace(&mut self, v: bool) {
+        self.patch_against_workspace = v;
+    }
+
+    // .kythe.proto.CorpusPathFilters corpus_path_filters = 19;
+
+
+    pub fn get_corpus_path_filters(&self) -> &CorpusPathFilters {
+        self.corpus_path_filters.as_ref().unwrap_or_else(|| <CorpusPathFilters as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_corpus_path_filters(&mut self) {
+        self.corpus_path_filters.clear();
+    }
+
+    pub fn has_corpus_path_filters(&self) -> bool {
+        self.corpus_path_filters.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_corpus_path_filters(&mut self, v: CorpusPathFilters) {
+        self.corpus_path_filters = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_corpus_path_filters(&mut self) -> &mut CorpusPathFilters {
+        if self.corpus_path_filters.is_none() {
+            self.corpus_path_filters.set_default();
+        }
+        self.corpus_path_filters.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_corpus_path_filters(&mut self) -> CorpusPathFilters {
+        self.corpus_path_filters.take().unwrap_or_else(|| CorpusPathFilters::new())
+    }
+}
+
+impl ::protobuf::Message for CrossReferencesRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.workspace {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.corpus_path_filters {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.ticket)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.definition_kind, 2, &mut self.unknown_fields)?
+                },
+                7 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.declaration_kind, 7, &mut self.unknown_fields)?
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.reference_kind, 3, &mut self.unknown_fields)?
+                },
+                12 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.caller_kind, 12, &mut self.unknown_fields)?
+                },
+                20 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.semantic_scopes = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.filter)?;
+                },
+                14 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.related_node_kind)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.anchor_text = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.node_definitions = tmp;
+                },
+                16 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.totals_quality, 16, &mut self.unknown_fields)?
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.page_size = tmp;
+                },
+                11 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.page_token)?;
+                },
+                13 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.snippets, 13, &mut self.unknown_fields)?
+                },
+                15 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.build_config)?;
+                },
+                17 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.workspace)?;
+                },
+                18 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.patch_against_workspace = tmp;
+                },
+                19 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.corpus_path_filters)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.ticket {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        if self.definition_kind != CrossReferencesRequest_DefinitionKind::NO_DEFINITIONS {
+            my_size += ::protobuf::rt::enum_size(2, self.definition_kind);
+        }
+        if self.declaration_kind != CrossReferencesRequest_DeclarationKind::NO_DECLARATIONS {
+            my_size += ::protobuf::rt::enum_size(7, self.declaration_kind);
+        }
+        if self.reference_kind != CrossReferencesRequest_ReferenceKind::NO_REFERENCES {
+            my_size += ::protobuf::rt::enum_size(3, self.reference_kind);
+        }
+        if self.caller_kind != CrossReferencesRequest_CallerKind::NO_CALLERS {
+            my_size += ::protobuf::rt::enum_size(12, self.caller_kind);
+        }
+        if self.semantic_scopes != false {
+            my_size += 3;
+        }
+        for value in &self.filter {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        for value in &self.related_node_kind {
+            my_size += ::protobuf::rt::string_size(14, &value);
+        };
+        if self.anchor_text != false {
+            my_size += 2;
+        }
+        if self.node_definitions != false {
+            my_size += 2;
+        }
+        if self.totals_quality != CrossReferencesRequest_TotalsQuality::UNSPECIFIED_TOTALS {
+            my_size += ::protobuf::rt::enum_size(16, self.totals_quality);
+        }
+        if self.page_size != 0 {
+            my_size += ::protobuf::rt::value_size(10, self.page_size, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.page_token.is_empty() {
+            my_size += ::protobuf::rt::string_size(11, &self.page_token);
+        }
+        if self.snippets != SnippetsKind::NONE {
+            my_size += ::protobuf::rt::enum_size(13, self.snippets);
+        }
+        for value in &self.build_config {
+            my_size += ::protobuf::rt::string_size(15, &value);
+        };
+        if let Some(ref v) = self.workspace.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.patch_against_workspace != false {
+            my_size += 3;
+        }
+        if let Some(ref v) = self.corpus_path_filters.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.ticket {
+            os.write_string(1, &v)?;
+        };
+        if self.definition_kind != CrossReferencesRequest_DefinitionKind::NO_DEFINITIONS {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.definition_kind))?;
+        }
+        if self.declaration_kind != CrossReferencesRequest_DeclarationKind::NO_DECLARATIONS {
+            os.write_enum(7, ::protobuf::ProtobufEnum::value(&self.declaration_kind))?;
+        }
+        if self.reference_kind != CrossReferencesRequest_ReferenceKind::NO_REFERENCES {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.reference_kind))?;
+        }
+        if self.caller_kind != CrossReferencesRequest_CallerKind::NO_CALLERS {
+            os.write_enum(12, ::protobuf::ProtobufEnum::value(&self.caller_kind))?;
+        }
+        if self.semantic_scopes != false {
+            os.write_bool(20, self.semantic_scopes)?;
+        }
+        for v in &self.filter {
+            os.write_string(5, &v)?;
+        };
+        for v in &self.related_node_kind {
+            os.write_string(14, &v)?;
+        };
+        if self.anchor_text != false {
+            os.write_bool(6, self.anchor_text)?;
+        }
+        if self.node_definitions != false {
+            os.write_bool(8, self.node_definitions)?;
+        }
+        if self.totals_quality != CrossReferencesRequest_TotalsQuality::UNSPECIFIED_TOTALS {
+            os.write_enum(16, ::protobuf::ProtobufEnum::value(&self.totals_quality))?;
+        }
+        if self.page_size != 0 {
+            os.write_int32(10, self.page_size)?;
+        }
+        if !self.page_token.is_empty() {
+            os.write_string(11, &self.page_token)?;
+        }
+        if self.snippets != SnippetsKind::NONE {
+            os.write_enum(13, ::protobuf::ProtobufEnum::value(&self.snippets))?;
+        }
+        for v in &self.build_config {
+            os.write_string(15, &v)?;
+        };
+        if let Some(ref v) = self.workspace.as_ref() {
+            os.write_tag(17, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.patch_against_workspace != false {
+            os.write_bool(18, self.patch_against_workspace)?;
+        }
+        if let Some(ref v) = self.corpus_path_filters.as_ref() {
+            os.write_tag(19, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CrossReferencesRequest {
+        CrossReferencesRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "ticket",
+                |m: &CrossReferencesRequest| { &m.ticket },
+                |m: &mut CrossReferencesRequest| { &mut m.ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<CrossReferencesRequest_DefinitionKind>>(
+                "definition_kind",
+                |m: &CrossReferencesRequest| { &m.definition_kind },
+                |m: &mut CrossReferencesRequest| { &mut m.definition_kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<CrossReferencesRequest_DeclarationKind>>(
+                "declaration_kind",
+                |m: &CrossReferencesRequest| { &m.declaration_kind },
+                |m: &mut CrossReferencesRequest| { &mut m.declaration_kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<CrossReferencesRequest_ReferenceKind>>(
+                "reference_kind",
+                |m: &CrossReferencesRequest| { &m.reference_kind },
+                |m: &mut CrossReferencesRequest| { &mut m.reference_kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<CrossReferencesRequest_CallerKind>>(
+                "caller_kind",
+                |m: &CrossReferencesRequest| { &m.caller_kind },
+                |m: &mut CrossReferencesRequest| { &mut m.caller_kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "semantic_scopes",
+                |m: &CrossReferencesRequest| { &m.semantic_scopes },
+                |m: &mut CrossReferencesRequest| { &mut m.semantic_scopes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "filter",
+                |m: &CrossReferencesRequest| { &m.filter },
+                |m: &mut CrossReferencesRequest| { &mut m.filter },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "related_node_kind",
+                |m: &CrossReferencesRequest| { &m.related_node_kind },
+                |m: &mut CrossReferencesRequest| { &mut m.related_node_kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "anchor_text",
+                |m: &CrossReferencesRequest| { &m.anchor_text },
+                |m: &mut CrossReferencesRequest| { &mut m.anchor_text },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "node_definitions",
+                |m: &CrossReferencesRequest| { &m.node_definitions },
+                |m: &mut CrossReferencesRequest| { &mut m.node_definitions },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<CrossReferencesRequest_TotalsQuality>>(
+                "totals_quality",
+                |m: &CrossReferencesRequest| { &m.totals_quality },
+                |m: &mut CrossReferencesRequest| { &mut m.totals_quality },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "page_size",
+                |m: &CrossReferencesRequest| { &m.page_size },
+                |m: &mut CrossReferencesRequest| { &mut m.page_size },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "page_token",
+                |m: &CrossReferencesRequest| { &m.page_token },
+                |m: &mut CrossReferencesRequest| { &mut m.page_token },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<SnippetsKind>>(
+                "snippets",
+                |m: &CrossReferencesRequest| { &m.snippets },
+                |m: &mut CrossReferencesRequest| { &mut m.snippets },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "build_config",
+                |m: &CrossReferencesRequest| { &m.build_config },
+                |m: &mut CrossReferencesRequest| { &mut m.build_config },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Workspace>>(
+                "workspace",
+                |m: &CrossReferencesRequest| { &m.workspace },
+                |m: &mut CrossReferencesRequest| { &mut m.workspace },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "patch_against_workspace",
+                |m: &CrossReferencesRequest| { &m.patch_against_workspace },
+                |m: &mut CrossReferencesRequest| { &mut m.patch_against_workspace },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CorpusPathFilters>>(
+                "corpus_path_filters",
+                |m: &CrossReferencesRequest| { &m.corpus_path_filters },
+                |m: &mut CrossReferencesRequest| { &mut m.corpus_path_filters },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CrossReferencesRequest>(
+                "CrossReferencesRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CrossReferencesRequest {
+        static instance: ::protobuf::rt::LazyV2<CrossReferencesRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CrossReferencesRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for CrossReferencesRequest {
+    fn clear(&mut self) {
+        self.ticket.clear();
+        self.definition_kind = CrossReferencesRequest_DefinitionKind::NO_DEFINITIONS;
+        self.declaration_kind = CrossReferencesRequest_DeclarationKind::NO_DECLARATIONS;
+        self.reference_kind = CrossReferencesRequest_ReferenceKind::NO_REFERENCES;
+        self.caller_kind = CrossReferencesRequest_CallerKind::NO_CALLERS;
+        self.semantic_scopes = false;
+        self.filter.clear();
+        self.related_node_kind.clear();
+        self.anchor_text = false;
+        self.node_definitions = false;
+        self.totals_quality = CrossReferencesRequest_TotalsQuality::UNSPECIFIED_TOTALS;
+        self.page_size = 0;
+        self.page_token.clear();
+        self.snippets = SnippetsKind::NONE;
+        self.build_config.clear();
+        self.workspace.clear();
+        self.patch_against_workspace = false;
+        self.corpus_path_filters.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CrossReferencesRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CrossReferencesRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum CrossReferencesRequest_DefinitionKind {
+    NO_DEFINITIONS = 0,
+    ALL_DEFINITIONS = 1,
+    FULL_DEFINITIONS = 2,
+    BINDING_DEFINITIONS = 3,
+}
+
+impl ::protobuf::ProtobufEnum for CrossReferencesRequest_DefinitionKind {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<CrossReferencesRequest_DefinitionKind> {
+        match value {
+            0 => ::std::option::Option::Some(CrossReferencesRequest_DefinitionKind::NO_DEFINITIONS),
+            1 => ::std::option::Option::Some(CrossReferencesRequest_DefinitionKind::ALL_DEFINITIONS),
+            2 => ::std::option::Option::Some(CrossReferencesRequest_DefinitionKind::FULL_DEFINITIONS),
+            3 => ::std::option::Option::Some(CrossReferencesRequest_DefinitionKind::BINDING_DEFINITIONS),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [CrossReferencesRequest_DefinitionKind] = &[
+            CrossReferencesRequest_DefinitionKind::NO_DEFINITIONS,
+            CrossReferencesRequest_DefinitionKind::ALL_DEFINITIONS,
+            CrossReferencesRequest_DefinitionKind::FULL_DEFINITIONS,
+            CrossReferencesRequest_DefinitionKind::BINDING_DEFINITIONS,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<CrossReferencesRequest_DefinitionKind>("CrossReferencesRequest.DefinitionKind", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for CrossReferencesRequest_DefinitionKind {
+}
+
+impl ::std::default::Default for CrossReferencesRequest_DefinitionKind {
+    fn default() -> Self {
+        CrossReferencesRequest_DefinitionKind::NO_DEFINITIONS
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CrossReferencesRequest_DefinitionKind {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum CrossReferencesRequest_DeclarationKind {
+    NO_DECLARATIONS = 0,
+    ALL_DECLARATIONS = 1,
+}
+
+impl ::protobuf::ProtobufEnum for CrossReferencesRequest_DeclarationKind {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<CrossReferencesRequest_DeclarationKind> {
+        match value {
+            0 => ::std::option::Option::Some(CrossReferencesRequest_DeclarationKind::NO_DECLARATIONS),
+            1 => ::std::option::Option::Some(CrossReferencesRequest_DeclarationKind::ALL_DECLARATIONS),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [CrossReferencesRequest_DeclarationKind] = &[
+            CrossReferencesRequest_DeclarationKind::NO_DECLARATIONS,
+            CrossReferencesRequest_DeclarationKind::ALL_DECLARATIONS,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<CrossReferencesRequest_DeclarationKind>("CrossReferencesRequest.DeclarationKind", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for CrossReferencesRequest_DeclarationKind {
+}
+
+impl ::std::default::Default for CrossReferencesRequest_DeclarationKind {
+    fn default() -> Self {
+        CrossReferencesRequest_DeclarationKind::NO_DECLARATIONS
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CrossReferencesRequest_DeclarationKind {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum CrossReferencesRequest_ReferenceKind {
+    NO_REFERENCES = 0,
+    CALL_REFERENCES = 1,
+    NON_CALL_REFERENCES = 2,
+    ALL_REFERENCES = 3,
+}
+
+impl ::protobuf::ProtobufEnum for CrossReferencesRequest_ReferenceKind {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<CrossReferencesRequest_ReferenceKind> {
+        match value {
+            0 => ::std::option::Option::Some(CrossReferencesRequest_ReferenceKind::NO_REFERENCES),
+            1 => ::std::option::Option::Some(CrossReferencesRequest_ReferenceKind::CALL_REFERENCES),
+            2 => ::std::option::Option::Some(CrossReferencesRequest_ReferenceKind::NON_CALL_REFERENCES),
+            3 => ::std::option::Option::Some(CrossReferencesRequest_ReferenceKind::ALL_REFERENCES),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [CrossReferencesRequest_ReferenceKind] = &[
+            CrossReferencesRequest_ReferenceKind::NO_REFERENCES,
+            CrossReferencesRequest_ReferenceKind::CALL_REFERENCES,
+            CrossReferencesRequest_ReferenceKind::NON_CALL_REFERENCES,
+            CrossReferencesRequest_ReferenceKind::ALL_REFERENCES,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<CrossReferencesRequest_ReferenceKind>("CrossReferencesRequest.ReferenceKind", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for CrossReferencesRequest_ReferenceKind {
+}
+
+impl ::std::default::Default for CrossReferencesRequest_ReferenceKind {
+    fn default() -> Self {
+        CrossReferencesRequest_ReferenceKind::NO_REFERENCES
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CrossReferencesRequest_ReferenceKind {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum CrossReferencesRequest_CallerKind {
+    NO_CALLERS = 0,
+    DIRECT_CALLERS = 1,
+    OVERRIDE_CALLERS = 2,
+}
+
+impl ::protobuf::ProtobufEnum for CrossReferencesRequest_CallerKind {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<CrossReferencesRequest_CallerKind> {
+        match value {
+            0 => ::std::option::Option::Some(CrossReferencesRequest_CallerKind::NO_CALLERS),
+            1 => ::std::option::Option::Some(CrossReferencesRequest_CallerKind::DIRECT_CALLERS),
+            2 => ::std::option::Option::Some(CrossReferencesRequest_CallerKind::OVERRIDE_CALLERS),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [CrossReferencesRequest_CallerKind] = &[
+            CrossReferencesRequest_CallerKind::NO_CALLERS,
+            CrossReferencesRequest_CallerKind::DIRECT_CALLERS,
+            CrossReferencesRequest_CallerKind::OVERRIDE_CALLERS,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<CrossReferencesRequest_CallerKind>("CrossReferencesRequest.CallerKind", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for CrossReferencesRequest_CallerKind {
+}
+
+impl ::std::default::Default for CrossReferencesRequest_CallerKind {
+    fn default() -> Self {
+        CrossReferencesRequest_CallerKind::NO_CALLERS
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CrossReferencesRequest_CallerKind {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum CrossReferencesRequest_TotalsQuality {
+    UNSPECIFIED_TOTALS = 0,
+    PRECISE_TOTALS = 1,
+    APPROXIMATE_TOTALS = 2,
+}
+
+impl ::protobuf::ProtobufEnum for CrossReferencesRequest_TotalsQuality {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<CrossReferencesRequest_TotalsQuality> {
+        match value {
+            0 => ::std::option::Option::Some(CrossReferencesRequest_TotalsQuality::UNSPECIFIED_TOTALS),
+            1 => ::std::option::Option::Some(CrossReferencesRequest_TotalsQuality::PRECISE_TOTALS),
+            2 => ::std::option::Option::Some(CrossReferencesRequest_TotalsQuality::APPROXIMATE_TOTALS),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [CrossReferencesRequest_TotalsQuality] = &[
+            CrossReferencesRequest_TotalsQuality::UNSPECIFIED_TOTALS,
+            CrossReferencesRequest_TotalsQuality::PRECISE_TOTALS,
+            CrossReferencesRequest_TotalsQuality::APPROXIMATE_TOTALS,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<CrossReferencesRequest_TotalsQuality>("CrossReferencesRequest.TotalsQuality", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for CrossReferencesRequest_TotalsQuality {
+}
+
+impl ::std::default::Default for CrossReferencesRequest_TotalsQuality {
+    fn default() -> Self {
+        CrossReferencesRequest_TotalsQuality::UNSPECIFIED_TOTALS
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CrossReferencesRequest_TotalsQuality {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CorpusPathFilters {
+    // message fields
+    pub filter: ::protobuf::RepeatedField<CorpusPathFilter>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CorpusPathFilters {
+    fn default() -> &'a CorpusPathFilters {
+        <CorpusPathFilters as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CorpusPathFilters {
+    pub fn new() -> CorpusPathFilters {
+        ::std::default::Default::default()
+    }
+
+    // repeated .kythe.proto.CorpusPathFilter filter = 1;
+
+
+    pub fn get_filter(&self) -> &[CorpusPathFilter] {
+        &self.filter
+    }
+    pub fn clear_filter(&mut self) {
+        self.filter.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_filter(&mut self, v: ::protobuf::RepeatedField<CorpusPathFilter>) {
+        self.filter = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_filter(&mut self) -> &mut ::protobuf::RepeatedField<CorpusPathFilter> {
+        &mut self.filter
+    }
+
+    // Take field
+    pub fn take_filter(&mut self) -> ::protobuf::RepeatedField<CorpusPathFilter> {
+        ::std::mem::replace(&mut self.filter, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for CorpusPathFilters {
+    fn is_initialized(&self) -> bool {
+        for v in &self.filter {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.filter)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.filter {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.filter {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CorpusPathFilters {
+        CorpusPathFilters::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CorpusPathFilter>>(
+                "filter",
+                |m: &CorpusPathFilters| { &m.filter },
+                |m: &mut CorpusPathFilters| { &mut m.filter },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CorpusPathFilters>(
+                "CorpusPathFilters",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CorpusPathFilters {
+        static instance: ::protobuf::rt::LazyV2<CorpusPathFilters> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CorpusPathFilters::new)
+    }
+}
+
+impl ::protobuf::Clear for CorpusPathFilters {
+    fn clear(&mut self) {
+        self.filter.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CorpusPathFilters {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CorpusPathFilters {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CorpusPathFilter {
+    // message fields
+    pub field_type: CorpusPathFilter_Type,
+    pub corpus: ::std::string::String,
+    pub corpus_specific_filter: bool,
+    pub root: ::std::string::String,
+    pub path: ::std::string::String,
+    pub resolved_path: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CorpusPathFilter {
+    fn default() -> &'a CorpusPathFilter {
+        <CorpusPathFilter as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CorpusPathFilter {
+    pub fn new() -> CorpusPathFilter {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.CorpusPathFilter.Type type = 1;
+
+
+    pub fn get_field_type(&self) -> CorpusPathFilter_Type {
+        self.field_type
+    }
+    pub fn clear_field_type(&mut self) {
+        self.field_type = CorpusPathFilter_Type::DEFAULT;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: CorpusPathFilter_Type) {
+        self.field_type = v;
+    }
+
+    // string corpus = 2;
+
+
+    pub fn get_corpus(&self) -> &str {
+        &self.corpus
+    }
+    pub fn clear_corpus(&mut self) {
+        self.corpus.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_corpus(&mut self, v: ::std::string::String) {
+        self.corpus = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_corpus(&mut self) -> &mut ::std::string::String {
+        &mut self.corpus
+    }
+
+    // Take field
+    pub fn take_corpus(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.corpus, ::std::string::String::new())
+    }
+
+    // bool corpus_specific_filter = 6;
+
+
+    pub fn get_corpus_specific_filter(&self) -> bool {
+        self.corpus_specific_filter
+    }
+    pub fn clear_corpus_specific_filter(&mut self) {
+        self.corpus_specific_filter = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_corpus_specific_filter(&mut self, v: bool) {
+        self.corpus_specific_filter = v;
+    }
+
+    // string root = 3;
+
+
+    pub fn get_root(&self) -> &str {
+        &self.root
+    }
+    pub fn clear_root(&mut self) {
+        self.root.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_root(&mut self, v: ::std::string::String) {
+        self.root = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_root(&mut self) -> &mut ::std::string::String {
+        &mut self.root
+    }
+
+    // Take field
+    pub fn take_root(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.root, ::std::string::String::new())
+    }
+
+    // string path = 4;
+
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+    pub fn clear_path(&mut self) {
+        self.path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        &mut self.path
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.path, ::std::string::String::new())
+    }
+
+    // string resolved_path = 5;
+
+
+    pub fn get_resolved_path(&self) -> &str {
+        &self.resolved_path
+    }
+    pub fn clear_resolved_path(&mut self) {
+        self.resolved_path.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_resolved_path(&mut self, v: ::std::string::String) {
+        self.resolved_path = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_resolved_path(&mut self) -> &mut ::std::string::String {
+        &mut self.resolved_path
+    }
+
+    // Take field
+    pub fn take_resolved_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.resolved_path, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for CorpusPathFilter {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.field_type, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.corpus)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.corpus_specific_filter = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.root)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.resolved_path)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.field_type != CorpusPathFilter_Type::DEFAULT {
+            my_size += ::protobuf::rt::enum_size(1, self.field_type);
+        }
+        if !self.corpus.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.corpus);
+        }
+        if self.corpus_specific_filter != false {
+            my_size += 2;
+        }
+        if !self.root.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.root);
+        }
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.path);
+        }
+        if !self.resolved_path.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.resolved_path);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.field_type != CorpusPathFilter_Type::DEFAULT {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.field_type))?;
+        }
+        if !self.corpus.is_empty() {
+            os.write_string(2, &self.corpus)?;
+        }
+        if self.corpus_specific_filter != false {
+            os.write_bool(6, self.corpus_specific_filter)?;
+        }
+        if !self.root.is_empty() {
+            os.write_string(3, &self.root)?;
+        }
+        if !self.path.is_empty() {
+            os.write_string(4, &self.path)?;
+        }
+        if !self.resolved_path.is_empty() {
+            os.write_string(5, &self.resolved_path)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CorpusPathFilter {
+        CorpusPathFilter::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<CorpusPathFilter_Type>>(
+                "type",
+                |m: &CorpusPathFilter| { &m.field_type },
+                |m: &mut CorpusPathFilter| { &mut m.field_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "corpus",
+                |m: &CorpusPathFilter| { &m.corpus },
+                |m: &mut CorpusPathFilter| { &mut m.corpus },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "corpus_specific_filter",
+                |m: &CorpusPathFilter| { &m.corpus_specific_filter },
+                |m: &mut CorpusPathFilter| { &mut m.corpus_specific_filter },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "root",
+                |m: &CorpusPathFilter| { &m.root },
+                |m: &mut CorpusPathFilter| { &mut m.root },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "path",
+                |m: &CorpusPathFilter| { &m.path },
+                |m: &mut CorpusPathFilter| { &mut m.path },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "resolved_path",
+                |m: &CorpusPathFilter| { &m.resolved_path },
+                |m: &mut CorpusPathFilter| { &mut m.resolved_path },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CorpusPathFilter>(
+                "CorpusPathFilter",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CorpusPathFilter {
+        static instance: ::protobuf::rt::LazyV2<CorpusPathFilter> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CorpusPathFilter::new)
+    }
+}
+
+impl ::protobuf::Clear for CorpusPathFilter {
+    fn clear(&mut self) {
+        self.field_type = CorpusPathFilter_Type::DEFAULT;
+        self.corpus.clear();
+        self.corpus_specific_filter = false;
+        self.root.clear();
+        self.path.clear();
+        self.resolved_path.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CorpusPathFilter {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CorpusPathFilter {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum CorpusPathFilter_Type {
+    DEFAULT = 0,
+    INCLUDE_ONLY = 1,
+    EXCLUDE = 2,
+}
+
+impl ::protobuf::ProtobufEnum for CorpusPathFilter_Type {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<CorpusPathFilter_Type> {
+        match value {
+            0 => ::std::option::Option::Some(CorpusPathFilter_Type::DEFAULT),
+            1 => ::std::option::Option::Some(CorpusPathFilter_Type::INCLUDE_ONLY),
+            2 => ::std::option::Option::Some(CorpusPathFilter_Type::EXCLUDE),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [CorpusPathFilter_Type] = &[
+            CorpusPathFilter_Type::DEFAULT,
+            CorpusPathFilter_Type::INCLUDE_ONLY,
+            CorpusPathFilter_Type::EXCLUDE,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<CorpusPathFilter_Type>("CorpusPathFilter.Type", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for CorpusPathFilter_Type {
+}
+
+impl ::std::default::Default for CorpusPathFilter_Type {
+    fn default() -> Self {
+        CorpusPathFilter_Type::DEFAULT
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CorpusPathFilter_Type {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Anchor {
+    // message fields
+    pub ticket: ::std::string::String,
+    pub kind: ::std::string::String,
+    pub parent: ::std::string::String,
+    pub span: ::protobuf::SingularPtrField<super::common::Span>,
+    pub text: ::std::string::String,
+    pub snippet: ::std::string::String,
+    pub snippet_span: ::protobuf::SingularPtrField<super::common::Span>,
+    pub build_config: ::std::string::String,
+    pub revision: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Anchor {
+    fn default() -> &'a Anchor {
+        <Anchor as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Anchor {
+    pub fn new() -> Anchor {
+        ::std::default::Default::default()
+    }
+
+    // string ticket = 1;
+
+
+    pub fn get_ticket(&self) -> &str {
+        &self.ticket
+    }
+    pub fn clear_ticket(&mut self) {
+        self.ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ticket(&mut self, v: ::std::string::String) {
+        self.ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ticket(&mut self) -> &mut ::std::string::String {
+        &mut self.ticket
+    }
+
+    // Take field
+    pub fn take_ticket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.ticket, ::std::string::String::new())
+    }
+
+    // string kind = 2;
+
+
+    pub fn get_kind(&self) -> &str {
+        &self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: ::std::string::String) {
+        self.kind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_kind(&mut self) -> &mut ::std::string::String {
+        &mut self.kind
+    }
+
+    // Take field
+    pub fn take_kind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.kind, ::std::string::String::new())
+    }
+
+    // string parent = 3;
+
+
+    pub fn get_parent(&self) -> &str {
+        &self.parent
+    }
+    pub fn clear_parent(&mut self) {
+        self.parent.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_parent(&mut self, v: ::std::string::String) {
+        self.parent = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_parent(&mut self) -> &mut ::std::string::String {
+        &mut self.parent
+    }
+
+    // Take field
+    pub fn take_parent(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.parent, ::std::string::String::new())
+    }
+
+    // .kythe.proto.common.Span span = 10;
+
+
+    pub fn get_span(&self) -> &super::common::Span {
+        self.span.as_ref().unwrap_or_else(|| <super::common::Span as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_span(&mut self) {
+        self.span.clear();
+    }
+
+    pub fn has_span(&self) -> bool {
+        self.span.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_span(&mut self, v: super::common::Span) {
+        self.span = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_span(&mut self) -> &mut super::common::Span {
+        if self.span.is_none() {
+            self.span.set_default();
+        }
+        self.span.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_span(&mut self) -> super::common::Span {
+        self.span.take().unwrap_or_else(|| super::common::Span::new())
+    }
+
+    // string text = 6;
+
+
+    pub fn get_text(&self) -> &str {
+        &self.text
+    }
+    pub fn clear_text(&mut self) {
+        self.text.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_text(&mut self, v: ::std::string::String) {
+        self.text = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_text(&mut self) -> &mut ::std::string::String {
+        &mut self.text
+    }
+
+    // Take field
+    pub fn take_text(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.text, ::std::string::String::new())
+    }
+
+    // string snippet = 7;
+
+
+    pub fn get_snippet(&self) -> &str {
+        &self.snippet
+    }
+    pub fn clear_snippet(&mut self) {
+        self.snippet.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_snippet(&mut self, v: ::std::string::String) {
+        self.snippet = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_snippet(&mut self) -> &mut ::std::string::String {
+        &mut self.snippet
+    }
+
+    // Take field
+    pub fn take_snippet(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.snippet, ::std::string::String::new())
+    }
+
+    // .kythe.proto.common.Span snippet_span = 11;
+
+
+    pub fn get_snippet_span(&self) -> &super::common::Span {
+        self.snippet_span.as_ref().unwrap_or_else(|| <super::common::Span as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_snippet_span(&mut self) {
+        self.snippet_span.clear();
+    }
+
+    pub fn has_snippet_span(&self) -> bool {
+        self.snippet_span.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_snippet_span(&mut self, v: super::common::Span) {
+        self.snippet_span = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_snippet_span(&mut self) -> &mut super::common::Span {
+        if self.snippet_span.is_none() {
+            self.snippet_span.set_default();
+        }
+        self.snippet_span.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_snippet_span(&mut self) -> super::common::Span {
+        self.snippet_span.take().unwrap_or_else(|| super::common::Span::new())
+    }
+
+    // string build_config = 12;
+
+
+    pub fn get_build_config(&self) -> &str {
+        &self.build_config
+    }
+    pub fn clear_build_config(&mut self) {
+        self.build_config.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_build_config(&mut self, v: ::std::string::String) {
+        self.build_config = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_build_config(&mut self) -> &mut ::std::string::String {
+        &mut self.build_config
+    }
+
+    // Take field
+    pub fn take_build_config(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.build_config, ::std::string::String::new())
+    }
+
+    // string revision = 14;
+
+
+    pub fn get_revision(&self) -> &str {
+        &self.revision
+    }
+    pub fn clear_revision(&mut self) {
+        self.revision.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_revision(&mut self, v: ::std::string::String) {
+        self.revision = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_revision(&mut self) -> &mut ::std::string::String {
+        &mut self.revision
+    }
+
+    // Take field
+    pub fn take_revision(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.revision, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Anchor {
+    fn is_initialized(&self) -> bool {
+        for v in &self.span {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.snippet_span {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.ticket)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.kind)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.parent)?;
+                },
+                10 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.span)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.text)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.snippet)?;
+                },
+                11 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.snippet_span)?;
+                },
+                12 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.build_config)?;
+                },
+                14 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.revision)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.ticket.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.ticket);
+        }
+        if !self.kind.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.kind);
+        }
+        if !self.parent.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.parent);
+        }
+        if let Some(ref v) = self.span.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.text.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.text);
+        }
+        if !self.snippet.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.snippet);
+        }
+        if let Some(ref v) = self.snippet_span.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.build_config.is_empty() {
+            my_size += ::protobuf::rt::string_size(12, &self.build_config);
+        }
+        if !self.revision.is_empty() {
+            my_size += ::protobuf::rt::string_size(14, &self.revision);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.ticket.is_empty() {
+            os.write_string(1, &self.ticket)?;
+        }
+        if !self.kind.is_empty() {
+            os.write_string(2, &self.kind)?;
+        }
+        if !self.parent.is_empty() {
+            os.write_string(3, &self.parent)?;
+        }
+        if let Some(ref v) = self.span.as_ref() {
+            os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.text.is_empty() {
+            os.write_string(6, &self.text)?;
+        }
+        if !self.snippet.is_empty() {
+            os.write_string(7, &self.snippet)?;
+        }
+        if let Some(ref v) = self.snippet_span.as_ref() {
+            os.write_tag(11, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.build_config.is_empty() {
+            os.write_string(12, &self.build_config)?;
+        }
+        if !self.revision.is_empty() {
+            os.write_string(14, &self.revision)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Anchor {
+        Anchor::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "ticket",
+                |m: &Anchor| { &m.ticket },
+                |m: &mut Anchor| { &mut m.ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "kind",
+                |m: &Anchor| { &m.kind },
+                |m: &mut Anchor| { &mut m.kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "parent",
+                |m: &Anchor| { &m.parent },
+                |m: &mut Anchor| { &mut m.parent },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::Span>>(
+                "span",
+                |m: &Anchor| { &m.span },
+                |m: &mut Anchor| { &mut m.span },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "text",
+                |m: &Anchor| { &m.text },
+                |m: &mut Anchor| { &mut m.text },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "snippet",
+                |m: &Anchor| { &m.snippet },
+                |m: &mut Anchor| { &mut m.snippet },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::Span>>(
+                "snippet_span",
+                |m: &Anchor| { &m.snippet_span },
+                |m: &mut Anchor| { &mut m.snippet_span },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "build_config",
+                |m: &Anchor| { &m.build_config },
+                |m: &mut Anchor| { &mut m.build_config },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "revision",
+                |m: &Anchor| { &m.revision },
+                |m: &mut Anchor| { &mut m.revision },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Anchor>(
+                "Anchor",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Anchor {
+        static instance: ::protobuf::rt::LazyV2<Anchor> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Anchor::new)
+    }
+}
+
+impl ::protobuf::Clear for Anchor {
+    fn clear(&mut self) {
+        self.ticket.clear();
+        self.kind.clear();
+        self.parent.clear();
+        self.span.clear();
+        self.text.clear();
+        self.snippet.clear();
+        self.snippet_span.clear();
+        self.build_config.clear();
+        self.revision.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Anchor {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Anchor {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Printable {
+    // message fields
+    pub raw_text: ::std::string::String,
+    pub link: ::protobuf::RepeatedField<super::common::Link>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Printable {
+    fn default() -> &'a Printable {
+        <Printable as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Printable {
+    pub fn new() -> Printable {
+        ::std::default::Default::default()
+    }
+
+    // string raw_text = 1;
+
+
+    pub fn get_raw_text(&self) -> &str {
+        &self.raw_text
+    }
+    pub fn clear_raw_text(&mut self) {
+        self.raw_text.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_raw_text(&mut self, v: ::std::string::String) {
+        self.raw_text = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_raw_text(&mut self) -> &mut ::std::string::String {
+        &mut self.raw_text
+    }
+
+    // Take field
+    pub fn take_raw_text(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.raw_text, ::std::string::String::new())
+    }
+
+    // repeated .kythe.proto.common.Link link = 2;
+
+
+    pub fn get_link(&self) -> &[super::common::Link] {
+        &self.link
+    }
+    pub fn clear_link(&mut self) {
+        self.link.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_link(&mut self, v: ::protobuf::RepeatedField<super::common::Link>) {
+        self.link = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_link(&mut self) -> &mut ::protobuf::RepeatedField<super::common::Link> {
+        &mut self.link
+    }
+
+    // Take field
+    pub fn take_link(&mut self) -> ::protobuf::RepeatedField<super::common::Link> {
+        ::std::mem::replace(&mut self.link, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Printable {
+    fn is_initialized(&self) -> bool {
+        for v in &self.link {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.raw_text)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.link)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.raw_text.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.raw_text);
+        }
+        for value in &self.link {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.raw_text.is_empty() {
+            os.write_string(1, &self.raw_text)?;
+        }
+        for v in &self.link {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Printable {
+        Printable::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "raw_text",
+                |m: &Printable| { &m.raw_text },
+                |m: &mut Printable| { &mut m.raw_text },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::Link>>(
+                "link",
+                |m: &Printable| { &m.link },
+                |m: &mut Printable| { &mut m.link },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Printable>(
+                "Printable",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Printable {
+        static instance: ::protobuf::rt::LazyV2<Printable> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Printable::new)
+    }
+}
+
+impl ::protobuf::Clear for Printable {
+    fn clear(&mut self) {
+        self.raw_text.clear();
+        self.link.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Printable {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Printable {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CrossReferencesReply {
+    // message fields
+    pub total: ::protobuf::SingularPtrField<CrossReferencesReply_Total>,
+    pub filtered: ::protobuf::SingularPtrField<CrossReferencesReply_Total>,
+    pub cross_references: ::std::collections::HashMap<::std::string::String, CrossReferencesReply_CrossReferenceSet>,
+    pub nodes: ::std::collections::HashMap<::std::string::String, super::common::NodeInfo>,
+    pub definition_locations: ::std::collections::HashMap<::std::string::String, Anchor>,
+    pub next_page_token: ::std::string::String,
+    pub build_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CrossReferencesReply {
+    fn default() -> &'a CrossReferencesReply {
+        <CrossReferencesReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CrossReferencesReply {
+    pub fn new() -> CrossReferencesReply {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.CrossReferencesReply.Total total = 5;
+
+
+    pub fn get_total(&self) -> &CrossReferencesReply_Total {
+        self.total.as_ref().unwrap_or_else(|| <CrossReferencesReply_Total as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_total(&mut self) {
+        self.total.clear();
+    }
+
+    pub fn has_total(&self) -> bool {
+        self.total.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_total(&mut self, v: CrossReferencesReply_Total) {
+        self.total = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_total(&mut self) -> &mut CrossReferencesReply_Total {
+        if self.total.is_none() {
+            self.total.set_default();
+        }
+        self.total.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_total(&mut self) -> CrossReferencesReply_Total {
+        self.total.take().unwrap_or_else(|| CrossReferencesReply_Total::new())
+    }
+
+    // .kythe.proto.CrossReferencesReply.Total filtered = 12;
+
+
+    pub fn get_filtered(&self) -> &CrossReferencesReply_Total {
+        self.filtered.as_ref().unwrap_or_else(|| <CrossReferencesReply_Total as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_filtered(&mut self) {
+        self.filtered.clear();
+    }
+
+    pub fn has_filtered(&self) -> bool {
+        self.filtered.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_filtered(&mut self, v: CrossReferencesReply_Total) {
+        self.filtered = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_filtered(&mut self) -> &mut CrossReferencesReply_Total {
+        if self.filtered.is_none() {
+            self.filtered.set_default();
+        }
+        self.filtered.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_filtered(&mut self) -> CrossReferencesReply_Total {
+        self.filtered.take().unwrap_or_else(|| CrossReferencesReply_Total::new())
+    }
+
+    // repeated .kythe.proto.CrossReferencesReply.CrossReferencesEntry cross_references = 1;
+
+
+    pub fn get_cross_references(&self) -> &::std::collections::HashMap<::std::string::String, CrossReferencesReply_CrossReferenceSet> {
+        &self.cross_references
+    }
+    pub fn clear_cross_references(&mut self) {
+        self.cross_references.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cross_references(&mut self, v: ::std::collections::HashMap<::std::string::String, CrossReferencesReply_CrossReferenceSet>) {
+        self.cross_references = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cross_references(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, CrossReferencesReply_CrossReferenceSet> {
+        &mut self.cross_references
+    }
+
+    // Take field
+    pub fn take_cross_references(&mut self) -> ::std::collections::HashMap<::std::string::String, CrossReferencesReply_CrossReferenceSet> {
+        ::std::mem::replace(&mut self.cross_references, ::std::collections::HashMap::new())
+    }
+
+    // repeated .kythe.proto.CrossReferencesReply.NodesEntry nodes = 2;
+
+
+    pub fn get_nodes(&self) -> &::std::collections::HashMap<::std::string::String, super::common::NodeInfo> {
+        &self.nodes
+    }
+    pub fn clear_nodes(&mut self) {
+        self.nodes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nodes(&mut self, v: ::std::collections::HashMap<::std::string::String, super::common::NodeInfo>) {
+        self.nodes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_nodes(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, super::common::NodeInfo> {
+        &mut self.nodes
+    }
+
+    // Take field
+    pub fn take_nodes(&mut self) -> ::std::collections::HashMap<::std::string::String, super::common::NodeInfo> {
+        ::std::mem::replace(&mut self.nodes, ::std::collections::HashMap::new())
+    }
+
+    // repeated .kythe.proto.CrossReferencesReply.DefinitionLocationsEntry definition_locations = 3;
+
+
+    pub fn get_definition_locations(&self) -> &::std::collections::HashMap<::std::string::String, Anchor> {
+        &self.definition_locations
+    }
+    pub fn clear_definition_locations(&mut self) {
+        self.definition_locations.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_definition_locations(&mut self, v: ::std::collections::HashMap<::std::string::String, Anchor>) {
+        self.definition_locations = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_definition_locations(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, Anchor> {
+        &mut self.definition_locations
+    }
+
+    // Take field
+    pub fn take_definition_locations(&mut self) -> ::std::collections::HashMap<::std::string::String, Anchor> {
+        ::std::mem::replace(&mut self.definition_locations, ::std::collections::HashMap::new())
+    }
+
+    // string next_page_token = 10;
+
+
+    pub fn get_next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    pub fn clear_next_page_token(&mut self) {
+        self.next_page_token.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_next_page_token(&mut self, v: ::std::string::String) {
+        self.next_page_token = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_next_page_token(&mut self) -> &mut ::std::string::String {
+        &mut self.next_page_token
+    }
+
+    // Take field
+    pub fn take_next_page_token(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.next_page_token, ::std::string::String::new())
+    }
+
+    // string build_id = 11;
+
+
+    pub fn get_build_id(&self) -> &str {
+        &self.build_id
+    }
+    pub fn clear_build_id(&mut self) {
+        self.build_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_build_id(&mut self, v: ::std::string::String) {
+        self.build_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_build_id(&mut self) -> &mut ::std::string::String {
+        &mut self.build_id
+    }
+
+    // Take field
+    pub fn take_build_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.build_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for CrossReferencesReply {
+    fn is_initialized(&self) -> bool {
+        for v in &self.total {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.filtered {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.total)?;
+                },
+                12 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.filtered)?;
+                },
+                1 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<CrossReferencesReply_CrossReferenceSet>>(wire_type, is, &mut self.cross_references)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<super::common::NodeInfo>>(wire_type, is, &mut self.nodes)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Anchor>>(wire_type, is, &mut self.definition_locations)?;
+                },
+                10 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.next_page_token)?;
+                },
+                11 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.build_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.total.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.filtered.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<CrossReferencesReply_CrossReferenceSet>>(1, &self.cross_references);
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<super::common::NodeInfo>>(2, &self.nodes);
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Anchor>>(3, &self.definition_locations);
+        if !self.next_page_token.is_empty() {
+            my_size += ::protobuf::rt::string_size(10, &self.next_page_token);
+        }
+        if !self.build_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(11, &self.build_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.total.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.filtered.as_ref() {
+            os.write_tag(12, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<CrossReferencesReply_CrossReferenceSet>>(1, &self.cross_references, os)?;
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<super::common::NodeInfo>>(2, &self.nodes, os)?;
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Anchor>>(3, &self.definition_locations, os)?;
+        if !self.next_page_token.is_empty() {
+            os.write_string(10, &self.next_page_token)?;
+        }
+        if !self.build_id.is_empty() {
+            os.write_string(11, &self.build_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CrossReferencesReply {
+        CrossReferencesReply::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CrossReferencesReply_Total>>(
+                "total",
+                |m: &CrossReferencesReply| { &m.total },
+                |m: &mut CrossReferencesReply| { &mut m.total },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CrossReferencesReply_Total>>(
+                "filtered",
+                |m: &CrossReferencesReply| { &m.filtered },
+                |m: &mut CrossReferencesReply| { &mut m.filtered },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<CrossReferencesReply_CrossReferenceSet>>(
+                "cross_references",
+                |m: &CrossReferencesReply| { &m.cross_references },
+                |m: &mut CrossReferencesReply| { &mut m.cross_references },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<super::common::NodeInfo>>(
+                "nodes",
+                |m: &CrossReferencesReply| { &m.nodes },
+                |m: &mut CrossReferencesReply| { &mut m.nodes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Anchor>>(
+                "definition_locations",
+                |m: &CrossReferencesReply| { &m.definition_locations },
+                |m: &mut CrossReferencesReply| { &mut m.definition_locations },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "next_page_token",
+                |m: &CrossReferencesReply| { &m.next_page_token },
+                |m: &mut CrossReferencesReply| { &mut m.next_page_token },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "build_id",
+                |m: &CrossReferencesReply| { &m.build_id },
+                |m: &mut CrossReferencesReply| { &mut m.build_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CrossReferencesReply>(
+                "CrossReferencesReply",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CrossReferencesReply {
+        static instance: ::protobuf::rt::LazyV2<CrossReferencesReply> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CrossReferencesReply::new)
+    }
+}
+
+impl ::protobuf::Clear for CrossReferencesReply {
+    fn clear(&mut self) {
+        self.total.clear();
+        self.filtered.clear();
+        self.cross_references.clear();
+        self.nodes.clear();
+        self.definition_locations.clear();
+        self.next_page_token.clear();
+        self.build_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CrossReferencesReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CrossReferencesReply {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CrossReferencesReply_RelatedNode {
+    // message fields
+    pub ticket: ::std::string::String,
+    pub relation_kind: ::std::string::String,
+    pub ordinal: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CrossReferencesReply_RelatedNode {
+    fn default() -> &'a CrossReferencesReply_RelatedNode {
+        <CrossReferencesReply_RelatedNode as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CrossReferencesReply_RelatedNode {
+    pub fn new() -> CrossReferencesReply_RelatedNode {
+        ::std::default::Default::default()
+    }
+
+    // string ticket = 1;
+
+
+    pub fn get_ticket(&self) -> &str {
+        &self.ticket
+    }
+    pub fn clear_ticket(&mut self) {
+        self.ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ticket(&mut self, v: ::std::string::String) {
+        self.ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ticket(&mut self) -> &mut ::std::string::String {
+        &mut self.ticket
+    }
+
+    // Take field
+    pub fn take_ticket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.ticket, ::std::string::String::new())
+    }
+
+    // string relation_kind = 2;
+
+
+    pub fn get_relation_kind(&self) -> &str {
+        &self.relation_kind
+    }
+    pub fn clear_relation_kind(&mut self) {
+        self.relation_kind.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_relation_kind(&mut self, v: ::std::string::String) {
+        self.relation_kind = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_relation_kind(&mut self) -> &mut ::std::string::String {
+        &mut self.relation_kind
+    }
+
+    // Take field
+    pub fn take_relation_kind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.relation_kind, ::std::string::String::new())
+    }
+
+    // int32 ordinal = 3;
+
+
+    pub fn get_ordinal(&self) -> i32 {
+        self.ordinal
+    }
+    pub fn clear_ordinal(&mut self) {
+        self.ordinal = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ordinal(&mut self, v: i32) {
+        self.ordinal = v;
+    }
+}
+
+impl ::protobuf::Message for CrossReferencesReply_RelatedNode {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.ticket)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.relation_kind)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.ordinal = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.ticket.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.ticket);
+        }
+        if !self.relation_kind.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.relation_kind);
+        }
+        if self.ordinal != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.ordinal, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.ticket.is_empty() {
+            os.write_string(1, &self.ticket)?;
+        }
+        if !self.relation_kind.is_empty() {
+            os.write_string(2, &self.relation_kind)?;
+        }
+        if self.ordinal != 0 {
+            os.write_int32(3, self.ordinal)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CrossReferencesReply_RelatedNode {
+        CrossReferencesReply_RelatedNode::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "ticket",
+                |m: &CrossReferencesReply_RelatedNode| { &m.ticket },
+                |m: &mut CrossReferencesReply_RelatedNode| { &mut m.ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "relation_kind",
+                |m: &CrossReferencesReply_RelatedNode| { &m.relation_kind },
+                |m: &mut CrossReferencesReply_RelatedNode| { &mut m.relation_kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "ordinal",
+                |m: &CrossReferencesReply_RelatedNode| { &m.ordinal },
+                |m: &mut CrossReferencesReply_RelatedNode| { &mut m.ordinal },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CrossReferencesReply_RelatedNode>(
+                "CrossReferencesReply.RelatedNode",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CrossReferencesReply_RelatedNode {
+        static instance: ::protobuf::rt::LazyV2<CrossReferencesReply_RelatedNode> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CrossReferencesReply_RelatedNode::new)
+    }
+}
+
+impl ::protobuf::Clear for CrossReferencesReply_RelatedNode {
+    fn clear(&mut self) {
+        self.ticket.clear();
+        self.relation_kind.clear();
+        self.ordinal = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CrossReferencesReply_RelatedNode {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CrossReferencesReply_RelatedNode {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CrossReferencesReply_RelatedAnchor {
+    // message fields
+    pub anchor: ::protobuf::SingularPtrField<Anchor>,
+    pub marked_source: ::protobuf::SingularPtrField<super::common::MarkedSource>,
+    pub site: ::protobuf::RepeatedField<Anchor>,
+    pub ticket: ::std::string::String,
+    pub speculative: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CrossReferencesReply_RelatedAnchor {
+    fn default() -> &'a CrossReferencesReply_RelatedAnchor {
+        <CrossReferencesReply_RelatedAnchor as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CrossReferencesReply_RelatedAnchor {
+    pub fn new() -> CrossReferencesReply_RelatedAnchor {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.Anchor anchor = 1;
+
+
+    pub fn get_anchor(&self) -> &Anchor {
+        self.anchor.as_ref().unwrap_or_else(|| <Anchor as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_anchor(&mut self) {
+        self.anchor.clear();
+    }
+
+    pub fn has_anchor(&self) -> bool {
+        self.anchor.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_anchor(&mut self, v: Anchor) {
+        self.anchor = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_anchor(&mut self) -> &mut Anchor {
+        if self.anchor.is_none() {
+            self.anchor.set_default();
+        }
+        self.anchor.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_anchor(&mut self) -> Anchor {
+        self.anchor.take().unwrap_or_else(|| Anchor::new())
+    }
+
+    // .kythe.proto.common.MarkedSource marked_source = 5;
+
+
+    pub fn get_marked_source(&self) -> &super::common::MarkedSource {
+        self.marked_source.as_ref().unwrap_or_else(|| <super::common::MarkedSource as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_marked_source(&mut self) {
+        self.marked_source.clear();
+    }
+
+    pub fn has_marked_source(&self) -> bool {
+        self.marked_source.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_marked_source(&mut self, v: super::common::MarkedSource) {
+        self.marked_source = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_marked_source(&mut self) -> &mut super::common::MarkedSource {
+        if self.marked_source.is_none() {
+            self.marked_source.set_default();
+        }
+        self.marked_source.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_marked_source(&mut self) -> super::common::MarkedSource {
+        self.marked_source.take().unwrap_or_else(|| super::common::MarkedSource::new())
+    }
+
+    // repeated .kythe.proto.Anchor site = 3;
+
+
+    pub fn get_site(&self) -> &[Anchor] {
+        &self.site
+    }
+    pub fn clear_site(&mut self) {
+        self.site.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_site(&mut self, v: ::protobuf::RepeatedField<Anchor>) {
+        self.site = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_site(&mut self) -> &mut ::protobuf::RepeatedField<Anchor> {
+        &mut self.site
+    }
+
+    // Take field
+    pub fn take_site(&mut self) -> ::protobuf::RepeatedField<Anchor> {
+        ::std::mem::replace(&mut self.site, ::protobuf::RepeatedField::new())
+    }
+
+    // string ticket = 4;
+
+
+    pub fn get_ticket(&self) -> &str {
+        &self.ticket
+    }
+    pub fn clear_ticket(&mut self) {
+        self.ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ticket(&mut self, v: ::std::string::String) {
+        self.ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ticket(&mut self) -> &mut ::std::string::String {
+        &mut self.ticket
+    }
+
+    // Take field
+    pub fn take_ticket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.ticket, ::std::string::String::new())
+    }
+
+    // bool speculative = 6;
+
+
+    pub fn get_speculative(&self) -> bool {
+        self.speculative
+    }
+    pub fn clear_speculative(&mut self) {
+        self.speculative = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_speculative(&mut self, v: bool) {
+        self.speculative = v;
+    }
+}
+
+impl ::protobuf::Message for CrossReferencesReply_RelatedAnchor {
+    fn is_initialized(&self) -> bool {
+        for v in &self.anchor {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.marked_source {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.site {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.anchor)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.marked_source)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.site)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.ticket)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.speculative = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.anchor.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.marked_source.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.site {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.ticket.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.ticket);
+        }
+        if self.speculative != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.anchor.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.marked_source.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.site {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if !self.ticket.is_empty() {
+            os.write_string(4, &self.ticket)?;
+        }
+        if self.speculative != false {
+            os.write_bool(6, self.speculative)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CrossReferencesReply_RelatedAnchor {
+        CrossReferencesReply_RelatedAnchor::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Anchor>>(
+                "anchor",
+                |m: &CrossReferencesReply_RelatedAnchor| { &m.anchor },
+                |m: &mut CrossReferencesReply_RelatedAnchor| { &mut m.anchor },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::MarkedSource>>(
+                "marked_source",
+                |m: &CrossReferencesReply_RelatedAnchor| { &m.marked_source },
+                |m: &mut CrossReferencesReply_RelatedAnchor| { &mut m.marked_source },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Anchor>>(
+                "site",
+                |m: &CrossReferencesReply_RelatedAnchor| { &m.site },
+                |m: &mut CrossReferencesReply_RelatedAnchor| { &mut m.site },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "ticket",
+                |m: &CrossReferencesReply_RelatedAnchor| { &m.ticket },
+                |m: &mut CrossReferencesReply_RelatedAnchor| { &mut m.ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "speculative",
+                |m: &CrossReferencesReply_RelatedAnchor| { &m.speculative },
+                |m: &mut CrossReferencesReply_RelatedAnchor| { &mut m.speculative },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CrossReferencesReply_RelatedAnchor>(
+                "CrossReferencesReply.RelatedAnchor",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CrossReferencesReply_RelatedAnchor {
+        static instance: ::protobuf::rt::LazyV2<CrossReferencesReply_RelatedAnchor> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CrossReferencesReply_RelatedAnchor::new)
+    }
+}
+
+impl ::protobuf::Clear for CrossReferencesReply_RelatedAnchor {
+    fn clear(&mut self) {
+        self.anchor.clear();
+        self.marked_source.clear();
+        self.site.clear();
+        self.ticket.clear();
+        self.speculative = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CrossReferencesReply_RelatedAnchor {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CrossReferencesReply_RelatedAnchor {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CrossReferencesReply_CrossReferenceSet {
+    // message fields
+    pub ticket: ::std::string::String,
+    pub marked_source: ::protobuf::SingularPtrField<super::common::MarkedSource>,
+    pub definition: ::protobuf::RepeatedField<CrossReferencesReply_RelatedAnchor>,
+    pub declaration: ::protobuf::RepeatedField<CrossReferencesReply_RelatedAnchor>,
+    pub reference: ::protobuf::RepeatedField<CrossReferencesReply_RelatedAnchor>,
+    pub caller: ::protobuf::RepeatedField<CrossReferencesReply_RelatedAnchor>,
+    pub related_node: ::protobuf::RepeatedField<CrossReferencesReply_RelatedNode>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CrossReferencesReply_CrossReferenceSet {
+    fn default() -> &'a CrossReferencesReply_CrossReferenceSet {
+        <CrossReferencesReply_CrossReferenceSet as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CrossReferencesReply_CrossReferenceSet {
+    pub fn new() -> CrossReferencesReply_CrossReferenceSet {
+        ::std::default::Default::default()
+    }
+
+    // string ticket = 1;
+
+
+    pub fn get_ticket(&self) -> &str {
+        &self.ticket
+    }
+    pub fn clear_ticket(&mut self) {
+        self.ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ticket(&mut self, v: ::std::string::String) {
+        self.ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ticket(&mut self) -> &mut ::std::string::String {
+        &mut self.ticket
+    }
+
+    // Take field
+    pub fn take_ticket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.ticket, ::std::string::String::new())
+    }
+
+    // .kythe.proto.common.MarkedSource marked_source = 8;
+
+
+    pub fn get_marked_source(&self) -> &super::common::MarkedSource {
+        self.marked_source.as_ref().unwrap_or_else(|| <super::common::MarkedSource as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_marked_source(&mut self) {
+        self.marked_source.clear();
+    }
+
+    pub fn has_marked_source(&self) -> bool {
+        self.marked_source.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_marked_source(&mut self, v: super::common::MarkedSource) {
+        self.marked_source = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_marked_source(&mut self) -> &mut super::common::MarkedSource {
+        if self.marked_source.is_none() {
+            self.marked_source.set_default();
+        }
+        self.marked_source.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_marked_source(&mut self) -> super::common::MarkedSource {
+        self.marked_source.take().unwrap_or_else(|| super::common::MarkedSource::new())
+    }
+
+    // repeated .kythe.proto.CrossReferencesReply.RelatedAnchor definition = 2;
+
+
+    pub fn get_definition(&self) -> &[CrossReferencesReply_RelatedAnchor] {
+        &self.definition
+    }
+    pub fn clear_definition(&mut self) {
+        self.definition.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_definition(&mut self, v: ::protobuf::RepeatedField<CrossReferencesReply_RelatedAnchor>) {
+        self.definition = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_definition(&mut self) -> &mut ::protobuf::RepeatedField<CrossReferencesReply_RelatedAnchor> {
+        &mut self.definition
+    }
+
+    // Take field
+    pub fn take_definition(&mut self) -> ::protobuf::RepeatedField<CrossReferencesReply_RelatedAnchor> {
+        ::std::mem::replace(&mut self.definition, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .kythe.proto.CrossReferencesReply.RelatedAnchor declaration = 5;
+
+
+    pub fn get_declaration(&self) -> &[CrossReferencesReply_RelatedAnchor] {
+        &self.declaration
+    }
+    pub fn clear_declaration(&mut self) {
+        self.declaration.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_declaration(&mut self, v: ::protobuf::RepeatedField<CrossReferencesReply_RelatedAnchor>) {
+        self.declaration = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_declaration(&mut self) -> &mut ::protobuf::RepeatedField<CrossReferencesReply_RelatedAnchor> {
+        &mut self.declaration
+    }
+
+    // Take field
+    pub fn take_declaration(&mut self) -> ::protobuf::RepeatedField<CrossReferencesReply_RelatedAnchor> {
+        ::std::mem::replace(&mut self.declaration, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .kythe.proto.CrossReferencesReply.RelatedAnchor reference = 3;
+
+
+    pub fn get_reference(&self) -> &[CrossReferencesReply_RelatedAnchor] {
+        &self.reference
+    }
+    pub fn clear_reference(&mut self) {
+        self.reference.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reference(&mut self, v: ::protobuf::RepeatedField<CrossReferencesReply_RelatedAnchor>) {
+        self.reference = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_reference(&mut self) -> &mut ::protobuf::RepeatedField<CrossReferencesReply_RelatedAnchor> {
+        &mut self.reference
+    }
+
+    // Take field
+    pub fn take_reference(&mut self) -> ::protobuf::RepeatedField<CrossReferencesReply_RelatedAnchor> {
+        ::std::mem::replace(&mut self.reference, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .kythe.proto.CrossReferencesReply.RelatedAnchor caller = 6;
+
+
+    pub fn get_caller(&self) -> &[CrossReferencesReply_RelatedAnchor] {
+        &self.caller
+    }
+    pub fn clear_caller(&mut self) {
+        self.caller.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_caller(&mut self, v: ::protobuf::RepeatedField<CrossReferencesReply_RelatedAnchor>) {
+        self.caller = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_caller(&mut self) -> &mut ::protobuf::RepeatedField<CrossReferencesReply_RelatedAnchor> {
+        &mut self.caller
+    }
+
+    // Take field
+    pub fn take_caller(&mut self) -> ::protobuf::RepeatedField<CrossReferencesReply_RelatedAnchor> {
+        ::std::mem::replace(&mut self.caller, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .kythe.proto.CrossReferencesReply.RelatedNode related_node = 10;
+
+
+    pub fn get_related_node(&self) -> &[CrossReferencesReply_RelatedNode] {
+        &self.related_node
+    }
+    pub fn clear_related_node(&mut self) {
+        self.related_node.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_related_node(&mut self, v: ::protobuf::RepeatedField<CrossReferencesReply_RelatedNode>) {
+        self.related_node = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_related_node(&mut self) -> &mut ::protobuf::RepeatedField<CrossReferencesReply_RelatedNode> {
+        &mut self.related_node
+    }
+
+    // Take field
+    pub fn take_related_node(&mut self) -> ::protobuf::RepeatedField<CrossReferencesReply_RelatedNode> {
+        ::std::mem::replace(&mut self.related_node, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for CrossReferencesReply_CrossReferenceSet {
+    fn is_initialized(&self) -> bool {
+        for v in &self.marked_source {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.definition {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.declaration {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.reference {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.caller {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.related_node {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.ticket)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.marked_source)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.definition)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.declaration)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.reference)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.caller)?;
+                },
+                10 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.related_node)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.ticket.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.ticket);
+        }
+        if let Some(ref v) = self.marked_source.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.definition {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.declaration {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.reference {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.caller {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.related_node {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.ticket.is_empty() {
+            os.write_string(1, &self.ticket)?;
+        }
+        if let Some(ref v) = self.marked_source.as_ref() {
+            os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.definition {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.declaration {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.reference {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.caller {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.related_node {
+            os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CrossReferencesReply_CrossReferenceSet {
+        CrossReferencesReply_CrossReferenceSet::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "ticket",
+                |m: &CrossReferencesReply_CrossReferenceSet| { &m.ticket },
+                |m: &mut CrossReferencesReply_CrossReferenceSet| { &mut m.ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::MarkedSource>>(
+                "marked_source",
+                |m: &CrossReferencesReply_CrossReferenceSet| { &m.marked_source },
+                |m: &mut CrossReferencesReply_CrossReferenceSet| { &mut m.marked_source },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CrossReferencesReply_RelatedAnchor>>(
+                "definition",
+                |m: &CrossReferencesReply_CrossReferenceSet| { &m.definition },
+                |m: &mut CrossReferencesReply_CrossReferenceSet| { &mut m.definition },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CrossReferencesReply_RelatedAnchor>>(
+                "declaration",
+                |m: &CrossReferencesReply_CrossReferenceSet| { &m.declaration },
+                |m: &mut CrossReferencesReply_CrossReferenceSet| { &mut m.declaration },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CrossReferencesReply_RelatedAnchor>>(
+                "reference",
+                |m: &CrossReferencesReply_CrossReferenceSet| { &m.reference },
+                |m: &mut CrossReferencesReply_CrossReferenceSet| { &mut m.reference },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CrossReferencesReply_RelatedAnchor>>(
+                "caller",
+                |m: &CrossReferencesReply_CrossReferenceSet| { &m.caller },
+                |m: &mut CrossReferencesReply_CrossReferenceSet| { &mut m.caller },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CrossReferencesReply_RelatedNode>>(
+                "related_node",
+                |m: &CrossReferencesReply_CrossReferenceSet| { &m.related_node },
+                |m: &mut CrossReferencesReply_CrossReferenceSet| { &mut m.related_node },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CrossReferencesReply_CrossReferenceSet>(
+                "CrossReferencesReply.CrossReferenceSet",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CrossReferencesReply_CrossReferenceSet {
+        static instance: ::protobuf::rt::LazyV2<CrossReferencesReply_CrossReferenceSet> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CrossReferencesReply_CrossReferenceSet::new)
+    }
+}
+
+impl ::protobuf::Clear for CrossReferencesReply_CrossReferenceSet {
+    fn clear(&mut self) {
+        self.ticket.clear();
+        self.marked_source.clear();
+        self.definition.clear();
+        self.declaration.clear();
+        self.reference.clear();
+        self.caller.clear();
+        self.related_node.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CrossReferencesReply_CrossReferenceSet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CrossReferencesReply_CrossReferenceSet {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CrossReferencesReply_Total {
+    // message fields
+    pub definitions: i64,
+    pub declarations: i64,
+    pub references: i64,
+    pub documentation: i64,
+    pub callers: i64,
+    pub ref_edge_to_count: ::std::collections::HashMap<::std::string::String, i64>,
+    pub related_nodes_by_relation: ::std::collections::HashMap<::std::string::String, i64>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CrossReferencesReply_Total {
+    fn default() -> &'a CrossReferencesReply_Total {
+        <CrossReferencesReply_Total as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CrossReferencesReply_Total {
+    pub fn new() -> CrossReferencesReply_Total {
+        ::std::default::Default::default()
+    }
+
+    // int64 definitions = 1;
+
+
+    pub fn get_definitions(&self) -> i64 {
+        self.definitions
+    }
+    pub fn clear_definitions(&mut self) {
+        self.definitions = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_definitions(&mut self, v: i64) {
+        self.definitions = v;
+    }
+
+    // int64 declarations = 2;
+
+
+    pub fn get_declarations(&self) -> i64 {
+        self.declarations
+    }
+    pub fn clear_declarations(&mut self) {
+        self.declarations = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_declarations(&mut self, v: i64) {
+        self.declarations = v;
+    }
+
+    // int64 references = 3;
+
+
+    pub fn get_references(&self) -> i64 {
+        self.references
+    }
+    pub fn clear_references(&mut self) {
+        self.references = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_references(&mut self, v: i64) {
+        self.references = v;
+    }
+
+    // int64 documentation = 4;
+
+
+    pub fn get_documentation(&self) -> i64 {
+        self.documentation
+    }
+    pub fn clear_documentation(&mut self) {
+        self.documentation = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_documentation(&mut self, v: i64) {
+        self.documentation = v;
+    }
+
+    // int64 callers = 5;
+
+
+    pub fn get_callers(&self) -> i64 {
+        self.callers
+    }
+    pub fn clear_callers(&mut self) {
+        self.callers = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_callers(&mut self, v: i64) {
+        self.callers = v;
+    }
+
+    // repeated .kythe.proto.CrossReferencesReply.Total.RefEdgeToCountEntry ref_edge_to_count = 7;
+
+
+    pub fn get_ref_edge_to_count(&self) -> &::std::collections::HashMap<::std::string::String, i64> {
+        &self.ref_edge_to_count
+    }
+    pub fn clear_ref_edge_to_count(&mut self) {
+        self.ref_edge_to_count.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ref_edge_to_count(&mut self, v: ::std::collections::HashMap<::std::string::String, i64>) {
+        self.ref_edge_to_count = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_ref_edge_to_count(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, i64> {
+        &mut self.ref_edge_to_count
+    }
+
+    // Take field
+    pub fn take_ref_edge_to_count(&mut self) -> ::std::collections::HashMap<::std::string::String, i64> {
+        ::std::mem::replace(&mut self.ref_edge_to_count, ::std::collections::HashMap::new())
+    }
+
+    // repeated .kythe.proto.CrossReferencesReply.Total.RelatedNodesByRelationEntry related_nodes_by_relation = 6;
+
+
+    pub fn get_related_nodes_by_relation(&self) -> &::std::collections::HashMap<::std::string::String, i64> {
+        &self.related_nodes_by_relation
+    }
+    pub fn clear_related_nodes_by_relation(&mut self) {
+        self.related_nodes_by_relation.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_related_nodes_by_relation(&mut self, v: ::std::collections::HashMap<::std::string::String, i64>) {
+        self.related_nodes_by_relation = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_related_nodes_by_relation(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, i64> {
+        &mut self.related_nodes_by_relation
+    }
+
+    // Take field
+    pub fn take_related_nodes_by_relation(&mut self) -> ::std::collections::HashMap<::std::string::String, i64> {
+        ::std::mem::replace(&mut self.related_nodes_by_relation, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for CrossReferencesReply_Total {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.definitions = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.declarations = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.references = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.documentation = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.callers = tmp;
+                },
+                7 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeInt64>(wire_type, is, &mut self.ref_edge_to_count)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeInt64>(wire_type, is, &mut self.related_nodes_by_relation)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.definitions != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.definitions, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.declarations != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.declarations, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.references != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.references, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.documentation != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.documentation, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.callers != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.callers, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeInt64>(7, &self.ref_edge_to_count);
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeInt64>(6, &self.related_nodes_by_relation);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.definitions != 0 {
+            os.write_int64(1, self.definitions)?;
+        }
+        if self.declarations != 0 {
+            os.write_int64(2, self.declarations)?;
+        }
+        if self.references != 0 {
+            os.write_int64(3, self.references)?;
+        }
+        if self.documentation != 0 {
+            os.write_int64(4, self.documentation)?;
+        }
+        if self.callers != 0 {
+            os.write_int64(5, self.callers)?;
+        }
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeInt64>(7, &self.ref_edge_to_count, os)?;
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeInt64>(6, &self.related_nodes_by_relation, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CrossReferencesReply_Total {
+        CrossReferencesReply_Total::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "definitions",
+                |m: &CrossReferencesReply_Total| { &m.definitions },
+                |m: &mut CrossReferencesReply_Total| { &mut m.definitions },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "declarations",
+                |m: &CrossReferencesReply_Total| { &m.declarations },
+                |m: &mut CrossReferencesReply_Total| { &mut m.declarations },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "references",
+                |m: &CrossReferencesReply_Total| { &m.references },
+                |m: &mut CrossReferencesReply_Total| { &mut m.references },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "documentation",
+                |m: &CrossReferencesReply_Total| { &m.documentation },
+                |m: &mut CrossReferencesReply_Total| { &mut m.documentation },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "callers",
+                |m: &CrossReferencesReply_Total| { &m.callers },
+                |m: &mut CrossReferencesReply_Total| { &mut m.callers },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeInt64>(
+                "ref_edge_to_count",
+                |m: &CrossReferencesReply_Total| { &m.ref_edge_to_count },
+                |m: &mut CrossReferencesReply_Total| { &mut m.ref_edge_to_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeInt64>(
+                "related_nodes_by_relation",
+                |m: &CrossReferencesReply_Total| { &m.related_nodes_by_relation },
+                |m: &mut CrossReferencesReply_Total| { &mut m.related_nodes_by_relation },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CrossReferencesReply_Total>(
+                "CrossReferencesReply.Total",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CrossReferencesReply_Total {
+        static instance: ::protobuf::rt::LazyV2<CrossReferencesReply_Total> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CrossReferencesReply_Total::new)
+    }
+}
+
+impl ::protobuf::Clear for CrossReferencesReply_Total {
+    fn clear(&mut self) {
+        self.definitions = 0;
+        self.declarations = 0;
+        self.references = 0;
+        self.documentation = 0;
+        self.callers = 0;
+        self.ref_edge_to_count.clear();
+        self.related_nodes_by_relation.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CrossReferencesReply_Total {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CrossReferencesReply_Total {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DocumentationRequest {
+    // message fields
+    pub ticket: ::protobuf::RepeatedField<::std::string::String>,
+    pub filter: ::protobuf::RepeatedField<::std::string::String>,
+    pub include_children: bool,
+    pub workspace: ::protobuf::SingularPtrField<Workspace>,
+    pub patch_against_workspace: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DocumentationRequest {
+    fn default() -> &'a DocumentationRequest {
+        <DocumentationRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DocumentationRequest {
+    pub fn new() -> DocumentationRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated string ticket = 1;
+
+
+    pub fn get_ticket(&self) -> &[::std::string::String] {
+        &self.ticket
+    }
+    pub fn clear_ticket(&mut self) {
+        self.ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ticket(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_ticket(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.ticket
+    }
+
+    // Take field
+    pub fn take_ticket(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.ticket, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string filter = 2;
+
+
+    pub fn get_filter(&self) -> &[::std::string::String] {
+        &self.filter
+    }
+    pub fn clear_filter(&mut self) {
+        self.filter.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_filter(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.filter = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_filter(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.filter
+    }
+
+    // Take field
+    pub fn take_filter(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.filter, ::protobuf::RepeatedField::new())
+    }
+
+    // bool include_children = 3;
+
+
+    pub fn get_include_children(&self) -> bool {
+        self.include_children
+    }
+    pub fn clear_include_children(&mut self) {
+        self.include_children = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_include_children(&mut self, v: bool) {
+        self.include_children = v;
+    }
+
+    // .kythe.proto.Workspace workspace = 4;
+
+
+    pub fn get_workspace(&self) -> &Workspace {
+        self.workspace.as_ref().unwrap_or_else(|| <Workspace as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_workspace(&mut self) {
+        self.workspace.clear();
+    }
+
+    pub fn has_workspace(&self) -> bool {
+        self.workspace.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace(&mut self, v: Workspace) {
+        self.workspace = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace(&mut self) -> &mut Workspace {
+        if self.workspace.is_none() {
+            self.workspace.set_default();
+        }
+        self.workspace.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_workspace(&mut self) -> Workspace {
+        self.workspace.take().unwrap_or_else(|| Workspace::new())
+    }
+
+    // bool patch_against_workspace = 5;
+
+
+    pub fn get_patch_against_workspace(&self) -> bool {
+        self.patch_against_workspace
+    }
+    pub fn clear_patch_against_workspace(&mut self) {
+        self.patch_against_workspace = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_patch_against_workspace(&mut self, v: bool) {
+        self.patch_against_workspace = v;
+    }
+}
+
+impl ::protobuf::Message for DocumentationRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.workspace {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.ticket)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.filter)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.include_children = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.workspace)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.patch_against_workspace = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.ticket {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in &self.filter {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        if self.include_children != false {
+            my_size += 2;
+        }
+        if let Some(ref v) = self.workspace.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.patch_against_workspace != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.ticket {
+            os.write_string(1, &v)?;
+        };
+        for v in &self.filter {
+            os.write_string(2, &v)?;
+        };
+        if self.include_children != false {
+            os.write_bool(3, self.include_children)?;
+        }
+        if let Some(ref v) = self.workspace.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.patch_against_workspace != false {
+            os.write_bool(5, self.patch_against_workspace)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DocumentationRequest {
+        DocumentationRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "ticket",
+                |m: &DocumentationRequest| { &m.ticket },
+                |m: &mut DocumentationRequest| { &mut m.ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "filter",
+                |m: &DocumentationRequest| { &m.filter },
+                |m: &mut DocumentationRequest| { &mut m.filter },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "include_children",
+                |m: &DocumentationRequest| { &m.include_children },
+                |m: &mut DocumentationRequest| { &mut m.include_children },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Workspace>>(
+                "workspace",
+                |m: &DocumentationRequest| { &m.workspace },
+                |m: &mut DocumentationRequest| { &mut m.workspace },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "patch_against_workspace",
+                |m: &DocumentationRequest| { &m.patch_against_workspace },
+                |m: &mut DocumentationRequest| { &mut m.patch_against_workspace },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocumentationRequest>(
+                "DocumentationRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DocumentationRequest {
+        static instance: ::protobuf::rt::LazyV2<DocumentationRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DocumentationRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for DocumentationRequest {
+    fn clear(&mut self) {
+        self.ticket.clear();
+        self.filter.clear();
+        self.include_children = false;
+        self.workspace.clear();
+        self.patch_against_workspace = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DocumentationRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DocumentationRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DocumentationReply {
+    // message fields
+    pub document: ::protobuf::RepeatedField<DocumentationReply_Document>,
+    pub nodes: ::std::collections::HashMap<::std::string::String, super::common::NodeInfo>,
+    pub definition_locations: ::std::collections::HashMap<::std::string::String, Anchor>,
+    pub build_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DocumentationReply {
+    fn default() -> &'a DocumentationReply {
+        <DocumentationReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DocumentationReply {
+    pub fn new() -> DocumentationReply {
+        ::std::default::Default::default()
+    }
+
+    // repeated .kythe.proto.DocumentationReply.Document document = 1;
+
+
+    pub fn get_document(&self) -> &[DocumentationReply_Document] {
+        &self.document
+    }
+    pub fn clear_document(&mut self) {
+        self.document.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_document(&mut self, v: ::protobuf::RepeatedField<DocumentationReply_Document>) {
+        self.document = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_document(&mut self) -> &mut ::protobuf::RepeatedField<DocumentationReply_Document> {
+        &mut self.document
+    }
+
+    // Take field
+    pub fn take_document(&mut self) -> ::protobuf::RepeatedField<DocumentationReply_Document> {
+        ::std::mem::replace(&mut self.document, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .kythe.proto.DocumentationReply.NodesEntry nodes = 2;
+
+
+    pub fn get_nodes(&self) -> &::std::collections::HashMap<::std::string::String, super::common::NodeInfo> {
+        &self.nodes
+    }
+    pub fn clear_nodes(&mut self) {
+        self.nodes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nodes(&mut self, v: ::std::collections::HashMap<::std::string::String, super::common::NodeInfo>) {
+        self.nodes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_nodes(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, super::common::NodeInfo> {
+        &mut self.nodes
+    }
+
+    // Take field
+    pub fn take_nodes(&mut self) -> ::std::collections::HashMap<::std::string::String, super::common::NodeInfo> {
+        ::std::mem::replace(&mut self.nodes, ::std::collections::HashMap::new())
+    }
+
+    // repeated .kythe.proto.DocumentationReply.DefinitionLocationsEntry definition_locations = 3;
+
+
+    pub fn get_definition_locations(&self) -> &::std::collections::HashMap<::std::string::String, Anchor> {
+        &self.definition_locations
+    }
+    pub fn clear_definition_locations(&mut self) {
+        self.definition_locations.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_definition_locations(&mut self, v: ::std::collections::HashMap<::std::string::String, Anchor>) {
+        self.definition_locations = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_definition_locations(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, Anchor> {
+        &mut self.definition_locations
+    }
+
+    // Take field
+    pub fn take_definition_locations(&mut self) -> ::std::collections::HashMap<::std::string::String, Anchor> {
+        ::std::mem::replace(&mut self.definition_locations, ::std::collections::HashMap::new())
+    }
+
+    // string build_id = 4;
+
+
+    pub fn get_build_id(&self) -> &str {
+        &self.build_id
+    }
+    pub fn clear_build_id(&mut self) {
+        self.build_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_build_id(&mut self, v: ::std::string::String) {
+        self.build_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_build_id(&mut self) -> &mut ::std::string::String {
+        &mut self.build_id
+    }
+
+    // Take field
+    pub fn take_build_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.build_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for DocumentationReply {
+    fn is_initialized(&self) -> bool {
+        for v in &self.document {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.document)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<super::common::NodeInfo>>(wire_type, is, &mut self.nodes)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Anchor>>(wire_type, is, &mut self.definition_locations)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.build_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.document {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<super::common::NodeInfo>>(2, &self.nodes);
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Anchor>>(3, &self.definition_locations);
+        if !self.build_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.build_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.document {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<super::common::NodeInfo>>(2, &self.nodes, os)?;
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Anchor>>(3, &self.definition_locations, os)?;
+        if !self.build_id.is_empty() {
+            os.write_string(4, &self.build_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DocumentationReply {
+        DocumentationReply::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DocumentationReply_Document>>(
+                "document",
+                |m: &DocumentationReply| { &m.document },
+                |m: &mut DocumentationReply| { &mut m.document },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<super::common::NodeInfo>>(
+                "nodes",
+                |m: &DocumentationReply| { &m.nodes },
+                |m: &mut DocumentationReply| { &mut m.nodes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Anchor>>(
+                "definition_locations",
+                |m: &DocumentationReply| { &m.definition_locations },
+                |m: &mut DocumentationReply| { &mut m.definition_locations },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "build_id",
+                |m: &DocumentationReply| { &m.build_id },
+                |m: &mut DocumentationReply| { &mut m.build_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocumentationReply>(
+                "DocumentationReply",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DocumentationReply {
+        static instance: ::protobuf::rt::LazyV2<DocumentationReply> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DocumentationReply::new)
+    }
+}
+
+impl ::protobuf::Clear for DocumentationReply {
+    fn clear(&mut self) {
+        self.document.clear();
+        self.nodes.clear();
+        self.definition_locations.clear();
+        self.build_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DocumentationReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DocumentationReply {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DocumentationReply_Document {
+    // message fields
+    pub ticket: ::std::string::String,
+    pub text: ::protobuf::SingularPtrField<Printable>,
+    pub marked_source: ::protobuf::SingularPtrField<super::common::MarkedSource>,
+    pub children: ::protobuf::RepeatedField<DocumentationReply_Document>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DocumentationReply_Document {
+    fn default() -> &'a DocumentationReply_Document {
+        <DocumentationReply_Document as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DocumentationReply_Document {
+    pub fn new() -> DocumentationReply_Document {
+        ::std::default::Default::default()
+    }
+
+    // string ticket = 1;
+
+
+    pub fn get_ticket(&self) -> &str {
+        &self.ticket
+    }
+    pub fn clear_ticket(&mut self) {
+        self.ticket.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ticket(&mut self, v: ::std::string::String) {
+        self.ticket = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ticket(&mut self) -> &mut ::std::string::String {
+        &mut self.ticket
+    }
+
+    // Take field
+    pub fn take_ticket(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.ticket, ::std::string::String::new())
+    }
+
+    // .kythe.proto.Printable text = 2;
+
+
+    pub fn get_text(&self) -> &Printable {
+        self.text.as_ref().unwrap_or_else(|| <Printable as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_text(&mut self) {
+        self.text.clear();
+    }
+
+    pub fn has_text(&self) -> bool {
+        self.text.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_text(&mut self, v: Printable) {
+        self.text = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_text(&mut self) -> &mut Printable {
+        if self.text.is_none() {
+            self.text.set_default();
+        }
+        self.text.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_text(&mut self) -> Printable {
+        self.text.take().unwrap_or_else(|| Printable::new())
+    }
+
+    // .kythe.proto.common.MarkedSource marked_source = 8;
+
+
+    pub fn get_marked_source(&self) -> &super::common::MarkedSource {
+        self.marked_source.as_ref().unwrap_or_else(|| <super::common::MarkedSource as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_marked_source(&mut self) {
+        self.marked_source.clear();
+    }
+
+    pub fn has_marked_source(&self) -> bool {
+        self.marked_source.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_marked_source(&mut self, v: super::common::MarkedSource) {
+        self.marked_source = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_marked_source(&mut self) -> &mut super::common::MarkedSource {
+        if self.marked_source.is_none() {
+            self.marked_source.set_default();
+        }
+        self.marked_source.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_marked_source(&mut self) -> super::common::MarkedSource {
+        self.marked_source.take().unwrap_or_else(|| super::common::MarkedSource::new())
+    }
+
+    // repeated .kythe.proto.DocumentationReply.Document children = 9;
+
+
+    pub fn get_children(&self) -> &[DocumentationReply_Document] {
+        &self.children
+    }
+    pub fn clear_children(&mut self) {
+        self.children.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_children(&mut self, v: ::protobuf::RepeatedField<DocumentationReply_Document>) {
+        self.children = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_children(&mut self) -> &mut ::protobuf::RepeatedField<DocumentationReply_Document> {
+        &mut self.children
+    }
+
+    // Take field
+    pub fn take_children(&mut self) -> ::protobuf::RepeatedField<DocumentationReply_Document> {
+        ::std::mem::replace(&mut self.children, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for DocumentationReply_Document {
+    fn is_initialized(&self) -> bool {
+        for v in &self.text {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.marked_source {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.children {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.ticket)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.text)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.marked_source)?;
+                },
+                9 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.children)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.ticket.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.ticket);
+        }
+        if let Some(ref v) = self.text.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.marked_source.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.children {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.ticket.is_empty() {
+            os.write_string(1, &self.ticket)?;
+        }
+        if let Some(ref v) = self.text.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.marked_source.as_ref() {
+            os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.children {
+            os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DocumentationReply_Document {
+        DocumentationReply_Document::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "ticket",
+                |m: &DocumentationReply_Document| { &m.ticket },
+                |m: &mut DocumentationReply_Document| { &mut m.ticket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Printable>>(
+                "text",
+                |m: &DocumentationReply_Document| { &m.text },
+                |m: &mut DocumentationReply_Document| { &mut m.text },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::MarkedSource>>(
+                "marked_source",
+                |m: &DocumentationReply_Document| { &m.marked_source },
+                |m: &mut DocumentationReply_Document| { &mut m.marked_source },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DocumentationReply_Document>>(
+                "children",
+                |m: &DocumentationReply_Document| { &m.children },
+                |m: &mut DocumentationReply_Document| { &mut m.children },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocumentationReply_Document>(
+                "DocumentationReply.Document",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DocumentationReply_Document {
+        static instance: ::protobuf::rt::LazyV2<DocumentationReply_Document> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DocumentationReply_Document::new)
+    }
+}
+
+impl ::protobuf::Clear for DocumentationReply_Document {
+    fn clear(&mut self) {
+        self.ticket.clear();
+        self.text.clear();
+        self.marked_source.clear();
+        self.children.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DocumentationReply_Document {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DocumentationReply_Document {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Workspace {
+    // message fields
+    pub uri: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Workspace {
+    fn default() -> &'a Workspace {
+        <Workspace as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Workspace {
+    pub fn new() -> Workspace {
+        ::std::default::Default::default()
+    }
+
+    // string uri = 1;
+
+
+    pub fn get_uri(&self) -> &str {
+        &self.uri
+    }
+    pub fn clear_uri(&mut self) {
+        self.uri.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_uri(&mut self, v: ::std::string::String) {
+        self.uri = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_uri(&mut self) -> &mut ::std::string::String {
+        &mut self.uri
+    }
+
+    // Take field
+    pub fn take_uri(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.uri, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Workspace {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.uri)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.uri.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.uri);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.uri.is_empty() {
+            os.write_string(1, &self.uri)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Workspace {
+        Workspace::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "uri",
+                |m: &Workspace| { &m.uri },
+                |m: &mut Workspace| { &mut m.uri },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Workspace>(
+                "Workspace",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Workspace {
+        static instance: ::protobuf::rt::LazyV2<Workspace> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Workspace::new)
+    }
+}
+
+impl ::protobuf::Clear for Workspace {
+    fn clear(&mut self) {
+        self.uri.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Workspace {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Workspace {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum SnippetsKind {
+    NONE = 0,
+    DEFAULT = 1,
+}
+
+impl ::protobuf::ProtobufEnum for SnippetsKind {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<SnippetsKind> {
+        match value {
+            0 => ::std::option::Option::Some(SnippetsKind::NONE),
+            1 => ::std::option::Option::Some(SnippetsKind::DEFAULT),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [SnippetsKind] = &[
+            SnippetsKind::NONE,
+            SnippetsKind::DEFAULT,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<SnippetsKind>("SnippetsKind", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for SnippetsKind {
+}
+
+impl ::std::default::Default for SnippetsKind {
+    fn default() -> Self {
+        SnippetsKind::NONE
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SnippetsKind {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x16kythe/proto/xref.proto\x12\x0bkythe.proto\x1a\x18kythe/proto/commo\
+    n.proto\"\xa8\x01\n\x08Location\x12\x16\n\x06ticket\x18\x01\x20\x01(\tR\
+    \x06ticket\x12.\n\x04kind\x18\x02\x20\x01(\x0e2\x1a.kythe.proto.Location\
+    .KindR\x04kind\x12,\n\x04span\x18\x05\x20\x01(\x0b2\x18.kythe.proto.comm\
+    on.SpanR\x04span\"\x1a\n\x04Kind\x12\x08\n\x04FILE\x10\0\x12\x08\n\x04SP\
+    AN\x10\x01J\x04\x08\x03\x10\x04J\x04\x08\x04\x10\x05\"\xa7\x05\n\x12Deco\
+    rationsRequest\x121\n\x08location\x18\x01\x20\x01(\x0b2\x15.kythe.proto.\
+    LocationR\x08location\x12E\n\tspan_kind\x18\n\x20\x01(\x0e2(.kythe.proto\
+    .DecorationsRequest.SpanKindR\x08spanKind\x12!\n\x0cdirty_buffer\x18\x02\
+    \x20\x01(\x0cR\x0bdirtyBuffer\x12\x1f\n\x0bsource_text\x18\x03\x20\x01(\
+    \x08R\nsourceText\x12\x1e\n\nreferences\x18\x04\x20\x01(\x08R\nreference\
+    s\x12-\n\x12target_definitions\x18\x06\x20\x01(\x08R\x11targetDefinition\
+    s\x12\x16\n\x06filter\x18\x05\x20\x03(\tR\x06filter\x12+\n\x11extends_ov\
+    errides\x18\x07\x20\x01(\x08R\x10extendsOverrides\x12\x20\n\x0bdiagnosti\
+    cs\x18\x08\x20\x01(\x08R\x0bdiagnostics\x125\n\x08snippets\x18\t\x20\x01\
+    (\x0e2\x19.kythe.proto.SnippetsKindR\x08snippets\x12!\n\x0cbuild_config\
+    \x18\x0b\x20\x03(\tR\x0bbuildConfig\x12'\n\x0fsemantic_scopes\x18\x0c\
+    \x20\x01(\x08R\x0esemanticScopes\x124\n\tworkspace\x18\r\x20\x01(\x0b2\
+    \x16.kythe.proto.WorkspaceR\tworkspace\x126\n\x17patch_against_workspace\
+    \x18\x0e\x20\x01(\x08R\x15patchAgainstWorkspace\",\n\x08SpanKind\x12\x0f\
+    \n\x0bWITHIN_SPAN\x10\0\x12\x0f\n\x0bAROUND_SPAN\x10\x01\"c\n\x04File\
+    \x12?\n\x0bcorpus_path\x18\x01\x20\x01(\x0b2\x1e.kythe.proto.common.Corp\
+    usPathR\ncorpusPath\x12\x1a\n\x08revision\x18\x02\x20\x01(\tR\x08revisio\
+    n\"\xc6\x0c\n\x10DecorationsReply\x121\n\x08location\x18\x01\x20\x01(\
+    \x0b2\x15.kythe.proto.LocationR\x08location\x12\x1a\n\x08revision\x18\
+    \x12\x20\x01(\tR\x08revision\x12$\n\x0bsource_text\x18\x02\x20\x01(\x0cH\
+    \0R\nsourceText\x88\x01\x01\x12\x1a\n\x08encoding\x18\x03\x20\x01(\tR\
+    \x08encoding\x12E\n\treference\x18\x04\x20\x03(\x0b2'.kythe.proto.Decora\
+    tionsReply.ReferenceR\treference\x12>\n\ndiagnostic\x18\x05\x20\x03(\x0b\
+    2\x1e.kythe.proto.common.DiagnosticR\ndiagnostic\x12=\n\x11generated_by_\
+    file\x18\x13\x20\x03(\x0b2\x11.kythe.proto.FileR\x0fgeneratedByFile\x12>\
+    \n\x05nodes\x18\x0f\x20\x03(\x0b2(.kythe.proto.DecorationsReply.NodesEnt\
+    ryR\x05nodes\x12i\n\x14definition_locations\x18\x10\x20\x03(\x0b26.kythe\
+    .proto.DecorationsReply.DefinitionLocationsEntryR\x13definitionLocations\
+    \x12`\n\x11extends_overrides\x18\x11\x20\x03(\x0b23.kythe.proto.Decorati\
+    onsReply.ExtendsOverridesEntryR\x10extendsOverrides\x12\x19\n\x08build_i\
+    d\x18\x14\x20\x01(\tR\x07buildId\x1a\xa4\x02\n\tReference\x12#\n\rtarget\
+    _ticket\x18\x02\x20\x01(\tR\x0ctargetTicket\x12\x12\n\x04kind\x18\x03\
+    \x20\x01(\tR\x04kind\x12+\n\x11target_definition\x18\x04\x20\x01(\tR\x10\
+    targetDefinition\x12,\n\x04span\x18\x05\x20\x01(\x0b2\x18.kythe.proto.co\
+    mmon.SpanR\x04span\x12!\n\x0cbuild_config\x18\x06\x20\x01(\tR\x0bbuildCo\
+    nfig\x12%\n\x0esemantic_scope\x18\x07\x20\x01(\tR\rsemanticScope\x12'\n\
+    \x0ftarget_revision\x18\x08\x20\x01(\tR\x0etargetRevisionJ\x04\x08\x01\
+    \x10\x02J\x04\x08\n\x10\x0bJ\x04\x08\x0b\x10\x0c\x1a\x81\x02\n\x08Overri\
+    de\x12\x16\n\x06target\x18\x01\x20\x01(\tR\x06target\x12+\n\x11target_de\
+    finition\x18\x05\x20\x01(\tR\x10targetDefinition\x12?\n\x04kind\x18\x02\
+    \x20\x01(\x0e2+.kythe.proto.DecorationsReply.Override.KindR\x04kind\x12E\
+    \n\rmarked_source\x18\x04\x20\x01(\x0b2\x20.kythe.proto.common.MarkedSou\
+    rceR\x0cmarkedSource\"\"\n\x04Kind\x12\r\n\tOVERRIDES\x10\0\x12\x0b\n\
+    \x07EXTENDS\x10\x01J\x04\x08\x03\x10\x04\x1aO\n\tOverrides\x12B\n\x08ove\
+    rride\x18\x01\x20\x03(\x0b2&.kythe.proto.DecorationsReply.OverrideR\x08o\
+    verride\x1aV\n\nNodesEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\
+    \x122\n\x05value\x18\x02\x20\x01(\x0b2\x1c.kythe.proto.common.NodeInfoR\
+    \x05value:\x028\x01\x1a[\n\x18DefinitionLocationsEntry\x12\x10\n\x03key\
+    \x18\x01\x20\x01(\tR\x03key\x12)\n\x05value\x18\x02\x20\x01(\x0b2\x13.ky\
+    the.proto.AnchorR\x05value:\x028\x01\x1al\n\x15ExtendsOverridesEntry\x12\
+    \x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12=\n\x05value\x18\x02\x20\x01\
+    (\x0b2'.kythe.proto.DecorationsReply.OverridesR\x05value:\x028\x01B\x0e\
+    \n\x0c_source_textJ\x04\x08\x06\x10\x07\"\xb6\x0b\n\x16CrossReferencesRe\
+    quest\x12\x16\n\x06ticket\x18\x01\x20\x03(\tR\x06ticket\x12[\n\x0fdefini\
+    tion_kind\x18\x02\x20\x01(\x0e22.kythe.proto.CrossReferencesRequest.Defi\
+    nitionKindR\x0edefinitionKind\x12^\n\x10declaration_kind\x18\x07\x20\x01\
+    (\x0e23.kythe.proto.CrossReferencesRequest.DeclarationKindR\x0fdeclarati\
+    onKind\x12X\n\x0ereference_kind\x18\x03\x20\x01(\x0e21.kythe.proto.Cross\
+    ReferencesRequest.ReferenceKindR\rreferenceKind\x12O\n\x0bcaller_kind\
+    \x18\x0c\x20\x01(\x0e2..kythe.proto.CrossReferencesRequest.CallerKindR\n\
+    callerKind\x12'\n\x0fsemantic_scopes\x18\x14\x20\x01(\x08R\x0esemanticSc\
+    opes\x12\x16\n\x06filter\x18\x05\x20\x03(\tR\x06filter\x12*\n\x11related\
+    _node_kind\x18\x0e\x20\x03(\tR\x0frelatedNodeKind\x12\x1f\n\x0banchor_te\
+    xt\x18\x06\x20\x01(\x08R\nanchorText\x12)\n\x10node_definitions\x18\x08\
+    \x20\x01(\x08R\x0fnodeDefinitions\x12X\n\x0etotals_quality\x18\x10\x20\
+    \x01(\x0e21.kythe.proto.CrossReferencesRequest.TotalsQualityR\rtotalsQua\
+    lity\x12\x1b\n\tpage_size\x18\n\x20\x01(\x05R\x08pageSize\x12\x1d\n\npag\
+    e_token\x18\x0b\x20\x01(\tR\tpageToken\x125\n\x08snippets\x18\r\x20\x01(\
+    \x0e2\x19.kythe.proto.SnippetsKindR\x08snippets\x12!\n\x0cbuild_config\
+    \x18\x0f\x20\x03(\tR\x0bbuildConfig\x124\n\tworkspace\x18\x11\x20\x01(\
+    \x0b2\x16.kythe.proto.WorkspaceR\tworkspace\x126\n\x17patch_against_work\
+    space\x18\x12\x20\x01(\x08R\x15patchAgainstWorkspace\x12N\n\x13corpus_pa\
+    th_filters\x18\x13\x20\x01(\x0b2\x1e.kythe.proto.CorpusPathFiltersR\x11c\
+    orpusPathFilters\"h\n\x0eDefinitionKind\x12\x12\n\x0eNO_DEFINITIONS\x10\
+    \0\x12\x13\n\x0fALL_DEFINITIONS\x10\x01\x12\x14\n\x10FULL_DEFINITIONS\
+    \x10\x02\x12\x17\n\x13BINDING_DEFINITIONS\x10\x03\"<\n\x0fDeclarationKin\
+    d\x12\x13\n\x0fNO_DECLARATIONS\x10\0\x12\x14\n\x10ALL_DECLARATIONS\x10\
+    \x01\"d\n\rReferenceKind\x12\x11\n\rNO_REFERENCES\x10\0\x12\x13\n\x0fCAL\
+    L_REFERENCES\x10\x01\x12\x17\n\x13NON_CALL_REFERENCES\x10\x02\x12\x12\n\
+    \x0eALL_REFERENCES\x10\x03\"F\n\nCallerKind\x12\x0e\n\nNO_CALLERS\x10\0\
+    \x12\x12\n\x0eDIRECT_CALLERS\x10\x01\x12\x14\n\x10OVERRIDE_CALLERS\x10\
+    \x02\"S\n\rTotalsQuality\x12\x16\n\x12UNSPECIFIED_TOTALS\x10\0\x12\x12\n\
+    \x0ePRECISE_TOTALS\x10\x01\x12\x16\n\x12APPROXIMATE_TOTALS\x10\x02J\x04\
+    \x08\x04\x10\x05J\x04\x08d\x10e\"J\n\x11CorpusPathFilters\x125\n\x06filt\
+    er\x18\x01\x20\x03(\x0b2\x1d.kythe.proto.CorpusPathFilterR\x06filter\"\
+    \x99\x02\n\x10CorpusPathFilter\x126\n\x04type\x18\x01\x20\x01(\x0e2\".ky\
+    the.proto.CorpusPathFilter.TypeR\x04type\x12\x16\n\x06corpus\x18\x02\x20\
+    \x01(\tR\x06corpus\x124\n\x16corpus_specific_filter\x18\x06\x20\x01(\x08\
+    R\x14corpusSpecificFilter\x12\x12\n\x04root\x18\x03\x20\x01(\tR\x04root\
+    \x12\x12\n\x04path\x18\x04\x20\x01(\tR\x04path\x12#\n\rresolved_path\x18\
+    \x05\x20\x01(\tR\x0cresolvedPath\"2\n\x04Type\x12\x0b\n\x07DEFAULT\x10\0\
+    \x12\x10\n\x0cINCLUDE_ONLY\x10\x01\x12\x0b\n\x07EXCLUDE\x10\x02\"\xbc\
+    \x02\n\x06Anchor\x12\x16\n\x06ticket\x18\x01\x20\x01(\tR\x06ticket\x12\
+    \x12\n\x04kind\x18\x02\x20\x01(\tR\x04kind\x12\x16\n\x06parent\x18\x03\
+    \x20\x01(\tR\x06parent\x12,\n\x04span\x18\n\x20\x01(\x0b2\x18.kythe.prot\
+    o.common.SpanR\x04span\x12\x12\n\x04text\x18\x06\x20\x01(\tR\x04text\x12\
+    \x18\n\x07snippet\x18\x07\x20\x01(\tR\x07snippet\x12;\n\x0csnippet_span\
+    \x18\x0b\x20\x01(\x0b2\x18.kythe.proto.common.SpanR\x0bsnippetSpan\x12!\
+    \n\x0cbuild_config\x18\x0c\x20\x01(\tR\x0bbuildConfig\x12\x1a\n\x08revis\
+    ion\x18\x0e\x20\x01(\tR\x08revisionJ\x04\x08\x04\x10\x05J\x04\x08\x05\
+    \x10\x06J\x04\x08\x08\x10\tJ\x04\x08\t\x10\n\"T\n\tPrintable\x12\x19\n\
+    \x08raw_text\x18\x01\x20\x01(\tR\x07rawText\x12,\n\x04link\x18\x02\x20\
+    \x03(\x0b2\x18.kythe.proto.common.LinkR\x04link\"\xaf\x11\n\x14CrossRefe\
+    rencesReply\x12=\n\x05total\x18\x05\x20\x01(\x0b2'.kythe.proto.CrossRefe\
+    rencesReply.TotalR\x05total\x12C\n\x08filtered\x18\x0c\x20\x01(\x0b2'.ky\
+    the.proto.CrossReferencesReply.TotalR\x08filtered\x12a\n\x10cross_refere\
+    nces\x18\x01\x20\x03(\x0b26.kythe.proto.CrossReferencesReply.CrossRefere\
+    ncesEntryR\x0fcrossReferences\x12B\n\x05nodes\x18\x02\x20\x03(\x0b2,.kyt\
+    he.proto.CrossReferencesReply.NodesEntryR\x05nodes\x12m\n\x14definition_\
+    locations\x18\x03\x20\x03(\x0b2:.kythe.proto.CrossReferencesReply.Defini\
+    tionLocationsEntryR\x13definitionLocations\x12&\n\x0fnext_page_token\x18\
+    \n\x20\x01(\tR\rnextPageToken\x12\x19\n\x08build_id\x18\x0b\x20\x01(\tR\
+    \x07buildId\x1ad\n\x0bRelatedNode\x12\x16\n\x06ticket\x18\x01\x20\x01(\t\
+    R\x06ticket\x12#\n\rrelation_kind\x18\x02\x20\x01(\tR\x0crelationKind\
+    \x12\x18\n\x07ordinal\x18\x03\x20\x01(\x05R\x07ordinal\x1a\xec\x01\n\rRe\
+    latedAnchor\x12+\n\x06anchor\x18\x01\x20\x01(\x0b2\x13.kythe.proto.Ancho\
+    rR\x06anchor\x12E\n\rmarked_source\x18\x05\x20\x01(\x0b2\x20.kythe.proto\
+    .common.MarkedSourceR\x0cmarkedSource\x12'\n\x04site\x18\x03\x20\x03(\
+    \x0b2\x13.kythe.proto.AnchorR\x04site\x12\x16\n\x06ticket\x18\x04\x20\
+    \x01(\tR\x06ticket\x12\x20\n\x0bspeculative\x18\x06\x20\x01(\x08R\x0bspe\
+    culativeJ\x04\x08\x02\x10\x03\x1a\x8c\x04\n\x11CrossReferenceSet\x12\x16\
+    \n\x06ticket\x18\x01\x20\x01(\tR\x06ticket\x12E\n\rmarked_source\x18\x08\
+    \x20\x01(\x0b2\x20.kythe.proto.common.MarkedSourceR\x0cmarkedSource\x12O\
+    \n\ndefinition\x18\x02\x20\x03(\x0b2/.kythe.proto.CrossReferencesReply.R\
+    elatedAnchorR\ndefinition\x12Q\n\x0bdeclaration\x18\x05\x20\x03(\x0b2/.k\
+    ythe.proto.CrossReferencesReply.RelatedAnchorR\x0bdeclaration\x12M\n\tre\
+    ference\x18\x03\x20\x03(\x0b2/.kythe.proto.CrossReferencesReply.RelatedA\
+    nchorR\treference\x12G\n\x06caller\x18\x06\x20\x03(\x0b2/.kythe.proto.Cr\
+    ossReferencesReply.RelatedAnchorR\x06caller\x12P\n\x0crelated_node\x18\n\
+    \x20\x03(\x0b2-.kythe.proto.CrossReferencesReply.RelatedNodeR\x0brelated\
+    NodeJ\x04\x08\x04\x10\x05J\x04\x08\x07\x10\x08\x1a\xa7\x04\n\x05Total\
+    \x12\x20\n\x0bdefinitions\x18\x01\x20\x01(\x03R\x0bdefinitions\x12\"\n\
+    \x0cdeclarations\x18\x02\x20\x01(\x03R\x0cdeclarations\x12\"\n\nreferenc\
+    es\x18\x03\x20\x01(\x03R\nreferencesB\x02\x18\x01\x12$\n\rdocumentation\
+    \x18\x04\x20\x01(\x03R\rdocumentation\x12\x18\n\x07callers\x18\x05\x20\
+    \x01(\x03R\x07callers\x12f\n\x11ref_edge_to_count\x18\x07\x20\x03(\x0b2;\
+    .kythe.proto.CrossReferencesReply.Total.RefEdgeToCountEntryR\x0erefEdgeT\
+    oCount\x12~\n\x19related_nodes_by_relation\x18\x06\x20\x03(\x0b2C.kythe.\
+    proto.CrossReferencesReply.Total.RelatedNodesByRelationEntryR\x16related\
+    NodesByRelation\x1aA\n\x13RefEdgeToCountEntry\x12\x10\n\x03key\x18\x01\
+    \x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\x03R\x05value:\
+    \x028\x01\x1aI\n\x1bRelatedNodesByRelationEntry\x12\x10\n\x03key\x18\x01\
+    \x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\x03R\x05value:\
+    \x028\x01\x1aw\n\x14CrossReferencesEntry\x12\x10\n\x03key\x18\x01\x20\
+    \x01(\tR\x03key\x12I\n\x05value\x18\x02\x20\x01(\x0b23.kythe.proto.Cross\
+    ReferencesReply.CrossReferenceSetR\x05value:\x028\x01\x1aV\n\nNodesEntry\
+    \x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x122\n\x05value\x18\x02\x20\
+    \x01(\x0b2\x1c.kythe.proto.common.NodeInfoR\x05value:\x028\x01\x1a[\n\
+    \x18DefinitionLocationsEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\
+    \x12)\n\x05value\x18\x02\x20\x01(\x0b2\x13.kythe.proto.AnchorR\x05value:\
+    \x028\x01\"\xdf\x01\n\x14DocumentationRequest\x12\x16\n\x06ticket\x18\
+    \x01\x20\x03(\tR\x06ticket\x12\x16\n\x06filter\x18\x02\x20\x03(\tR\x06fi\
+    lter\x12)\n\x10include_children\x18\x03\x20\x01(\x08R\x0fincludeChildren\
+    \x124\n\tworkspace\x18\x04\x20\x01(\x0b2\x16.kythe.proto.WorkspaceR\twor\
+    kspace\x126\n\x17patch_against_workspace\x18\x05\x20\x01(\x08R\x15patchA\
+    gainstWorkspace\"\xd5\x05\n\x12DocumentationReply\x12D\n\x08document\x18\
+    \x01\x20\x03(\x0b2(.kythe.proto.DocumentationReply.DocumentR\x08document\
+    \x12@\n\x05nodes\x18\x02\x20\x03(\x0b2*.kythe.proto.DocumentationReply.N\
+    odesEntryR\x05nodes\x12k\n\x14definition_locations\x18\x03\x20\x03(\x0b2\
+    8.kythe.proto.DocumentationReply.DefinitionLocationsEntryR\x13definition\
+    Locations\x12\x19\n\x08build_id\x18\x04\x20\x01(\tR\x07buildId\x1a\xf9\
+    \x01\n\x08Document\x12\x16\n\x06ticket\x18\x01\x20\x01(\tR\x06ticket\x12\
+    *\n\x04text\x18\x02\x20\x01(\x0b2\x16.kythe.proto.PrintableR\x04text\x12\
+    E\n\rmarked_source\x18\x08\x20\x01(\x0b2\x20.kythe.proto.common.MarkedSo\
+    urceR\x0cmarkedSource\x12D\n\x08children\x18\t\x20\x03(\x0b2(.kythe.prot\
+    o.DocumentationReply.DocumentR\x08childrenJ\x04\x08\x03\x10\x04J\x04\x08\
+    \x04\x10\x05J\x04\x08\x05\x10\x06J\x04\x08\x06\x10\x07J\x04\x08\x07\x10\
+    \x08\x1aV\n\nNodesEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x122\
+    \n\x05value\x18\x02\x20\x01(\x0b2\x1c.kythe.proto.common.NodeInfoR\x05va\
+    lue:\x028\x01\x1a[\n\x18DefinitionLocationsEntry\x12\x10\n\x03key\x18\
+    \x01\x20\x01(\tR\x03key\x12)\n\x05value\x18\x02\x20\x01(\x0b2\x13.kythe.\
+    proto.AnchorR\x05value:\x028\x01\"\x1d\n\tWorkspace\x12\x10\n\x03uri\x18\
+    \x01\x20\x01(\tR\x03uri*%\n\x0cSnippetsKind\x12\x08\n\x04NONE\x10\0\x12\
+    \x0b\n\x07DEFAULT\x10\x012\x92\x02\n\x0bXRefService\x12O\n\x0bDecoration\
+    s\x12\x1f.kythe.proto.DecorationsRequest\x1a\x1d.kythe.proto.Decorations\
+    Reply\"\0\x12[\n\x0fCrossReferences\x12#.kythe.proto.CrossReferencesRequ\
+    est\x1a!.kythe.proto.CrossReferencesReply\"\0\x12U\n\rDocumentation\x12!\
+    .kythe.proto.DocumentationRequest\x1a\x1f.kythe.proto.DocumentationReply\
+    \"\0BG\n\x1fcom.google.devtools.kythe.protoP\x01Z\"kythe.io/kythe/proto/\
+    xref_go_protob\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
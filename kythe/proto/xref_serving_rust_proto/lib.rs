@@ -0,0 +1,15 @@
+extern crate protobuf;
+pub mod timestamp;
+pub use timestamp::*;
+pub mod common;
+pub use common::*;
+pub mod storage;
+pub use storage::*;
+pub mod descriptor;
+pub use descriptor::*;
+pub mod schema;
+pub use schema::*;
+pub mod serving;
+pub use serving::*;
+pub mod xref_serving;
+pub use xref_serving::*;
\ No newline at end of file
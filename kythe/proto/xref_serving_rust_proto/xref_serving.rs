@@ -0,0 +1,5776 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `kythe/proto/xref_serving.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileDecorations {
+    // message fields
+    pub file: ::protobuf::SingularPtrField<super::storage::VName>,
+    // message oneof groups
+    pub entry: ::std::option::Option<FileDecorations_oneof_entry>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileDecorations {
+    fn default() -> &'a FileDecorations {
+        <FileDecorations as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum FileDecorations_oneof_entry {
+    index(FileDecorations_Index),
+    text(FileDecorations_Text),
+    target(FileDecorations_Target),
+    target_override(FileDecorations_TargetOverride),
+    target_node(FileDecorations_TargetNode),
+    target_definition(FileDecorations_TargetDefinition),
+    definition_location(FileDecorations_DefinitionLocation),
+    field_override(FileDecorations_Override),
+    diagnostic(FileDecorations_Diagnostic),
+}
+
+impl FileDecorations {
+    pub fn new() -> FileDecorations {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.VName file = 1;
+
+
+    pub fn get_file(&self) -> &super::storage::VName {
+        self.file.as_ref().unwrap_or_else(|| <super::storage::VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_file(&mut self) {
+        self.file.clear();
+    }
+
+    pub fn has_file(&self) -> bool {
+        self.file.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file(&mut self, v: super::storage::VName) {
+        self.file = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_file(&mut self) -> &mut super::storage::VName {
+        if self.file.is_none() {
+            self.file.set_default();
+        }
+        self.file.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_file(&mut self) -> super::storage::VName {
+        self.file.take().unwrap_or_else(|| super::storage::VName::new())
+    }
+
+    // .kythe.proto.serving.xrefs.FileDecorations.Index index = 2;
+
+
+    pub fn get_index(&self) -> &FileDecorations_Index {
+        match self.entry {
+            ::std::option::Option::Some(FileDecorations_oneof_entry::index(ref v)) => v,
+            _ => <FileDecorations_Index as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_index(&mut self) {
+        self.entry = ::std::option::Option::None;
+    }
+
+    pub fn has_index(&self) -> bool {
+        match self.entry {
+            ::std::option::Option::Some(FileDecorations_oneof_entry::index(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: FileDecorations_Index) {
+        self.entry = ::std::option::Option::Some(FileDecorations_oneof_entry::index(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_index(&mut self) -> &mut FileDecorations_Index {
+        if let ::std::option::Option::Some(FileDecorations_oneof_entry::index(_)) = self.entry {
+        } else {
+            self.entry = ::std::option::Option::Some(FileDecorations_oneof_entry::index(FileDecorations_Index::new()));
+        }
+        match self.entry {
+            ::std::option::Option::Some(FileDecorations_oneof_entry::index(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_index(&mut self) -> FileDecorations_Index {
+        if self.has_index() {
+            match self.entry.take() {
+                ::std::option::Option::Some(FileDecorations_oneof_entry::index(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FileDecorations_Index::new()
+        }
+    }
+
+    // .kythe.proto.serving.xrefs.FileDecorations.Text text = 3;
+
+
+    pub fn get_text(&self) -> &FileDecorations_Text {
+        match self.entry {
+            ::std::option::Option::Some(FileDecorations_oneof_entry::text(ref v)) => v,
+            _ => <FileDecorations_Text as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_text(&mut self) {
+        self.entry = ::std::option::Option::None;
+    }
+
+    pub fn has_text(&self) -> bool {
+        match self.entry {
+            ::std::option::Option::Some(FileDecorations_oneof_entry::text(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_text(&mut self, v: FileDecorations_Text) {
+        self.entry = ::std::option::Option::Some(FileDecorations_oneof_entry::text(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_text(&mut self) -> &mut FileDecorations_Text {
+        if let ::std::option::Option::Some(FileDecorations_oneof_entry::text(_)) = self.entry {
+        } else {
+            self.entry = ::std::option::Option::Some(FileDecorations_oneof_entry::text(FileDecorations_Text::new()));
+        }
+        match self.entry {
+            ::std::option::Option::Some(FileDecorations_oneof_entry::text(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_text(&mut self) -> FileDecorations_Text {
+        if self.has_text() {
+            match self.entry.take() {
+                ::std::option::Option::Some(FileDecorations_oneof_entry::text(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FileDecorations_Text::new()
+        }
+    }
+
+    // .kythe.proto.serving.xrefs.FileDecorations.Target target = 4;
+
+
+    pub fn get_target(&self) -> &FileDecorations_Target {
+        match self.entry {
+            ::std::option::Option::Some(FileDecorations_oneof_entry::target(ref v)) => v,
+            _ => <FileDecorations_Target as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_target(&mut self) {
+        self.entry = ::std::option::Option::None;
+    }
+
+    pub fn has_target(&self) -> bool {
+        match self.entry {
+            ::std::option::Option::Some(FileDecorations_oneof_entry::target(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: FileDecorations_Target) {
+        self.entry = ::std::option::Option::Some(FileDecorations_oneof_entry::target(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_target(&mut self) -> &mut FileDecorations_Target {
+        if let ::std::option::Option::Some(FileDecorations_oneof_entry::target(_)) = self.entry {
+        } else {
+            self.entry = ::std::option::Option::Some(FileDecorations_oneof_entry::target(FileDecorations_Target::new()));
+        }
+        match self.entry {
+            ::std::option::Option::Some(FileDecorations_oneof_entry::target(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> FileDecorations_Target {
+        if self.has_target() {
+            match self.entry.take() {
+                ::std::option::Option::Some(FileDecorations_oneof_entry::target(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FileDecorations_Target::new()
+        }
+    }
+
+    // .kythe.proto.serving.xrefs.FileDecorations.TargetOverride target_override = 5;
+
+
+    pub fn get_target_override(&self) -> &FileDecorations_TargetOverride {
+        match self.entry {
+            ::std::option::Option::Some(FileDecorations_oneof_entry::target_override(ref v)) => v,
+            _ => <FileDecorations_TargetOverride as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_target_override(&mut self) {
+        self.entry = ::std::option::Option::None;
+    }
+
+    pub fn has_target_override(&self) -> bool {
+        match self.entry {
+            ::std::option::Option::Some(FileDecorations_oneof_entry::target_override(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_override(&mut self, v: FileDecorations_TargetOverride) {
+        self.entry = ::std::option::Option::Some(FileDecorations_oneof_entry::target_override(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_target_override(&mut self) -> &mut FileDecorations_TargetOverride {
+        if let ::std::option::Option::Some(FileDecorations_oneof_entry::target_override(_)) = self.entry {
+        } else {
+            self.entry = ::std::option::Option::Some(FileDecorations_oneof_entry::target_override(FileDecorations_TargetOverride::new()));
+        }
+        match self.entry {
+            ::std::option::Option::Some(FileDecorations_oneof_entry::target_override(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_target_override(&mut self) -> FileDecorations_TargetOverride {
+        if self.has_target_override() {
+            match self.entry.take() {
+                ::std::option::Option::Some(FileDecorations_oneof_entry::target_override(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FileDecorations_TargetOverride::new()
+        }
+    }
+
+    // .kythe.proto.serving.xrefs.FileDecorations.TargetNode target_node = 6;
+
+
+    pub fn get_target_node(&self) -> &FileDecorations_TargetNode {
+        match self.entry {
+            ::std::option::Option::Some(FileDecorations_oneof_entry::target_node(ref v)) => v,
+            _ => <FileDecorations_TargetNode as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_target_node(&mut self) {
+        self.entry = ::std::option::Option::None;
+    }
+
+    pub fn has_target_node(&self) -> bool {
+        match self.entry {
+            ::std::option::Option::Some(FileDecorations_oneof_entry::target_node(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_node(&mut self, v: FileDecorations_TargetNode) {
+        self.entry = ::std::option::Option::Some(FileDecorations_oneof_entry::target_node(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_target_node(&mut self) -> &mut FileDecorations_TargetNode {
+        if let ::std::option::Option::Some(FileDecorations_oneof_entry::target_node(_)) = self.entry {
+        } else {
+            self.entry = ::std::option::Option::Some(FileDecorations_oneof_entry::target_node(FileDecorations_TargetNode::new()));
+        }
+        match self.entry {
+            ::std::option::Option::Some(FileDecorations_oneof_entry::target_node(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_target_node(&mut self) -> FileDecorations_TargetNode {
+        if self.has_target_node() {
+            match self.entry.take() {
+                ::std::option::Option::Some(FileDecorations_oneof_entry::target_node(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FileDecorations_TargetNode::new()
+        }
+    }
+
+    // .kythe.proto.serving.xrefs.FileDecorations.TargetDefinition target_definition = 7;
+
+
+    pub fn get_target_definition(&self) -> &FileDecorations_TargetDefinition {
+        match self.entry {
+            ::std::option::Option::Some(FileDecorations_oneof_entry::target_definition(ref v)) => v,
+            _ => <FileDecorations_TargetDefinition as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_target_definition(&mut self) {
+        self.entry = ::std::option::Option::None;
+    }
+
+    pub fn has_target_definition(&self) -> bool {
+        match self.entry {
+            ::std::option::Option::Some(FileDecorations_oneof_entry::target_definition(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_definition(&mut self, v: FileDecorations_TargetDefinition) {
+        self.entry = ::std::option::Option::Some(FileDecorations_oneof_entry::target_definition(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_target_definition(&mut self) -> &mut FileDecorations_TargetDefinition {
+        if let ::std::option::Option::Some(FileDecorations_oneof_entry::target_definition(_)) = self.entry {
+        } else {
+            self.entry = ::std::option::Option::Some(FileDecorations_oneof_entry::target_definition(FileDecorations_TargetDefinition::new()));
+        }
+        match self.entry {
+            ::std::option::Option::Some(FileDecorations_oneof_entry::target_definition(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_target_definition(&mut self) -> FileDecorations_TargetDefinition {
+        if self.has_target_definition() {
+            match self.entry.take() {
+                ::std::option::Option::Some(FileDecorations_oneof_entry::target_definition(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FileDecorations_TargetDefinition::new()
+        }
+    }
+
+    // .kythe.proto.serving.xrefs.FileDecorations.DefinitionLocation definition_location = 8;
+
+
+    pub fn get_definition_location(&self) -> &FileDecorations_DefinitionLocation {
+        match self.entry {
+            ::std::option::Option::Some(FileDecorations_oneof_entry::definition_location(ref v)) => v,
+            _ => <FileDecorations_DefinitionLocation as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_definition_location(&mut self) {
+        self.entry = ::std::option::Option::None;
+    }
+
+    pub fn has_definition_location(&self) -> bool {
+        match self.entry {
+            ::std::option::Option::Some(FileDecorations_oneof_entry::definition_location(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_definition_location(&mut self, v: FileDecorations_DefinitionLocation) {
+        self.entry = ::std::option::Option::Some(FileDecorations_oneof_entry::definition_location(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_definition_location(&mut self) -> &mut FileDecorations_DefinitionLocation {
+        if let ::std::option::Option::Some(FileDecorations_oneof_entry::definition_location(_)) = self.entry {
+        } else {
+            self.entry = ::std::option::Option::Some(FileDecorations_oneof_entry::definition_location(FileDecorations_DefinitionLocation::new()));
+        }
+        match self.entry {
+            ::std::option::Option::Some(FileDecorations_oneof_entry::definition_location(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_definition_location(&mut self) -> FileDecorations_DefinitionLocation {
+        if self.has_definition_location() {
+            match self.entry.take() {
+                ::std::option::Option::Some(FileDecorations_oneof_entry::definition_location(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FileDecorations_DefinitionLocation::new()
+        }
+    }
+
+    // .kythe.proto.serving.xrefs.FileDecorations.Override override = 9;
+
+
+    pub fn get_field_override(&self) -> &FileDecorations_Override {
+        match self.entry {
+            ::std::option::Option::Some(FileDecorations_oneof_entry::field_override(ref v)) => v,
+            _ => <FileDecorations_Override as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_field_override(&mut self) {
+        self.entry = ::std::option::Option::None;
+    }
+
+    pub fn has_field_override(&self) -> bool {
+        match self.entry {
+            ::std::option::Option::Some(FileDecorations_oneof_entry::field_override(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_override(&mut self, v: FileDecorations_Override) {
+        self.entry = ::std::option::Option::Some(FileDecorations_oneof_entry::field_override(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_field_override(&mut self) -> &mut FileDecorations_Override {
+        if let ::std::option::Option::Some(FileDecorations_oneof_entry::field_override(_)) = self.entry {
+        } else {
+            self.entry = ::std::option::Option::Some(FileDecorations_oneof_entry::field_override(FileDecorations_Override::new()));
+        }
+        match self.entry {
+            ::std::option::Option::Some(FileDecorations_oneof_entry::field_override(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_field_override(&mut self) -> FileDecorations_Override {
+        if self.has_field_override() {
+            match self.entry.take() {
+                ::std::option::Option::Some(FileDecorations_oneof_entry::field_override(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FileDecorations_Override::new()
+        }
+    }
+
+    // .kythe.proto.serving.xrefs.FileDecorations.Diagnostic diagnostic = 10;
+
+
+    pub fn get_diagnostic(&self) -> &FileDecorations_Diagnostic {
+        match self.entry {
+            ::std::option::Option::Some(FileDecorations_oneof_entry::diagnostic(ref v)) => v,
+            _ => <FileDecorations_Diagnostic as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_diagnostic(&mut self) {
+        self.entry = ::std::option::Option::None;
+    }
+
+    pub fn has_diagnostic(&self) -> bool {
+        match self.entry {
+            ::std::option::Option::Some(FileDecorations_oneof_entry::diagnostic(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_diagnostic(&mut self, v: FileDecorations_Diagnostic) {
+        self.entry = ::std::option::Option::Some(FileDecorations_oneof_entry::diagnostic(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_diagnostic(&mut self) -> &mut FileDecorations_Diagnostic {
+        if let ::std::option::Option::Some(FileDecorations_oneof_entry::diagnostic(_)) = self.entry {
+        } else {
+            self.entry = ::std::option::Option::Some(FileDecorations_oneof_entry::diagnostic(FileDecorations_Diagnostic::new()));
+        }
+        match self.entry {
+            ::std::option::Option::Some(FileDecorations_oneof_entry::diagnostic(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_diagnostic(&mut self) -> FileDecorations_Diagnostic {
+        if self.has_diagnostic() {
+            match self.entry.take() {
+                ::std::option::Option::Some(FileDecorations_oneof_entry::diagnostic(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FileDecorations_Diagnostic::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for FileDecorations {
+    fn is_initialized(&self) -> bool {
+        for v in &self.file {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        if let Some(FileDecorations_oneof_entry::index(ref v)) = self.entry {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(FileDecorations_oneof_entry::text(ref v)) = self.entry {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(FileDecorations_oneof_entry::target(ref v)) = self.entry {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(FileDecorations_oneof_entry::target_override(ref v)) = self.entry {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(FileDecorations_oneof_entry::target_node(ref v)) = self.entry {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(FileDecorations_oneof_entry::target_definition(ref v)) = self.entry {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(FileDecorations_oneof_entry::definition_location(ref v)) = self.entry {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(FileDecorations_oneof_entry::field_override(ref v)) = self.entry {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(FileDecorations_oneof_entry::diagnostic(ref v)) = self.entry {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.file)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.entry = ::std::option::Option::Some(FileDecorations_oneof_entry::index(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.entry = ::std::option::Option::Some(FileDecorations_oneof_entry::text(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.entry = ::std::option::Option::Some(FileDecorations_oneof_entry::target(is.read_message()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.entry = ::std::option::Option::Some(FileDecorations_oneof_entry::target_override(is.read_message()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.entry = ::std::option::Option::Some(FileDecorations_oneof_entry::target_node(is.read_message()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.entry = ::std::option::Option::Some(FileDecorations_oneof_entry::target_definition(is.read_message()?));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.entry = ::std::option::Option::Some(FileDecorations_oneof_entry::definition_location(is.read_message()?));
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.entry = ::std::option::Option::Some(FileDecorations_oneof_entry::field_override(is.read_message()?));
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.entry = ::std::option::Option::Some(FileDecorations_oneof_entry::diagnostic(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.file.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let ::std::option::Option::Some(ref v) = self.entry {
+            match v {
+                &FileDecorations_oneof_entry::index(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &FileDecorations_oneof_entry::text(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &FileDecorations_oneof_entry::target(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &FileDecorations_oneof_entry::target_override(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &FileDecorations_oneof_entry::target_node(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &FileDecorations_oneof_entry::target_definition(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &FileDecorations_oneof_entry::definition_location(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &FileDecorations_oneof_entry::field_override(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &FileDecorations_oneof_entry::diagnostic(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.file.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.entry {
+            match v {
+                &FileDecorations_oneof_entry::index(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &FileDecorations_oneof_entry::text(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &FileDecorations_oneof_entry::target(ref v) => {
+                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &FileDecorations_oneof_entry::target_override(ref v) => {
+                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &FileDecorations_oneof_entry::target_node(ref v) => {
+                    os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &FileDecorations_oneof_entry::target_definition(ref v) => {
+                    os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &FileDecorations_oneof_entry::definition_location(ref v) => {
+                    os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &FileDecorations_oneof_entry::field_override(ref v) => {
+                    os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &FileDecorations_oneof_entry::diagnostic(ref v) => {
+                    os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FileDecorations {
+        FileDecorations::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::storage::VName>>(
+                "file",
+                |m: &FileDecorations| { &m.file },
+                |m: &mut FileDecorations| { &mut m.file },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, FileDecorations_Index>(
+                "index",
+                FileDecorations::has_index,
+                FileDecorations::get_index,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, FileDecorations_Text>(
+                "text",
+                FileDecorations::has_text,
+                FileDecorations::get_text,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, FileDecorations_Target>(
+                "target",
+                FileDecorations::has_target,
+                FileDecorations::get_target,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, FileDecorations_TargetOverride>(
+                "target_override",
+                FileDecorations::has_target_override,
+                FileDecorations::get_target_override,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, FileDecorations_TargetNode>(
+                "target_node",
+                FileDecorations::has_target_node,
+                FileDecorations::get_target_node,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, FileDecorations_TargetDefinition>(
+                "target_definition",
+                FileDecorations::has_target_definition,
+                FileDecorations::get_target_definition,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, FileDecorations_DefinitionLocation>(
+                "definition_location",
+                FileDecorations::has_definition_location,
+                FileDecorations::get_definition_location,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, FileDecorations_Override>(
+                "override",
+                FileDecorations::has_field_override,
+                FileDecorations::get_field_override,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, FileDecorations_Diagnostic>(
+                "diagnostic",
+                FileDecorations::has_diagnostic,
+                FileDecorations::get_diagnostic,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FileDecorations>(
+                "FileDecorations",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FileDecorations {
+        static instance: ::protobuf::rt::LazyV2<FileDecorations> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FileDecorations::new)
+    }
+}
+
+impl ::protobuf::Clear for FileDecorations {
+    fn clear(&mut self) {
+        self.file.clear();
+        self.entry = ::std::option::Option::None;
+        self.entry = ::std::option::Option::None;
+        self.entry = ::std::option::Option::None;
+        self.entry = ::std::option::Option::None;
+        self.entry = ::std::option::Option::None;
+        self.entry = ::std::option::Option::None;
+        self.entry = ::std::option::Option::None;
+        self.entry = ::std::option::Option::None;
+        self.entry = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileDecorations {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileDecorations {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileDecorations_Index {
+    // message fields
+    pub text_encoding: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileDecorations_Index {
+    fn default() -> &'a FileDecorations_Index {
+        <FileDecorations_Index as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FileDecorations_Index {
+    pub fn new() -> FileDecorations_Index {
+        ::std::default::Default::default()
+    }
+
+    // string text_encoding = 1;
+
+
+    pub fn get_text_encoding(&self) -> &str {
+        &self.text_encoding
+    }
+    pub fn clear_text_encoding(&mut self) {
+        self.text_encoding.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_text_encoding(&mut self, v: ::std::string::String) {
+        self.text_encoding = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_text_encoding(&mut self) -> &mut ::std::string::String {
+        &mut self.text_encoding
+    }
+
+    // Take field
+    pub fn take_text_encoding(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.text_encoding, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for FileDecorations_Index {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.text_encoding)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.text_encoding.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.text_encoding);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.text_encoding.is_empty() {
+            os.write_string(1, &self.text_encoding)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FileDecorations_Index {
+        FileDecorations_Index::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "text_encoding",
+                |m: &FileDecorations_Index| { &m.text_encoding },
+                |m: &mut FileDecorations_Index| { &mut m.text_encoding },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FileDecorations_Index>(
+                "FileDecorations.Index",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FileDecorations_Index {
+        static instance: ::protobuf::rt::LazyV2<FileDecorations_Index> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FileDecorations_Index::new)
+    }
+}
+
+impl ::protobuf::Clear for FileDecorations_Index {
+    fn clear(&mut self) {
+        self.text_encoding.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileDecorations_Index {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileDecorations_Index {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileDecorations_Text {
+    // message fields
+    pub start_offset: i32,
+    pub end_offset: i32,
+    pub text: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileDecorations_Text {
+    fn default() -> &'a FileDecorations_Text {
+        <FileDecorations_Text as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FileDecorations_Text {
+    pub fn new() -> FileDecorations_Text {
+        ::std::default::Default::default()
+    }
+
+    // int32 start_offset = 1;
+
+
+    pub fn get_start_offset(&self) -> i32 {
+        self.start_offset
+    }
+    pub fn clear_start_offset(&mut self) {
+        self.start_offset = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start_offset(&mut self, v: i32) {
+        self.start_offset = v;
+    }
+
+    // int32 end_offset = 2;
+
+
+    pub fn get_end_offset(&self) -> i32 {
+        self.end_offset
+    }
+    pub fn clear_end_offset(&mut self) {
+        self.end_offset = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end_offset(&mut self, v: i32) {
+        self.end_offset = v;
+    }
+
+    // bytes text = 3;
+
+
+    pub fn get_text(&self) -> &[u8] {
+        &self.text
+    }
+    pub fn clear_text(&mut self) {
+        self.text.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_text(&mut self, v: ::std::vec::Vec<u8>) {
+        self.text = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_text(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.text
+    }
+
+    // Take field
+    pub fn take_text(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.text, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for FileDecorations_Text {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.start_offset = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.end_offset = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.text)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.start_offset != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.start_offset, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.end_offset != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.end_offset, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.text.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.text);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.start_offset != 0 {
+            os.write_int32(1, self.start_offset)?;
+        }
+        if self.end_offset != 0 {
+            os.write_int32(2, self.end_offset)?;
+        }
+        if !self.text.is_empty() {
+            os.write_bytes(3, &self.text)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FileDecorations_Text {
+        FileDecorations_Text::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "start_offset",
+                |m: &FileDecorations_Text| { &m.start_offset },
+                |m: &mut FileDecorations_Text| { &mut m.start_offset },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "end_offset",
+                |m: &FileDecorations_Text| { &m.end_offset },
+                |m: &mut FileDecorations_Text| { &mut m.end_offset },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "text",
+                |m: &FileDecorations_Text| { &m.text },
+                |m: &mut FileDecorations_Text| { &mut m.text },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FileDecorations_Text>(
+                "FileDecorations.Text",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FileDecorations_Text {
+        static instance: ::protobuf::rt::LazyV2<FileDecorations_Text> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FileDecorations_Text::new)
+    }
+}
+
+impl ::protobuf::Clear for FileDecorations_Text {
+    fn clear(&mut self) {
+        self.start_offset = 0;
+        self.end_offset = 0;
+        self.text.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileDecorations_Text {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileDecorations_Text {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileDecorations_Target {
+    // message fields
+    pub start_offset: i32,
+    pub end_offset: i32,
+    pub target: ::protobuf::SingularPtrField<super::storage::VName>,
+    pub build_config: ::std::string::String,
+    // message oneof groups
+    pub kind: ::std::option::Option<FileDecorations_Target_oneof_kind>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileDecorations_Target {
+    fn default() -> &'a FileDecorations_Target {
+        <FileDecorations_Target as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum FileDecorations_Target_oneof_kind {
+    kythe_kind(super::schema::EdgeKind),
+    generic_kind(::std::string::String),
+}
+
+impl FileDecorations_Target {
+    pub fn new() -> FileDecorations_Target {
+        ::std::default::Default::default()
+    }
+
+    // int32 start_offset = 1;
+
+
+    pub fn get_start_offset(&self) -> i32 {
+        self.start_offset
+    }
+    pub fn clear_start_offset(&mut self) {
+        self.start_offset = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start_offset(&mut self, v: i32) {
+        self.start_offset = v;
+    }
+
+    // int32 end_offset = 2;
+
+
+    pub fn get_end_offset(&self) -> i32 {
+        self.end_offset
+    }
+    pub fn clear_end_offset(&mut self) {
+        self.end_offset = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end_offset(&mut self, v: i32) {
+        self.end_offset = v;
+    }
+
+    // .kythe.proto.schema.EdgeKind kythe_kind = 3;
+
+
+    pub fn get_kythe_kind(&self) -> super::schema::EdgeKind {
+        match self.kind {
+            ::std::option::Option::Some(FileDecorations_Target_oneof_kind::kythe_kind(v)) => v,
+            _ => super::schema::EdgeKind::UNKNOWN_EDGE_KIND,
+        }
+    }
+    pub fn clear_kythe_kind(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_kythe_kind(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(FileDecorations_Target_oneof_kind::kythe_kind(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kythe_kind(&mut self, v: super::schema::EdgeKind) {
+        self.kind = ::std::option::Option::Some(FileDecorations_Target_oneof_kind::kythe_kind(v))
+    }
+
+    // string generic_kind = 4;
+
+
+    pub fn get_generic_kind(&self) -> &str {
+        match self.kind {
+            ::std::option::Option::Some(FileDecorations_Target_oneof_kind::generic_kind(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_generic_kind(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_generic_kind(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(FileDecorations_Target_oneof_kind::generic_kind(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_generic_kind(&mut self, v: ::std::string::String) {
+        self.kind = ::std::option::Option::Some(FileDecorations_Target_oneof_kind::generic_kind(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_generic_kind(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(FileDecorations_Target_oneof_kind::generic_kind(_)) = self.kind {
+        } else {
+            self.kind = ::std::option::Option::Some(FileDecorations_Target_oneof_kind::generic_kind(::std::string::String::new()));
+        }
+        match self.kind {
+            ::std::option::Option::Some(FileDecorations_Target_oneof_kind::generic_kind(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_generic_kind(&mut self) -> ::std::string::String {
+        if self.has_generic_kind() {
+            match self.kind.take() {
+                ::std::option::Option::Some(FileDecorations_Target_oneof_kind::generic_kind(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // .kythe.proto.VName target = 5;
+
+
+    pub fn get_target(&self) -> &super::storage::VName {
+        self.target.as_ref().unwrap_or_else(|| <super::storage::VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: super::storage::VName) {
+        self.target = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut super::storage::VName {
+        if self.target.is_none() {
+            self.target.set_default();
+        }
+        self.target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> super::storage::VName {
+        self.target.take().unwrap_or_else(|| super::storage::VName::new())
+    }
+
+    // string build_config = 6;
+
+
+    pub fn get_build_config(&self) -> &str {
+        &self.build_config
+    }
+    pub fn clear_build_config(&mut self) {
+        self.build_config.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_build_config(&mut self, v: ::std::string::String) {
+        self.build_config = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_build_config(&mut self) -> &mut ::std::string::String {
+        &mut self.build_config
+    }
+
+    // Take field
+    pub fn take_build_config(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.build_config, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for FileDecorations_Target {
+    fn is_initialized(&self) -> bool {
+        for v in &self.target {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.start_offset = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.end_offset = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.kind = ::std::option::Option::Some(FileDecorations_Target_oneof_kind::kythe_kind(is.read_enum()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.kind = ::std::option::Option::Some(FileDecorations_Target_oneof_kind::generic_kind(is.read_string()?));
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.target)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.build_config)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.start_offset != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.start_offset, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.end_offset != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.end_offset, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.build_config.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.build_config);
+        }
+        if let ::std::option::Option::Some(ref v) = self.kind {
+            match v {
+                &FileDecorations_Target_oneof_kind::kythe_kind(v) => {
+                    my_size += ::protobuf::rt::enum_size(3, v);
+                },
+                &FileDecorations_Target_oneof_kind::generic_kind(ref v) => {
+                    my_size += ::protobuf::rt::string_size(4, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.start_offset != 0 {
+            os.write_int32(1, self.start_offset)?;
+        }
+        if self.end_offset != 0 {
+            os.write_int32(2, self.end_offset)?;
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.build_config.is_empty() {
+            os.write_string(6, &self.build_config)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.kind {
+            match v {
+                &FileDecorations_Target_oneof_kind::kythe_kind(v) => {
+                    os.write_enum(3, ::protobuf::ProtobufEnum::value(&v))?;
+                },
+                &FileDecorations_Target_oneof_kind::generic_kind(ref v) => {
+                    os.write_string(4, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FileDecorations_Target {
+        FileDecorations_Target::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "start_offset",
+                |m: &FileDecorations_Target| { &m.start_offset },
+                |m: &mut FileDecorations_Target| { &mut m.start_offset },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "end_offset",
+                |m: &FileDecorations_Target| { &m.end_offset },
+                |m: &mut FileDecorations_Target| { &mut m.end_offset },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor::<_, super::schema::EdgeKind>(
+                "kythe_kind",
+                FileDecorations_Target::has_kythe_kind,
+                FileDecorations_Target::get_kythe_kind,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "generic_kind",
+                FileDecorations_Target::has_generic_kind,
+                FileDecorations_Target::get_generic_kind,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::storage::VName>>(
+                "target",
+                |m: &FileDecorations_Target| { &m.target },
+                |m: &mut FileDecorations_Target| { &mut m.target },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "build_config",
+                |m: &FileDecorations_Target| { &m.build_config },
+                |m: &mut FileDecorations_Target| { &mut m.build_config },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FileDecorations_Target>(
+                "FileDecorations.Target",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FileDecorations_Target {
+        static instance: ::protobuf::rt::LazyV2<FileDecorations_Target> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FileDecorations_Target::new)
+    }
+}
+
+impl ::protobuf::Clear for FileDecorations_Target {
+    fn clear(&mut self) {
+        self.start_offset = 0;
+        self.end_offset = 0;
+        self.kind = ::std::option::Option::None;
+        self.kind = ::std::option::Option::None;
+        self.target.clear();
+        self.build_config.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileDecorations_Target {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileDecorations_Target {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileDecorations_TargetOverride {
+    // message fields
+    pub overridden: ::protobuf::SingularPtrField<super::storage::VName>,
+    pub kind: super::serving::FileDecorations_Override_Kind,
+    pub overriding: ::protobuf::SingularPtrField<super::storage::VName>,
+    pub overriding_definition: ::protobuf::SingularPtrField<super::serving::ExpandedAnchor>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileDecorations_TargetOverride {
+    fn default() -> &'a FileDecorations_TargetOverride {
+        <FileDecorations_TargetOverride as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FileDecorations_TargetOverride {
+    pub fn new() -> FileDecorations_TargetOverride {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.VName overridden = 1;
+
+
+    pub fn get_overridden(&self) -> &super::storage::VName {
+        self.overridden.as_ref().unwrap_or_else(|| <super::storage::VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_overridden(&mut self) {
+        self.overridden.clear();
+    }
+
+    pub fn has_overridden(&self) -> bool {
+        self.overridden.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_overridden(&mut self, v: super::storage::VName) {
+        self.overridden = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_overridden(&mut self) -> &mut super::storage::VName {
+        if self.overridden.is_none() {
+            self.overridden.set_default();
+        }
+        self.overridden.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_overridden(&mut self) -> super::storage::VName {
+        self.overridden.take().unwrap_or_else(|| super::storage::VName::new())
+    }
+
+    // .kythe.proto.serving.FileDecorations.Override.Kind kind = 2;
+
+
+    pub fn get_kind(&self) -> super::serving::FileDecorations_Override_Kind {
+        self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind = super::serving::FileDecorations_Override_Kind::OVERRIDES;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: super::serving::FileDecorations_Override_Kind) {
+        self.kind = v;
+    }
+
+    // .kythe.proto.VName overriding = 3;
+
+
+    pub fn get_overriding(&self) -> &super::storage::VName {
+        self.overriding.as_ref().unwrap_or_else(|| <super::storage::VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_overriding(&mut self) {
+        self.overriding.clear();
+    }
+
+    pub fn has_overriding(&self) -> bool {
+        self.overriding.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_overriding(&mut self, v: super::storage::VName) {
+        self.overriding = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_overriding(&mut self) -> &mut super::storage::VName {
+        if self.overriding.is_none() {
+            self.overriding.set_default();
+        }
+        self.overriding.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_overriding(&mut self) -> super::storage::VName {
+        self.overriding.take().unwrap_or_else(|| super::storage::VName::new())
+    }
+
+    // .kythe.proto.serving.ExpandedAnchor overriding_definition = 4;
+
+
+    pub fn get_overriding_definition(&self) -> &super::serving::ExpandedAnchor {
+        self.overriding_definition.as_ref().unwrap_or_else(|| <super::serving::ExpandedAnchor as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_overriding_definition(&mut self) {
+        self.overriding_definition.clear();
+    }
+
+    pub fn has_overriding_definition(&self) -> bool {
+        self.overriding_definition.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_overriding_definition(&mut self, v: super::serving::ExpandedAnchor) {
+        self.overriding_definition = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_overriding_definition(&mut self) -> &mut super::serving::ExpandedAnchor {
+        if self.overriding_definition.is_none() {
+            self.overriding_definition.set_default();
+        }
+        self.overriding_definition.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_overriding_definition(&mut self) -> super::serving::ExpandedAnchor {
+        self.overriding_definition.take().unwrap_or_else(|| super::serving::ExpandedAnchor::new())
+    }
+}
+
+impl ::protobuf::Message for FileDecorations_TargetOverride {
+    fn is_initialized(&self) -> bool {
+        for v in &self.overridden {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.overriding {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.overriding_definition {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.overridden)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.kind, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.overriding)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.overriding_definition)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.overridden.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.kind != super::serving::FileDecorations_Override_Kind::OVERRIDES {
+            my_size += ::protobuf::rt::enum_size(2, self.kind);
+        }
+        if let Some(ref v) = self.overriding.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.overriding_definition.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.overridden.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.kind != super::serving::FileDecorations_Override_Kind::OVERRIDES {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.kind))?;
+        }
+        if let Some(ref v) = self.overriding.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.overriding_definition.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FileDecorations_TargetOverride {
+        FileDecorations_TargetOverride::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::storage::VName>>(
+                "overridden",
+                |m: &FileDecorations_TargetOverride| { &m.overridden },
+                |m: &mut FileDecorations_TargetOverride| { &mut m.overridden },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::serving::FileDecorations_Override_Kind>>(
+                "kind",
+                |m: &FileDecorations_TargetOverride| { &m.kind },
+                |m: &mut FileDecorations_TargetOverride| { &mut m.kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::storage::VName>>(
+                "overriding",
+                |m: &FileDecorations_TargetOverride| { &m.overriding },
+                |m: &mut FileDecorations_TargetOverride| { &mut m.overriding },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::serving::ExpandedAnchor>>(
+                "overriding_definition",
+                |m: &FileDecorations_TargetOverride| { &m.overriding_definition },
+                |m: &mut FileDecorations_TargetOverride| { &mut m.overriding_definition },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FileDecorations_TargetOverride>(
+                "FileDecorations.TargetOverride",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FileDecorations_TargetOverride {
+        static instance: ::protobuf::rt::LazyV2<FileDecorations_TargetOverride> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FileDecorations_TargetOverride::new)
+    }
+}
+
+impl ::protobuf::Clear for FileDecorations_TargetOverride {
+    fn clear(&mut self) {
+        self.overridden.clear();
+        self.kind = super::serving::FileDecorations_Override_Kind::OVERRIDES;
+        self.overriding.clear();
+        self.overriding_definition.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileDecorations_TargetOverride {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileDecorations_TargetOverride {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileDecorations_TargetNode {
+    // message fields
+    pub node: ::protobuf::SingularPtrField<super::schema::Node>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileDecorations_TargetNode {
+    fn default() -> &'a FileDecorations_TargetNode {
+        <FileDecorations_TargetNode as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FileDecorations_TargetNode {
+    pub fn new() -> FileDecorations_TargetNode {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.schema.Node node = 1;
+
+
+    pub fn get_node(&self) -> &super::schema::Node {
+        self.node.as_ref().unwrap_or_else(|| <super::schema::Node as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_node(&mut self) {
+        self.node.clear();
+    }
+
+    pub fn has_node(&self) -> bool {
+        self.node.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_node(&mut self, v: super::schema::Node) {
+        self.node = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_node(&mut self) -> &mut super::schema::Node {
+        if self.node.is_none() {
+            self.node.set_default();
+        }
+        self.node.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_node(&mut self) -> super::schema::Node {
+        self.node.take().unwrap_or_else(|| super::schema::Node::new())
+    }
+}
+
+impl ::protobuf::Message for FileDecorations_TargetNode {
+    fn is_initialized(&self) -> bool {
+        for v in &self.node {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.node)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.node.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.node.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FileDecorations_TargetNode {
+        FileDecorations_TargetNode::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::schema::Node>>(
+                "node",
+                |m: &FileDecorations_TargetNode| { &m.node },
+                |m: &mut FileDecorations_TargetNode| { &mut m.node },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FileDecorations_TargetNode>(
+                "FileDecorations.TargetNode",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FileDecorations_TargetNode {
+        static instance: ::protobuf::rt::LazyV2<FileDecorations_TargetNode> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FileDecorations_TargetNode::new)
+    }
+}
+
+impl ::protobuf::Clear for FileDecorations_TargetNode {
+    fn clear(&mut self) {
+        self.node.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileDecorations_TargetNode {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileDecorations_TargetNode {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileDecorations_TargetDefinition {
+    // message fields
+    pub target: ::protobuf::SingularPtrField<super::storage::VName>,
+    pub definition: ::protobuf::SingularPtrField<super::storage::VName>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileDecorations_TargetDefinition {
+    fn default() -> &'a FileDecorations_TargetDefinition {
+        <FileDecorations_TargetDefinition as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FileDecorations_TargetDefinition {
+    pub fn new() -> FileDecorations_TargetDefinition {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.VName target = 1;
+
+
+    pub fn get_target(&self) -> &super::storage::VName {
+        self.target.as_ref().unwrap_or_else(|| <super::storage::VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: super::storage::VName) {
+        self.target = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut super::storage::VName {
+        if self.target.is_none() {
+            self.target.set_default();
+        }
+        self.target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> super::storage::VName {
+        self.target.take().unwrap_or_else(|| super::storage::VName::new())
+    }
+
+    // .kythe.proto.VName definition = 2;
+
+
+    pub fn get_definition(&self) -> &super::storage::VName {
+        self.definition.as_ref().unwrap_or_else(|| <super::storage::VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_definition(&mut self) {
+        self.definition.clear();
+    }
+
+    pub fn has_definition(&self) -> bool {
+        self.definition.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_definition(&mut self, v: super::storage::VName) {
+        self.definition = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_definition(&mut self) -> &mut super::storage::VName {
+        if self.definition.is_none() {
+            self.definition.set_default();
+        }
+        self.definition.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_definition(&mut self) -> super::storage::VName {
+        self.definition.take().unwrap_or_else(|| super::storage::VName::new())
+    }
+}
+
+impl ::protobuf::Message for FileDecorations_TargetDefinition {
+    fn is_initialized(&self) -> bool {
+        for v in &self.target {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.definition {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.target)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.definition)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.target.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.definition.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.target.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.definition.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FileDecorations_TargetDefinition {
+        FileDecorations_TargetDefinition::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::storage::VName>>(
+                "target",
+                |m: &FileDecorations_TargetDefinition| { &m.target },
+                |m: &mut FileDecorations_TargetDefinition| { &mut m.target },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::storage::VName>>(
+                "definition",
+                |m: &FileDecorations_TargetDefinition| { &m.definition },
+                |m: &mut FileDecorations_TargetDefinition| { &mut m.definition },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FileDecorations_TargetDefinition>(
+                "FileDecorations.TargetDefinition",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FileDecorations_TargetDefinition {
+        static instance: ::protobuf::rt::LazyV2<FileDecorations_TargetDefinition> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FileDecorations_TargetDefinition::new)
+    }
+}
+
+impl ::protobuf::Clear for FileDecorations_TargetDefinition {
+    fn clear(&mut self) {
+        self.target.clear();
+        self.definition.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileDecorations_TargetDefinition {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileDecorations_TargetDefinition {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileDecorations_DefinitionLocation {
+    // message fields
+    pub location: ::protobuf::SingularPtrField<super::serving::ExpandedAnchor>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileDecorations_DefinitionLocation {
+    fn default() -> &'a FileDecorations_DefinitionLocation {
+        <FileDecorations_DefinitionLocation as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FileDecorations_DefinitionLocation {
+    pub fn new() -> FileDecorations_DefinitionLocation {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.serving.ExpandedAnchor location = 1;
+
+
+    pub fn get_location(&self) -> &super::serving::ExpandedAnchor {
+        self.location.as_ref().unwrap_or_else(|| <super::serving::ExpandedAnchor as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_location(&mut self) {
+        self.location.clear();
+    }
+
+    pub fn has_location(&self) -> bool {
+        self.location.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_location(&mut self, v: super::serving::ExpandedAnchor) {
+        self.location = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_location(&mut self) -> &mut super::serving::ExpandedAnchor {
+        if self.location.is_none() {
+            self.location.set_default();
+        }
+        self.location.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_location(&mut self) -> super::serving::ExpandedAnchor {
+        self.location.take().unwrap_or_else(|| super::serving::ExpandedAnchor::new())
+    }
+}
+
+impl ::protobuf::Message for FileDecorations_DefinitionLocation {
+    fn is_initialized(&self) -> bool {
+        for v in &self.location {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.location)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.location.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.location.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FileDecorations_DefinitionLocation {
+        FileDecorations_DefinitionLocation::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::serving::ExpandedAnchor>>(
+                "location",
+                |m: &FileDecorations_DefinitionLocation| { &m.location },
+                |m: &mut FileDecorations_DefinitionLocation| { &mut m.location },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FileDecorations_DefinitionLocation>(
+                "FileDecorations.DefinitionLocation",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FileDecorations_DefinitionLocation {
+        static instance: ::protobuf::rt::LazyV2<FileDecorations_DefinitionLocation> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FileDecorations_DefinitionLocation::new)
+    }
+}
+
+impl ::protobuf::Clear for FileDecorations_DefinitionLocation {
+    fn clear(&mut self) {
+        self.location.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileDecorations_DefinitionLocation {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileDecorations_DefinitionLocation {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileDecorations_Override {
+    // message fields
+    pub field_override: ::protobuf::SingularPtrField<super::storage::VName>,
+    pub marked_source: ::protobuf::SingularPtrField<super::common::MarkedSource>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileDecorations_Override {
+    fn default() -> &'a FileDecorations_Override {
+        <FileDecorations_Override as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FileDecorations_Override {
+    pub fn new() -> FileDecorations_Override {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.VName override = 1;
+
+
+    pub fn get_field_override(&self) -> &super::storage::VName {
+        self.field_override.as_ref().unwrap_or_else(|| <super::storage::VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_field_override(&mut self) {
+        self.field_override.clear();
+    }
+
+    pub fn has_field_override(&self) -> bool {
+        self.field_override.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_override(&mut self, v: super::storage::VName) {
+        self.field_override = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_field_override(&mut self) -> &mut super::storage::VName {
+        if self.field_override.is_none() {
+            self.field_override.set_default();
+        }
+        self.field_override.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_field_override(&mut self) -> super::storage::VName {
+        self.field_override.take().unwrap_or_else(|| super::storage::VName::new())
+    }
+
+    // .kythe.proto.common.MarkedSource marked_source = 2;
+
+
+    pub fn get_marked_source(&self) -> &super::common::MarkedSource {
+        self.marked_source.as_ref().unwrap_or_else(|| <super::common::MarkedSource as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_marked_source(&mut self) {
+        self.marked_source.clear();
+    }
+
+    pub fn has_marked_source(&self) -> bool {
+        self.marked_source.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_marked_source(&mut self, v: super::common::MarkedSource) {
+        self.marked_source = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_marked_source(&mut self) -> &mut super::common::MarkedSource {
+        if self.marked_source.is_none() {
+            self.marked_source.set_default();
+        }
+        self.marked_source.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_marked_source(&mut self) -> super::common::MarkedSource {
+        self.marked_source.take().unwrap_or_else(|| super::common::MarkedSource::new())
+    }
+}
+
+impl ::protobuf::Message for FileDecorations_Override {
+    fn is_initialized(&self) -> bool {
+        for v in &self.field_override {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.marked_source {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.field_override)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.marked_source)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.field_override.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.marked_source.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.field_override.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.marked_source.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FileDecorations_Override {
+        FileDecorations_Override::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::storage::VName>>(
+                "override",
+                |m: &FileDecorations_Override| { &m.field_override },
+                |m: &mut FileDecorations_Override| { &mut m.field_override },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::MarkedSource>>(
+                "marked_source",
+                |m: &FileDecorations_Override| { &m.marked_source },
+                |m: &mut FileDecorations_Override| { &mut m.marked_source },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FileDecorations_Override>(
+                "FileDecorations.Override",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FileDecorations_Override {
+        static instance: ::protobuf::rt::LazyV2<FileDecorations_Override> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FileDecorations_Override::new)
+    }
+}
+
+impl ::protobuf::Clear for FileDecorations_Override {
+    fn clear(&mut self) {
+        self.field_override.clear();
+        self.marked_source.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileDecorations_Override {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileDecorations_Override {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileDecorations_Diagnostic {
+    // message fields
+    pub diagnostic: ::protobuf::SingularPtrField<super::common::Diagnostic>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileDecorations_Diagnostic {
+    fn default() -> &'a FileDecorations_Diagnostic {
+        <FileDecorations_Diagnostic as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FileDecorations_Diagnostic {
+    pub fn new() -> FileDecorations_Diagnostic {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.common.Diagnostic diagnostic = 1;
+
+
+    pub fn get_diagnostic(&self) -> &super::common::Diagnostic {
+        self.diagnostic.as_ref().unwrap_or_else(|| <super::common::Diagnostic as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_diagnostic(&mut self) {
+        self.diagnostic.clear();
+    }
+
+    pub fn has_diagnostic(&self) -> bool {
+        self.diagnostic.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_diagnostic(&mut self, v: super::common::Diagnostic) {
+        self.diagnostic = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_diagnostic(&mut self) -> &mut super::common::Diagnostic {
+        if self.diagnostic.is_none() {
+            self.diagnostic.set_default();
+        }
+        self.diagnostic.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_diagnostic(&mut self) -> super::common::Diagnostic {
+        self.diagnostic.take().unwrap_or_else(|| super::common::Diagnostic::new())
+    }
+}
+
+impl ::protobuf::Message for FileDecorations_Diagnostic {
+    fn is_initialized(&self) -> bool {
+        for v in &self.diagnostic {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.diagnostic)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.diagnostic.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.diagnostic.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FileDecorations_Diagnostic {
+        FileDecorations_Diagnostic::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::Diagnostic>>(
+                "diagnostic",
+                |m: &FileDecorations_Diagnostic| { &m.diagnostic },
+                |m: &mut FileDecorations_Diagnostic| { &mut m.diagnostic },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FileDecorations_Diagnostic>(
+                "FileDecorations.Diagnostic",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FileDecorations_Diagnostic {
+        static instance: ::protobuf::rt::LazyV2<FileDecorations_Diagnostic> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FileDecorations_Diagnostic::new)
+    }
+}
+
+impl ::protobuf::Clear for FileDecorations_Diagnostic {
+    fn clear(&mut self) {
+        self.diagnostic.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileDecorations_Diagnostic {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileDecorations_Diagnostic {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CrossReferences {
+    // message fields
+    pub source: ::protobuf::SingularPtrField<super::storage::VName>,
+    // message oneof groups
+    pub entry: ::std::option::Option<CrossReferences_oneof_entry>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CrossReferences {
+    fn default() -> &'a CrossReferences {
+        <CrossReferences as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum CrossReferences_oneof_entry {
+    index(CrossReferences_Index),
+    reference(CrossReferences_Reference),
+    relation(CrossReferences_Relation),
+    caller(CrossReferences_Caller),
+    callsite(CrossReferences_Callsite),
+    related_node(CrossReferences_RelatedNode),
+    node_definition(CrossReferences_NodeDefinition),
+}
+
+impl CrossReferences {
+    pub fn new() -> CrossReferences {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.VName source = 1;
+
+
+    pub fn get_source(&self) -> &super::storage::VName {
+        self.source.as_ref().unwrap_or_else(|| <super::storage::VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_source(&mut self) {
+        self.source.clear();
+    }
+
+    pub fn has_source(&self) -> bool {
+        self.source.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source(&mut self, v: super::storage::VName) {
+        self.source = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source(&mut self) -> &mut super::storage::VName {
+        if self.source.is_none() {
+            self.source.set_default();
+        }
+        self.source.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_source(&mut self) -> super::storage::VName {
+        self.source.take().unwrap_or_else(|| super::storage::VName::new())
+    }
+
+    // .kythe.proto.serving.xrefs.CrossReferences.Index index = 2;
+
+
+    pub fn get_index(&self) -> &CrossReferences_Index {
+        match self.entry {
+            ::std::option::Option::Some(CrossReferences_oneof_entry::index(ref v)) => v,
+            _ => <CrossReferences_Index as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_index(&mut self) {
+        self.entry = ::std::option::Option::None;
+    }
+
+    pub fn has_index(&self) -> bool {
+        match self.entry {
+            ::std::option::Option::Some(CrossReferences_oneof_entry::index(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: CrossReferences_Index) {
+        self.entry = ::std::option::Option::Some(CrossReferences_oneof_entry::index(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_index(&mut self) -> &mut CrossReferences_Index {
+        if let ::std::option::Option::Some(CrossReferences_oneof_entry::index(_)) = self.entry {
+        } else {
+            self.entry = ::std::option::Option::Some(CrossReferences_oneof_entry::index(CrossReferences_Index::new()));
+        }
+        match self.entry {
+            ::std::option::Option::Some(CrossReferences_oneof_entry::index(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_index(&mut self) -> CrossReferences_Index {
+        if self.has_index() {
+            match self.entry.take() {
+                ::std::option::Option::Some(CrossReferences_oneof_entry::index(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            CrossReferences_Index::new()
+        }
+    }
+
+    // .kythe.proto.serving.xrefs.CrossReferences.Reference reference = 3;
+
+
+    pub fn get_reference(&self) -> &CrossReferences_Reference {
+        match self.entry {
+            ::std::option::Option::Some(CrossReferences_oneof_entry::reference(ref v)) => v,
+            _ => <CrossReferences_Reference as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_reference(&mut self) {
+        self.entry = ::std::option::Option::None;
+    }
+
+    pub fn has_reference(&self) -> bool {
+        match self.entry {
+            ::std::option::Option::Some(CrossReferences_oneof_entry::reference(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reference(&mut self, v: CrossReferences_Reference) {
+        self.entry = ::std::option::Option::Some(CrossReferences_oneof_entry::reference(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_reference(&mut self) -> &mut CrossReferences_Reference {
+        if let ::std::option::Option::Some(CrossReferences_oneof_entry::reference(_)) = self.entry {
+        } else {
+            self.entry = ::std::option::Option::Some(CrossReferences_oneof_entry::reference(CrossReferences_Reference::new()));
+        }
+        match self.entry {
+            ::std::option::Option::Some(CrossReferences_oneof_entry::reference(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_reference(&mut self) -> CrossReferences_Reference {
+        if self.has_reference() {
+            match self.entry.take() {
+                ::std::option::Option::Some(CrossReferences_oneof_entry::reference(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            CrossReferences_Reference::new()
+        }
+    }
+
+    // .kythe.proto.serving.xrefs.CrossReferences.Relation relation = 4;
+
+
+    pub fn get_relation(&self) -> &CrossReferences_Relation {
+        match self.entry {
+            ::std::option::Option::Some(CrossReferences_oneof_entry::relation(ref v)) => v,
+            _ => <CrossReferences_Relation as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_relation(&mut self) {
+        self.entry = ::std::option::Option::None;
+    }
+
+    pub fn has_relation(&self) -> bool {
+        match self.entry {
+            ::std::option::Option::Some(CrossReferences_oneof_entry::relation(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_relation(&mut self, v: CrossReferences_Relation) {
+        self.entry = ::std::option::Option::Some(CrossReferences_oneof_entry::relation(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_relation(&mut self) -> &mut CrossReferences_Relation {
+        if let ::std::option::Option::Some(CrossReferences_oneof_entry::relation(_)) = self.entry {
+        } else {
+            self.entry = ::std::option::Option::Some(CrossReferences_oneof_entry::relation(CrossReferences_Relation::new()));
+        }
+        match self.entry {
+            ::std::option::Option::Some(CrossReferences_oneof_entry::relation(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_relation(&mut self) -> CrossReferences_Relation {
+        if self.has_relation() {
+            match self.entry.take() {
+                ::std::option::Option::Some(CrossReferences_oneof_entry::relation(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            CrossReferences_Relation::new()
+        }
+    }
+
+    // .kythe.proto.serving.xrefs.CrossReferences.Caller caller = 5;
+
+
+    pub fn get_caller(&self) -> &CrossReferences_Caller {
+        match self.entry {
+            ::std::option::Option::Some(CrossReferences_oneof_entry::caller(ref v)) => v,
+            _ => <CrossReferences_Caller as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_caller(&mut self) {
+        self.entry = ::std::option::Option::None;
+    }
+
+    pub fn has_caller(&self) -> bool {
+        match self.entry {
+            ::std::option::Option::Some(CrossReferences_oneof_entry::caller(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_caller(&mut self, v: CrossReferences_Caller) {
+        self.entry = ::std::option::Option::Some(CrossReferences_oneof_entry::caller(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_caller(&mut self) -> &mut CrossReferences_Caller {
+        if let ::std::option::Option::Some(CrossReferences_oneof_entry::caller(_)) = self.entry {
+        } else {
+            self.entry = ::std::option::Option::Some(CrossReferences_oneof_entry::caller(CrossReferences_Caller::new()));
+        }
+        match self.entry {
+            ::std::option::Option::Some(CrossReferences_oneof_entry::caller(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_caller(&mut self) -> CrossReferences_Caller {
+        if self.has_caller() {
+            match self.entry.take() {
+                ::std::option::Option::Some(CrossReferences_oneof_entry::caller(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            CrossReferences_Caller::new()
+        }
+    }
+
+    // .kythe.proto.serving.xrefs.CrossReferences.Callsite callsite = 6;
+
+
+    pub fn get_callsite(&self) -> &CrossReferences_Callsite {
+        match self.entry {
+            ::std::option::Option::Some(CrossReferences_oneof_entry::callsite(ref v)) => v,
+            _ => <CrossReferences_Callsite as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_callsite(&mut self) {
+        self.entry = ::std::option::Option::None;
+    }
+
+    pub fn has_callsite(&self) -> bool {
+        match self.entry {
+            ::std::option::Option::Some(CrossReferences_oneof_entry::callsite(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_callsite(&mut self, v: CrossReferences_Callsite) {
+        self.entry = ::std::option::Option::Some(CrossReferences_oneof_entry::callsite(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_callsite(&mut self) -> &mut CrossReferences_Callsite {
+        if let ::std::option::Option::Some(CrossReferences_oneof_entry::callsite(_)) = self.entry {
+        } else {
+            self.entry = ::std::option::Option::Some(CrossReferences_oneof_entry::callsite(CrossReferences_Callsite::new()));
+        }
+        match self.entry {
+            ::std::option::Option::Some(CrossReferences_oneof_entry::callsite(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_callsite(&mut self) -> CrossReferences_Callsite {
+        if self.has_callsite() {
+            match self.entry.take() {
+                ::std::option::Option::Some(CrossReferences_oneof_entry::callsite(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            CrossReferences_Callsite::new()
+        }
+    }
+
+    // .kythe.proto.serving.xrefs.CrossReferences.RelatedNode related_node = 7;
+
+
+    pub fn get_related_node(&self) -> &CrossReferences_RelatedNode {
+        match self.entry {
+            ::std::option::Option::Some(CrossReferences_oneof_entry::related_node(ref v)) => v,
+            _ => <CrossReferences_RelatedNode as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_related_node(&mut self) {
+        self.entry = ::std::option::Option::None;
+    }
+
+    pub fn has_related_node(&self) -> bool {
+        match self.entry {
+            ::std::option::Option::Some(CrossReferences_oneof_entry::related_node(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_related_node(&mut self, v: CrossReferences_RelatedNode) {
+        self.entry = ::std::option::Option::Some(CrossReferences_oneof_entry::related_node(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_related_node(&mut self) -> &mut CrossReferences_RelatedNode {
+        if let ::std::option::Option::Some(CrossReferences_oneof_entry::related_node(_)) = self.entry {
+        } else {
+            self.entry = ::std::option::Option::Some(CrossReferences_oneof_entry::related_node(CrossReferences_RelatedNode::new()));
+        }
+        match self.entry {
+            ::std::option::Option::Some(CrossReferences_oneof_entry::related_node(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_related_node(&mut self) -> CrossReferences_RelatedNode {
+        if self.has_related_node() {
+            match self.entry.take() {
+                ::std::option::Option::Some(CrossReferences_oneof_entry::related_node(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            CrossReferences_RelatedNode::new()
+        }
+    }
+
+    // .kythe.proto.serving.xrefs.CrossReferences.NodeDefinition node_definition = 8;
+
+
+    pub fn get_node_definition(&self) -> &CrossReferences_NodeDefinition {
+        match self.entry {
+            ::std::option::Option::Some(CrossReferences_oneof_entry::node_definition(ref v)) => v,
+            _ => <CrossReferences_NodeDefinition as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_node_definition(&mut self) {
+        self.entry = ::std::option::Option::None;
+    }
+
+    pub fn has_node_definition(&self) -> bool {
+        match self.entry {
+            ::std::option::Option::Some(CrossReferences_oneof_entry::node_definition(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_node_definition(&mut self, v: CrossReferences_NodeDefinition) {
+        self.entry = ::std::option::Option::Some(CrossReferences_oneof_entry::node_definition(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_node_definition(&mut self) -> &mut CrossReferences_NodeDefinition {
+        if let ::std::option::Option::Some(CrossReferences_oneof_entry::node_definition(_)) = self.entry {
+        } else {
+            self.entry = ::std::option::Option::Some(CrossReferences_oneof_entry::node_definition(CrossReferences_NodeDefinition::new()));
+        }
+        match self.entry {
+            ::std::option::Option::Some(CrossReferences_oneof_entry::node_definition(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_node_definition(&mut self) -> CrossReferences_NodeDefinition {
+        if self.has_node_definition() {
+            match self.entry.take() {
+                ::std::option::Option::Some(CrossReferences_oneof_entry::node_definition(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            CrossReferences_NodeDefinition::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for CrossReferences {
+    fn is_initialized(&self) -> bool {
+        for v in &self.source {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        if let Some(CrossReferences_oneof_entry::index(ref v)) = self.entry {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(CrossReferences_oneof_entry::reference(ref v)) = self.entry {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(CrossReferences_oneof_entry::relation(ref v)) = self.entry {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(CrossReferences_oneof_entry::caller(ref v)) = self.entry {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(CrossReferences_oneof_entry::callsite(ref v)) = self.entry {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(CrossReferences_oneof_entry::related_node(ref v)) = self.entry {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(CrossReferences_oneof_entry::node_definition(ref v)) = self.entry {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.source)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.entry = ::std::option::Option::Some(CrossReferences_oneof_entry::index(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.entry = ::std::option::Option::Some(CrossReferences_oneof_entry::reference(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.entry = ::std::option::Option::Some(CrossReferences_oneof_entry::relation(is.read_message()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.entry = ::std::option::Option::Some(CrossReferences_oneof_entry::caller(is.read_message()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.entry = ::std::option::Option::Some(CrossReferences_oneof_entry::callsite(is.read_message()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.entry = ::std::option::Option::Some(CrossReferences_oneof_entry::related_node(is.read_message()?));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.entry = ::std::option::Option::Some(CrossReferences_oneof_entry::node_definition(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.source.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let ::std::option::Option::Some(ref v) = self.entry {
+            match v {
+                &CrossReferences_oneof_entry::index(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &CrossReferences_oneof_entry::reference(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &CrossReferences_oneof_entry::relation(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &CrossReferences_oneof_entry::caller(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &CrossReferences_oneof_entry::callsite(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &CrossReferences_oneof_entry::related_node(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &CrossReferences_oneof_entry::node_definition(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.source.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.entry {
+            match v {
+                &CrossReferences_oneof_entry::index(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &CrossReferences_oneof_entry::reference(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &CrossReferences_oneof_entry::relation(ref v) => {
+                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &CrossReferences_oneof_entry::caller(ref v) => {
+                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &CrossReferences_oneof_entry::callsite(ref v) => {
+                    os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &CrossReferences_oneof_entry::related_node(ref v) => {
+                    os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &CrossReferences_oneof_entry::node_definition(ref v) => {
+                    os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CrossReferences {
+        CrossReferences::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::storage::VName>>(
+                "source",
+                |m: &CrossReferences| { &m.source },
+                |m: &mut CrossReferences| { &mut m.source },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, CrossReferences_Index>(
+                "index",
+                CrossReferences::has_index,
+                CrossReferences::get_index,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, CrossReferences_Reference>(
+                "reference",
+                CrossReferences::has_reference,
+                CrossReferences::get_reference,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, CrossReferences_Relation>(
+                "relation",
+                CrossReferences::has_relation,
+                CrossReferences::get_relation,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, CrossReferences_Caller>(
+                "caller",
+                CrossReferences::has_caller,
+                CrossReferences::get_caller,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, CrossReferences_Callsite>(
+                "callsite",
+                CrossReferences::has_callsite,
+                CrossReferences::get_callsite,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, CrossReferences_RelatedNode>(
+                "related_node",
+                CrossReferences::has_related_node,
+                CrossReferences::get_related_node,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, CrossReferences_NodeDefinition>(
+                "node_definition",
+                CrossReferences::has_node_definition,
+                CrossReferences::get_node_definition,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CrossReferences>(
+                "CrossReferences",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CrossReferences {
+        static instance: ::protobuf::rt::LazyV2<CrossReferences> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CrossReferences::new)
+    }
+}
+
+impl ::protobuf::Clear for CrossReferences {
+    fn clear(&mut self) {
+        self.source.clear();
+        self.entry = ::std::option::Option::None;
+        self.entry = ::std::option::Option::None;
+        self.entry = ::std::option::Option::None;
+        self.entry = ::std::option::Option::None;
+        self.entry = ::std::option::Option::None;
+        self.entry = ::std::option::Option::None;
+        self.entry = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CrossReferences {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CrossReferences {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CrossReferences_Index {
+    // message fields
+    pub node: ::protobuf::SingularPtrField<super::schema::Node>,
+    pub marked_source: ::protobuf::SingularPtrField<super::common::MarkedSource>,
+    pub merge_with: ::protobuf::RepeatedField<super::storage::VName>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CrossReferences_Index {
+    fn default() -> &'a CrossReferences_Index {
+        <CrossReferences_Index as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CrossReferences_Index {
+    pub fn new() -> CrossReferences_Index {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.schema.Node node = 1;
+
+
+    pub fn get_node(&self) -> &super::schema::Node {
+        self.node.as_ref().unwrap_or_else(|| <super::schema::Node as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_node(&mut self) {
+        self.node.clear();
+    }
+
+    pub fn has_node(&self) -> bool {
+        self.node.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_node(&mut self, v: super::schema::Node) {
+        self.node = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_node(&mut self) -> &mut super::schema::Node {
+        if self.node.is_none() {
+            self.node.set_default();
+        }
+        self.node.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_node(&mut self) -> super::schema::Node {
+        self.node.take().unwrap_or_else(|| super::schema::Node::new())
+    }
+
+    // .kythe.proto.common.MarkedSource marked_source = 2;
+
+
+    pub fn get_marked_source(&self) -> &super::common::MarkedSource {
+        self.marked_source.as_ref().unwrap_or_else(|| <super::common::MarkedSource as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_marked_source(&mut self) {
+        self.marked_source.clear();
+    }
+
+    pub fn has_marked_source(&self) -> bool {
+        self.marked_source.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_marked_source(&mut self, v: super::common::MarkedSource) {
+        self.marked_source = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_marked_source(&mut self) -> &mut super::common::MarkedSource {
+        if self.marked_source.is_none() {
+            self.marked_source.set_default();
+        }
+        self.marked_source.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_marked_source(&mut self) -> super::common::MarkedSource {
+        self.marked_source.take().unwrap_or_else(|| super::common::MarkedSource::new())
+    }
+
+    // repeated .kythe.proto.VName merge_with = 3;
+
+
+    pub fn get_merge_with(&self) -> &[super::storage::VName] {
+        &self.merge_with
+    }
+    pub fn clear_merge_with(&mut self) {
+        self.merge_with.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_merge_with(&mut self, v: ::protobuf::RepeatedField<super::storage::VName>) {
+        self.merge_with = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_merge_with(&mut self) -> &mut ::protobuf::RepeatedField<super::storage::VName> {
+        &mut self.merge_with
+    }
+
+    // Take field
+    pub fn take_merge_with(&mut self) -> ::protobuf::RepeatedField<super::storage::VName> {
+        ::std::mem::replace(&mut self.merge_with, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for CrossReferences_Index {
+    fn is_initialized(&self) -> bool {
+        for v in &self.node {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.marked_source {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.merge_with {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.node)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.marked_source)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.merge_with)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.node.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.marked_source.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.merge_with {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.node.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.marked_source.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.merge_with {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CrossReferences_Index {
+        CrossReferences_Index::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::schema::Node>>(
+                "node",
+                |m: &CrossReferences_Index| { &m.node },
+                |m: &mut CrossReferences_Index| { &mut m.node },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::MarkedSource>>(
+                "marked_source",
+                |m: &CrossReferences_Index| { &m.marked_source },
+                |m: &mut CrossReferences_Index| { &mut m.marked_source },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::storage::VName>>(
+                "merge_with",
+                |m: &CrossReferences_Index| { &m.merge_with },
+                |m: &mut CrossReferences_Index| { &mut m.merge_with },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CrossReferences_Index>(
+                "CrossReferences.Index",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CrossReferences_Index {
+        static instance: ::protobuf::rt::LazyV2<CrossReferences_Index> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CrossReferences_Index::new)
+    }
+}
+
+impl ::protobuf::Clear for CrossReferences_Index {
+    fn clear(&mut self) {
+        self.node.clear();
+        self.marked_source.clear();
+        self.merge_with.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CrossReferences_Index {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CrossReferences_Index {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CrossReferences_Reference {
+    // message fields
+    pub location: ::protobuf::SingularPtrField<super::serving::ExpandedAnchor>,
+    // message oneof groups
+    pub kind: ::std::option::Option<CrossReferences_Reference_oneof_kind>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CrossReferences_Reference {
+    fn default() -> &'a CrossReferences_Reference {
+        <CrossReferences_Reference as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum CrossReferences_Reference_oneof_kind {
+    kythe_kind(super::schema::EdgeKind),
+    generic_kind(::std::string::String),
+}
+
+impl CrossReferences_Reference {
+    pub fn new() -> CrossReferences_Reference {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.schema.EdgeKind kythe_kind = 1;
+
+
+    pub fn get_kythe_kind(&self) -> super::schema::EdgeKind {
+        match self.kind {
+            ::std::option::Option::Some(CrossReferences_Reference_oneof_kind::kythe_kind(v)) => v,
+            _ => super::schema::EdgeKind::UNKNOWN_EDGE_KIND,
+        }
+    }
+    pub fn clear_kythe_kind(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_kythe_kind(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(CrossReferences_Reference_oneof_kind::kythe_kind(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kythe_kind(&mut self, v: super::schema::EdgeKind) {
+        self.kind = ::std::option::Option::Some(CrossReferences_Reference_oneof_kind::kythe_kind(v))
+    }
+
+    // string generic_kind = 2;
+
+
+    pub fn get_generic_kind(&self) -> &str {
+        match self.kind {
+            ::std::option::Option::Some(CrossReferences_Reference_oneof_kind::generic_kind(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_generic_kind(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_generic_kind(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(CrossReferences_Reference_oneof_kind::generic_kind(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_generic_kind(&mut self, v: ::std::string::String) {
+        self.kind = ::std::option::Option::Some(CrossReferences_Reference_oneof_kind::generic_kind(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_generic_kind(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(CrossReferences_Reference_oneof_kind::generic_kind(_)) = self.kind {
+        } else {
+            self.kind = ::std::option::Option::Some(CrossReferences_Reference_oneof_kind::generic_kind(::std::string::String::new()));
+        }
+        match self.kind {
+            ::std::option::Option::Some(CrossReferences_Reference_oneof_kind::generic_kind(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_generic_kind(&mut self) -> ::std::string::String {
+        if self.has_generic_kind() {
+            match self.kind.take() {
+                ::std::option::Option::Some(CrossReferences_Reference_oneof_kind::generic_kind(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // .kythe.proto.serving.ExpandedAnchor location = 3;
+
+
+    pub fn get_location(&self) -> &super::serving::ExpandedAnchor {
+        self.location.as_ref().unwrap_or_else(|| <super::serving::ExpandedAnchor as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_location(&mut self) {
+        self.location.clear();
+    }
+
+    pub fn has_location(&self) -> bool {
+        self.location.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_location(&mut self, v: super::serving::ExpandedAnchor) {
+        self.location = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_location(&mut self) -> &mut super::serving::ExpandedAnchor {
+        if self.location.is_none() {
+            self.location.set_default();
+        }
+        self.location.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_location(&mut self) -> super::serving::ExpandedAnchor {
+        self.location.take().unwrap_or_else(|| super::serving::ExpandedAnchor::new())
+    }
+}
+
+impl ::protobuf::Message for CrossReferences_Reference {
+    fn is_initialized(&self) -> bool {
+        for v in &self.location {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.kind = ::std::option::Option::Some(CrossReferences_Reference_oneof_kind::kythe_kind(is.read_enum()?));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.kind = ::std::option::Option::Some(CrossReferences_Reference_oneof_kind::generic_kind(is.read_string()?));
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.location)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.location.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let ::std::option::Option::Some(ref v) = self.kind {
+            match v {
+                &CrossReferences_Reference_oneof_kind::kythe_kind(v) => {
+                    my_size += ::protobuf::rt::enum_size(1, v);
+                },
+                &CrossReferences_Reference_oneof_kind::generic_kind(ref v) => {
+                    my_size += ::protobuf::rt::string_size(2, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.location.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.kind {
+            match v {
+                &CrossReferences_Reference_oneof_kind::kythe_kind(v) => {
+                    os.write_enum(1, ::protobuf::ProtobufEnum::value(&v))?;
+                },
+                &CrossReferences_Reference_oneof_kind::generic_kind(ref v) => {
+                    os.write_string(2, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CrossReferences_Reference {
+        CrossReferences_Reference::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor::<_, super::schema::EdgeKind>(
+                "kythe_kind",
+                CrossReferences_Reference::has_kythe_kind,
+                CrossReferences_Reference::get_kythe_kind,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "generic_kind",
+                CrossReferences_Reference::has_generic_kind,
+                CrossReferences_Reference::get_generic_kind,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::serving::ExpandedAnchor>>(
+                "location",
+                |m: &CrossReferences_Reference| { &m.location },
+                |m: &mut CrossReferences_Reference| { &mut m.location },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CrossReferences_Reference>(
+                "CrossReferences.Reference",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CrossReferences_Reference {
+        static instance: ::protobuf::rt::LazyV2<CrossReferences_Reference> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CrossReferences_Reference::new)
+    }
+}
+
+impl ::protobuf::Clear for CrossReferences_Reference {
+    fn clear(&mut self) {
+        self.kind = ::std::option::Option::None;
+        self.kind = ::std::option::Option::None;
+        self.location.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CrossReferences_Reference {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CrossReferences_Reference {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CrossReferences_Relation {
+    // message fields
+    pub node: ::protobuf::SingularPtrField<super::storage::VName>,
+    pub ordinal: i32,
+    pub reverse: bool,
+    // message oneof groups
+    pub kind: ::std::option::Option<CrossReferences_Relation_oneof_kind>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CrossReferences_Relation {
+    fn default() -> &'a CrossReferences_Relation {
+        <CrossReferences_Relation as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum CrossReferences_Relation_oneof_kind {
+    kythe_kind(super::schema::EdgeKind),
+    generic_kind(::std::string::String),
+}
+
+impl CrossReferences_Relation {
+    pub fn new() -> CrossReferences_Relation {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.VName node = 1;
+
+
+    pub fn get_node(&self) -> &super::storage::VName {
+        self.node.as_ref().unwrap_or_else(|| <super::storage::VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_node(&mut self) {
+        self.node.clear();
+    }
+
+    pub fn has_node(&self) -> bool {
+        self.node.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_node(&mut self, v: super::storage::VName) {
+        self.node = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_node(&mut self) -> &mut super::storage::VName {
+        if self.node.is_none() {
+            self.node.set_default();
+        }
+        self.node.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_node(&mut self) -> super::storage::VName {
+        self.node.take().unwrap_or_else(|| super::storage::VName::new())
+    }
+
+    // .kythe.proto.schema.EdgeKind kythe_kind = 2;
+
+
+    pub fn get_kythe_kind(&self) -> super::schema::EdgeKind {
+        match self.kind {
+            ::std::option::Option::Some(CrossReferences_Relation_oneof_kind::kythe_kind(v)) => v,
+            _ => super::schema::EdgeKind::UNKNOWN_EDGE_KIND,
+        }
+    }
+    pub fn clear_kythe_kind(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_kythe_kind(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(CrossReferences_Relation_oneof_kind::kythe_kind(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kythe_kind(&mut self, v: super::schema::EdgeKind) {
+        self.kind = ::std::option::Option::Some(CrossReferences_Relation_oneof_kind::kythe_kind(v))
+    }
+
+    // string generic_kind = 3;
+
+
+    pub fn get_generic_kind(&self) -> &str {
+        match self.kind {
+            ::std::option::Option::Some(CrossReferences_Relation_oneof_kind::generic_kind(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_generic_kind(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_generic_kind(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(CrossReferences_Relation_oneof_kind::generic_kind(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_generic_kind(&mut self, v: ::std::string::String) {
+        self.kind = ::std::option::Option::Some(CrossReferences_Relation_oneof_kind::generic_kind(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_generic_kind(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(CrossReferences_Relation_oneof_kind::generic_kind(_)) = self.kind {
+        } else {
+            self.kind = ::std::option::Option::Some(CrossReferences_Relation_oneof_kind::generic_kind(::std::string::String::new()));
+        }
+        match self.kind {
+            ::std::option::Option::Some(CrossReferences_Relation_oneof_kind::generic_kind(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_generic_kind(&mut self) -> ::std::string::String {
+        if self.has_generic_kind() {
+            match self.kind.take() {
+                ::std::option::Option::Some(CrossReferences_Relation_oneof_kind::generic_kind(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // int32 ordinal = 4;
+
+
+    pub fn get_ordinal(&self) -> i32 {
+        self.ordinal
+    }
+    pub fn clear_ordinal(&mut self) {
+        self.ordinal = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ordinal(&mut self, v: i32) {
+        self.ordinal = v;
+    }
+
+    // bool reverse = 5;
+
+
+    pub fn get_reverse(&self) -> bool {
+        self.reverse
+    }
+    pub fn clear_reverse(&mut self) {
+        self.reverse = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reverse(&mut self, v: bool) {
+        self.reverse = v;
+    }
+}
+
+impl ::protobuf::Message for CrossReferences_Relation {
+    fn is_initialized(&self) -> bool {
+        for v in &self.node {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.node)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.kind = ::std::option::Option::Some(CrossReferences_Relation_oneof_kind::kythe_kind(is.read_enum()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.kind = ::std::option::Option::Some(CrossReferences_Relation_oneof_kind::generic_kind(is.read_string()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.ordinal = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.reverse = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.node.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.ordinal != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.ordinal, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.reverse != false {
+            my_size += 2;
+        }
+        if let ::std::option::Option::Some(ref v) = self.kind {
+            match v {
+                &CrossReferences_Relation_oneof_kind::kythe_kind(v) => {
+                    my_size += ::protobuf::rt::enum_size(2, v);
+                },
+                &CrossReferences_Relation_oneof_kind::generic_kind(ref v) => {
+                    my_size += ::protobuf::rt::string_size(3, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.node.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.ordinal != 0 {
+            os.write_int32(4, self.ordinal)?;
+        }
+        if self.reverse != false {
+            os.write_bool(5, self.reverse)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.kind {
+            match v {
+                &CrossReferences_Relation_oneof_kind::kythe_kind(v) => {
+                    os.write_enum(2, ::protobuf::ProtobufEnum::value(&v))?;
+                },
+                &CrossReferences_Relation_oneof_kind::generic_kind(ref v) => {
+                    os.write_string(3, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CrossReferences_Relation {
+        CrossReferences_Relation::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::storage::VName>>(
+                "node",
+                |m: &CrossReferences_Relation| { &m.node },
+                |m: &mut CrossReferences_Relation| { &mut m.node },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor::<_, super::schema::EdgeKind>(
+                "kythe_kind",
+                CrossReferences_Relation::has_kythe_kind,
+                CrossReferences_Relation::get_kythe_kind,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "generic_kind",
+                CrossReferences_Relation::has_generic_kind,
+                CrossReferences_Relation::get_generic_kind,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "ordinal",
+                |m: &CrossReferences_Relation| { &m.ordinal },
+                |m: &mut CrossReferences_Relation| { &mut m.ordinal },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "reverse",
+                |m: &CrossReferences_Relation| { &m.reverse },
+                |m: &mut CrossReferences_Relation| { &mut m.reverse },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CrossReferences_Relation>(
+                "CrossReferences.Relation",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CrossReferences_Relation {
+        static instance: ::protobuf::rt::LazyV2<CrossReferences_Relation> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CrossReferences_Relation::new)
+    }
+}
+
+impl ::protobuf::Clear for CrossReferences_Relation {
+    fn clear(&mut self) {
+        self.node.clear();
+        self.kind = ::std::option::Option::None;
+        self.kind = ::std::option::Option::None;
+        self.ordinal = 0;
+        self.reverse = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CrossReferences_Relation {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CrossReferences_Relation {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CrossReferences_Caller {
+    // message fields
+    pub caller: ::protobuf::SingularPtrField<super::storage::VName>,
+    pub location: ::protobuf::SingularPtrField<super::serving::ExpandedAnchor>,
+    pub marked_source: ::protobuf::SingularPtrField<super::common::MarkedSource>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CrossReferences_Caller {
+    fn default() -> &'a CrossReferences_Caller {
+        <CrossReferences_Caller as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CrossReferences_Caller {
+    pub fn new() -> CrossReferences_Caller {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.VName caller = 1;
+
+
+    pub fn get_caller(&self) -> &super::storage::VName {
+        self.caller.as_ref().unwrap_or_else(|| <super::storage::VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_caller(&mut self) {
+        self.caller.clear();
+    }
+
+    pub fn has_caller(&self) -> bool {
+        self.caller.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_caller(&mut self, v: super::storage::VName) {
+        self.caller = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_caller(&mut self) -> &mut super::storage::VName {
+        if self.caller.is_none() {
+            self.caller.set_default();
+        }
+        self.caller.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_caller(&mut self) -> super::storage::VName {
+        self.caller.take().unwrap_or_else(|| super::storage::VName::new())
+    }
+
+    // .kythe.proto.serving.ExpandedAnchor location = 2;
+
+
+    pub fn get_location(&self) -> &super::serving::ExpandedAnchor {
+        self.location.as_ref().unwrap_or_else(|| <super::serving::ExpandedAnchor as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_location(&mut self) {
+        self.location.clear();
+    }
+
+    pub fn has_location(&self) -> bool {
+        self.location.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_location(&mut self, v: super::serving::ExpandedAnchor) {
+        self.location = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_location(&mut self) -> &mut super::serving::ExpandedAnchor {
+        if self.location.is_none() {
+            self.location.set_default();
+        }
+        self.location.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_location(&mut self) -> super::serving::ExpandedAnchor {
+        self.location.take().unwrap_or_else(|| super::serving::ExpandedAnchor::new())
+    }
+
+    // .kythe.proto.common.MarkedSource marked_source = 3;
+
+
+    pub fn get_marked_source(&self) -> &super::common::MarkedSource {
+        self.marked_source.as_ref().unwrap_or_else(|| <super::common::MarkedSource as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_marked_source(&mut self) {
+        self.marked_source.clear();
+    }
+
+    pub fn has_marked_source(&self) -> bool {
+        self.marked_source.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_marked_source(&mut self, v: super::common::MarkedSource) {
+        self.marked_source = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_marked_source(&mut self) -> &mut super::common::MarkedSource {
+        if self.marked_source.is_none() {
+            self.marked_source.set_default();
+        }
+        self.marked_source.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_marked_source(&mut self) -> super::common::MarkedSource {
+        self.marked_source.take().unwrap_or_else(|| super::common::MarkedSource::new())
+    }
+}
+
+impl ::protobuf::Message for CrossReferences_Caller {
+    fn is_initialized(&self) -> bool {
+        for v in &self.caller {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.location {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.marked_source {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.caller)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.location)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.marked_source)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.caller.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.location.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.marked_source.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.caller.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.location.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.marked_source.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CrossReferences_Caller {
+        CrossReferences_Caller::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::storage::VName>>(
+                "caller",
+                |m: &CrossReferences_Caller| { &m.caller },
+                |m: &mut CrossReferences_Caller| { &mut m.caller },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::serving::ExpandedAnchor>>(
+                "location",
+                |m: &CrossReferences_Caller| { &m.location },
+                |m: &mut CrossReferences_Caller| { &mut m.location },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::common::MarkedSource>>(
+                "marked_source",
+                |m: &CrossReferences_Caller| { &m.marked_source },
+                |m: &mut CrossReferences_Caller| { &mut m.marked_source },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CrossReferences_Caller>(
+                "CrossReferences.Caller",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CrossReferences_Caller {
+        static instance: ::protobuf::rt::LazyV2<CrossReferences_Caller> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CrossReferences_Caller::new)
+    }
+}
+
+impl ::protobuf::Clear for CrossReferences_Caller {
+    fn clear(&mut self) {
+        self.caller.clear();
+        self.location.clear();
+        self.marked_source.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CrossReferences_Caller {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CrossReferences_Caller {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CrossReferences_Callsite {
+    // message fields
+    pub caller: ::protobuf::SingularPtrField<super::storage::VName>,
+    pub location: ::protobuf::SingularPtrField<super::serving::ExpandedAnchor>,
+    pub kind: CrossReferences_Callsite_Kind,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CrossReferences_Callsite {
+    fn default() -> &'a CrossReferences_Callsite {
+        <CrossReferences_Callsite as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CrossReferences_Callsite {
+    pub fn new() -> CrossReferences_Callsite {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.VName caller = 1;
+
+
+    pub fn get_caller(&self) -> &super::storage::VName {
+        self.caller.as_ref().unwrap_or_else(|| <super::storage::VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_caller(&mut self) {
+        self.caller.clear();
+    }
+
+    pub fn has_caller(&self) -> bool {
+        self.caller.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_caller(&mut self, v: super::storage::VName) {
+        self.caller = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_caller(&mut self) -> &mut super::storage::VName {
+        if self.caller.is_none() {
+            self.caller.set_default();
+        }
+        self.caller.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_caller(&mut self) -> super::storage::VName {
+        self.caller.take().unwrap_or_else(|| super::storage::VName::new())
+    }
+
+    // .kythe.proto.serving.ExpandedAnchor location = 2;
+
+
+    pub fn get_location(&self) -> &super::serving::ExpandedAnchor {
+        self.location.as_ref().unwrap_or_else(|| <super::serving::ExpandedAnchor as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_location(&mut self) {
+        self.location.clear();
+    }
+
+    pub fn has_location(&self) -> bool {
+        self.location.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_location(&mut self, v: super::serving::ExpandedAnchor) {
+        self.location = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_location(&mut self) -> &mut super::serving::ExpandedAnchor {
+        if self.location.is_none() {
+            self.location.set_default();
+        }
+        self.location.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_location(&mut self) -> super::serving::ExpandedAnchor {
+        self.location.take().unwrap_or_else(|| super::serving::ExpandedAnchor::new())
+    }
+
+    // .kythe.proto.serving.xrefs.CrossReferences.Callsite.Kind kind = 3;
+
+
+    pub fn get_kind(&self) -> CrossReferences_Callsite_Kind {
+        self.kind
+    }
+    pub fn clear_kind(&mut self) {
+        self.kind = CrossReferences_Callsite_Kind::UNKNOWN;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: CrossReferences_Callsite_Kind) {
+        self.kind = v;
+    }
+}
+
+impl ::protobuf::Message for CrossReferences_Callsite {
+    fn is_initialized(&self) -> bool {
+        for v in &self.caller {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.location {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.caller)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.location)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.kind, 3, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.caller.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.location.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.kind != CrossReferences_Callsite_Kind::UNKNOWN {
+            my_size += ::protobuf::rt::enum_size(3, self.kind);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.caller.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.location.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.kind != CrossReferences_Callsite_Kind::UNKNOWN {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.kind))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CrossReferences_Callsite {
+        CrossReferences_Callsite::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::storage::VName>>(
+                "caller",
+                |m: &CrossReferences_Callsite| { &m.caller },
+                |m: &mut CrossReferences_Callsite| { &mut m.caller },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::serving::ExpandedAnchor>>(
+                "location",
+                |m: &CrossReferences_Callsite| { &m.location },
+                |m: &mut CrossReferences_Callsite| { &mut m.location },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<CrossReferences_Callsite_Kind>>(
+                "kind",
+                |m: &CrossReferences_Callsite| { &m.kind },
+                |m: &mut CrossReferences_Callsite| { &mut m.kind },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CrossReferences_Callsite>(
+                "CrossReferences.Callsite",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CrossReferences_Callsite {
+        static instance: ::protobuf::rt::LazyV2<CrossReferences_Callsite> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CrossReferences_Callsite::new)
+    }
+}
+
+impl ::protobuf::Clear for CrossReferences_Callsite {
+    fn clear(&mut self) {
+        self.caller.clear();
+        self.location.clear();
+        self.kind = CrossReferences_Callsite_Kind::UNKNOWN;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CrossReferences_Callsite {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CrossReferences_Callsite {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum CrossReferences_Callsite_Kind {
+    UNKNOWN = 0,
+    DIRECT = 1,
+    OVERRIDE = 2,
+}
+
+impl ::protobuf::ProtobufEnum for CrossReferences_Callsite_Kind {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<CrossReferences_Callsite_Kind> {
+        match value {
+            0 => ::std::option::Option::Some(CrossReferences_Callsite_Kind::UNKNOWN),
+            1 => ::std::option::Option::Some(CrossReferences_Callsite_Kind::DIRECT),
+            2 => ::std::option::Option::Some(CrossReferences_Callsite_Kind::OVERRIDE),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [CrossReferences_Callsite_Kind] = &[
+            CrossReferences_Callsite_Kind::UNKNOWN,
+            CrossReferences_Callsite_Kind::DIRECT,
+            CrossReferences_Callsite_Kind::OVERRIDE,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<CrossReferences_Callsite_Kind>("CrossReferences.Callsite.Kind", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for CrossReferences_Callsite_Kind {
+}
+
+impl ::std::default::Default for CrossReferences_Callsite_Kind {
+    fn default() -> Self {
+        CrossReferences_Callsite_Kind::UNKNOWN
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CrossReferences_Callsite_Kind {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CrossReferences_RelatedNode {
+    // message fields
+    pub node: ::protobuf::SingularPtrField<super::schema::Node>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CrossReferences_RelatedNode {
+    fn default() -> &'a CrossReferences_RelatedNode {
+        <CrossReferences_RelatedNode as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CrossReferences_RelatedNode {
+    pub fn new() -> CrossReferences_RelatedNode {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.schema.Node node = 1;
+
+
+    pub fn get_node(&self) -> &super::schema::Node {
+        self.node.as_ref().unwrap_or_else(|| <super::schema::Node as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_node(&mut self) {
+        self.node.clear();
+    }
+
+    pub fn has_node(&self) -> bool {
+        self.node.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_node(&mut self, v: super::schema::Node) {
+        self.node = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_node(&mut self) -> &mut super::schema::Node {
+        if self.node.is_none() {
+            self.node.set_default();
+        }
+        self.node.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_node(&mut self) -> super::schema::Node {
+        self.node.take().unwrap_or_else(|| super::schema::Node::new())
+    }
+}
+
+impl ::protobuf::Message for CrossReferences_RelatedNode {
+    fn is_initialized(&self) -> bool {
+        for v in &self.node {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.node)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.node.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.node.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CrossReferences_RelatedNode {
+        CrossReferences_RelatedNode::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::schema::Node>>(
+                "node",
+                |m: &CrossReferences_RelatedNode| { &m.node },
+                |m: &mut CrossReferences_RelatedNode| { &mut m.node },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CrossReferences_RelatedNode>(
+                "CrossReferences.RelatedNode",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CrossReferences_RelatedNode {
+        static instance: ::protobuf::rt::LazyV2<CrossReferences_RelatedNode> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CrossReferences_RelatedNode::new)
+    }
+}
+
+impl ::protobuf::Clear for CrossReferences_RelatedNode {
+    fn clear(&mut self) {
+        self.node.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CrossReferences_RelatedNode {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CrossReferences_RelatedNode {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CrossReferences_NodeDefinition {
+    // message fields
+    pub node: ::protobuf::SingularPtrField<super::storage::VName>,
+    pub location: ::protobuf::SingularPtrField<super::serving::ExpandedAnchor>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CrossReferences_NodeDefinition {
+    fn default() -> &'a CrossReferences_NodeDefinition {
+        <CrossReferences_NodeDefinition as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CrossReferences_NodeDefinition {
+    pub fn new() -> CrossReferences_NodeDefinition {
+        ::std::default::Default::default()
+    }
+
+    // .kythe.proto.VName node = 1;
+
+
+    pub fn get_node(&self) -> &super::storage::VName {
+        self.node.as_ref().unwrap_or_else(|| <super::storage::VName as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_node(&mut self) {
+        self.node.clear();
+    }
+
+    pub fn has_node(&self) -> bool {
+        self.node.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_node(&mut self, v: super::storage::VName) {
+        self.node = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_node(&mut self) -> &mut super::storage::VName {
+        if self.node.is_none() {
+            self.node.set_default();
+        }
+        self.node.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_node(&mut self) -> super::storage::VName {
+        self.node.take().unwrap_or_else(|| super::storage::VName::new())
+    }
+
+    // .kythe.proto.serving.ExpandedAnchor location = 2;
+
+
+    pub fn get_location(&self) -> &super::serving::ExpandedAnchor {
+        self.location.as_ref().unwrap_or_else(|| <super::serving::ExpandedAnchor as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_location(&mut self) {
+        self.location.clear();
+    }
+
+    pub fn has_location(&self) -> bool {
+        self.location.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_location(&mut self, v: super::serving::ExpandedAnchor) {
+        self.location = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_location(&mut self) -> &mut super::serving::ExpandedAnchor {
+        if self.location.is_none() {
+            self.location.set_default();
+        }
+        self.location.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_location(&mut self) -> super::serving::ExpandedAnchor {
+        self.location.take().unwrap_or_else(|| super::serving::ExpandedAnchor::new())
+    }
+}
+
+impl ::protobuf::Message for CrossReferences_NodeDefinition {
+    fn is_initialized(&self) -> bool {
+        for v in &self.node {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.location {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.node)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.location)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.node.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.location.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.node.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.location.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CrossReferences_NodeDefinition {
+        CrossReferences_NodeDefinition::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::storage::VName>>(
+                "node",
+                |m: &CrossReferences_NodeDefinition| { &m.node },
+                |m: &mut CrossReferences_NodeDefinition| { &mut m.node },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::serving::ExpandedAnchor>>(
+                "location",
+                |m: &CrossReferences_NodeDefinition| { &m.location },
+                |m: &mut CrossReferences_NodeDefinition| { &mut m.location },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CrossReferences_NodeDefinition>(
+                "CrossReferences.NodeDefinition",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CrossReferences_NodeDefinition {
+        static instance: ::protobuf::rt::LazyV2<CrossReferences_NodeDefinition> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CrossReferences_NodeDefinition::new)
+    }
+}
+
+impl ::protobuf::Clear for CrossReferences_NodeDefinition {
+    fn clear(&mut self) {
+        self.node.clear();
+        self.location.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CrossReferences_NodeDefinition {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CrossReferences_NodeDefinition {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x1ekythe/proto/xref_serving.proto\x12\x19kythe.proto.serving.xrefs\
+    \x1a\x18kythe/proto/common.proto\x1a\x18kythe/proto/schema.proto\x1a\x19\
+    kythe/proto/serving.proto\x1a\x19kythe/proto/storage.proto\"\xf4\x0f\n\
+    \x0fFileDecorations\x12&\n\x04file\x18\x01\x20\x01(\x0b2\x12.kythe.proto\
+    .VNameR\x04file\x12H\n\x05index\x18\x02\x20\x01(\x0b20.kythe.proto.servi\
+    ng.xrefs.FileDecorations.IndexH\0R\x05index\x12E\n\x04text\x18\x03\x20\
+    \x01(\x0b2/.kythe.proto.serving.xrefs.FileDecorations.TextH\0R\x04text\
+    \x12K\n\x06target\x18\x04\x20\x01(\x0b21.kythe.proto.serving.xrefs.FileD\
+    ecorations.TargetH\0R\x06target\x12d\n\x0ftarget_override\x18\x05\x20\
+    \x01(\x0b29.kythe.proto.serving.xrefs.FileDecorations.TargetOverrideH\0R\
+    \x0etargetOverride\x12X\n\x0btarget_node\x18\x06\x20\x01(\x0b25.kythe.pr\
+    oto.serving.xrefs.FileDecorations.TargetNodeH\0R\ntargetNode\x12j\n\x11t\
+    arget_definition\x18\x07\x20\x01(\x0b2;.kythe.proto.serving.xrefs.FileDe\
+    corations.TargetDefinitionH\0R\x10targetDefinition\x12p\n\x13definition_\
+    location\x18\x08\x20\x01(\x0b2=.kythe.proto.serving.xrefs.FileDecoration\
+    s.DefinitionLocationH\0R\x12definitionLocation\x12Q\n\x08override\x18\t\
+    \x20\x01(\x0b23.kythe.proto.serving.xrefs.FileDecorations.OverrideH\0R\
+    \x08override\x12W\n\ndiagnostic\x18\n\x20\x01(\x0b25.kythe.proto.serving\
+    .xrefs.FileDecorations.DiagnosticH\0R\ndiagnostic\x1a,\n\x05Index\x12#\n\
+    \rtext_encoding\x18\x01\x20\x01(\tR\x0ctextEncoding\x1a\\\n\x04Text\x12!\
+    \n\x0cstart_offset\x18\x01\x20\x01(\x05R\x0bstartOffset\x12\x1d\n\nend_o\
+    ffset\x18\x02\x20\x01(\x05R\tendOffset\x12\x12\n\x04text\x18\x03\x20\x01\
+    (\x0cR\x04text\x1a\x85\x02\n\x06Target\x12!\n\x0cstart_offset\x18\x01\
+    \x20\x01(\x05R\x0bstartOffset\x12\x1d\n\nend_offset\x18\x02\x20\x01(\x05\
+    R\tendOffset\x12=\n\nkythe_kind\x18\x03\x20\x01(\x0e2\x1c.kythe.proto.sc\
+    hema.EdgeKindH\0R\tkytheKind\x12#\n\x0cgeneric_kind\x18\x04\x20\x01(\tH\
+    \0R\x0bgenericKind\x12*\n\x06target\x18\x05\x20\x01(\x0b2\x12.kythe.prot\
+    o.VNameR\x06target\x12!\n\x0cbuild_config\x18\x06\x20\x01(\tR\x0bbuildCo\
+    nfigB\x06\n\x04kind\x1a\x9a\x02\n\x0eTargetOverride\x122\n\noverridden\
+    \x18\x01\x20\x01(\x0b2\x12.kythe.proto.VNameR\noverridden\x12F\n\x04kind\
+    \x18\x02\x20\x01(\x0e22.kythe.proto.serving.FileDecorations.Override.Kin\
+    dR\x04kind\x122\n\noverriding\x18\x03\x20\x01(\x0b2\x12.kythe.proto.VNam\
+    eR\noverriding\x12X\n\x15overriding_definition\x18\x04\x20\x01(\x0b2#.ky\
+    the.proto.serving.ExpandedAnchorR\x14overridingDefinition\x1a:\n\nTarget\
+    Node\x12,\n\x04node\x18\x01\x20\x01(\x0b2\x18.kythe.proto.schema.NodeR\
+    \x04node\x1ar\n\x10TargetDefinition\x12*\n\x06target\x18\x01\x20\x01(\
+    \x0b2\x12.kythe.proto.VNameR\x06target\x122\n\ndefinition\x18\x02\x20\
+    \x01(\x0b2\x12.kythe.proto.VNameR\ndefinition\x1aU\n\x12DefinitionLocati\
+    on\x12?\n\x08location\x18\x01\x20\x01(\x0b2#.kythe.proto.serving.Expande\
+    dAnchorR\x08location\x1a\x81\x01\n\x08Override\x12.\n\x08override\x18\
+    \x01\x20\x01(\x0b2\x12.kythe.proto.VNameR\x08override\x12E\n\rmarked_sou\
+    rce\x18\x02\x20\x01(\x0b2\x20.kythe.proto.common.MarkedSourceR\x0cmarked\
+    Source\x1aL\n\nDiagnostic\x12>\n\ndiagnostic\x18\x01\x20\x01(\x0b2\x1e.k\
+    ythe.proto.common.DiagnosticR\ndiagnosticB\x07\n\x05entry\"\xcc\x0e\n\
+    \x0fCrossReferences\x12*\n\x06source\x18\x01\x20\x01(\x0b2\x12.kythe.pro\
+    to.VNameR\x06source\x12H\n\x05index\x18\x02\x20\x01(\x0b20.kythe.proto.s\
+    erving.xrefs.CrossReferences.IndexH\0R\x05index\x12T\n\treference\x18\
+    \x03\x20\x01(\x0b24.kythe.proto.serving.xrefs.CrossReferences.ReferenceH\
+    \0R\treference\x12Q\n\x08relation\x18\x04\x20\x01(\x0b23.kythe.proto.ser\
+    ving.xrefs.CrossReferences.RelationH\0R\x08relation\x12K\n\x06caller\x18\
+    \x05\x20\x01(\x0b21.kythe.proto.serving.xrefs.CrossReferences.CallerH\0R\
+    \x06caller\x12Q\n\x08callsite\x18\x06\x20\x01(\x0b23.kythe.proto.serving\
+    .xrefs.CrossReferences.CallsiteH\0R\x08callsite\x12[\n\x0crelated_node\
+    \x18\x07\x20\x01(\x0b26.kythe.proto.serving.xrefs.CrossReferences.Relate\
+    dNodeH\0R\x0brelatedNode\x12d\n\x0fnode_definition\x18\x08\x20\x01(\x0b2\
+    9.kythe.proto.serving.xrefs.CrossReferences.NodeDefinitionH\0R\x0enodeDe\
+    finition\x1a\xaf\x01\n\x05Index\x12,\n\x04node\x18\x01\x20\x01(\x0b2\x18\
+    .kythe.proto.schema.NodeR\x04node\x12E\n\rmarked_source\x18\x02\x20\x01(\
+    \x0b2\x20.kythe.proto.common.MarkedSourceR\x0cmarkedSource\x121\n\nmerge\
+    _with\x18\x03\x20\x03(\x0b2\x12.kythe.proto.VNameR\tmergeWith\x1a\xb8\
+    \x01\n\tReference\x12=\n\nkythe_kind\x18\x01\x20\x01(\x0e2\x1c.kythe.pro\
+    to.schema.EdgeKindH\0R\tkytheKind\x12#\n\x0cgeneric_kind\x18\x02\x20\x01\
+    (\tH\0R\x0bgenericKind\x12?\n\x08location\x18\x03\x20\x01(\x0b2#.kythe.p\
+    roto.serving.ExpandedAnchorR\x08locationB\x06\n\x04kind\x1a\xd2\x01\n\
+    \x08Relation\x12&\n\x04node\x18\x01\x20\x01(\x0b2\x12.kythe.proto.VNameR\
+    \x04node\x12=\n\nkythe_kind\x18\x02\x20\x01(\x0e2\x1c.kythe.proto.schema\
+    .EdgeKindH\0R\tkytheKind\x12#\n\x0cgeneric_kind\x18\x03\x20\x01(\tH\0R\
+    \x0bgenericKind\x12\x18\n\x07ordinal\x18\x04\x20\x01(\x05R\x07ordinal\
+    \x12\x18\n\x07reverse\x18\x05\x20\x01(\x08R\x07reverseB\x06\n\x04kind\
+    \x1a\xbc\x01\n\x06Caller\x12*\n\x06caller\x18\x01\x20\x01(\x0b2\x12.kyth\
+    e.proto.VNameR\x06caller\x12?\n\x08location\x18\x02\x20\x01(\x0b2#.kythe\
+    .proto.serving.ExpandedAnchorR\x08location\x12E\n\rmarked_source\x18\x03\
+    \x20\x01(\x0b2\x20.kythe.proto.common.MarkedSourceR\x0cmarkedSource\x1a\
+    \xf4\x01\n\x08Callsite\x12*\n\x06caller\x18\x01\x20\x01(\x0b2\x12.kythe.\
+    proto.VNameR\x06caller\x12?\n\x08location\x18\x02\x20\x01(\x0b2#.kythe.p\
+    roto.serving.ExpandedAnchorR\x08location\x12L\n\x04kind\x18\x03\x20\x01(\
+    \x0e28.kythe.proto.serving.xrefs.CrossReferences.Callsite.KindR\x04kind\
+    \"-\n\x04Kind\x12\x0b\n\x07UNKNOWN\x10\0\x12\n\n\x06DIRECT\x10\x01\x12\
+    \x0c\n\x08OVERRIDE\x10\x02\x1a;\n\x0bRelatedNode\x12,\n\x04node\x18\x01\
+    \x20\x01(\x0b2\x18.kythe.proto.schema.NodeR\x04node\x1ay\n\x0eNodeDefini\
+    tion\x12&\n\x04node\x18\x01\x20\x01(\x0b2\x12.kythe.proto.VNameR\x04node\
+    \x12?\n\x08location\x18\x02\x20\x01(\x0b2#.kythe.proto.serving.ExpandedA\
+    nchorR\x08locationB\x07\n\x05entryBM\n\x1fcom.google.devtools.kythe.prot\
+    oZ*kythe.io/kythe/proto/xref_serving_go_protob\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,226 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Inspects and manipulates kzip archives.
+//!
+//! Usage:
+//!   kzip info --input in.kzip
+//!   kzip view --input in.kzip [DIGEST...]
+//!   kzip merge --output out.kzip [--encoding=ENC] [--fail_on_conflict] in.kzip...
+//!   kzip filter --input in.kzip --output out.kzip [--encoding=ENC]
+//!               [--languages=go,java] [--corpora=C] [--path=REGEX]
+//!   kzip create --output out.kzip [--encoding=ENC] --unit unit.json [FILE...]
+
+use analysis_rust_proto::analysis::CompilationUnit;
+use anyhow::{Context, Result};
+use clap::{Parser, Subcommand};
+use kythe_kzip::{descriptors, ConflictPolicy, Encoding, Reader, UnitFilter, Writer};
+use regex::Regex;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+#[derive(Parser)]
+#[command(about = "Inspects and manipulates kzip archives")]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Prints a summary of a kzip as KzipInfo JSON.
+    Info {
+        /// The kzip to summarize.
+        #[arg(long)]
+        input: PathBuf,
+    },
+    /// Prints compilation units as IndexedCompilation JSON, one per line.
+    View {
+        /// The kzip to read.
+        #[arg(long)]
+        input: PathBuf,
+        /// The digests of the units to print; all units if none are given.
+        digests: Vec<String>,
+    },
+    /// Merges kzips into one, removing duplicate units and files.
+    Merge {
+        #[command(flatten)]
+        output: Output,
+        /// Fail if two units share a v_name but differ, rather than keeping
+        /// the first.
+        #[arg(long = "fail_on_conflict")]
+        fail_on_conflict: bool,
+        /// The kzips to merge.
+        #[arg(required = true)]
+        inputs: Vec<PathBuf>,
+    },
+    /// Copies the units of a kzip that match all of the given predicates,
+    /// along with the files they require.
+    Filter {
+        /// The kzip to filter.
+        #[arg(long)]
+        input: PathBuf,
+        #[command(flatten)]
+        output: Output,
+        /// Keep units in one of these languages.
+        #[arg(long, value_delimiter = ',')]
+        languages: Vec<String>,
+        /// Keep units in one of these corpora.
+        #[arg(long, value_delimiter = ',')]
+        corpora: Vec<String>,
+        /// Keep units with a source file matching this regular expression.
+        #[arg(long)]
+        path: Option<Regex>,
+    },
+    /// Creates a kzip holding a single compilation unit and its files.
+    Create {
+        #[command(flatten)]
+        output: Output,
+        /// A CompilationUnit in JSON form.
+        #[arg(long)]
+        unit: PathBuf,
+        /// Files to add as required inputs, by their path in the unit.
+        files: Vec<String>,
+    },
+}
+
+/// Where and how a subcommand writes its kzip.
+#[derive(clap::Args)]
+struct Output {
+    /// The path of the kzip to write.
+    #[arg(long)]
+    output: PathBuf,
+    /// The encoding of the units written: json, proto or all.
+    #[arg(long, default_value = "proto")]
+    encoding: Encoding,
+}
+
+impl Output {
+    fn create(&self) -> Result<Writer<BufWriter<File>>> {
+        let path = &self.output;
+        let file = File::create(path).with_context(|| format!("creating {}", path.display()))?;
+        Ok(Writer::new(BufWriter::new(file))?.with_encoding(self.encoding))
+    }
+
+    fn finish(&self, mut w: BufWriter<File>) -> Result<()> {
+        w.flush()
+            .with_context(|| format!("writing {}", self.output.display()))
+    }
+}
+
+fn main() -> Result<()> {
+    match Args::parse().command {
+        Command::Info { input } => info(&input),
+        Command::View { input, digests } => view(&input, &digests),
+        Command::Merge {
+            output,
+            fail_on_conflict,
+            inputs,
+        } => {
+            let policy = if fail_on_conflict {
+                ConflictPolicy::Fail
+            } else {
+                ConflictPolicy::KeepFirst
+            };
+            merge(&output, &inputs, policy)
+        }
+        Command::Filter {
+            input,
+            output,
+            languages,
+            corpora,
+            path,
+        } => {
+            let filter = UnitFilter {
+                languages,
+                corpora,
+                path,
+            };
+            filter_units(&input, &output, &filter)
+        }
+        Command::Create {
+            output,
+            unit,
+            files,
+        } => create(&output, &unit, &files),
+    }
+}
+
+fn open(path: &Path) -> Result<Reader<BufReader<File>>> {
+    let file = File::open(path).with_context(|| format!("opening {}", path.display()))?;
+    Reader::new(BufReader::new(file)).with_context(|| format!("reading {}", path.display()))
+}
+
+fn info(input: &Path) -> Result<()> {
+    let size = std::fs::metadata(input)
+        .with_context(|| format!("reading {}", input.display()))?
+        .len();
+    let info = kythe_kzip::info(&mut open(input)?, size as i64)?;
+    println!("{}", descriptors().to_json(&info)?);
+    Ok(())
+}
+
+fn view(input: &Path, digests: &[String]) -> Result<()> {
+    let units = kythe_kzip::view(&mut open(input)?, digests)?;
+    let d = descriptors();
+    let mut out = BufWriter::new(io::stdout().lock());
+    for ic in units {
+        writeln!(out, "{}", d.to_json(&ic)?)?;
+    }
+    out.flush()?;
+    Ok(())
+}
+
+fn merge(output: &Output, inputs: &[PathBuf], policy: ConflictPolicy) -> Result<()> {
+    let mut readers = inputs
+        .iter()
+        .map(|input| open(input))
+        .collect::<Result<Vec<_>>>()?;
+    let (w, stats) = kythe_kzip::merge(output.create()?, &mut readers, policy)?;
+    if stats.conflicting_units > 0 {
+        eprintln!(
+            "kzip: dropped {} units conflicting with earlier ones",
+            stats.conflicting_units
+        );
+    }
+    output.finish(w)
+}
+
+fn filter_units(input: &Path, output: &Output, filter: &UnitFilter) -> Result<()> {
+    let mut w = output.create()?;
+    kythe_kzip::filter(&mut open(input)?, &mut w, filter)?;
+    output.finish(w.into_inner()?)
+}
+
+fn create(output: &Output, unit_path: &Path, files: &[String]) -> Result<()> {
+    let json =
+        std::fs::read(unit_path).with_context(|| format!("reading {}", unit_path.display()))?;
+    let unit: CompilationUnit = descriptors()
+        .from_json(&serde_json::from_slice(&json)?)
+        .with_context(|| format!("parsing {}", unit_path.display()))?;
+    let files = files
+        .iter()
+        .map(|path| {
+            let data = std::fs::read(path).with_context(|| format!("reading {path}"))?;
+            Ok((path.clone(), data))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut w = output.create()?;
+    let digest = kythe_kzip::create(&mut w, unit, &files)?;
+    output.finish(w.into_inner()?)?;
+    eprintln!("kzip: created unit {digest}");
+    Ok(())
+}
@@ -0,0 +1,298 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The operations of the `kzip` tool that go beyond reading and writing a
+//! single archive.
+
+use crate::{ConflictPolicy, Error, MergeStats, Merger, Reader, Result, Unit, Writer};
+use analysis_rust_proto::analysis::{
+    CompilationUnit, CompilationUnit_FileInput, IndexedCompilation, IndexedCompilation_Index,
+};
+use regex::Regex;
+use std::collections::HashSet;
+use std::io::{Read, Seek, Write};
+
+/// Returns the units with the given digests, or all units if `digests` is
+/// empty, along with their indices.
+pub fn view<R: Read + Seek>(
+    r: &mut Reader<R>,
+    digests: &[String],
+) -> Result<Vec<IndexedCompilation>> {
+    let all;
+    let digests = if digests.is_empty() {
+        all = r.unit_digests();
+        &all
+    } else {
+        digests
+    };
+    digests
+        .iter()
+        .map(|digest| {
+            let unit = r.lookup(digest)?;
+            let mut ic = IndexedCompilation::new();
+            ic.set_unit(unit.proto);
+            ic.set_index(unit.index);
+            Ok(ic)
+        })
+        .collect()
+}
+
+/// Merges the units and files of `inputs` into `w`, returning the underlying
+/// writer and what was merged.
+pub fn merge<R, W>(
+    w: Writer<W>,
+    inputs: &mut [Reader<R>],
+    policy: ConflictPolicy,
+) -> Result<(W, MergeStats)>
+where
+    R: Read + Seek,
+    W: Write + Seek,
+{
+    let mut m = Merger::new(w, policy);
+    for r in inputs {
+        m.add(r)?;
+    }
+    let stats = m.stats();
+    Ok((m.into_inner()?, stats))
+}
+
+/// Selects compilation units by language, corpus and source path. Empty
+/// criteria match every unit.
+#[derive(Clone, Debug, Default)]
+pub struct UnitFilter {
+    /// Keep units in one of these languages.
+    pub languages: Vec<String>,
+    /// Keep units in one of these corpora.
+    pub corpora: Vec<String>,
+    /// Keep units with a source file matching this regular expression.
+    pub path: Option<Regex>,
+}
+
+impl UnitFilter {
+    /// Reports whether `unit` matches all of the criteria.
+    pub fn matches(&self, unit: &CompilationUnit) -> bool {
+        let vname = unit.get_v_name();
+        (self.languages.is_empty() || self.languages.iter().any(|l| l == vname.get_language()))
+            && (self.corpora.is_empty() || self.corpora.iter().any(|c| c == vname.get_corpus()))
+            && self
+                .path
+                .iter()
+                .all(|re| unit.get_source_file().iter().any(|s| re.is_match(s)))
+    }
+}
+
+/// Copies the units of `r` matching `filter` to `w`, along with the files
+/// they require, and returns the number of units copied.
+pub fn filter<R, W>(r: &mut Reader<R>, w: &mut Writer<W>, filter: &UnitFilter) -> Result<usize>
+where
+    R: Read + Seek,
+    W: Write + Seek,
+{
+    let mut copied = 0;
+    for digest in r.unit_digests() {
+        let unit = r.lookup(&digest)?;
+        if filter.matches(&unit.proto) {
+            copy_unit(r, w, unit)?;
+            copied += 1;
+        }
+    }
+    Ok(copied)
+}
+
+/// Copies `unit` and its required inputs from `r` to `w`, skipping units
+/// and files that `w` already holds.
+fn copy_unit<R, W>(r: &mut Reader<R>, w: &mut Writer<W>, unit: Unit) -> Result<()>
+where
+    R: Read + Seek,
+    W: Write + Seek,
+{
+    for ri in unit.proto.get_required_input() {
+        let digest = ri.get_info().get_digest();
+        if !w.has_file(digest) {
+            w.add_file(&r.read_file(digest)?)?;
+        }
+    }
+    match w.add_unit(unit.proto, unit.index) {
+        Ok(_) | Err(Error::UnitExists(_)) => Ok(()),
+        Err(err) => Err(err),
+    }
+}
+
+/// Adds `unit` to `w` along with `files`, given as pairs of paths and
+/// contents, and returns the digest of the unit. Each file fills in the
+/// digest of the required input with its path, or is added as a new
+/// required input; every required input must be given a file.
+pub fn create<W: Write + Seek>(
+    w: &mut Writer<W>,
+    mut unit: CompilationUnit,
+    files: &[(String, Vec<u8>)],
+) -> Result<String> {
+    let mut added = HashSet::new();
+    for (path, data) in files {
+        let digest = w.add_file(data)?;
+        added.insert(digest.clone());
+        let inputs = unit.mut_required_input();
+        match inputs
+            .iter_mut()
+            .find(|ri| ri.get_info().get_path() == path)
+        {
+            Some(ri) => ri.mut_info().set_digest(digest),
+            None => {
+                let mut ri = CompilationUnit_FileInput::new();
+                ri.mut_v_name().set_path(path.clone());
+                ri.mut_info().set_path(path.clone());
+                ri.mut_info().set_digest(digest);
+                inputs.push(ri);
+            }
+        }
+    }
+    for ri in unit.get_required_input() {
+        if !added.contains(ri.get_info().get_digest()) {
+            return Err(Error::InvalidArgument(format!(
+                "no file given for required input {:?}",
+                ri.get_info().get_path()
+            )));
+        }
+    }
+    w.add_unit(unit, IndexedCompilation_Index::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{info, Encoding};
+    use std::io::Cursor;
+
+    fn unit(corpus: &str, language: &str, source: &str) -> CompilationUnit {
+        let mut unit = CompilationUnit::new();
+        unit.mut_v_name().set_corpus(corpus.into());
+        unit.mut_v_name().set_language(language.into());
+        unit.mut_source_file().push(source.into());
+        unit
+    }
+
+    fn writer() -> Writer<Cursor<Vec<u8>>> {
+        Writer::new(Cursor::new(Vec::new())).unwrap()
+    }
+
+    fn reader(w: Writer<Cursor<Vec<u8>>>) -> Reader<Cursor<Vec<u8>>> {
+        Reader::new(Cursor::new(w.into_inner().unwrap().into_inner())).unwrap()
+    }
+
+    /// Returns a kzip holding a Go unit in corpus a and a Java unit in
+    /// corpus b, each with one source file.
+    fn archive() -> Reader<Cursor<Vec<u8>>> {
+        let mut w = writer().with_encoding(Encoding::All);
+        create(
+            &mut w,
+            unit("a", "go", "a.go"),
+            &[("a.go".into(), b"a".to_vec())],
+        )
+        .unwrap();
+        create(
+            &mut w,
+            unit("b", "java", "B.java"),
+            &[("B.java".into(), b"b".to_vec())],
+        )
+        .unwrap();
+        reader(w)
+    }
+
+    #[test]
+    fn creates_units() {
+        let mut r = archive();
+        let units = view(&mut r, &[]).unwrap();
+        assert_eq!(units.len(), 2);
+        let go = units
+            .iter()
+            .map(IndexedCompilation::get_unit)
+            .find(|u| u.get_v_name().get_language() == "go")
+            .unwrap();
+        let ri = &go.get_required_input()[0];
+        assert_eq!(ri.get_v_name().get_path(), "a.go");
+        assert_eq!(r.read_file(ri.get_info().get_digest()).unwrap(), b"a");
+
+        let digests = r.unit_digests();
+        let one = view(&mut r, &digests[..1]).unwrap();
+        assert_eq!(one.len(), 1);
+        assert_eq!(*one[0].get_unit(), r.lookup(&digests[0]).unwrap().proto);
+        assert!(view(&mut r, &["nope".into()]).is_err());
+
+        let mut missing = unit("a", "go", "a.go");
+        let mut ri = CompilationUnit_FileInput::new();
+        ri.mut_info().set_path("lib.go".into());
+        missing.mut_required_input().push(ri);
+        assert!(matches!(
+            create(&mut writer(), missing, &[("a.go".into(), b"a".to_vec())]),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn filters_units() {
+        let check = |filter: &UnitFilter, want: &[&str]| {
+            let mut w = writer();
+            let copied = super::filter(&mut archive(), &mut w, filter).unwrap();
+            assert_eq!(copied, want.len());
+            let mut r = reader(w);
+            let info = info(&mut r, 0).unwrap();
+            let mut corpora: Vec<&str> = info.get_corpora().keys().map(String::as_str).collect();
+            corpora.sort();
+            assert_eq!(corpora, want);
+            for ic in view(&mut r, &[]).unwrap() {
+                for ri in ic.get_unit().get_required_input() {
+                    assert!(r.read_file(ri.get_info().get_digest()).is_ok());
+                }
+            }
+        };
+        check(&UnitFilter::default(), &["a", "b"]);
+        let languages = UnitFilter {
+            languages: vec!["java".into(), "c++".into()],
+            ..Default::default()
+        };
+        check(&languages, &["b"]);
+        let corpora = UnitFilter {
+            corpora: vec!["a".into()],
+            ..Default::default()
+        };
+        check(&corpora, &["a"]);
+        let path = UnitFilter {
+            path: Some(Regex::new(r"\.go$").unwrap()),
+            ..Default::default()
+        };
+        check(&path, &["a"]);
+        let none = UnitFilter {
+            corpora: vec!["b".into()],
+            ..path
+        };
+        check(&none, &[]);
+    }
+
+    #[test]
+    fn merges_archives() {
+        let only_b = UnitFilter {
+            corpora: vec!["b".into()],
+            ..Default::default()
+        };
+        let mut w = writer();
+        super::filter(&mut archive(), &mut w, &only_b).unwrap();
+        let mut inputs = [archive(), reader(w)];
+        let (data, stats) = merge(writer(), &mut inputs, ConflictPolicy::Fail).unwrap();
+        assert_eq!(stats.units, 2);
+        assert_eq!(stats.duplicate_units, 1);
+        assert_eq!(stats.files, 2);
+        let mut r = Reader::new(Cursor::new(data.into_inner())).unwrap();
+        assert_eq!(view(&mut r, &[]).unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,152 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Summarizing the contents of kzip archives, as `kythe/go/platform/kzip/info`.
+
+use crate::{format_vname, Reader, Result};
+use analysis_rust_proto::analysis::{CompilationUnit, KzipInfo, KzipInfo_CorpusInfo};
+use kythe_uri::clean_path;
+use std::collections::BTreeSet;
+use std::io::{Read, Seek};
+
+/// Returns a summary of the units in the kzip read by `r`, whose file is
+/// `size` bytes long.
+pub fn info<R: Read + Seek>(r: &mut Reader<R>, size: i64) -> Result<KzipInfo> {
+    let mut acc = Accumulator::new(size);
+    for digest in r.unit_digests() {
+        acc.accumulate(&r.lookup(&digest)?.proto);
+    }
+    Ok(acc.into_info())
+}
+
+/// Accumulates a [`KzipInfo`] one compilation unit at a time.
+#[derive(Clone, Debug, Default)]
+pub struct Accumulator {
+    info: KzipInfo,
+    absolute_paths: BTreeSet<String>,
+}
+
+impl Accumulator {
+    /// Returns an empty accumulator for a kzip file of `size` bytes.
+    pub fn new(size: i64) -> Self {
+        let mut info = KzipInfo::new();
+        info.set_size(size);
+        Self {
+            info,
+            absolute_paths: BTreeSet::new(),
+        }
+    }
+
+    /// Adds the counts for `unit`, recording any problems found with it in
+    /// `critical_kzip_errors`.
+    pub fn accumulate(&mut self, unit: &CompilationUnit) {
+        let vname = unit.get_v_name();
+        let lang = vname.get_language();
+        if lang.is_empty() {
+            self.error(format!(
+                "CU({}) does not specify a language",
+                format_vname(vname)
+            ));
+            return;
+        }
+        let cu_info = corpus_info(&mut self.info, vname.get_corpus())
+            .mut_language_cu_info()
+            .entry(lang.to_string())
+            .or_default();
+        cu_info.set_count(cu_info.get_count() + 1);
+        if lang == "java" {
+            *cu_info
+                .mut_java_version_count()
+                .entry(java_source_version(unit.get_argument()))
+                .or_default() += 1;
+        }
+
+        let srcs: BTreeSet<String> = unit
+            .get_source_file()
+            .iter()
+            .map(|p| clean_path(p))
+            .collect();
+        let mut srcs_with_input = BTreeSet::new();
+        for ri in unit.get_required_input() {
+            let path = ri.get_v_name().get_path();
+            if path.starts_with('/') && !path.starts_with("/kythe_builtins/") {
+                self.absolute_paths.insert(path.to_string());
+            }
+            let corpus = match ri.get_v_name().get_corpus() {
+                "" => vname.get_corpus(),
+                corpus => corpus,
+            };
+            if corpus.is_empty() {
+                self.error(format!(
+                    "unable to determine corpus for required_input {:?} in CU({})",
+                    ri.get_info().get_path(),
+                    format_vname(vname)
+                ));
+                return;
+            }
+            let corpus_info = corpus_info(&mut self.info, corpus);
+            let inputs = corpus_info
+                .mut_language_required_inputs()
+                .entry(lang.to_string())
+                .or_default();
+            inputs.set_count(inputs.get_count() + 1);
+            let input_path = clean_path(ri.get_info().get_path());
+            if srcs.contains(&input_path) {
+                let sources = corpus_info
+                    .mut_language_sources()
+                    .entry(lang.to_string())
+                    .or_default();
+                sources.set_count(sources.get_count() + 1);
+                srcs_with_input.insert(input_path);
+            }
+        }
+        for path in srcs.difference(&srcs_with_input) {
+            self.error(format!(
+                "source {path:?} in CU({}) doesn't have a required_input entry",
+                format_vname(vname)
+            ));
+        }
+    }
+
+    /// Returns the summary accumulated so far.
+    pub fn info(&self) -> KzipInfo {
+        let mut info = self.info.clone();
+        info.set_absolute_paths(self.absolute_paths.iter().cloned().collect());
+        info
+    }
+
+    /// Returns the accumulated summary.
+    pub fn into_info(mut self) -> KzipInfo {
+        self.info
+            .set_absolute_paths(self.absolute_paths.into_iter().collect());
+        self.info
+    }
+
+    fn error(&mut self, msg: String) {
+        self.info.mut_critical_kzip_errors().push(msg);
+    }
+}
+
+fn corpus_info<'a>(info: &'a mut KzipInfo, corpus: &str) -> &'a mut KzipInfo_CorpusInfo {
+    info.mut_corpora().entry(corpus.to_string()).or_default()
+}
+
+/// Returns the Java language version given by a `-source` argument, or 0.
+fn java_source_version(args: &[String]) -> i32 {
+    args.iter()
+        .position(|a| a == "-source" || a == "--source")
+        .and_then(|i| args.get(i + 1))
+        .and_then(|v| v.strip_prefix("1.").unwrap_or(v).parse().ok())
+        .unwrap_or(0)
+}
@@ -0,0 +1,415 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A reader and writer for kzip archives, compatible with
+//! `kythe/go/platform/kzip`.
+//!
+//! A kzip is a zip archive with a single root directory holding compilation
+//! units and the files they require under `files/`, each named by the hex
+//! SHA-256 digest of its contents. Units are stored as protos under
+//! `pbunits/`, as JSON under `units/`, or both.
+//!
+//! Format spec: https://kythe.io/docs/kythe-kzip.html
+
+mod commands;
+mod info;
+mod merge;
+mod reader;
+mod writer;
+
+pub use commands::{create, filter, merge, view, UnitFilter};
+pub use info::{info, Accumulator};
+pub use merge::{ConflictPolicy, MergeStats, Merger};
+pub use reader::Reader;
+pub use writer::Writer;
+
+use analysis_rust_proto::analysis::{
+    CompilationUnit, CompilationUnit_FileInput, IndexedCompilation_Index,
+};
+use analysis_rust_proto::storage::VName;
+use kythe_protojson::Descriptors;
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Errors produced while reading or writing kzip archives.
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("zip error: {0}")]
+    Zip(#[from] zip::result::ZipError),
+    #[error("protobuf error: {0}")]
+    Proto(#[from] protobuf::ProtobufError),
+    #[error("invalid argument: {0}")]
+    InvalidArgument(String),
+    #[error("invalid kzip: {0}")]
+    InvalidArchive(String),
+    #[error("JSON error: {0}")]
+    Json(#[from] kythe_protojson::Error),
+    #[error("digest not found: {0}")]
+    DigestNotFound(String),
+    #[error("unit already exists: {0}")]
+    UnitExists(String),
+    #[error("units {first} and {second} both have v_name {vname}")]
+    Conflict {
+        vname: String,
+        first: String,
+        second: String,
+    },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The directory holding proto-encoded compilation units.
+const PROTO_UNITS: &str = "pbunits";
+/// The directory holding JSON-encoded compilation units.
+const JSON_UNITS: &str = "units";
+/// The directory holding required input files.
+const FILES: &str = "files";
+
+/// The encodings in which compilation units are stored.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Encoding {
+    /// JSON, under `units/`.
+    Json,
+    /// Wire-format protos, under `pbunits/`.
+    #[default]
+    Proto,
+    /// Both JSON and proto.
+    All,
+}
+
+impl Encoding {
+    fn has_json(self) -> bool {
+        self != Encoding::Proto
+    }
+
+    fn has_proto(self) -> bool {
+        self != Encoding::Json
+    }
+}
+
+impl FromStr for Encoding {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "json" => Ok(Encoding::Json),
+            "proto" => Ok(Encoding::Proto),
+            "all" => Ok(Encoding::All),
+            _ => Err(Error::InvalidArgument(format!("unknown encoding: {s:?}"))),
+        }
+    }
+}
+
+impl fmt::Display for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Encoding::Json => "JSON",
+            Encoding::Proto => "Proto",
+            Encoding::All => "All",
+        })
+    }
+}
+
+/// Returns the descriptors used by default to convert units to and from
+/// JSON: those of [`kythe_ptypes::Registry::kythe`], which covers the details
+/// written by Kythe's own extractors. Units whose details hold other message
+/// types need a [`Reader`] or [`Writer`] given descriptors for those types as
+/// well.
+pub fn descriptors() -> Descriptors {
+    kythe_ptypes::Registry::kythe().descriptors().clone()
+}
+
+/// A compilation unit read from a kzip, along with its digest.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Unit {
+    pub digest: String,
+    pub proto: CompilationUnit,
+    pub index: IndexedCompilation_Index,
+}
+
+/// Returns the hex SHA-256 digest of `data`, as used to name kzip files.
+pub fn file_digest(data: &[u8]) -> String {
+    hex(&Sha256::digest(data))
+}
+
+/// Orders the required inputs of `unit` by digest and path (dropping
+/// duplicates), its environment variables by name, its source files by path
+/// and its details by type URL, so that equivalent units have equal digests.
+pub fn canonicalize(unit: &mut CompilationUnit) {
+    let mut inputs = unit.take_required_input().into_vec();
+    inputs.sort_by(|a, b| input_key(a).cmp(&input_key(b)));
+    inputs.dedup_by(|a, b| input_key(a) == input_key(b));
+    unit.set_required_input(inputs.into());
+    unit.mut_environment()
+        .sort_by(|a, b| a.get_name().cmp(b.get_name()));
+    unit.mut_source_file().sort();
+    unit.mut_details()
+        .sort_by(|a, b| a.get_type_url().cmp(b.get_type_url()));
+}
+
+fn input_key(ri: &CompilationUnit_FileInput) -> (&str, &str) {
+    (ri.get_info().get_digest(), ri.get_info().get_path())
+}
+
+/// Returns the digest of a (canonicalized) compilation unit, as computed by
+/// the Go `kcd/kythe` package.
+pub fn unit_digest(unit: &CompilationUnit) -> String {
+    let mut sha = Sha256::new();
+    let mut put = |tag: &str, values: &[&[u8]]| {
+        sha.update(tag);
+        sha.update("\n");
+        for v in values {
+            sha.update(v);
+            sha.update("\0");
+        }
+    };
+    put("CU", &vname_fields(unit.get_v_name()));
+    for ri in unit.get_required_input() {
+        put("RI", &vname_fields(ri.get_v_name()));
+        put(
+            "IN",
+            &[
+                ri.get_info().get_path().as_bytes(),
+                ri.get_info().get_digest().as_bytes(),
+            ],
+        );
+    }
+    put("ARG", &strs(unit.get_argument()));
+    put("OUT", &[unit.get_output_key().as_bytes()]);
+    put("SRC", &strs(unit.get_source_file()));
+    put("CWD", &[unit.get_working_directory().as_bytes()]);
+    put("CTX", &[unit.get_entry_context().as_bytes()]);
+    for env in unit.get_environment() {
+        put(
+            "ENV",
+            &[env.get_name().as_bytes(), env.get_value().as_bytes()],
+        );
+    }
+    for d in unit.get_details() {
+        put("DET", &[d.get_type_url().as_bytes(), d.get_value()]);
+    }
+    hex(&sha.finalize())
+}
+
+fn vname_fields(v: &VName) -> [&[u8]; 5] {
+    [
+        v.get_signature(),
+        v.get_corpus(),
+        v.get_root(),
+        v.get_path(),
+        v.get_language(),
+    ]
+    .map(str::as_bytes)
+}
+
+fn strs(ss: &[String]) -> Vec<&[u8]> {
+    ss.iter().map(String::as_bytes).collect()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Formats `v` compactly for use in messages.
+fn format_vname(v: &VName) -> String {
+    protobuf::text_format::print_to_string(v).trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use analysis_rust_proto::analysis::CompilationUnit_FileInput;
+    use std::io::Cursor;
+
+    fn unit(corpus: &str, language: &str, sources: &[&str]) -> CompilationUnit {
+        let mut unit = CompilationUnit::new();
+        unit.mut_v_name().set_corpus(corpus.into());
+        unit.mut_v_name().set_language(language.into());
+        unit.set_source_file(sources.iter().map(|s| s.to_string()).collect());
+        unit
+    }
+
+    fn input(path: &str, data: &[u8]) -> CompilationUnit_FileInput {
+        let mut ri = CompilationUnit_FileInput::new();
+        ri.mut_v_name().set_path(path.into());
+        ri.mut_info().set_path(path.into());
+        ri.mut_info().set_digest(file_digest(data));
+        ri
+    }
+
+    #[test]
+    fn unit_digest_matches_go() {
+        // From kythe/testdata/platform/missing-unit.kzip.
+        assert_eq!(
+            unit_digest(&unit("foo", "bar", &["blodgit"])),
+            "8dc36b36bbd39d5ed4fdb3029e1dc5c107d52171c99296f1f3d5b492f619a487"
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut cu = unit("corpus", "go", &["b.go", "a.go"]);
+        cu.mut_required_input().push(input("b.go", b"package b"));
+        cu.mut_required_input().push(input("a.go", b"package a"));
+        cu.mut_required_input().push(input("a.go", b"package a"));
+        let mut index = IndexedCompilation_Index::new();
+        index.mut_revisions().push("r1".into());
+
+        let mut w = Writer::new(Cursor::new(Vec::new())).unwrap();
+        let a = w.add_file(b"package a").unwrap();
+        assert_eq!(w.add_file(b"package a").unwrap(), a);
+        w.add_file(b"package b").unwrap();
+        let digest = w.add_unit(cu.clone(), index.clone()).unwrap();
+        assert!(matches!(
+            w.add_unit(cu.clone(), index.clone()),
+            Err(Error::UnitExists(d)) if d == digest
+        ));
+        let data = w.into_inner().unwrap().into_inner();
+
+        let mut r = Reader::new(Cursor::new(data)).unwrap();
+        assert_eq!(r.root(), "root");
+        assert_eq!(r.unit_digests(), vec![digest.clone()]);
+        let got = r.lookup(&digest).unwrap();
+        canonicalize(&mut cu);
+        assert_eq!(got.proto, cu);
+        assert_eq!(got.index, index);
+        assert_eq!(got.proto.get_required_input().len(), 2);
+        assert_eq!(got.proto.get_source_file(), ["a.go", "b.go"]);
+        assert_eq!(unit_digest(&got.proto), digest);
+        assert_eq!(r.read_file(&a).unwrap(), b"package a");
+        assert!(matches!(r.read_file("nope"), Err(Error::DigestNotFound(_))));
+    }
+
+    #[test]
+    fn accumulates_info() {
+        let mut java = unit("c", "java", &["./A.java", "B.java"]);
+        java.mut_argument().push("-source".into());
+        java.mut_argument().push("1.8".into());
+        java.mut_required_input()
+            .push(input("A.java", b"class A {}"));
+        java.mut_required_input()
+            .push(input("/usr/lib/rt.jar", b"jar"));
+        let mut other = input("lib.h", b"");
+        other.mut_v_name().set_corpus("other".into());
+        java.mut_required_input().push(other);
+
+        let mut acc = Accumulator::new(42);
+        acc.accumulate(&java);
+        acc.accumulate(&unit("c", "", &[]));
+        let info = acc.into_info();
+
+        assert_eq!(info.get_size(), 42);
+        let c = &info.get_corpora()["c"];
+        let cu_info = &c.get_language_cu_info()["java"];
+        assert_eq!(cu_info.get_count(), 1);
+        assert_eq!(cu_info.get_java_version_count()[&8], 1);
+        assert_eq!(c.get_language_required_inputs()["java"].get_count(), 2);
+        assert_eq!(c.get_language_sources()["java"].get_count(), 1);
+        assert_eq!(
+            info.get_corpora()["other"].get_language_required_inputs()["java"].get_count(),
+            1
+        );
+        assert_eq!(info.get_absolute_paths(), ["/usr/lib/rt.jar"]);
+        let errors = info.get_critical_kzip_errors();
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].starts_with("source \"B.java\" in CU("));
+        assert!(errors[1].ends_with("does not specify a language"));
+    }
+
+    #[test]
+    fn encodings() {
+        use metadata_rust_proto::metadata::{GeneratedCodeInfo, GeneratedCodeInfo_Type};
+        use protobuf::well_known_types::Any;
+        use protobuf::Message;
+
+        let mut cu = unit("corpus", "go", &["a.go"]);
+        let mut ri = input("a.go", b"package a");
+        let mut info = GeneratedCodeInfo::new();
+        info.set_field_type(GeneratedCodeInfo_Type::KYTHE0);
+        info.mut_meta().push(Default::default());
+        info.mut_meta()[0].set_edge("/kythe/edge/generates".into());
+        let mut details = Any::new();
+        details.set_type_url("kythe.io/proto/kythe.proto.metadata.GeneratedCodeInfo".into());
+        details.set_value(info.write_to_bytes().unwrap());
+        ri.mut_details().push(details);
+        cu.mut_required_input().push(ri);
+        let mut details = Any::new();
+        details.set_type_url("kythe.io/proto/kythe.proto.VName".into());
+        details.set_value(cu.get_v_name().write_to_bytes().unwrap());
+        cu.mut_details().push(details);
+        let d = || descriptors().with_file(metadata_rust_proto::metadata::file_descriptor_proto());
+
+        for encoding in [Encoding::Json, Encoding::Proto, Encoding::All] {
+            let mut w = Writer::new(Cursor::new(Vec::new()))
+                .unwrap()
+                .with_encoding(encoding)
+                .with_descriptors(d());
+            let digest = w
+                .add_unit(cu.clone(), IndexedCompilation_Index::new())
+                .unwrap();
+            let data = w.into_inner().unwrap().into_inner();
+
+            let mut r = Reader::new(Cursor::new(data.clone())).unwrap();
+            assert_eq!(r.encoding(), encoding);
+            assert_eq!(r.unit_digests(), vec![digest.clone()]);
+            if encoding == Encoding::Json {
+                assert!(matches!(r.lookup(&digest), Err(Error::Json(_))));
+                r = r.with_descriptors(d());
+            }
+            assert_eq!(r.lookup(&digest).unwrap().proto, cu);
+        }
+
+        let mut w = Writer::new(Cursor::new(Vec::new()))
+            .unwrap()
+            .with_encoding(Encoding::Json);
+        assert!(matches!(
+            w.add_unit(cu, IndexedCompilation_Index::new()),
+            Err(Error::Json(kythe_protojson::Error::UnknownType(_)))
+        ));
+
+        assert_eq!("ALL".parse::<Encoding>().unwrap(), Encoding::All);
+        assert_eq!(Encoding::Json.to_string(), "JSON");
+        assert!("xml".parse::<Encoding>().is_err());
+    }
+
+    #[test]
+    fn mismatched_encodings() {
+        for data in [
+            &include_bytes!("../../../testdata/platform/missing-unit.kzip")[..],
+            &include_bytes!("../../../testdata/platform/missing-pbunit.kzip")[..],
+        ] {
+            assert!(matches!(
+                Reader::new(Cursor::new(data)),
+                Err(Error::InvalidArchive(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn language_details() {
+        let data = &include_bytes!("../../../testdata/test.kzip")[..];
+        let mut r = Reader::new(Cursor::new(data)).unwrap();
+        assert_eq!(r.encoding(), Encoding::Json);
+        let digest = r.unit_digests().remove(0);
+        let unit = r.lookup(&digest).unwrap();
+        let details: java_rust_proto::JavaDetails = kythe_ptypes::find(unit.proto.get_details())
+            .unwrap()
+            .unwrap();
+        assert_eq!(details.get_classpath(), ["!CLASS_PATH_JAR!"]);
+    }
+}
@@ -0,0 +1,267 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Merging kzip archives.
+
+use crate::{canonicalize, format_vname, unit_digest, Accumulator, Error, Reader, Result, Writer};
+use analysis_rust_proto::analysis::KzipInfo;
+use analysis_rust_proto::storage::VName;
+use std::collections::HashMap;
+use std::io::{Read, Seek, Write};
+
+/// How a [`Merger`] handles units with the same `v_name` but different
+/// contents.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ConflictPolicy {
+    /// Keep the first unit added and drop the later ones.
+    #[default]
+    KeepFirst,
+    /// Fail with [`Error::Conflict`].
+    Fail,
+}
+
+/// Counts of what a [`Merger`] has done so far.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MergeStats {
+    /// Units written to the merged archive.
+    pub units: u64,
+    /// Units dropped because an identical unit was already written.
+    pub duplicate_units: u64,
+    /// Units dropped because a different unit with the same `v_name` was
+    /// already written.
+    pub conflicting_units: u64,
+    /// Files written to the merged archive.
+    pub files: u64,
+}
+
+/// Streams the units of several kzips into one, deduplicating files by
+/// digest and units by compilation digest, and detecting units that share a
+/// `v_name` but differ in content.
+pub struct Merger<W: Write + Seek> {
+    w: Writer<W>,
+    policy: ConflictPolicy,
+    /// The digest of the unit written for each compilation `v_name`.
+    vnames: HashMap<[String; 5], String>,
+    info: Accumulator,
+    stats: MergeStats,
+}
+
+impl<W: Write + Seek> Merger<W> {
+    /// Returns a merger writing to `w`.
+    pub fn new(w: Writer<W>, policy: ConflictPolicy) -> Self {
+        Self {
+            w,
+            policy,
+            vnames: HashMap::new(),
+            info: Accumulator::new(0),
+            stats: MergeStats::default(),
+        }
+    }
+
+    /// Copies the units of `r`, and the files they require, into the merged
+    /// archive.
+    pub fn add<R: Read + Seek>(&mut self, r: &mut Reader<R>) -> Result<()> {
+        for digest in r.unit_digests() {
+            let mut unit = r.lookup(&digest)?;
+            canonicalize(&mut unit.proto);
+            let digest = unit_digest(&unit.proto);
+            if self.w.has_unit(&digest) {
+                self.stats.duplicate_units += 1;
+                continue;
+            }
+            let vname = unit.proto.get_v_name();
+            let key = vname_key(vname);
+            if let Some(first) = self.vnames.get(&key) {
+                match self.policy {
+                    ConflictPolicy::KeepFirst => {
+                        self.stats.conflicting_units += 1;
+                        continue;
+                    }
+                    ConflictPolicy::Fail => {
+                        return Err(Error::Conflict {
+                            vname: format_vname(vname),
+                            first: first.clone(),
+                            second: digest,
+                        })
+                    }
+                }
+            }
+            for ri in unit.proto.get_required_input() {
+                let file = ri.get_info().get_digest();
+                if !self.w.has_file(file) {
+                    self.w.add_file(&r.read_file(file)?)?;
+                    self.stats.files += 1;
+                }
+            }
+            self.info.accumulate(&unit.proto);
+            self.vnames.insert(key, digest);
+            self.w.add_unit(unit.proto, unit.index)?;
+            self.stats.units += 1;
+        }
+        Ok(())
+    }
+
+    /// Returns a summary of the units merged so far. Its `size` is always 0,
+    /// since the merged archive is not yet complete.
+    pub fn info(&self) -> KzipInfo {
+        self.info.info()
+    }
+
+    /// Returns counts of the units and files merged so far.
+    pub fn stats(&self) -> MergeStats {
+        self.stats
+    }
+
+    /// Finishes the merged archive and returns the underlying writer.
+    pub fn into_inner(self) -> Result<W> {
+        self.w.into_inner()
+    }
+}
+
+/// Returns the key under which units with `v` are checked for conflicts: all
+/// of its fields, so that units with an empty signature conflict as well.
+fn vname_key(v: &VName) -> [String; 5] {
+    [
+        v.get_signature(),
+        v.get_corpus(),
+        v.get_root(),
+        v.get_path(),
+        v.get_language(),
+    ]
+    .map(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file_digest;
+    use analysis_rust_proto::analysis::{
+        CompilationUnit, CompilationUnit_FileInput, IndexedCompilation_Index,
+    };
+    use std::io::Cursor;
+
+    fn unit(signature: &str, language: &str, inputs: &[&str]) -> CompilationUnit {
+        let mut unit = CompilationUnit::new();
+        unit.mut_v_name().set_signature(signature.into());
+        unit.mut_v_name().set_corpus("corpus".into());
+        unit.mut_v_name().set_language(language.into());
+        for path in inputs {
+            let mut ri = CompilationUnit_FileInput::new();
+            ri.mut_v_name().set_path(path.to_string());
+            ri.mut_info().set_path(path.to_string());
+            ri.mut_info().set_digest(file_digest(path.as_bytes()));
+            unit.mut_required_input().push(ri);
+        }
+        unit
+    }
+
+    /// Returns a kzip holding `units`, whose required inputs hold their own
+    /// paths.
+    fn archive(units: &[&CompilationUnit]) -> Reader<Cursor<Vec<u8>>> {
+        let mut w = Writer::new(Cursor::new(Vec::new())).unwrap();
+        for unit in units {
+            for ri in unit.get_required_input() {
+                w.add_file(ri.get_info().get_path().as_bytes()).unwrap();
+            }
+            w.add_unit((*unit).clone(), IndexedCompilation_Index::new())
+                .unwrap();
+        }
+        Reader::new(Cursor::new(w.into_inner().unwrap().into_inner())).unwrap()
+    }
+
+    fn merger(policy: ConflictPolicy) -> Merger<Cursor<Vec<u8>>> {
+        Merger::new(Writer::new(Cursor::new(Vec::new())).unwrap(), policy)
+    }
+
+    fn digest(unit: &CompilationUnit) -> String {
+        let mut unit = unit.clone();
+        canonicalize(&mut unit);
+        unit_digest(&unit)
+    }
+
+    #[test]
+    fn merges() {
+        let a = unit("//a", "go", &["a.go", "common.go"]);
+        let b = unit("//b", "go", &["common.go"]);
+        let mut a2 = a.clone();
+        a2.mut_argument().push("-race".into());
+        // Units with an empty signature are told apart by the rest of their
+        // v_name.
+        let c = unit("", "c++", &[]);
+        let mut c2 = c.clone();
+        c2.mut_argument().push("-O2".into());
+        let d = unit("", "java", &[]);
+
+        let mut m = merger(ConflictPolicy::KeepFirst);
+        m.add(&mut archive(&[&a, &c])).unwrap();
+        assert_eq!(
+            m.info().get_corpora()["corpus"].get_language_cu_info()["go"].get_count(),
+            1
+        );
+        m.add(&mut archive(&[&a, &b, &a2, &c2, &d])).unwrap();
+        assert_eq!(
+            m.stats(),
+            MergeStats {
+                units: 4,
+                duplicate_units: 1,
+                conflicting_units: 2,
+                files: 2,
+            }
+        );
+        let info = m.info();
+        let corpus = &info.get_corpora()["corpus"];
+        assert_eq!(corpus.get_language_cu_info()["go"].get_count(), 2);
+        assert_eq!(corpus.get_language_cu_info()["c++"].get_count(), 1);
+        assert_eq!(corpus.get_language_cu_info()["java"].get_count(), 1);
+        assert_eq!(corpus.get_language_required_inputs()["go"].get_count(), 3);
+
+        let data = m.into_inner().unwrap().into_inner();
+        let mut r = Reader::new(Cursor::new(data)).unwrap();
+        let mut digests = r.unit_digests();
+        digests.sort();
+        let mut want = vec![digest(&a), digest(&b), digest(&c), digest(&d)];
+        want.sort();
+        assert_eq!(digests, want);
+        assert_eq!(
+            r.read_file(&file_digest(b"common.go")).unwrap(),
+            b"common.go"
+        );
+    }
+
+    #[test]
+    fn fails_on_conflict() {
+        let a = unit("//a", "go", &["a.go"]);
+        let mut a2 = a.clone();
+        a2.mut_argument().push("-race".into());
+        let mut m = merger(ConflictPolicy::Fail);
+        m.add(&mut archive(&[&a])).unwrap();
+        m.add(&mut archive(&[&a])).unwrap();
+        assert!(matches!(
+            m.add(&mut archive(&[&a2])),
+            Err(Error::Conflict { first, second, .. })
+                if first == digest(&a) && second == digest(&a2)
+        ));
+
+        let c = unit("", "c++", &[]);
+        let mut c2 = c.clone();
+        c2.mut_argument().push("-O2".into());
+        let mut m = merger(ConflictPolicy::Fail);
+        m.add(&mut archive(&[&c])).unwrap();
+        assert!(matches!(
+            m.add(&mut archive(&[&c2])),
+            Err(Error::Conflict { first, .. }) if first == digest(&c)
+        ));
+        assert_eq!(m.stats().units, 1);
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reading kzip archives.
+
+use crate::{descriptors, Encoding, Error, Result, Unit, FILES, JSON_UNITS, PROTO_UNITS};
+use analysis_rust_proto::analysis::IndexedCompilation;
+use kythe_protojson::Descriptors;
+use protobuf::Message;
+use std::io::{Read, Seek};
+use zip::result::ZipError;
+use zip::ZipArchive;
+
+/// Reads compilation units and files from a kzip archive.
+///
+/// Units are read from their proto encoding when the archive has one, and
+/// from JSON otherwise.
+pub struct Reader<R> {
+    zip: ZipArchive<R>,
+    /// The root directory of the archive, including its trailing slash.
+    root: String,
+    /// The names of the entries in the archive, in sorted order.
+    names: Vec<String>,
+    encoding: Encoding,
+    descriptors: Descriptors,
+}
+
+impl<R: Read + Seek> Reader<R> {
+    /// Opens the kzip archive read from `r`.
+    pub fn new(r: R) -> Result<Self> {
+        let zip = ZipArchive::new(r)?;
+        let mut names: Vec<String> = zip.file_names().map(String::from).collect();
+        names.sort();
+        let root = match names.first() {
+            None => return Err(Error::InvalidArchive("archive is empty".into())),
+            Some(first) if !first.ends_with('/') => {
+                return Err(Error::InvalidArchive(format!(
+                    "archive root directory missing: expected a directory but got {first:?}"
+                )))
+            }
+            Some(first) => first.clone(),
+        };
+        let mut reader = Self {
+            zip,
+            root,
+            names,
+            encoding: Encoding::Proto,
+            descriptors: descriptors(),
+        };
+        reader.encoding = match (
+            reader.has_entries(PROTO_UNITS),
+            reader.has_entries(JSON_UNITS),
+        ) {
+            (false, true) => Encoding::Json,
+            (true, true) => {
+                if !reader.entries(PROTO_UNITS).eq(reader.entries(JSON_UNITS)) {
+                    return Err(Error::InvalidArchive(
+                        "both proto and JSON units found but are not identical".into(),
+                    ));
+                }
+                Encoding::All
+            }
+            _ => Encoding::Proto,
+        };
+        Ok(reader)
+    }
+
+    /// Sets the descriptors used to read JSON-encoded units. These must
+    /// include the message types held in the units' details.
+    pub fn with_descriptors(mut self, descriptors: Descriptors) -> Self {
+        self.descriptors = descriptors;
+        self
+    }
+
+    /// Returns the encodings of the units in the archive.
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    /// Returns the root directory of the archive, without its trailing slash.
+    pub fn root(&self) -> &str {
+        self.root.trim_end_matches('/')
+    }
+
+    /// Returns the digests of the compilation units in the archive, in sorted
+    /// order.
+    pub fn unit_digests(&self) -> Vec<String> {
+        self.entries(self.units_dir()).map(String::from).collect()
+    }
+
+    /// Reads the compilation unit with the given digest.
+    pub fn lookup(&mut self, digest: &str) -> Result<Unit> {
+        let data = self.read_entry(self.units_dir(), digest)?;
+        let mut ic: IndexedCompilation = if self.encoding.has_proto() {
+            IndexedCompilation::parse_from_bytes(&data)?
+        } else {
+            let json = serde_json::from_slice(&data).map_err(kythe_protojson::Error::from)?;
+            self.descriptors.from_json(&json)?
+        };
+        Ok(Unit {
+            digest: digest.to_string(),
+            proto: ic.take_unit(),
+            index: ic.take_index(),
+        })
+    }
+
+    /// Reads the contents of the file with the given digest.
+    pub fn read_file(&mut self, digest: &str) -> Result<Vec<u8>> {
+        self.read_entry(FILES, digest)
+    }
+
+    fn units_dir(&self) -> &'static str {
+        if self.encoding.has_proto() {
+            PROTO_UNITS
+        } else {
+            JSON_UNITS
+        }
+    }
+
+    fn read_entry(&mut self, dir: &str, digest: &str) -> Result<Vec<u8>> {
+        let name = format!("{}{dir}/{digest}", self.root);
+        let mut file = match self.zip.by_name(&name) {
+            Err(ZipError::FileNotFound) => return Err(Error::DigestNotFound(digest.into())),
+            file => file?,
+        };
+        // The size in the header is not trusted to pre-size the buffer.
+        let mut data = Vec::new();
+        file.read_to_end(&mut data)?;
+        Ok(data)
+    }
+
+    /// Returns the names of the entries in `dir`, relative to it.
+    fn entries<'a>(&'a self, dir: &str) -> impl Iterator<Item = &'a str> {
+        let prefix = format!("{}{dir}/", self.root);
+        let start = self.names.partition_point(|n| *n < prefix);
+        self.names[start..]
+            .iter()
+            .map_while(move |n| n.strip_prefix(&prefix))
+            .filter(|n| !n.is_empty())
+    }
+
+    fn has_entries(&self, dir: &str) -> bool {
+        self.entries(dir).next().is_some()
+    }
+}
@@ -0,0 +1,144 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Writing kzip archives.
+
+use crate::{
+    canonicalize, descriptors, file_digest, unit_digest, Encoding, Error, Result, FILES,
+    JSON_UNITS, PROTO_UNITS,
+};
+use analysis_rust_proto::analysis::{
+    CompilationUnit, IndexedCompilation, IndexedCompilation_Index,
+};
+use kythe_protojson::Descriptors;
+use protobuf::Message;
+use std::collections::HashSet;
+use std::io::{Seek, Write};
+use zip::write::FileOptions;
+use zip::{CompressionMethod, DateTime, ZipWriter};
+
+/// The root directory of archives created by [`Writer`].
+const ROOT: &str = "root";
+
+/// Writes compilation units and files to a new kzip archive.
+///
+/// Entries are given a constant modification time so that archives with the
+/// same contents are byte-for-byte identical.
+pub struct Writer<W: Write + Seek> {
+    zip: ZipWriter<W>,
+    /// The digests of the files written so far.
+    files: HashSet<String>,
+    /// The digests of the units written so far.
+    units: HashSet<String>,
+    encoding: Encoding,
+    descriptors: Descriptors,
+}
+
+impl<W: Write + Seek> Writer<W> {
+    /// Starts a new kzip archive written to `w`.
+    pub fn new(w: W) -> Result<Self> {
+        let mut zip = ZipWriter::new(w);
+        zip.set_comment("Kythe kzip archive");
+        zip.add_directory(ROOT, options().unix_permissions(0o755))?;
+        Ok(Self {
+            zip,
+            files: HashSet::new(),
+            units: HashSet::new(),
+            encoding: Encoding::default(),
+            descriptors: descriptors(),
+        })
+    }
+
+    /// Sets the encodings in which units are written. Units are written as
+    /// protos by default.
+    pub fn with_encoding(mut self, encoding: Encoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Sets the descriptors used to write JSON-encoded units. These must
+    /// include the message types held in the units' details.
+    pub fn with_descriptors(mut self, descriptors: Descriptors) -> Self {
+        self.descriptors = descriptors;
+        self
+    }
+
+    /// Canonicalizes `unit` and adds it to the archive, returning its digest.
+    /// Fails with [`Error::UnitExists`] if an equivalent unit was already
+    /// added.
+    pub fn add_unit(
+        &mut self,
+        mut unit: CompilationUnit,
+        index: IndexedCompilation_Index,
+    ) -> Result<String> {
+        canonicalize(&mut unit);
+        let digest = unit_digest(&unit);
+        if self.units.contains(&digest) {
+            return Err(Error::UnitExists(digest));
+        }
+        let mut ic = IndexedCompilation::new();
+        ic.set_unit(unit);
+        ic.set_index(index);
+        if self.encoding.has_json() {
+            let json = self.descriptors.to_json(&ic)?;
+            self.put(JSON_UNITS, &digest, json.to_string().as_bytes())?;
+        }
+        if self.encoding.has_proto() {
+            self.put(PROTO_UNITS, &digest, &ic.write_to_bytes()?)?;
+        }
+        self.units.insert(digest.clone());
+        Ok(digest)
+    }
+
+    /// Adds a file with the given contents to the archive, returning its
+    /// digest. Files already in the archive are not written again.
+    pub fn add_file(&mut self, data: &[u8]) -> Result<String> {
+        let digest = file_digest(data);
+        if !self.files.contains(&digest) {
+            self.put(FILES, &digest, data)?;
+            self.files.insert(digest.clone());
+        }
+        Ok(digest)
+    }
+
+    /// Reports whether a file with the given digest has been added.
+    pub fn has_file(&self, digest: &str) -> bool {
+        self.files.contains(digest)
+    }
+
+    /// Reports whether a unit with the given digest has been added.
+    pub fn has_unit(&self, digest: &str) -> bool {
+        self.units.contains(digest)
+    }
+
+    /// Finishes the archive and returns the underlying writer.
+    pub fn into_inner(mut self) -> Result<W> {
+        Ok(self.zip.finish()?)
+    }
+
+    fn put(&mut self, dir: &str, digest: &str, data: &[u8]) -> Result<()> {
+        self.zip.start_file(
+            format!("{ROOT}/{dir}/{digest}"),
+            options()
+                .compression_method(CompressionMethod::Deflated)
+                .unix_permissions(0o600),
+        )?;
+        self.zip.write_all(data)?;
+        Ok(())
+    }
+}
+
+fn options() -> FileOptions {
+    FileOptions::default().last_modified_time(DateTime::default())
+}
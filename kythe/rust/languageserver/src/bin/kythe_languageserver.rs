@@ -0,0 +1,86 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A language server answering editor requests from Kythe serving tables.
+//!
+//! Usage: kythe_languageserver --serving_table=TABLE [--vnames=RULES]
+//!   [--corpus=CORPUS] [--root=DIR]
+//!
+//! The server speaks LSP over stdin and stdout. Files are mapped to VNames by
+//! the rewrite rules in RULES, a JSON array as used by the extractors, or by
+//! putting every workspace path in CORPUS if no rules are given.
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use kythe_languageserver::pathmap::Rules;
+use kythe_languageserver::{Server, Workspace};
+use kythe_serving::xrefs::Table;
+use kythe_serving::LocalTable;
+use lsp_server::Connection;
+use lsp_types::InitializeParams;
+use std::path::PathBuf;
+
+#[derive(Parser)]
+#[command(about = "Answers LSP requests from Kythe serving tables")]
+struct Args {
+    /// Path of the serving table written by write_tables.
+    #[arg(long = "serving_table")]
+    serving_table: PathBuf,
+
+    /// Path of a JSON file of VName rewrite rules for workspace paths.
+    #[arg(long)]
+    vnames: Option<PathBuf>,
+
+    /// Corpus of the workspace files when no rewrite rules are given.
+    #[arg(long, default_value = "")]
+    corpus: String,
+
+    /// Workspace root; defaults to the root URI sent by the client.
+    #[arg(long)]
+    root: Option<PathBuf>,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let table = LocalTable::open(&args.serving_table)
+        .with_context(|| format!("opening {}", args.serving_table.display()))?;
+    let rules = match &args.vnames {
+        Some(path) => {
+            let json = std::fs::read_to_string(path)
+                .with_context(|| format!("reading {}", path.display()))?;
+            Rules::from_json(&json).with_context(|| format!("parsing {}", path.display()))?
+        }
+        None => Rules::from_json(
+            &serde_json::json!([{"pattern": "(.*)", "vname": {"corpus": args.corpus, "path": "@1@"}}])
+                .to_string(),
+        )?,
+    };
+
+    let (conn, io_threads) = Connection::stdio();
+    let params = conn.initialize(serde_json::to_value(Server::<Table>::capabilities())?)?;
+    let params: InitializeParams = serde_json::from_value(params)?;
+    let root = match args.root {
+        Some(root) => root,
+        None => params
+            .root_uri
+            .and_then(|uri| uri.to_file_path().ok())
+            .map_or_else(std::env::current_dir, Ok)?,
+    };
+    eprintln!("kythe_languageserver: serving {}", root.display());
+
+    Server::new(Table::new(table), Workspace::new(root, rules)).run(&conn)?;
+    drop(conn);
+    io_threads.join()?;
+    Ok(())
+}
@@ -0,0 +1,77 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion between byte offsets into a file's text and LSP positions,
+//! whose characters are counted in UTF-16 code units.
+
+use kythe_span::{Encoding, Normalizer};
+use lsp_types::{Position, Range};
+
+/// The text of a file, indexed for conversion between offsets and positions.
+pub struct Document {
+    norm: Normalizer,
+}
+
+impl Document {
+    pub fn new(text: &[u8]) -> Self {
+        Document {
+            norm: Normalizer::new(text),
+        }
+    }
+
+    /// Returns the byte offset of `pos`, clamping its character to the end
+    /// of its line, or `None` if its line is past the end of the text.
+    pub fn offset(&self, pos: Position) -> Option<usize> {
+        let line = pos.line as usize;
+        (line < self.norm.line_count()).then(|| {
+            let pos = kythe_span::Position::new(line, pos.character as usize);
+            self.norm.offset(pos, Encoding::Utf16)
+        })
+    }
+
+    /// Returns the position of the byte `offset`, which is clamped to the
+    /// text and rounded down to a character boundary.
+    pub fn position(&self, offset: usize) -> Position {
+        let pos = self.norm.position(offset, Encoding::Utf16);
+        Position::new(pos.line as u32, pos.column as u32)
+    }
+
+    /// Returns the range of the bytes `start..end`.
+    pub fn range(&self, start: usize, end: usize) -> Range {
+        Range::new(self.position(start), self.position(end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_utf16_positions() {
+        // "é" is two bytes and one UTF-16 unit; "𝄞" is four bytes and two.
+        let doc = Document::new("é𝄞x\ny\n".as_bytes());
+        assert_eq!(doc.offset(Position::new(0, 0)), Some(0));
+        assert_eq!(doc.offset(Position::new(0, 1)), Some(2));
+        assert_eq!(doc.offset(Position::new(0, 3)), Some(6));
+        assert_eq!(doc.offset(Position::new(0, 99)), Some(7));
+        assert_eq!(doc.offset(Position::new(1, 0)), Some(8));
+        assert_eq!(doc.offset(Position::new(2, 0)), Some(10));
+        assert_eq!(doc.offset(Position::new(3, 0)), None);
+
+        assert_eq!(doc.position(6), Position::new(0, 3));
+        assert_eq!(doc.position(4), Position::new(0, 1));
+        assert_eq!(doc.position(9), Position::new(1, 1));
+        assert_eq!(doc.position(99), Position::new(2, 0));
+    }
+}
@@ -0,0 +1,34 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use thiserror::Error;
+
+/// Errors produced while mapping files or answering requests.
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error(transparent)]
+    Serving(#[from] kythe_serving::Error),
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("protocol error: {0}")]
+    Protocol(#[from] lsp_server::ProtocolError),
+    #[error("invalid rewrite rule: {0}")]
+    InvalidRule(String),
+    #[error("file is not in the workspace: {0}")]
+    NotInWorkspace(String),
+    #[error("connection closed")]
+    Disconnected,
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
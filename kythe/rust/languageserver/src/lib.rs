@@ -0,0 +1,27 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A language server for editors, answering go-to-definition, references,
+//! hover and highlight requests from Kythe serving data, as
+//! `kythe/go/languageserver`.
+
+pub mod document;
+mod error;
+pub mod pathmap;
+pub mod server;
+pub mod workspace;
+
+pub use error::{Error, Result};
+pub use server::Server;
+pub use workspace::Workspace;
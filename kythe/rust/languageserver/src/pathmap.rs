@@ -0,0 +1,382 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Mapping between workspace-relative paths and VNames by
+//! `VNameRewriteRules`, as `kythe/go/util/vnameutil`.
+//!
+//! Rules map a path to a VName by matching the rule's pattern and filling the
+//! `@n@` markers of its VName template with the pattern's groups. A rule can
+//! also be applied in reverse when its pattern is literal text and capture
+//! groups only, which is the case for the usual `prefix/(.*)` rules.
+
+use crate::{Error, Result};
+use regex::Regex;
+use std::collections::HashMap;
+use storage_rust_proto::{VName, VNameRewriteRule, VNameRewriteRules};
+
+/// A part of a rule's pattern or of a VName template field.
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Literal(String),
+    Group(String),
+}
+
+/// A single compiled `VNameRewriteRule`.
+#[derive(Debug, Clone)]
+pub struct Rule {
+    regex: Regex,
+    template: VName,
+    /// The pattern as literal text and groups, if it can be inverted.
+    segments: Option<Vec<Segment>>,
+}
+
+impl Rule {
+    /// Compiles `rule`, implicitly anchoring its pattern at both ends.
+    pub fn new(rule: &VNameRewriteRule) -> Result<Self> {
+        let pattern = trim_anchors(rule.get_pattern());
+        let regex = Regex::new(&format!("^(?:{pattern})$"))
+            .map_err(|err| Error::InvalidRule(format!("{pattern:?}: {err}")))?;
+        Ok(Rule {
+            regex,
+            template: rule.get_v_name().clone(),
+            segments: parse_pattern(pattern),
+        })
+    }
+
+    /// Returns the VName for `input`, if the rule's pattern matches it.
+    pub fn apply(&self, input: &str) -> Option<VName> {
+        let caps = self.regex.captures(input)?;
+        let expand = |template: &str| {
+            let mut out = String::new();
+            caps.expand(&fix_template(template), &mut out);
+            out
+        };
+        let t = &self.template;
+        let mut v = VName::new();
+        v.set_corpus(expand(t.get_corpus()));
+        v.set_root(expand(t.get_root()));
+        v.set_path(expand(t.get_path()));
+        v.set_language(expand(t.get_language()));
+        v.set_signature(expand(t.get_signature()));
+        Some(v)
+    }
+
+    /// Returns the input for which [`Rule::apply`] produces the corpus, root
+    /// and path of `v`, if the rule can be inverted and matches `v`.
+    pub fn invert(&self, v: &VName) -> Option<String> {
+        let segments = self.segments.as_ref()?;
+        let t = &self.template;
+        let mut groups = HashMap::new();
+        for (template, value) in [
+            (t.get_corpus(), v.get_corpus()),
+            (t.get_root(), v.get_root()),
+            (t.get_path(), v.get_path()),
+        ] {
+            match_template(template, value, &mut groups)?;
+        }
+        let mut input = String::new();
+        for seg in segments {
+            match seg {
+                Segment::Literal(s) => input.push_str(s),
+                Segment::Group(name) => input.push_str(groups.get(name)?),
+            }
+        }
+        // The groups must also satisfy their own sub-patterns.
+        let w = self.apply(&input)?;
+        (w.get_corpus() == v.get_corpus()
+            && w.get_root() == v.get_root()
+            && w.get_path() == v.get_path())
+        .then_some(input)
+    }
+}
+
+/// An ordered list of rules, of which the first matching rule applies.
+#[derive(Debug, Clone, Default)]
+pub struct Rules {
+    rules: Vec<Rule>,
+}
+
+impl Rules {
+    /// Compiles each of `rules`.
+    pub fn new(rules: &VNameRewriteRules) -> Result<Self> {
+        Ok(Rules {
+            rules: rules
+                .get_rule()
+                .iter()
+                .map(Rule::new)
+                .collect::<Result<_>>()?,
+        })
+    }
+
+    /// Parses rules from their JSON form, an array of objects with a
+    /// `pattern` and a `vname` template.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let value: serde_json::Value = serde_json::from_str(json)?;
+        let serde_json::Value::Array(items) = value else {
+            return Err(Error::InvalidRule("expected an array of rules".into()));
+        };
+        let mut rules = VNameRewriteRules::new();
+        for item in &items {
+            let str_field = |v: &serde_json::Value, name: &str| -> Result<String> {
+                match v.get(name) {
+                    None | Some(serde_json::Value::Null) => Ok(String::new()),
+                    Some(serde_json::Value::String(s)) => Ok(s.clone()),
+                    Some(other) => Err(Error::InvalidRule(format!(
+                        "field {name:?} is not a string: {other}"
+                    ))),
+                }
+            };
+            let mut rule = VNameRewriteRule::new();
+            rule.set_pattern(str_field(item, "pattern")?);
+            let template = item
+                .get("vname")
+                .or_else(|| item.get("v_name"))
+                .ok_or_else(|| Error::InvalidRule(format!("rule without a vname: {item}")))?;
+            let v = rule.mut_v_name();
+            v.set_corpus(str_field(template, "corpus")?);
+            v.set_root(str_field(template, "root")?);
+            v.set_path(str_field(template, "path")?);
+            v.set_language(str_field(template, "language")?);
+            v.set_signature(str_field(template, "signature")?);
+            rules.mut_rule().push(rule);
+        }
+        Rules::new(&rules)
+    }
+
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Returns the VName produced by the first rule matching `input`.
+    pub fn apply(&self, input: &str) -> Option<VName> {
+        self.rules.iter().find_map(|r| r.apply(input))
+    }
+
+    /// Returns the input produced by the first rule that inverts `v`.
+    pub fn invert(&self, v: &VName) -> Option<String> {
+        self.rules.iter().find_map(|r| r.invert(v))
+    }
+}
+
+/// Removes the optional `^` and `$` anchors around `pattern`.
+fn trim_anchors(pattern: &str) -> &str {
+    let pattern = pattern.strip_prefix('^').unwrap_or(pattern);
+    match pattern.strip_suffix('$') {
+        Some(p) if !p.ends_with('\\') || p.ends_with("\\\\") => p,
+        _ => pattern,
+    }
+}
+
+/// Rewrites the `@n@` markers of a template as `${n}` for
+/// [`regex::Captures::expand`], escaping any literal `$`.
+fn fix_template(template: &str) -> String {
+    let mut out = String::new();
+    for seg in parse_template(template) {
+        match seg {
+            Segment::Literal(s) => out.push_str(&s.replace('$', "$$")),
+            Segment::Group(name) => {
+                out.push_str("${");
+                out.push_str(&name);
+                out.push('}');
+            }
+        }
+    }
+    out
+}
+
+/// Splits a template into literal text and `@name@` group markers.
+fn parse_template(template: &str) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('@') {
+        let after = &rest[start + 1..];
+        let len = after
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(after.len());
+        if len > 0 && after[len..].starts_with('@') {
+            if start > 0 {
+                segments.push(Segment::Literal(rest[..start].into()));
+            }
+            segments.push(Segment::Group(after[..len].into()));
+            rest = &after[len + 1..];
+        } else {
+            segments.push(Segment::Literal(rest[..=start].into()));
+            rest = after;
+        }
+    }
+    if !rest.is_empty() {
+        segments.push(Segment::Literal(rest.into()));
+    }
+    segments
+}
+
+/// Matches `value` against a template, recording the value of each group
+/// marker in `groups`. Fails if a group would take two different values.
+fn match_template(template: &str, value: &str, groups: &mut HashMap<String, String>) -> Option<()> {
+    let segments = parse_template(template);
+    let mut pattern = String::from("^");
+    let mut names = Vec::new();
+    for seg in &segments {
+        match seg {
+            Segment::Literal(s) => pattern.push_str(&regex::escape(s)),
+            Segment::Group(name) => {
+                pattern.push_str("(.*)");
+                names.push(name);
+            }
+        }
+    }
+    pattern.push('$');
+    let caps = Regex::new(&pattern).ok()?.captures(value)?;
+    for (i, name) in names.into_iter().enumerate() {
+        let v = caps.get(i + 1).map_or("", |m| m.as_str());
+        match groups.get(name.as_str()) {
+            Some(prev) if prev != v => return None,
+            Some(_) => {}
+            None => {
+                groups.insert(name.clone(), v.into());
+            }
+        }
+    }
+    Some(())
+}
+
+/// Splits a pattern into literal text and top-level capture groups, or
+/// returns `None` if it has any other regular expression syntax outside of
+/// its groups.
+fn parse_pattern(pattern: &str) -> Option<Vec<Segment>> {
+    let mut segments = Vec::new();
+    let mut literal = String::new();
+    let mut index = 0;
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                e if e.is_ascii_punctuation() => literal.push(e),
+                _ => return None,
+            },
+            '(' => {
+                index += 1;
+                let body = group_body(&mut chars)?;
+                let name = match body.strip_prefix("?P<").or_else(|| body.strip_prefix("?<")) {
+                    Some(named) => named.split_once('>')?.0.to_owned(),
+                    None if body.starts_with('?') => return None,
+                    None => index.to_string(),
+                };
+                if !literal.is_empty() {
+                    segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                }
+                segments.push(Segment::Group(name));
+            }
+            '.' | '*' | '+' | '?' | '[' | ']' | '{' | '}' | '|' | '^' | '$' | ')' => return None,
+            c => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        segments.push(Segment::Literal(literal));
+    }
+    Some(segments)
+}
+
+/// Consumes the body of a group up to its closing parenthesis. Nested groups
+/// are not supported, since they would renumber the groups that follow.
+fn group_body(chars: &mut std::str::Chars) -> Option<String> {
+    let mut body = String::new();
+    let mut in_class = false;
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                body.push(c);
+                body.push(chars.next()?);
+            }
+            '[' if !in_class => {
+                in_class = true;
+                body.push(c);
+            }
+            ']' if in_class => {
+                in_class = false;
+                body.push(c);
+            }
+            ')' if !in_class => return Some(body),
+            '(' if !in_class => return None,
+            c => body.push(c),
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RULES: &str = r#"[
+        {"pattern": "third_party/(re2|abseil)/(.*)", "vname": {"corpus": "@1@", "path": "@2@"}},
+        {"pattern": "bazel-out/[^/]+/(.*)", "vname": {"corpus": "kythe", "root": "out", "path": "@1@"}},
+        {"pattern": "(?P<path>.*\\.rs)", "vname": {"corpus": "kythe", "path": "src/@path@"}}
+    ]"#;
+
+    fn vname(corpus: &str, root: &str, path: &str) -> VName {
+        let mut v = VName::new();
+        v.set_corpus(corpus.into());
+        v.set_root(root.into());
+        v.set_path(path.into());
+        v
+    }
+
+    #[test]
+    fn applies_first_matching_rule() {
+        let rules = Rules::from_json(RULES).unwrap();
+        assert_eq!(rules.len(), 3);
+        assert_eq!(
+            rules.apply("third_party/re2/re2.h"),
+            Some(vname("re2", "", "re2.h"))
+        );
+        assert_eq!(
+            rules.apply("bazel-out/k8/a.rs"),
+            Some(vname("kythe", "out", "a.rs"))
+        );
+        assert_eq!(
+            rules.apply("lib.rs"),
+            Some(vname("kythe", "", "src/lib.rs"))
+        );
+        assert_eq!(rules.apply("README.md"), None);
+    }
+
+    #[test]
+    fn inverts_literal_rules() {
+        let rules = Rules::from_json(RULES).unwrap();
+        assert_eq!(
+            rules.invert(&vname("re2", "", "re2.h")).as_deref(),
+            Some("third_party/re2/re2.h")
+        );
+        assert_eq!(
+            rules.invert(&vname("kythe", "", "src/lib.rs")).as_deref(),
+            Some("lib.rs")
+        );
+        // The second rule drops part of its input, and the third rejects
+        // paths that are not Rust files.
+        assert_eq!(rules.invert(&vname("kythe", "out", "a.rs")), None);
+        assert_eq!(rules.invert(&vname("kythe", "", "src/README.md")), None);
+    }
+
+    #[test]
+    fn rejects_invalid_rules() {
+        assert!(Rules::from_json(r#"[{"pattern": "("}]"#).is_err());
+        assert!(Rules::from_json(r#"[{"pattern": "(", "vname": {}}]"#).is_err());
+        assert!(Rules::from_json(r#"{}"#).is_err());
+    }
+}
@@ -0,0 +1,536 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A language server answering editor requests from a Kythe `XRefService`.
+//!
+//! Positions in requests are resolved against the text of the file as it was
+//! indexed, so results assume that the local copy matches the index.
+
+use crate::document::Document;
+use crate::workspace::Workspace;
+use crate::{Error, Result};
+use kythe_serving::xrefs::XRefService;
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::notification::{DidCloseTextDocument, Notification as _};
+use lsp_types::request::{
+    DocumentHighlightRequest, GotoDefinition, HoverRequest, References, Request as _,
+};
+use lsp_types::{
+    DidCloseTextDocumentParams, DocumentHighlight, DocumentHighlightKind, GotoDefinitionResponse,
+    Hover, HoverContents, HoverProviderCapability, Location, MarkupContent, MarkupKind, OneOf,
+    ServerCapabilities, TextDocumentPositionParams, TextDocumentSyncCapability,
+    TextDocumentSyncOptions, Url,
+};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use xref_rust_proto::{
+    Anchor, CrossReferencesRequest, CrossReferencesRequest_DeclarationKind as DeclarationKind,
+    CrossReferencesRequest_DefinitionKind as DefinitionKind,
+    CrossReferencesRequest_ReferenceKind as ReferenceKind, DecorationsReply,
+    DecorationsReply_Reference, DecorationsRequest, DocumentationRequest, Location_Kind,
+};
+
+/// The maximum number of files whose indexed text is cached.
+const MAX_DOCUMENTS: usize = 64;
+
+/// The decorations of a file and the reference at a position within it.
+struct Target {
+    document: Rc<Document>,
+    decorations: DecorationsReply,
+    reference: DecorationsReply_Reference,
+}
+
+/// A language server over an `XRefService` and a workspace.
+pub struct Server<X> {
+    xrefs: X,
+    workspace: Workspace,
+    /// The indexed text of recently used files, by ticket.
+    documents: RefCell<HashMap<String, Rc<Document>>>,
+}
+
+impl<X: XRefService> Server<X> {
+    pub fn new(xrefs: X, workspace: Workspace) -> Self {
+        Server {
+            xrefs,
+            workspace,
+            documents: RefCell::default(),
+        }
+    }
+
+    /// Returns the capabilities to report in reply to `initialize`.
+    pub fn capabilities() -> ServerCapabilities {
+        ServerCapabilities {
+            definition_provider: Some(OneOf::Left(true)),
+            references_provider: Some(OneOf::Left(true)),
+            hover_provider: Some(HoverProviderCapability::Simple(true)),
+            document_highlight_provider: Some(OneOf::Left(true)),
+            text_document_sync: Some(TextDocumentSyncCapability::Options(
+                TextDocumentSyncOptions {
+                    open_close: Some(true),
+                    ..Default::default()
+                },
+            )),
+            ..Default::default()
+        }
+    }
+
+    /// Answers the requests received on `conn` until it is shut down.
+    pub fn run(&self, conn: &Connection) -> Result<()> {
+        for msg in &conn.receiver {
+            let req = match msg {
+                Message::Request(req) => req,
+                Message::Notification(not) => {
+                    self.notify(not);
+                    continue;
+                }
+                Message::Response(_) => continue,
+            };
+            if conn.handle_shutdown(&req)? {
+                return Ok(());
+            }
+            conn.sender
+                .send(Message::Response(self.dispatch(req)))
+                .map_err(|_| Error::Disconnected)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the response to a single request.
+    pub fn dispatch(&self, req: Request) -> Response {
+        let Request { id, method, params } = req;
+        let result = match method.as_str() {
+            GotoDefinition::METHOD => call::<GotoDefinition>(params, |p| {
+                let locations = self.definition(&p.text_document_position_params)?;
+                Ok(Some(GotoDefinitionResponse::Array(locations)))
+            }),
+            References::METHOD => call::<References>(params, |p| {
+                let pos = &p.text_document_position;
+                Ok(Some(self.references(pos, p.context.include_declaration)?))
+            }),
+            HoverRequest::METHOD => {
+                call::<HoverRequest>(params, |p| self.hover(&p.text_document_position_params))
+            }
+            DocumentHighlightRequest::METHOD => call::<DocumentHighlightRequest>(params, |p| {
+                Ok(Some(self.highlights(&p.text_document_position_params)?))
+            }),
+            _ => {
+                return Response::new_err(
+                    id,
+                    ErrorCode::MethodNotFound as i32,
+                    format!("unsupported method: {method}"),
+                );
+            }
+        };
+        match result {
+            Ok(value) => Response::new_ok(id, value),
+            // As with the Go server, files outside the workspace have no
+            // results rather than failing.
+            Err(Error::NotInWorkspace(_)) => Response::new_ok(id, serde_json::Value::Null),
+            Err(err @ Error::Json(_)) => {
+                Response::new_err(id, ErrorCode::InvalidParams as i32, err.to_string())
+            }
+            Err(err) => Response::new_err(id, ErrorCode::InternalError as i32, err.to_string()),
+        }
+    }
+
+    /// Handles a notification from the client.
+    pub fn notify(&self, not: Notification) {
+        if not.method != DidCloseTextDocument::METHOD {
+            return;
+        }
+        if let Ok(params) = serde_json::from_value::<DidCloseTextDocumentParams>(not.params) {
+            self.close(&params.text_document.uri);
+        }
+    }
+
+    /// Drops the cached text of the file at `url`.
+    fn close(&self, url: &Url) {
+        if let Ok(file) = self.workspace.vname(url) {
+            self.documents
+                .borrow_mut()
+                .remove(&kythe_uri::to_string(&file));
+        }
+    }
+
+    /// Caches the indexed text of the file named by `ticket`, dropping the
+    /// cached text of all other files once there are too many.
+    fn cache(&self, ticket: &str, document: Rc<Document>) {
+        let mut documents = self.documents.borrow_mut();
+        if documents.len() >= MAX_DOCUMENTS && !documents.contains_key(ticket) {
+            documents.clear();
+        }
+        documents.insert(ticket.into(), document);
+    }
+
+    /// Returns the definitions of the node referenced at a position.
+    pub fn definition(&self, params: &TextDocumentPositionParams) -> Result<Vec<Location>> {
+        let Some(target) = self.target(params)? else {
+            return Ok(Vec::new());
+        };
+        let def = target.reference.get_target_definition();
+        if let Some(anchor) = target.decorations.get_definition_locations().get(def) {
+            return Ok(self.location(anchor)?.into_iter().collect());
+        }
+        let mut req = CrossReferencesRequest::new();
+        req.set_definition_kind(DefinitionKind::BINDING_DEFINITIONS);
+        req.set_reference_kind(ReferenceKind::NO_REFERENCES);
+        self.cross_references(target.reference.get_target_ticket(), req)
+    }
+
+    /// Returns the references to the node referenced at a position, and its
+    /// definitions and declarations if `include_declaration` is set.
+    pub fn references(
+        &self,
+        params: &TextDocumentPositionParams,
+        include_declaration: bool,
+    ) -> Result<Vec<Location>> {
+        let Some(target) = self.target(params)? else {
+            return Ok(Vec::new());
+        };
+        let mut req = CrossReferencesRequest::new();
+        req.set_reference_kind(ReferenceKind::ALL_REFERENCES);
+        if include_declaration {
+            req.set_definition_kind(DefinitionKind::ALL_DEFINITIONS);
+            req.set_declaration_kind(DeclarationKind::ALL_DECLARATIONS);
+        }
+        self.cross_references(target.reference.get_target_ticket(), req)
+    }
+
+    /// Returns the documentation of the node referenced at a position.
+    pub fn hover(&self, params: &TextDocumentPositionParams) -> Result<Option<Hover>> {
+        let Some(target) = self.target(params)? else {
+            return Ok(None);
+        };
+        let mut req = DocumentationRequest::new();
+        req.mut_ticket()
+            .push(target.reference.get_target_ticket().into());
+        let reply = self.xrefs.documentation(&req)?;
+        let text: Vec<&str> = reply
+            .get_document()
+            .iter()
+            .map(|doc| doc.get_text().get_raw_text())
+            .filter(|text| !text.is_empty())
+            .collect();
+        if text.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: text.join("\n\n"),
+            }),
+            range: Some(range(&target.document, &target.reference)),
+        }))
+    }
+
+    /// Returns the references in the same file to the node referenced at a
+    /// position.
+    pub fn highlights(
+        &self,
+        params: &TextDocumentPositionParams,
+    ) -> Result<Vec<DocumentHighlight>> {
+        let Some(target) = self.target(params)? else {
+            return Ok(Vec::new());
+        };
+        let ticket = target.reference.get_target_ticket();
+        Ok(target
+            .decorations
+            .get_reference()
+            .iter()
+            .filter(|r| r.get_target_ticket() == ticket)
+            .map(|r| DocumentHighlight {
+                range: range(&target.document, r),
+                kind: Some(DocumentHighlightKind::TEXT),
+            })
+            .collect())
+    }
+
+    /// Returns the innermost reference at a position in a file.
+    fn target(&self, params: &TextDocumentPositionParams) -> Result<Option<Target>> {
+        let file = self.workspace.vname(&params.text_document.uri)?;
+        let mut req = DecorationsRequest::new();
+        req.mut_location().set_ticket(kythe_uri::to_string(&file));
+        req.mut_location().set_kind(Location_Kind::FILE);
+        req.set_references(true);
+        req.set_target_definitions(true);
+        req.set_source_text(true);
+        let mut decorations = self.xrefs.decorations(&req)?;
+        let document = Rc::new(Document::new(decorations.get_source_text()));
+        self.cache(decorations.get_location().get_ticket(), document.clone());
+
+        let Some(offset) = document.offset(params.position) else {
+            return Ok(None);
+        };
+        let reference = decorations
+            .get_reference()
+            .iter()
+            .filter(|r| {
+                let (start, end) = span(r);
+                start <= offset && offset <= end
+            })
+            .min_by_key(|r| {
+                let (start, end) = span(r);
+                end - start
+            })
+            .cloned();
+        decorations.clear_source_text();
+        Ok(reference.map(|reference| Target {
+            document,
+            decorations,
+            reference,
+        }))
+    }
+
+    /// Returns the locations of the cross-references of `ticket` selected
+    /// by `req`, across all pages.
+    fn cross_references(
+        &self,
+        ticket: &str,
+        mut req: CrossReferencesRequest,
+    ) -> Result<Vec<Location>> {
+        req.mut_ticket().push(ticket.into());
+        let mut locations = Vec::new();
+        loop {
+            let reply = self.xrefs.cross_references(&req)?;
+            for set in reply.get_cross_references().values() {
+                let anchors = set
+                    .get_definition()
+                    .iter()
+                    .chain(set.get_declaration())
+                    .chain(set.get_reference());
+                for ra in anchors {
+                    locations.extend(self.location(ra.get_anchor())?);
+                }
+            }
+            if reply.get_next_page_token().is_empty() {
+                return Ok(locations);
+            }
+            req.set_page_token(reply.get_next_page_token().into());
+        }
+    }
+
+    /// Returns the local location of an anchor, if its file is in the
+    /// workspace.
+    fn location(&self, anchor: &Anchor) -> Result<Option<Location>> {
+        let Ok(file) = kythe_uri::to_vname(anchor.get_parent()) else {
+            return Ok(None);
+        };
+        let Some(uri) = self.workspace.url(&file) else {
+            return Ok(None);
+        };
+        let document = self.document(anchor.get_parent())?;
+        let span = anchor.get_span();
+        Ok(Some(Location::new(
+            uri,
+            document.range(
+                span.get_start().get_byte_offset() as usize,
+                span.get_end().get_byte_offset() as usize,
+            ),
+        )))
+    }
+
+    /// Returns the indexed text of the file named by `ticket`.
+    fn document(&self, ticket: &str) -> Result<Rc<Document>> {
+        if let Some(doc) = self.documents.borrow().get(ticket) {
+            return Ok(doc.clone());
+        }
+        let mut req = DecorationsRequest::new();
+        req.mut_location().set_ticket(ticket.into());
+        req.set_source_text(true);
+        let reply = self.xrefs.decorations(&req)?;
+        let doc = Rc::new(Document::new(reply.get_source_text()));
+        self.cache(ticket, doc.clone());
+        Ok(doc)
+    }
+}
+
+/// Calls `f` with the parameters of a request of type `R`, returning its
+/// result as JSON.
+fn call<R: lsp_types::request::Request>(
+    params: serde_json::Value,
+    f: impl FnOnce(R::Params) -> Result<R::Result>,
+) -> Result<serde_json::Value> {
+    let params = serde_json::from_value(params)?;
+    Ok(serde_json::to_value(f(params)?)?)
+}
+
+fn span(r: &DecorationsReply_Reference) -> (usize, usize) {
+    let span = r.get_span();
+    (
+        span.get_start().get_byte_offset() as usize,
+        span.get_end().get_byte_offset() as usize,
+    )
+}
+
+fn range(document: &Document, r: &DecorationsReply_Reference) -> lsp_types::Range {
+    let (start, end) = span(r);
+    document.range(start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pathmap::Rules;
+    use kythe_schema::{edges, facts, nodes};
+    use kythe_serving::xrefs::Table;
+    use kythe_serving::{build_tables, LocalTable, Options};
+    use kythe_storage::emitter::{anchor_vname, Emitter};
+    use kythe_storage::MemoryStore;
+    use lsp_server::RequestId;
+    use lsp_types::{Position, TextDocumentIdentifier, Url};
+    use storage_rust_proto::VName;
+
+    const TEXT: &str = "fn f() {}\nfn g() { f(); f(); }\n";
+
+    fn vname(signature: &str, path: &str) -> VName {
+        let mut v = VName::new();
+        v.set_signature(signature.into());
+        v.set_corpus("c".into());
+        v.set_path(path.into());
+        v
+    }
+
+    fn server() -> Server<Table> {
+        let mut e = Emitter::new(MemoryStore::new());
+        let (file, f, doc) = (vname("", "a.rs"), vname("f", ""), vname("doc", ""));
+        e.emit_file(&file, TEXT.as_bytes(), None).unwrap();
+        e.emit_node(&f, nodes::FUNCTION, []).unwrap();
+        e.emit_node(&doc, nodes::DOC, [(facts::TEXT, &b"Does f."[..])])
+            .unwrap();
+        e.emit_edge(&doc, edges::DOCUMENTS, &f).unwrap();
+        for (start, end, kind) in [
+            (3, 4, edges::DEFINES_BINDING),
+            (19, 20, edges::REF_CALL),
+            (24, 25, edges::REF_CALL),
+        ] {
+            let a = anchor_vname(&file, start, end);
+            e.emit_anchor(&a, start, end).unwrap();
+            e.emit_edge(&a, kind, &f).unwrap();
+        }
+        let mut table = LocalTable::new();
+        build_tables(&e.into_inner(), &mut table, &Options::default()).unwrap();
+
+        let rules = Rules::from_json(
+            r#"[{"pattern": "src/(.*)", "vname": {"corpus": "c", "path": "@1@"}}]"#,
+        )
+        .unwrap();
+        Server::new(Table::new(table), Workspace::new("/work", rules))
+    }
+
+    fn url() -> Url {
+        Url::parse("file:///work/src/a.rs").unwrap()
+    }
+
+    fn at(line: u32, character: u32) -> TextDocumentPositionParams {
+        TextDocumentPositionParams::new(
+            TextDocumentIdentifier::new(url()),
+            Position::new(line, character),
+        )
+    }
+
+    fn lines(locations: &[Location]) -> Vec<(u32, u32)> {
+        locations
+            .iter()
+            .map(|l| (l.range.start.line, l.range.start.character))
+            .collect()
+    }
+
+    #[test]
+    fn finds_definitions_and_references() {
+        let server = server();
+        let defs = server.definition(&at(1, 9)).unwrap();
+        assert_eq!(lines(&defs), [(0, 3)]);
+        assert_eq!(defs[0].uri, url());
+
+        let refs = server.references(&at(0, 3), false).unwrap();
+        assert_eq!(lines(&refs), [(1, 9), (1, 14)]);
+        let all = server.references(&at(0, 3), true).unwrap();
+        assert_eq!(all.len(), 3);
+
+        assert!(server.definition(&at(1, 0)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn hovers_and_highlights() {
+        let server = server();
+        let hover = server.hover(&at(1, 15)).unwrap().unwrap();
+        let HoverContents::Markup(content) = hover.contents else {
+            panic!("unexpected hover: {hover:?}");
+        };
+        assert_eq!(content.value, "Does f.");
+        assert_eq!(server.highlights(&at(1, 9)).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn dispatches_requests() {
+        let server = server();
+        let req = Request::new(
+            RequestId::from(1),
+            GotoDefinition::METHOD.into(),
+            serde_json::json!({
+                "textDocument": {"uri": url()},
+                "position": {"line": 1, "character": 9},
+            }),
+        );
+        let resp = server.dispatch(req);
+        assert!(resp.error.is_none());
+        let result = resp.result.unwrap();
+        assert_eq!(
+            result[0]["range"]["start"],
+            serde_json::json!({"line": 0, "character": 3})
+        );
+
+        let req = Request::new(RequestId::from(2), "textDocument/rename".into(), ());
+        let resp = server.dispatch(req);
+        assert_eq!(resp.error.unwrap().code, ErrorCode::MethodNotFound as i32);
+
+        for method in [
+            GotoDefinition::METHOD,
+            References::METHOD,
+            HoverRequest::METHOD,
+        ] {
+            let req = Request::new(
+                RequestId::from(3),
+                method.into(),
+                serde_json::json!({
+                    "textDocument": {"uri": "file:///elsewhere/a.rs"},
+                    "position": {"line": 0, "character": 0},
+                    "context": {"includeDeclaration": true},
+                }),
+            );
+            let resp = server.dispatch(req);
+            assert!(resp.error.is_none(), "{method}: {:?}", resp.error);
+            assert_eq!(resp.result, Some(serde_json::Value::Null));
+        }
+    }
+
+    #[test]
+    fn drops_closed_documents() {
+        let server = server();
+        server.definition(&at(1, 9)).unwrap();
+        assert_eq!(server.documents.borrow().len(), 1);
+
+        server.notify(Notification::new(
+            DidCloseTextDocument::METHOD.into(),
+            serde_json::json!({"textDocument": {"uri": url()}}),
+        ));
+        assert!(server.documents.borrow().is_empty());
+
+        for i in 0..MAX_DOCUMENTS + 1 {
+            server.cache(&format!("kythe://c?path={i}"), Rc::new(Document::new(b"")));
+        }
+        assert!(server.documents.borrow().len() <= MAX_DOCUMENTS);
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A local checkout whose files are mapped to and from the VNames of the
+//! index by a set of rewrite rules.
+
+use crate::pathmap::Rules;
+use crate::{Error, Result};
+use lsp_types::Url;
+use std::path::{Path, PathBuf};
+use storage_rust_proto::VName;
+
+/// A workspace root and the rules mapping paths relative to it to VNames.
+#[derive(Debug, Clone)]
+pub struct Workspace {
+    root: PathBuf,
+    rules: Rules,
+}
+
+impl Workspace {
+    pub fn new(root: impl Into<PathBuf>, rules: Rules) -> Self {
+        Workspace {
+            root: root.into(),
+            rules,
+        }
+    }
+
+    /// Returns the root directory of the workspace.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns the VName of the file at `url`, which must be a `file:` URL
+    /// within the workspace.
+    pub fn vname(&self, url: &Url) -> Result<VName> {
+        let path = url
+            .to_file_path()
+            .map_err(|_| Error::NotInWorkspace(url.to_string()))?;
+        let rel = path
+            .strip_prefix(&self.root)
+            .map_err(|_| Error::NotInWorkspace(url.to_string()))?;
+        let rel: Vec<_> = rel.iter().map(|c| c.to_string_lossy()).collect();
+        self.rules
+            .apply(&rel.join("/"))
+            .ok_or_else(|| Error::NotInWorkspace(url.to_string()))
+    }
+
+    /// Returns the `file:` URL of the local copy of the file named by `v`,
+    /// if the rules can map it back into the workspace.
+    pub fn url(&self, v: &VName) -> Option<Url> {
+        let rel = self.rules.invert(v)?;
+        Url::from_file_path(self.root.join(rel)).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_urls_to_vnames() {
+        let rules = Rules::from_json(
+            r#"[{"pattern": "src/(.*)", "vname": {"corpus": "c", "path": "@1@"}}]"#,
+        )
+        .unwrap();
+        let ws = Workspace::new("/work", rules);
+        let url = Url::parse("file:///work/src/a/b.rs").unwrap();
+        let v = ws.vname(&url).unwrap();
+        assert_eq!((v.get_corpus(), v.get_path()), ("c", "a/b.rs"));
+        assert_eq!(ws.url(&v), Some(url));
+
+        assert!(ws
+            .vname(&Url::parse("file:///elsewhere/src/a.rs").unwrap())
+            .is_err());
+        assert!(ws
+            .vname(&Url::parse("file:///work/README").unwrap())
+            .is_err());
+    }
+}
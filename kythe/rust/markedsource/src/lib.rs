@@ -0,0 +1,557 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rendering of the MarkedSource messages found in `/kythe/code` facts, as
+//! `kythe/go/util/markedsource`.
+//!
+//! The `render_*` functions flatten a MarkedSource into plain text or HTML,
+//! keeping only the kinds of nodes relevant to each rendering. Lookup nodes
+//! that have not been replaced by a [`Resolver`] render as `???`.
+
+mod resolve;
+
+pub use resolve::Resolver;
+
+use common_rust_proto::{MarkedSource, MarkedSource_Kind as Kind, SymbolInfo};
+use thiserror::Error;
+
+/// The depth at which rendering stops descending into a MarkedSource, as
+/// `kMaxRenderDepth` in the C++ renderer.
+pub const MAX_RENDER_DEPTH: usize = 20;
+
+const INVALID_LOOKUP_MARKER: &str = "???";
+
+/// An error resolving MarkedSource from graph entries.
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("invalid code for {ticket}: {source}")]
+    InvalidCode {
+        ticket: String,
+        source: protobuf::ProtobufError,
+    },
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// The format of rendered text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Plaintext,
+    /// Escaped HTML, in which nodes with a linkified definition are links.
+    Html,
+}
+
+/// Maps the ticket of a definition to the URL to link it to, if any.
+pub type Linkify<'a> = &'a dyn Fn(&str) -> Option<String>;
+
+/// A set of MarkedSource kinds.
+#[derive(Clone, Copy, Default)]
+struct Kinds(u32);
+
+impl Kinds {
+    fn of(kinds: &[Kind]) -> Self {
+        Kinds(kinds.iter().fold(0, |set, k| set | 1 << *k as u32))
+    }
+
+    fn all() -> Self {
+        Kinds(u32::MAX)
+    }
+
+    fn contains(self, kind: Kind) -> bool {
+        self.0 & 1 << kind as u32 != 0
+    }
+
+    fn with(self, kind: Kind) -> Self {
+        Kinds(self.0 | 1 << kind as u32)
+    }
+
+    fn intersects(self, other: Kinds) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+
+/// Renders `ms` as plain text, including all of its nodes.
+pub fn render(ms: &MarkedSource) -> String {
+    Renderer::new(Kinds::all(), Format::Plaintext, None).render(ms)
+}
+
+/// Renders the full signature of `ms`: its identifiers, types, parameters
+/// and modifiers.
+pub fn render_signature(ms: &MarkedSource, format: Format, linkify: Option<Linkify>) -> String {
+    let enabled = Kinds::of(&[
+        Kind::IDENTIFIER,
+        Kind::TYPE,
+        Kind::PARAMETER,
+        Kind::MODIFIER,
+    ]);
+    Renderer::new(enabled, format, linkify).render(ms)
+}
+
+/// Renders the signature of `ms` for a call site as plain text, leaving out
+/// types to keep it compact.
+pub fn render_call_site_signature(ms: &MarkedSource) -> String {
+    let enabled = Kinds::of(&[Kind::IDENTIFIER, Kind::PARAMETER]);
+    Renderer::new(enabled, Format::Plaintext, None).render(ms)
+}
+
+/// Renders the initializer of `ms`.
+pub fn render_initializer(ms: &MarkedSource, format: Format, linkify: Option<Linkify>) -> String {
+    Renderer::new(Kinds::of(&[Kind::INITIALIZER]), format, linkify).render(ms)
+}
+
+/// Renders the context of `ms`, followed by its identifier when
+/// `include_identifier` is set.
+pub fn render_simple_qualified_name(
+    ms: &MarkedSource,
+    include_identifier: bool,
+    format: Format,
+    linkify: Option<Linkify>,
+) -> String {
+    let mut enabled = Kinds::of(&[Kind::CONTEXT]);
+    if include_identifier {
+        enabled = enabled.with(Kind::IDENTIFIER);
+    }
+    Renderer::new(enabled, format, linkify).render(ms)
+}
+
+/// Renders the identifier of `ms`.
+pub fn render_simple_identifier(
+    ms: &MarkedSource,
+    format: Format,
+    linkify: Option<Linkify>,
+) -> String {
+    Renderer::new(Kinds::of(&[Kind::IDENTIFIER]), format, linkify).render(ms)
+}
+
+/// Renders the identifier of each parameter of `ms`.
+pub fn render_simple_params(
+    ms: &MarkedSource,
+    format: Format,
+    linkify: Option<Linkify>,
+) -> Vec<String> {
+    let mut out = Vec::new();
+    simple_params(ms, format, linkify, 0, &mut out);
+    out
+}
+
+fn simple_params(
+    ms: &MarkedSource,
+    format: Format,
+    linkify: Option<Linkify>,
+    depth: usize,
+    out: &mut Vec<String>,
+) {
+    if depth >= MAX_RENDER_DEPTH {
+        return;
+    }
+    match ms.get_kind() {
+        Kind::BOX => {
+            for kid in ms.get_child() {
+                simple_params(kid, format, linkify, depth + 1, out);
+            }
+        }
+        Kind::PARAMETER => {
+            for kid in ms.get_child() {
+                let mut r = Renderer::new(Kinds::of(&[Kind::IDENTIFIER]), format, linkify);
+                r.render_node(kid, Kinds::default(), depth + 1);
+                out.push(r.buffer.build(format));
+            }
+        }
+        Kind::PARAMETER_LOOKUP_BY_PARAM
+        | Kind::PARAMETER_LOOKUP_BY_PARAM_WITH_DEFAULTS
+        | Kind::PARAMETER_LOOKUP_BY_TPARAM => out.push(invalid_lookup(ms, format)),
+        _ => {}
+    }
+}
+
+/// Returns the base name of `ms`, its first identifier, and its qualified
+/// name, the identifiers of its first context joined by the context's
+/// separator and followed by the base name.
+pub fn render_qualified_name(ms: &MarkedSource) -> SymbolInfo {
+    let mut info = SymbolInfo::new();
+    let Some(id) = first_matching(ms, &|m| {
+        m.get_kind() == Kind::IDENTIFIER && !m.get_pre_text().is_empty()
+    }) else {
+        return info;
+    };
+    info.set_base_name(id.get_pre_text().into());
+    if let Some(ctx) = first_matching(ms, &|m| m.get_kind() == Kind::CONTEXT) {
+        let delim = match ctx.get_post_child_text() {
+            "" => ".",
+            delim => delim,
+        };
+        let quals: Vec<String> = ctx
+            .get_child()
+            .iter()
+            .filter(|kid| matches!(kid.get_kind(), Kind::IDENTIFIER | Kind::BOX))
+            .map(render)
+            .filter(|s| !s.is_empty())
+            .collect();
+        if !quals.is_empty() {
+            info.set_qualified_name(
+                quals.join(delim) + ctx.get_post_child_text() + id.get_pre_text(),
+            );
+        }
+    }
+    info
+}
+
+/// Returns `ms` or the first of its descendants, breadth first by level,
+/// for which `f` holds.
+fn first_matching<'a>(
+    ms: &'a MarkedSource,
+    f: &dyn Fn(&MarkedSource) -> bool,
+) -> Option<&'a MarkedSource> {
+    if f(ms) {
+        return Some(ms);
+    }
+    if let Some(kid) = ms.get_child().iter().find(|kid| f(kid)) {
+        return Some(kid);
+    }
+    ms.get_child().iter().find_map(|kid| first_matching(kid, f))
+}
+
+/// Reports whether an unresolved lookup of `kind` renders as a marker.
+fn renders_invalid_lookup(kind: Kind, enabled: Kinds) -> bool {
+    match kind {
+        Kind::PARAMETER_LOOKUP_BY_PARAM
+        | Kind::PARAMETER_LOOKUP_BY_PARAM_WITH_DEFAULTS
+        | Kind::PARAMETER_LOOKUP_BY_TPARAM
+        | Kind::LOOKUP_BY_PARAM
+        | Kind::LOOKUP_BY_TPARAM => enabled.contains(Kind::PARAMETER),
+        Kind::LOOKUP_BY_TYPED => enabled.contains(Kind::TYPE),
+        _ => false,
+    }
+}
+
+fn invalid_lookup(ms: &MarkedSource, format: Format) -> String {
+    escape(ms.get_pre_text(), format) + INVALID_LOOKUP_MARKER + &escape(ms.get_post_text(), format)
+}
+
+fn escape(text: &str, format: Format) -> String {
+    match format {
+        Format::Plaintext => text.into(),
+        Format::Html => {
+            let mut out = String::with_capacity(text.len());
+            for c in text.chars() {
+                match c {
+                    '&' => out.push_str("&amp;"),
+                    '<' => out.push_str("&lt;"),
+                    '>' => out.push_str("&gt;"),
+                    '"' => out.push_str("&quot;"),
+                    '\'' => out.push_str("&#39;"),
+                    c => out.push(c),
+                }
+            }
+            out
+        }
+    }
+}
+
+/// Text being rendered, and the target it links to.
+#[derive(Default)]
+struct Content {
+    text: String,
+    target: Option<String>,
+}
+
+impl Content {
+    fn build(&self, format: Format) -> String {
+        match (&self.target, format) {
+            (Some(target), Format::Html) => format!(
+                "<a href=\"{}\">{}</a>",
+                escape(target, Format::Html),
+                self.text
+            ),
+            _ => self.text.clone(),
+        }
+    }
+}
+
+struct Renderer<'a> {
+    enabled: Kinds,
+    format: Format,
+    linkify: Option<Linkify<'a>>,
+    buffer: Content,
+    /// A final list token, added only if more text follows.
+    pending: String,
+    nonempty: bool,
+    ends_in_space: bool,
+    /// Whether to separate the next text from a preceding type.
+    space_next: bool,
+}
+
+impl<'a> Renderer<'a> {
+    fn new(enabled: Kinds, format: Format, linkify: Option<Linkify<'a>>) -> Self {
+        Renderer {
+            enabled,
+            format,
+            linkify,
+            buffer: Content::default(),
+            pending: String::new(),
+            nonempty: false,
+            ends_in_space: false,
+            space_next: false,
+        }
+    }
+
+    fn render(mut self, ms: &MarkedSource) -> String {
+        self.render_node(ms, Kinds::default(), 0);
+        self.buffer.build(self.format)
+    }
+
+    fn will_render(&self, ms: &MarkedSource, depth: usize) -> bool {
+        let kind = ms.get_kind();
+        depth < MAX_RENDER_DEPTH
+            && (matches!(kind, Kind::BOX | Kind::IDENTIFIER)
+                || renders_invalid_lookup(kind, self.enabled)
+                || self.enabled.contains(kind))
+    }
+
+    /// Renders `ms` and its descendants, where `under` holds the kinds of
+    /// its enclosing nodes. Text is rendered only within an enabled kind.
+    fn render_node(&mut self, ms: &MarkedSource, mut under: Kinds, depth: usize) {
+        if depth >= MAX_RENDER_DEPTH {
+            return;
+        }
+        let kind = ms.get_kind();
+        if kind != Kind::BOX || self.enabled.contains(Kind::BOX) {
+            if renders_invalid_lookup(kind, self.enabled) {
+                self.add(&invalid_lookup(ms, self.format));
+                return;
+            }
+            if kind != Kind::IDENTIFIER && !self.enabled.contains(kind) {
+                return;
+            }
+            under = under.with(kind);
+        }
+        let render = self.enabled.intersects(under);
+        let mut saved = None;
+        if render {
+            if let Some(target) = self.link(ms) {
+                saved = Some(std::mem::replace(
+                    &mut self.buffer,
+                    Content {
+                        text: String::new(),
+                        target: Some(target),
+                    },
+                ));
+            }
+            self.add(&escape(ms.get_pre_text(), self.format));
+        }
+        let kids = ms.get_child();
+        let last = kids
+            .iter()
+            .rposition(|kid| self.will_render(kid, depth + 1));
+        let sep = escape(ms.get_post_child_text(), self.format);
+        for (i, kid) in kids.iter().enumerate() {
+            if !self.will_render(kid, depth + 1) {
+                continue;
+            }
+            self.render_node(kid, under, depth + 1);
+            if last > Some(i) {
+                self.add(&sep);
+            } else if ms.get_add_final_list_token() {
+                self.pending.push_str(&sep);
+            }
+        }
+        if render {
+            self.add(&escape(ms.get_post_text(), self.format));
+            if let Some(saved) = saved {
+                self.buffer = Content {
+                    text: saved.build(self.format) + &self.buffer.build(self.format),
+                    target: None,
+                };
+            }
+            if kind == Kind::TYPE {
+                self.space_next = true;
+            }
+        }
+    }
+
+    /// Returns the link target of the first linkified definition of `ms`.
+    fn link(&self, ms: &MarkedSource) -> Option<String> {
+        let linkify = self.linkify?;
+        ms.get_link()
+            .iter()
+            .flat_map(|link| link.get_definition())
+            .find_map(|def| linkify(def))
+    }
+
+    fn add(&mut self, s: &str) {
+        if s.is_empty() {
+            return;
+        }
+        if !self.pending.is_empty() {
+            self.buffer.text.push_str(&self.pending);
+            self.ends_in_space = self.pending.ends_with(' ');
+            self.pending.clear();
+            self.nonempty = true;
+        }
+        if self.space_next && self.nonempty && !self.ends_in_space && !closes(s) {
+            self.buffer.text.push(' ');
+        }
+        self.buffer.text.push_str(s);
+        self.ends_in_space = s.ends_with(' ');
+        self.nonempty = true;
+        self.space_next = false;
+    }
+}
+
+/// Reports whether `s` starts with text that should not be separated from
+/// what precedes it, such as a closing bracket or a comma, escaped or not.
+fn closes(s: &str) -> bool {
+    let s = s.strip_prefix('\\').unwrap_or(s);
+    s.starts_with(|c: char| matches!(c, ')' | ',' | ']' | '>') || c.is_whitespace())
+        || s.starts_with("&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protobuf::RepeatedField;
+
+    pub(crate) fn ms(kind: Kind, pre_text: &str, kids: Vec<MarkedSource>) -> MarkedSource {
+        let mut ms = MarkedSource::new();
+        ms.set_kind(kind);
+        ms.set_pre_text(pre_text.into());
+        ms.set_child(RepeatedField::from_vec(kids));
+        ms
+    }
+
+    fn list(sep: &str, final_token: bool, kids: Vec<MarkedSource>) -> MarkedSource {
+        let mut ms = ms(Kind::BOX, "PRE", kids);
+        ms.set_post_text("POST".into());
+        ms.set_post_child_text(sep.into());
+        ms.set_add_final_list_token(final_token);
+        ms
+    }
+
+    /// Returns `func(int a, b) bool`, declared in namespace `ns` and linked
+    /// to the definition `ns.func`.
+    fn function() -> MarkedSource {
+        let mut ctx = ms(Kind::CONTEXT, "", vec![ms(Kind::IDENTIFIER, "ns", vec![])]);
+        ctx.set_post_child_text(".".into());
+        ctx.set_add_final_list_token(true);
+        let mut name = ms(Kind::IDENTIFIER, "func", vec![]);
+        name.mut_link()
+            .push_default()
+            .mut_definition()
+            .push("ns.func".into());
+        let a = ms(
+            Kind::BOX,
+            "",
+            vec![
+                ms(Kind::TYPE, "int", vec![]),
+                ms(Kind::IDENTIFIER, "a", vec![]),
+            ],
+        );
+        let mut params = ms(
+            Kind::PARAMETER,
+            "(",
+            vec![a, ms(Kind::IDENTIFIER, "b", vec![])],
+        );
+        params.set_post_child_text(", ".into());
+        params.set_post_text(")".into());
+        let ret = ms(Kind::TYPE, " ", vec![ms(Kind::IDENTIFIER, "bool", vec![])]);
+        ms(
+            Kind::BOX,
+            "",
+            vec![ms(Kind::BOX, "", vec![ctx, name]), params, ret],
+        )
+    }
+
+    #[test]
+    fn renders_lists() {
+        let c = |s| ms(Kind::BOX, s, vec![]);
+        assert_eq!(render(&MarkedSource::new()), "");
+        assert_eq!(render(&list(",", true, vec![])), "PREPOST");
+        assert_eq!(render(&list(",", false, vec![c("C1")])), "PREC1POST");
+        assert_eq!(render(&list(",", true, vec![c("C1")])), "PREC1,POST");
+        assert_eq!(
+            render(&list(",", false, vec![c("C1"), c("C2")])),
+            "PREC1,C2POST"
+        );
+        assert_eq!(
+            render(&list(",", true, vec![c("C1"), c("C2")])),
+            "PREC1,C2,POST"
+        );
+    }
+
+    #[test]
+    fn renders_parts_of_signatures() {
+        let f = function();
+        assert_eq!(render(&f), "ns.func(int a, b) bool");
+        assert_eq!(
+            render_signature(&f, Format::Plaintext, None),
+            "func(int a, b) bool"
+        );
+        assert_eq!(render_call_site_signature(&f), "func(a, b)");
+        assert_eq!(
+            render_simple_qualified_name(&f, true, Format::Plaintext, None),
+            "ns.func"
+        );
+        assert_eq!(
+            render_simple_qualified_name(&f, false, Format::Plaintext, None),
+            "ns"
+        );
+        assert_eq!(
+            render_simple_identifier(&f, Format::Plaintext, None),
+            "func"
+        );
+        assert_eq!(
+            render_simple_params(&f, Format::Plaintext, None),
+            ["a", "b"]
+        );
+
+        let linkify = |def: &str| Some(format!("/#{def}&x"));
+        assert_eq!(
+            render_signature(&f, Format::Html, Some(&linkify)),
+            "<a href=\"/#ns.func&amp;x\">func</a>(int a, b) bool"
+        );
+
+        let mut lookup = ms(Kind::LOOKUP_BY_TYPED, "<", vec![]);
+        lookup.set_post_text(">".into());
+        let g = ms(
+            Kind::BOX,
+            "",
+            vec![ms(Kind::IDENTIFIER, "g", vec![]), lookup],
+        );
+        assert_eq!(render_signature(&g, Format::Html, None), "g&lt;???&gt;");
+        assert_eq!(render_simple_identifier(&g, Format::Plaintext, None), "g");
+    }
+
+    #[test]
+    fn renders_qualified_names() {
+        let qname = |ms: &MarkedSource| {
+            let info = render_qualified_name(ms);
+            (info.base_name, info.qualified_name)
+        };
+        let id = |s| ms(Kind::IDENTIFIER, s, vec![]);
+        assert_eq!(
+            qname(&function()),
+            ("func".to_owned(), "ns.func".to_owned())
+        );
+        // A context without a separator joins its parts with dots but adds
+        // none before the identifier.
+        let f = ms(
+            Kind::BOX,
+            "",
+            vec![ms(Kind::CONTEXT, "", vec![id("a"), id("b")]), id("-tail")],
+        );
+        assert_eq!(qname(&f), ("-tail".to_owned(), "a.b-tail".to_owned()));
+        assert_eq!(qname(&id("hello")), ("hello".to_owned(), String::new()));
+        assert_eq!(qname(&MarkedSource::new()), Default::default());
+    }
+}
@@ -0,0 +1,324 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Resolution of the lookup nodes of MarkedSource, as
+//! `kythe/go/util/markedsource/resolve.go`.
+
+use crate::{Error, Result, MAX_RENDER_DEPTH};
+use common_rust_proto::{MarkedSource, MarkedSource_Kind as Kind};
+use kythe_schema::{edges, facts};
+use protobuf::{Message, RepeatedField};
+use std::collections::{BTreeMap, HashMap};
+use storage_rust_proto::{Entry, VName};
+
+/// Produces MarkedSource in which lookup nodes are replaced by the
+/// MarkedSource of the nodes they refer to.
+#[derive(Default)]
+pub struct Resolver {
+    nodes: HashMap<String, MarkedSource>,
+    /// The targets of each node's param and tparam edges, by ordinal.
+    params: HashMap<String, Params>,
+    tparams: HashMap<String, Params>,
+    typed: HashMap<String, String>,
+}
+
+impl Resolver {
+    /// Builds a resolver from the `/kythe/code` facts and the `param`,
+    /// `tparam` and `typed` edges among `entries`.
+    pub fn new<'a>(entries: impl IntoIterator<Item = &'a Entry>) -> Result<Self> {
+        let mut r = Resolver::default();
+        for e in entries {
+            let ticket = || kythe_uri::to_string(e.get_source());
+            if e.get_fact_name() == facts::CODE {
+                let ms = MarkedSource::parse_from_bytes(e.get_fact_value()).map_err(|source| {
+                    Error::InvalidCode {
+                        ticket: ticket(),
+                        source,
+                    }
+                })?;
+                r.nodes.insert(ticket(), ms);
+                continue;
+            }
+            if e.get_edge_kind().is_empty() {
+                continue;
+            }
+            let target = kythe_uri::to_string(e.get_target());
+            let (kind, ordinal) = edges::parse_ordinal(e.get_edge_kind());
+            let list = match kind {
+                edges::TYPED => {
+                    r.typed.insert(ticket(), target);
+                    continue;
+                }
+                edges::PARAM => r.params.entry(ticket()).or_default(),
+                edges::TPARAM => r.tparams.entry(ticket()).or_default(),
+                _ => continue,
+            };
+            list.insert(ordinal.unwrap_or_default(), target);
+        }
+        Ok(r)
+    }
+
+    /// Returns the resolved MarkedSource of the node named by `vname`.
+    pub fn resolve(&self, vname: &VName) -> Option<MarkedSource> {
+        self.resolve_ticket(&kythe_uri::to_string(vname))
+    }
+
+    /// Returns the resolved MarkedSource of the node named by `ticket`.
+    pub fn resolve_ticket(&self, ticket: &str) -> Option<MarkedSource> {
+        self.resolve_at(ticket, 0)
+    }
+
+    fn resolve_at(&self, ticket: &str, depth: usize) -> Option<MarkedSource> {
+        if ticket.is_empty() || depth >= MAX_RENDER_DEPTH {
+            return None;
+        }
+        self.nodes
+            .get(ticket)
+            .and_then(|ms| self.resolve_node(ticket, ms, depth))
+    }
+
+    fn resolve_node(&self, ticket: &str, ms: &MarkedSource, depth: usize) -> Option<MarkedSource> {
+        let kind = ms.get_kind();
+        let index = ms.get_lookup_index();
+        let empty = Params::new();
+        // A missing ordinal below the highest one is an empty ticket, and one
+        // above it is an invalid lookup.
+        let lookup = |lists: &HashMap<String, Params>| {
+            let list = lists.get(ticket).unwrap_or(&empty);
+            match list.get(&index) {
+                Some(p) => exclude(
+                    kind,
+                    ensure_kind(self.resolve_at(p, depth + 1), Kind::PARAMETER),
+                ),
+                None if index < param_count(list) => self.resolve_children(ticket, ms, depth),
+                None => Some(invalid_lookup()),
+            }
+        };
+        let parameters = |lists: &HashMap<String, Params>| {
+            let list = lists.get(ticket).unwrap_or(&empty);
+            if index > param_count(list) {
+                return Some(invalid_lookup());
+            }
+            Some(self.resolve_parameters(ms, list.range(index..).map(|(_, p)| p), depth))
+        };
+        match kind {
+            Kind::LOOKUP_BY_PARAM => lookup(&self.params),
+            Kind::LOOKUP_BY_TPARAM => lookup(&self.tparams),
+            Kind::LOOKUP_BY_TYPED => {
+                let ty = self.typed.get(ticket).map_or("", String::as_str);
+                exclude(
+                    kind,
+                    ensure_kind(self.resolve_at(ty, depth + 1), Kind::TYPE),
+                )
+            }
+            Kind::PARAMETER_LOOKUP_BY_PARAM | Kind::PARAMETER_LOOKUP_BY_PARAM_WITH_DEFAULTS => {
+                parameters(&self.params)
+            }
+            Kind::PARAMETER_LOOKUP_BY_TPARAM => parameters(&self.tparams),
+            _ => self.resolve_children(ticket, ms, depth),
+        }
+    }
+
+    /// Returns a PARAMETER node like `base` whose children are the resolved
+    /// MarkedSource of `params`.
+    fn resolve_parameters<'a>(
+        &self,
+        base: &MarkedSource,
+        params: impl Iterator<Item = &'a String>,
+        depth: usize,
+    ) -> MarkedSource {
+        let mut ms = base.clone();
+        ms.set_kind(Kind::PARAMETER);
+        ms.set_lookup_index(0);
+        ms.set_child(
+            params
+                .filter_map(|p| exclude(base.get_kind(), self.resolve_at(p, depth + 1)?))
+                .collect(),
+        );
+        ms
+    }
+
+    fn resolve_children(
+        &self,
+        ticket: &str,
+        ms: &MarkedSource,
+        depth: usize,
+    ) -> Option<MarkedSource> {
+        let mut out = ms.clone();
+        out.set_child(
+            ms.get_child()
+                .iter()
+                .filter_map(|kid| self.resolve_node(ticket, kid, depth + 1))
+                .collect(),
+        );
+        Some(out)
+    }
+}
+
+/// The targets of a node's param or tparam edges, by ordinal.
+type Params = BTreeMap<u32, String>;
+
+/// Returns one past the highest ordinal in `params`.
+fn param_count(params: &Params) -> u32 {
+    params
+        .keys()
+        .next_back()
+        .map_or(0, |&last| last.saturating_add(1))
+}
+
+/// Returns the PARAMETER that stands in for a lookup that cannot be
+/// resolved.
+fn invalid_lookup() -> MarkedSource {
+    let mut ms = MarkedSource::new();
+    ms.set_pre_text("???".into());
+    ensure_kind(Some(ms), Kind::PARAMETER)
+}
+
+/// Returns `ms` as a node of `kind`: a BOX becomes one, and anything else is
+/// wrapped in one.
+fn ensure_kind(ms: Option<MarkedSource>, kind: Kind) -> MarkedSource {
+    match ms {
+        Some(ms) if ms.get_kind() == kind => ms,
+        Some(mut ms) if ms.get_kind() == Kind::BOX => {
+            ms.set_kind(kind);
+            ms
+        }
+        ms => {
+            let mut wrapper = MarkedSource::new();
+            wrapper.set_kind(kind);
+            wrapper.set_child(ms.into_iter().collect::<RepeatedField<_>>());
+            wrapper
+        }
+    }
+}
+
+/// Returns `ms` without the nodes, itself included, that ask to be excluded
+/// when included by a lookup of `kind`.
+fn exclude(kind: Kind, mut ms: MarkedSource) -> Option<MarkedSource> {
+    if ms.get_exclude_on_include().contains(&kind) {
+        return None;
+    }
+    let kids = ms.take_child();
+    ms.set_child(
+        kids.into_iter()
+            .filter_map(|kid| exclude(kind, kid))
+            .collect(),
+    );
+    Some(ms)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::ms;
+    use crate::{render_signature, Format};
+
+    fn entry(source: &str, kind: &str, target: &str) -> Entry {
+        let vname = |s: &str| {
+            let mut v = VName::new();
+            v.set_signature(s.into());
+            v
+        };
+        let mut e = Entry::new();
+        e.set_source(vname(source));
+        e.set_edge_kind(kind.into());
+        e.set_target(vname(target));
+        e.set_fact_name("/".into());
+        e
+    }
+
+    fn code(source: &str, ms: &MarkedSource) -> Entry {
+        let mut e = entry(source, "", "");
+        e.clear_target();
+        e.set_fact_name(facts::CODE.into());
+        e.set_fact_value(ms.write_to_bytes().unwrap());
+        e
+    }
+
+    fn ticket(signature: &str) -> String {
+        format!("kythe:#{signature}")
+    }
+
+    #[test]
+    fn resolves_lookups() {
+        let mut params = ms(Kind::PARAMETER_LOOKUP_BY_PARAM, "(", vec![]);
+        params.set_post_child_text(", ".into());
+        params.set_post_text(")".into());
+        params.set_lookup_index(1);
+        let mut ret = ms(Kind::LOOKUP_BY_TYPED, " ", vec![]);
+        ret.set_post_text(";".into());
+        let f = ms(
+            Kind::BOX,
+            "",
+            vec![
+                ms(Kind::IDENTIFIER, "f", vec![]),
+                params,
+                ms(Kind::LOOKUP_BY_PARAM, "", vec![]),
+                ret,
+            ],
+        );
+        let mut hidden = ms(Kind::IDENTIFIER, "hidden", vec![]);
+        hidden
+            .mut_exclude_on_include()
+            .push(Kind::PARAMETER_LOOKUP_BY_PARAM);
+        let y = ms(
+            Kind::BOX,
+            "",
+            vec![ms(Kind::IDENTIFIER, "y", vec![]), hidden],
+        );
+        let entries = [
+            code("f", &f),
+            code("x", &ms(Kind::IDENTIFIER, "x", vec![])),
+            code("y", &y),
+            code("z", &ms(Kind::IDENTIFIER, "z", vec![])),
+            entry("f", &edges::ordinal(edges::PARAM, 0), "x"),
+            entry("f", &edges::ordinal(edges::PARAM, 1), "y"),
+            entry("f", &edges::ordinal(edges::PARAM, 2), "z"),
+            entry("f", edges::TYPED, "x"),
+        ];
+        let r = Resolver::new(&entries).unwrap();
+        let resolved = r.resolve_ticket(&ticket("f")).unwrap();
+        assert_eq!(
+            render_signature(&resolved, Format::Plaintext, None),
+            "f(y, z)xx"
+        );
+        assert_eq!(r.resolve_ticket(&ticket("missing")), None);
+        assert!(Resolver::new(&[{
+            let mut e = code("bad", &f);
+            e.set_fact_value(b"\xff"[..].into());
+            e
+        }])
+        .is_err());
+    }
+
+    #[test]
+    fn resolves_huge_ordinals() {
+        let mut lookup = ms(Kind::LOOKUP_BY_PARAM, "", vec![]);
+        lookup.set_lookup_index(4_000_000_000);
+        let f = ms(
+            Kind::BOX,
+            "",
+            vec![ms(Kind::IDENTIFIER, "f", vec![]), lookup],
+        );
+        let entries = [
+            code("f", &f),
+            code("x", &ms(Kind::IDENTIFIER, "x", vec![])),
+            entry("f", "/kythe/edge/param.4000000000", "x"),
+            entry("f", &edges::ordinal(edges::PARAM, u32::MAX), "x"),
+        ];
+        let r = Resolver::new(&entries).unwrap();
+        let resolved = r.resolve_ticket(&ticket("f")).unwrap();
+        assert_eq!(render_signature(&resolved, Format::Plaintext, None), "fx");
+    }
+}
@@ -0,0 +1,750 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Generated-code metadata, as `kythe/cxx/common/kythe_metadata_file.h` and
+//! `kythe/go/util/metadata`.
+//!
+//! A code generator may describe how its output relates to its input with a
+//! metadata sidecar: either a `kythe0` JSON object listing [`MappingRule`]s
+//! or the protobuf compiler's `GeneratedCodeInfo`, stored in a file of its
+//! own or base64-encoded in a comment of the generated file. [`Rules`] loads
+//! any of these forms. An indexer of the generated file then hands it each
+//! anchor that defines a node, and the matching rules link that node back to
+//! its source with `generates` and `imputes` edges.
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use kythe_schema::edges;
+use kythe_storage::emitter::Emitter;
+use kythe_storage::EntrySink;
+use metadata_rust_proto::{
+    GeneratedCodeInfo, GeneratedCodeInfo_Type, MappingRule, MappingRule_Semantic, MappingRule_Type,
+};
+use protobuf::descriptor::GeneratedCodeInfo as ProtoCodeInfo;
+use protobuf::Message;
+use serde_json::Value;
+use storage_rust_proto::VName;
+use thiserror::Error;
+
+/// The language of the nodes named by protobuf `GeneratedCodeInfo`.
+const PROTOBUF_LANGUAGE: &str = "protobuf";
+
+/// File name suffixes of sidecars holding protobuf `GeneratedCodeInfo`.
+const PROTOBUF_SUFFIXES: &[&str] = &[
+    ".pb.h.meta",
+    ".pb.h",
+    ".proto.h.meta",
+    ".proto.h",
+    ".stubby.h",
+];
+
+/// Errors produced while loading metadata.
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("protobuf error: {0}")]
+    Proto(#[from] protobuf::ProtobufError),
+    #[error("invalid metadata: {0}")]
+    Invalid(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// An additional semantic a rule gives to the node it matches.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Semantic {
+    #[default]
+    None,
+    Write,
+    ReadWrite,
+    TakeAlias,
+}
+
+/// A single metadata rule.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rule {
+    /// The start of the generated text to match, inclusive.
+    pub begin: usize,
+    /// The end of the generated text to match, exclusive.
+    pub end: usize,
+    /// The edge kind an anchor over the match must have to the node it
+    /// defines.
+    pub edge_in: String,
+    /// The edge kind to emit between the defined node and `vname`; empty if
+    /// the rule only carries a semantic.
+    pub edge_out: String,
+    /// The node to draw the edge to or from.
+    pub vname: VName,
+    /// Whether the edge is drawn from `vname` rather than to it.
+    pub reverse: bool,
+    /// The `[begin, end)` span of an anchor to create in `vname`'s file; the
+    /// anchor replaces `vname` as the far end of the edge.
+    pub anchor: Option<(usize, usize)>,
+    pub semantic: Semantic,
+}
+
+impl Rule {
+    /// Converts a `MappingRule` as the C++ indexer does, returning `None` for
+    /// `NOP` rules.
+    pub fn from_mapping(mapping: &MappingRule) -> Result<Option<Self>> {
+        let kind = mapping.get_field_type();
+        if kind == MappingRule_Type::NOP {
+            return Ok(None);
+        }
+        let edge = mapping.get_edge();
+        if edge.is_empty()
+            && !(kind == MappingRule_Type::ANCHOR_DEFINES
+                && mapping.get_semantic() != MappingRule_Semantic::SEMA_NONE)
+        {
+            return Err(Error::Invalid("rule with an empty edge".into()));
+        }
+        let reverse = edges::is_reverse(edge);
+        let edge_out = edges::canonical(edge).to_owned();
+        match kind {
+            MappingRule_Type::ANCHOR_DEFINES => Ok(Some(Rule {
+                begin: mapping.get_begin() as usize,
+                end: mapping.get_end() as usize,
+                edge_in: edges::DEFINES_BINDING.to_owned(),
+                edge_out,
+                vname: checked_vname(mapping.get_vname())?,
+                reverse,
+                anchor: None,
+                semantic: match mapping.get_semantic() {
+                    MappingRule_Semantic::SEMA_NONE => Semantic::None,
+                    MappingRule_Semantic::SEMA_WRITE => Semantic::Write,
+                    MappingRule_Semantic::SEMA_READ_WRITE => Semantic::ReadWrite,
+                    MappingRule_Semantic::SEMA_TAKE_ALIAS => Semantic::TakeAlias,
+                },
+            })),
+            // The source anchor is the far end of the edge, so an `imputes`
+            // rule, written from the generated node's point of view, runs from
+            // the source anchor unless it is marked as reversed.
+            MappingRule_Type::ANCHOR_ANCHOR => Ok(Some(Rule {
+                begin: mapping.get_target_begin() as usize,
+                end: mapping.get_target_end() as usize,
+                edge_in: edges::DEFINES_BINDING.to_owned(),
+                edge_out,
+                vname: checked_vname(mapping.get_source_vname())?,
+                reverse: !reverse,
+                anchor: Some((
+                    mapping.get_source_begin() as usize,
+                    mapping.get_source_end() as usize,
+                )),
+                semantic: Semantic::None,
+            })),
+            other => Err(Error::Invalid(format!("unknown rule type {other:?}"))),
+        }
+    }
+}
+
+/// The metadata rules of a single generated file.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Rules {
+    /// Rules over a span of the file, ordered by `begin`.
+    spans: Vec<Rule>,
+    /// Rules about the file as a whole.
+    files: Vec<Rule>,
+}
+
+impl Rules {
+    fn new(mut spans: Vec<Rule>, files: Vec<Rule>) -> Self {
+        spans.sort_by_key(|rule| rule.begin);
+        Self { spans, files }
+    }
+
+    /// Loads the rules of a `kythe0` metadata message.
+    pub fn from_code_info(info: &GeneratedCodeInfo) -> Result<Self> {
+        if info.get_field_type() != GeneratedCodeInfo_Type::KYTHE0 {
+            return Err(Error::Invalid(format!(
+                "wrong type tag {:?}",
+                info.get_field_type()
+            )));
+        }
+        let mut spans = Vec::new();
+        for mapping in info.get_meta() {
+            spans.extend(Rule::from_mapping(mapping)?);
+        }
+        Ok(Self::new(spans, Vec::new()))
+    }
+
+    /// Parses the JSON encoding of a `kythe0` metadata message, accepting
+    /// lower-case enum names as existing generators write them.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let value: Value = serde_json::from_str(json)?;
+        Self::from_code_info(&code_info_from_json(&value)?)
+    }
+
+    /// Loads the annotations the protobuf compiler attaches to generated
+    /// code. `context` is the VName of the metadata file, whose corpus labels
+    /// the `.proto` files the annotations name.
+    pub fn from_proto_code_info(info: &ProtoCodeInfo, context: &VName) -> Self {
+        let mut spans: Vec<Rule> = Vec::new();
+        let mut last: Option<&VName> = None;
+        let mut last_begin = 0;
+        for annotation in info.get_annotation() {
+            let mut vname = VName::new();
+            vname.set_corpus(context.get_corpus().to_owned());
+            if annotation.get_source_file().ends_with(".gen.proto") {
+                vname.set_root(context.get_root().to_owned());
+            }
+            vname.set_path(annotation.get_source_file().to_owned());
+            vname.set_language(PROTOBUF_LANGUAGE.to_owned());
+            let path: Vec<String> = annotation.get_path().iter().map(i32::to_string).collect();
+            vname.set_signature(path.join("."));
+            spans.push(Rule {
+                begin: annotation.get_begin().max(0) as usize,
+                end: annotation.get_end().max(0) as usize,
+                edge_in: edges::DEFINES_BINDING.to_owned(),
+                edge_out: edges::GENERATES.to_owned(),
+                vname,
+                reverse: true,
+                anchor: None,
+                semantic: Semantic::None,
+            });
+        }
+        // The generating .proto is the source of the last annotation; other
+        // files only contribute forward declarations early in the output.
+        for rule in &spans {
+            if !rule.vname.get_path().is_empty() && (last.is_none() || rule.begin > last_begin) {
+                last = Some(&rule.vname);
+                last_begin = rule.begin;
+            }
+        }
+        let files = last
+            .map(|source| {
+                let mut vname = source.clone();
+                vname.clear_signature();
+                vname.clear_language();
+                vec![Rule {
+                    begin: 0,
+                    end: 0,
+                    edge_in: String::new(),
+                    edge_out: edges::GENERATES.to_owned(),
+                    vname,
+                    reverse: true,
+                    anchor: None,
+                    semantic: Semantic::None,
+                }]
+            })
+            .unwrap_or_default();
+        Self::new(spans, files)
+    }
+
+    /// Parses the metadata sidecar `file_name`.
+    ///
+    /// A `.h` sidecar may hold its metadata base64-encoded in a leading
+    /// comment. Sidecars of protobuf-generated headers hold `GeneratedCodeInfo`;
+    /// anything else is read as `kythe0` JSON. `context` is the VName of the
+    /// sidecar itself.
+    pub fn parse(file_name: &str, buffer: &[u8], context: &VName) -> Result<Self> {
+        if let Some(stem) = file_name.strip_suffix(".h") {
+            if buffer.starts_with(b"/*") || buffer.starts_with(b"//") {
+                if let Some(decoded) = comment_payload(buffer, 0, 2) {
+                    return Self::parse_decoded(stem, &decoded, context);
+                }
+            }
+        }
+        Self::parse_decoded(file_name, buffer, context)
+    }
+
+    /// Parses metadata embedded in the generated file `file_name` within a
+    /// comment that starts with `marker`, returning `None` if there is none.
+    pub fn parse_inline(
+        file_name: &str,
+        buffer: &[u8],
+        marker: &str,
+        context: &VName,
+    ) -> Result<Option<Self>> {
+        let start = [format!("/* {marker}"), format!("// {marker}")]
+            .iter()
+            .find_map(|prefix| find(buffer, prefix.as_bytes(), 0));
+        let Some(start) = start else {
+            return Ok(None);
+        };
+        let decoded = comment_payload(buffer, start, start + 3 + marker.len())
+            .ok_or_else(|| Error::Invalid("metadata comment is not base64".into()))?;
+        Self::parse_decoded(file_name, &decoded, context).map(Some)
+    }
+
+    fn parse_decoded(file_name: &str, buffer: &[u8], context: &VName) -> Result<Self> {
+        if PROTOBUF_SUFFIXES.iter().any(|s| file_name.ends_with(s)) {
+            if let Ok(info) = ProtoCodeInfo::parse_from_bytes(buffer) {
+                return Ok(Self::from_proto_code_info(&info, context));
+            }
+        }
+        let json = std::str::from_utf8(buffer)
+            .map_err(|e| Error::Invalid(format!("{file_name} is not UTF-8: {e}")))?;
+        Self::from_json(json)
+    }
+
+    /// Returns the rules over spans of the file, ordered by `begin`.
+    pub fn rules(&self) -> &[Rule] {
+        &self.spans
+    }
+
+    /// Returns the rules about the file as a whole.
+    pub fn file_rules(&self) -> &[Rule] {
+        &self.files
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty() && self.files.is_empty()
+    }
+
+    /// Returns the rules matching an anchor over `[begin, end)` that has an
+    /// `edge` to the node it defines.
+    pub fn matching<'a>(
+        &'a self,
+        begin: usize,
+        end: usize,
+        edge: &'a str,
+    ) -> impl Iterator<Item = &'a Rule> + 'a {
+        let first = self.spans.partition_point(|rule| rule.begin < begin);
+        self.spans[first..]
+            .iter()
+            .take_while(move |rule| rule.begin == begin)
+            .filter(move |rule| {
+                rule.end == end && is_definition(edge) && is_definition(&rule.edge_in)
+            })
+    }
+
+    /// Returns the semantic that rules give to the node defined by an anchor
+    /// over `[begin, end)`.
+    pub fn semantic(&self, begin: usize, end: usize, edge: &str) -> Semantic {
+        self.matching(begin, end, edge)
+            .map(|rule| rule.semantic)
+            .find(|&semantic| semantic != Semantic::None)
+            .unwrap_or_default()
+    }
+
+    /// Emits the edges of the rules matching an anchor over `[begin, end)`
+    /// that has an `edge` to `node`, along with any source anchors they name.
+    pub fn apply<S: EntrySink>(
+        &self,
+        emitter: &mut Emitter<S>,
+        begin: usize,
+        end: usize,
+        edge: &str,
+        node: &VName,
+    ) -> kythe_storage::Result<()> {
+        for rule in self.matching(begin, end, edge) {
+            if rule.edge_out.is_empty() {
+                continue;
+            }
+            let mut remote = rule.vname.clone();
+            if let Some((start, end)) = rule.anchor {
+                // Distinguish these anchors from ordinary ones for debugging.
+                remote.set_signature(format!("@@m{start}-{end}"));
+                emitter.emit_anchor(&remote, start, end)?;
+            }
+            emit(emitter, rule, &remote, node)?;
+        }
+        Ok(())
+    }
+
+    /// Emits the edges of the file-scope rules for the generated `file`. This
+    /// should be called once for each file the rules are applied to.
+    pub fn apply_file<S: EntrySink>(
+        &self,
+        emitter: &mut Emitter<S>,
+        file: &VName,
+    ) -> kythe_storage::Result<()> {
+        let mut file = file.clone();
+        file.clear_signature();
+        file.clear_language();
+        for rule in &self.files {
+            emit(emitter, rule, &rule.vname, &file)?;
+        }
+        Ok(())
+    }
+}
+
+fn emit<S: EntrySink>(
+    emitter: &mut Emitter<S>,
+    rule: &Rule,
+    remote: &VName,
+    node: &VName,
+) -> kythe_storage::Result<()> {
+    if rule.reverse {
+        emitter.emit_edge(remote, &rule.edge_out, node)
+    } else {
+        emitter.emit_edge(node, &rule.edge_out, remote)
+    }
+}
+
+fn is_definition(edge: &str) -> bool {
+    edge == edges::DEFINES || edge == edges::DEFINES_BINDING
+}
+
+fn checked_vname(vname: &metadata_rust_proto::VName) -> Result<VName> {
+    if vname.get_signature().is_empty()
+        && vname.get_corpus().is_empty()
+        && vname.get_root().is_empty()
+        && vname.get_path().is_empty()
+        && vname.get_language().is_empty()
+    {
+        return Err(Error::Invalid("rule with an empty vname".into()));
+    }
+    let mut out = VName::new();
+    out.set_signature(vname.get_signature().to_owned());
+    out.set_corpus(vname.get_corpus().to_owned());
+    out.set_root(vname.get_root().to_owned());
+    out.set_path(vname.get_path().to_owned());
+    out.set_language(vname.get_language().to_owned());
+    Ok(out)
+}
+
+/// Returns the member `name` of a JSON object, also accepting the
+/// lowerCamelCase spelling of the proto3 JSON mapping.
+fn member<'a>(value: &'a Value, name: &str) -> Option<&'a Value> {
+    if let Some(v) = value.get(name) {
+        return Some(v);
+    }
+    let mut camel = String::new();
+    let mut upper = false;
+    for c in name.chars() {
+        match c {
+            '_' => upper = true,
+            c if upper => {
+                camel.push(c.to_ascii_uppercase());
+                upper = false;
+            }
+            c => camel.push(c),
+        }
+    }
+    value.get(camel.as_str())
+}
+
+fn string_member(value: &Value, name: &str) -> Result<String> {
+    match member(value, name) {
+        None | Some(Value::Null) => Ok(String::new()),
+        Some(Value::String(s)) => Ok(s.clone()),
+        Some(other) => Err(Error::Invalid(format!(
+            "field {name:?} is not a string: {other}"
+        ))),
+    }
+}
+
+fn u32_member(value: &Value, name: &str) -> Result<u32> {
+    let invalid = |v: &Value| Error::Invalid(format!("field {name:?} is not a uint32: {v}"));
+    match member(value, name) {
+        None | Some(Value::Null) => Ok(0),
+        Some(v @ Value::Number(n)) => n
+            .as_u64()
+            .and_then(|n| u32::try_from(n).ok())
+            .ok_or_else(|| invalid(v)),
+        Some(v @ Value::String(s)) => s.parse().map_err(|_| invalid(v)),
+        Some(other) => Err(invalid(other)),
+    }
+}
+
+/// Returns the index in `names` of the enum member `name`, spelled either as
+/// a case-insensitive name or as its number.
+fn enum_member(value: &Value, name: &str, names: &[&str]) -> Result<usize> {
+    let found = match member(value, name) {
+        None | Some(Value::Null) => Some(0),
+        Some(Value::String(s)) => names.iter().position(|n| n.eq_ignore_ascii_case(s)),
+        Some(Value::Number(n)) => n.as_u64().map(|n| n as usize).filter(|&n| n < names.len()),
+        Some(_) => None,
+    };
+    found.ok_or_else(|| {
+        Error::Invalid(format!(
+            "field {name:?} is not one of {names:?}: {}",
+            member(value, name).unwrap_or(&Value::Null)
+        ))
+    })
+}
+
+fn vname_from_json(value: Option<&Value>) -> Result<metadata_rust_proto::VName> {
+    let mut vname = metadata_rust_proto::VName::new();
+    if let Some(value) = value.filter(|v| !v.is_null()) {
+        vname.set_signature(string_member(value, "signature")?);
+        vname.set_corpus(string_member(value, "corpus")?);
+        vname.set_root(string_member(value, "root")?);
+        vname.set_path(string_member(value, "path")?);
+        vname.set_language(string_member(value, "language")?);
+    }
+    Ok(vname)
+}
+
+fn code_info_from_json(value: &Value) -> Result<GeneratedCodeInfo> {
+    let mut info = GeneratedCodeInfo::new();
+    info.set_field_type(match enum_member(value, "type", &["none", "kythe0"])? {
+        0 => GeneratedCodeInfo_Type::NONE,
+        _ => GeneratedCodeInfo_Type::KYTHE0,
+    });
+    let meta = match member(value, "meta") {
+        None | Some(Value::Null) => &[][..],
+        Some(Value::Array(items)) => &items[..],
+        Some(other) => return Err(Error::Invalid(format!("meta is not an array: {other}"))),
+    };
+    for item in meta {
+        let mut rule = MappingRule::new();
+        rule.set_field_type(
+            match enum_member(
+                item,
+                "type",
+                &["none", "nop", "anchor_defines", "anchor_anchor"],
+            )? {
+                0 => MappingRule_Type::NONE,
+                1 => MappingRule_Type::NOP,
+                2 => MappingRule_Type::ANCHOR_DEFINES,
+                _ => MappingRule_Type::ANCHOR_ANCHOR,
+            },
+        );
+        rule.set_edge(string_member(item, "edge")?);
+        rule.set_vname(vname_from_json(member(item, "vname"))?);
+        rule.set_begin(u32_member(item, "begin")?);
+        rule.set_end(u32_member(item, "end")?);
+        rule.set_semantic(
+            match enum_member(
+                item,
+                "semantic",
+                &[
+                    "sema_none",
+                    "sema_write",
+                    "sema_read_write",
+                    "sema_take_alias",
+                ],
+            )? {
+                0 => MappingRule_Semantic::SEMA_NONE,
+                1 => MappingRule_Semantic::SEMA_WRITE,
+                2 => MappingRule_Semantic::SEMA_READ_WRITE,
+                _ => MappingRule_Semantic::SEMA_TAKE_ALIAS,
+            },
+        );
+        rule.set_source_vname(vname_from_json(member(item, "source_vname"))?);
+        rule.set_source_begin(u32_member(item, "source_begin")?);
+        rule.set_source_end(u32_member(item, "source_end")?);
+        rule.set_target_begin(u32_member(item, "target_begin")?);
+        rule.set_target_end(u32_member(item, "target_end")?);
+        info.mut_meta().push(rule);
+    }
+    Ok(info)
+}
+
+fn find(haystack: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+    haystack[from..]
+        .windows(needle.len())
+        .position(|w| w == needle)
+        .map(|i| i + from)
+}
+
+/// Decodes the base64 payload of the comment whose leading `/` is at `slash`,
+/// starting at `start`. Whitespace around each line of a block comment is
+/// ignored; a line comment holds the whole payload on its one line.
+fn comment_payload(text: &[u8], slash: usize, start: usize) -> Option<Vec<u8>> {
+    let single_line = text.get(slash + 1) == Some(&b'/');
+    let term = if single_line {
+        None
+    } else {
+        find(text, b"*/", start)
+    };
+    let mut raw = Vec::new();
+    let mut pos = start.min(text.len());
+    loop {
+        while pos < text.len() && text[pos].is_ascii_whitespace() {
+            pos += 1;
+        }
+        match (term, find(text, b"\n", pos)) {
+            (Some(t), Some(n)) if t < n => {
+                raw.extend_from_slice(&text[pos..t]);
+                break;
+            }
+            (Some(t), None) => {
+                raw.extend_from_slice(&text[pos..t]);
+                break;
+            }
+            (_, Some(n)) => {
+                raw.extend_from_slice(&text[pos..n]);
+                pos = n + 1;
+                if single_line {
+                    break;
+                }
+            }
+            (None, None) => {
+                raw.extend_from_slice(&text[pos..]);
+                break;
+            }
+        }
+    }
+    STANDARD.decode(raw).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protobuf::descriptor::GeneratedCodeInfo_Annotation;
+    use storage_rust_proto::Entry;
+
+    fn vname(signature: &str, path: &str, language: &str) -> VName {
+        let mut v = VName::new();
+        v.set_corpus("kythe".into());
+        v.set_path(path.into());
+        v.set_signature(signature.into());
+        v.set_language(language.into());
+        v
+    }
+
+    fn edges_of(entries: &[Entry]) -> Vec<(String, String, String)> {
+        entries
+            .iter()
+            .filter(|e| !e.get_edge_kind().is_empty())
+            .map(|e| {
+                (
+                    e.get_source().get_signature().to_owned(),
+                    e.get_edge_kind().to_owned(),
+                    e.get_target().get_signature().to_owned(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn applies_json_rules() {
+        let rules = Rules::from_json(
+            r#"{"type": "kythe0", "meta": [
+                {"type": "nop", "begin": 1, "end": 2},
+                {"type": "anchor_defines", "begin": 10, "end": 13,
+                 "edge": "%/kythe/edge/generates",
+                 "vname": {"signature": "src", "corpus": "kythe", "language": "c++"}},
+                {"type": "anchor_defines", "begin": 10, "end": 13,
+                 "semantic": "SEMA_WRITE",
+                 "vname": {"signature": "ignored", "corpus": "kythe"}},
+                {"type": "ANCHOR_ANCHOR", "target_begin": 20, "targetEnd": 25,
+                 "source_begin": 3, "source_end": 8,
+                 "edge": "/kythe/edge/imputes",
+                 "source_vname": {"corpus": "kythe", "path": "a.gen"}}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(rules.rules().len(), 3);
+        assert_eq!(
+            rules.semantic(10, 13, edges::DEFINES_BINDING),
+            Semantic::Write
+        );
+
+        let node = vname("gen", "a.h", "c++");
+        let mut emitter = Emitter::new(Vec::new());
+        rules
+            .apply(&mut emitter, 10, 13, edges::DEFINES_BINDING, &node)
+            .unwrap();
+        rules
+            .apply(&mut emitter, 20, 25, edges::DEFINES, &node)
+            .unwrap();
+        rules
+            .apply(&mut emitter, 20, 25, edges::REF, &node)
+            .unwrap();
+        let entries = emitter.into_inner();
+        assert_eq!(
+            edges_of(&entries),
+            [
+                (
+                    "src".to_owned(),
+                    edges::GENERATES.to_owned(),
+                    "gen".to_owned()
+                ),
+                (
+                    "@@m3-8".to_owned(),
+                    edges::IMPUTES.to_owned(),
+                    "gen".to_owned()
+                ),
+            ]
+        );
+        let anchor = entries
+            .iter()
+            .find(|e| e.get_fact_name() == "/kythe/loc/start")
+            .unwrap();
+        assert_eq!(anchor.get_source().get_path(), "a.gen");
+        assert_eq!(anchor.get_fact_value(), b"3");
+    }
+
+    #[test]
+    fn rejects_invalid_json() {
+        assert!(Rules::from_json(r#"{"type": "kythe1", "meta": []}"#).is_err());
+        assert!(Rules::from_json(
+            r#"{"type": "kythe0", "meta": [{"type": "anchor_defines", "edge": "/kythe/edge/generates"}]}"#
+        )
+        .is_err());
+        assert!(Rules::from_json(
+            r#"{"type": "kythe0", "meta": [{"type": "anchor_defines", "vname": {"signature": "s"}}]}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn parses_protobuf_header_metadata() {
+        let mut info = ProtoCodeInfo::new();
+        for (path, begin, end) in [(vec![4, 0], 30, 33), (vec![4, 0, 2, 1], 50, 53)] {
+            let mut annotation = GeneratedCodeInfo_Annotation::new();
+            annotation.set_path(path);
+            annotation.set_source_file("a.proto".into());
+            annotation.set_begin(begin);
+            annotation.set_end(end);
+            info.mut_annotation().push(annotation);
+        }
+        let header = format!(
+            "/* {}\n */\n",
+            STANDARD.encode(info.write_to_bytes().unwrap())
+        );
+        let context = vname("", "a.pb.h.meta", "");
+        let rules = Rules::parse("a.pb.h.meta.h", header.as_bytes(), &context).unwrap();
+        assert_eq!(
+            rules.rules()[1].vname,
+            vname("4.0.2.1", "a.proto", "protobuf")
+        );
+
+        let node = vname("field", "a.pb.h", "c++");
+        let mut emitter = Emitter::new(Vec::new());
+        rules
+            .apply(&mut emitter, 50, 53, edges::DEFINES_BINDING, &node)
+            .unwrap();
+        rules.apply_file(&mut emitter, &node).unwrap();
+        let entries = emitter.into_inner();
+        assert_eq!(
+            edges_of(&entries),
+            [
+                (
+                    "4.0.2.1".to_owned(),
+                    edges::GENERATES.to_owned(),
+                    "field".to_owned()
+                ),
+                (String::new(), edges::GENERATES.to_owned(), String::new()),
+            ]
+        );
+        assert_eq!(entries[1].get_source().get_path(), "a.proto");
+        assert_eq!(entries[1].get_target(), &vname("", "a.pb.h", ""));
+    }
+
+    #[test]
+    fn parses_inline_metadata() {
+        let json = r#"{"type":"kythe0","meta":[{"type":"anchor_defines","begin":1,"end":2,"edge":"/kythe/edge/generates","vname":{"signature":"s"}}]}"#;
+        let text = format!(
+            "int x;\n// kythe-inline-metadata {}\n",
+            STANDARD.encode(json)
+        );
+        let context = VName::new();
+        let rules = Rules::parse_inline("a.cc", text.as_bytes(), "kythe-inline-metadata", &context)
+            .unwrap()
+            .unwrap();
+        assert_eq!(rules.rules()[0].vname.get_signature(), "s");
+        assert!(!rules.rules()[0].reverse);
+        assert!(
+            Rules::parse_inline("a.cc", b"int x;", "kythe-inline-metadata", &context)
+                .unwrap()
+                .is_none()
+        );
+    }
+}
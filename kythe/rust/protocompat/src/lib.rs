@@ -0,0 +1,93 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversions between rust-protobuf and prost messages.
+//!
+//! `rust_proto_library(codegen = "prost")` builds a prost crate as a separate
+//! target next to the rust-protobuf one, e.g. `storage_rust_prost_proto` beside
+//! `storage_rust_proto`. Code that adopts it can use [`to_prost`] and
+//! [`from_prost`] wherever it exchanges messages with code that has not.
+//!
+//! The two crates define distinct types for every message, and so does each
+//! rust-protobuf crate that compiles its own copy of a dependency (e.g. the
+//! `VName` inside `analysis_rust_proto`). The conversions bridge them by
+//! serializing the message and parsing the bytes back, which works between
+//! any two bindings of the same message type but costs a full copy per call:
+//! keep them at the boundary between migrated and unmigrated code rather than
+//! in per-entry loops.
+
+use thiserror::Error;
+
+/// Errors produced while converting messages.
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("protobuf error: {0}")]
+    Proto(#[from] protobuf::ProtobufError),
+    #[error("prost decoding error: {0}")]
+    Decode(#[from] prost::DecodeError),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Converts a rust-protobuf message to the prost message `P`.
+pub fn to_prost<P: prost::Message + Default>(msg: &impl protobuf::Message) -> Result<P> {
+    Ok(P::decode(&msg.write_to_bytes()?[..])?)
+}
+
+/// Converts a prost message to the rust-protobuf message `M`.
+pub fn from_prost<M: protobuf::Message>(msg: &impl prost::Message) -> Result<M> {
+    Ok(M::parse_from_bytes(&msg.encode_to_vec())?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use storage_rust_prost_proto::{Entry, VName};
+    use storage_rust_proto::{Entry as LegacyEntry, VName as LegacyVName};
+
+    #[test]
+    fn converts() {
+        let mut vname = LegacyVName::new();
+        vname.set_corpus("kythe".into());
+        vname.set_path("a.go".into());
+
+        let p: VName = to_prost(&vname).unwrap();
+        assert_eq!(
+            p,
+            VName {
+                corpus: "kythe".into(),
+                path: "a.go".into(),
+                ..Default::default()
+            }
+        );
+        assert_eq!(from_prost::<LegacyVName>(&p).unwrap(), vname);
+
+        let mut entry = LegacyEntry::new();
+        entry.set_source(vname.clone());
+        entry.set_edge_kind("/kythe/edge/childof".into());
+        entry.set_target(vname);
+        entry.set_fact_name("/".into());
+        entry.set_fact_value(b"\xff".to_vec());
+        let p: Entry = to_prost(&entry).unwrap();
+        assert_eq!(p.target.as_ref().unwrap().path, "a.go");
+        assert_eq!(p.fact_value, b"\xff");
+        assert_eq!(from_prost::<LegacyEntry>(&p).unwrap(), entry);
+
+        let bad = protobuf::well_known_types::BytesValue {
+            value: vec![0xff],
+            ..Default::default()
+        };
+        assert!(to_prost::<VName>(&bad).is_err());
+    }
+}
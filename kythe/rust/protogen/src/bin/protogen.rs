@@ -0,0 +1,71 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Generates a Rust crate for protocol buffers with prost, pbjson and tonic.
+//!
+//! Usage: protogen --output lib.rs --descriptor_set FILE...
+//!            [--direct_descriptor_set FILE...]
+//!
+//! Code is generated for every file in the given descriptor sets, and the
+//! packages of the files in the direct descriptor sets are re-exported from
+//! the crate root.
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use prost::Message;
+use prost_types::{FileDescriptorProto, FileDescriptorSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Parser)]
+#[command(about = "Generates a Rust crate for protocol buffers")]
+struct Args {
+    /// Path of the crate root to write.
+    #[arg(long = "output")]
+    output: PathBuf,
+
+    /// Descriptor sets holding every file to generate code for.
+    #[arg(long = "descriptor_set", required = true)]
+    descriptor_sets: Vec<PathBuf>,
+
+    /// Descriptor sets holding the files whose packages are re-exported.
+    #[arg(long = "direct_descriptor_set")]
+    direct_descriptor_sets: Vec<PathBuf>,
+}
+
+fn read_files(paths: &[PathBuf]) -> Result<Vec<FileDescriptorProto>> {
+    let mut files = Vec::new();
+    for path in paths {
+        files.extend(read_descriptor_set(path)?.file);
+    }
+    Ok(files)
+}
+
+fn read_descriptor_set(path: &Path) -> Result<FileDescriptorSet> {
+    let data = fs::read(path).with_context(|| format!("reading {}", path.display()))?;
+    FileDescriptorSet::decode(&data[..]).with_context(|| format!("decoding {}", path.display()))
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let files = read_files(&args.descriptor_sets)?;
+    let mut exported: Vec<_> = read_files(&args.direct_descriptor_sets)?
+        .into_iter()
+        .map(|f| f.package().to_string())
+        .collect();
+    exported.sort();
+    exported.dedup();
+    let src = kythe_protogen::generate(&files, &exported)?;
+    fs::write(&args.output, src).with_context(|| format!("writing {}", args.output.display()))
+}
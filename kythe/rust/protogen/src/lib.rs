@@ -0,0 +1,178 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Generates Rust crates for protocol buffers with prost, pbjson and tonic.
+//!
+//! The crates produced by `rust_proto_library(codegen = "prost")` in
+//! `kythe/proto/rust.bzl` use plain `Option` and `Vec` fields rather than the
+//! rust-protobuf wrappers, implement `serde` traits following the proto3 JSON
+//! mapping and include tonic clients and servers for any services. Each
+//! protobuf package becomes a module, and the well-known types are taken from
+//! `pbjson_types` so that they serialize to JSON too.
+
+use prost_build::Module;
+use prost_types::FileDescriptorProto;
+use std::collections::{BTreeMap, HashSet};
+use std::io;
+use thiserror::Error;
+
+/// Errors produced while generating a crate.
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+    #[error("invalid descriptor set: {0}")]
+    Decode(#[from] prost::DecodeError),
+    #[error("invalid generated code: {0}")]
+    Syntax(#[from] syn::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The package of the well-known types.
+const WELL_KNOWN_TYPES: &str = ".google.protobuf";
+
+/// The crate providing the well-known types.
+const PBJSON_TYPES: &str = "::pbjson_types";
+
+/// Generates the source of a crate holding the messages, enums and services
+/// defined in `files`, which must include every file they import. Code is
+/// not generated for the well-known types. The contents of the `exported`
+/// packages are also re-exported from the crate root.
+pub fn generate(files: &[FileDescriptorProto], exported: &[String]) -> Result<String> {
+    let mut names = HashSet::new();
+    let (well_known, files): (Vec<_>, Vec<_>) = files
+        .iter()
+        .filter(|f| names.insert(f.name()))
+        .cloned()
+        .partition(|f| is_well_known(f.package()));
+
+    let mut config = prost_build::Config::new();
+    config
+        .compile_well_known_types()
+        .extern_path(WELL_KNOWN_TYPES, PBJSON_TYPES)
+        .service_generator(tonic_build::configure().service_generator());
+    let requests = files
+        .iter()
+        .map(|f| (Module::from_protobuf_package_name(f.package()), f.clone()))
+        .collect();
+    let mut modules = Tree::default();
+    for (module, code) in config.generate(requests)? {
+        modules.insert(&module, &code);
+    }
+
+    let mut json = pbjson_build::Builder::new();
+    for file in well_known.into_iter().chain(files.iter().cloned()) {
+        json.register_file_descriptor(file);
+    }
+    let mut prefixes: Vec<_> = files.iter().map(|f| format!(".{}", f.package())).collect();
+    prefixes.sort();
+    prefixes.dedup();
+    let serde = json
+        .extern_path(WELL_KNOWN_TYPES, PBJSON_TYPES)
+        .preserve_proto_field_names()
+        .generate(&prefixes, |_| Ok(Vec::new()))?;
+    for (package, code) in serde {
+        let module = Module::from_protobuf_package_name(&package.to_string());
+        modules.insert(&module, &String::from_utf8_lossy(&code));
+    }
+
+    let mut src = String::from("#![allow(clippy::all)]\n");
+    modules.render(&mut src);
+    for package in exported {
+        let module = Module::from_protobuf_package_name(package);
+        if !module.is_empty() {
+            let path: Vec<_> = module.parts().collect();
+            src.push_str(&format!("pub use self::{}::*;\n", path.join("::")));
+        }
+    }
+    let file = syn::parse_file(&src)?;
+    Ok(format!(
+        "// This file is generated by //kythe/rust/protogen. DO NOT EDIT.\n\n{}",
+        prettyplease::unparse(&file)
+    ))
+}
+
+/// Reports whether `package` holds well-known types.
+fn is_well_known(package: &str) -> bool {
+    format!(".{package}") == WELL_KNOWN_TYPES
+}
+
+/// The generated code of a module and its submodules.
+#[derive(Default)]
+struct Tree {
+    code: String,
+    children: BTreeMap<String, Tree>,
+}
+
+impl Tree {
+    fn insert(&mut self, module: &Module, code: &str) {
+        let tree = module.parts().fold(self, |tree, part| {
+            tree.children.entry(part.to_string()).or_default()
+        });
+        tree.code.push_str(code);
+    }
+
+    fn render(&self, out: &mut String) {
+        for (name, child) in &self.children {
+            out.push_str(&format!("pub mod {name} {{\n"));
+            child.render(out);
+            out.push_str("}\n");
+        }
+        out.push_str(&self.code);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message as _;
+    use protobuf::Message as _;
+
+    fn descriptor(file: &protobuf::descriptor::FileDescriptorProto) -> FileDescriptorProto {
+        FileDescriptorProto::decode(&file.write_to_bytes().unwrap()[..]).unwrap()
+    }
+
+    #[test]
+    fn generates_messages() {
+        let storage = descriptor(storage_rust_proto::storage::file_descriptor_proto());
+        let src = generate(&[storage.clone(), storage], &["kythe.proto".into()]).unwrap();
+        assert!(src.starts_with("// This file is generated"));
+        assert!(src.contains("pub mod kythe {"));
+        assert!(src.contains("pub struct VName {"));
+        assert!(src.contains("impl serde::Serialize for VName {"));
+        assert!(src.contains("\"signature\""));
+        assert!(src.contains("pub use self::kythe::proto::*;"));
+        assert_eq!(src.matches("pub struct VName {").count(), 1);
+    }
+
+    #[test]
+    fn generates_services() {
+        use xref_rust_proto::{common, timestamp, xref};
+        let files: Vec<_> = [
+            timestamp::file_descriptor_proto(),
+            common::file_descriptor_proto(),
+            xref::file_descriptor_proto(),
+        ]
+        .into_iter()
+        .map(descriptor)
+        .collect();
+        let src = generate(&files, &[]).unwrap();
+        assert!(src.contains("pub mod x_ref_service_client {"));
+        assert!(src.contains("pub mod x_ref_service_server {"));
+        assert!(src.contains("::pbjson_types::Timestamp"));
+        assert!(!src.contains("pub mod google"));
+        assert!(!src.contains("pub use self::"));
+    }
+}
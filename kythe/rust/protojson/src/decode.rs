@@ -0,0 +1,338 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion from the wire encoding of messages to JSON.
+
+use crate::{field_type_name, type_url_name, wkt, Descriptors, Error, Result, ANY, MAX_DEPTH};
+use base64::Engine;
+use protobuf::descriptor::{
+    FieldDescriptorProto, FieldDescriptorProto_Label, FieldDescriptorProto_Type,
+};
+use serde_json::{Map, Number, Value};
+
+/// Returns the JSON encoding of the message of type `type_name` encoded in
+/// `data`.
+pub(crate) fn message(
+    d: &Descriptors,
+    type_name: &str,
+    data: &[u8],
+    depth: usize,
+) -> Result<Value> {
+    if depth > MAX_DEPTH {
+        return Err(Error::Invalid(format!(
+            "message nesting deeper than {MAX_DEPTH}"
+        )));
+    }
+    if type_name == ANY {
+        return any(d, data, depth);
+    }
+    if wkt::is_well_known(type_name) {
+        return wkt::to_json(type_name, data, depth);
+    }
+    let message = d.message(type_name)?;
+    let fields = message.proto.get_field();
+
+    // Gather the values of each field; the last value of a singular field
+    // wins, and the occurrences of a singular message are merged.
+    let mut values: Vec<Vec<Value>> = vec![Vec::new(); fields.len()];
+    let mut merged: Vec<Vec<u8>> = vec![Vec::new(); fields.len()];
+    let mut present = vec![false; fields.len()];
+    let mut input = Input(data);
+    while !input.0.is_empty() {
+        let key = input.varint()?;
+        let (number, wire_type) = (key >> 3, key & 7);
+        let Some(i) = fields.iter().position(|f| f.get_number() as u64 == number) else {
+            input.skip(wire_type)?;
+            continue;
+        };
+        let field = &fields[i];
+        present[i] = true;
+        let repeated = field.get_label() == FieldDescriptorProto_Label::LABEL_REPEATED;
+        if wire_type == 2 && is_packable(field.get_field_type()) {
+            let mut packed = Input(input.bytes()?);
+            while !packed.0.is_empty() {
+                let value = scalar(d, field, &mut packed)?;
+                push(&mut values[i], value, repeated);
+            }
+            continue;
+        }
+        if wire_type != expected_wire_type(field.get_field_type()) {
+            return Err(Error::Invalid(format!(
+                "wire type {wire_type} for field {}",
+                field.get_name()
+            )));
+        }
+        if field.get_field_type() == FieldDescriptorProto_Type::TYPE_MESSAGE {
+            let bytes = input.bytes()?;
+            if repeated {
+                let value = submessage(d, field, bytes, depth)?;
+                values[i].push(value);
+            } else {
+                merged[i].extend_from_slice(bytes);
+            }
+        } else {
+            let value = scalar(d, field, &mut input)?;
+            push(&mut values[i], value, repeated);
+        }
+    }
+
+    let mut out = Map::new();
+    for (i, field) in fields.iter().enumerate() {
+        if !present[i] {
+            continue;
+        }
+        let repeated = field.get_label() == FieldDescriptorProto_Label::LABEL_REPEATED;
+        let value = if repeated {
+            let values = std::mem::take(&mut values[i]);
+            match d.map_entry(field) {
+                Some(entry_type) => {
+                    // Keys and values equal to their defaults may be omitted
+                    // from map entries on the wire.
+                    let default = |number| {
+                        entry_type
+                            .proto
+                            .get_field()
+                            .iter()
+                            .find(|f| f.get_number() == number)
+                            .map_or(Value::Null, |f| zero(d, f))
+                    };
+                    let mut map = Map::new();
+                    for entry in values {
+                        let Value::Object(mut entry) = entry else {
+                            continue;
+                        };
+                        let key = match entry.remove("key").unwrap_or_else(|| default(1)) {
+                            Value::String(key) => key,
+                            key => key.to_string(),
+                        };
+                        let value = entry.remove("value").unwrap_or_else(|| default(2));
+                        map.insert(key, value);
+                    }
+                    Value::Object(map)
+                }
+                None => Value::Array(values),
+            }
+        } else if field.get_field_type() == FieldDescriptorProto_Type::TYPE_MESSAGE {
+            submessage(d, field, &merged[i], depth)?
+        } else {
+            let value = values[i].pop().unwrap_or_default();
+            // Proto3 fields without presence are omitted when zero.
+            let has_presence = field.has_oneof_index() || field.get_proto3_optional();
+            if message.proto3 && !has_presence && value == zero(d, field) {
+                continue;
+            }
+            value
+        };
+        out.insert(field.get_name().to_string(), value);
+    }
+    Ok(Value::Object(out))
+}
+
+/// Returns the JSON encoding of a `google.protobuf.Any`: the fields of the
+/// packed message, with its type URL under `@type`. Packed messages with a
+/// special encoding are instead held under `value`.
+fn any(d: &Descriptors, data: &[u8], depth: usize) -> Result<Value> {
+    let (mut type_url, mut value) = (String::new(), Vec::new());
+    let mut input = Input(data);
+    while !input.0.is_empty() {
+        match input.varint()? {
+            0x0a => {
+                type_url = String::from_utf8(input.bytes()?.to_vec())
+                    .map_err(|_| Error::Invalid("Any type_url".into()))?;
+            }
+            0x12 => value = input.bytes()?.to_vec(),
+            key => input.skip(key & 7)?,
+        }
+    }
+    if type_url.is_empty() && value.is_empty() {
+        return Ok(Value::Object(Map::new()));
+    }
+    let name = type_url_name(&type_url);
+    if !d.contains(name) {
+        return Err(Error::UnknownType(type_url));
+    }
+    let special = name == ANY || wkt::is_well_known(name);
+    let value = message(d, name, &value, depth + 1)?;
+    let mut out = Map::new();
+    out.insert("@type".into(), Value::String(type_url));
+    match value {
+        Value::Object(fields) if !special => out.extend(fields),
+        value => {
+            out.insert("value".into(), value);
+        }
+    }
+    Ok(Value::Object(out))
+}
+
+fn submessage(
+    d: &Descriptors,
+    field: &FieldDescriptorProto,
+    data: &[u8],
+    depth: usize,
+) -> Result<Value> {
+    message(d, field_type_name(field), data, depth + 1)
+}
+
+/// Returns the JSON encoding of the zero value of `field`.
+fn zero(d: &Descriptors, field: &FieldDescriptorProto) -> Value {
+    use FieldDescriptorProto_Type::*;
+    match field.get_field_type() {
+        TYPE_MESSAGE | TYPE_GROUP => Value::Object(Map::new()),
+        TYPE_STRING | TYPE_BYTES => Value::String(String::new()),
+        TYPE_BOOL => Value::Bool(false),
+        TYPE_DOUBLE | TYPE_FLOAT => float(0.0),
+        TYPE_INT64 | TYPE_UINT64 | TYPE_FIXED64 | TYPE_SFIXED64 | TYPE_SINT64 => "0".into(),
+        TYPE_ENUM => enum_value(d, field, 0),
+        TYPE_INT32 | TYPE_UINT32 | TYPE_FIXED32 | TYPE_SFIXED32 | TYPE_SINT32 => 0.into(),
+    }
+}
+
+fn push(values: &mut Vec<Value>, value: Value, repeated: bool) {
+    if !repeated {
+        values.clear();
+    }
+    values.push(value);
+}
+
+fn is_packable(field_type: FieldDescriptorProto_Type) -> bool {
+    use FieldDescriptorProto_Type::*;
+    !matches!(
+        field_type,
+        TYPE_STRING | TYPE_BYTES | TYPE_MESSAGE | TYPE_GROUP
+    )
+}
+
+fn expected_wire_type(field_type: FieldDescriptorProto_Type) -> u64 {
+    use FieldDescriptorProto_Type::*;
+    match field_type {
+        TYPE_DOUBLE | TYPE_FIXED64 | TYPE_SFIXED64 => 1,
+        TYPE_FLOAT | TYPE_FIXED32 | TYPE_SFIXED32 => 5,
+        TYPE_STRING | TYPE_BYTES | TYPE_MESSAGE => 2,
+        TYPE_GROUP => 3,
+        _ => 0,
+    }
+}
+
+/// Decodes a non-message value of `field`.
+fn scalar(d: &Descriptors, field: &FieldDescriptorProto, input: &mut Input) -> Result<Value> {
+    use FieldDescriptorProto_Type::*;
+    Ok(match field.get_field_type() {
+        TYPE_DOUBLE => float(f64::from_bits(input.fixed64()?)),
+        TYPE_FLOAT => float(f64::from(f32::from_bits(input.fixed32()?))),
+        TYPE_INT64 => (input.varint()? as i64).to_string().into(),
+        TYPE_UINT64 => input.varint()?.to_string().into(),
+        TYPE_INT32 => (input.varint()? as i32).into(),
+        TYPE_FIXED64 => input.fixed64()?.to_string().into(),
+        TYPE_FIXED32 => input.fixed32()?.into(),
+        TYPE_BOOL => (input.varint()? != 0).into(),
+        TYPE_STRING => String::from_utf8(input.bytes()?.to_vec())
+            .map_err(|_| Error::Invalid(format!("UTF-8 in field {}", field.get_name())))?
+            .into(),
+        TYPE_BYTES => base64::engine::general_purpose::STANDARD
+            .encode(input.bytes()?)
+            .into(),
+        TYPE_UINT32 => (input.varint()? as u32).into(),
+        TYPE_ENUM => enum_value(d, field, input.varint()? as i32),
+        TYPE_SFIXED32 => (input.fixed32()? as i32).into(),
+        TYPE_SFIXED64 => (input.fixed64()? as i64).to_string().into(),
+        TYPE_SINT32 => {
+            let n = input.varint()? as u32;
+            (((n >> 1) as i32) ^ -((n & 1) as i32)).into()
+        }
+        TYPE_SINT64 => {
+            let n = input.varint()?;
+            (((n >> 1) as i64) ^ -((n & 1) as i64)).to_string().into()
+        }
+        TYPE_MESSAGE | TYPE_GROUP => {
+            return Err(Error::Invalid(format!("scalar field {}", field.get_name())))
+        }
+    })
+}
+
+pub(crate) fn float(f: f64) -> Value {
+    match Number::from_f64(f) {
+        Some(n) => Value::Number(n),
+        None if f.is_nan() => "NaN".into(),
+        None if f > 0.0 => "Infinity".into(),
+        None => "-Infinity".into(),
+    }
+}
+
+/// Returns the name of the value `number` of the enum type of `field`, or
+/// the number itself if it has no name.
+fn enum_value(d: &Descriptors, field: &FieldDescriptorProto, number: i32) -> Value {
+    if field_type_name(field) == wkt::NULL_VALUE {
+        return Value::Null;
+    }
+    d.enum_type(field_type_name(field))
+        .and_then(|e| e.get_value().iter().find(|v| v.get_number() == number))
+        .map_or_else(|| number.into(), |v| v.get_name().into())
+}
+
+/// A cursor over wire-encoded data.
+struct Input<'a>(&'a [u8]);
+
+impl<'a> Input<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8]> {
+        if n > self.0.len() {
+            return Err(Error::Invalid("truncated message".into()));
+        }
+        let (head, tail) = self.0.split_at(n);
+        self.0 = tail;
+        Ok(head)
+    }
+
+    fn varint(&mut self) -> Result<u64> {
+        let mut value = 0u64;
+        for (i, &b) in self.0.iter().take(10).enumerate() {
+            value |= u64::from(b & 0x7f) << (7 * i);
+            if b & 0x80 == 0 {
+                self.0 = &self.0[i + 1..];
+                return Ok(value);
+            }
+        }
+        Err(Error::Invalid("varint".into()))
+    }
+
+    fn fixed32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn fixed64(&mut self) -> Result<u64> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn bytes(&mut self) -> Result<&'a [u8]> {
+        let n = usize::try_from(self.varint()?).map_err(|_| Error::Invalid("length".into()))?;
+        self.take(n)
+    }
+
+    /// Skips a value of an unknown field.
+    fn skip(&mut self, wire_type: u64) -> Result<()> {
+        match wire_type {
+            0 => self.varint().map(drop),
+            1 => self.take(8).map(drop),
+            2 => self.bytes().map(drop),
+            5 => self.take(4).map(drop),
+            3 => loop {
+                let key = self.varint()?;
+                if key & 7 == 4 {
+                    return Ok(());
+                }
+                self.skip(key & 7)?;
+            },
+            _ => Err(Error::Invalid(format!("wire type {wire_type}"))),
+        }
+    }
+}
@@ -0,0 +1,314 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion from JSON to the wire encoding of messages.
+
+use crate::{field_type_name, type_url_name, wkt, Descriptors, Error, Result, ANY, MAX_DEPTH};
+use base64::Engine;
+use protobuf::descriptor::{
+    FieldDescriptorProto, FieldDescriptorProto_Label, FieldDescriptorProto_Type,
+};
+use serde_json::{Map, Value};
+
+/// Appends the wire encoding of the message of type `type_name` encoded as
+/// `json` to `out`.
+pub(crate) fn message(
+    d: &Descriptors,
+    type_name: &str,
+    json: &Value,
+    out: &mut Vec<u8>,
+    depth: usize,
+) -> Result<()> {
+    if depth > MAX_DEPTH {
+        return Err(Error::Invalid(format!(
+            "message nesting deeper than {MAX_DEPTH}"
+        )));
+    }
+    if wkt::is_well_known(type_name) {
+        out.extend(wkt::from_json(type_name, json, depth)?);
+        return Ok(());
+    }
+    let Value::Object(fields) = json else {
+        return Err(Error::Invalid(format!(
+            "{type_name}: expected an object, found {json}"
+        )));
+    };
+    if type_name == ANY {
+        return any(d, fields, out, depth);
+    }
+    let message = d.message(type_name)?;
+    let mut present = Vec::with_capacity(fields.len());
+    for (key, value) in fields {
+        let field = message
+            .proto
+            .get_field()
+            .iter()
+            .find(|f| f.get_name() == key || json_name(f) == *key)
+            .ok_or_else(|| Error::Invalid(format!("field {key:?} of {type_name}")))?;
+        // Null means absent, except for singular google.protobuf.Value
+        // fields, which then hold a null value.
+        let null_value = field.get_label() != FieldDescriptorProto_Label::LABEL_REPEATED
+            && field_type_name(field) == wkt::VALUE;
+        if !value.is_null() || null_value {
+            present.push((field, key, value));
+        }
+    }
+    // Fields are written in field number order, as protobuf serializers do.
+    present.sort_by_key(|(field, _, _)| field.get_number());
+    for (field, key, value) in present {
+        if field.get_label() != FieldDescriptorProto_Label::LABEL_REPEATED {
+            field_value(d, field, value, out, depth)?;
+        } else if let Some(entry) = d.map_entry(field) {
+            let Value::Object(map) = value else {
+                return Err(Error::Invalid(format!(
+                    "{key}: expected an object, found {value}"
+                )));
+            };
+            let entry_fields = entry.proto.get_field();
+            let (Some(key_field), Some(value_field)) = (
+                entry_fields.iter().find(|f| f.get_number() == 1),
+                entry_fields.iter().find(|f| f.get_number() == 2),
+            ) else {
+                return Err(Error::Invalid(format!("map entry of {key}")));
+            };
+            for (k, v) in map {
+                let mut buf = Vec::new();
+                field_value(d, key_field, &Value::String(k.clone()), &mut buf, depth)?;
+                field_value(d, value_field, v, &mut buf, depth)?;
+                put_key(out, field.get_number(), 2);
+                put_bytes(out, &buf);
+            }
+        } else {
+            let Value::Array(items) = value else {
+                return Err(Error::Invalid(format!(
+                    "{key}: expected an array, found {value}"
+                )));
+            };
+            for item in items {
+                field_value(d, field, item, out, depth)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Appends the wire encoding of a `google.protobuf.Any` encoded as `fields`,
+/// which hold the packed message under `value` if it has a special encoding.
+fn any(
+    d: &Descriptors,
+    fields: &Map<String, Value>,
+    out: &mut Vec<u8>,
+    depth: usize,
+) -> Result<()> {
+    let Some(type_url) = fields.get("@type") else {
+        if fields.is_empty() {
+            return Ok(());
+        }
+        return Err(Error::Invalid("Any without @type".into()));
+    };
+    let Value::String(type_url) = type_url else {
+        return Err(Error::Invalid(format!("Any @type: {type_url}")));
+    };
+    let name = type_url_name(type_url);
+    if !d.contains(name) {
+        return Err(Error::UnknownType(type_url.clone()));
+    }
+    let mut value = Vec::new();
+    if name == ANY || wkt::is_well_known(name) {
+        let packed = fields
+            .get("value")
+            .ok_or_else(|| Error::Invalid(format!("Any of {type_url} without value")))?;
+        message(d, name, packed, &mut value, depth + 1)?;
+    } else {
+        let mut packed = fields.clone();
+        packed.remove("@type");
+        message(d, name, &Value::Object(packed), &mut value, depth + 1)?;
+    }
+    put_key(out, 1, 2);
+    put_bytes(out, type_url.as_bytes());
+    put_key(out, 2, 2);
+    put_bytes(out, &value);
+    Ok(())
+}
+
+/// Appends a single value of `field`, with its key, to `out`.
+fn field_value(
+    d: &Descriptors,
+    field: &FieldDescriptorProto,
+    value: &Value,
+    out: &mut Vec<u8>,
+    depth: usize,
+) -> Result<()> {
+    use FieldDescriptorProto_Type::*;
+    let number = field.get_number();
+    let invalid = || Error::Invalid(format!("value for field {}: {value}", field.get_name()));
+    match field.get_field_type() {
+        TYPE_MESSAGE => {
+            let mut buf = Vec::new();
+            message(d, field_type_name(field), value, &mut buf, depth + 1)?;
+            put_key(out, number, 2);
+            put_bytes(out, &buf);
+        }
+        TYPE_STRING => {
+            put_key(out, number, 2);
+            put_bytes(out, value.as_str().ok_or_else(invalid)?.as_bytes());
+        }
+        TYPE_BYTES => {
+            let text = value.as_str().ok_or_else(invalid)?;
+            put_key(out, number, 2);
+            put_bytes(out, &decode_base64(text).ok_or_else(invalid)?);
+        }
+        TYPE_BOOL => {
+            let b = match value {
+                Value::Bool(b) => *b,
+                Value::String(s) if s == "true" => true,
+                Value::String(s) if s == "false" => false,
+                _ => return Err(invalid()),
+            };
+            put_key(out, number, 0);
+            put_varint(out, u64::from(b));
+        }
+        TYPE_ENUM => {
+            let n = match value {
+                Value::String(name) => d
+                    .enum_type(field_type_name(field))
+                    .and_then(|e| e.get_value().iter().find(|v| v.get_name() == name))
+                    .map(|v| v.get_number())
+                    .ok_or_else(invalid)?,
+                _ => integer(value, i32::MIN.into(), i32::MAX.into()).ok_or_else(invalid)? as i32,
+            };
+            put_key(out, number, 0);
+            put_varint(out, n as i64 as u64);
+        }
+        TYPE_INT32 | TYPE_INT64 | TYPE_UINT32 | TYPE_UINT64 | TYPE_SINT32 | TYPE_SINT64 => {
+            let (min, max) = range(field.get_field_type());
+            let n = integer(value, min, max).ok_or_else(invalid)?;
+            let n = n as i64;
+            put_key(out, number, 0);
+            match field.get_field_type() {
+                TYPE_SINT32 | TYPE_SINT64 => put_varint(out, ((n << 1) ^ (n >> 63)) as u64),
+                _ => put_varint(out, n as u64),
+            }
+        }
+        TYPE_FIXED32 | TYPE_SFIXED32 => {
+            let (min, max) = range(field.get_field_type());
+            let n = integer(value, min, max).ok_or_else(invalid)?;
+            put_key(out, number, 5);
+            out.extend_from_slice(&(n as u32).to_le_bytes());
+        }
+        TYPE_FIXED64 | TYPE_SFIXED64 => {
+            let (min, max) = range(field.get_field_type());
+            let n = integer(value, min, max).ok_or_else(invalid)?;
+            put_key(out, number, 1);
+            out.extend_from_slice(&(n as u64).to_le_bytes());
+        }
+        TYPE_DOUBLE => {
+            put_key(out, number, 1);
+            out.extend_from_slice(&float(value).ok_or_else(invalid)?.to_le_bytes());
+        }
+        TYPE_FLOAT => {
+            put_key(out, number, 5);
+            out.extend_from_slice(&(float(value).ok_or_else(invalid)? as f32).to_le_bytes());
+        }
+        TYPE_GROUP => return Err(invalid()),
+    }
+    Ok(())
+}
+
+/// Returns the range of values of an integer field type.
+fn range(field_type: FieldDescriptorProto_Type) -> (i128, i128) {
+    use FieldDescriptorProto_Type::*;
+    match field_type {
+        TYPE_INT32 | TYPE_SINT32 | TYPE_SFIXED32 => (i32::MIN.into(), i32::MAX.into()),
+        TYPE_UINT32 | TYPE_FIXED32 => (0, u32::MAX.into()),
+        TYPE_UINT64 | TYPE_FIXED64 => (0, u64::MAX.into()),
+        _ => (i64::MIN.into(), i64::MAX.into()),
+    }
+}
+
+/// Parses an integer from a JSON number or string within `min..=max`.
+pub(crate) fn integer(value: &Value, min: i128, max: i128) -> Option<i128> {
+    let n = match value {
+        Value::Number(n) => match (n.as_i64(), n.as_u64(), n.as_f64()) {
+            (Some(n), _, _) => n.into(),
+            (_, Some(n), _) => n.into(),
+            (_, _, Some(f)) if f.fract() == 0.0 && f.abs() < 1e38 => f as i128,
+            _ => return None,
+        },
+        Value::String(s) => s.parse().ok()?,
+        _ => return None,
+    };
+    (min..=max).contains(&n).then_some(n)
+}
+
+/// Parses a floating point number from a JSON number or string.
+pub(crate) fn float(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => match s.as_str() {
+            "NaN" => Some(f64::NAN),
+            "Infinity" => Some(f64::INFINITY),
+            "-Infinity" => Some(f64::NEG_INFINITY),
+            s => s.parse().ok(),
+        },
+        _ => None,
+    }
+}
+
+/// Decodes standard or URL-safe base64, with or without padding.
+pub(crate) fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    use base64::engine::general_purpose::{STANDARD_NO_PAD, URL_SAFE_NO_PAD};
+    let text = text.trim_end_matches('=');
+    STANDARD_NO_PAD
+        .decode(text)
+        .or_else(|_| URL_SAFE_NO_PAD.decode(text))
+        .ok()
+}
+
+/// Returns the JSON name of `field`.
+fn json_name(field: &FieldDescriptorProto) -> String {
+    if field.has_json_name() {
+        return field.get_json_name().to_string();
+    }
+    let mut name = String::new();
+    let mut upper = false;
+    for c in field.get_name().chars() {
+        match c {
+            '_' => upper = true,
+            c if upper => {
+                name.push(c.to_ascii_uppercase());
+                upper = false;
+            }
+            c => name.push(c),
+        }
+    }
+    name
+}
+
+fn put_key(out: &mut Vec<u8>, number: i32, wire_type: u64) {
+    put_varint(out, (number as u64) << 3 | wire_type);
+}
+
+fn put_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    put_varint(out, bytes.len() as u64);
+    out.extend_from_slice(bytes);
+}
+
+fn put_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
@@ -0,0 +1,419 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion between protocol buffer messages and their canonical JSON
+//! encoding, compatible with the Go `protojson` package.
+//!
+//! The rust-protobuf runtime has no JSON support, so messages are transcoded
+//! from and to their wire encoding using the descriptors of their files,
+//! which must first be added to a [`Descriptors`] set. Fields are written
+//! with their original proto names, as with `protojson.MarshalOptions{
+//! UseProtoNames: true }`, and parsed from either their proto or JSON names.
+//!
+//! `google.protobuf.Any` messages are written with an `@type` key and the
+//! fields of the packed message, whose type must also be known. The other
+//! well-known types have their special encodings, e.g. RFC 3339 strings for
+//! `Timestamp` and plain JSON values for `Struct`, and need no descriptors.
+
+mod decode;
+mod encode;
+mod wkt;
+
+use protobuf::descriptor::{
+    DescriptorProto, EnumDescriptorProto, FieldDescriptorProto, FieldDescriptorProto_Type,
+    FileDescriptorProto,
+};
+use protobuf::Message;
+use serde_json::Value;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Errors produced while converting messages to or from JSON.
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("protobuf error: {0}")]
+    Proto(#[from] protobuf::ProtobufError),
+    #[error("unknown message type: {0}")]
+    UnknownType(String),
+    #[error("invalid {0}")]
+    Invalid(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The full name of `google.protobuf.Any`, which has a special encoding.
+const ANY: &str = "google.protobuf.Any";
+
+/// The maximum nesting of messages that will be converted.
+const MAX_DEPTH: usize = 100;
+
+/// A message type known to a [`Descriptors`] set.
+#[derive(Clone)]
+struct MessageType {
+    proto: DescriptorProto,
+    proto3: bool,
+}
+
+/// A set of message and enum types, keyed by their full names.
+#[derive(Clone, Default)]
+pub struct Descriptors {
+    messages: HashMap<String, MessageType>,
+    enums: HashMap<String, EnumDescriptorProto>,
+}
+
+impl Descriptors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the types defined in `file`. The types of its dependencies must
+    /// be added separately.
+    pub fn add_file(&mut self, file: &FileDescriptorProto) {
+        let proto3 = file.get_syntax() == "proto3";
+        let package = file.get_package();
+        for message in file.get_message_type() {
+            self.add_message(package, message, proto3);
+        }
+        for enum_type in file.get_enum_type() {
+            self.enums
+                .insert(full_name(package, enum_type.get_name()), enum_type.clone());
+        }
+    }
+
+    /// Returns `self` after adding the types defined in `file`.
+    pub fn with_file(mut self, file: &FileDescriptorProto) -> Self {
+        self.add_file(file);
+        self
+    }
+
+    fn add_message(&mut self, scope: &str, message: &DescriptorProto, proto3: bool) {
+        let name = full_name(scope, message.get_name());
+        for nested in message.get_nested_type() {
+            self.add_message(&name, nested, proto3);
+        }
+        for enum_type in message.get_enum_type() {
+            self.enums
+                .insert(full_name(&name, enum_type.get_name()), enum_type.clone());
+        }
+        self.messages.insert(
+            name,
+            MessageType {
+                proto: message.clone(),
+                proto3,
+            },
+        );
+    }
+
+    /// Reports whether the message type with the full name `type_name` is
+    /// known.
+    pub fn contains(&self, type_name: &str) -> bool {
+        type_name == ANY || wkt::is_well_known(type_name) || self.messages.contains_key(type_name)
+    }
+
+    /// Returns the JSON encoding of `msg`.
+    pub fn to_json<M: Message>(&self, msg: &M) -> Result<Value> {
+        self.bytes_to_json(M::descriptor_static().full_name(), &msg.write_to_bytes()?)
+    }
+
+    /// Parses a message of type `M` from its JSON encoding.
+    pub fn from_json<M: Message>(&self, json: &Value) -> Result<M> {
+        let bytes = self.json_to_bytes(M::descriptor_static().full_name(), json)?;
+        Ok(M::parse_from_bytes(&bytes)?)
+    }
+
+    /// Returns the JSON encoding of a message of type `type_name`, given its
+    /// wire encoding.
+    pub fn bytes_to_json(&self, type_name: &str, data: &[u8]) -> Result<Value> {
+        decode::message(self, type_name, data, 0)
+    }
+
+    /// Returns the wire encoding of a message of type `type_name`, given its
+    /// JSON encoding.
+    pub fn json_to_bytes(&self, type_name: &str, json: &Value) -> Result<Vec<u8>> {
+        let mut out = Vec::new();
+        encode::message(self, type_name, json, &mut out, 0)?;
+        Ok(out)
+    }
+
+    fn message(&self, type_name: &str) -> Result<&MessageType> {
+        self.messages
+            .get(type_name)
+            .ok_or_else(|| Error::UnknownType(type_name.to_string()))
+    }
+
+    /// Returns the map entry type of `field`, if it is a map.
+    fn map_entry(&self, field: &FieldDescriptorProto) -> Option<&MessageType> {
+        if field.get_field_type() != FieldDescriptorProto_Type::TYPE_MESSAGE {
+            return None;
+        }
+        self.message(field_type_name(field))
+            .ok()
+            .filter(|m| m.proto.get_options().get_map_entry())
+    }
+
+    fn enum_type(&self, type_name: &str) -> Option<&EnumDescriptorProto> {
+        self.enums.get(type_name)
+    }
+}
+
+fn full_name(scope: &str, name: &str) -> String {
+    if scope.is_empty() {
+        name.to_string()
+    } else {
+        format!("{scope}.{name}")
+    }
+}
+
+/// Returns the full name of the message or enum type of `field`.
+fn field_type_name(field: &FieldDescriptorProto) -> &str {
+    field.get_type_name().trim_start_matches('.')
+}
+
+/// Returns the message type named by an `Any` type URL.
+fn type_url_name(type_url: &str) -> &str {
+    type_url.rsplit('/').next().unwrap_or(type_url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use analysis_rust_proto::{
+        BuildMetadata, CompilationUnit, CompilationUnit_FileInput, KzipInfo, KzipInfo_CorpusInfo,
+        KzipInfo_CorpusInfo_CUInfo,
+    };
+    use serde_json::json;
+    use storage_rust_proto::Entry;
+
+    fn descriptors() -> Descriptors {
+        Descriptors::new()
+            .with_file(analysis_rust_proto::storage::file_descriptor_proto())
+            .with_file(analysis_rust_proto::analysis::file_descriptor_proto())
+    }
+
+    #[test]
+    fn entry_round_trip() {
+        let mut entry = Entry::new();
+        entry.mut_source().set_signature("sig".into());
+        entry.mut_source().set_corpus("kythe".into());
+        entry.set_fact_name("/kythe/text".into());
+        entry.set_fact_value(b"hello\xff".to_vec());
+        let d = Descriptors::new().with_file(storage_rust_proto::storage::file_descriptor_proto());
+        let json = d.to_json(&entry).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "source": {"signature": "sig", "corpus": "kythe"},
+                "fact_name": "/kythe/text",
+                "fact_value": "aGVsbG//",
+            })
+        );
+        assert_eq!(d.from_json::<Entry>(&json).unwrap(), entry);
+        // Fields are encoded in field number order, not JSON key order.
+        assert_eq!(
+            d.json_to_bytes("kythe.proto.Entry", &json).unwrap(),
+            entry.write_to_bytes().unwrap()
+        );
+        // JSON names are accepted too.
+        let camel = json!({"factName": "/kythe/text", "factValue": "aGVsbG//"});
+        assert_eq!(
+            d.from_json::<Entry>(&camel).unwrap().get_fact_value(),
+            b"hello\xff"
+        );
+    }
+
+    #[test]
+    fn compilation_unit_round_trip() {
+        let d = descriptors();
+        let mut unit = CompilationUnit::new();
+        unit.mut_v_name().set_language("go".into());
+        let mut input = CompilationUnit_FileInput::new();
+        input.mut_info().set_path("a.go".into());
+        input.mut_info().set_digest("abc".into());
+        unit.mut_required_input().push(input);
+        unit.mut_argument().push("-v".into());
+        unit.set_has_compile_errors(true);
+        let mut details = protobuf::well_known_types::Any::new();
+        details.set_type_url("kythe.io/proto/kythe.proto.VName".into());
+        details.set_value(unit.get_v_name().write_to_bytes().unwrap());
+        unit.mut_details().push(details);
+
+        let json = d.to_json(&unit).unwrap();
+        assert_eq!(
+            json["details"],
+            json!([{"@type": "kythe.io/proto/kythe.proto.VName", "language": "go"}])
+        );
+        assert_eq!(json["has_compile_errors"], json!(true));
+        assert_eq!(d.from_json::<CompilationUnit>(&json).unwrap(), unit);
+
+        let mut info = KzipInfo::new();
+        info.set_size(1 << 40);
+        let mut cu_info = KzipInfo_CorpusInfo_CUInfo::new();
+        cu_info.mut_java_version_count().insert(0, 0);
+        let mut corpus = KzipInfo_CorpusInfo::new();
+        corpus.mut_language_cu_info().insert("java".into(), cu_info);
+        info.mut_corpora().insert("kythe".into(), corpus);
+        let json = d.to_json(&info).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "corpora": {"kythe": {"language_cu_info": {"java": {"java_version_count": {"0": 0}}}}},
+                "size": "1099511627776"
+            })
+        );
+        assert_eq!(d.from_json::<KzipInfo>(&json).unwrap(), info);
+    }
+
+    #[test]
+    fn well_known_types() {
+        use protobuf::well_known_types::{
+            BytesValue, DoubleValue, Duration, Empty, FieldMask, Int64Value, Struct, Timestamp,
+            UInt32Value, Value as Val,
+        };
+        let d = descriptors();
+
+        // The expected encodings are those of Go's protojson.
+        let mut metadata = BuildMetadata::new();
+        metadata.mut_commit_timestamp().set_seconds(1_600_000_000);
+        let json = json!({"commit_timestamp": "2020-09-13T12:26:40Z"});
+        assert_eq!(d.to_json(&metadata).unwrap(), json);
+        assert_eq!(d.from_json::<BuildMetadata>(&json).unwrap(), metadata);
+
+        let timestamp = |seconds, nanos| {
+            let mut t = Timestamp::new();
+            t.set_seconds(seconds);
+            t.set_nanos(nanos);
+            t
+        };
+        for (t, text) in [
+            (timestamp(0, 1_000), "1970-01-01T00:00:00.000001Z"),
+            (timestamp(-1, 500_000_000), "1969-12-31T23:59:59.500Z"),
+            (timestamp(951_782_400, 0), "2000-02-29T00:00:00Z"),
+            (timestamp(-62_135_596_800, 0), "0001-01-01T00:00:00Z"),
+            (
+                timestamp(253_402_300_799, 1),
+                "9999-12-31T23:59:59.000000001Z",
+            ),
+        ] {
+            assert_eq!(d.to_json(&t).unwrap(), json!(text));
+            assert_eq!(d.from_json::<Timestamp>(&json!(text)).unwrap(), t);
+        }
+        assert_eq!(
+            d.from_json::<Timestamp>(&json!("2020-09-13T14:26:40.5+02:00"))
+                .unwrap(),
+            timestamp(1_600_000_000, 500_000_000)
+        );
+
+        let duration = |seconds, nanos| {
+            let mut d = Duration::new();
+            d.set_seconds(seconds);
+            d.set_nanos(nanos);
+            d
+        };
+        for (duration, text) in [
+            (duration(3, 0), "3s"),
+            (duration(0, 0), "0s"),
+            (duration(-1, -500_000_000), "-1.500s"),
+            (duration(0, -1), "-0.000000001s"),
+            (duration(1, 20_000), "1.000020s"),
+        ] {
+            assert_eq!(d.to_json(&duration).unwrap(), json!(text));
+            assert_eq!(d.from_json::<Duration>(&json!(text)).unwrap(), duration);
+        }
+
+        let json = json!({"a": [1.5, "x", null, true, {"b": {}}, []], "c": null});
+        let s = d.from_json::<Struct>(&json).unwrap();
+        assert_eq!(s.get_fields().len(), 2);
+        assert_eq!(d.to_json(&s).unwrap(), json);
+        assert_eq!(
+            d.to_json(&d.from_json::<Val>(&json!(null)).unwrap())
+                .unwrap(),
+            json!(null)
+        );
+
+        let mut mask = FieldMask::new();
+        mask.mut_paths().push("foo_bar".into());
+        mask.mut_paths().push("baz.qux_quux".into());
+        assert_eq!(d.to_json(&mask).unwrap(), json!("fooBar,baz.quxQuux"));
+        assert_eq!(
+            d.from_json::<FieldMask>(&json!("fooBar,baz.quxQuux"))
+                .unwrap(),
+            mask
+        );
+
+        let mut int64 = Int64Value::new();
+        int64.set_value(-5);
+        assert_eq!(d.to_json(&int64).unwrap(), json!("-5"));
+        assert_eq!(d.from_json::<Int64Value>(&json!(-5)).unwrap(), int64);
+        let mut uint32 = UInt32Value::new();
+        uint32.set_value(7);
+        assert_eq!(d.to_json(&uint32).unwrap(), json!(7));
+        let mut double = DoubleValue::new();
+        double.set_value(f64::NEG_INFINITY);
+        assert_eq!(d.to_json(&double).unwrap(), json!("-Infinity"));
+        let mut bytes = BytesValue::new();
+        bytes.set_value(b"hi".to_vec());
+        assert_eq!(d.to_json(&bytes).unwrap(), json!("aGk="));
+        assert_eq!(d.from_json::<BytesValue>(&json!("aGk=")).unwrap(), bytes);
+        assert_eq!(d.to_json(&Empty::new()).unwrap(), json!({}));
+        assert_eq!(d.from_json::<Empty>(&json!({})).unwrap(), Empty::new());
+
+        // Packed well-known types are held under "value".
+        let mut unit = CompilationUnit::new();
+        let mut details = protobuf::well_known_types::Any::new();
+        details.set_type_url("type.googleapis.com/google.protobuf.Timestamp".into());
+        details.set_value(timestamp(1_600_000_000, 0).write_to_bytes().unwrap());
+        unit.mut_details().push(details);
+        let json = d.to_json(&unit).unwrap();
+        assert_eq!(
+            json["details"],
+            json!([{
+                "@type": "type.googleapis.com/google.protobuf.Timestamp",
+                "value": "2020-09-13T12:26:40Z"
+            }])
+        );
+        assert_eq!(d.from_json::<CompilationUnit>(&json).unwrap(), unit);
+
+        for text in [
+            "2020-02-30T00:00:00Z",
+            "2020-09-13 12:26:40Z",
+            "2020-09-13T12:26:40",
+        ] {
+            assert!(d.from_json::<Timestamp>(&json!(text)).is_err(), "{text}");
+        }
+        assert!(d.to_json(&timestamp(0, -1)).is_err());
+        for text in ["1", "1.5", "s", "--1s", "1.0000000001s"] {
+            assert!(d.from_json::<Duration>(&json!(text)).is_err(), "{text}");
+        }
+        assert!(d.to_json(&duration(1, -1)).is_err());
+        assert!(d.to_json(&duration(i64::MIN, 0)).is_err());
+        assert!(d.to_json(&Val::new()).is_err());
+        mask.mut_paths().push("Foo".into());
+        assert!(d.to_json(&mask).is_err());
+    }
+
+    #[test]
+    fn errors() {
+        let d = descriptors();
+        let mut unit = CompilationUnit::new();
+        let mut details = protobuf::well_known_types::Any::new();
+        details.set_type_url("kythe.io/proto/unknown.Type".into());
+        unit.mut_details().push(details);
+        assert!(matches!(d.to_json(&unit), Err(Error::UnknownType(_))));
+        assert!(d.from_json::<Entry>(&json!({"fact_name": 1})).is_err());
+        assert!(d.from_json::<Entry>(&json!({"no_such_field": ""})).is_err());
+        assert!(d.from_json::<Entry>(&json!([])).is_err());
+    }
+}
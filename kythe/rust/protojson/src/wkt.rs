@@ -0,0 +1,509 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The special JSON encodings of the well-known types other than `Any`,
+//! which are converted through their rust-protobuf messages rather than
+//! descriptors.
+
+use crate::decode::float as float_json;
+use crate::encode::{decode_base64, float, integer};
+use crate::{Error, Result, MAX_DEPTH};
+use base64::Engine;
+use protobuf::well_known_types::{
+    BoolValue, BytesValue, DoubleValue, Duration, FieldMask, FloatValue, Int32Value, Int64Value,
+    ListValue, NullValue, StringValue, Struct, Timestamp, UInt32Value, UInt64Value, Value as Val,
+    Value_oneof_kind as Kind,
+};
+use protobuf::Message;
+use serde_json::{Map, Value};
+
+/// The full name of `google.protobuf.Value`, which may be encoded as `null`.
+pub(crate) const VALUE: &str = "google.protobuf.Value";
+
+/// The full name of `google.protobuf.NullValue`, which is encoded as `null`.
+pub(crate) const NULL_VALUE: &str = "google.protobuf.NullValue";
+
+/// The well-known message types with a special JSON encoding, besides `Any`.
+const TYPES: &[&str] = &[
+    "google.protobuf.BoolValue",
+    "google.protobuf.BytesValue",
+    "google.protobuf.DoubleValue",
+    "google.protobuf.Duration",
+    "google.protobuf.Empty",
+    "google.protobuf.FieldMask",
+    "google.protobuf.FloatValue",
+    "google.protobuf.Int32Value",
+    "google.protobuf.Int64Value",
+    "google.protobuf.ListValue",
+    "google.protobuf.StringValue",
+    "google.protobuf.Struct",
+    "google.protobuf.Timestamp",
+    "google.protobuf.UInt32Value",
+    "google.protobuf.UInt64Value",
+    VALUE,
+];
+
+/// The range of seconds of a `Timestamp`, from 0001-01-01T00:00:00Z to
+/// 9999-12-31T23:59:59Z.
+const MIN_TIMESTAMP: i64 = -62_135_596_800;
+const MAX_TIMESTAMP: i64 = 253_402_300_799;
+
+/// The range of seconds of a `Duration`, roughly 10,000 years.
+const MAX_DURATION: i64 = 315_576_000_000;
+
+const NANOS_PER_SECOND: i64 = 1_000_000_000;
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Reports whether the message type `type_name` has a special encoding.
+pub(crate) fn is_well_known(type_name: &str) -> bool {
+    TYPES.contains(&type_name)
+}
+
+/// Returns the JSON encoding of the well-known message of type `type_name`
+/// encoded in `data`.
+pub(crate) fn to_json(type_name: &str, data: &[u8], depth: usize) -> Result<Value> {
+    let name = type_name.trim_start_matches("google.protobuf.");
+    Ok(match name {
+        "BoolValue" => BoolValue::parse_from_bytes(data)?.get_value().into(),
+        "BytesValue" => base64::engine::general_purpose::STANDARD
+            .encode(BytesValue::parse_from_bytes(data)?.get_value())
+            .into(),
+        "DoubleValue" => float_json(DoubleValue::parse_from_bytes(data)?.get_value()),
+        "FloatValue" => float_json(FloatValue::parse_from_bytes(data)?.get_value().into()),
+        "Int32Value" => Int32Value::parse_from_bytes(data)?.get_value().into(),
+        "Int64Value" => Int64Value::parse_from_bytes(data)?
+            .get_value()
+            .to_string()
+            .into(),
+        "UInt32Value" => UInt32Value::parse_from_bytes(data)?.get_value().into(),
+        "UInt64Value" => UInt64Value::parse_from_bytes(data)?
+            .get_value()
+            .to_string()
+            .into(),
+        "StringValue" => StringValue::parse_from_bytes(data)?.get_value().into(),
+        "Duration" => format_duration(&Duration::parse_from_bytes(data)?)?.into(),
+        "Timestamp" => format_timestamp(&Timestamp::parse_from_bytes(data)?)?.into(),
+        "FieldMask" => format_field_mask(&FieldMask::parse_from_bytes(data)?)?.into(),
+        "Struct" => struct_to_json(&Struct::parse_from_bytes(data)?, depth)?,
+        "ListValue" => list_to_json(&ListValue::parse_from_bytes(data)?, depth)?,
+        "Value" => value_to_json(&Val::parse_from_bytes(data)?, depth)?,
+        _ => Value::Object(Map::new()),
+    })
+}
+
+/// Returns the wire encoding of the well-known message of type `type_name`
+/// encoded as `json`.
+pub(crate) fn from_json(type_name: &str, json: &Value, depth: usize) -> Result<Vec<u8>> {
+    let name = type_name.trim_start_matches("google.protobuf.");
+    let invalid = || Error::Invalid(format!("{type_name}: {json}"));
+    let int = |min: i64, max: i64| {
+        integer(json, min.into(), max.into())
+            .map(|n| n as i64)
+            .ok_or_else(invalid)
+    };
+    let msg: Box<dyn Message> = match name {
+        "BoolValue" => {
+            let mut m = BoolValue::new();
+            m.set_value(json.as_bool().ok_or_else(invalid)?);
+            Box::new(m)
+        }
+        "BytesValue" => {
+            let mut m = BytesValue::new();
+            m.set_value(decode_base64(json.as_str().ok_or_else(invalid)?).ok_or_else(invalid)?);
+            Box::new(m)
+        }
+        "DoubleValue" => {
+            let mut m = DoubleValue::new();
+            m.set_value(float(json).ok_or_else(invalid)?);
+            Box::new(m)
+        }
+        "FloatValue" => {
+            let mut m = FloatValue::new();
+            m.set_value(float(json).ok_or_else(invalid)? as f32);
+            Box::new(m)
+        }
+        "Int32Value" => {
+            let mut m = Int32Value::new();
+            m.set_value(int(i32::MIN.into(), i32::MAX.into())? as i32);
+            Box::new(m)
+        }
+        "Int64Value" => {
+            let mut m = Int64Value::new();
+            m.set_value(int(i64::MIN, i64::MAX)?);
+            Box::new(m)
+        }
+        "UInt32Value" => {
+            let mut m = UInt32Value::new();
+            m.set_value(int(0, u32::MAX.into())? as u32);
+            Box::new(m)
+        }
+        "UInt64Value" => {
+            let mut m = UInt64Value::new();
+            m.set_value(integer(json, 0, u64::MAX.into()).ok_or_else(invalid)? as u64);
+            Box::new(m)
+        }
+        "StringValue" => {
+            let mut m = StringValue::new();
+            m.set_value(json.as_str().ok_or_else(invalid)?.into());
+            Box::new(m)
+        }
+        "Duration" => Box::new(parse_duration(json.as_str().ok_or_else(invalid)?)?),
+        "Timestamp" => Box::new(parse_timestamp(json.as_str().ok_or_else(invalid)?)?),
+        "FieldMask" => Box::new(parse_field_mask(json.as_str().ok_or_else(invalid)?)),
+        "Struct" => Box::new(json_to_struct(json, depth)?),
+        "ListValue" => Box::new(json_to_list(json, depth)?),
+        "Value" => Box::new(json_to_value(json, depth)?),
+        _ => {
+            if !json.as_object().is_some_and(Map::is_empty) {
+                return Err(invalid());
+            }
+            return Ok(Vec::new());
+        }
+    };
+    Ok(msg.write_to_bytes()?)
+}
+
+fn check_depth(depth: usize) -> Result<()> {
+    if depth > MAX_DEPTH {
+        return Err(Error::Invalid(format!(
+            "message nesting deeper than {MAX_DEPTH}"
+        )));
+    }
+    Ok(())
+}
+
+fn struct_to_json(s: &Struct, depth: usize) -> Result<Value> {
+    check_depth(depth)?;
+    let mut out = Map::new();
+    for (key, value) in s.get_fields() {
+        out.insert(key.clone(), value_to_json(value, depth + 1)?);
+    }
+    Ok(Value::Object(out))
+}
+
+fn list_to_json(list: &ListValue, depth: usize) -> Result<Value> {
+    check_depth(depth)?;
+    list.get_values()
+        .iter()
+        .map(|value| value_to_json(value, depth + 1))
+        .collect()
+}
+
+fn value_to_json(value: &Val, depth: usize) -> Result<Value> {
+    check_depth(depth)?;
+    Ok(match &value.kind {
+        None => {
+            return Err(Error::Invalid(
+                "google.protobuf.Value without a kind".into(),
+            ))
+        }
+        Some(Kind::null_value(_)) => Value::Null,
+        Some(Kind::number_value(n)) => {
+            if !n.is_finite() {
+                return Err(Error::Invalid(format!("google.protobuf.Value number {n}")));
+            }
+            float_json(*n)
+        }
+        Some(Kind::string_value(s)) => s.as_str().into(),
+        Some(Kind::bool_value(b)) => (*b).into(),
+        Some(Kind::struct_value(s)) => struct_to_json(s, depth + 1)?,
+        Some(Kind::list_value(list)) => list_to_json(list, depth + 1)?,
+    })
+}
+
+fn json_to_struct(json: &Value, depth: usize) -> Result<Struct> {
+    check_depth(depth)?;
+    let Value::Object(fields) = json else {
+        return Err(Error::Invalid(format!(
+            "google.protobuf.Struct: expected an object, found {json}"
+        )));
+    };
+    let mut s = Struct::new();
+    for (key, value) in fields {
+        s.mut_fields()
+            .insert(key.clone(), json_to_value(value, depth + 1)?);
+    }
+    Ok(s)
+}
+
+fn json_to_list(json: &Value, depth: usize) -> Result<ListValue> {
+    check_depth(depth)?;
+    let Value::Array(items) = json else {
+        return Err(Error::Invalid(format!(
+            "google.protobuf.ListValue: expected an array, found {json}"
+        )));
+    };
+    let mut list = ListValue::new();
+    for item in items {
+        list.mut_values().push(json_to_value(item, depth + 1)?);
+    }
+    Ok(list)
+}
+
+fn json_to_value(json: &Value, depth: usize) -> Result<Val> {
+    check_depth(depth)?;
+    let mut value = Val::new();
+    match json {
+        Value::Null => value.set_null_value(NullValue::NULL_VALUE),
+        Value::Bool(b) => value.set_bool_value(*b),
+        Value::Number(n) => value.set_number_value(n.as_f64().unwrap_or_default()),
+        Value::String(s) => value.set_string_value(s.clone()),
+        Value::Array(_) => value.set_list_value(json_to_list(json, depth + 1)?),
+        Value::Object(_) => value.set_struct_value(json_to_struct(json, depth + 1)?),
+    }
+    Ok(value)
+}
+
+/// Formats the fraction of a second `nanos`, if any, with 3, 6 or 9 digits.
+fn format_nanos(nanos: i64) -> String {
+    if nanos == 0 {
+        return String::new();
+    }
+    let mut text = format!(".{nanos:09}");
+    for _ in 0..2 {
+        match text.strip_suffix("000") {
+            Some(trimmed) => text.truncate(trimmed.len()),
+            None => break,
+        }
+    }
+    text
+}
+
+/// Parses a fraction of a second of up to 9 digits, without its leading `.`.
+fn parse_nanos(digits: &str) -> Option<i64> {
+    if digits.is_empty() || digits.len() > 9 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some(digits.parse::<i64>().ok()? * 10i64.pow(9 - digits.len() as u32))
+}
+
+/// Formats a `Duration` as seconds with a `s` suffix, e.g. `-1.500s`.
+fn format_duration(d: &Duration) -> Result<String> {
+    let (seconds, nanos) = (d.get_seconds(), i64::from(d.get_nanos()));
+    if !(-MAX_DURATION..=MAX_DURATION).contains(&seconds)
+        || nanos.abs() >= NANOS_PER_SECOND
+        || (seconds < 0 && nanos > 0)
+        || (seconds > 0 && nanos < 0)
+    {
+        return Err(Error::Invalid(format!(
+            "google.protobuf.Duration {seconds}s {nanos}ns"
+        )));
+    }
+    let sign = if seconds < 0 || nanos < 0 { "-" } else { "" };
+    Ok(format!(
+        "{sign}{}{}s",
+        seconds.unsigned_abs(),
+        format_nanos(nanos.abs())
+    ))
+}
+
+fn parse_duration(text: &str) -> Result<Duration> {
+    let invalid = || Error::Invalid(format!("google.protobuf.Duration {text:?}"));
+    let body = text.strip_suffix('s').ok_or_else(invalid)?;
+    let (negative, body) = match body.strip_prefix('-') {
+        Some(body) => (true, body),
+        None => (false, body),
+    };
+    let (whole, fraction) = match body.split_once('.') {
+        Some((whole, fraction)) => (whole, parse_nanos(fraction).ok_or_else(invalid)?),
+        None => (body, 0),
+    };
+    if whole.is_empty() || !whole.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let seconds: i64 = whole.parse().map_err(|_| invalid())?;
+    if seconds > MAX_DURATION {
+        return Err(invalid());
+    }
+    let sign = if negative { -1 } else { 1 };
+    let mut d = Duration::new();
+    d.set_seconds(sign * seconds);
+    d.set_nanos((sign * fraction) as i32);
+    Ok(d)
+}
+
+/// Formats a `Timestamp` in RFC 3339 form in UTC, e.g.
+/// `2020-09-13T12:26:40.500Z`.
+fn format_timestamp(t: &Timestamp) -> Result<String> {
+    let (seconds, nanos) = (t.get_seconds(), i64::from(t.get_nanos()));
+    if !(MIN_TIMESTAMP..=MAX_TIMESTAMP).contains(&seconds)
+        || !(0..NANOS_PER_SECOND).contains(&nanos)
+    {
+        return Err(Error::Invalid(format!(
+            "google.protobuf.Timestamp {seconds}s {nanos}ns"
+        )));
+    }
+    let (days, time) = (
+        seconds.div_euclid(SECONDS_PER_DAY),
+        seconds.rem_euclid(SECONDS_PER_DAY),
+    );
+    let (year, month, day) = civil_from_days(days);
+    Ok(format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}{}Z",
+        time / 3600,
+        time / 60 % 60,
+        time % 60,
+        format_nanos(nanos)
+    ))
+}
+
+/// Parses an RFC 3339 timestamp with a `Z` or numeric UTC offset.
+fn parse_timestamp(text: &str) -> Result<Timestamp> {
+    let invalid = || Error::Invalid(format!("google.protobuf.Timestamp {text:?}"));
+    let b = text.as_bytes();
+    let number = |range: std::ops::Range<usize>| -> Result<i64> {
+        let digits = text.get(range).ok_or_else(invalid)?;
+        if !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        digits.parse().map_err(|_| invalid())
+    };
+    if b.len() < 20
+        || b[4] != b'-'
+        || b[7] != b'-'
+        || !matches!(b[10], b'T' | b't')
+        || b[13] != b':'
+        || b[16] != b':'
+    {
+        return Err(invalid());
+    }
+    let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+    let (hour, minute, second) = (number(11..13)?, number(14..16)?, number(17..19)?);
+    if !(1..=12).contains(&month)
+        || day < 1
+        || day > days_in_month(year, month)
+        || hour > 23
+        || minute > 59
+        || second > 59
+    {
+        return Err(invalid());
+    }
+    let mut rest = &text[19..];
+    let mut nanos = 0;
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let end = fraction
+            .find(|c: char| !c.is_ascii_digit())
+            .ok_or_else(invalid)?;
+        nanos = parse_nanos(&fraction[..end]).ok_or_else(invalid)?;
+        rest = &fraction[end..];
+    }
+    let offset = match rest.as_bytes() {
+        [b'Z' | b'z'] => 0,
+        [sign @ (b'+' | b'-'), h1, h2, b':', m1, m2] => {
+            let digits = [*h1, *h2, *m1, *m2];
+            if !digits.iter().all(u8::is_ascii_digit) {
+                return Err(invalid());
+            }
+            let [h1, h2, m1, m2] = digits.map(|d| i64::from(d - b'0'));
+            let (hours, minutes) = (h1 * 10 + h2, m1 * 10 + m2);
+            if hours > 23 || minutes > 59 {
+                return Err(invalid());
+            }
+            let offset = hours * 3600 + minutes * 60;
+            if *sign == b'-' {
+                -offset
+            } else {
+                offset
+            }
+        }
+        _ => return Err(invalid()),
+    };
+    let seconds =
+        days_from_civil(year, month, day) * SECONDS_PER_DAY + hour * 3600 + minute * 60 + second
+            - offset;
+    if !(MIN_TIMESTAMP..=MAX_TIMESTAMP).contains(&seconds) {
+        return Err(invalid());
+    }
+    let mut t = Timestamp::new();
+    t.set_seconds(seconds);
+    t.set_nanos(nanos as i32);
+    Ok(t)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the number of days between 1970-01-01 and a date of the
+/// proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Returns the date of the proleptic Gregorian calendar `days` after
+/// 1970-01-01, the inverse of [`days_from_civil`].
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Formats a `FieldMask` as its comma-separated paths in lower camel case.
+fn format_field_mask(mask: &FieldMask) -> Result<String> {
+    let mut paths = Vec::with_capacity(mask.get_paths().len());
+    for path in mask.get_paths() {
+        // Paths that would not survive the round trip are rejected, as they
+        // are by Go.
+        let invalid = || Error::Invalid(format!("google.protobuf.FieldMask path {path:?}"));
+        let mut camel = String::with_capacity(path.len());
+        let mut chars = path.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                'A'..='Z' => return Err(invalid()),
+                '_' => match chars.next() {
+                    Some(c) if c.is_ascii_lowercase() => camel.push(c.to_ascii_uppercase()),
+                    _ => return Err(invalid()),
+                },
+                c => camel.push(c),
+            }
+        }
+        paths.push(camel);
+    }
+    Ok(paths.join(","))
+}
+
+fn parse_field_mask(text: &str) -> FieldMask {
+    let mut mask = FieldMask::new();
+    for path in text.split(',').filter(|p| !p.is_empty()) {
+        let mut snake = String::with_capacity(path.len());
+        for c in path.chars() {
+            if c.is_ascii_uppercase() {
+                snake.push('_');
+                snake.push(c.to_ascii_lowercase());
+            } else {
+                snake.push(c);
+            }
+        }
+        mask.mut_paths().push(snake);
+    }
+    mask
+}
@@ -0,0 +1,384 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers for `google.protobuf.Any` messages, as `kythe/go/util/ptypes`.
+//!
+//! Kythe stores extractor- and analyzer-specific data in the `details` of
+//! compilation units, required inputs and analysis results as `Any`s whose
+//! type URLs are `kythe.io/proto/` followed by the full name of a Kythe
+//! message type. [`pack`] and [`unpack`] convert between those and typed
+//! messages, and a [`Registry`] decodes them without knowing their type in
+//! advance, or converts them to and from JSON.
+
+use kythe_protojson::Descriptors;
+use protobuf::descriptor::{EnumDescriptorProto, EnumValueDescriptorProto, FileDescriptorProto};
+use protobuf::reflect::MessageDescriptor;
+use protobuf::well_known_types::{
+    Any, BoolValue, BytesValue, DoubleValue, Duration, Empty, FieldMask, FloatValue, Int32Value,
+    Int64Value, ListValue, StringValue, Struct, Timestamp, UInt32Value, UInt64Value,
+    Value as StructValue,
+};
+use protobuf::Message;
+use serde_json::Value;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Errors produced while packing or unpacking `Any` messages.
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("protobuf error: {0}")]
+    Proto(#[from] protobuf::ProtobufError),
+    #[error("JSON error: {0}")]
+    Json(#[from] kythe_protojson::Error),
+    #[error("unknown type URL: {0}")]
+    UnknownType(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The type URL prefix used for Kythe message types.
+pub const KYTHE_TYPE_URL_PREFIX: &str = "kythe.io/proto/";
+
+/// The type URL prefix used for all other message types.
+pub const DEFAULT_TYPE_URL_PREFIX: &str = "type.googleapis.com/";
+
+/// Returns the type URL of the message type with the given full name.
+pub fn type_url(full_name: &str) -> String {
+    if full_name.starts_with("kythe.") {
+        format!("{KYTHE_TYPE_URL_PREFIX}{full_name}")
+    } else {
+        format!("{DEFAULT_TYPE_URL_PREFIX}{full_name}")
+    }
+}
+
+/// Returns the full name of the message type named by `type_url`.
+pub fn type_name(type_url: &str) -> &str {
+    type_url.rsplit('/').next().unwrap_or(type_url)
+}
+
+/// Packs `msg` into an `Any`.
+pub fn pack<M: Message>(msg: &M) -> Result<Any> {
+    let mut any = Any::new();
+    any.set_type_url(type_url(M::descriptor_static().full_name()));
+    any.set_value(msg.write_to_bytes()?);
+    Ok(any)
+}
+
+/// Reports whether `any` holds a message of type `M`.
+pub fn is<M: Message>(any: &Any) -> bool {
+    type_name(any.get_type_url()) == M::descriptor_static().full_name()
+}
+
+/// Unpacks the message held by `any`, or returns `None` if it is not of type
+/// `M`.
+pub fn unpack<M: Message>(any: &Any) -> Result<Option<M>> {
+    if !is::<M>(any) {
+        return Ok(None);
+    }
+    Ok(Some(M::parse_from_bytes(any.get_value())?))
+}
+
+/// Returns the first message of type `M` among `details`, if any.
+pub fn find<M: Message>(details: &[Any]) -> Result<Option<M>> {
+    match details.iter().find(|any| is::<M>(any)) {
+        Some(any) => unpack(any),
+        None => Ok(None),
+    }
+}
+
+/// A set of message types that `Any`s may hold, keyed by their full names.
+#[derive(Clone, Default)]
+pub struct Registry {
+    types: HashMap<String, &'static MessageDescriptor>,
+    descriptors: Descriptors,
+}
+
+impl Registry {
+    /// Returns an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a registry of the well-known types other than `Any`, such as
+    /// `Timestamp`, which Kythe messages use as fields and which may also be
+    /// packed in `Any`s.
+    pub fn well_known() -> Self {
+        let mut r = Self::new()
+            .with_type::<BoolValue>()
+            .with_type::<BytesValue>()
+            .with_type::<DoubleValue>()
+            .with_type::<Duration>()
+            .with_type::<Empty>()
+            .with_type::<FieldMask>()
+            .with_type::<FloatValue>()
+            .with_type::<Int32Value>()
+            .with_type::<Int64Value>()
+            .with_type::<ListValue>()
+            .with_type::<StringValue>()
+            .with_type::<Struct>()
+            .with_type::<StructValue>()
+            .with_type::<Timestamp>()
+            .with_type::<UInt32Value>()
+            .with_type::<UInt64Value>();
+        for file in well_known_files() {
+            r.add_file(&file);
+        }
+        r
+    }
+
+    /// Returns a registry of the Kythe message types used in `details`
+    /// fields, along with the well-known types.
+    pub fn kythe() -> Self {
+        use analysis_rust_proto::{analysis, storage};
+        use java_rust_proto::java;
+        Self::well_known()
+            .with_file(analysis::file_descriptor_proto())
+            .with_file(storage::file_descriptor_proto())
+            .with_file(buildinfo_rust_proto::buildinfo::file_descriptor_proto())
+            .with_file(cxx_rust_proto::cxx::file_descriptor_proto())
+            .with_file(go_rust_proto::go::file_descriptor_proto())
+            .with_file(java::file_descriptor_proto())
+            .with_type::<analysis::BuildMetadata>()
+            .with_type::<buildinfo_rust_proto::BuildDetails>()
+            .with_type::<cxx_rust_proto::CxxCompilationUnitDetails>()
+            .with_type::<go_rust_proto::GoDetails>()
+            .with_type::<go_rust_proto::GoPackageInfo>()
+            .with_type::<java::JarDetails>()
+            .with_type::<java::JarEntryDetails>()
+            .with_type::<java::JavaDetails>()
+    }
+
+    /// Registers `M` so that `Any`s holding it can be unpacked with
+    /// [`Registry::unpack`]. The file defining `M` must be added with
+    /// [`Registry::add_file`] for JSON conversion.
+    pub fn register<M: Message>(&mut self) {
+        let descriptor = M::descriptor_static();
+        self.types
+            .insert(descriptor.full_name().to_string(), descriptor);
+    }
+
+    /// Returns `self` after registering `M`.
+    pub fn with_type<M: Message>(mut self) -> Self {
+        self.register::<M>();
+        self
+    }
+
+    /// Adds the types defined in `file`, a generated module's
+    /// `file_descriptor_proto()`, for JSON conversion.
+    pub fn add_file(&mut self, file: &FileDescriptorProto) {
+        self.descriptors.add_file(file);
+    }
+
+    /// Returns `self` after adding the types defined in `file`.
+    pub fn with_file(mut self, file: &FileDescriptorProto) -> Self {
+        self.add_file(file);
+        self
+    }
+
+    /// Reports whether `Any`s with the given type URL can be unpacked.
+    pub fn contains(&self, type_url: &str) -> bool {
+        self.types.contains_key(type_name(type_url))
+    }
+
+    /// Unpacks the message held by `any`, whose type must be registered.
+    /// Use `as_any().downcast_ref()` on the result to recover its type.
+    pub fn unpack(&self, any: &Any) -> Result<Box<dyn Message>> {
+        let descriptor = self
+            .types
+            .get(type_name(any.get_type_url()))
+            .ok_or_else(|| Error::UnknownType(any.get_type_url().into()))?;
+        let mut msg = descriptor.new_instance();
+        msg.merge_from_bytes(any.get_value())?;
+        Ok(msg)
+    }
+
+    /// Returns the JSON encoding of `any`: an object holding its type URL
+    /// under `@type` along with the fields of the message it holds.
+    pub fn to_json(&self, any: &Any) -> Result<Value> {
+        Ok(self.descriptors.to_json(any)?)
+    }
+
+    /// Parses an `Any` from its JSON encoding.
+    pub fn from_json(&self, json: &Value) -> Result<Any> {
+        Ok(self.descriptors.from_json(json)?)
+    }
+
+    /// Returns the descriptors of the files added, for converting messages
+    /// holding `Any`s to and from JSON.
+    pub fn descriptors(&self) -> &Descriptors {
+        &self.descriptors
+    }
+}
+
+/// Returns descriptors of the files defining the well-known types other than
+/// `Any`. rust-protobuf keeps the descriptors of those files private, so they
+/// are rebuilt from the descriptors of their messages.
+fn well_known_files() -> Vec<FileDescriptorProto> {
+    let file = |name: &str, messages: &[&MessageDescriptor]| {
+        let mut file = FileDescriptorProto::new();
+        file.set_name(format!("google/protobuf/{name}.proto"));
+        file.set_package("google.protobuf".into());
+        file.set_syntax("proto3".into());
+        for m in messages {
+            file.mut_message_type().push(m.get_proto().clone());
+        }
+        file
+    };
+    let mut null = EnumValueDescriptorProto::new();
+    null.set_name("NULL_VALUE".into());
+    let mut null_value = EnumDescriptorProto::new();
+    null_value.set_name("NullValue".into());
+    null_value.mut_value().push(null);
+    let mut struct_file = file(
+        "struct",
+        &[
+            Struct::descriptor_static(),
+            StructValue::descriptor_static(),
+            ListValue::descriptor_static(),
+        ],
+    );
+    struct_file.mut_enum_type().push(null_value);
+    vec![
+        file("duration", &[Duration::descriptor_static()]),
+        file("empty", &[Empty::descriptor_static()]),
+        file("field_mask", &[FieldMask::descriptor_static()]),
+        struct_file,
+        file("timestamp", &[Timestamp::descriptor_static()]),
+        file(
+            "wrappers",
+            &[
+                DoubleValue::descriptor_static(),
+                FloatValue::descriptor_static(),
+                Int64Value::descriptor_static(),
+                UInt64Value::descriptor_static(),
+                Int32Value::descriptor_static(),
+                UInt32Value::descriptor_static(),
+                BoolValue::descriptor_static(),
+                StringValue::descriptor_static(),
+                BytesValue::descriptor_static(),
+            ],
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use analysis_rust_proto::analysis::{CompilationUnit, FileInfo};
+    use analysis_rust_proto::storage::VName;
+    use serde_json::json;
+
+    #[test]
+    fn packs_and_unpacks() {
+        let mut vname = VName::new();
+        vname.set_corpus("kythe".into());
+        let any = pack(&vname).unwrap();
+        assert_eq!(any.get_type_url(), "kythe.io/proto/kythe.proto.VName");
+        assert_eq!(
+            type_url("google.protobuf.Any"),
+            "type.googleapis.com/google.protobuf.Any"
+        );
+        assert!(is::<VName>(&any));
+        assert_eq!(unpack::<VName>(&any).unwrap(), Some(vname.clone()));
+        assert_eq!(unpack::<CompilationUnit>(&any).unwrap(), None);
+
+        let info = pack(&FileInfo::new()).unwrap();
+        assert_eq!(find::<VName>(&[info.clone(), any]).unwrap(), Some(vname));
+        assert_eq!(find::<VName>(&[info]).unwrap(), None);
+    }
+
+    #[test]
+    fn registry() {
+        let mut info = FileInfo::new();
+        info.set_path("a.go".into());
+        let any = pack(&info).unwrap();
+
+        let r = Registry::kythe();
+        assert!(matches!(r.unpack(&any), Err(Error::UnknownType(_))));
+        let r = r.with_type::<FileInfo>();
+        assert!(r.contains("kythe.io/proto/kythe.proto.FileInfo"));
+        let msg = r.unpack(&any).unwrap();
+        assert_eq!(msg.as_any().downcast_ref::<FileInfo>(), Some(&info));
+
+        let json = r.to_json(&any).unwrap();
+        assert_eq!(
+            json,
+            json!({"@type": "kythe.io/proto/kythe.proto.FileInfo", "path": "a.go"})
+        );
+        assert_eq!(r.from_json(&json).unwrap(), any);
+        assert!(Registry::new().to_json(&any).is_err());
+    }
+
+    #[test]
+    fn language_details() {
+        use cxx_rust_proto::{CxxCompilationUnitDetails, CxxCompilationUnitDetails_StatPath};
+
+        let mut details = CxxCompilationUnitDetails::new();
+        let mut stat = CxxCompilationUnitDetails_StatPath::new();
+        stat.set_path("/usr/include".into());
+        details.mut_stat_path().push(stat);
+        let any = pack(&details).unwrap();
+
+        let r = Registry::kythe();
+        let msg = r.unpack(&any).unwrap();
+        assert_eq!(
+            msg.as_any().downcast_ref::<CxxCompilationUnitDetails>(),
+            Some(&details)
+        );
+        let json = r.to_json(&any).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "@type": "kythe.io/proto/kythe.proto.CxxCompilationUnitDetails",
+                "stat_path": [{"path": "/usr/include"}],
+            })
+        );
+        assert_eq!(r.from_json(&json).unwrap(), any);
+        for name in ["BuildDetails", "GoDetails", "JavaDetails"] {
+            assert!(r.contains(&type_url(&format!("kythe.proto.{name}"))));
+        }
+    }
+
+    #[test]
+    fn well_known_types() {
+        use analysis_rust_proto::analysis::BuildMetadata;
+
+        let mut metadata = BuildMetadata::new();
+        metadata.mut_commit_timestamp().set_seconds(1_600_000_000);
+        let any = pack(&metadata).unwrap();
+        let r = Registry::kythe();
+        let json = r.to_json(&any).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "@type": "kythe.io/proto/kythe.proto.BuildMetadata",
+                "commit_timestamp": "2020-09-13T12:26:40Z",
+            })
+        );
+        let any = r.from_json(&json).unwrap();
+        assert_eq!(unpack::<BuildMetadata>(&any).unwrap(), Some(metadata));
+
+        let timestamp = pack(&Timestamp::new()).unwrap();
+        assert_eq!(
+            timestamp.get_type_url(),
+            "type.googleapis.com/google.protobuf.Timestamp"
+        );
+        assert!(r.unpack(&timestamp).is_ok());
+        for name in ["Timestamp", "Duration", "Struct", "Int64Value", "FieldMask"] {
+            let name = format!("google.protobuf.{name}");
+            assert!(r.contains(&type_url(&name)), "{name}");
+            assert!(r.descriptors().contains(&name), "{name}");
+        }
+    }
+}
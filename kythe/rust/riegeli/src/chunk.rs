@@ -0,0 +1,447 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The physical layout of a Riegeli file: block headers interleaved every
+//! 64 KiB with a sequence of chunks.
+//!
+//! See https://github.com/google/riegeli/blob/master/doc/riegeli_records_file_format.md.
+
+use crate::hash::hash;
+use crate::{Error, Result};
+use std::io::{Read, Seek, SeekFrom, Write};
+
+pub(crate) const BLOCK_SIZE: u64 = 1 << 16;
+pub(crate) const BLOCK_HEADER_SIZE: u64 = 24;
+pub(crate) const USABLE_BLOCK_SIZE: u64 = BLOCK_SIZE - BLOCK_HEADER_SIZE;
+pub(crate) const CHUNK_HEADER_SIZE: u64 = 40;
+
+// Chunk types.
+pub(crate) const FILE_SIGNATURE_CHUNK: u8 = b's';
+pub(crate) const FILE_METADATA_CHUNK: u8 = b'm';
+pub(crate) const SIMPLE_CHUNK: u8 = b'r';
+pub(crate) const TRANSPOSED_CHUNK: u8 = b't';
+
+/// The header found at the start of every block, locating the chunk that
+/// the block interrupts.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct BlockHeader {
+    /// The distance from the start of the interrupted chunk to the block.
+    pub(crate) previous_chunk: u64,
+    /// The distance from the block to the end of the interrupted chunk.
+    pub(crate) next_chunk: u64,
+}
+
+impl BlockHeader {
+    fn encode(&self) -> [u8; BLOCK_HEADER_SIZE as usize] {
+        let mut buf = [0u8; BLOCK_HEADER_SIZE as usize];
+        buf[8..16].copy_from_slice(&self.previous_chunk.to_le_bytes());
+        buf[16..24].copy_from_slice(&self.next_chunk.to_le_bytes());
+        let header_hash = hash(&buf[8..]);
+        buf[..8].copy_from_slice(&header_hash.to_le_bytes());
+        buf
+    }
+
+    fn decode(buf: &[u8]) -> Result<Self> {
+        check_hash(
+            "block header",
+            &buf[..8],
+            &buf[8..BLOCK_HEADER_SIZE as usize],
+        )?;
+        Ok(Self {
+            previous_chunk: le_u64(&buf[8..16]),
+            next_chunk: le_u64(&buf[16..24]),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct ChunkHeader {
+    pub(crate) data_size: u64,
+    pub(crate) data_hash: u64,
+    pub(crate) chunk_type: u8,
+    /// The number of records in the chunk; only 7 bytes are stored.
+    pub(crate) num_records: u64,
+    /// The sum of the sizes of the chunk's records.
+    pub(crate) decoded_data_size: u64,
+}
+
+impl ChunkHeader {
+    fn encode(&self) -> [u8; CHUNK_HEADER_SIZE as usize] {
+        let mut buf = [0u8; CHUNK_HEADER_SIZE as usize];
+        buf[8..16].copy_from_slice(&self.data_size.to_le_bytes());
+        buf[16..24].copy_from_slice(&self.data_hash.to_le_bytes());
+        buf[24] = self.chunk_type;
+        buf[25..32].copy_from_slice(&self.num_records.to_le_bytes()[..7]);
+        buf[32..40].copy_from_slice(&self.decoded_data_size.to_le_bytes());
+        let header_hash = hash(&buf[8..]);
+        buf[..8].copy_from_slice(&header_hash.to_le_bytes());
+        buf
+    }
+
+    fn decode(buf: &[u8; CHUNK_HEADER_SIZE as usize]) -> Result<Self> {
+        check_hash("chunk header", &buf[..8], &buf[8..])?;
+        let mut num_records = [0u8; 8];
+        num_records[..7].copy_from_slice(&buf[25..32]);
+        Ok(Self {
+            data_size: le_u64(&buf[8..16]),
+            data_hash: le_u64(&buf[16..24]),
+            chunk_type: buf[24],
+            num_records: u64::from_le_bytes(num_records),
+            decoded_data_size: le_u64(&buf[32..40]),
+        })
+    }
+
+    /// Returns the size of the padding following the chunk's data. A chunk
+    /// spans at least `num_records` bytes so that every record has a distinct
+    /// position in the file.
+    fn padding(&self) -> u64 {
+        self.num_records
+            .saturating_sub(CHUNK_HEADER_SIZE + self.data_size)
+    }
+
+    /// Returns the size the chunk occupies in a file when it starts at `pos`,
+    /// including padding and intervening block headers.
+    fn size_at(&self, pos: u64) -> u64 {
+        let size = CHUNK_HEADER_SIZE + self.data_size + self.padding();
+        size + BLOCK_HEADER_SIZE * intervening_block_headers(pos, size)
+    }
+}
+
+/// A chunk header with its data.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Chunk {
+    pub(crate) header: ChunkHeader,
+    pub(crate) data: Vec<u8>,
+}
+
+impl Chunk {
+    pub(crate) fn new(
+        chunk_type: u8,
+        data: Vec<u8>,
+        num_records: u64,
+        decoded_data_size: u64,
+    ) -> Self {
+        Self {
+            header: ChunkHeader {
+                data_size: data.len() as u64,
+                data_hash: hash(&data),
+                chunk_type,
+                num_records,
+                decoded_data_size,
+            },
+            data,
+        }
+    }
+
+    /// Returns the chunk that starts every Riegeli file.
+    pub(crate) fn signature() -> Self {
+        Self::new(FILE_SIGNATURE_CHUNK, Vec::new(), 0, 0)
+    }
+}
+
+/// Returns the number of block headers that interrupt a chunk of `size`
+/// bytes starting at `pos`.
+fn intervening_block_headers(pos: u64, size: u64) -> u64 {
+    (size + (pos + USABLE_BLOCK_SIZE - 1) % BLOCK_SIZE) / USABLE_BLOCK_SIZE
+}
+
+fn le_u64(buf: &[u8]) -> u64 {
+    u64::from_le_bytes(buf.try_into().unwrap())
+}
+
+fn check_hash(what: &str, expected: &[u8], data: &[u8]) -> Result<()> {
+    let (found, expected) = (hash(data), le_u64(expected));
+    if found != expected {
+        return Err(Error::Corrupt(format!(
+            "{what} hash mismatch: found {found:#x}; expected {expected:#x}"
+        )));
+    }
+    Ok(())
+}
+
+/// Writes chunks, interleaving a block header at every block boundary.
+pub(crate) struct BlockWriter<W: Write> {
+    w: W,
+    pos: u64,
+}
+
+impl<W: Write> BlockWriter<W> {
+    pub(crate) fn new(w: W, pos: u64) -> Self {
+        Self { w, pos }
+    }
+
+    pub(crate) fn position(&self) -> u64 {
+        self.pos
+    }
+
+    pub(crate) fn get_mut(&mut self) -> &mut W {
+        &mut self.w
+    }
+
+    pub(crate) fn into_inner(self) -> W {
+        self.w
+    }
+
+    /// Writes `chunk` at the current position.
+    pub(crate) fn write_chunk(&mut self, chunk: &Chunk) -> Result<()> {
+        let padding = chunk.header.padding() as usize;
+        let mut buf = Vec::with_capacity(CHUNK_HEADER_SIZE as usize + chunk.data.len() + padding);
+        buf.extend_from_slice(&chunk.header.encode());
+        buf.extend_from_slice(&chunk.data);
+        buf.resize(buf.len() + padding, 0);
+        self.write_interleaved(&buf)
+    }
+
+    fn write_interleaved(&mut self, chunk: &[u8]) -> Result<()> {
+        let size = chunk.len() as u64;
+        let chunk_start = self.pos;
+        let chunk_end =
+            chunk_start + size + BLOCK_HEADER_SIZE * intervening_block_headers(chunk_start, size);
+        let mut rest = chunk;
+        while !rest.is_empty() {
+            if self.pos & (BLOCK_SIZE - 1) == 0 {
+                let header = BlockHeader {
+                    previous_chunk: self.pos - chunk_start,
+                    next_chunk: chunk_end - self.pos,
+                };
+                self.w.write_all(&header.encode())?;
+                self.pos += BLOCK_HEADER_SIZE;
+            }
+            let room = (BLOCK_SIZE - self.pos % BLOCK_SIZE) as usize;
+            let (head, tail) = rest.split_at(room.min(rest.len()));
+            self.w.write_all(head)?;
+            self.pos += head.len() as u64;
+            rest = tail;
+        }
+        Ok(())
+    }
+}
+
+/// Reads the chunks of a file, skipping over block headers.
+pub(crate) struct ChunkReader<R> {
+    r: R,
+    /// The data of the current block, after its header.
+    block: Vec<u8>,
+    /// The file offset of the current block, if one is loaded.
+    block_start: Option<u64>,
+    block_header: BlockHeader,
+    /// The offset within `block` of the next byte to read.
+    offset: usize,
+    /// The file offset of the start of the last chunk read.
+    chunk_start: u64,
+}
+
+impl<R: Read> ChunkReader<R> {
+    pub(crate) fn new(r: R) -> Self {
+        Self {
+            r,
+            block: Vec::new(),
+            block_start: None,
+            block_header: BlockHeader::default(),
+            offset: 0,
+            chunk_start: 0,
+        }
+    }
+
+    /// Returns the file offset of the start of the last chunk read.
+    pub(crate) fn chunk_start(&self) -> u64 {
+        self.chunk_start
+    }
+
+    /// Returns the file offset of the next byte to read. A chunk beginning
+    /// right after a block header is considered to start at the block.
+    fn position(&self) -> u64 {
+        match self.block_start {
+            Some(start) if self.offset == 0 => start,
+            Some(start) => start + BLOCK_HEADER_SIZE + self.offset as u64,
+            None => 0,
+        }
+    }
+
+    /// Reads the next chunk, returning it with the size it occupies in the
+    /// file, or `None` at the end of the file.
+    pub(crate) fn next_chunk(&mut self) -> Result<Option<(Chunk, u64)>> {
+        let start = self.position();
+        let mut buf = [0u8; CHUNK_HEADER_SIZE as usize];
+        if !self.read_exact(&mut buf)? {
+            return Ok(None);
+        }
+        let header = ChunkHeader::decode(&buf)?;
+        let data_size = usize::try_from(header.data_size)
+            .map_err(|_| Error::Corrupt(format!("chunk too large: {}", header.data_size)))?;
+        // Grow the buffer as data arrives rather than trusting the header.
+        let mut data = Vec::new();
+        while data.len() < data_size {
+            let filled = data.len();
+            data.resize(data_size.min(filled + BLOCK_SIZE as usize), 0);
+            if !self.read_exact(&mut data[filled..])? {
+                return Err(Error::Corrupt("truncated chunk data".into()));
+            }
+        }
+        if hash(&data) != header.data_hash {
+            return Err(Error::Corrupt(format!(
+                "chunk data hash mismatch at {start}"
+            )));
+        }
+        let mut padding = header.padding();
+        while padding > 0 {
+            let mut skip = [0u8; 4096];
+            let n = padding.min(skip.len() as u64) as usize;
+            if !self.read_exact(&mut skip[..n])? {
+                return Err(Error::Corrupt("truncated chunk padding".into()));
+            }
+            padding -= n as u64;
+        }
+        self.chunk_start = start;
+        Ok(Some((Chunk { header, data }, header.size_at(start))))
+    }
+
+    /// Fills `buf` from the chunk data, returning false if the file ends
+    /// before the first byte.
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<bool> {
+        let mut filled = 0;
+        while filled < buf.len() {
+            if self.offset == self.block.len() && !self.next_block()? {
+                if filled == 0 {
+                    return Ok(false);
+                }
+                return Err(Error::Corrupt("unexpected end of file".into()));
+            }
+            let n = (buf.len() - filled).min(self.block.len() - self.offset);
+            buf[filled..filled + n].copy_from_slice(&self.block[self.offset..self.offset + n]);
+            self.offset += n;
+            filled += n;
+        }
+        Ok(true)
+    }
+
+    /// Loads the block following the current one.
+    fn next_block(&mut self) -> Result<bool> {
+        let start = match self.block_start {
+            Some(start) => start + BLOCK_SIZE,
+            None => 0,
+        };
+        self.load_block(start)
+    }
+
+    /// Reads the block at `start` from the underlying reader, which must be
+    /// positioned there.
+    fn load_block(&mut self, start: u64) -> Result<bool> {
+        let mut block = Vec::with_capacity(BLOCK_SIZE as usize);
+        (&mut self.r).take(BLOCK_SIZE).read_to_end(&mut block)?;
+        if block.is_empty() {
+            return Ok(false);
+        }
+        if block.len() < BLOCK_HEADER_SIZE as usize {
+            return Err(Error::Corrupt(format!(
+                "short read for block header: {}",
+                block.len()
+            )));
+        }
+        self.block_header = BlockHeader::decode(&block)?;
+        block.drain(..BLOCK_HEADER_SIZE as usize);
+        self.block = block;
+        self.block_start = Some(start);
+        self.offset = 0;
+        Ok(true)
+    }
+}
+
+impl<R: Read + Seek> ChunkReader<R> {
+    /// Positions the reader at the chunk starting at `pos`.
+    pub(crate) fn seek(&mut self, pos: u64) -> Result<()> {
+        let block_start = pos / BLOCK_SIZE * BLOCK_SIZE;
+        let offset = match pos - block_start {
+            0 => 0,
+            n if n < BLOCK_HEADER_SIZE => {
+                return Err(Error::InvalidArgument(format!(
+                    "position {pos} is within a block header"
+                )))
+            }
+            n => n - BLOCK_HEADER_SIZE,
+        };
+        self.read_block(block_start)?;
+        if offset as usize > self.block.len() {
+            return Err(Error::InvalidArgument(format!(
+                "position {pos} is past the end of the file"
+            )));
+        }
+        self.offset = offset as usize;
+        Ok(())
+    }
+
+    /// Positions the reader at the chunk containing the file offset `pos`.
+    /// Returns false if there is no chunk at or after `pos`.
+    pub(crate) fn seek_to_chunk_containing(&mut self, pos: u64) -> Result<bool> {
+        let mut block_start = pos / BLOCK_SIZE * BLOCK_SIZE;
+        if !self.seek_to_next_chunk_in_block(block_start)? {
+            return Ok(false);
+        }
+        if pos < self.position() && block_start > 0 {
+            // The chunk starts in the previous block.
+            block_start -= BLOCK_SIZE;
+            self.seek_to_next_chunk_in_block(block_start)?;
+        }
+        loop {
+            let start = self.position();
+            let mut buf = [0u8; CHUNK_HEADER_SIZE as usize];
+            if !self.read_exact(&mut buf)? {
+                return Ok(false);
+            }
+            let next = start + ChunkHeader::decode(&buf)?.size_at(start);
+            if pos < next {
+                self.seek(start)?;
+                return Ok(true);
+            }
+            self.seek(next)?;
+        }
+    }
+
+    /// Positions the reader at the first chunk starting in the block at
+    /// `block_start` or later.
+    fn seek_to_next_chunk_in_block(&mut self, mut block_start: u64) -> Result<bool> {
+        loop {
+            if !self.read_block(block_start)? {
+                return Ok(false);
+            }
+            let offset = if self.block_header.previous_chunk == 0 {
+                0
+            } else {
+                self.block_header
+                    .next_chunk
+                    .saturating_sub(BLOCK_HEADER_SIZE)
+            };
+            if offset < USABLE_BLOCK_SIZE {
+                self.offset = (offset as usize).min(self.block.len());
+                return Ok(true);
+            }
+            block_start += BLOCK_SIZE;
+        }
+    }
+
+    fn read_block(&mut self, block_start: u64) -> Result<bool> {
+        if self.block_start == Some(block_start) {
+            return Ok(true);
+        }
+        self.r.seek(SeekFrom::Start(block_start))?;
+        let loaded = self.load_block(block_start)?;
+        if !loaded {
+            // Leave the reader at the end of the file.
+            self.block.clear();
+            self.block_start = Some(block_start);
+            self.offset = 0;
+        }
+        Ok(loaded)
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compression of chunk data.
+//!
+//! Each compressed block starts with the varint-encoded size of its
+//! decompressed data; uncompressed blocks are stored as is.
+
+use crate::wire::{put_varint, Input};
+use crate::{Compression, Error, Result};
+use std::io::{Read, Write};
+
+// Compression type bytes.
+pub(crate) const UNCOMPRESSED: u8 = 0;
+const BROTLI: u8 = b'b';
+const ZSTD: u8 = b'z';
+const SNAPPY: u8 = b's';
+
+/// The brotli window size used by the Riegeli writers.
+const BROTLI_WINDOW_LOG: u32 = 22;
+
+const BROTLI_BUFFER_SIZE: usize = 4096;
+
+impl Compression {
+    /// Returns the byte identifying the compression in chunk data.
+    pub(crate) fn type_byte(self) -> u8 {
+        match self {
+            Compression::Uncompressed => UNCOMPRESSED,
+            Compression::Brotli(_) => BROTLI,
+            Compression::Zstd(_) => ZSTD,
+            Compression::Snappy => SNAPPY,
+        }
+    }
+}
+
+/// Decompresses a block compressed as identified by `compression`.
+pub(crate) fn decompress(data: &[u8], compression: u8) -> Result<Vec<u8>> {
+    if compression == UNCOMPRESSED {
+        return Ok(data.to_vec());
+    }
+    let mut input = Input::new(data);
+    let size = input.size("decompressed size")?;
+    let data = input.rest();
+    let out = match compression {
+        BROTLI => {
+            let mut out = Vec::new();
+            brotli::Decompressor::new(data, BROTLI_BUFFER_SIZE).read_to_end(&mut out)?;
+            out
+        }
+        ZSTD => zstd::stream::decode_all(data)?,
+        SNAPPY => snap::raw::Decoder::new()
+            .decompress_vec(data)
+            .map_err(|e| Error::Corrupt(format!("snappy: {e}")))?,
+        other => {
+            return Err(Error::Unsupported(format!(
+                "compression type {:?}",
+                char::from(other)
+            )))
+        }
+    };
+    if out.len() != size {
+        return Err(Error::Corrupt(format!(
+            "decompressed {} bytes; expected {size}",
+            out.len()
+        )));
+    }
+    Ok(out)
+}
+
+/// Compresses `data` into a block.
+pub(crate) fn compress(data: &[u8], compression: Compression) -> Result<Vec<u8>> {
+    let compressed = match compression {
+        Compression::Uncompressed => return Ok(data.to_vec()),
+        Compression::Brotli(level) => {
+            let mut w = brotli::CompressorWriter::new(
+                Vec::new(),
+                BROTLI_BUFFER_SIZE,
+                level,
+                BROTLI_WINDOW_LOG,
+            );
+            w.write_all(data)?;
+            w.into_inner()
+        }
+        Compression::Zstd(level) => zstd::stream::encode_all(data, level)?,
+        Compression::Snappy => snap::raw::Encoder::new()
+            .compress_vec(data)
+            .map_err(|e| Error::InvalidArgument(format!("snappy: {e}")))?,
+    };
+    let mut out = Vec::with_capacity(compressed.len() + 10);
+    put_varint(&mut out, data.len() as u64);
+    out.extend_from_slice(&compressed);
+    Ok(out)
+}
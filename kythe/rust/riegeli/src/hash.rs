@@ -0,0 +1,161 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! HighwayHash-64, keyed as the Riegeli format requires.
+//!
+//! A port of the portable reference implementation in
+//! `github.com/google/highwayhash`.
+
+/// The hash key: the bytes of `"Riegeli/records\n"` twice, as little-endian
+/// words.
+const KEY: [u64; 4] = [
+    0x2f696c6567656952,
+    0x0a7364726f636572,
+    0x2f696c6567656952,
+    0x0a7364726f636572,
+];
+
+const INIT_MUL0: [u64; 4] = [
+    0xdbe6d5d5fe4cce2f,
+    0xa4093822299f31d0,
+    0x13198a2e03707344,
+    0x243f6a8885a308d3,
+];
+
+const INIT_MUL1: [u64; 4] = [
+    0x3bd39e10cb0ef593,
+    0xc0acf169b5f18a8c,
+    0xbe5466cf34e90c6c,
+    0x452821e638d01377,
+];
+
+/// Returns the Riegeli hash of `data`.
+pub(crate) fn hash(data: &[u8]) -> u64 {
+    let mut state = State::new();
+    let mut packets = data.chunks_exact(32);
+    for packet in &mut packets {
+        state.update_packet(packet);
+    }
+    let remainder = packets.remainder();
+    if !remainder.is_empty() {
+        state.update_remainder(remainder);
+    }
+    state.finalize()
+}
+
+struct State {
+    v0: [u64; 4],
+    v1: [u64; 4],
+    mul0: [u64; 4],
+    mul1: [u64; 4],
+}
+
+impl State {
+    fn new() -> Self {
+        let mut state = Self {
+            v0: [0; 4],
+            v1: [0; 4],
+            mul0: INIT_MUL0,
+            mul1: INIT_MUL1,
+        };
+        for i in 0..4 {
+            state.v0[i] = INIT_MUL0[i] ^ KEY[i];
+            state.v1[i] = INIT_MUL1[i] ^ KEY[i].rotate_left(32);
+        }
+        state
+    }
+
+    fn update(&mut self, lanes: [u64; 4]) {
+        for (i, lane) in lanes.into_iter().enumerate() {
+            self.v1[i] = self.v1[i].wrapping_add(self.mul0[i].wrapping_add(lane));
+            self.mul0[i] ^= (self.v1[i] & 0xffff_ffff).wrapping_mul(self.v0[i] >> 32);
+            self.v0[i] = self.v0[i].wrapping_add(self.mul1[i]);
+            self.mul1[i] ^= (self.v0[i] & 0xffff_ffff).wrapping_mul(self.v1[i] >> 32);
+        }
+        let (v0, v1) = (&mut self.v0, &mut self.v1);
+        zipper_merge_and_add(v1[1], v1[0], v0, 1, 0);
+        zipper_merge_and_add(v1[3], v1[2], v0, 3, 2);
+        zipper_merge_and_add(v0[1], v0[0], v1, 1, 0);
+        zipper_merge_and_add(v0[3], v0[2], v1, 3, 2);
+    }
+
+    fn update_packet(&mut self, packet: &[u8]) {
+        let mut lanes = [0u64; 4];
+        for (lane, bytes) in lanes.iter_mut().zip(packet.chunks_exact(8)) {
+            *lane = u64::from_le_bytes(bytes.try_into().unwrap());
+        }
+        self.update(lanes);
+    }
+
+    fn update_remainder(&mut self, bytes: &[u8]) {
+        let size = bytes.len();
+        let size_mod4 = size & 3;
+        let base = size & !3;
+        let remainder = &bytes[base..];
+        for v in &mut self.v0 {
+            *v = v.wrapping_add(((size as u64) << 32) + size as u64);
+        }
+        for v in &mut self.v1 {
+            let lo = (*v as u32).rotate_left(size as u32);
+            let hi = ((*v >> 32) as u32).rotate_left(size as u32);
+            *v = u64::from(lo) | (u64::from(hi) << 32);
+        }
+        let mut packet = [0u8; 32];
+        packet[..base].copy_from_slice(&bytes[..base]);
+        if size & 16 != 0 {
+            packet[28..].copy_from_slice(&bytes[size - 4..]);
+        } else if size_mod4 != 0 {
+            packet[16] = remainder[0];
+            packet[17] = remainder[size_mod4 >> 1];
+            packet[18] = remainder[size_mod4 - 1];
+        }
+        self.update_packet(&packet);
+    }
+
+    fn finalize(mut self) -> u64 {
+        for _ in 0..4 {
+            let v0 = self.v0;
+            self.update([
+                v0[2].rotate_left(32),
+                v0[3].rotate_left(32),
+                v0[0].rotate_left(32),
+                v0[1].rotate_left(32),
+            ]);
+        }
+        self.v0[0]
+            .wrapping_add(self.v1[0])
+            .wrapping_add(self.mul0[0])
+            .wrapping_add(self.mul1[0])
+    }
+}
+
+fn zipper_merge_and_add(v1: u64, v0: u64, add: &mut [u64; 4], i1: usize, i0: usize) {
+    add[i0] = add[i0].wrapping_add(
+        (((v0 & 0xff00_0000) | (v1 & 0xff_0000_0000)) >> 24)
+            | (((v0 & 0xff00_0000_0000) | (v1 & 0xff_0000_0000_0000)) >> 16)
+            | (v0 & 0xff_0000)
+            | ((v0 & 0xff00) << 32)
+            | ((v1 & 0xff00_0000_0000_0000) >> 8)
+            | (v0 << 56),
+    );
+    add[i1] = add[i1].wrapping_add(
+        (((v1 & 0xff00_0000) | (v0 & 0xff_0000_0000)) >> 24)
+            | (v1 & 0xff_0000)
+            | ((v1 & 0xff00_0000_0000) >> 16)
+            | ((v1 & 0xff00) << 24)
+            | ((v0 & 0xff_0000_0000_0000) >> 8)
+            | ((v1 & 0xff) << 48)
+            | (v0 & 0xff00_0000_0000_0000),
+    );
+}
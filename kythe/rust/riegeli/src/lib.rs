@@ -0,0 +1,262 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A reader and writer for Riegeli records files, compatible with
+//! `kythe/go/util/riegeli`.
+//!
+//! Riegeli files are a sequence of chunks, interleaved with block headers
+//! every 64KiB so that readers can seek and recover from corruption. Record
+//! chunks may be compressed with brotli, zstd or snappy, and protocol buffer
+//! records may be transposed so that like fields compress together.
+//!
+//! Format spec: https://github.com/google/riegeli/blob/master/doc/riegeli_records_file_format.md
+
+mod chunk;
+mod compression;
+mod hash;
+mod reader;
+mod transpose;
+mod wire;
+mod writer;
+
+pub use reader::{entries, Messages, Reader};
+pub use records_metadata_rust_proto::RecordsMetadata;
+pub use writer::Writer;
+
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Errors produced while reading or writing Riegeli files.
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("protobuf error: {0}")]
+    Proto(#[from] protobuf::ProtobufError),
+    #[error("corrupt riegeli file: {0}")]
+    Corrupt(String),
+    #[error("unsupported: {0}")]
+    Unsupported(String),
+    #[error("invalid argument: {0}")]
+    InvalidArgument(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl From<Error> for kythe_storage::Error {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::Io(err) => Self::Io(err),
+            Error::Proto(err) => Self::Proto(err),
+            Error::InvalidArgument(msg) => Self::InvalidArgument(msg),
+            err => Self::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                err.to_string(),
+            )),
+        }
+    }
+}
+
+/// The default uncompressed size of a chunk of records.
+pub const DEFAULT_CHUNK_SIZE: u64 = 1 << 20;
+pub const DEFAULT_BROTLI_LEVEL: u32 = 9;
+pub const DEFAULT_ZSTD_LEVEL: i32 = 9;
+
+/// The compression used for chunks of records.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Compression {
+    Uncompressed,
+    /// Brotli with a quality level in 0..=11.
+    Brotli(u32),
+    /// Zstd with a compression level in 0..=22.
+    Zstd(i32),
+    Snappy,
+}
+
+impl Default for Compression {
+    fn default() -> Self {
+        Compression::Brotli(DEFAULT_BROTLI_LEVEL)
+    }
+}
+
+impl fmt::Display for Compression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Compression::Uncompressed => f.write_str("uncompressed"),
+            Compression::Brotli(DEFAULT_BROTLI_LEVEL) => f.write_str("brotli"),
+            Compression::Brotli(level) => write!(f, "brotli:{level}"),
+            Compression::Zstd(DEFAULT_ZSTD_LEVEL) => f.write_str("zstd"),
+            Compression::Zstd(level) => write!(f, "zstd:{level}"),
+            Compression::Snappy => f.write_str("snappy"),
+        }
+    }
+}
+
+/// Customizes the behavior of a [`Writer`].
+///
+/// The options have a textual form, recorded in the metadata of each file:
+///
+/// ```text
+/// options ::= option? ("," option?)*
+/// option ::=
+///   "default" |
+///   "transpose" (":" ("true" | "false"))? |
+///   "uncompressed" |
+///   "brotli" (":" brotli_level)? |
+///   "zstd" (":" zstd_level)? |
+///   "snappy" |
+///   "chunk_size" ":" chunk_size
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WriterOptions {
+    /// The desired uncompressed size of a chunk of records; defaults to
+    /// [`DEFAULT_CHUNK_SIZE`].
+    pub chunk_size: Option<u64>,
+    /// The compression of chunks; defaults to brotli.
+    pub compression: Option<Compression>,
+    /// Whether protocol buffer records have their fields encoded in separate
+    /// buffers for better compression.
+    pub transpose: bool,
+}
+
+impl WriterOptions {
+    pub(crate) fn chunk_size(&self) -> u64 {
+        self.chunk_size
+            .filter(|&size| size > 0)
+            .unwrap_or(DEFAULT_CHUNK_SIZE)
+    }
+
+    pub(crate) fn compression(&self) -> Compression {
+        self.compression.unwrap_or_default()
+    }
+}
+
+impl FromStr for WriterOptions {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let malformed = |opt: &str| Error::InvalidArgument(format!("malformed option: {opt:?}"));
+        let mut opts = WriterOptions::default();
+        for opt in s.split(',').filter(|opt| !opt.is_empty()) {
+            let (key, value) = match opt.split_once(':') {
+                Some((key, value)) => (key, Some(value)),
+                None => (opt, None),
+            };
+            match (key, value) {
+                ("default", _) => {}
+                ("uncompressed", None) => opts.compression = Some(Compression::Uncompressed),
+                ("snappy", None) => opts.compression = Some(Compression::Snappy),
+                ("brotli", level) => {
+                    let level = match level {
+                        Some(level) => level.parse::<i64>().map_err(|_| malformed(opt))?,
+                        None => DEFAULT_BROTLI_LEVEL.into(),
+                    };
+                    let level = u32::try_from(level)
+                        .ok()
+                        .filter(|&level| level <= 11)
+                        .unwrap_or(DEFAULT_BROTLI_LEVEL);
+                    opts.compression = Some(Compression::Brotli(level));
+                }
+                ("zstd", level) => {
+                    let level = match level {
+                        Some(level) => level.parse::<i64>().map_err(|_| malformed(opt))?,
+                        None => DEFAULT_ZSTD_LEVEL.into(),
+                    };
+                    let level = i32::try_from(level)
+                        .ok()
+                        .filter(|level| (0..=22).contains(level))
+                        .unwrap_or(DEFAULT_ZSTD_LEVEL);
+                    opts.compression = Some(Compression::Zstd(level));
+                }
+                ("transpose", None | Some("true")) => opts.transpose = true,
+                ("transpose", Some("false")) => opts.transpose = false,
+                ("chunk_size", size) => {
+                    let size = match size {
+                        Some(size) => size.parse().map_err(|_| malformed(opt))?,
+                        None => DEFAULT_CHUNK_SIZE,
+                    };
+                    opts.chunk_size = Some(size);
+                }
+                ("uncompressed" | "snappy" | "transpose", _) => return Err(malformed(opt)),
+                _ => {
+                    return Err(Error::InvalidArgument(format!("unknown option: {opt:?}")));
+                }
+            }
+        }
+        Ok(opts)
+    }
+}
+
+impl fmt::Display for WriterOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut options = Vec::new();
+        if let Some(size) = self.chunk_size.filter(|&size| size > 0) {
+            options.push(format!("chunk_size:{size}"));
+        }
+        if let Some(compression) = self.compression {
+            options.push(compression.to_string());
+        }
+        if self.transpose {
+            options.push("transpose".to_string());
+        }
+        if options.is_empty() {
+            return f.write_str("default");
+        }
+        options.sort();
+        f.write_str(&options.join(","))
+    }
+}
+
+/// The position of a record within a Riegeli file.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RecordPosition {
+    /// The starting offset of the record's chunk.
+    pub chunk_begin: u64,
+    /// The index of the record within its chunk.
+    pub record_index: u64,
+}
+
+impl RecordPosition {
+    /// Returns a byte offset that orders records within the file, usable
+    /// with [`Reader::seek`].
+    pub fn index(&self) -> u64 {
+        self.chunk_begin + self.record_index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writer_options_round_trip() {
+        for (text, want) in [
+            ("", "default"),
+            ("default", "default"),
+            ("brotli", "brotli"),
+            ("brotli:5,transpose", "brotli:5,transpose"),
+            ("transpose,zstd:9", "transpose,zstd"),
+            ("snappy,chunk_size:1024", "chunk_size:1024,snappy"),
+            ("uncompressed,transpose:false", "uncompressed"),
+            ("brotli:42", "brotli"),
+        ] {
+            let opts: WriterOptions = text.parse().unwrap();
+            assert_eq!(opts.to_string(), want, "{text}");
+        }
+        for bad in ["gzip", "transpose:maybe", "brotli:x", "uncompressed:1"] {
+            assert!(bad.parse::<WriterOptions>().is_err(), "{bad}");
+        }
+    }
+}
@@ -0,0 +1,371 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sequential and seekable reading of Riegeli files.
+
+use crate::chunk::{
+    Chunk, ChunkReader, FILE_METADATA_CHUNK, FILE_SIGNATURE_CHUNK, SIMPLE_CHUNK, TRANSPOSED_CHUNK,
+};
+use crate::compression::{decompress, UNCOMPRESSED};
+use crate::wire::Input;
+use crate::{transpose, Error, RecordPosition, Result};
+use protobuf::Message;
+use records_metadata_rust_proto::RecordsMetadata;
+use std::io::{Read, Seek};
+use std::marker::PhantomData;
+use storage_rust_proto::Entry;
+
+/// Reads records from a Riegeli file.
+pub struct Reader<R: Read> {
+    chunks: ChunkReader<R>,
+    metadata: Option<RecordsMetadata>,
+    /// The records of the current chunk.
+    records: Vec<Vec<u8>>,
+    /// The index of the next record to return from `records`.
+    index: usize,
+    /// The start of the chunk holding `records`, if they are still loaded.
+    records_chunk: Option<u64>,
+    /// The size of the chunk holding `records` within the file.
+    chunk_size: u64,
+}
+
+impl<R: Read> Reader<R> {
+    pub fn new(r: R) -> Self {
+        Self {
+            chunks: ChunkReader::new(r),
+            metadata: None,
+            records: Vec::new(),
+            index: 0,
+            records_chunk: None,
+            chunk_size: 0,
+        }
+    }
+
+    /// Returns the metadata of the file, or an empty `RecordsMetadata` if it
+    /// has none.
+    pub fn records_metadata(&mut self) -> Result<&RecordsMetadata> {
+        if self.metadata.is_none() {
+            self.ensure_records()?;
+        }
+        Ok(self.metadata.get_or_insert_with(RecordsMetadata::new))
+    }
+
+    /// Returns the next record in the file, or `None` at its end. The
+    /// returned slice is only valid until the next call.
+    pub fn next_record(&mut self) -> Result<Option<&[u8]>> {
+        if !self.ensure_records()? {
+            return Ok(None);
+        }
+        self.index += 1;
+        Ok(Some(&self.records[self.index - 1]))
+    }
+
+    /// Decodes the next record in the file as a message of type `M`.
+    pub fn next_message<M: Message>(&mut self) -> Result<Option<M>> {
+        match self.next_record()? {
+            Some(record) => Ok(Some(M::parse_from_bytes(record)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns an iterator over the remaining records decoded as `M`.
+    pub fn messages<M: Message>(self) -> Messages<R, M> {
+        Messages {
+            reader: self,
+            done: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the position of the next record to be read.
+    pub fn position(&mut self) -> Result<RecordPosition> {
+        self.records_metadata()?;
+        Ok(RecordPosition {
+            chunk_begin: self.chunks.chunk_start(),
+            record_index: self.index as u64,
+        })
+    }
+
+    /// Loads the next chunk of records if the current one is exhausted,
+    /// returning false at the end of the file.
+    fn ensure_records(&mut self) -> Result<bool> {
+        while self.index >= self.records.len() {
+            self.records_chunk = None;
+            let Some((chunk, size)) = self.chunks.next_chunk()? else {
+                return Ok(false);
+            };
+            let header = &chunk.header;
+            if header.num_records == 0
+                && header.chunk_type != FILE_SIGNATURE_CHUNK
+                && header.chunk_type != FILE_METADATA_CHUNK
+            {
+                // Skip chunks without records, even of unknown types.
+                continue;
+            }
+            self.chunk_size = size;
+            let records = match header.chunk_type {
+                FILE_SIGNATURE_CHUNK => {
+                    let signature = Chunk::signature();
+                    if *header != signature.header || !chunk.data.is_empty() {
+                        return Err(Error::Corrupt("invalid file signature".into()));
+                    }
+                    continue;
+                }
+                FILE_METADATA_CHUNK => {
+                    let records = transpose::decode(&chunk.data, 1)?;
+                    self.metadata = Some(RecordsMetadata::parse_from_bytes(&records[0])?);
+                    continue;
+                }
+                TRANSPOSED_CHUNK => transpose::decode(&chunk.data, num_records(&chunk)?)?,
+                SIMPLE_CHUNK => decode_simple(&chunk)?,
+                other => {
+                    return Err(Error::Unsupported(format!(
+                        "chunk type {:?}",
+                        char::from(other)
+                    )))
+                }
+            };
+            self.records = records;
+            self.index = 0;
+            self.records_chunk = Some(self.chunks.chunk_start());
+        }
+        Ok(true)
+    }
+}
+
+impl<R: Read + Seek> Reader<R> {
+    /// Positions the reader at the first record whose [`RecordPosition::index`]
+    /// is at least `pos`.
+    pub fn seek(&mut self, pos: u64) -> Result<()> {
+        self.records_metadata()?;
+        let in_chunk = self
+            .records_chunk
+            .is_some_and(|start| start <= pos && pos < start + self.chunk_size);
+        if !in_chunk {
+            self.records.clear();
+            self.index = 0;
+            if !self.chunks.seek_to_chunk_containing(pos)? || !self.ensure_records()? {
+                return Ok(());
+            }
+        }
+        let start = self.chunks.chunk_start();
+        self.index = (pos.saturating_sub(start) as usize).min(self.records.len());
+        Ok(())
+    }
+
+    /// Positions the reader at the record at `pos`, as returned by
+    /// [`Reader::position`] or [`crate::Writer::position`].
+    pub fn seek_to_record(&mut self, pos: RecordPosition) -> Result<()> {
+        self.records_metadata()?;
+        if self.records_chunk != Some(pos.chunk_begin) {
+            self.records.clear();
+            self.index = 0;
+            self.chunks.seek(pos.chunk_begin)?;
+            if !self.ensure_records()? {
+                return Ok(());
+            }
+        }
+        self.index = (pos.record_index as usize).min(self.records.len());
+        Ok(())
+    }
+}
+
+/// Returns the number of records in `chunk`, checked against its size.
+fn num_records(chunk: &Chunk) -> Result<usize> {
+    let num_records = chunk.header.num_records;
+    // Every record takes at least one byte of decoded data or of its length.
+    let limit = (chunk.data.len() as u64)
+        .checked_mul(8)
+        .and_then(|n| n.checked_add(chunk.header.decoded_data_size));
+    match usize::try_from(num_records) {
+        Ok(n) if limit.is_some_and(|limit| num_records <= limit) => Ok(n),
+        _ => Err(Error::Corrupt(format!(
+            "implausible record count: {num_records}"
+        ))),
+    }
+}
+
+/// Decodes a simple chunk: record sizes and values, compressed separately.
+fn decode_simple(chunk: &Chunk) -> Result<Vec<Vec<u8>>> {
+    let mut input = Input::new(&chunk.data);
+    let compression = input.byte("compression type")?;
+    let sizes_size = input.size("record sizes size")?;
+    let sizes = decompress(input.take(sizes_size, "record sizes")?, compression)?;
+    let values = decompress(input.rest(), compression)?;
+    if compression == UNCOMPRESSED && values.len() as u64 != chunk.header.decoded_data_size {
+        return Err(Error::Corrupt(format!(
+            "decoded data size {}; expected {}",
+            values.len(),
+            chunk.header.decoded_data_size
+        )));
+    }
+
+    let mut sizes = Input::new(&sizes);
+    let mut values = Input::new(&values);
+    let mut records = Vec::new();
+    for _ in 0..num_records(chunk)? {
+        let size = sizes.size("record size")?;
+        records.push(values.take(size, "record")?.to_vec());
+    }
+    if !sizes.is_empty() || !values.is_empty() {
+        return Err(Error::Corrupt("trailing simple chunk data".into()));
+    }
+    Ok(records)
+}
+
+/// An iterator over the messages in a Riegeli file.
+pub struct Messages<R: Read, M> {
+    reader: Reader<R>,
+    done: bool,
+    _marker: PhantomData<M>,
+}
+
+impl<R: Read, M: Message> Iterator for Messages<R, M> {
+    type Item = Result<M>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let next = self.reader.next_message().transpose();
+        self.done = !matches!(next, Some(Ok(_)));
+        next
+    }
+}
+
+/// Returns an iterator over the entries in a Riegeli file.
+pub fn entries<R: Read>(r: R) -> Messages<R, Entry> {
+    Reader::new(r).messages()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    const GOLDEN_JSON: &str = include_str!("../../../go/util/riegeli/testdata/golden.entries.json");
+
+    const GOLDEN_VARIANTS: &[(&str, &[u8])] = &[
+        (
+            "uncompressed",
+            include_bytes!("../../../go/util/riegeli/testdata/golden.entries.uncompressed.riegeli"),
+        ),
+        (
+            "uncompressed_transpose",
+            include_bytes!(
+                "../../../go/util/riegeli/testdata/golden.entries.uncompressed_transpose.riegeli"
+            ),
+        ),
+        (
+            "brotli",
+            include_bytes!("../../../go/util/riegeli/testdata/golden.entries.brotli.riegeli"),
+        ),
+        (
+            "brotli_transpose",
+            include_bytes!(
+                "../../../go/util/riegeli/testdata/golden.entries.brotli_transpose.riegeli"
+            ),
+        ),
+        (
+            "snappy",
+            include_bytes!("../../../go/util/riegeli/testdata/golden.entries.snappy.riegeli"),
+        ),
+        (
+            "zstd",
+            include_bytes!("../../../go/util/riegeli/testdata/golden.entries.zstd.riegeli"),
+        ),
+    ];
+
+    /// Returns the fact names of the golden entries, in order.
+    fn golden_fact_names() -> Vec<&'static str> {
+        GOLDEN_JSON
+            .lines()
+            .map(|line| {
+                let start = line.find(r#""fact_name":""#).unwrap() + 13;
+                let len = line[start..].find('"').unwrap();
+                &line[start..start + len]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn golden_files() {
+        let want = golden_fact_names();
+        assert_eq!(want.len(), 100);
+        let expected = entries(Cursor::new(GOLDEN_VARIANTS[0].1))
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        for &(variant, data) in GOLDEN_VARIANTS {
+            let mut reader = Reader::new(Cursor::new(data));
+            let metadata = reader.records_metadata().unwrap();
+            assert_eq!(metadata.get_record_type_name(), "kythe.proto.Entry");
+            assert_eq!(
+                metadata.get_record_writer_options(),
+                variant.replace('_', ","),
+                "{variant}"
+            );
+            let found = reader
+                .messages::<Entry>()
+                .collect::<Result<Vec<_>>>()
+                .unwrap();
+            assert_eq!(found, expected, "{variant}");
+            let names: Vec<_> = found.iter().map(|e| e.get_fact_name()).collect();
+            assert_eq!(names, want, "{variant}");
+        }
+    }
+
+    #[test]
+    fn seek_golden_files() {
+        for &(variant, data) in GOLDEN_VARIANTS {
+            let mut reader = Reader::new(Cursor::new(data));
+            let mut records = Vec::new();
+            loop {
+                let pos = reader.position().unwrap();
+                match reader.next_record().unwrap() {
+                    Some(record) => records.push((pos, record.to_vec())),
+                    None => break,
+                }
+            }
+            assert_eq!(records.len(), 100, "{variant}");
+            for (pos, record) in records.iter().rev() {
+                reader.seek_to_record(*pos).unwrap();
+                assert_eq!(
+                    reader.next_record().unwrap(),
+                    Some(&record[..]),
+                    "{variant}"
+                );
+                reader.seek(pos.index()).unwrap();
+                assert_eq!(reader.position().unwrap(), *pos, "{variant}");
+                assert_eq!(
+                    reader.next_record().unwrap(),
+                    Some(&record[..]),
+                    "{variant}"
+                );
+            }
+            reader.seek(data.len() as u64).unwrap();
+            assert_eq!(reader.next_record().unwrap(), None, "{variant}");
+        }
+    }
+
+    #[test]
+    fn implausible_record_counts() {
+        let chunk = Chunk::new(0, vec![0; 2], 16, 0);
+        assert_eq!(num_records(&chunk).unwrap(), 16);
+        let chunk = Chunk::new(0, vec![0; 2], 17, 0);
+        assert!(num_records(&chunk).is_err());
+        // The bound overflows rather than admitting any count.
+        let chunk = Chunk::new(0, vec![0; 2], 1 << 56, u64::MAX);
+        assert!(num_records(&chunk).is_err());
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Transposed chunks, which store the fields of protocol buffer records in
+//! separate buffers for better compression.
+//!
+//! See https://github.com/google/riegeli/blob/master/doc/riegeli_records_file_format.md#transposed-chunk-with-records.
+
+mod decoder;
+mod encoder;
+
+pub(crate) use decoder::decode;
+pub(crate) use encoder::Encoder;
+
+use crate::wire::put_varint;
+
+// Reserved tags, which overload the protocol buffer keys of field 0.
+const NO_OP_TAG: u64 = 0;
+const NON_PROTO_TAG: u64 = 1;
+const START_OF_SUBMESSAGE_TAG: u64 = 2;
+const START_OF_MESSAGE_TAG: u64 = 3;
+/// The parent of the fields of a record; never encoded.
+const ROOT_TAG: u64 = 4;
+
+// Protocol buffer wire types, plus one for the end of a submessage.
+const VARINT_TYPE: u64 = 0;
+const FIXED64_TYPE: u64 = 1;
+const BYTES_TYPE: u64 = 2;
+const START_GROUP_TYPE: u64 = 3;
+const END_GROUP_TYPE: u64 = 4;
+const FIXED32_TYPE: u64 = 5;
+const SUBMESSAGE_TYPE: u64 = 6;
+
+// Subtypes of varint fields: the length of a varint stored in a buffer, or
+// a small value inlined in the state.
+const VARINT_1_SUBTYPE: u8 = 0;
+const VARINT_MAX_SUBTYPE: u8 = VARINT_1_SUBTYPE + crate::wire::MAX_VARINT_LEN as u8 - 1;
+const VARINT_INLINE_0_SUBTYPE: u8 = VARINT_MAX_SUBTYPE + 1;
+
+// Subtypes of length-delimited fields.
+const DELIMITED_STRING_SUBTYPE: u8 = 0;
+const DELIMITED_START_OF_SUBMESSAGE_SUBTYPE: u8 = 1;
+const DELIMITED_END_OF_SUBMESSAGE_SUBTYPE: u8 = 2;
+
+/// Reports whether fields with `tag` and `subtype` keep their values in a
+/// data buffer.
+fn has_data_buffer(tag: u64, subtype: u8) -> bool {
+    match tag & 7 {
+        VARINT_TYPE => subtype < VARINT_INLINE_0_SUBTYPE,
+        FIXED32_TYPE | FIXED64_TYPE => true,
+        BYTES_TYPE => subtype == DELIMITED_STRING_SUBTYPE,
+        _ => false,
+    }
+}
+
+/// Reports whether `tag` is a protocol buffer key rather than a reserved tag.
+fn valid_proto_tag(tag: u64) -> bool {
+    match tag & 7 {
+        VARINT_TYPE | FIXED32_TYPE | FIXED64_TYPE | BYTES_TYPE | START_GROUP_TYPE
+        | END_GROUP_TYPE => tag >= 8,
+        _ => false,
+    }
+}
+
+/// Reports whether states with `tag` carry a subtype.
+fn has_subtype(tag: u64) -> bool {
+    tag & 7 == VARINT_TYPE
+}
+
+/// Collects pieces of a record that are produced back to front.
+#[derive(Debug, Default)]
+struct BackwardWriter {
+    pieces: Vec<Vec<u8>>,
+    len: usize,
+}
+
+impl BackwardWriter {
+    /// Prepends `piece` to the data.
+    fn push(&mut self, piece: &[u8]) {
+        if !piece.is_empty() {
+            self.len += piece.len();
+            self.pieces.push(piece.to_vec());
+        }
+    }
+
+    /// Prepends the varint encoding of `value` to the data.
+    fn push_varint(&mut self, value: u64) {
+        let mut buf = Vec::new();
+        put_varint(&mut buf, value);
+        self.push(&buf);
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the data, leaving the writer empty.
+    fn take(&mut self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.len);
+        for piece in self.pieces.drain(..).rev() {
+            out.extend_from_slice(&piece);
+        }
+        self.len = 0;
+        out
+    }
+}
@@ -0,0 +1,316 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Decoding of transposed chunks, as `kythe/go/util/riegeli/transpose_decoder.go`.
+//!
+//! A transposed chunk holds a state machine. Each state names a field (or a
+//! reserved action) and may draw its value from a data buffer; running the
+//! machine from its initial state rebuilds the records back to front.
+
+use super::*;
+use crate::compression::decompress;
+use crate::wire::{varint, Input};
+use crate::{Error, Result};
+
+struct State {
+    tag: u64,
+    subtype: u8,
+    /// The index of the state that follows this one.
+    next: usize,
+    /// Whether moving to the state does not count against the iterations
+    /// granted by a transition byte.
+    implicit: bool,
+    /// The encoded key of the field, with any inlined value.
+    data: Vec<u8>,
+    /// The index of the state's data buffer.
+    buffer: Option<usize>,
+}
+
+/// Decodes the `num_records` records of the transposed chunk `data`.
+pub(crate) fn decode(data: &[u8], num_records: usize) -> Result<Vec<Vec<u8>>> {
+    let mut input = Input::new(data);
+    let compression = input.byte("compression type")?;
+    let header_size = input.size("header size")?;
+    let header = decompress(input.take(header_size, "header")?, compression)?;
+    let mut header = Input::new(&header);
+
+    let num_buckets = header.size("number of buckets")?;
+    let num_buffers = header.size("number of buffers")?;
+    let mut buckets = Vec::new();
+    for _ in 0..num_buckets {
+        let size = header.size("bucket size")?;
+        buckets.push(decompress(input.take(size, "bucket")?, compression)?);
+    }
+
+    // Split the buckets into the data buffers.
+    let mut bucket_data: Vec<Input> = buckets.iter().map(|b| Input::new(b)).collect();
+    let mut buffers = Vec::new();
+    let mut bucket = 0;
+    for i in 0..num_buffers {
+        let size = header.size("buffer size")?;
+        while size > 0 && bucket < bucket_data.len() && bucket_data[bucket].is_empty() {
+            bucket += 1;
+        }
+        let Some(source) = bucket_data.get_mut(bucket) else {
+            return Err(Error::Corrupt(format!(
+                "buffer {i}: ran out of bucket data"
+            )));
+        };
+        buffers.push(Input::new(source.take(size, "buffer")?));
+    }
+    if bucket_data.iter().any(|b| !b.is_empty()) {
+        return Err(Error::Corrupt("trailing bucket data".into()));
+    }
+
+    let num_states = header.size("number of states")?;
+    let tags = (0..num_states)
+        .map(|_| header.varint("state tag"))
+        .collect::<Result<Vec<_>>>()?;
+    let mut states = Vec::new();
+    for &tag in &tags {
+        let mut next = header.size("next state")?;
+        let implicit = next >= num_states;
+        if implicit {
+            next -= num_states;
+        }
+        if next >= num_states {
+            return Err(Error::Corrupt(format!("invalid state transition: {next}")));
+        }
+        states.push(State {
+            tag,
+            subtype: 0,
+            next,
+            implicit,
+            data: Vec::new(),
+            buffer: None,
+        });
+    }
+    let num_subtypes = tags
+        .iter()
+        .filter(|&&tag| valid_proto_tag(tag) && has_subtype(tag))
+        .count();
+    let mut subtypes = Input::new(header.take(num_subtypes, "subtypes")?);
+
+    let buffer_index = |header: &mut Input| -> Result<usize> {
+        let index = header.size("buffer index")?;
+        if index >= buffers.len() {
+            return Err(Error::Corrupt(format!("invalid buffer index: {index}")));
+        }
+        Ok(index)
+    };
+    let mut has_non_proto = false;
+    for state in &mut states {
+        match state.tag {
+            NO_OP_TAG | START_OF_MESSAGE_TAG | START_OF_SUBMESSAGE_TAG => {}
+            NON_PROTO_TAG => {
+                state.buffer = Some(buffer_index(&mut header)?);
+                has_non_proto = true;
+            }
+            mut tag => {
+                let mut subtype = 0;
+                // The end of a submessage is encoded with its own wire type.
+                if tag & 7 == SUBMESSAGE_TYPE {
+                    tag -= SUBMESSAGE_TYPE - BYTES_TYPE;
+                    subtype = DELIMITED_END_OF_SUBMESSAGE_SUBTYPE;
+                }
+                if has_subtype(tag) {
+                    subtype = subtypes.byte("subtype")?;
+                }
+                if has_data_buffer(tag, subtype) {
+                    state.buffer = Some(buffer_index(&mut header)?);
+                }
+                state.tag = tag;
+                state.subtype = subtype;
+                state.data = varint(tag);
+                if tag & 7 == VARINT_TYPE && subtype >= VARINT_INLINE_0_SUBTYPE {
+                    state.data.push(subtype - VARINT_INLINE_0_SUBTYPE);
+                }
+            }
+        }
+    }
+    if !subtypes.is_empty() {
+        return Err(Error::Corrupt("not all subtypes used".into()));
+    }
+    let non_proto_lengths = has_non_proto.then(|| buffers.len() - 1);
+
+    let initial = header.size("initial state")?;
+    if initial >= num_states {
+        return Err(Error::Corrupt(format!("invalid initial state: {initial}")));
+    }
+    if !header.is_empty() {
+        return Err(Error::Corrupt(format!(
+            "{} leftover header bytes",
+            header.len()
+        )));
+    }
+    let transitions = match input.rest() {
+        [] => Vec::new(),
+        rest => decompress(rest, compression)?,
+    };
+
+    let machine = Machine {
+        states,
+        buffers,
+        non_proto_lengths,
+    };
+    machine.execute(initial, &transitions, num_records)
+}
+
+struct Machine<'a> {
+    states: Vec<State>,
+    buffers: Vec<Input<'a>>,
+    non_proto_lengths: Option<usize>,
+}
+
+impl Machine<'_> {
+    fn execute(
+        mut self,
+        initial: usize,
+        transitions: &[u8],
+        num_records: usize,
+    ) -> Result<Vec<Vec<u8>>> {
+        let mut current = initial;
+        let mut transitions = transitions.iter();
+        // The number of moves to make before reading a transition byte.
+        let mut iterations = usize::from(self.states[current].implicit);
+        // Moves made without consuming input, to detect loops.
+        let mut idle = 0;
+        // The lengths of the record at the ends of the open submessages,
+        // with the keys of those submessages.
+        let mut submessages: Vec<(usize, Vec<u8>)> = Vec::new();
+        let mut writer = BackwardWriter::default();
+        let mut records = Vec::new();
+
+        loop {
+            let state = &self.states[current];
+            match state.tag {
+                NO_OP_TAG => {}
+                NON_PROTO_TAG => {
+                    let lengths = self.non_proto_lengths.unwrap_or_default();
+                    let size = self.buffers[lengths].size("non-proto record length")?;
+                    let buffer = &mut self.buffers[state.buffer.unwrap_or_default()];
+                    records.push(buffer.take(size, "non-proto record")?.to_vec());
+                }
+                START_OF_MESSAGE_TAG => {
+                    if !submessages.is_empty() {
+                        return Err(Error::Corrupt("unterminated submessage".into()));
+                    }
+                    records.push(writer.take());
+                }
+                START_OF_SUBMESSAGE_TAG => {
+                    let Some((end, key)) = submessages.pop() else {
+                        return Err(Error::Corrupt("submessage stack underflow".into()));
+                    };
+                    writer.push_varint((writer.len() - end) as u64);
+                    writer.push(&key);
+                }
+                tag => {
+                    let mut buffer = state.buffer.map(|i| &mut self.buffers[i]);
+                    let mut read = |n: usize, what: &str| match buffer.as_mut() {
+                        Some(b) => b.take(n, what),
+                        None => Err(Error::Corrupt(format!("{what}: no buffer"))),
+                    };
+                    match tag & 7 {
+                        VARINT_TYPE if state.subtype >= VARINT_INLINE_0_SUBTYPE => {
+                            writer.push(&state.data);
+                        }
+                        VARINT_TYPE => {
+                            let n = usize::from(state.subtype - VARINT_1_SUBTYPE) + 1;
+                            let mut value = read(n, "varint")?.to_vec();
+                            for b in &mut value[..n - 1] {
+                                *b |= 0x80;
+                            }
+                            writer.push(&value);
+                            writer.push(&state.data);
+                        }
+                        FIXED32_TYPE => {
+                            writer.push(read(4, "fixed32")?);
+                            writer.push(&state.data);
+                        }
+                        FIXED64_TYPE => {
+                            writer.push(read(8, "fixed64")?);
+                            writer.push(&state.data);
+                        }
+                        BYTES_TYPE => match state.subtype {
+                            DELIMITED_STRING_SUBTYPE => {
+                                let Some(buffer) = buffer else {
+                                    return Err(Error::Corrupt("string: no buffer".into()));
+                                };
+                                let size = buffer.size("string length")?;
+                                writer.push(buffer.take(size, "string")?);
+                                writer.push_varint(size as u64);
+                                writer.push(&state.data);
+                            }
+                            DELIMITED_END_OF_SUBMESSAGE_SUBTYPE => {
+                                submessages.push((writer.len(), state.data.clone()));
+                            }
+                            other => {
+                                return Err(Error::Corrupt(format!(
+                                    "unknown delimited subtype: {other}"
+                                )))
+                            }
+                        },
+                        START_GROUP_TYPE | END_GROUP_TYPE => writer.push(&state.data),
+                        _ => return Err(Error::Corrupt(format!("unknown tag: {tag:#x}"))),
+                    }
+                }
+            }
+            if records.len() > num_records {
+                return Err(Error::Corrupt(format!(
+                    "more than the expected {num_records} records"
+                )));
+            }
+
+            current = self.states[current].next;
+            if iterations == 0 {
+                let Some(&transition) = transitions.next() else {
+                    break;
+                };
+                current += usize::from(transition >> 2);
+                if current >= self.states.len() {
+                    return Err(Error::Corrupt(format!("invalid transition to {current}")));
+                }
+                iterations =
+                    usize::from(transition & 3) + usize::from(self.states[current].implicit);
+                idle = 0;
+            } else if !self.states[current].implicit {
+                iterations -= 1;
+                idle = 0;
+            } else {
+                idle += 1;
+                if idle > self.states.len() {
+                    return Err(Error::Corrupt("state machine loops".into()));
+                }
+            }
+        }
+
+        if writer.len() != 0 {
+            return Err(Error::Corrupt(format!(
+                "{} leftover record bytes",
+                writer.len()
+            )));
+        }
+        if let Some(i) = self.buffers.iter().position(|b| !b.is_empty()) {
+            return Err(Error::Corrupt(format!("leftover data in buffer {i}")));
+        }
+        if records.len() != num_records {
+            return Err(Error::Corrupt(format!(
+                "found {} records; expected {num_records}",
+                records.len()
+            )));
+        }
+        records.reverse();
+        Ok(records)
+    }
+}
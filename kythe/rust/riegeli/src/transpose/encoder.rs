@@ -0,0 +1,398 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Encoding of transposed chunks, as `kythe/go/util/riegeli/transpose_encoder.go`.
+//!
+//! Every field of the records is given a node keyed by its parent and field
+//! number, whose values are collected into a data buffer. The sequence of
+//! fields is recorded as a state machine that the decoder runs back to front.
+
+use std::collections::{BTreeSet, HashMap};
+
+use super::*;
+use crate::compression::compress;
+use crate::wire::{put_varint, Input};
+use crate::{Compression, Error, Result};
+
+/// The categories of data buffers, in the order they are written.
+#[derive(Clone, Copy)]
+enum BufferType {
+    Varint,
+    Fixed32,
+    Fixed64,
+    Delimited,
+    NonProto,
+}
+
+const BUFFER_TYPES: usize = 5;
+
+/// The cutoff point of decoding length-delimited fields as submessages.
+const MAX_RECURSION_DEPTH: usize = 100;
+
+/// The largest offset that a transition byte can encode.
+const MAX_TRANSITIONS: usize = 63;
+
+/// A field of a message: its parent's node id and its field number.
+type NodeKey = (u64, u64);
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct EncodedTag {
+    parent: u64,
+    tag: u64,
+    subtype: u8,
+}
+
+#[derive(Default)]
+struct Node {
+    id: u64,
+    buffer: Option<usize>,
+}
+
+#[derive(Default)]
+struct StateInfo {
+    tag: u64,
+    next: usize,
+    subtype: u8,
+    /// The 1-based index of the state's data buffer, or 0 for none.
+    buffer: usize,
+}
+
+/// Accumulates records for a transposed chunk.
+pub(crate) struct Encoder {
+    compression: Compression,
+    next_node_id: u64,
+    nodes: HashMap<NodeKey, Node>,
+    /// The distinct tags, indexed by position.
+    tags: Vec<EncodedTag>,
+    tag_pos: HashMap<EncodedTag, usize>,
+    /// The positions of the tags of every record, in order.
+    encoded_tags: Vec<usize>,
+    /// The data buffers with their node keys, indexed by `buffer_order`.
+    buffers: Vec<(NodeKey, BackwardWriter)>,
+    buffer_order: [Vec<usize>; BUFFER_TYPES],
+    non_proto_lengths: BackwardWriter,
+}
+
+impl Encoder {
+    pub(crate) fn new(compression: Compression) -> Self {
+        Self {
+            compression,
+            next_node_id: ROOT_TAG + 1,
+            nodes: HashMap::new(),
+            tags: Vec::new(),
+            tag_pos: HashMap::new(),
+            encoded_tags: Vec::new(),
+            buffers: Vec::new(),
+            buffer_order: Default::default(),
+            non_proto_lengths: BackwardWriter::default(),
+        }
+    }
+
+    /// Adds `record` to the chunk, transposing it if it parses as a protocol
+    /// buffer message.
+    pub(crate) fn put(&mut self, record: &[u8]) -> Result<()> {
+        if is_proto_message(record) {
+            self.add_tag(START_OF_MESSAGE_TAG, 0, 0);
+            self.add_message(record, ROOT_TAG, 0)
+        } else {
+            self.add_tag(NON_PROTO_TAG, 0, 0);
+            self.buffer((NON_PROTO_TAG, 0), BufferType::NonProto)
+                .push(record);
+            self.non_proto_lengths.push_varint(record.len() as u64);
+            Ok(())
+        }
+    }
+
+    /// Appends a tag to the sequence of the chunk. Reserved tags are passed
+    /// as `parent`, with a zero `tag`.
+    fn add_tag(&mut self, parent: u64, tag: u64, subtype: u8) {
+        let encoded = EncodedTag {
+            parent,
+            tag,
+            subtype,
+        };
+        let tags = &mut self.tags;
+        let pos = *self.tag_pos.entry(encoded).or_insert_with(|| {
+            tags.push(encoded);
+            tags.len() - 1
+        });
+        self.encoded_tags.push(pos);
+    }
+
+    fn node(&mut self, key: NodeKey) -> &mut Node {
+        let next_id = &mut self.next_node_id;
+        self.nodes.entry(key).or_insert_with(|| {
+            let id = *next_id;
+            *next_id += 1;
+            Node { id, buffer: None }
+        })
+    }
+
+    fn buffer(&mut self, key: NodeKey, kind: BufferType) -> &mut BackwardWriter {
+        let index = match self.node(key).buffer {
+            Some(index) => index,
+            None => {
+                let index = self.buffers.len();
+                self.buffers.push((key, BackwardWriter::default()));
+                self.buffer_order[kind as usize].push(index);
+                self.node(key).buffer = Some(index);
+                index
+            }
+        };
+        &mut self.buffers[index].1
+    }
+
+    fn add_message(&mut self, record: &[u8], parent: u64, depth: usize) -> Result<()> {
+        let mut input = Input::new(record);
+        while !input.is_empty() {
+            let tag = input.varint("field key")?;
+            let key = (parent, tag >> 3);
+            match tag & 7 {
+                VARINT_TYPE => {
+                    // The value is kept as encoded; the decoder restores the
+                    // continuation bits.
+                    let start = record.len() - input.len();
+                    input.varint("varint field")?;
+                    let end = record.len() - input.len();
+                    self.add_tag(parent, tag, VARINT_1_SUBTYPE + (end - start) as u8 - 1);
+                    self.buffer(key, BufferType::Varint)
+                        .push(&record[start..end]);
+                }
+                FIXED32_TYPE => {
+                    let value = input.take(4, "fixed32 field")?;
+                    self.add_tag(parent, tag, 0);
+                    self.buffer(key, BufferType::Fixed32).push(value);
+                }
+                FIXED64_TYPE => {
+                    let value = input.take(8, "fixed64 field")?;
+                    self.add_tag(parent, tag, 0);
+                    self.buffer(key, BufferType::Fixed64).push(value);
+                }
+                BYTES_TYPE => {
+                    let start = record.len() - input.len();
+                    let size = input.size("delimited field size")?;
+                    let value = input.take(size, "delimited field")?;
+                    if depth < MAX_RECURSION_DEPTH && is_proto_message(value) {
+                        self.add_tag(parent, tag, DELIMITED_START_OF_SUBMESSAGE_SUBTYPE);
+                        let id = self.node(key).id;
+                        self.add_message(value, id, depth + 1)?;
+                        self.add_tag(parent, tag, DELIMITED_END_OF_SUBMESSAGE_SUBTYPE);
+                    } else {
+                        self.add_tag(parent, tag, DELIMITED_STRING_SUBTYPE);
+                        let end = record.len() - input.len();
+                        self.buffer(key, BufferType::Delimited)
+                            .push(&record[start..end]);
+                    }
+                }
+                START_GROUP_TYPE | END_GROUP_TYPE => self.add_tag(parent, tag, 0),
+                _ => {
+                    return Err(Error::InvalidArgument(format!(
+                        "unknown wire type: {tag:#x}"
+                    )))
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Encodes the chunk data, leaving the encoder empty.
+    pub(crate) fn encode(&mut self) -> Result<Vec<u8>> {
+        let mut data = Vec::new();
+        let mut buffer_indices = HashMap::new();
+        let mut buffer_sizes = Vec::new();
+        for &index in self.buffer_order.iter().flatten() {
+            let (key, writer) = &mut self.buffers[index];
+            buffer_indices.insert(*key, buffer_indices.len() + 1);
+            buffer_sizes.push(writer.len());
+            data.extend_from_slice(&writer.take());
+        }
+        if self.non_proto_lengths.len() > 0 {
+            buffer_sizes.push(self.non_proto_lengths.len());
+            data.extend_from_slice(&self.non_proto_lengths.take());
+        }
+        let data = compress(&data, self.compression)?;
+
+        let (states, transitions, initial) = self.build_state_machine(&buffer_indices);
+
+        let mut header = Vec::new();
+        put_varint(&mut header, 1); // num_buckets
+        put_varint(&mut header, buffer_sizes.len() as u64);
+        put_varint(&mut header, data.len() as u64);
+        for size in buffer_sizes {
+            put_varint(&mut header, size as u64);
+        }
+        put_varint(&mut header, states.len() as u64);
+        // States are split into four parallel lists.
+        let (mut nexts, mut subtypes, mut buffers) = (Vec::new(), Vec::new(), Vec::new());
+        for state in &states {
+            put_varint(&mut header, state.tag);
+            put_varint(&mut nexts, state.next as u64);
+            if valid_proto_tag(state.tag) && has_subtype(state.tag) {
+                subtypes.push(state.subtype);
+            }
+            if state.buffer > 0 {
+                put_varint(&mut buffers, state.buffer as u64 - 1);
+            }
+        }
+        header.extend_from_slice(&nexts);
+        header.extend_from_slice(&subtypes);
+        header.extend_from_slice(&buffers);
+        put_varint(&mut header, initial as u64);
+        let header = compress(&header, self.compression)?;
+
+        let mut out = vec![self.compression.type_byte()];
+        put_varint(&mut out, header.len() as u64);
+        out.extend_from_slice(&header);
+        out.extend_from_slice(&data);
+        out.extend_from_slice(&compress(&transitions, self.compression)?);
+
+        *self = Self::new(self.compression);
+        Ok(out)
+    }
+
+    fn build_state_machine(
+        &self,
+        buffer_indices: &HashMap<NodeKey, usize>,
+    ) -> (Vec<StateInfo>, Vec<u8>, usize) {
+        let Some(&first) = self.encoded_tags.first() else {
+            // A machine that does nothing.
+            return (vec![StateInfo::default()], Vec::new(), 0);
+        };
+
+        // The decoder runs backwards, so each tag is followed by its
+        // predecessor in the records.
+        let mut nexts = vec![BTreeSet::new(); self.tags.len()];
+        for pair in self.encoded_tags.windows(2) {
+            nexts[pair[1]].insert(pair[0]);
+        }
+        let halt = self.tags.len();
+        nexts[first].insert(halt);
+
+        let mut states: Vec<StateInfo> = (0..pos_to_index(halt))
+            .map(|_| StateInfo::default())
+            .collect();
+        for (pos, encoded) in self.tags.iter().enumerate() {
+            let mut tag = encoded.tag;
+            let mut buffer = buffer_indices
+                .get(&(encoded.parent, encoded.tag >> 3))
+                .copied()
+                .unwrap_or_default();
+            if encoded.parent == NON_PROTO_TAG || encoded.parent == START_OF_MESSAGE_TAG {
+                tag = encoded.parent;
+            } else if tag & 7 == BYTES_TYPE {
+                match encoded.subtype {
+                    DELIMITED_END_OF_SUBMESSAGE_SUBTYPE => tag = tag & !7 | SUBMESSAGE_TYPE,
+                    DELIMITED_START_OF_SUBMESSAGE_SUBTYPE => tag = START_OF_SUBMESSAGE_TAG,
+                    _ => {}
+                }
+            }
+            // The node may have a buffer for another encoding of the field.
+            if encoded.parent != NON_PROTO_TAG
+                && !(valid_proto_tag(tag) && has_data_buffer(tag, encoded.subtype))
+            {
+                buffer = 0;
+            }
+            // The smallest possible next state is the static one; transition
+            // bytes move further.
+            let next = nexts[pos].first().copied().unwrap_or(halt);
+            states[pos_to_index(pos)] = StateInfo {
+                tag,
+                next: pos_to_index(next),
+                subtype: encoded.subtype,
+                buffer,
+            };
+        }
+
+        // Rather than splitting states with too many successors, add a no-op
+        // state periodically to trampoline to any later state.
+        for i in (0..states.len()).step_by(MAX_TRANSITIONS + 1) {
+            states[i] = StateInfo {
+                next: i + 1,
+                ..Default::default()
+            };
+        }
+
+        // States with a single successor need no transition byte.
+        let num_states = states.len();
+        for (pos, successors) in nexts.iter().enumerate() {
+            let state = &mut states[pos_to_index(pos)];
+            if successors.len() <= 1 && state.next < num_states {
+                state.next += num_states;
+            }
+        }
+
+        let mut transitions = Vec::new();
+        for i in (1..self.encoded_tags.len()).rev() {
+            let pos = self.encoded_tags[i];
+            if nexts[pos].len() <= 1 {
+                continue;
+            }
+            let base = states[pos_to_index(pos)].next;
+            let mut offset = pos_to_index(self.encoded_tags[i - 1]) - base;
+            if offset > MAX_TRANSITIONS {
+                let jump =
+                    base / (MAX_TRANSITIONS + 1) * (MAX_TRANSITIONS + 1) + MAX_TRANSITIONS + 1;
+                transitions.push(((jump - base) as u8) << 2);
+                offset -= jump - base;
+                while offset > MAX_TRANSITIONS {
+                    transitions.push((MAX_TRANSITIONS as u8) << 2);
+                    offset -= MAX_TRANSITIONS + 1;
+                }
+                offset -= 1;
+            }
+            transitions.push((offset as u8) << 2);
+        }
+
+        let initial = pos_to_index(self.encoded_tags[self.encoded_tags.len() - 1]);
+        (states, transitions, initial)
+    }
+}
+
+/// Returns the index of the state for the tag at `pos`, skipping the no-op
+/// states.
+fn pos_to_index(pos: usize) -> usize {
+    pos + 1 + pos / MAX_TRANSITIONS
+}
+
+/// Reports whether `record` parses as a non-empty protocol buffer message.
+fn is_proto_message(record: &[u8]) -> bool {
+    let mut input = Input::new(record);
+    let mut groups = Vec::new();
+    while !input.is_empty() {
+        let Ok(tag) = input.varint("field key") else {
+            return false;
+        };
+        let field = tag >> 3;
+        let valid = field != 0
+            && match tag & 7 {
+                VARINT_TYPE => input.varint("varint field").is_ok(),
+                FIXED32_TYPE => input.take(4, "fixed32 field").is_ok(),
+                FIXED64_TYPE => input.take(8, "fixed64 field").is_ok(),
+                BYTES_TYPE => input
+                    .size("delimited field size")
+                    .and_then(|size| input.take(size, "delimited field"))
+                    .is_ok(),
+                START_GROUP_TYPE => {
+                    groups.push(field);
+                    true
+                }
+                END_GROUP_TYPE => groups.pop() == Some(field),
+                _ => false,
+            };
+        if !valid {
+            return false;
+        }
+    }
+    !record.is_empty() && groups.is_empty()
+}
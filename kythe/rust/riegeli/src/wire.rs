@@ -0,0 +1,98 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Varint encoding and a cursor over encoded bytes.
+
+use crate::{Error, Result};
+
+/// The maximum length of a varint-encoded `u64`.
+pub(crate) const MAX_VARINT_LEN: usize = 10;
+
+/// Appends the varint encoding of `value` to `out`.
+pub(crate) fn put_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Returns the varint encoding of `value`.
+pub(crate) fn varint(value: u64) -> Vec<u8> {
+    let mut out = Vec::with_capacity(MAX_VARINT_LEN);
+    put_varint(&mut out, value);
+    out
+}
+
+/// A cursor over a byte slice, consumed from the front.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Input<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Input<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    /// Returns the number of bytes left.
+    pub(crate) fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the unread bytes, consuming them.
+    pub(crate) fn rest(&mut self) -> &'a [u8] {
+        std::mem::take(&mut self.data)
+    }
+
+    pub(crate) fn byte(&mut self, what: &str) -> Result<u8> {
+        Ok(self.take(1, what)?[0])
+    }
+
+    /// Consumes the next `n` bytes.
+    pub(crate) fn take(&mut self, n: usize, what: &str) -> Result<&'a [u8]> {
+        if n > self.data.len() {
+            return Err(Error::Corrupt(format!(
+                "reading {what}: want {n} bytes, have {}",
+                self.data.len()
+            )));
+        }
+        let (head, tail) = self.data.split_at(n);
+        self.data = tail;
+        Ok(head)
+    }
+
+    /// Consumes a varint-encoded `u64`.
+    pub(crate) fn varint(&mut self, what: &str) -> Result<u64> {
+        let mut value = 0u64;
+        for (i, &b) in self.data.iter().take(MAX_VARINT_LEN).enumerate() {
+            value |= u64::from(b & 0x7f) << (7 * i);
+            if b & 0x80 == 0 {
+                self.data = &self.data[i + 1..];
+                return Ok(value);
+            }
+        }
+        Err(Error::Corrupt(format!("reading {what}: bad varint")))
+    }
+
+    /// Consumes a varint-encoded size, checked to fit in a `usize`.
+    pub(crate) fn size(&mut self, what: &str) -> Result<usize> {
+        let size = self.varint(what)?;
+        usize::try_from(size).map_err(|_| Error::Corrupt(format!("{what} too large: {size}")))
+    }
+}
@@ -0,0 +1,296 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Writing of Riegeli files.
+
+use crate::chunk::{BlockWriter, Chunk, FILE_METADATA_CHUNK, SIMPLE_CHUNK, TRANSPOSED_CHUNK};
+use crate::compression::compress;
+use crate::wire::put_varint;
+use crate::{transpose, Compression, RecordPosition, Result, WriterOptions};
+use kythe_storage::EntrySink;
+use protobuf::Message;
+use records_metadata_rust_proto::RecordsMetadata;
+use std::io::Write;
+use storage_rust_proto::Entry;
+
+/// Writes records to a Riegeli file.
+///
+/// Records are buffered into chunks; [`Writer::flush`] or
+/// [`Writer::into_inner`] must be called to write the last chunk.
+pub struct Writer<W: Write> {
+    w: BlockWriter<W>,
+    options: WriterOptions,
+    header_written: bool,
+    chunk: ChunkEncoder,
+    num_records: u64,
+    decoded_size: u64,
+}
+
+impl<W: Write> Writer<W> {
+    pub fn new(w: W, options: WriterOptions) -> Self {
+        let chunk = ChunkEncoder::new(&options);
+        Self {
+            w: BlockWriter::new(w, 0),
+            options,
+            header_written: false,
+            chunk,
+            num_records: 0,
+            decoded_size: 0,
+        }
+    }
+
+    /// Buffers `record`, writing out the current chunk once it reaches the
+    /// configured size.
+    pub fn put_record(&mut self, record: &[u8]) -> Result<()> {
+        self.write_header()?;
+        self.chunk.put(record)?;
+        self.num_records += 1;
+        self.decoded_size += record.len() as u64;
+        if self.decoded_size >= self.options.chunk_size() {
+            self.write_chunk()?;
+        }
+        Ok(())
+    }
+
+    /// Buffers the wire encoding of `msg` as a single record.
+    pub fn put<M: Message>(&mut self, msg: &M) -> Result<()> {
+        self.put_record(&msg.write_to_bytes()?)
+    }
+
+    /// Writes any buffered records and flushes the underlying writer.
+    pub fn flush(&mut self) -> Result<()> {
+        self.write_header()?;
+        self.write_chunk()?;
+        Ok(self.w.get_mut().flush()?)
+    }
+
+    /// Returns the position that the next record will have.
+    pub fn position(&self) -> RecordPosition {
+        if !self.header_written {
+            return RecordPosition {
+                chunk_begin: self.w.position() + crate::chunk::BLOCK_HEADER_SIZE,
+                record_index: 0,
+            };
+        }
+        RecordPosition {
+            chunk_begin: self.w.position(),
+            record_index: self.num_records,
+        }
+    }
+
+    /// Writes any buffered records and returns the underlying writer.
+    pub fn into_inner(mut self) -> Result<W> {
+        self.flush()?;
+        Ok(self.w.into_inner())
+    }
+
+    /// Writes the file signature and a metadata chunk recording the options.
+    fn write_header(&mut self) -> Result<()> {
+        if self.header_written {
+            return Ok(());
+        }
+        self.w.write_chunk(&Chunk::signature())?;
+
+        let mut metadata = RecordsMetadata::new();
+        metadata.set_record_writer_options(self.options.to_string());
+        let record = metadata.write_to_bytes()?;
+        let mut encoder = transpose::Encoder::new(self.options.compression());
+        encoder.put(&record)?;
+        let chunk = Chunk::new(
+            FILE_METADATA_CHUNK,
+            encoder.encode()?,
+            0,
+            record.len() as u64,
+        );
+        self.w.write_chunk(&chunk)?;
+        self.header_written = true;
+        Ok(())
+    }
+
+    /// Writes the buffered records as a chunk, if there are any.
+    fn write_chunk(&mut self) -> Result<()> {
+        if self.num_records == 0 {
+            return Ok(());
+        }
+        let (chunk_type, data) = self.chunk.encode()?;
+        let chunk = Chunk::new(chunk_type, data, self.num_records, self.decoded_size);
+        self.w.write_chunk(&chunk)?;
+        self.num_records = 0;
+        self.decoded_size = 0;
+        Ok(())
+    }
+}
+
+impl<W: Write> EntrySink for Writer<W> {
+    fn write_entry(&mut self, entry: Entry) -> kythe_storage::Result<()> {
+        Ok(self.put(&entry)?)
+    }
+
+    fn flush(&mut self) -> kythe_storage::Result<()> {
+        Ok(Writer::flush(self)?)
+    }
+}
+
+/// Accumulates the records of a chunk.
+enum ChunkEncoder {
+    Simple {
+        compression: Compression,
+        sizes: Vec<u8>,
+        values: Vec<u8>,
+    },
+    Transposed(Box<transpose::Encoder>),
+}
+
+impl ChunkEncoder {
+    fn new(options: &WriterOptions) -> Self {
+        if options.transpose {
+            ChunkEncoder::Transposed(Box::new(transpose::Encoder::new(options.compression())))
+        } else {
+            ChunkEncoder::Simple {
+                compression: options.compression(),
+                sizes: Vec::new(),
+                values: Vec::new(),
+            }
+        }
+    }
+
+    fn put(&mut self, record: &[u8]) -> Result<()> {
+        match self {
+            ChunkEncoder::Simple { sizes, values, .. } => {
+                put_varint(sizes, record.len() as u64);
+                values.extend_from_slice(record);
+                Ok(())
+            }
+            ChunkEncoder::Transposed(encoder) => encoder.put(record),
+        }
+    }
+
+    /// Returns the chunk type and data of the buffered records, leaving the
+    /// encoder empty.
+    fn encode(&mut self) -> Result<(u8, Vec<u8>)> {
+        match self {
+            ChunkEncoder::Simple {
+                compression,
+                sizes,
+                values,
+            } => {
+                let sizes = compress(&std::mem::take(sizes), *compression)?;
+                let values = compress(&std::mem::take(values), *compression)?;
+                let mut data = vec![compression.type_byte()];
+                put_varint(&mut data, sizes.len() as u64);
+                data.extend_from_slice(&sizes);
+                data.extend_from_slice(&values);
+                Ok((SIMPLE_CHUNK, data))
+            }
+            ChunkEncoder::Transposed(encoder) => Ok((TRANSPOSED_CHUNK, encoder.encode()?)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{entries, Reader};
+    use analysis_rust_proto::CompilationUnit;
+    use std::io::Cursor;
+
+    const GOLDEN: &[u8] =
+        include_bytes!("../../../go/util/riegeli/testdata/golden.entries.uncompressed.riegeli");
+
+    fn golden_records() -> Vec<Vec<u8>> {
+        let mut records = Vec::new();
+        let mut unit = CompilationUnit::new();
+        unit.mut_v_name().set_corpus("kythe".into());
+        unit.mut_v_name().set_language("rust".into());
+        unit.mut_source_file().push("src/lib.rs".into());
+        unit.mut_argument().push("--edition=2021".into());
+        records.push(unit.write_to_bytes().unwrap());
+        // Enough entries to span several blocks.
+        for _ in 0..3 {
+            for entry in entries(Cursor::new(GOLDEN)) {
+                records.push(entry.unwrap().write_to_bytes().unwrap());
+            }
+        }
+        records.push(b"not a \xffproto".to_vec());
+        records.push(Vec::new());
+        records
+    }
+
+    #[test]
+    fn round_trip() {
+        let records = golden_records();
+        for options in [
+            "uncompressed",
+            "uncompressed,transpose",
+            "brotli",
+            "brotli:4,transpose",
+            "zstd",
+            "transpose,zstd:3",
+            "snappy",
+            "snappy,transpose",
+            "chunk_size:5000,transpose,uncompressed",
+            "chunk_size:5000,snappy",
+        ] {
+            let options: WriterOptions = options.parse().unwrap();
+            let mut writer = Writer::new(Vec::new(), options.clone());
+            for record in &records {
+                writer.put_record(record).unwrap();
+            }
+            let data = writer.into_inner().unwrap();
+
+            let mut reader = Reader::new(Cursor::new(&data));
+            assert_eq!(
+                reader
+                    .records_metadata()
+                    .unwrap()
+                    .get_record_writer_options(),
+                options.to_string()
+            );
+            let mut found = Vec::new();
+            while let Some(record) = reader.next_record().unwrap() {
+                found.push(record.to_vec());
+            }
+            assert_eq!(found.len(), records.len(), "{options}");
+            assert!(found == records, "{options}");
+            let unit = CompilationUnit::parse_from_bytes(&found[0]).unwrap();
+            assert_eq!(unit.get_source_file(), ["src/lib.rs"]);
+        }
+    }
+
+    #[test]
+    fn seek_across_chunks() {
+        let records = golden_records();
+        let options: WriterOptions = "chunk_size:2000,transpose,uncompressed".parse().unwrap();
+        let mut writer = Writer::new(Vec::new(), options);
+        // The position of the first record is only known once the file
+        // header is written.
+        writer.put_record(&records[0]).unwrap();
+        let mut positions = Vec::new();
+        for record in &records[1..] {
+            positions.push(writer.position());
+            writer.put_record(record).unwrap();
+        }
+        let data = writer.into_inner().unwrap();
+        assert!(data.len() as u64 > crate::chunk::BLOCK_SIZE);
+
+        let mut reader = Reader::new(Cursor::new(&data));
+        for (pos, record) in positions.iter().zip(&records[1..]).rev().step_by(7) {
+            reader.seek_to_record(*pos).unwrap();
+            assert_eq!(reader.next_record().unwrap(), Some(&record[..]), "{pos:?}");
+            reader.seek(pos.index()).unwrap();
+            assert_eq!(reader.position().unwrap(), *pos);
+            assert_eq!(reader.next_record().unwrap(), Some(&record[..]), "{pos:?}");
+        }
+    }
+}
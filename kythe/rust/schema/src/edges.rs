@@ -0,0 +1,250 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Constants and helpers for Kythe edge kinds.
+
+/// The common prefix for all Kythe edge kinds.
+pub const PREFIX: &str = "/kythe/edge/";
+
+// Edge kind labels.
+pub const CHILD_OF: &str = "/kythe/edge/childof";
+pub const COMPLETED_BY: &str = "/kythe/edge/completedby";
+pub const EXTENDS: &str = "/kythe/edge/extends";
+pub const EXTENDS_PRIVATE: &str = "/kythe/edge/extends/private";
+pub const EXTENDS_PRIVATE_VIRTUAL: &str = "/kythe/edge/extends/private/virtual";
+pub const EXTENDS_PROTECTED: &str = "/kythe/edge/extends/protected";
+pub const EXTENDS_PROTECTED_VIRTUAL: &str = "/kythe/edge/extends/protected/virtual";
+pub const EXTENDS_PUBLIC: &str = "/kythe/edge/extends/public";
+pub const EXTENDS_PUBLIC_VIRTUAL: &str = "/kythe/edge/extends/public/virtual";
+pub const EXTENDS_VIRTUAL: &str = "/kythe/edge/extends/virtual";
+pub const GENERATES: &str = "/kythe/edge/generates";
+pub const NAMED: &str = "/kythe/edge/named";
+pub const OVERRIDES: &str = "/kythe/edge/overrides";
+pub const OVERRIDES_TRANSITIVE: &str = "/kythe/edge/overrides/transitive";
+pub const PARAM: &str = "/kythe/edge/param";
+pub const SATISFIES: &str = "/kythe/edge/satisfies";
+pub const TPARAM: &str = "/kythe/edge/tparam";
+pub const TYPED: &str = "/kythe/edge/typed";
+
+// Edge kinds associated with anchors.
+pub const DEFINES: &str = "/kythe/edge/defines";
+pub const DEFINES_BINDING: &str = "/kythe/edge/defines/binding";
+pub const DOCUMENTS: &str = "/kythe/edge/documents";
+pub const IMPUTES: &str = "/kythe/edge/imputes";
+pub const REF: &str = "/kythe/edge/ref";
+pub const REF_CALL: &str = "/kythe/edge/ref/call";
+pub const REF_IMPLICIT: &str = "/kythe/edge/ref/implicit";
+pub const REF_CALL_IMPLICIT: &str = "/kythe/edge/ref/call/implicit";
+pub const REF_IMPORTS: &str = "/kythe/edge/ref/imports";
+pub const REF_INIT: &str = "/kythe/edge/ref/init";
+pub const REF_INIT_IMPLICIT: &str = "/kythe/edge/ref/init/implicit";
+pub const REF_WRITES: &str = "/kythe/edge/ref/writes";
+pub const TAGGED: &str = "/kythe/edge/tagged";
+
+/// The edge kinds defined by the Kythe schema (`kythe/proto/schema.proto`).
+pub const SCHEMA_KINDS: &[&str] = &[
+    "/kythe/edge/aliases",
+    "/kythe/edge/aliases/root",
+    "/kythe/edge/annotatedby",
+    "/kythe/edge/bounded/lower",
+    "/kythe/edge/bounded/upper",
+    "/kythe/edge/childof",
+    "/kythe/edge/childof/context",
+    "/kythe/edge/completedby",
+    "/kythe/edge/defines",
+    "/kythe/edge/defines/binding",
+    "/kythe/edge/defines/implicit",
+    "/kythe/edge/depends",
+    "/kythe/edge/documents",
+    "/kythe/edge/exports",
+    "/kythe/edge/extends",
+    "/kythe/edge/generates",
+    "/kythe/edge/imputes",
+    "/kythe/edge/instantiates",
+    "/kythe/edge/instantiates/speculative",
+    "/kythe/edge/named",
+    "/kythe/edge/overrides",
+    "/kythe/edge/overrides/root",
+    "/kythe/edge/overrides/transitive",
+    "/kythe/edge/param",
+    "/kythe/edge/property/reads",
+    "/kythe/edge/property/writes",
+    "/kythe/edge/ref",
+    "/kythe/edge/ref/call",
+    "/kythe/edge/ref/call/implicit",
+    "/kythe/edge/ref/doc",
+    "/kythe/edge/ref/expands",
+    "/kythe/edge/ref/expands/transitive",
+    "/kythe/edge/ref/file",
+    "/kythe/edge/ref/id",
+    "/kythe/edge/ref/implicit",
+    "/kythe/edge/ref/imports",
+    "/kythe/edge/ref/includes",
+    "/kythe/edge/ref/init",
+    "/kythe/edge/ref/init/implicit",
+    "/kythe/edge/ref/queries",
+    "/kythe/edge/ref/writes",
+    "/kythe/edge/satisfies",
+    "/kythe/edge/specializes",
+    "/kythe/edge/specializes/speculative",
+    "/kythe/edge/tagged",
+    "/kythe/edge/tparam",
+    "/kythe/edge/typed",
+    "/kythe/edge/undefines",
+];
+
+/// Distinguishes reverse edge kinds from forward ones.
+const REV_PREFIX: &str = "%";
+
+/// Returns an edge label of the form `kind.ordinal`.
+pub fn ordinal(kind: &str, ordinal: u32) -> String {
+    format!("{kind}.{ordinal}")
+}
+
+/// Returns an edge label of the form `param.i` for the `i` given.
+pub fn param_index(i: u32) -> String {
+    ordinal(PARAM, i)
+}
+
+/// Returns an edge label of the form `tparam.i` for the `i` given.
+pub fn tparam_index(i: u32) -> String {
+    ordinal(TPARAM, i)
+}
+
+/// Returns the opposite-directional edge label for `kind`.
+pub fn mirror(kind: &str) -> String {
+    match kind.strip_prefix(REV_PREFIX) {
+        Some(forward) => forward.to_owned(),
+        None => format!("{REV_PREFIX}{kind}"),
+    }
+}
+
+/// Returns the canonical forward version of an edge kind.
+pub fn canonical(kind: &str) -> &str {
+    kind.strip_prefix(REV_PREFIX).unwrap_or(kind)
+}
+
+/// Reports whether `kind` is a forward edge kind.
+pub fn is_forward(kind: &str) -> bool {
+    !is_reverse(kind)
+}
+
+/// Reports whether `kind` is a reverse edge kind.
+pub fn is_reverse(kind: &str) -> bool {
+    kind.starts_with(REV_PREFIX)
+}
+
+/// Reports whether `x` is equal to or a subkind of `y`, e.g.
+/// `is_variant(DEFINES_BINDING, DEFINES)` is true.
+pub fn is_variant(x: &str, y: &str) -> bool {
+    x.strip_prefix(y)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+/// Reports whether `kind` is one associated with anchors.
+pub fn is_anchor_edge(kind: &str) -> bool {
+    let canon = canonical(kind);
+    [DEFINES, DOCUMENTS, REF]
+        .iter()
+        .any(|base| is_variant(canon, base))
+}
+
+/// Splits an ordinal suffix (`.nnn`) from `kind`, returning the base kind and
+/// the ordinal if one is present. Kinds without an ordinal are returned as
+/// written.
+pub fn parse_ordinal(kind: &str) -> (&str, Option<u32>) {
+    if let Some((base, suffix)) = kind.rsplit_once('.') {
+        if !base.is_empty() && !suffix.is_empty() && suffix.bytes().all(|b| b.is_ascii_digit()) {
+            if let Ok(ordinal) = suffix.parse() {
+                return (base, Some(ordinal));
+            }
+        }
+    }
+    (kind, None)
+}
+
+/// Reports whether `kind` (which does not have an ordinal suffix) generally
+/// has an associated ordinal.
+pub fn is_ordinal_kind(kind: &str) -> bool {
+    matches!(canonical(kind), PARAM | TPARAM)
+}
+
+/// The documented subkinds of `extends`, which the schema leaves to the
+/// languages that need them.
+const EXTENDS_SUBKINDS: &[&str] = &[
+    EXTENDS_PRIVATE,
+    EXTENDS_PRIVATE_VIRTUAL,
+    EXTENDS_PROTECTED,
+    EXTENDS_PROTECTED_VIRTUAL,
+    EXTENDS_PUBLIC,
+    EXTENDS_PUBLIC_VIRTUAL,
+    EXTENDS_VIRTUAL,
+];
+
+/// Reports whether `kind` is a forward schema edge kind or a documented
+/// subkind of one, ignoring any ordinal suffix.
+pub fn is_schema_kind(kind: &str) -> bool {
+    let (base, _) = parse_ordinal(kind);
+    SCHEMA_KINDS.contains(&base) || EXTENDS_SUBKINDS.contains(&base)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mirror_round_trips() {
+        assert_eq!(mirror(CHILD_OF), "%/kythe/edge/childof");
+        assert_eq!(mirror(&mirror(CHILD_OF)), CHILD_OF);
+        assert_eq!(canonical("%/kythe/edge/ref"), REF);
+        assert!(is_reverse("%/kythe/edge/ref"));
+        assert!(is_forward(REF));
+    }
+
+    #[test]
+    fn variants() {
+        assert!(is_variant(DEFINES_BINDING, DEFINES));
+        assert!(is_variant(DEFINES, DEFINES));
+        assert!(!is_variant(DEFINES, DEFINES_BINDING));
+        assert!(!is_variant("/kythe/edge/definesx", DEFINES));
+        assert!(is_anchor_edge(REF_CALL_IMPLICIT));
+        assert!(is_anchor_edge(&mirror(DOCUMENTS)));
+        assert!(!is_anchor_edge(CHILD_OF));
+    }
+
+    #[test]
+    fn ordinals() {
+        assert_eq!(param_index(3), "/kythe/edge/param.3");
+        assert_eq!(parse_ordinal("/kythe/edge/param.3"), (PARAM, Some(3)));
+        assert_eq!(parse_ordinal(PARAM), (PARAM, None));
+        assert_eq!(
+            parse_ordinal("/kythe/edge/param."),
+            ("/kythe/edge/param.", None)
+        );
+        assert_eq!(parse_ordinal(".1"), (".1", None));
+        assert!(is_ordinal_kind(PARAM));
+        assert!(!is_ordinal_kind(REF));
+    }
+
+    #[test]
+    fn schema_kinds() {
+        assert!(is_schema_kind(EXTENDS_PUBLIC_VIRTUAL));
+        assert!(is_schema_kind("/kythe/edge/param.12"));
+        assert!(is_schema_kind("/kythe/edge/ref/expands/transitive"));
+        assert!(!is_schema_kind("/kythe/edge/refx"));
+        assert!(!is_schema_kind("/kythe/edge/ref/bogus"));
+        assert!(!is_schema_kind("/kythe/edge/extends/bogus"));
+        assert!(!is_schema_kind(&mirror(REF)));
+    }
+}
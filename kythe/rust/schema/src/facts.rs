@@ -0,0 +1,98 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Constants for Kythe facts.
+
+// Node fact labels.
+pub const ANCHOR_END: &str = "/kythe/loc/end";
+pub const ANCHOR_START: &str = "/kythe/loc/start";
+pub const BUILD_CONFIG: &str = "/kythe/build/config";
+pub const CODE: &str = "/kythe/code";
+pub const COMPLETE: &str = "/kythe/complete";
+pub const CONTEXT_URL: &str = "/kythe/context/url";
+pub const DEPRECATED: &str = "/kythe/tag/deprecated";
+pub const DETAILS: &str = "/kythe/details";
+pub const DOC_URI: &str = "/kythe/doc/uri";
+pub const MESSAGE: &str = "/kythe/message";
+pub const NODE_KIND: &str = "/kythe/node/kind";
+pub const PARAM_DEFAULT: &str = "/kythe/param/default";
+pub const SEMANTIC_GENERATED: &str = "/kythe/semantic/generated";
+pub const SNIPPET_END: &str = "/kythe/snippet/end";
+pub const SNIPPET_START: &str = "/kythe/snippet/start";
+pub const SUBKIND: &str = "/kythe/subkind";
+pub const TEXT: &str = "/kythe/text";
+pub const TEXT_ENCODING: &str = "/kythe/text/encoding";
+
+/// The implicit value for [`TEXT_ENCODING`] if it is empty or missing from a
+/// node with a [`TEXT`] fact.
+pub const DEFAULT_TEXT_ENCODING: &str = "UTF-8";
+
+/// Reports whether `name` is a well-formed fact name.
+///
+/// The grammar for fact names, from `storage.proto`, is:
+///
+/// ```text
+/// name   = "/" | 1*path
+/// path   = "/" word
+/// word   = 1*{LETTER|DIGIT|PUNCT}
+/// LETTER = [A-Za-z]
+/// DIGIT  = [0-9]
+/// PUNCT  = [-.@#$%&_+:()]
+/// ```
+pub fn is_valid_name(name: &str) -> bool {
+    if name == "/" {
+        return true;
+    }
+    let Some(rest) = name.strip_prefix('/') else {
+        return false;
+    };
+    rest.split('/').all(|word| {
+        !word.is_empty()
+            && word
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b"-.@#$%&_+:()".contains(&b))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_names() {
+        for name in [
+            "/",
+            NODE_KIND,
+            ANCHOR_START,
+            "/x",
+            "/kythe/a.b@c#d$e%f&g_h+i:j(k)l-m",
+        ] {
+            assert!(is_valid_name(name), "{name:?} should be valid");
+        }
+    }
+
+    #[test]
+    fn invalid_names() {
+        for name in [
+            "",
+            "kythe/node/kind",
+            "//",
+            "/kythe/",
+            "/kythe//kind",
+            "/kythe/node kind",
+        ] {
+            assert!(!is_valid_name(name), "{name:?} should be invalid");
+        }
+    }
+}
@@ -0,0 +1,34 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Constants and helpers describing the Kythe graph schema.
+//!
+//! This mirrors `kythe/go/util/schema` and its `facts`, `edges` and `nodes`
+//! subpackages.
+
+pub mod edges;
+pub mod facts;
+pub mod nodes;
+
+/// The label prefix for the Kythe schema.
+pub const PREFIX: &str = "/kythe/";
+
+/// The fact name used by entries that describe an edge.
+pub const EDGE_FACT_NAME: &str = "/";
+
+/// A fact filter matching anchor locations.
+pub const ANCHOR_LOC_FILTER: &str = "/kythe/loc/*";
+
+/// A fact filter matching snippet locations.
+pub const SNIPPET_LOC_FILTER: &str = "/kythe/snippet/*";
@@ -0,0 +1,76 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Constants for Kythe node kinds and subkinds.
+
+// Node kind labels.
+pub const ABS: &str = "abs";
+pub const ANCHOR: &str = "anchor";
+pub const CONSTANT: &str = "constant";
+pub const DIAGNOSTIC: &str = "diagnostic";
+pub const DOC: &str = "doc";
+pub const FILE: &str = "file";
+pub const FUNCTION: &str = "function";
+pub const INTERFACE: &str = "interface";
+pub const NAME: &str = "name";
+pub const PACKAGE: &str = "package";
+pub const RECORD: &str = "record";
+pub const SYMBOL: &str = "symbol";
+pub const TALIAS: &str = "talias";
+pub const TAPP: &str = "tapp";
+pub const TBUILTIN: &str = "tbuiltin";
+pub const TNOMINAL: &str = "tnominal";
+pub const TVAR: &str = "tvar";
+pub const VARIABLE: &str = "variable";
+
+// Node subkinds.
+pub const CLASS: &str = "class";
+pub const ENUM: &str = "enum";
+pub const ENUM_CLASS: &str = "enumClass";
+pub const FIELD: &str = "field";
+pub const IMPLICIT: &str = "implicit";
+pub const LOCAL: &str = "local";
+pub const LOCAL_PARAMETER: &str = "local/parameter";
+pub const STRUCT: &str = "struct";
+pub const TYPE: &str = "type";
+pub const UNION: &str = "union";
+
+/// The node kinds defined by the Kythe schema (`kythe/proto/schema.proto`).
+pub const SCHEMA_KINDS: &[&str] = &[
+    "anchor",
+    "constant",
+    "diagnostic",
+    "doc",
+    "file",
+    "google/gflag",
+    "interface",
+    "function",
+    "lookup",
+    "macro",
+    "meta",
+    "name",
+    "package",
+    "process",
+    "record",
+    "sum",
+    "symbol",
+    "talias",
+    "tapp",
+    "tvar",
+    "tbuiltin",
+    "tnominal",
+    "tsigma",
+    "variable",
+    "vcs",
+];
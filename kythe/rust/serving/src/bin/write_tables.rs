@@ -0,0 +1,72 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Builds xrefs serving tables from a stream of delimited Kythe entries.
+//!
+//! Usage: write_tables --out=TABLE [--max_page_size=N] [FILE...]
+//!
+//! Entries are read from the given files, or from stdin if none are given.
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use kythe_serving::{build_tables, LocalTable, Options};
+use kythe_storage::{delimited, MemoryStore};
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::path::PathBuf;
+
+#[derive(Parser)]
+#[command(about = "Builds xrefs serving tables from delimited Kythe entries")]
+struct Args {
+    /// Path of the table to write.
+    #[arg(long)]
+    out: PathBuf,
+
+    /// Maximum number of cross-references in a set before it is paged; 0
+    /// disables paging.
+    #[arg(long = "max_page_size", default_value_t = kythe_serving::pipeline::DEFAULT_MAX_PAGE_SIZE)]
+    max_page_size: usize,
+
+    /// Delimited entry files to read; stdin is read if none are given.
+    files: Vec<PathBuf>,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let mut entries = Vec::new();
+    if args.files.is_empty() {
+        for entry in delimited::entries(io::stdin().lock()) {
+            entries.push(entry.context("reading stdin")?);
+        }
+    }
+    for path in &args.files {
+        let f = File::open(path).with_context(|| format!("opening {}", path.display()))?;
+        for entry in delimited::entries(BufReader::new(f)) {
+            entries.push(entry.with_context(|| format!("reading {}", path.display()))?);
+        }
+    }
+    // Sorting once is much cheaper than inserting each entry in order.
+    let store = MemoryStore::from_iter(entries);
+
+    let mut table = LocalTable::new();
+    let opts = Options {
+        max_page_size: args.max_page_size,
+    };
+    let stats = build_tables(&store, &mut table, &opts).context("building tables")?;
+    table
+        .save(&args.out)
+        .with_context(|| format!("writing {}", args.out.display()))?;
+    eprintln!("write_tables: {stats:?}");
+    Ok(())
+}
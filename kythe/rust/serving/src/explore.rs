@@ -0,0 +1,591 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An implementation of the `ExploreService` API over the relations between
+//! semantic nodes in a GraphStore: calls, containment (`childof`), type
+//! hierarchy (`extends` and `satisfies`) and function parameters.
+
+use crate::span::Normalizer;
+use crate::xrefs::{fix_tickets, recast};
+use crate::{Error, Result};
+use explore_rust_proto::{
+    CalleesReply, CalleesRequest, CallersReply, CallersRequest, ChildrenReply, ChildrenRequest,
+    Graph, GraphNode, Location, Location_Kind, MarkedSource, NodeData, NodeFilter, ParametersReply,
+    ParametersRequest, ParentsReply, ParentsRequest, Tickets, TypeHierarchyReply,
+    TypeHierarchyRequest,
+};
+use kythe_schema::{edges, facts, nodes};
+use kythe_storage::GraphStore;
+use protobuf::{Message, RepeatedField};
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use std::ops::ControlFlow;
+use storage_rust_proto::{Entry, ScanRequest, VName};
+
+/// The Kythe graph exploration service.
+pub trait ExploreService {
+    /// Returns the transitive callers of a set of functions.
+    fn callers(&self, req: &CallersRequest) -> Result<CallersReply>;
+
+    /// Returns the transitive callees of a set of functions.
+    fn callees(&self, req: &CalleesRequest) -> Result<CalleesReply>;
+
+    /// Returns the enclosing contexts of a set of nodes.
+    fn parents(&self, req: &ParentsRequest) -> Result<ParentsReply>;
+
+    /// Returns the nodes enclosed by a set of nodes.
+    fn children(&self, req: &ChildrenRequest) -> Result<ChildrenReply>;
+
+    /// Returns the supertypes and subtypes of a type.
+    fn type_hierarchy(&self, req: &TypeHierarchyRequest) -> Result<TypeHierarchyReply>;
+
+    /// Returns the parameters and return values of a set of functions.
+    fn parameters(&self, req: &ParametersRequest) -> Result<ParametersReply>;
+}
+
+/// The facts of a node relevant to exploration.
+#[derive(Default)]
+struct Node {
+    kind: String,
+    subkind: String,
+    code: Option<Vec<u8>>,
+    start: Option<usize>,
+    end: Option<usize>,
+    text: Option<Vec<u8>>,
+}
+
+/// The normalizers of file texts, by file ticket.
+type Normalizers = HashMap<String, Normalizer>;
+
+/// A relation between nodes, indexed in both directions.
+#[derive(Default)]
+struct Relation {
+    forward: BTreeMap<String, BTreeSet<String>>,
+    reverse: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl Relation {
+    fn insert(&mut self, from: &str, to: &str) {
+        self.forward
+            .entry(from.into())
+            .or_default()
+            .insert(to.into());
+        self.reverse
+            .entry(to.into())
+            .or_default()
+            .insert(from.into());
+    }
+}
+
+/// Which way to follow a relation when building a graph.
+#[derive(Clone, Copy)]
+enum Direction {
+    Forward,
+    Reverse,
+}
+
+/// An in-memory index of the relations between the nodes of a GraphStore.
+#[derive(Default)]
+pub struct Explorer {
+    nodes: HashMap<String, Node>,
+    /// The VNames of the nodes with outbound entries, by ticket.
+    vnames: HashMap<String, VName>,
+    calls: Relation,
+    child_of: Relation,
+    extends: Relation,
+    /// The parameters of each function, by ordinal.
+    params: BTreeMap<String, BTreeMap<u32, String>>,
+    typed: HashMap<String, String>,
+    /// The anchors defining each node.
+    definitions: BTreeMap<String, BTreeSet<String>>,
+    /// The (anchor, callee) pairs of `ref/call` edges, resolved into `calls`
+    /// once the anchors' parents are known.
+    call_sites: Vec<(String, String)>,
+}
+
+impl Explorer {
+    /// Builds an index of the entries of `store`.
+    pub fn new(store: &dyn GraphStore) -> Result<Self> {
+        let mut explorer = Explorer::default();
+        store.scan(&ScanRequest::new(), &mut |e| {
+            explorer.add_entry(e);
+            ControlFlow::Continue(())
+        })?;
+        explorer.finish();
+        Ok(explorer)
+    }
+
+    fn add_entry(&mut self, e: &Entry) {
+        let source = kythe_uri::to_string(e.get_source());
+        self.vnames
+            .entry(source.clone())
+            .or_insert_with(|| e.get_source().clone());
+        if e.get_edge_kind().is_empty() {
+            let node = self.nodes.entry(source).or_default();
+            let value = e.get_fact_value();
+            let number = || std::str::from_utf8(value).ok()?.parse().ok();
+            match e.get_fact_name() {
+                facts::NODE_KIND => node.kind = String::from_utf8_lossy(value).into_owned(),
+                facts::SUBKIND => node.subkind = String::from_utf8_lossy(value).into_owned(),
+                facts::CODE => node.code = Some(value.to_vec()),
+                facts::ANCHOR_START => node.start = number(),
+                facts::ANCHOR_END => node.end = number(),
+                facts::TEXT => node.text = Some(value.to_vec()),
+                _ => {}
+            }
+            return;
+        }
+        let target = kythe_uri::to_string(e.get_target());
+        let (kind, ordinal) = edges::parse_ordinal(e.get_edge_kind());
+        let kind = edges::canonical(kind);
+        if edges::is_variant(kind, edges::REF_CALL) {
+            self.call_sites.push((source, target));
+        } else if kind == edges::CHILD_OF {
+            self.child_of.insert(&source, &target);
+        } else if edges::is_variant(kind, edges::EXTENDS) || kind == edges::SATISFIES {
+            self.extends.insert(&source, &target);
+        } else if kind == edges::PARAM {
+            self.params
+                .entry(source)
+                .or_default()
+                .insert(ordinal.unwrap_or_default(), target);
+        } else if kind == edges::TYPED {
+            self.typed.insert(source, target);
+        } else if edges::is_variant(kind, edges::DEFINES) {
+            self.definitions.entry(target).or_default().insert(source);
+        }
+    }
+
+    /// Resolves call sites to their enclosing functions and removes anchors
+    /// from the containment relation.
+    fn finish(&mut self) {
+        for (anchor, callee) in std::mem::take(&mut self.call_sites) {
+            for caller in self.child_of.forward.get(&anchor).into_iter().flatten() {
+                self.calls.insert(caller, &callee);
+            }
+        }
+        let mut child_of = Relation::default();
+        for (child, parents) in &self.child_of.forward {
+            if !self.is_anchor(child) {
+                for parent in parents {
+                    child_of.insert(child, parent);
+                }
+            }
+        }
+        self.child_of = child_of;
+    }
+
+    fn is_anchor(&self, ticket: &str) -> bool {
+        self.nodes
+            .get(ticket)
+            .is_some_and(|n| n.kind == nodes::ANCHOR)
+    }
+
+    /// Returns the data of a node, including the locations of the anchors
+    /// defining it. `norms` caches the normalizers of the files seen so far
+    /// in the request.
+    fn node_data(&self, ticket: &str, norms: &mut Normalizers) -> Result<NodeData> {
+        let mut data = NodeData::new();
+        let Some(node) = self.nodes.get(ticket) else {
+            return Ok(data);
+        };
+        data.set_kind(node.kind.clone());
+        data.set_subkind(node.subkind.clone());
+        // A malformed code fact is left out rather than failing the request.
+        if let Some(code) = node
+            .code
+            .as_deref()
+            .and_then(|code| MarkedSource::parse_from_bytes(code).ok())
+        {
+            data.set_code(code);
+        }
+        for anchor in self.definitions.get(ticket).into_iter().flatten() {
+            if data.get_definition_anchor().is_empty() {
+                data.set_definition_anchor(anchor.clone());
+            }
+            if let Some(loc) = self.location(anchor, norms)? {
+                data.mut_locations().push(loc);
+            }
+        }
+        Ok(data)
+    }
+
+    /// Returns the span of an anchor within its file.
+    fn location(&self, anchor: &str, norms: &mut Normalizers) -> Result<Option<Location>> {
+        let (Some(node), Some(vname)) = (self.nodes.get(anchor), self.vnames.get(anchor)) else {
+            return Ok(None);
+        };
+        let (Some(start), Some(end)) = (node.start, node.end) else {
+            return Ok(None);
+        };
+        let mut file = VName::new();
+        file.set_corpus(vname.get_corpus().into());
+        file.set_root(vname.get_root().into());
+        file.set_path(vname.get_path().into());
+        let file = kythe_uri::to_string(&file);
+        let mut loc = Location::new();
+        match self.nodes.get(&file).and_then(|f| f.text.as_deref()) {
+            Some(text) => {
+                let norm = norms
+                    .entry(file.clone())
+                    .or_insert_with(|| Normalizer::new(text));
+                loc.set_span(recast(&norm.span(start, end))?);
+            }
+            None => {
+                let span = loc.mut_span();
+                span.mut_start().set_byte_offset(start as i32);
+                span.mut_end().set_byte_offset(end as i32);
+            }
+        }
+        loc.set_ticket(file);
+        loc.set_kind(Location_Kind::SPAN);
+        Ok(Some(loc))
+    }
+
+    /// Reports whether a node passes a request's node filter.
+    fn matches(&self, ticket: &str, filter: &NodeFilter) -> bool {
+        let langs = filter.get_included_languages();
+        let files = filter.get_included_files();
+        let Ok(v) = kythe_uri::to_vname(ticket) else {
+            return false;
+        };
+        if !langs.is_empty() && !langs.iter().any(|l| l == v.get_language()) {
+            return false;
+        }
+        files.is_empty()
+            || self
+                .definitions
+                .get(ticket)
+                .into_iter()
+                .flatten()
+                .filter_map(|anchor| self.vnames.get(anchor))
+                .any(|a| {
+                    files.iter().any(|f| {
+                        (f.get_corpus(), f.get_root(), f.get_path())
+                            == (a.get_corpus(), a.get_root(), a.get_path())
+                    })
+                })
+    }
+
+    /// Returns the graph of the nodes reachable from `starts` by following
+    /// each relation in its direction, skipping nodes rejected by `keep`.
+    /// Edges in the graph always point along the relation's forward
+    /// direction.
+    fn graph(
+        &self,
+        starts: &[String],
+        relations: &[(&Relation, Direction)],
+        keep: &dyn Fn(&str) -> bool,
+    ) -> Result<Graph> {
+        let mut topology: BTreeMap<String, (BTreeSet<String>, BTreeSet<String>)> = BTreeMap::new();
+        for start in starts {
+            topology.entry(start.clone()).or_default();
+        }
+        for &(relation, direction) in relations {
+            let next = match direction {
+                Direction::Forward => &relation.forward,
+                Direction::Reverse => &relation.reverse,
+            };
+            let mut queue: VecDeque<&String> = starts.iter().collect();
+            let mut seen: BTreeSet<&String> = starts.iter().collect();
+            while let Some(n) = queue.pop_front() {
+                for m in next.get(n).into_iter().flatten() {
+                    if !keep(m) {
+                        continue;
+                    }
+                    let (from, to) = match direction {
+                        Direction::Forward => (n, m),
+                        Direction::Reverse => (m, n),
+                    };
+                    topology
+                        .entry(from.clone())
+                        .or_default()
+                        .1
+                        .insert(to.clone());
+                    topology
+                        .entry(to.clone())
+                        .or_default()
+                        .0
+                        .insert(from.clone());
+                    if seen.insert(m) {
+                        queue.push_back(m);
+                    }
+                }
+            }
+        }
+        let mut graph = Graph::new();
+        let mut norms = Normalizers::new();
+        for (ticket, (predecessors, successors)) in topology {
+            let mut node = GraphNode::new();
+            node.set_node_data(self.node_data(&ticket, &mut norms)?);
+            node.set_predecessors(predecessors.into_iter().collect());
+            node.set_successors(successors.into_iter().collect());
+            graph.mut_nodes().insert(ticket, node);
+        }
+        Ok(graph)
+    }
+}
+
+/// Returns the tickets related to each of `tickets`, present even when
+/// there are none.
+fn related(
+    tickets: &[String],
+    relation: &BTreeMap<String, BTreeSet<String>>,
+) -> Result<HashMap<String, Tickets>> {
+    let mut out = HashMap::new();
+    for ticket in fix_tickets(tickets)? {
+        let mut t = Tickets::new();
+        t.set_tickets(
+            relation
+                .get(&ticket)
+                .into_iter()
+                .flatten()
+                .cloned()
+                .collect(),
+        );
+        out.insert(ticket, t);
+    }
+    Ok(out)
+}
+
+impl ExploreService for Explorer {
+    fn callers(&self, req: &CallersRequest) -> Result<CallersReply> {
+        let tickets = fix_tickets(req.get_tickets())?;
+        let mut reply = CallersReply::new();
+        reply.set_graph(self.graph(&tickets, &[(&self.calls, Direction::Reverse)], &|_| true)?);
+        Ok(reply)
+    }
+
+    fn callees(&self, req: &CalleesRequest) -> Result<CalleesReply> {
+        let tickets = fix_tickets(req.get_tickets())?;
+        let mut reply = CalleesReply::new();
+        reply.set_graph(self.graph(&tickets, &[(&self.calls, Direction::Forward)], &|_| true)?);
+        Ok(reply)
+    }
+
+    fn parents(&self, req: &ParentsRequest) -> Result<ParentsReply> {
+        let mut reply = ParentsReply::new();
+        reply.set_input_to_parents(related(req.get_tickets(), &self.child_of.forward)?);
+        Ok(reply)
+    }
+
+    fn children(&self, req: &ChildrenRequest) -> Result<ChildrenReply> {
+        let mut reply = ChildrenReply::new();
+        reply.set_input_to_children(related(req.get_tickets(), &self.child_of.reverse)?);
+        Ok(reply)
+    }
+
+    fn type_hierarchy(&self, req: &TypeHierarchyRequest) -> Result<TypeHierarchyReply> {
+        if req.get_type_ticket().is_empty() {
+            return Err(Error::InvalidArgument("missing type ticket".into()));
+        }
+        let tickets = fix_tickets(&[req.get_type_ticket().to_owned()])?;
+        let filter = req.get_node_filter();
+        let graph = self.graph(
+            &tickets,
+            &[
+                (&self.extends, Direction::Forward),
+                (&self.extends, Direction::Reverse),
+            ],
+            &|t| self.matches(t, filter),
+        )?;
+        let mut reply = TypeHierarchyReply::new();
+        reply.set_type_ticket(tickets[0].clone());
+        reply.set_graph(graph);
+        Ok(reply)
+    }
+
+    fn parameters(&self, req: &ParametersRequest) -> Result<ParametersReply> {
+        let mut reply = ParametersReply::new();
+        let mut norms = Normalizers::new();
+        for function in fix_tickets(req.get_function_tickets())? {
+            let params: Vec<String> = self
+                .params
+                .get(&function)
+                .into_iter()
+                .flat_map(|p| p.values().cloned())
+                .collect();
+            for p in &params {
+                reply
+                    .mut_node_data()
+                    .insert(p.clone(), self.node_data(p, &mut norms)?);
+            }
+            let mut t = Tickets::new();
+            t.set_tickets(RepeatedField::from_vec(params));
+            reply
+                .mut_function_to_parameters()
+                .insert(function.clone(), t);
+
+            // The type of a function applies the `fn` builtin to its return
+            // type and then its parameter types.
+            let ret = self
+                .typed
+                .get(&function)
+                .and_then(|ty| self.params.get(ty))
+                .and_then(|args| args.get(&1));
+            if let Some(ret) = ret {
+                reply
+                    .mut_node_data()
+                    .insert(ret.clone(), self.node_data(ret, &mut norms)?);
+                reply
+                    .mut_function_to_return_value()
+                    .insert(function.clone(), ret.clone());
+            }
+            let data = self.node_data(&function, &mut norms)?;
+            reply.mut_node_data().insert(function, data);
+        }
+        Ok(reply)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testdata::{self, ticket, vname};
+    use kythe_storage::emitter::Emitter;
+
+    fn explorer() -> Explorer {
+        let mut e = Emitter::new(testdata::store());
+        let (f, x, y) = (vname("f", ""), vname("x", ""), vname("y", ""));
+        e.emit_ordinal_edge(&f, edges::PARAM, &y, 1).unwrap();
+        e.emit_ordinal_edge(&f, edges::PARAM, &x, 0).unwrap();
+        // A malformed code fact, which is left out of y's node data.
+        e.emit_fact(&y, facts::CODE, b"\xff").unwrap();
+        e.emit_edge(&x, edges::CHILD_OF, &f).unwrap();
+        let (ty, fun, unit) = (vname("fn()", ""), vname("fn", ""), vname("()", ""));
+        e.emit_edge(&f, edges::TYPED, &ty).unwrap();
+        e.emit_ordinal_edge(&ty, edges::PARAM, &fun, 0).unwrap();
+        e.emit_ordinal_edge(&ty, edges::PARAM, &unit, 1).unwrap();
+
+        let (base, mid, leaf) = (vname("Base", ""), vname("Mid", ""), vname("Leaf", ""));
+        let mut java = vname("Other", "");
+        java.set_language("java".into());
+        e.emit_edge(&mid, edges::EXTENDS, &base).unwrap();
+        e.emit_edge(&leaf, edges::EXTENDS_PUBLIC, &mid).unwrap();
+        e.emit_edge(&java, edges::SATISFIES, &mid).unwrap();
+        Explorer::new(&e.into_inner()).unwrap()
+    }
+
+    fn tickets(sigs: &[&str]) -> RepeatedField<String> {
+        sigs.iter().map(|s| ticket(s, "")).collect()
+    }
+
+    fn graph_edges(graph: &Graph) -> Vec<(String, String)> {
+        let mut out = Vec::new();
+        for (ticket, node) in graph.get_nodes() {
+            for s in node.get_successors() {
+                out.push((ticket.clone(), s.clone()));
+            }
+        }
+        out.sort();
+        out
+    }
+
+    #[test]
+    fn follows_calls() {
+        let ex = explorer();
+        let mut req = CallersRequest::new();
+        req.set_tickets(tickets(&["f"]));
+        let graph = ex.callers(&req).unwrap().take_graph();
+        assert_eq!(graph_edges(&graph), [(ticket("g", ""), ticket("f", ""))]);
+        let f = graph.get_nodes()[&ticket("f", "")].get_node_data();
+        assert_eq!(f.get_kind(), nodes::FUNCTION);
+        let loc = &f.get_locations()[0];
+        assert_eq!(loc.get_ticket(), ticket("", "a.rs"));
+        assert_eq!(loc.get_span().get_start().get_column_offset(), 3);
+        assert_eq!(f.get_definition_anchor(), loc_anchor(3, 4));
+
+        let mut req = CalleesRequest::new();
+        req.set_tickets(tickets(&["g"]));
+        let graph = ex.callees(&req).unwrap().take_graph();
+        assert_eq!(graph_edges(&graph), [(ticket("g", ""), ticket("f", ""))]);
+    }
+
+    fn loc_anchor(start: usize, end: usize) -> String {
+        kythe_uri::to_string(&kythe_storage::emitter::anchor_vname(
+            &vname("", "a.rs"),
+            start,
+            end,
+        ))
+    }
+
+    #[test]
+    fn relates_parents_children_and_parameters() {
+        let ex = explorer();
+        let mut req = ParentsRequest::new();
+        req.set_tickets(tickets(&["x", "g"]));
+        let reply = ex.parents(&req).unwrap();
+        assert_eq!(
+            reply.get_input_to_parents()[&ticket("x", "")].get_tickets(),
+            [ticket("f", "")]
+        );
+        // Anchors are not semantic children.
+        assert!(reply.get_input_to_parents()[&ticket("g", "")]
+            .get_tickets()
+            .is_empty());
+        let mut req = ChildrenRequest::new();
+        req.set_tickets(tickets(&["f", "g"]));
+        let reply = ex.children(&req).unwrap();
+        assert_eq!(
+            reply.get_input_to_children()[&ticket("f", "")].get_tickets(),
+            [ticket("x", "")]
+        );
+        assert!(reply.get_input_to_children()[&ticket("g", "")]
+            .get_tickets()
+            .is_empty());
+
+        let mut req = ParametersRequest::new();
+        req.set_function_tickets(tickets(&["f"]));
+        let reply = ex.parameters(&req).unwrap();
+        assert_eq!(
+            reply.get_function_to_parameters()[&ticket("f", "")].get_tickets(),
+            [ticket("x", ""), ticket("y", "")]
+        );
+        assert_eq!(
+            reply.get_function_to_return_value()[&ticket("f", "")],
+            ticket("()", "")
+        );
+        assert!(reply.get_node_data().contains_key(&ticket("f", "")));
+        assert!(!reply.get_node_data()[&ticket("y", "")].has_code());
+    }
+
+    #[test]
+    fn walks_filtered_type_hierarchy() {
+        let ex = explorer();
+        let mut req = TypeHierarchyRequest::new();
+        req.set_type_ticket(ticket("Mid", ""));
+        let reply = ex.type_hierarchy(&req).unwrap();
+        assert_eq!(
+            graph_edges(reply.get_graph()),
+            [
+                (ticket("Leaf", ""), ticket("Mid", "")),
+                (ticket("Mid", ""), ticket("Base", "")),
+                (
+                    kythe_uri::to_string(&{
+                        let mut v = vname("Other", "");
+                        v.set_language("java".into());
+                        v
+                    }),
+                    ticket("Mid", "")
+                ),
+            ]
+        );
+
+        req.mut_node_filter()
+            .mut_included_languages()
+            .push(String::new());
+        let reply = ex.type_hierarchy(&req).unwrap();
+        assert_eq!(graph_edges(reply.get_graph()).len(), 2);
+        assert!(ex.type_hierarchy(&TypeHierarchyRequest::new()).is_err());
+    }
+}
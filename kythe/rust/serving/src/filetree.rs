@@ -0,0 +1,324 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An implementation of the `FileTreeService` API over the file nodes of an
+//! Entry stream, as `kythe/go/services/filetree`.
+
+use crate::Result;
+use filetree_rust_proto::{
+    CorpusRootsReply, CorpusRootsReply_Corpus, CorpusRootsRequest, DirectoryReply,
+    DirectoryReply_Entry, DirectoryReply_Kind as Kind, DirectoryRequest,
+};
+use kythe_schema::{edges, facts, nodes};
+use kythe_storage::GraphStore;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::ControlFlow;
+use storage_rust_proto::{Entry, ScanRequest, VName};
+
+/// The Kythe file tree service.
+pub trait FileTreeService {
+    /// Returns all known corpus/root pairs for stored files.
+    fn corpus_roots(&self, req: &CorpusRootsRequest) -> Result<CorpusRootsReply>;
+
+    /// Returns the files and subdirectories of a directory.
+    fn directory(&self, req: &DirectoryRequest) -> Result<DirectoryReply>;
+}
+
+/// Returns `path` cleaned and relative to its corpus root, as Go's
+/// `filetree.CleanDirPath`.
+pub fn clean_dir_path(path: &str) -> String {
+    kythe_uri::clean_path(&format!("/{path}"))
+        .trim_start_matches('/')
+        .to_owned()
+}
+
+/// What is known about a path from the entries seen so far.
+#[derive(Default)]
+struct File {
+    is_file: bool,
+    has_text: bool,
+    generated: bool,
+    build_configs: BTreeSet<String>,
+}
+
+/// An in-memory file tree, keyed by corpus, root and path.
+#[derive(Default)]
+pub struct FileTree {
+    files: BTreeMap<(String, String, String), File>,
+}
+
+impl FileTree {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of files in the tree.
+    pub fn len(&self) -> usize {
+        self.files.values().filter(|f| f.is_file).count()
+    }
+
+    /// Reports whether the tree has no files.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds every entry of `store` to the tree.
+    pub fn populate(&mut self, store: &dyn GraphStore) -> Result<()> {
+        store.scan(&ScanRequest::new(), &mut |e| {
+            self.add_entry(e);
+            ControlFlow::Continue(())
+        })?;
+        Ok(())
+    }
+
+    /// Records what `entry` says about a file: its node kind and text, the
+    /// build configurations of anchors within it, and whether another file
+    /// generates it. Other entries are ignored.
+    pub fn add_entry(&mut self, entry: &Entry) {
+        let source = entry.get_source();
+        if !entry.get_edge_kind().is_empty() {
+            if edges::canonical(entry.get_edge_kind()) == edges::GENERATES {
+                if let Some(file) = self.file_mut(entry.get_target()) {
+                    file.generated = true;
+                }
+            }
+            return;
+        }
+        let is_file_vname = source.get_signature().is_empty() && source.get_language().is_empty();
+        let value = entry.get_fact_value();
+        match entry.get_fact_name() {
+            facts::NODE_KIND if is_file_vname && value == nodes::FILE.as_bytes() => {
+                if let Some(file) = self.file_mut(source) {
+                    file.is_file = true;
+                }
+            }
+            facts::TEXT if is_file_vname => {
+                if let Some(file) = self.file_mut(source) {
+                    file.has_text = true;
+                }
+            }
+            facts::BUILD_CONFIG if !value.is_empty() => {
+                if let Some(file) = self.file_mut(source) {
+                    file.build_configs
+                        .insert(String::from_utf8_lossy(value).into_owned());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Adds a file node with text to the tree.
+    pub fn add_file(&mut self, file: &VName) {
+        if let Some(f) = self.file_mut(file) {
+            f.is_file = true;
+            f.has_text = true;
+        }
+    }
+
+    fn file_mut(&mut self, v: &VName) -> Option<&mut File> {
+        let path = clean_dir_path(v.get_path());
+        if path.is_empty() {
+            return None;
+        }
+        Some(
+            self.files
+                .entry((v.get_corpus().into(), v.get_root().into(), path))
+                .or_default(),
+        )
+    }
+}
+
+impl Extend<Entry> for FileTree {
+    fn extend<I: IntoIterator<Item = Entry>>(&mut self, entries: I) {
+        for e in entries {
+            self.add_entry(&e);
+        }
+    }
+}
+
+impl FromIterator<Entry> for FileTree {
+    fn from_iter<I: IntoIterator<Item = Entry>>(entries: I) -> Self {
+        let mut tree = FileTree::new();
+        tree.extend(entries);
+        tree
+    }
+}
+
+impl FileTreeService for FileTree {
+    fn corpus_roots(&self, _req: &CorpusRootsRequest) -> Result<CorpusRootsReply> {
+        let mut corpora: BTreeMap<&str, (BTreeSet<&str>, BTreeSet<&str>)> = BTreeMap::new();
+        for ((corpus, root, _), file) in &self.files {
+            if file.is_file {
+                let (roots, configs) = corpora.entry(corpus).or_default();
+                roots.insert(root);
+                configs.extend(file.build_configs.iter().map(String::as_str));
+            }
+        }
+        let mut reply = CorpusRootsReply::new();
+        for (name, (roots, configs)) in corpora {
+            let mut c = CorpusRootsReply_Corpus::new();
+            c.set_name(name.into());
+            c.set_root(roots.into_iter().map(String::from).collect());
+            c.set_build_config(configs.into_iter().map(String::from).collect());
+            reply.mut_corpus().push(c);
+        }
+        Ok(reply)
+    }
+
+    fn directory(&self, req: &DirectoryRequest) -> Result<DirectoryReply> {
+        let dir = clean_dir_path(req.get_path());
+        let prefix = if dir.is_empty() {
+            String::new()
+        } else {
+            format!("{dir}/")
+        };
+        let mut reply = DirectoryReply::new();
+        reply.set_corpus(req.get_corpus().into());
+        reply.set_root(req.get_root().into());
+        reply.set_path(dir);
+
+        let start = (
+            req.get_corpus().into(),
+            req.get_root().into(),
+            prefix.clone(),
+        );
+        let mut entries: BTreeMap<&str, (DirectoryReply_Entry, BTreeSet<&str>)> = BTreeMap::new();
+        for ((corpus, root, path), file) in self.files.range(start..) {
+            if corpus != req.get_corpus() || root != req.get_root() || !path.starts_with(&prefix) {
+                break;
+            }
+            if !file.is_file || !(file.has_text || req.get_include_files_missing_text()) {
+                continue;
+            }
+            let generated = !root.is_empty() || file.generated;
+            let (name, kind) = match path[prefix.len()..].split_once('/') {
+                Some((name, _)) => (name, Kind::DIRECTORY),
+                None => (&path[prefix.len()..], Kind::FILE),
+            };
+            let (entry, configs) = entries.entry(name).or_insert_with(|| {
+                let mut e = DirectoryReply_Entry::new();
+                e.set_kind(kind);
+                e.set_name(name.into());
+                e.set_generated(true);
+                e.set_missing_text(true);
+                (e, BTreeSet::new())
+            });
+            // A directory is generated or missing text only if everything
+            // below it is.
+            entry.set_generated(entry.get_generated() && generated);
+            entry.set_missing_text(entry.get_missing_text() && !file.has_text);
+            configs.extend(file.build_configs.iter().map(String::as_str));
+        }
+        for (_, (mut entry, configs)) in entries {
+            entry.set_build_config(configs.into_iter().map(String::from).collect());
+            reply.mut_entry().push(entry);
+        }
+        Ok(reply)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testdata::{self, vname};
+    use kythe_storage::emitter::Emitter;
+
+    fn tree() -> FileTree {
+        let mut e = Emitter::new(testdata::store());
+        let mut gen = vname("", "out/a.pb.rs");
+        gen.set_root("bazel-out".into());
+        e.emit_node(&gen, nodes::FILE, []).unwrap();
+        e.emit_edge(&vname("", "a.rs"), edges::GENERATES, &gen)
+            .unwrap();
+        e.emit_file(&vname("", "/src/lib/b.rs"), b"", None).unwrap();
+        e.emit_node(&vname("", "src/lib/c.rs"), nodes::FILE, [])
+            .unwrap();
+        let mut other = vname("", "d.rs");
+        other.set_corpus("d".into());
+        e.emit_file(&other, b"", None).unwrap();
+        let mut tree = FileTree::new();
+        tree.populate(&e.into_inner()).unwrap();
+        tree
+    }
+
+    fn directory(
+        tree: &FileTree,
+        root: &str,
+        path: &str,
+        missing: bool,
+    ) -> Vec<(String, Kind, bool, bool)> {
+        let mut req = DirectoryRequest::new();
+        req.set_corpus("c".into());
+        req.set_root(root.into());
+        req.set_path(path.into());
+        req.set_include_files_missing_text(missing);
+        tree.directory(&req)
+            .unwrap()
+            .get_entry()
+            .iter()
+            .map(|e| {
+                (
+                    e.get_name().to_owned(),
+                    e.get_kind(),
+                    e.get_generated(),
+                    e.get_missing_text(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn lists_corpus_roots() {
+        let tree = tree();
+        assert_eq!(tree.len(), 5);
+        let reply = tree.corpus_roots(&CorpusRootsRequest::new()).unwrap();
+        let corpora: Vec<_> = reply
+            .get_corpus()
+            .iter()
+            .map(|c| (c.get_name(), c.get_root().to_vec()))
+            .collect();
+        assert_eq!(
+            corpora,
+            [
+                ("c", vec!["".to_owned(), "bazel-out".to_owned()]),
+                ("d", vec!["".to_owned()])
+            ]
+        );
+    }
+
+    #[test]
+    fn separates_files_and_directories() {
+        let tree = tree();
+        assert_eq!(
+            directory(&tree, "", "", false),
+            [
+                ("a.rs".to_owned(), Kind::FILE, false, false),
+                ("src".to_owned(), Kind::DIRECTORY, false, false),
+            ]
+        );
+        assert_eq!(
+            directory(&tree, "", "/src/./lib/", true),
+            [
+                ("b.rs".to_owned(), Kind::FILE, false, false),
+                ("c.rs".to_owned(), Kind::FILE, false, true),
+            ]
+        );
+        assert!(directory(&tree, "bazel-out", "", false).is_empty());
+        assert_eq!(
+            directory(&tree, "bazel-out", "", true),
+            [("out".to_owned(), Kind::DIRECTORY, true, true)]
+        );
+        assert!(directory(&tree, "", "missing", true).is_empty());
+    }
+}
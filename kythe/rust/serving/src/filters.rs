@@ -0,0 +1,118 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Glob-style fact filters, as accepted by the `filter` fields of the xrefs
+//! and graph service requests.
+//!
+//! In a pattern, `**` matches any sequence of characters, `*` matches any
+//! sequence of characters other than `/`, and `?` matches any single
+//! character other than `/`. All other characters match themselves.
+
+/// A set of patterns matching fact names.
+#[derive(Clone, Debug, Default)]
+pub struct Filters {
+    patterns: Vec<Vec<Token>>,
+    matches_all: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Token {
+    Char(char),
+    /// `?`
+    One,
+    /// `*`
+    Segment,
+    /// `**`
+    Any,
+}
+
+impl Filters {
+    /// Returns a set of the given patterns.
+    pub fn new<S: AsRef<str>>(patterns: &[S]) -> Self {
+        let mut filters = Self::default();
+        for pattern in patterns {
+            let pattern = pattern.as_ref();
+            if pattern == "**" {
+                filters.matches_all = true;
+            }
+            filters.patterns.push(parse(pattern));
+        }
+        filters
+    }
+
+    /// Reports whether there are no patterns, in which case nothing matches.
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    /// Reports whether `name` matches any of the patterns.
+    pub fn matches(&self, name: &str) -> bool {
+        if self.matches_all {
+            return true;
+        }
+        let name: Vec<char> = name.chars().collect();
+        self.patterns.iter().any(|p| matches(p, &name))
+    }
+}
+
+fn parse(pattern: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        tokens.push(match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                Token::Any
+            }
+            '*' => Token::Segment,
+            '?' => Token::One,
+            c => Token::Char(c),
+        });
+    }
+    tokens
+}
+
+fn matches(pattern: &[Token], name: &[char]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some((Token::Char(c), rest)) => name.first() == Some(c) && matches(rest, &name[1..]),
+        Some((Token::One, rest)) => {
+            name.first().is_some_and(|&c| c != '/') && matches(rest, &name[1..])
+        }
+        Some((Token::Segment, rest)) => {
+            let segment = name.iter().position(|&c| c == '/').unwrap_or(name.len());
+            (0..=segment).any(|i| matches(rest, &name[i..]))
+        }
+        Some((Token::Any, rest)) => (0..=name.len()).any(|i| matches(rest, &name[i..])),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glob_patterns() {
+        let f = Filters::new(&["/kythe/node/*", "/kythe/?ext", "/x/**/end"]);
+        assert!(f.matches("/kythe/node/kind"));
+        assert!(!f.matches("/kythe/node/kind/sub"));
+        assert!(f.matches("/kythe/text"));
+        assert!(!f.matches("/kythe/loc/start"));
+        assert!(f.matches("/x/a/b/end"));
+        assert!(!f.matches("/x/a/b/ends"));
+
+        assert!(Filters::new(&["**"]).matches("anything/at/all"));
+        assert!(!Filters::new::<&str>(&[]).matches("/kythe/text"));
+    }
+}
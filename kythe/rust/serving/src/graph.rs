@@ -0,0 +1,302 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An implementation of the `GraphService` API answering directly from the
+//! entries of a GraphStore.
+
+use crate::filters::Filters;
+use crate::xrefs::fix_tickets;
+use crate::{Error, Result};
+use graph_rust_proto::{
+    EdgeSet, EdgeSet_Group_Edge, EdgesReply, EdgesRequest, NodeInfo, NodesReply, NodesRequest,
+};
+use kythe_schema::edges;
+use kythe_storage::GraphStore;
+use std::collections::{BTreeMap, HashSet};
+use storage_rust_proto::{Entry, ReadRequest};
+
+/// The number of edges returned when a request has no page size.
+pub const DEFAULT_PAGE_SIZE: usize = 2048;
+
+/// The maximum number of edges returned in a single reply.
+pub const MAX_PAGE_SIZE: usize = 10000;
+
+/// The Kythe graph service.
+pub trait GraphService {
+    /// Returns the facts of a set of nodes.
+    fn nodes(&self, req: &NodesRequest) -> Result<NodesReply>;
+
+    /// Returns a page of the outbound edges of a set of nodes.
+    fn edges(&self, req: &EdgesRequest) -> Result<EdgesReply>;
+}
+
+/// A `GraphService` over the entries of a GraphStore.
+pub struct Store<G> {
+    store: G,
+}
+
+/// An edge of a source node, ordered by kind, ordinal and target.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct Edge {
+    kind: String,
+    ordinal: Option<u32>,
+    target: String,
+}
+
+impl<G: GraphStore> Store<G> {
+    pub fn new(store: G) -> Self {
+        Store { store }
+    }
+
+    /// Returns the underlying store.
+    pub fn store(&self) -> &G {
+        &self.store
+    }
+
+    /// Returns the facts of the node named by `ticket` that match `filters`,
+    /// or every fact when `filters` is `None`.
+    fn node_info(&self, ticket: &str, filters: Option<&Filters>) -> Result<NodeInfo> {
+        let mut req = ReadRequest::new();
+        req.set_source(kythe_uri::to_vname(ticket).map_err(invalid_ticket(ticket))?);
+        let mut info = NodeInfo::new();
+        for e in self.store.read_entries(&req)? {
+            let wanted = match filters {
+                Some(filters) => filters.matches(e.get_fact_name()),
+                None => true,
+            };
+            if wanted {
+                info.mut_facts()
+                    .insert(e.get_fact_name().into(), e.get_fact_value().to_vec());
+            }
+        }
+        Ok(info)
+    }
+
+    /// Returns the sorted outbound edges of the node named by `ticket` whose
+    /// kinds are in `kinds`, or all of them when `kinds` is empty.
+    fn edges_of(&self, ticket: &str, kinds: &HashSet<&str>) -> Result<Vec<Edge>> {
+        let mut req = ReadRequest::new();
+        req.set_source(kythe_uri::to_vname(ticket).map_err(invalid_ticket(ticket))?);
+        req.set_edge_kind("*".into());
+        let mut edges: Vec<Edge> = self
+            .store
+            .read_entries(&req)?
+            .iter()
+            .filter(|e| !e.get_edge_kind().is_empty() && e.has_target())
+            .filter_map(|e: &Entry| {
+                let (kind, ordinal) = edges::parse_ordinal(e.get_edge_kind());
+                let wanted =
+                    kinds.is_empty() || kinds.contains(kind) || kinds.contains(e.get_edge_kind());
+                wanted.then(|| Edge {
+                    kind: kind.into(),
+                    ordinal,
+                    target: kythe_uri::to_string(e.get_target()),
+                })
+            })
+            .collect();
+        edges.sort();
+        edges.dedup();
+        Ok(edges)
+    }
+}
+
+fn invalid_ticket(ticket: &str) -> impl FnOnce(kythe_uri::ParseError) -> Error + '_ {
+    move |err| Error::InvalidArgument(format!("invalid ticket {ticket:?}: {err}"))
+}
+
+impl<G: GraphStore> GraphService for Store<G> {
+    fn nodes(&self, req: &NodesRequest) -> Result<NodesReply> {
+        let tickets = fix_tickets(req.get_ticket())?;
+        let filters = Filters::new(req.get_filter());
+        let filters = (!filters.is_empty()).then_some(&filters);
+        let mut reply = NodesReply::new();
+        for ticket in tickets {
+            let info = self.node_info(&ticket, filters)?;
+            if !info.get_facts().is_empty() {
+                reply.mut_nodes().insert(ticket, info);
+            }
+        }
+        Ok(reply)
+    }
+
+    fn edges(&self, req: &EdgesRequest) -> Result<EdgesReply> {
+        let mut tickets = fix_tickets(req.get_ticket())?;
+        tickets.sort();
+        tickets.dedup();
+        let page_size = match req.get_page_size() {
+            n if n < 0 => {
+                return Err(Error::InvalidArgument(format!("invalid page size: {n}")));
+            }
+            0 => DEFAULT_PAGE_SIZE,
+            n => (n as usize).min(MAX_PAGE_SIZE),
+        };
+        let offset: usize = match req.get_page_token() {
+            "" => 0,
+            token => token
+                .parse()
+                .map_err(|_| Error::InvalidArgument(format!("invalid page token: {token:?}")))?,
+        };
+        let kinds: HashSet<&str> = req.get_kind().iter().map(String::as_str).collect();
+        let filters = Filters::new(req.get_filter());
+
+        let mut reply = EdgesReply::new();
+        let mut totals: BTreeMap<String, i64> = BTreeMap::new();
+        let mut targets = HashSet::new();
+        // The index of each edge across all sources, for paging.
+        let mut index = 0;
+        let end = offset.saturating_add(page_size);
+        for ticket in &tickets {
+            let mut set = EdgeSet::new();
+            for edge in self.edges_of(ticket, &kinds)? {
+                *totals.entry(edge.kind.clone()).or_default() += 1;
+                index += 1;
+                if index <= offset || index > end {
+                    continue;
+                }
+                let mut e = EdgeSet_Group_Edge::new();
+                e.set_target_ticket(edge.target.clone());
+                e.set_ordinal(edge.ordinal.unwrap_or_default() as i32);
+                set.mut_groups()
+                    .entry(edge.kind)
+                    .or_default()
+                    .mut_edge()
+                    .push(e);
+                targets.insert(edge.target);
+            }
+            if !set.get_groups().is_empty() {
+                reply.mut_edge_sets().insert(ticket.clone(), set);
+            }
+        }
+        if index > end {
+            reply.set_next_page_token(end.to_string());
+        }
+        reply.set_total_edges_by_kind(totals.into_iter().collect());
+
+        if !filters.is_empty() {
+            for target in targets {
+                let info = self.node_info(&target, Some(&filters))?;
+                if !info.get_facts().is_empty() {
+                    reply.mut_nodes().insert(target, info);
+                }
+            }
+        }
+        Ok(reply)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testdata::{self, ticket};
+    use kythe_schema::facts;
+    use kythe_storage::emitter::Emitter;
+    use kythe_storage::MemoryStore;
+    use protobuf::RepeatedField;
+
+    fn service() -> Store<MemoryStore> {
+        let mut e = Emitter::new(testdata::store());
+        let (f, x, y) = (
+            testdata::vname("f", ""),
+            testdata::vname("x", ""),
+            testdata::vname("y", ""),
+        );
+        e.emit_node(&x, kythe_schema::nodes::VARIABLE, []).unwrap();
+        e.emit_node(&y, kythe_schema::nodes::VARIABLE, []).unwrap();
+        e.emit_edge(&f, &format!("{}.1", edges::PARAM), &y).unwrap();
+        e.emit_edge(&f, &format!("{}.0", edges::PARAM), &x).unwrap();
+        e.emit_edge(&f, edges::CHILD_OF, &testdata::vname("g", ""))
+            .unwrap();
+        Store::new(e.into_inner())
+    }
+
+    fn edges_request(kinds: &[&str], page_size: i32, token: &str) -> EdgesRequest {
+        let mut req = EdgesRequest::new();
+        req.set_ticket(RepeatedField::from_vec(vec![ticket("f", "")]));
+        req.set_kind(kinds.iter().map(|k| k.to_string()).collect());
+        req.set_page_size(page_size);
+        req.set_page_token(token.into());
+        req
+    }
+
+    #[test]
+    fn filters_node_facts() {
+        let svc = service();
+        let mut req = NodesRequest::new();
+        req.set_ticket(RepeatedField::from_vec(vec![
+            ticket("f", ""),
+            ticket("missing", ""),
+        ]));
+        let reply = svc.nodes(&req).unwrap();
+        assert_eq!(reply.get_nodes().len(), 1);
+        assert!(reply.get_nodes()[&ticket("f", "")]
+            .get_facts()
+            .contains_key(facts::NODE_KIND));
+
+        req.set_filter(RepeatedField::from_vec(vec!["/kythe/t*".into()]));
+        let reply = svc.nodes(&req).unwrap();
+        assert!(reply.get_nodes().is_empty());
+
+        req.set_filter(RepeatedField::from_vec(vec!["/kythe/**".into()]));
+        assert_eq!(svc.nodes(&req).unwrap().get_nodes().len(), 1);
+        assert!(svc.nodes(&NodesRequest::new()).is_err());
+    }
+
+    #[test]
+    fn groups_edges_by_kind() {
+        let svc = service();
+        let mut req = edges_request(&[edges::PARAM], 0, "");
+        req.set_filter(RepeatedField::from_vec(vec![facts::NODE_KIND.into()]));
+        let reply = svc.edges(&req).unwrap();
+        let set = &reply.get_edge_sets()[&ticket("f", "")];
+        let group: Vec<_> = set.get_groups()[edges::PARAM]
+            .get_edge()
+            .iter()
+            .map(|e| (e.get_target_ticket().to_string(), e.get_ordinal()))
+            .collect();
+        assert_eq!(group, [(ticket("x", ""), 0), (ticket("y", ""), 1)]);
+        assert_eq!(reply.get_total_edges_by_kind()[edges::PARAM], 2);
+        assert_eq!(reply.get_nodes().len(), 2);
+        assert!(reply.get_next_page_token().is_empty());
+
+        let reply = svc.edges(&edges_request(&[], 0, "")).unwrap();
+        assert_eq!(reply.get_total_edges_by_kind().len(), 2);
+        assert!(reply.get_nodes().is_empty());
+    }
+
+    #[test]
+    fn pages_edges() {
+        let svc = service();
+        let first = svc.edges(&edges_request(&[], 2, "")).unwrap();
+        assert_eq!(first.get_next_page_token(), "2");
+        let second = svc
+            .edges(&edges_request(&[], 2, first.get_next_page_token()))
+            .unwrap();
+        assert!(second.get_next_page_token().is_empty());
+        let count = |r: &EdgesReply| -> usize {
+            r.get_edge_sets()
+                .values()
+                .flat_map(|s| s.get_groups().values())
+                .map(|g| g.get_edge().len())
+                .sum()
+        };
+        assert_eq!(count(&first), 2);
+        assert_eq!(count(&second), 1);
+        assert_eq!(
+            first.get_total_edges_by_kind(),
+            second.get_total_edges_by_kind()
+        );
+        assert!(svc.edges(&edges_request(&[], -1, "")).is_err());
+        assert!(svc.edges(&edges_request(&[], 0, "x")).is_err());
+    }
+}
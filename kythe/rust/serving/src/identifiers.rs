@@ -0,0 +1,343 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An implementation of the `IdentifierService` API over the named nodes of
+//! an Entry stream, as `kythe/go/serving/identifiers`.
+//!
+//! A node's names come from its `/kythe/code` MarkedSource, and may be given
+//! explicitly by a rendered qualified name or an identifier fact instead.
+
+use crate::Result;
+use common_rust_proto::MarkedSource;
+use identifier_rust_proto::{FindReply, FindReply_Match, FindRequest};
+use kythe_schema::{edges, facts};
+use kythe_storage::GraphStore;
+use protobuf::Message;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::ops::ControlFlow;
+use storage_rust_proto::{Entry, ScanRequest};
+
+/// The fact holding a node's rendered qualified name, as written by
+/// `kythe/go/util/tools/markedsource --render_qualified_names`.
+pub const RENDERED_QUALIFIED_NAME: &str = "/kythe/code/rendered/qualified_name";
+
+/// The fact holding a node's identifier, as emitted by some indexers.
+pub const IDENTIFIER: &str = "/kythe/identifier";
+
+/// The Kythe identifier service.
+pub trait IdentifierService {
+    /// Returns the nodes whose qualified name is the requested identifier.
+    fn find(&self, req: &FindRequest) -> Result<FindReply>;
+}
+
+/// The naming facts of a node.
+#[derive(Default)]
+struct Node {
+    kind: String,
+    subkind: String,
+    code: Option<Vec<u8>>,
+    rendered: Option<String>,
+    identifier: Option<String>,
+    /// The node completing this one, such as the definition of a
+    /// declaration.
+    canonical: Option<String>,
+    /// The base and qualified names under which the node is indexed.
+    names: Option<(String, String)>,
+}
+
+/// An in-memory index of nodes by qualified name.
+#[derive(Default)]
+pub struct IdentifierIndex {
+    nodes: HashMap<String, Node>,
+    names: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl IdentifierIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of named nodes in the index.
+    pub fn len(&self) -> usize {
+        self.names.values().map(BTreeSet::len).sum()
+    }
+
+    /// Reports whether the index has no named nodes.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Adds every entry of `store` to the index.
+    pub fn populate(&mut self, store: &dyn GraphStore) -> Result<()> {
+        store.scan(&ScanRequest::new(), &mut |e| {
+            self.add_entry(e);
+            ControlFlow::Continue(())
+        })?;
+        Ok(())
+    }
+
+    /// Records what `entry` says about the names of its source node. Other
+    /// entries are ignored.
+    pub fn add_entry(&mut self, entry: &Entry) {
+        let ticket = kythe_uri::to_string(entry.get_source());
+        if !entry.get_edge_kind().is_empty() {
+            if edges::canonical(entry.get_edge_kind()) == edges::COMPLETED_BY {
+                let target = kythe_uri::to_string(entry.get_target());
+                self.nodes.entry(ticket).or_default().canonical = Some(target);
+            }
+            return;
+        }
+        let value = entry.get_fact_value();
+        let text = || String::from_utf8_lossy(value).into_owned();
+        let node = self.nodes.entry(ticket.clone()).or_default();
+        match entry.get_fact_name() {
+            facts::NODE_KIND => node.kind = text(),
+            facts::SUBKIND => node.subkind = text(),
+            facts::CODE => node.code = Some(value.to_vec()),
+            RENDERED_QUALIFIED_NAME => node.rendered = Some(text()),
+            IDENTIFIER => node.identifier = Some(text()),
+            _ => return,
+        }
+        let names = names(node);
+        if names == node.names {
+            return;
+        }
+        if let Some((_, old)) = std::mem::replace(&mut node.names, names.clone()) {
+            if let Some(tickets) = self.names.get_mut(&old) {
+                tickets.remove(&ticket);
+                if tickets.is_empty() {
+                    self.names.remove(&old);
+                }
+            }
+        }
+        if let Some((_, qualified)) = names {
+            self.names.entry(qualified).or_default().insert(ticket);
+        }
+    }
+}
+
+impl Extend<Entry> for IdentifierIndex {
+    fn extend<I: IntoIterator<Item = Entry>>(&mut self, entries: I) {
+        for e in entries {
+            self.add_entry(&e);
+        }
+    }
+}
+
+impl FromIterator<Entry> for IdentifierIndex {
+    fn from_iter<I: IntoIterator<Item = Entry>>(entries: I) -> Self {
+        let mut index = IdentifierIndex::new();
+        index.extend(entries);
+        index
+    }
+}
+
+impl IdentifierService for IdentifierIndex {
+    fn find(&self, req: &FindRequest) -> Result<FindReply> {
+        let corpora: HashSet<&str> = req.get_corpus().iter().map(String::as_str).collect();
+        let languages: HashSet<&str> = req.get_languages().iter().map(String::as_str).collect();
+        let tickets: Vec<&String> = self
+            .names
+            .get(req.get_identifier())
+            .into_iter()
+            .flatten()
+            .filter(|ticket| {
+                kythe_uri::Uri::parse(ticket).is_ok_and(|uri| {
+                    (corpora.is_empty() || corpora.contains(uri.corpus.as_str()))
+                        && (languages.is_empty() || languages.contains(uri.language.as_str()))
+                })
+            })
+            .collect();
+        let found: HashSet<&str> = tickets.iter().map(|t| t.as_str()).collect();
+
+        let mut reply = FindReply::new();
+        for ticket in tickets {
+            let node = &self.nodes[ticket];
+            if req.get_pick_canonical_nodes() {
+                if let Some(canonical) = &node.canonical {
+                    if canonical != ticket && found.contains(canonical.as_str()) {
+                        continue;
+                    }
+                }
+            }
+            let (base, qualified) = node.names.clone().unwrap_or_default();
+            let mut m = FindReply_Match::new();
+            m.set_ticket(ticket.clone());
+            m.set_node_kind(node.kind.clone());
+            m.set_node_subkind(node.subkind.clone());
+            m.set_base_name(base);
+            m.set_qualified_name(qualified);
+            reply.mut_matches().push(m);
+        }
+        Ok(reply)
+    }
+}
+
+/// Returns the base and qualified names of a node, preferring its explicit
+/// naming facts to those rendered from its MarkedSource.
+fn names(node: &Node) -> Option<(String, String)> {
+    let (mut base, mut qualified) = node
+        .code
+        .as_deref()
+        .and_then(|code| MarkedSource::parse_from_bytes(code).ok())
+        .map(|ms| {
+            let mut info = kythe_markedsource::render_qualified_name(&ms);
+            (info.take_base_name(), info.take_qualified_name())
+        })
+        .unwrap_or_default();
+    if let Some(id) = &node.identifier {
+        base = id.clone();
+    }
+    if let Some(rendered) = &node.rendered {
+        qualified = rendered.clone();
+        if base.is_empty() {
+            base = rendered
+                .rsplit(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+                .next()
+                .unwrap_or_default()
+                .to_owned();
+        }
+    }
+    if qualified.is_empty() {
+        qualified = base.clone();
+    }
+    (!qualified.is_empty()).then_some((base, qualified))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common_rust_proto::MarkedSource_Kind as Kind;
+    use kythe_schema::nodes;
+    use kythe_storage::emitter::Emitter;
+    use kythe_storage::MemoryStore;
+    use protobuf::RepeatedField;
+    use storage_rust_proto::VName;
+
+    fn vname(signature: &str, corpus: &str, language: &str) -> VName {
+        let mut v = VName::new();
+        v.set_signature(signature.into());
+        v.set_corpus(corpus.into());
+        v.set_language(language.into());
+        v
+    }
+
+    fn ms(
+        kind: Kind,
+        pre_text: &str,
+        post_child_text: &str,
+        kids: Vec<MarkedSource>,
+    ) -> MarkedSource {
+        let mut ms = MarkedSource::new();
+        ms.set_kind(kind);
+        ms.set_pre_text(pre_text.into());
+        ms.set_post_child_text(post_child_text.into());
+        ms.set_child(RepeatedField::from_vec(kids));
+        ms
+    }
+
+    /// Returns the MarkedSource of `name` within the namespaces `context`.
+    fn code(context: &[&str], name: &str) -> Vec<u8> {
+        let quals = context
+            .iter()
+            .map(|q| ms(Kind::IDENTIFIER, q, "", vec![]))
+            .collect();
+        ms(
+            Kind::BOX,
+            "",
+            "",
+            vec![
+                ms(Kind::CONTEXT, "", "::", quals),
+                ms(Kind::IDENTIFIER, name, "", vec![]),
+            ],
+        )
+        .write_to_bytes()
+        .unwrap()
+    }
+
+    fn index() -> IdentifierIndex {
+        let mut e = Emitter::new(MemoryStore::new());
+        let decl = vname("decl", "c", "c++");
+        let defn = vname("defn", "c", "c++");
+        for v in [&decl, &defn] {
+            let code = code(&["foo", "Bar"], "baz");
+            e.emit_node(v, nodes::FUNCTION, [(facts::CODE, &code[..])])
+                .unwrap();
+        }
+        e.emit_edge(&decl, edges::COMPLETED_BY, &defn).unwrap();
+        let code = code(&["foo", "Bar"], "baz");
+        e.emit_node(
+            &vname("java", "d", "java"),
+            nodes::FUNCTION,
+            [(facts::CODE, &code[..])],
+        )
+        .unwrap();
+        e.emit_node(
+            &vname("go", "c", "go"),
+            nodes::RECORD,
+            [(RENDERED_QUALIFIED_NAME, &b"pkg.T"[..])],
+        )
+        .unwrap();
+        e.into_inner().entries().iter().cloned().collect()
+    }
+
+    fn find(index: &IdentifierIndex, req: &FindRequest) -> Vec<String> {
+        let reply = index.find(req).unwrap();
+        reply
+            .get_matches()
+            .iter()
+            .map(|m| {
+                kythe_uri::to_vname(m.get_ticket())
+                    .unwrap()
+                    .get_signature()
+                    .to_owned()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn finds_qualified_names() {
+        let index = index();
+        assert_eq!(index.len(), 4);
+        let mut req = FindRequest::new();
+        req.set_identifier("foo::Bar::baz".into());
+        assert_eq!(find(&index, &req), ["decl", "defn", "java"]);
+        let reply = index.find(&req).unwrap();
+        let m = &reply.get_matches()[0];
+        assert_eq!(
+            (m.get_base_name(), m.get_node_kind()),
+            ("baz", nodes::FUNCTION)
+        );
+
+        req.set_identifier("pkg.T".into());
+        let reply = index.find(&req).unwrap();
+        assert_eq!(reply.get_matches()[0].get_base_name(), "T");
+        req.set_identifier("baz".into());
+        assert!(find(&index, &req).is_empty());
+    }
+
+    #[test]
+    fn filters_matches() {
+        let index = index();
+        let mut req = FindRequest::new();
+        req.set_identifier("foo::Bar::baz".into());
+        req.set_corpus(RepeatedField::from_vec(vec!["c".into()]));
+        assert_eq!(find(&index, &req), ["decl", "defn"]);
+        req.set_pick_canonical_nodes(true);
+        assert_eq!(find(&index, &req), ["defn"]);
+        req.set_corpus(RepeatedField::new());
+        req.set_languages(RepeatedField::from_vec(vec!["java".into()]));
+        assert_eq!(find(&index, &req), ["java"]);
+    }
+}
@@ -0,0 +1,35 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Serving tables for the Kythe xrefs API, built from the entries of a
+//! GraphStore as in `kythe/go/serving/pipeline`, and the services answering
+//! from them or from a GraphStore directly.
+
+mod error;
+pub mod explore;
+pub mod filetree;
+pub mod filters;
+pub mod graph;
+pub mod identifiers;
+pub mod link;
+pub mod pipeline;
+mod span;
+pub mod table;
+#[cfg(test)]
+mod testdata;
+pub mod xrefs;
+
+pub use error::{Error, Result};
+pub use pipeline::{build_tables, Options, Stats};
+pub use table::LocalTable;
@@ -0,0 +1,477 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An implementation of the `LinkService` API, resolving qualified names to
+//! the locations of their definitions as `kythe/go/services/link`.
+
+use crate::identifiers::{IdentifierService, RENDERED_QUALIFIED_NAME};
+use crate::xrefs::{recast, XRefService};
+use crate::{Error, Result};
+use identifier_rust_proto::FindRequest;
+use kythe_schema::{edges, facts};
+use kythe_uri::{Uri, SCHEME};
+use link_rust_proto::link::{
+    Link, LinkReply, LinkRequest, LinkRequest_DefinitionKind, LinkRequest_Location, Link_Node,
+};
+use protobuf::RepeatedField;
+use regex::Regex;
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use xref_rust_proto::{
+    Anchor, CrossReferencesReply, CrossReferencesRequest,
+    CrossReferencesRequest_DefinitionKind as DefinitionKind, SnippetsKind,
+};
+
+/// The most identifier matches a request may resolve to.
+pub const MAX_MATCHES: usize = 1000;
+
+/// The Kythe link resolution service.
+pub trait LinkService {
+    /// Returns the definition locations of the nodes named by the request's
+    /// identifier, which may also be the ticket of a node.
+    fn resolve(&self, req: &LinkRequest) -> Result<LinkReply>;
+}
+
+/// Resolves links by finding the nodes with an identifier and then their
+/// definitions.
+pub struct Resolver<X, I> {
+    xrefs: X,
+    identifiers: I,
+}
+
+/// The names of a node, as reported in a `Link_Node`.
+#[derive(Default)]
+struct Names {
+    base_name: String,
+    identifier: String,
+}
+
+impl<X: XRefService, I: IdentifierService> Resolver<X, I> {
+    pub fn new(xrefs: X, identifiers: I) -> Self {
+        Resolver { xrefs, identifiers }
+    }
+
+    /// Returns the tickets of the nodes named by the request's identifier,
+    /// along with their names. A ticket names only its own node, if it is in
+    /// the requested corpora and languages.
+    fn find(&self, req: &LinkRequest) -> Result<HashMap<String, Names>> {
+        let id = req.get_identifier();
+        if id.starts_with(SCHEME) {
+            let uri = Uri::parse(id)
+                .map_err(|err| Error::InvalidArgument(format!("invalid ticket {id:?}: {err}")))?;
+            let wanted =
+                |values: &[String], value: &String| values.is_empty() || values.contains(value);
+            if !wanted(req.get_corpus(), &uri.corpus) || !wanted(req.get_language(), &uri.language)
+            {
+                return Ok(HashMap::new());
+            }
+            return Ok(HashMap::from([(id.to_owned(), Names::default())]));
+        }
+
+        let mut find = FindRequest::new();
+        find.set_identifier(id.into());
+        find.set_corpus(req.get_corpus().into());
+        find.set_languages(req.get_language().into());
+        let found = self.identifiers.find(&find)?;
+        Ok(found
+            .get_matches()
+            .iter()
+            .filter(|m| kind_matches(m.get_node_kind(), m.get_node_subkind(), req.get_node_kind()))
+            .map(|m| {
+                let names = Names {
+                    base_name: m.get_base_name().into(),
+                    identifier: m.get_qualified_name().into(),
+                };
+                (m.get_ticket().to_owned(), names)
+            })
+            .collect())
+    }
+
+    /// Returns the cross-references of each ticket, requested separately and
+    /// merged, since related tickets in a single request may be merged by the
+    /// service.
+    fn cross_references(
+        &self,
+        tickets: &BTreeSet<&str>,
+        definition_kind: DefinitionKind,
+    ) -> Result<CrossReferencesReply> {
+        let mut merged = CrossReferencesReply::new();
+        for &ticket in tickets {
+            let mut req = CrossReferencesRequest::new();
+            req.set_ticket(RepeatedField::from_vec(vec![ticket.into()]));
+            req.set_definition_kind(definition_kind);
+            req.set_snippets(SnippetsKind::NONE);
+            req.set_filter(RepeatedField::from_vec(vec![
+                facts::NODE_KIND.into(),
+                facts::SUBKIND.into(),
+                facts::COMPLETE.into(),
+                RENDERED_QUALIFIED_NAME.into(),
+            ]));
+            req.set_related_node_kind(RepeatedField::from_vec(vec![edges::PARAM.into()]));
+            req.set_node_definitions(true);
+            let mut reply = self.xrefs.cross_references(&req)?;
+            merged
+                .mut_cross_references()
+                .extend(reply.take_cross_references());
+            merged.mut_nodes().extend(reply.take_nodes());
+            merged
+                .mut_definition_locations()
+                .extend(reply.take_definition_locations());
+        }
+        Ok(merged)
+    }
+}
+
+/// Selects files by their corpus, root and path.
+struct Matcher {
+    locations: Vec<(Option<String>, Regex, Regex)>,
+    /// The result when there are no locations.
+    empty: bool,
+}
+
+impl Matcher {
+    fn new(locs: &[LinkRequest_Location], empty: bool, what: &str) -> Result<Self> {
+        let regex = |re: &str| {
+            Regex::new(re).map_err(|err| Error::InvalidArgument(format!("{what}: {err}")))
+        };
+        let locations = locs
+            .iter()
+            .map(|loc| {
+                let corpus = (!loc.get_corpus().is_empty()).then(|| loc.get_corpus().to_owned());
+                Ok((corpus, regex(loc.get_root())?, regex(loc.get_path())?))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Matcher { locations, empty })
+    }
+
+    fn matches(&self, uri: &Uri) -> bool {
+        if self.locations.is_empty() {
+            return self.empty;
+        }
+        self.locations.iter().any(|(corpus, root, path)| {
+            corpus.iter().all(|c| *c == uri.corpus)
+                && root.is_match(&uri.root)
+                && path.is_match(&uri.path)
+        })
+    }
+}
+
+/// Reports whether a node kind and subkind are one of `kinds`, each of the
+/// form `kind` or `kind/subkind`. Lookup nodes match only when requested.
+fn kind_matches(kind: &str, subkind: &str, kinds: &[String]) -> bool {
+    if kinds.is_empty() {
+        return kind != "lookup";
+    }
+    let key = format!("{kind}/{subkind}");
+    kinds.iter().any(|k| k == kind || *k == key)
+}
+
+/// Returns the value of a fact of a node in `reply` as a string.
+fn fact<'a>(reply: &'a CrossReferencesReply, ticket: &str, name: &str) -> &'a str {
+    reply
+        .get_nodes()
+        .get(ticket)
+        .and_then(|info| info.get_facts().get(name))
+        .and_then(|value| std::str::from_utf8(value).ok())
+        .unwrap_or_default()
+}
+
+/// Returns the number of parameters of a node, given its related nodes.
+fn parameter_count(reply: &CrossReferencesReply, ticket: &str) -> i32 {
+    reply.get_cross_references().get(ticket).map_or(0, |set| {
+        set.get_related_node()
+            .iter()
+            .filter(|r| r.get_relation_kind() == edges::PARAM)
+            .map(|r| r.get_ordinal() + 1)
+            .max()
+            .unwrap_or(0)
+    })
+}
+
+/// Returns the anchors defining `ticket` in the selected files. A direct
+/// definition of the node supersedes all others.
+fn find_anchors(
+    ticket: &str,
+    reply: &CrossReferencesReply,
+    include: &Matcher,
+    exclude: &Matcher,
+) -> Vec<Anchor> {
+    let Ok(node) = Uri::parse(ticket) else {
+        return Vec::new();
+    };
+    let check = |anchor: &Anchor| match Uri::parse(anchor.get_ticket()) {
+        Ok(a) => {
+            (node.language.is_empty() || a.language == node.language)
+                && include.matches(&a)
+                && !exclude.matches(&a)
+        }
+        Err(_) => false,
+    };
+
+    let direct = reply
+        .get_nodes()
+        .get(ticket)
+        .and_then(|info| reply.get_definition_locations().get(info.get_definition()));
+    if let Some(anchor) = direct.filter(|a| check(a)) {
+        return vec![anchor.clone()];
+    }
+    reply
+        .get_cross_references()
+        .get(ticket)
+        .into_iter()
+        .flat_map(|set| set.get_definition())
+        .map(|ra| ra.get_anchor())
+        .filter(|a| edges::is_variant(a.get_kind(), edges::DEFINES) && check(a))
+        .cloned()
+        .collect()
+}
+
+impl<X: XRefService, I: IdentifierService> LinkService for Resolver<X, I> {
+    fn resolve(&self, req: &LinkRequest) -> Result<LinkReply> {
+        if req.get_identifier().is_empty() {
+            return Err(Error::InvalidArgument("missing link identifier".into()));
+        }
+        let include = Matcher::new(req.get_include(), true, "include")?;
+        let exclude = Matcher::new(req.get_exclude(), false, "exclude")?;
+
+        // Find the nodes named by the identifier.
+        let mut matches = self.find(req)?;
+        if matches.is_empty() {
+            return Err(Error::NotFound(format!(
+                "no matches for {:?}",
+                req.get_identifier()
+            )));
+        } else if matches.len() > MAX_MATCHES {
+            return Err(Error::InvalidArgument(format!(
+                "too many identifier matches ({} > {MAX_MATCHES})",
+                matches.len()
+            )));
+        }
+
+        // Find their definitions, preferring complete definitions to
+        // declarations when there are any.
+        let definition_kind = match req.get_definition_kind() {
+            LinkRequest_DefinitionKind::BINDING => DefinitionKind::BINDING_DEFINITIONS,
+            LinkRequest_DefinitionKind::FULL => DefinitionKind::FULL_DEFINITIONS,
+            LinkRequest_DefinitionKind::ANY => DefinitionKind::ALL_DEFINITIONS,
+        };
+        let reply = self.cross_references(
+            &matches.keys().map(String::as_str).collect(),
+            definition_kind,
+        )?;
+        if req.get_identifier().starts_with(SCHEME) {
+            // Nodes named by ticket are only now known to exist and be of
+            // the requested kind.
+            matches.retain(|ticket, names| {
+                names.identifier = fact(&reply, ticket, RENDERED_QUALIFIED_NAME).into();
+                reply.get_nodes().contains_key(ticket.as_str())
+                    && kind_matches(
+                        fact(&reply, ticket, facts::NODE_KIND),
+                        fact(&reply, ticket, facts::SUBKIND),
+                        req.get_node_kind(),
+                    )
+            });
+        }
+        let mut anchors: BTreeMap<&str, Vec<Anchor>> = BTreeMap::new();
+        let mut complete = BTreeSet::new();
+        let mut preferred = false;
+        for ticket in matches.keys().map(String::as_str) {
+            if req.has_params() && parameter_count(&reply, ticket) != req.get_params().get_count() {
+                continue;
+            }
+            let info = reply.get_nodes().get(ticket);
+            match info.and_then(|i| i.get_facts().get(facts::COMPLETE)) {
+                Some(c) if c == b"definition" => {
+                    if !preferred {
+                        complete.clear();
+                        preferred = true;
+                    }
+                    complete.insert(ticket);
+                }
+                Some(c) if c == b"complete" && !preferred => {
+                    complete.insert(ticket);
+                }
+                _ => {}
+            }
+            anchors.insert(ticket, find_anchors(ticket, &reply, &include, &exclude));
+        }
+        if !complete.is_empty() {
+            anchors.retain(|ticket, _| complete.contains(ticket));
+        }
+
+        // Keep one link per line of each file, recording every node defined
+        // there.
+        let mut links: BTreeMap<(String, i32), (Link, BTreeSet<&str>)> = BTreeMap::new();
+        for (ticket, anchors) in &anchors {
+            for anchor in anchors {
+                let key = (
+                    anchor.get_parent().to_owned(),
+                    anchor.get_span().get_start().get_line_number(),
+                );
+                let (_, nodes) = match links.entry(key) {
+                    Entry::Occupied(e) => e.into_mut(),
+                    Entry::Vacant(e) => {
+                        let mut link = Link::new();
+                        link.set_file_ticket(anchor.get_parent().into());
+                        link.set_span(recast(anchor.get_span())?);
+                        e.insert((link, BTreeSet::new()))
+                    }
+                };
+                nodes.insert(*ticket);
+            }
+        }
+        if links.is_empty() {
+            return Err(Error::NotFound(format!(
+                "no matching definitions for {:?}",
+                req.get_identifier()
+            )));
+        }
+
+        let mut out = LinkReply::new();
+        for (_, (mut link, nodes)) in links {
+            if req.get_include_nodes() {
+                for ticket in nodes {
+                    let names = &matches[ticket];
+                    let mut node = Link_Node::new();
+                    node.set_ticket(ticket.into());
+                    node.set_base_name(names.base_name.clone());
+                    node.set_identifier(names.identifier.clone());
+                    link.mut_nodes().push(node);
+                }
+            }
+            out.mut_links().push(link);
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identifiers::{IdentifierIndex, RENDERED_QUALIFIED_NAME};
+    use crate::table::LocalTable;
+    use crate::testdata::{self, ticket, vname};
+    use crate::xrefs::Table;
+    use crate::{build_tables, Options};
+    use kythe_schema::nodes;
+    use kythe_storage::emitter::{anchor_vname, Emitter};
+
+    /// Returns a resolver over the test graph, in which f and a function in
+    /// b.rs are both named `m::f`, and the latter is `complete` if given.
+    fn resolver(complete: Option<&str>) -> Resolver<Table, IdentifierIndex> {
+        let mut e = Emitter::new(testdata::store());
+        let (f, h, file) = (vname("f", ""), vname("h", ""), vname("", "b.rs"));
+        e.emit_fact(&f, RENDERED_QUALIFIED_NAME, b"m::f").unwrap();
+        e.emit_node(
+            &h,
+            nodes::FUNCTION,
+            [(RENDERED_QUALIFIED_NAME, &b"m::f"[..])],
+        )
+        .unwrap();
+        if let Some(complete) = complete {
+            e.emit_fact(&h, facts::COMPLETE, complete.as_bytes())
+                .unwrap();
+        }
+        e.emit_file(&file, b"fn f() {}\n", None).unwrap();
+        let anchor = anchor_vname(&file, 3, 4);
+        e.emit_anchor(&anchor, 3, 4).unwrap();
+        e.emit_edge(&anchor, edges::DEFINES_BINDING, &h).unwrap();
+        let store = e.into_inner();
+
+        let mut table = LocalTable::new();
+        build_tables(&store, &mut table, &Options::default()).unwrap();
+        let mut index = IdentifierIndex::new();
+        index.populate(&store).unwrap();
+        Resolver::new(Table::new(table), index)
+    }
+
+    fn location(corpus: &str, root: &str, path: &str) -> LinkRequest_Location {
+        let mut loc = LinkRequest_Location::new();
+        loc.set_corpus(corpus.into());
+        loc.set_root(root.into());
+        loc.set_path(path.into());
+        loc
+    }
+
+    fn files(reply: &LinkReply) -> Vec<&str> {
+        reply
+            .get_links()
+            .iter()
+            .map(|l| l.get_file_ticket())
+            .collect()
+    }
+
+    #[test]
+    fn resolves_filtered_definitions() {
+        let svc = resolver(None);
+        let mut req = LinkRequest::new();
+        req.set_identifier("m::f".into());
+        req.set_include_nodes(true);
+        let reply = svc.resolve(&req).unwrap();
+        assert_eq!(files(&reply), [ticket("", "a.rs"), ticket("", "b.rs")]);
+        let link = &reply.get_links()[0];
+        assert_eq!(link.get_span().get_start().get_byte_offset(), 3);
+        assert_eq!(link.get_nodes()[0].get_ticket(), ticket("f", ""));
+        assert_eq!(link.get_nodes()[0].get_identifier(), "m::f");
+
+        req.mut_exclude().push(location("", "", "^b"));
+        assert_eq!(files(&svc.resolve(&req).unwrap()), [ticket("", "a.rs")]);
+
+        req.mut_include().push(location("other", "", ""));
+        assert!(matches!(svc.resolve(&req), Err(Error::NotFound(_))));
+
+        req.mut_include()[0] = location("", "", "(");
+        assert!(matches!(svc.resolve(&req), Err(Error::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn prefers_complete_definitions() {
+        let mut req = LinkRequest::new();
+        req.set_identifier("m::f".into());
+        let reply = resolver(Some("definition")).resolve(&req).unwrap();
+        assert_eq!(files(&reply), [ticket("", "b.rs")]);
+        assert!(reply.get_links()[0].get_nodes().is_empty());
+
+        req.set_node_kind(RepeatedField::from_vec(vec!["record".into()]));
+        assert!(resolver(None).resolve(&req).is_err());
+    }
+
+    #[test]
+    fn resolves_tickets() {
+        let svc = resolver(None);
+        let mut req = LinkRequest::new();
+        req.set_identifier(ticket("h", ""));
+        req.set_include_nodes(true);
+        let reply = svc.resolve(&req).unwrap();
+        assert_eq!(files(&reply), [ticket("", "b.rs")]);
+        let node = &reply.get_links()[0].get_nodes()[0];
+        assert_eq!(node.get_ticket(), ticket("h", ""));
+        assert_eq!(node.get_identifier(), "m::f");
+
+        req.set_node_kind(RepeatedField::from_vec(vec!["function".into()]));
+        assert_eq!(svc.resolve(&req).unwrap().get_links().len(), 1);
+        req.set_node_kind(RepeatedField::from_vec(vec!["record".into()]));
+        assert!(matches!(svc.resolve(&req), Err(Error::NotFound(_))));
+        req.clear_node_kind();
+
+        req.set_corpus(RepeatedField::from_vec(vec!["other".into()]));
+        assert!(matches!(svc.resolve(&req), Err(Error::NotFound(_))));
+        req.clear_corpus();
+
+        req.set_identifier(ticket("missing", ""));
+        assert!(matches!(svc.resolve(&req), Err(Error::NotFound(_))));
+        req.set_identifier("kythe:?lang=%".into());
+        assert!(svc.resolve(&req).is_err());
+    }
+}
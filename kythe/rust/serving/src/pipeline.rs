@@ -0,0 +1,650 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Construction of xrefs serving tables from the entries of a GraphStore.
+//!
+//! The builder computes, for each file, its decorations (anchor spans and the
+//! nodes they refer to); for each referenced node, its paged cross-references
+//! (definitions, references and callers); a summary of every non-anchor
+//! node; and the documentation of each documented node. Everything is written
+//! to a [`LocalTable`] under the keys defined in [`crate::table`].
+
+use crate::span::Normalizer;
+use crate::table::{self, LocalTable};
+use crate::{Error, Result};
+use kythe_schema::{edges, facts, nodes};
+use kythe_storage::GraphStore;
+use serving_rust_proto::{
+    Document, ExpandedAnchor, Fact, FileDecorations, FileDecorations_Decoration, Node,
+    PagedCrossReferences, PagedCrossReferences_Caller, PagedCrossReferences_Group,
+    PagedCrossReferences_Page, PagedCrossReferences_PageIndex, RawAnchor,
+};
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::ControlFlow;
+use storage_rust_proto::{ScanRequest, VName};
+
+/// The default maximum number of anchors kept in a cross-references set
+/// before its groups are moved to separate pages.
+pub const DEFAULT_MAX_PAGE_SIZE: usize = 4000;
+
+/// The cross-references group kind holding the direct callers of a node.
+pub const CALLER_KIND_DIRECT: &str = "#internal/ref/call/direct";
+
+/// Options for [`build_tables`].
+#[derive(Clone, Debug)]
+pub struct Options {
+    /// The maximum number of anchors in a cross-references set before its
+    /// anchor groups are split into pages of at most this size. If zero, no
+    /// paging is done.
+    pub max_page_size: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            max_page_size: DEFAULT_MAX_PAGE_SIZE,
+        }
+    }
+}
+
+/// Counts of the values written by [`build_tables`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub nodes: usize,
+    pub files: usize,
+    pub decorations: usize,
+    pub cross_references: usize,
+    pub pages: usize,
+    pub documents: usize,
+}
+
+/// Reads every entry of `store` and writes the resulting serving data to
+/// `table`.
+pub fn build_tables(
+    store: &dyn GraphStore,
+    table: &mut LocalTable,
+    opts: &Options,
+) -> Result<Stats> {
+    let graph = Graph::load(store)?;
+    let mut stats = Stats::default();
+    graph.write_nodes(table, &mut stats)?;
+    graph.write_decorations(table, &mut stats)?;
+    graph.write_cross_references(table, opts, &mut stats)?;
+    graph.write_documents(table, &mut stats)?;
+    Ok(stats)
+}
+
+/// The facts and forward edges of a single node.
+#[derive(Default)]
+struct NodeData {
+    vname: VName,
+    facts: BTreeMap<String, Vec<u8>>,
+    /// (edge kind, target ticket) pairs.
+    edges: Vec<(String, String)>,
+}
+
+impl NodeData {
+    fn kind(&self) -> &[u8] {
+        self.facts.get(facts::NODE_KIND).map_or(&[], Vec::as_slice)
+    }
+
+    fn offset(&self, name: &str) -> Option<usize> {
+        std::str::from_utf8(self.facts.get(name)?)
+            .ok()?
+            .parse()
+            .ok()
+    }
+}
+
+struct FileData {
+    vname: VName,
+    text: Vec<u8>,
+    encoding: String,
+    norm: Normalizer,
+}
+
+struct Anchor {
+    file: String,
+    start: usize,
+    end: usize,
+    snippet: Option<(usize, usize)>,
+    build_config: String,
+    /// The semantic node enclosing the anchor, from its childof edge.
+    parent: Option<String>,
+}
+
+/// An edge from an anchor to the node it refers to.
+struct Reference {
+    anchor: String,
+    kind: String,
+    target: String,
+}
+
+struct Graph {
+    nodes: BTreeMap<String, NodeData>,
+    files: BTreeMap<String, FileData>,
+    anchors: BTreeMap<String, Anchor>,
+    refs: Vec<Reference>,
+    /// The definition anchor of each node that has one.
+    definitions: BTreeMap<String, String>,
+}
+
+impl Graph {
+    fn load(store: &dyn GraphStore) -> Result<Self> {
+        let mut nodes: BTreeMap<String, NodeData> = BTreeMap::new();
+        store.scan(&ScanRequest::new(), &mut |entry| {
+            let ticket = kythe_uri::to_string(entry.get_source());
+            let node = nodes.entry(ticket).or_insert_with(|| NodeData {
+                vname: entry.get_source().clone(),
+                ..NodeData::default()
+            });
+            if entry.get_edge_kind().is_empty() {
+                node.facts.insert(
+                    entry.get_fact_name().into(),
+                    entry.get_fact_value().to_vec(),
+                );
+            } else if edges::is_forward(entry.get_edge_kind()) {
+                node.edges.push((
+                    entry.get_edge_kind().into(),
+                    kythe_uri::to_string(entry.get_target()),
+                ));
+            }
+            ControlFlow::Continue(())
+        })?;
+
+        let files: BTreeMap<String, FileData> = nodes
+            .iter()
+            .filter(|(_, node)| node.kind() == nodes::FILE.as_bytes())
+            .filter_map(|(ticket, node)| {
+                let text = node.facts.get(facts::TEXT)?.clone();
+                let encoding = node.facts.get(facts::TEXT_ENCODING).map_or_else(
+                    || facts::DEFAULT_TEXT_ENCODING.to_string(),
+                    |e| String::from_utf8_lossy(e).into_owned(),
+                );
+                let norm = Normalizer::new(&text);
+                Some((
+                    ticket.clone(),
+                    FileData {
+                        vname: node.vname.clone(),
+                        text,
+                        encoding,
+                        norm,
+                    },
+                ))
+            })
+            .collect();
+
+        let mut anchors = BTreeMap::new();
+        let mut refs = Vec::new();
+        for (ticket, node) in &nodes {
+            if node.kind() != nodes::ANCHOR.as_bytes() {
+                continue;
+            }
+            let Some(anchor) = Self::anchor(node, &files) else {
+                continue;
+            };
+            for (kind, target) in &node.edges {
+                if edges::is_anchor_edge(kind) {
+                    refs.push(Reference {
+                        anchor: ticket.clone(),
+                        kind: kind.clone(),
+                        target: target.clone(),
+                    });
+                }
+            }
+            anchors.insert(ticket.clone(), anchor);
+        }
+
+        let mut definitions = BTreeMap::new();
+        for r in &refs {
+            if edges::canonical(&r.kind) == edges::DEFINES_BINDING {
+                definitions
+                    .entry(r.target.clone())
+                    .or_insert_with(|| r.anchor.clone());
+            }
+        }
+        Ok(Self {
+            nodes,
+            files,
+            anchors,
+            refs,
+            definitions,
+        })
+    }
+
+    /// Returns the anchor described by `node`, if it has valid offsets within
+    /// a known file.
+    fn anchor(node: &NodeData, files: &BTreeMap<String, FileData>) -> Option<Anchor> {
+        let mut file = None;
+        let mut parent = None;
+        for (kind, target) in &node.edges {
+            if kind == edges::CHILD_OF {
+                if files.contains_key(target) {
+                    file = Some(target.clone());
+                } else {
+                    parent = Some(target.clone());
+                }
+            }
+        }
+        // By convention, an anchor shares the corpus, root and path of its file.
+        let file = file.unwrap_or_else(|| {
+            let mut v = VName::new();
+            v.set_corpus(node.vname.get_corpus().into());
+            v.set_root(node.vname.get_root().into());
+            v.set_path(node.vname.get_path().into());
+            kythe_uri::to_string(&v)
+        });
+        let len = files.get(&file)?.text.len();
+        let (start, end) = (
+            node.offset(facts::ANCHOR_START)?,
+            node.offset(facts::ANCHOR_END)?,
+        );
+        if start > end || end > len {
+            return None;
+        }
+        let snippet = match (
+            node.offset(facts::SNIPPET_START),
+            node.offset(facts::SNIPPET_END),
+        ) {
+            (Some(s), Some(e)) if s <= e && e <= len => Some((s, e)),
+            _ => None,
+        };
+        let build_config = node
+            .facts
+            .get(facts::BUILD_CONFIG)
+            .map(|c| String::from_utf8_lossy(c).into_owned())
+            .unwrap_or_default();
+        Some(Anchor {
+            file,
+            start,
+            end,
+            snippet,
+            build_config,
+            parent,
+        })
+    }
+
+    fn raw_anchor(&self, ticket: &str) -> Result<RawAnchor> {
+        let anchor = &self.anchors[ticket];
+        let offset = |offset: usize| {
+            i32::try_from(offset).map_err(|_| {
+                Error::InvalidArgument(format!("offset {offset} of anchor {ticket} is too large"))
+            })
+        };
+        let mut raw = RawAnchor::new();
+        raw.set_ticket(ticket.into());
+        raw.set_start_offset(offset(anchor.start)?);
+        raw.set_end_offset(offset(anchor.end)?);
+        if let Some((start, end)) = anchor.snippet {
+            raw.set_snippet_start(offset(start)?);
+            raw.set_snippet_end(offset(end)?);
+        }
+        raw.set_build_configuration(anchor.build_config.clone());
+        Ok(raw)
+    }
+
+    /// Returns the anchor with its text, snippet and line-based spans.
+    fn expand_anchor(&self, ticket: &str, kind: &str) -> ExpandedAnchor {
+        let anchor = &self.anchors[ticket];
+        let file = &self.files[&anchor.file];
+        let (snippet_start, snippet_end) = anchor
+            .snippet
+            .unwrap_or_else(|| file.norm.line_bounds(anchor.start));
+        let text =
+            |start: usize, end: usize| String::from_utf8_lossy(&file.text[start..end]).into_owned();
+
+        let mut ea = ExpandedAnchor::new();
+        ea.set_ticket(ticket.into());
+        ea.set_kind(kind.into());
+        ea.set_text(text(anchor.start, anchor.end));
+        ea.set_span(file.norm.span(anchor.start, anchor.end));
+        ea.set_snippet(text(snippet_start, snippet_end));
+        ea.set_snippet_span(file.norm.span(snippet_start, snippet_end));
+        ea.set_build_configuration(anchor.build_config.clone());
+        let path = ea.mut_file_info().mut_corpus_path();
+        path.set_corpus(file.vname.get_corpus().into());
+        path.set_root(file.vname.get_root().into());
+        path.set_path(file.vname.get_path().into());
+        ea
+    }
+
+    fn definition(&self, ticket: &str) -> Option<ExpandedAnchor> {
+        let anchor = self.definitions.get(ticket)?;
+        Some(self.expand_anchor(anchor, edges::DEFINES_BINDING))
+    }
+
+    /// Returns a node with the given facts of `ticket`, or all of its facts
+    /// other than file text if `names` is `None`.
+    fn node(&self, ticket: &str, names: Option<&[&str]>) -> Node {
+        let mut node = Node::new();
+        node.set_ticket(ticket.into());
+        if let Some(data) = self.nodes.get(ticket) {
+            for (name, value) in &data.facts {
+                let wanted = match names {
+                    Some(names) => names.contains(&name.as_str()),
+                    None => name != facts::TEXT,
+                };
+                if wanted {
+                    let mut fact = Fact::new();
+                    fact.set_name(name.clone());
+                    fact.set_value(value.clone());
+                    node.mut_fact().push(fact);
+                }
+            }
+        }
+        node
+    }
+
+    fn write_nodes(&self, table: &mut LocalTable, stats: &mut Stats) -> Result<()> {
+        for (ticket, data) in &self.nodes {
+            if data.kind() == nodes::ANCHOR.as_bytes() {
+                continue;
+            }
+            let mut node = self.node(ticket, None);
+            if let Some(def) = self.definition(ticket) {
+                node.set_definition_location(def);
+            }
+            table.put_proto(table::node_key(ticket), &node)?;
+            stats.nodes += 1;
+        }
+        Ok(())
+    }
+
+    fn write_documents(&self, table: &mut LocalTable, stats: &mut Stats) -> Result<()> {
+        for data in self.nodes.values() {
+            if data.kind() != nodes::DOC.as_bytes() {
+                continue;
+            }
+            let text = data.facts.get(facts::TEXT).map_or(&[][..], Vec::as_slice);
+            for (kind, target) in &data.edges {
+                if kind != edges::DOCUMENTS {
+                    continue;
+                }
+                let mut doc = Document::new();
+                doc.set_ticket(target.clone());
+                doc.set_raw_text(String::from_utf8_lossy(text).into_owned());
+                table.put_proto(table::documentation_key(target), &doc)?;
+                stats.documents += 1;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_decorations(&self, table: &mut LocalTable, stats: &mut Stats) -> Result<()> {
+        let mut by_file: BTreeMap<&str, Vec<&Reference>> = BTreeMap::new();
+        for r in &self.refs {
+            by_file
+                .entry(&self.anchors[&r.anchor].file)
+                .or_default()
+                .push(r);
+        }
+
+        for (ticket, file) in &self.files {
+            let mut decor = FileDecorations::new();
+            let f = decor.mut_file();
+            f.set_ticket(ticket.clone());
+            f.set_text(file.text.clone());
+            f.set_encoding(file.encoding.clone());
+
+            let mut refs = by_file.remove(ticket.as_str()).unwrap_or_default();
+            refs.sort_by_key(|r| {
+                let a = &self.anchors[&r.anchor];
+                (a.start, a.end, &r.kind, &r.target)
+            });
+            let mut targets = BTreeSet::new();
+            let mut definitions = BTreeSet::new();
+            for r in refs {
+                let mut d = FileDecorations_Decoration::new();
+                d.set_anchor(self.raw_anchor(&r.anchor)?);
+                d.set_kind(r.kind.clone());
+                d.set_target(r.target.clone());
+                if let Some(def) = self.definitions.get(&r.target) {
+                    d.set_target_definition(def.clone());
+                    definitions.insert(def);
+                }
+                targets.insert(&r.target);
+                decor.mut_decoration().push(d);
+            }
+            for target in targets {
+                let node = self.node(target, Some(&[facts::NODE_KIND, facts::SUBKIND]));
+                decor.mut_target().push(node);
+            }
+            for def in definitions {
+                let ea = self.expand_anchor(def, edges::DEFINES_BINDING);
+                decor.mut_target_definitions().push(ea);
+            }
+            table.put_proto(table::decorations_key(ticket), &decor)?;
+            stats.files += 1;
+            stats.decorations += decor.get_decoration().len();
+        }
+        Ok(())
+    }
+
+    fn write_cross_references(
+        &self,
+        table: &mut LocalTable,
+        opts: &Options,
+        stats: &mut Stats,
+    ) -> Result<()> {
+        let mut by_target: BTreeMap<&str, Vec<&Reference>> = BTreeMap::new();
+        for r in &self.refs {
+            by_target.entry(&r.target).or_default().push(r);
+        }
+
+        for (ticket, refs) in by_target {
+            // Groups are keyed by (build config, kind) to keep them in order.
+            let mut groups: BTreeMap<(String, String), PagedCrossReferences_Group> =
+                BTreeMap::new();
+            let mut callers: BTreeMap<&str, BTreeMap<&str, Vec<&str>>> = BTreeMap::new();
+            for r in refs {
+                let anchor = &self.anchors[&r.anchor];
+                let key = (anchor.build_config.clone(), r.kind.clone());
+                groups
+                    .entry(key)
+                    .or_insert_with(|| new_group(&r.kind, &anchor.build_config))
+                    .mut_anchor()
+                    .push(self.expand_anchor(&r.anchor, &r.kind));
+
+                if edges::is_variant(edges::canonical(&r.kind), edges::REF_CALL) {
+                    if let Some(parent) = &anchor.parent {
+                        callers
+                            .entry(&anchor.build_config)
+                            .or_default()
+                            .entry(parent)
+                            .or_default()
+                            .push(&r.anchor);
+                    }
+                }
+            }
+            for (config, by_caller) in callers {
+                let key = (config.to_string(), CALLER_KIND_DIRECT.to_string());
+                let group = groups
+                    .entry(key)
+                    .or_insert_with(|| new_group(CALLER_KIND_DIRECT, config));
+                for (caller, sites) in by_caller {
+                    let mut c = PagedCrossReferences_Caller::new();
+                    c.set_semantic_caller(caller.into());
+                    if let Some(def) = self.definition(caller) {
+                        c.set_caller(def);
+                    }
+                    for site in sites {
+                        c.mut_callsite()
+                            .push(self.expand_anchor(site, edges::REF_CALL));
+                    }
+                    group.mut_caller().push(c);
+                }
+            }
+
+            let mut set = PagedCrossReferences::new();
+            set.set_source_ticket(ticket.into());
+            set.set_source_node(self.node(ticket, None));
+            let incomplete = self
+                .nodes
+                .get(ticket)
+                .and_then(|n| n.facts.get(facts::COMPLETE))
+                .is_some_and(|c| c != b"definition");
+            set.set_incomplete(incomplete);
+
+            let total: usize = groups.values().map(|g| g.get_anchor().len()).sum();
+            let paged = opts.max_page_size > 0 && total > opts.max_page_size;
+            for (_, mut group) in groups {
+                group
+                    .mut_anchor()
+                    .sort_by(|a, b| a.get_ticket().cmp(b.get_ticket()));
+                if !paged || group.get_anchor().is_empty() {
+                    set.mut_group().push(group);
+                    continue;
+                }
+                // Anchors are moved to pages, leaving any callers inline.
+                let anchors = group.take_anchor().into_vec();
+                for chunk in anchors.chunks(opts.max_page_size) {
+                    let key = format!("{}.{:010}", ticket, set.get_page_index().len());
+                    let mut idx = PagedCrossReferences_PageIndex::new();
+                    idx.set_kind(group.get_kind().into());
+                    idx.set_count(chunk.len() as i32);
+                    idx.set_page_key(key.clone());
+                    idx.set_build_config(group.get_build_config().into());
+                    set.mut_page_index().push(idx);
+
+                    let mut page = PagedCrossReferences_Page::new();
+                    page.set_page_key(key.clone());
+                    page.set_source_ticket(ticket.into());
+                    let g = page.mut_group();
+                    g.set_kind(group.get_kind().into());
+                    g.set_build_config(group.get_build_config().into());
+                    g.set_anchor(chunk.to_vec().into());
+                    table.put_proto(table::cross_references_page_key(&key), &page)?;
+                    stats.pages += 1;
+                }
+                if !group.get_caller().is_empty() {
+                    set.mut_group().push(group);
+                }
+            }
+            table.put_proto(table::cross_references_key(ticket), &set)?;
+            stats.cross_references += 1;
+        }
+        Ok(())
+    }
+}
+
+fn new_group(kind: &str, build_config: &str) -> PagedCrossReferences_Group {
+    let mut g = PagedCrossReferences_Group::new();
+    g.set_kind(kind.into());
+    g.set_build_config(build_config.into());
+    g
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testdata::{store, vname, TEXT};
+
+    fn xrefs(table: &LocalTable, node: &VName) -> PagedCrossReferences {
+        let key = table::cross_references_key(&kythe_uri::to_string(node));
+        table.get_proto(&key).unwrap().unwrap()
+    }
+
+    #[test]
+    fn builds_decorations_and_nodes() {
+        let mut table = LocalTable::new();
+        let stats = build_tables(&store(), &mut table, &Options::default()).unwrap();
+        assert_eq!(
+            stats,
+            Stats {
+                nodes: 4,
+                files: 1,
+                decorations: 4,
+                cross_references: 2,
+                pages: 0,
+                documents: 1,
+            }
+        );
+
+        let file = kythe_uri::to_string(&vname("", "a.rs"));
+        let decor: FileDecorations = table
+            .get_proto(&table::decorations_key(&file))
+            .unwrap()
+            .unwrap();
+        assert_eq!(decor.get_file().get_text(), TEXT.as_bytes());
+        let starts: Vec<i32> = decor
+            .get_decoration()
+            .iter()
+            .map(|d| d.get_anchor().get_start_offset())
+            .collect();
+        assert_eq!(starts, [3, 13, 19, 24]);
+        let call = &decor.get_decoration()[2];
+        assert_eq!(call.get_kind(), edges::REF_CALL);
+        assert_eq!(
+            call.get_target_definition(),
+            decor.get_decoration()[0].get_anchor().get_ticket()
+        );
+        assert_eq!(decor.get_target().len(), 2);
+        assert_eq!(decor.get_target_definitions().len(), 2);
+
+        let f = kythe_uri::to_string(&vname("f", ""));
+        let node: Node = table.get_proto(&table::node_key(&f)).unwrap().unwrap();
+        let def = node.get_definition_location();
+        assert_eq!(def.get_text(), "f");
+        assert_eq!(def.get_snippet(), "fn f() {}");
+        assert_eq!(def.get_span().get_start().get_line_number(), 1);
+    }
+
+    #[test]
+    fn builds_cross_references_with_callers() {
+        let mut table = LocalTable::new();
+        build_tables(&store(), &mut table, &Options::default()).unwrap();
+
+        let set = xrefs(&table, &vname("f", ""));
+        let kinds: Vec<&str> = set.get_group().iter().map(|g| g.get_kind()).collect();
+        assert_eq!(
+            kinds,
+            [CALLER_KIND_DIRECT, edges::DEFINES_BINDING, edges::REF_CALL]
+        );
+
+        let callers = set.get_group()[0].get_caller();
+        assert_eq!(callers.len(), 1);
+        assert_eq!(
+            callers[0].get_semantic_caller(),
+            kythe_uri::to_string(&vname("g", ""))
+        );
+        assert_eq!(callers[0].get_caller().get_text(), "g");
+        assert_eq!(callers[0].get_callsite().len(), 2);
+
+        let calls = set.get_group()[2].get_anchor();
+        assert_eq!(calls[0].get_span().get_start().get_line_number(), 2);
+        assert_eq!(calls[0].get_snippet(), "fn g() { f(); f(); }");
+    }
+
+    #[test]
+    fn pages_large_sets() {
+        let mut table = LocalTable::new();
+        let opts = Options { max_page_size: 2 };
+        let stats = build_tables(&store(), &mut table, &opts).unwrap();
+        assert_eq!(stats.pages, 2);
+
+        let set = xrefs(&table, &vname("f", ""));
+        let kinds: Vec<&str> = set.get_group().iter().map(|g| g.get_kind()).collect();
+        assert_eq!(kinds, [CALLER_KIND_DIRECT]);
+        let counts: Vec<(&str, i32)> = set
+            .get_page_index()
+            .iter()
+            .map(|idx| (idx.get_kind(), idx.get_count()))
+            .collect();
+        assert_eq!(counts, [(edges::DEFINES_BINDING, 1), (edges::REF_CALL, 2)]);
+
+        let key = table::cross_references_page_key(set.get_page_index()[1].get_page_key());
+        let page: PagedCrossReferences_Page = table.get_proto(&key).unwrap().unwrap();
+        assert_eq!(page.get_group().get_anchor().len(), 2);
+    }
+}
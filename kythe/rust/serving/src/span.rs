@@ -0,0 +1,83 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Serving points and spans computed by [`kythe_span::Normalizer`].
+
+use kythe_span::{Encoding, Position};
+use serving_rust_proto::{Point, Span};
+
+/// Maps byte offsets within a text to normalized points.
+pub(crate) struct Normalizer(kythe_span::Normalizer);
+
+impl Normalizer {
+    pub(crate) fn new(text: &[u8]) -> Self {
+        Self(kythe_span::Normalizer::new(text))
+    }
+
+    /// Returns the point for `offset`, clamped to the bounds of the text.
+    pub(crate) fn point(&self, offset: usize) -> Point {
+        let offset = offset.min(self.0.len());
+        let pos = self.0.position(offset, Encoding::Utf8);
+        let mut p = Point::new();
+        p.set_byte_offset(offset as i32);
+        p.set_line_number(pos.line as i32 + 1);
+        p.set_column_offset(pos.column as i32);
+        p
+    }
+
+    /// Returns the byte offset of a point, preferring its line and column if
+    /// its line number is known. The result is clamped to the text.
+    pub(crate) fn offset(&self, byte_offset: i32, line_number: i32, column_offset: i32) -> usize {
+        if line_number <= 0 {
+            return (byte_offset.max(0) as usize).min(self.0.len());
+        }
+        let pos = Position::new(line_number as usize - 1, column_offset.max(0) as usize);
+        self.0.offset(pos, Encoding::Utf8)
+    }
+
+    /// Returns the span between two offsets.
+    pub(crate) fn span(&self, start: usize, end: usize) -> Span {
+        let mut s = Span::new();
+        s.set_start(self.point(start));
+        s.set_end(self.point(end));
+        s
+    }
+
+    /// Returns the offsets of the line containing `offset`, excluding its
+    /// trailing newline.
+    pub(crate) fn line_bounds(&self, offset: usize) -> (usize, usize) {
+        self.0.line_bounds_at(offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_and_lines() {
+        let n = Normalizer::new(b"ab\ncd\n");
+        let p = n.point(4);
+        assert_eq!((p.get_line_number(), p.get_column_offset()), (2, 1));
+        assert_eq!(n.point(100).get_byte_offset(), 6);
+        assert_eq!(n.point(6).get_line_number(), 3);
+        assert_eq!(n.line_bounds(1), (0, 2));
+        assert_eq!(n.line_bounds(3), (3, 5));
+        assert_eq!(n.line_bounds(6), (6, 6));
+        assert_eq!(n.offset(4, 0, 0), 4);
+        assert_eq!(n.offset(0, 2, 1), 4);
+        assert_eq!(n.offset(0, 1, 10), 2);
+        assert_eq!(n.offset(-1, 0, 0), 0);
+    }
+}
@@ -0,0 +1,180 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A local key-value table holding serving data, and the keys under which
+//! each kind of serving data is stored, as `kythe/go/serving/xrefs`.
+//!
+//! On disk, a table is a delimited stream of alternating key and value
+//! records in key order.
+
+use crate::{Error, Result};
+use kythe_storage::delimited::{Reader, Writer};
+use protobuf::Message;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+const CROSS_REFERENCES_PREFIX: &str = "xrefs:";
+const CROSS_REFERENCES_PAGE_PREFIX: &str = "xrefPages:";
+const DECORATIONS_PREFIX: &str = "decor:";
+const DOCUMENTATION_PREFIX: &str = "docs:";
+const NODES_PREFIX: &str = "nodes:";
+
+/// Returns the key of the `FileDecorations` for the given file ticket.
+pub fn decorations_key(ticket: &str) -> Vec<u8> {
+    [DECORATIONS_PREFIX, ticket].concat().into_bytes()
+}
+
+/// Returns the key of the `PagedCrossReferences` for the given node ticket.
+pub fn cross_references_key(ticket: &str) -> Vec<u8> {
+    [CROSS_REFERENCES_PREFIX, ticket].concat().into_bytes()
+}
+
+/// Returns the key of the `PagedCrossReferences.Page` with the given page key.
+pub fn cross_references_page_key(page_key: &str) -> Vec<u8> {
+    [CROSS_REFERENCES_PAGE_PREFIX, page_key]
+        .concat()
+        .into_bytes()
+}
+
+/// Returns the key of the `Document` for the given node ticket.
+pub fn documentation_key(ticket: &str) -> Vec<u8> {
+    [DOCUMENTATION_PREFIX, ticket].concat().into_bytes()
+}
+
+/// Returns the key of the `Node` summary for the given node ticket.
+pub fn node_key(ticket: &str) -> Vec<u8> {
+    [NODES_PREFIX, ticket].concat().into_bytes()
+}
+
+/// An ordered key-value table held in memory and stored in a local file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LocalTable {
+    data: BTreeMap<Vec<u8>, Vec<u8>>,
+}
+
+impl LocalTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads the table stored at `path`.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::read_from(BufReader::new(File::open(path)?))
+    }
+
+    /// Reads a table from its on-disk encoding.
+    pub fn read_from<R: BufRead>(r: R) -> Result<Self> {
+        let mut rd = Reader::new(r);
+        let mut data = BTreeMap::new();
+        while let Some(key) = rd.next_record()? {
+            let key = key.to_vec();
+            let Some(value) = rd.next_record()? else {
+                return Err(Error::InvalidTable(format!(
+                    "missing value for key {:?}",
+                    String::from_utf8_lossy(&key)
+                )));
+            };
+            data.insert(key, value.to_vec());
+        }
+        Ok(Self { data })
+    }
+
+    /// Stores the table at `path`, replacing any existing file.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let mut w = BufWriter::new(File::create(path)?);
+        self.write_to(&mut w)?;
+        Ok(w.flush()?)
+    }
+
+    /// Writes the table's on-disk encoding to `w`.
+    pub fn write_to<W: Write>(&self, w: W) -> Result<()> {
+        let mut wr = Writer::new(w);
+        for (key, value) in &self.data {
+            wr.put_record(key)?;
+            wr.put_record(value)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the number of keys in the table.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Reports whether the table has no keys.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Sets the value of `key`, replacing any existing value.
+    pub fn put(&mut self, key: impl Into<Vec<u8>>, value: impl Into<Vec<u8>>) {
+        self.data.insert(key.into(), value.into());
+    }
+
+    /// Returns the value of `key`, if any.
+    pub fn get(&self, key: &[u8]) -> Option<&[u8]> {
+        self.data.get(key).map(Vec::as_slice)
+    }
+
+    /// Sets the value of `key` to the encoding of `msg`.
+    pub fn put_proto<M: Message>(&mut self, key: impl Into<Vec<u8>>, msg: &M) -> Result<()> {
+        self.put(key, msg.write_to_bytes()?);
+        Ok(())
+    }
+
+    /// Returns the value of `key` decoded as an `M`, if any.
+    pub fn get_proto<M: Message>(&self, key: &[u8]) -> Result<Option<M>> {
+        self.get(key)
+            .map(|value| M::parse_from_bytes(value).map_err(Error::from))
+            .transpose()
+    }
+
+    /// Returns the keys and values whose keys start with `prefix`, in order.
+    pub fn scan_prefix<'a>(
+        &'a self,
+        prefix: &'a [u8],
+    ) -> impl Iterator<Item = (&'a [u8], &'a [u8])> + 'a {
+        self.data
+            .range(prefix.to_vec()..)
+            .take_while(move |(key, _)| key.starts_with(prefix))
+            .map(|(key, value)| (key.as_slice(), value.as_slice()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut table = LocalTable::new();
+        table.put(node_key("kythe:#b"), "2");
+        table.put(node_key("kythe:#a"), "1");
+        table.put(decorations_key("kythe:#a"), "3");
+
+        let mut buf = Vec::new();
+        table.write_to(&mut buf).unwrap();
+        let loaded = LocalTable::read_from(&buf[..]).unwrap();
+        assert_eq!(loaded, table);
+
+        let values: Vec<&[u8]> = loaded.scan_prefix(b"nodes:").map(|(_, v)| v).collect();
+        assert_eq!(values, [b"1", b"2"]);
+        assert_eq!(loaded.get(b"decor:kythe:#a"), Some(&b"3"[..]));
+
+        buf.truncate(buf.len() - 2);
+        assert!(LocalTable::read_from(&buf[..]).is_err());
+    }
+}
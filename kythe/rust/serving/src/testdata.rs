@@ -0,0 +1,62 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A small graph shared by the tests of the table builder and services.
+
+use kythe_schema::{edges, facts, nodes};
+use kythe_storage::emitter::{anchor_vname, Emitter};
+use kythe_storage::MemoryStore;
+use storage_rust_proto::VName;
+
+pub(crate) const TEXT: &str = "fn f() {}\nfn g() { f(); f(); }\n";
+
+pub(crate) fn vname(signature: &str, path: &str) -> VName {
+    let mut v = VName::new();
+    v.set_signature(signature.into());
+    v.set_corpus("c".into());
+    v.set_path(path.into());
+    v
+}
+
+pub(crate) fn ticket(signature: &str, path: &str) -> String {
+    kythe_uri::to_string(&vname(signature, path))
+}
+
+/// Returns a store for TEXT in which g calls f twice and f is documented.
+pub(crate) fn store() -> MemoryStore {
+    let mut e = Emitter::new(MemoryStore::new());
+    let file = vname("", "a.rs");
+    let (f, g, doc) = (vname("f", ""), vname("g", ""), vname("doc", ""));
+    e.emit_file(&file, TEXT.as_bytes(), None).unwrap();
+    e.emit_node(&f, nodes::FUNCTION, []).unwrap();
+    e.emit_node(&g, nodes::FUNCTION, []).unwrap();
+    e.emit_node(&doc, nodes::DOC, [(facts::TEXT, &b"Does f."[..])])
+        .unwrap();
+    e.emit_edge(&doc, edges::DOCUMENTS, &f).unwrap();
+
+    let mut anchor =
+        |start: usize, end: usize, kind: &str, target: &VName, parent: Option<&VName>| {
+            let a = anchor_vname(&file, start, end);
+            e.emit_anchor(&a, start, end).unwrap();
+            e.emit_edge(&a, kind, target).unwrap();
+            if let Some(parent) = parent {
+                e.emit_edge(&a, edges::CHILD_OF, parent).unwrap();
+            }
+        };
+    anchor(3, 4, edges::DEFINES_BINDING, &f, None);
+    anchor(13, 14, edges::DEFINES_BINDING, &g, None);
+    anchor(19, 20, edges::REF_CALL, &f, Some(&g));
+    anchor(24, 25, edges::REF_CALL, &f, Some(&g));
+    e.into_inner()
+}
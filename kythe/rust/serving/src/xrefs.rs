@@ -0,0 +1,669 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An implementation of the `XRefService` API over serving tables, as
+//! `kythe/go/serving/xrefs`.
+
+use crate::filters::Filters;
+use crate::pipeline::CALLER_KIND_DIRECT;
+use crate::span::Normalizer;
+use crate::table::{self, LocalTable};
+use crate::{Error, Result};
+use kythe_schema::edges;
+use protobuf::{Message, RepeatedField};
+use serving_rust_proto::{
+    Document, ExpandedAnchor, FileDecorations, Node, PagedCrossReferences,
+    PagedCrossReferences_Group, PagedCrossReferences_Page,
+};
+use std::collections::{HashMap, HashSet};
+use xref_rust_proto::{
+    Anchor, CrossReferencesReply, CrossReferencesReply_CrossReferenceSet,
+    CrossReferencesReply_RelatedAnchor, CrossReferencesRequest,
+    CrossReferencesRequest_CallerKind as CallerKind,
+    CrossReferencesRequest_DeclarationKind as DeclarationKind,
+    CrossReferencesRequest_DefinitionKind as DefinitionKind,
+    CrossReferencesRequest_ReferenceKind as ReferenceKind, DecorationsReply,
+    DecorationsReply_Reference, DecorationsRequest, DecorationsRequest_SpanKind as SpanKind,
+    DocumentationReply, DocumentationReply_Document, DocumentationRequest, Location_Kind, NodeInfo,
+    SnippetsKind,
+};
+
+/// The number of cross-references returned when a request has no page size.
+pub const DEFAULT_PAGE_SIZE: usize = 2048;
+
+/// The maximum number of cross-references returned in a single reply.
+pub const MAX_PAGE_SIZE: usize = 10000;
+
+const CALLER_KIND_OVERRIDE: &str = "#internal/ref/call/override";
+const DECLARATION_KIND: &str = "#internal/ref/declare";
+
+/// The Kythe cross-reference service.
+pub trait XRefService {
+    /// Returns the references and other decorations of a file or span.
+    fn decorations(&self, req: &DecorationsRequest) -> Result<DecorationsReply>;
+
+    /// Returns the cross-references of a set of nodes.
+    fn cross_references(&self, req: &CrossReferencesRequest) -> Result<CrossReferencesReply>;
+
+    /// Returns the documentation of a set of nodes.
+    fn documentation(&self, req: &DocumentationRequest) -> Result<DocumentationReply>;
+}
+
+/// Reports whether a cross-references group kind matches the requested
+/// definition kind.
+pub fn is_def_kind(requested: DefinitionKind, kind: &str, incomplete: bool) -> bool {
+    let kind = edges::canonical(kind);
+    if is_decl_kind(DeclarationKind::ALL_DECLARATIONS, kind, incomplete) {
+        return false;
+    }
+    match requested {
+        DefinitionKind::NO_DEFINITIONS => false,
+        DefinitionKind::FULL_DEFINITIONS => kind == edges::DEFINES,
+        DefinitionKind::BINDING_DEFINITIONS => kind == edges::DEFINES_BINDING,
+        DefinitionKind::ALL_DEFINITIONS => edges::is_variant(kind, edges::DEFINES),
+    }
+}
+
+/// Reports whether a cross-references group kind matches the requested
+/// declaration kind.
+pub fn is_decl_kind(requested: DeclarationKind, kind: &str, incomplete: bool) -> bool {
+    let kind = edges::canonical(kind);
+    match requested {
+        DeclarationKind::NO_DECLARATIONS => false,
+        DeclarationKind::ALL_DECLARATIONS => {
+            (incomplete && edges::is_variant(kind, edges::DEFINES)) || kind == DECLARATION_KIND
+        }
+    }
+}
+
+/// Reports whether a cross-references group kind matches the requested
+/// reference kind.
+pub fn is_ref_kind(requested: ReferenceKind, kind: &str) -> bool {
+    let kind = edges::canonical(kind);
+    match requested {
+        ReferenceKind::NO_REFERENCES => false,
+        ReferenceKind::CALL_REFERENCES => edges::is_variant(kind, edges::REF_CALL),
+        ReferenceKind::NON_CALL_REFERENCES => {
+            !edges::is_variant(kind, edges::REF_CALL) && edges::is_variant(kind, edges::REF)
+        }
+        ReferenceKind::ALL_REFERENCES => edges::is_variant(kind, edges::REF),
+    }
+}
+
+/// Reports whether a cross-references group kind matches the requested caller
+/// kind.
+pub fn is_caller_kind(requested: CallerKind, kind: &str) -> bool {
+    match requested {
+        CallerKind::NO_CALLERS => false,
+        CallerKind::DIRECT_CALLERS => kind == CALLER_KIND_DIRECT,
+        CallerKind::OVERRIDE_CALLERS => kind == CALLER_KIND_DIRECT || kind == CALLER_KIND_OVERRIDE,
+    }
+}
+
+/// Returns the canonical form of each ticket, which must be non-empty.
+pub(crate) fn fix_tickets(tickets: &[String]) -> Result<Vec<String>> {
+    if tickets.is_empty() {
+        return Err(Error::InvalidArgument("no tickets specified".into()));
+    }
+    tickets
+        .iter()
+        .map(|t| {
+            kythe_uri::fix(t)
+                .map_err(|err| Error::InvalidArgument(format!("invalid ticket {t:?}: {err}")))
+        })
+        .collect()
+}
+
+/// Converts between the identical message types of different proto crates.
+pub(crate) fn recast<A: Message, B: Message>(msg: &A) -> Result<B> {
+    Ok(B::parse_from_bytes(&msg.write_to_bytes()?)?)
+}
+
+/// The category of a cross-reference in a reply.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Category {
+    Definition,
+    Declaration,
+    Reference,
+    Caller,
+}
+
+/// An [`XRefService`] answering from a table written by
+/// [`crate::build_tables`].
+pub struct Table {
+    table: LocalTable,
+}
+
+impl Table {
+    pub fn new(table: LocalTable) -> Self {
+        Self { table }
+    }
+
+    /// Returns the underlying serving table.
+    pub fn table(&self) -> &LocalTable {
+        &self.table
+    }
+
+    fn document(&self, doc: &Document) -> Result<DocumentationReply_Document> {
+        let mut d = DocumentationReply_Document::new();
+        d.set_ticket(doc.get_ticket().into());
+        let text = d.mut_text();
+        text.set_raw_text(doc.get_raw_text().into());
+        text.set_link(RepeatedField::from_vec(
+            doc.get_link().iter().map(recast).collect::<Result<_>>()?,
+        ));
+        if doc.has_marked_source() {
+            d.set_marked_source(recast(doc.get_marked_source())?);
+        }
+        Ok(d)
+    }
+
+    fn node(&self, ticket: &str) -> Result<Option<Node>> {
+        self.table.get_proto(&table::node_key(ticket))
+    }
+
+    /// Returns the facts of `node` matching `filters`, and records the node's
+    /// definition in `defs` if one is requested.
+    fn node_info(
+        &self,
+        node: &Node,
+        filters: &Filters,
+        defs: Option<&mut HashMap<String, Anchor>>,
+    ) -> Result<NodeInfo> {
+        let mut info = NodeInfo::new();
+        for fact in node.get_fact() {
+            if filters.matches(fact.get_name()) {
+                info.mut_facts()
+                    .insert(fact.get_name().into(), fact.get_value().to_vec());
+            }
+        }
+        if let Some(defs) = defs {
+            if node.has_definition_location() {
+                let def = node.get_definition_location();
+                info.set_definition(def.get_ticket().into());
+                defs.insert(def.get_ticket().into(), anchor(def, true, true)?);
+            }
+        }
+        Ok(info)
+    }
+
+    /// Returns the groups of a cross-references set whose kinds are wanted,
+    /// including those stored in separate pages.
+    fn groups(
+        &self,
+        set: &PagedCrossReferences,
+        wanted: impl Fn(&str) -> bool,
+    ) -> Result<Vec<PagedCrossReferences_Group>> {
+        let mut groups: Vec<_> = set
+            .get_group()
+            .iter()
+            .filter(|g| wanted(g.get_kind()))
+            .cloned()
+            .collect();
+        for idx in set.get_page_index() {
+            if !wanted(idx.get_kind()) {
+                continue;
+            }
+            let key = table::cross_references_page_key(idx.get_page_key());
+            let page: PagedCrossReferences_Page = self
+                .table
+                .get_proto(&key)?
+                .ok_or_else(|| Error::NotFound(format!("page {:?}", idx.get_page_key())))?;
+            groups.push(page.get_group().clone());
+        }
+        Ok(groups)
+    }
+}
+
+/// Returns the reply form of a serving anchor.
+fn anchor(ea: &ExpandedAnchor, text: bool, snippet: bool) -> Result<Anchor> {
+    let mut a = Anchor::new();
+    a.set_ticket(ea.get_ticket().into());
+    a.set_kind(ea.get_kind().into());
+    if ea.get_file_info().has_corpus_path() {
+        let path = ea.get_file_info().get_corpus_path();
+        a.set_parent(
+            kythe_uri::Uri {
+                corpus: path.get_corpus().into(),
+                root: path.get_root().into(),
+                path: path.get_path().into(),
+                ..Default::default()
+            }
+            .to_string(),
+        );
+    }
+    a.set_span(recast(ea.get_span())?);
+    if text {
+        a.set_text(ea.get_text().into());
+    }
+    if snippet {
+        a.set_snippet(ea.get_snippet().into());
+        a.set_snippet_span(recast(ea.get_snippet_span())?);
+    }
+    a.set_build_config(ea.get_build_configuration().into());
+    Ok(a)
+}
+
+impl XRefService for Table {
+    fn decorations(&self, req: &DecorationsRequest) -> Result<DecorationsReply> {
+        let loc = req.get_location();
+        if loc.get_ticket().is_empty() {
+            return Err(Error::InvalidArgument("missing location ticket".into()));
+        }
+        let ticket = fix_tickets(&[loc.get_ticket().to_string()])?.remove(0);
+        let decor: FileDecorations = self
+            .table
+            .get_proto(&table::decorations_key(&ticket))?
+            .ok_or_else(|| Error::NotFound(format!("file decorations for {ticket:?}")))?;
+        let file = decor.get_file();
+        let norm = Normalizer::new(file.get_text());
+
+        let mut reply = DecorationsReply::new();
+        let mut location = loc.clone();
+        location.set_ticket(ticket);
+        let span = match loc.get_kind() {
+            Location_Kind::FILE => None,
+            Location_Kind::SPAN => {
+                let (start, end) = (loc.get_span().get_start(), loc.get_span().get_end());
+                let start = norm.offset(
+                    start.get_byte_offset(),
+                    start.get_line_number(),
+                    start.get_column_offset(),
+                );
+                let end = norm.offset(
+                    end.get_byte_offset(),
+                    end.get_line_number(),
+                    end.get_column_offset(),
+                );
+                if start > end {
+                    return Err(Error::InvalidArgument(format!(
+                        "invalid span: [{start}, {end})"
+                    )));
+                }
+                location.set_span(recast(&norm.span(start, end))?);
+                Some((start, end))
+            }
+        };
+        reply.set_location(location);
+
+        if req.get_source_text() {
+            reply.set_source_text(file.get_text().to_vec());
+            reply.set_encoding(file.get_encoding().into());
+        }
+        if !req.get_references() {
+            return Ok(reply);
+        }
+
+        let filters = Filters::new(req.get_filter());
+        let configs: HashSet<&str> = req.get_build_config().iter().map(String::as_str).collect();
+        let mut targets = HashSet::new();
+        let mut defs = HashSet::new();
+        for d in decor.get_decoration() {
+            let a = d.get_anchor();
+            let (start, end) = (a.get_start_offset() as usize, a.get_end_offset() as usize);
+            let in_span = match span {
+                None => true,
+                Some((s, e)) => match req.get_span_kind() {
+                    SpanKind::WITHIN_SPAN => s <= start && end <= e,
+                    SpanKind::AROUND_SPAN => start <= s && e <= end,
+                },
+            };
+            if !in_span || !(configs.is_empty() || configs.contains(a.get_build_configuration())) {
+                continue;
+            }
+            let mut r = DecorationsReply_Reference::new();
+            r.set_target_ticket(d.get_target().into());
+            r.set_kind(d.get_kind().into());
+            r.set_span(recast(&norm.span(start, end))?);
+            r.set_build_config(a.get_build_configuration().into());
+            if req.get_target_definitions() && !d.get_target_definition().is_empty() {
+                r.set_target_definition(d.get_target_definition().into());
+                defs.insert(d.get_target_definition());
+            }
+            targets.insert(d.get_target());
+            reply.mut_reference().push(r);
+        }
+
+        if !filters.is_empty() {
+            for node in decor.get_target() {
+                if targets.contains(node.get_ticket()) {
+                    let info = self.node_info(node, &filters, None)?;
+                    reply.mut_nodes().insert(node.get_ticket().into(), info);
+                }
+            }
+        }
+        for def in decor.get_target_definitions() {
+            if defs.contains(def.get_ticket()) {
+                let a = anchor(def, true, true)?;
+                reply
+                    .mut_definition_locations()
+                    .insert(def.get_ticket().into(), a);
+            }
+        }
+        Ok(reply)
+    }
+
+    fn cross_references(&self, req: &CrossReferencesRequest) -> Result<CrossReferencesReply> {
+        let tickets = fix_tickets(req.get_ticket())?;
+        let page_size = match req.get_page_size() {
+            n if n < 0 => {
+                return Err(Error::InvalidArgument(format!("invalid page size: {n}")));
+            }
+            0 => DEFAULT_PAGE_SIZE,
+            n => (n as usize).min(MAX_PAGE_SIZE),
+        };
+        let offset: usize = match req.get_page_token() {
+            "" => 0,
+            token => token
+                .parse()
+                .map_err(|_| Error::InvalidArgument(format!("invalid page token: {token:?}")))?,
+        };
+        let filters = Filters::new(req.get_filter());
+        let configs: HashSet<&str> = req.get_build_config().iter().map(String::as_str).collect();
+        let snippets = req.get_snippets() == SnippetsKind::DEFAULT;
+        let text = req.get_anchor_text();
+
+        let category = |kind: &str, incomplete: bool| {
+            if is_caller_kind(req.get_caller_kind(), kind) {
+                Some(Category::Caller)
+            } else if is_decl_kind(req.get_declaration_kind(), kind, incomplete) {
+                Some(Category::Declaration)
+            } else if is_def_kind(req.get_definition_kind(), kind, incomplete) {
+                Some(Category::Definition)
+            } else if is_ref_kind(req.get_reference_kind(), kind) {
+                Some(Category::Reference)
+            } else {
+                None
+            }
+        };
+
+        let mut reply = CrossReferencesReply::new();
+        // The index of each cross-reference across all sets, for paging.
+        let mut index = 0;
+        let end = offset.saturating_add(page_size);
+        let mut seen = HashSet::new();
+        for ticket in &tickets {
+            if !seen.insert(ticket) {
+                continue;
+            }
+            let Some(set) = self
+                .table
+                .get_proto::<PagedCrossReferences>(&table::cross_references_key(ticket))?
+            else {
+                continue;
+            };
+            let incomplete = set.get_incomplete();
+            let groups = self.groups(&set, |kind| category(kind, incomplete).is_some())?;
+
+            let mut xset = CrossReferencesReply_CrossReferenceSet::new();
+            xset.set_ticket(ticket.clone());
+            if set.has_marked_source() {
+                xset.set_marked_source(recast(set.get_marked_source())?);
+            }
+            for group in &groups {
+                if !(configs.is_empty() || configs.contains(group.get_build_config())) {
+                    continue;
+                }
+                let Some(cat) = category(group.get_kind(), incomplete) else {
+                    continue;
+                };
+                let mut related = Vec::new();
+                if cat == Category::Caller {
+                    for c in group.get_caller() {
+                        let mut ra = CrossReferencesReply_RelatedAnchor::new();
+                        ra.set_ticket(c.get_semantic_caller().into());
+                        if c.has_caller() {
+                            ra.set_anchor(anchor(c.get_caller(), text, snippets)?);
+                        }
+                        if c.has_marked_source() {
+                            ra.set_marked_source(recast(c.get_marked_source())?);
+                        }
+                        for site in c.get_callsite() {
+                            ra.mut_site().push(anchor(site, text, snippets)?);
+                        }
+                        related.push(ra);
+                    }
+                } else {
+                    for a in group.get_anchor() {
+                        let mut ra = CrossReferencesReply_RelatedAnchor::new();
+                        ra.set_anchor(anchor(a, text, snippets)?);
+                        related.push(ra);
+                    }
+                }
+
+                let total = reply.mut_total();
+                let n = related.len() as i64;
+                match cat {
+                    Category::Definition => total.definitions += n,
+                    Category::Declaration => total.declarations += n,
+                    Category::Caller => total.callers += n,
+                    Category::Reference => {
+                        total.references += n;
+                        *total
+                            .mut_ref_edge_to_count()
+                            .entry(group.get_kind().into())
+                            .or_default() += n;
+                    }
+                }
+
+                for ra in related {
+                    if (offset..end).contains(&index) {
+                        let field = match cat {
+                            Category::Definition => xset.mut_definition(),
+                            Category::Declaration => xset.mut_declaration(),
+                            Category::Reference => xset.mut_reference(),
+                            Category::Caller => xset.mut_caller(),
+                        };
+                        field.push(ra);
+                    }
+                    index += 1;
+                }
+            }
+
+            if !filters.is_empty() && set.has_source_node() {
+                let defs = req
+                    .get_node_definitions()
+                    .then(|| reply.mut_definition_locations());
+                let info = self.node_info(set.get_source_node(), &filters, defs)?;
+                reply.mut_nodes().insert(ticket.clone(), info);
+            }
+            let has_refs = !(xset.get_definition().is_empty()
+                && xset.get_declaration().is_empty()
+                && xset.get_reference().is_empty()
+                && xset.get_caller().is_empty());
+            if has_refs || xset.has_marked_source() {
+                reply.mut_cross_references().insert(ticket.clone(), xset);
+            }
+        }
+        if index > end {
+            reply.set_next_page_token(end.to_string());
+        }
+        Ok(reply)
+    }
+
+    fn documentation(&self, req: &DocumentationRequest) -> Result<DocumentationReply> {
+        let tickets = fix_tickets(req.get_ticket())?;
+        let filters = Filters::new(req.get_filter());
+        let mut reply = DocumentationReply::new();
+        for ticket in tickets {
+            let Some(doc) = self
+                .table
+                .get_proto::<Document>(&table::documentation_key(&ticket))?
+            else {
+                continue;
+            };
+            let mut d = self.document(&doc)?;
+            if req.get_include_children() {
+                for child in doc.get_child_ticket() {
+                    let key = table::documentation_key(child);
+                    if let Some(child) = self.table.get_proto::<Document>(&key)? {
+                        d.mut_children().push(self.document(&child)?);
+                    }
+                }
+            }
+            reply.mut_document().push(d);
+
+            if !filters.is_empty() {
+                if let Some(node) = self.node(&ticket)? {
+                    let info =
+                        self.node_info(&node, &filters, Some(reply.mut_definition_locations()))?;
+                    reply.mut_nodes().insert(ticket, info);
+                }
+            }
+        }
+        Ok(reply)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testdata::{store, ticket};
+    use crate::{build_tables, Options};
+    use xref_rust_proto::Location;
+
+    fn service(max_page_size: usize) -> Table {
+        let mut table = LocalTable::new();
+        build_tables(&store(), &mut table, &Options { max_page_size }).unwrap();
+        Table::new(table)
+    }
+
+    fn decorations_request() -> DecorationsRequest {
+        let mut req = DecorationsRequest::new();
+        req.mut_location().set_ticket(ticket("", "a.rs"));
+        req.set_references(true);
+        req
+    }
+
+    #[test]
+    fn decorates_files_and_spans() {
+        let svc = service(0);
+        let mut req = decorations_request();
+        req.set_source_text(true);
+        req.set_target_definitions(true);
+        req.mut_filter().push("/kythe/node/*".into());
+        let reply = svc.decorations(&req).unwrap();
+        assert_eq!(reply.get_source_text(), crate::testdata::TEXT.as_bytes());
+        assert_eq!(reply.get_reference().len(), 4);
+        assert_eq!(reply.get_nodes().len(), 2);
+        assert_eq!(reply.get_definition_locations().len(), 2);
+        let f = &reply.get_nodes()[&ticket("f", "")];
+        assert_eq!(f.get_facts()["/kythe/node/kind"], b"function");
+
+        // Only the calls lie on the second line.
+        let mut loc = Location::new();
+        loc.set_ticket(ticket("", "a.rs"));
+        loc.set_kind(Location_Kind::SPAN);
+        loc.mut_span().mut_start().set_line_number(2);
+        loc.mut_span().mut_end().set_line_number(2);
+        loc.mut_span().mut_end().set_column_offset(100);
+        req.set_location(loc);
+        let reply = svc.decorations(&req).unwrap();
+        let kinds: Vec<&str> = reply.get_reference().iter().map(|r| r.get_kind()).collect();
+        assert_eq!(
+            kinds,
+            [edges::DEFINES_BINDING, edges::REF_CALL, edges::REF_CALL]
+        );
+
+        req.set_span_kind(SpanKind::AROUND_SPAN);
+        assert!(svc.decorations(&req).unwrap().get_reference().is_empty());
+
+        req.mut_location()
+            .set_ticket("kythe://c?path=missing.rs".into());
+        assert!(matches!(svc.decorations(&req), Err(Error::NotFound(_))));
+    }
+
+    #[test]
+    fn cross_references_by_kind() {
+        let svc = service(0);
+        let mut req = CrossReferencesRequest::new();
+        req.mut_ticket().push(ticket("f", ""));
+        req.set_definition_kind(DefinitionKind::BINDING_DEFINITIONS);
+        req.set_reference_kind(ReferenceKind::ALL_REFERENCES);
+        req.set_caller_kind(CallerKind::DIRECT_CALLERS);
+        req.set_anchor_text(true);
+        let reply = svc.cross_references(&req).unwrap();
+        let set = &reply.get_cross_references()[&ticket("f", "")];
+        assert_eq!(set.get_definition().len(), 1);
+        assert_eq!(set.get_definition()[0].get_anchor().get_text(), "f");
+        assert_eq!(
+            set.get_definition()[0].get_anchor().get_parent(),
+            ticket("", "a.rs")
+        );
+        assert_eq!(set.get_reference().len(), 2);
+        assert_eq!(set.get_caller().len(), 1);
+        assert_eq!(set.get_caller()[0].get_ticket(), ticket("g", ""));
+        assert_eq!(set.get_caller()[0].get_site().len(), 2);
+        assert_eq!(reply.get_total().get_references(), 2);
+        assert_eq!(reply.get_total().get_callers(), 1);
+        assert!(reply.get_next_page_token().is_empty());
+
+        req.set_reference_kind(ReferenceKind::NON_CALL_REFERENCES);
+        req.set_caller_kind(CallerKind::NO_CALLERS);
+        let reply = svc.cross_references(&req).unwrap();
+        let set = &reply.get_cross_references()[&ticket("f", "")];
+        assert!(set.get_reference().is_empty() && set.get_caller().is_empty());
+    }
+
+    #[test]
+    fn pages_cross_references() {
+        // Stored pages and reply pages are independent.
+        for max_page_size in [0, 1] {
+            let svc = service(max_page_size);
+            let mut req = CrossReferencesRequest::new();
+            req.mut_ticket().push(ticket("f", ""));
+            req.set_definition_kind(DefinitionKind::ALL_DEFINITIONS);
+            req.set_reference_kind(ReferenceKind::ALL_REFERENCES);
+            req.set_page_size(2);
+
+            let mut refs = 0;
+            let mut pages = 0;
+            loop {
+                let reply = svc.cross_references(&req).unwrap();
+                assert_eq!(reply.get_total().get_definitions(), 1);
+                if let Some(set) = reply.get_cross_references().get(&ticket("f", "")) {
+                    refs += set.get_definition().len() + set.get_reference().len();
+                }
+                pages += 1;
+                if reply.get_next_page_token().is_empty() {
+                    break;
+                }
+                req.set_page_token(reply.get_next_page_token().into());
+            }
+            assert_eq!((refs, pages), (3, 2));
+        }
+
+        let mut req = CrossReferencesRequest::new();
+        req.mut_ticket().push(ticket("f", ""));
+        req.set_page_token("bogus".into());
+        assert!(matches!(
+            service(0).cross_references(&req),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn documents_nodes() {
+        let mut req = DocumentationRequest::new();
+        req.mut_ticket().push(ticket("f", ""));
+        req.mut_ticket().push(ticket("g", ""));
+        req.mut_filter().push("**".into());
+        let reply = service(0).documentation(&req).unwrap();
+        assert_eq!(reply.get_document().len(), 1);
+        assert_eq!(reply.get_document()[0].get_text().get_raw_text(), "Does f.");
+        let info = &reply.get_nodes()[&ticket("f", "")];
+        let def = &reply.get_definition_locations()[info.get_definition()];
+        assert_eq!(def.get_text(), "f");
+    }
+}
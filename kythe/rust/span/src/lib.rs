@@ -0,0 +1,338 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion between byte offsets within a file's text and line/column
+//! positions, as `kythe/go/util/span`.
+//!
+//! Kythe anchors locate text by byte offset, while editors count columns in
+//! UTF-16 code units or code points. A [`Normalizer`] indexes a text once and
+//! converts in either direction in logarithmic time, clamping out-of-range
+//! input to the text. Bytes that are not valid UTF-8 count as one code point
+//! and one UTF-16 code unit each, as their replacement characters would.
+
+use common_rust_proto::{Point, Span};
+
+/// The unit in which columns are counted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Encoding {
+    /// Bytes of UTF-8, as in the column offsets of Kythe points.
+    Utf8,
+    /// UTF-16 code units, as in the Language Server Protocol.
+    Utf16,
+    /// Unicode code points.
+    CodePoint,
+}
+
+/// A zero-based line and column within a text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Position {
+    pub fn new(line: usize, column: usize) -> Self {
+        Position { line, column }
+    }
+}
+
+/// A character encoded in more than one byte of UTF-8.
+struct Wide {
+    offset: usize,
+    len: usize,
+}
+
+impl Wide {
+    /// Returns the length of the character in `encoding`.
+    fn units(&self, encoding: Encoding) -> usize {
+        match encoding {
+            Encoding::Utf8 => self.len,
+            Encoding::Utf16 if self.len == 4 => 2,
+            _ => 1,
+        }
+    }
+}
+
+/// An index of the lines and multi-byte characters of a text.
+pub struct Normalizer {
+    len: usize,
+    /// The byte offset at which each line starts.
+    line_starts: Vec<usize>,
+    wide: Vec<Wide>,
+    /// The number of bytes by which the wide characters before each index
+    /// exceed their length in code points and in UTF-16 code units.
+    excess: Vec<[usize; 2]>,
+}
+
+impl Normalizer {
+    pub fn new(text: &[u8]) -> Self {
+        let mut line_starts = vec![0];
+        line_starts.extend(
+            text.iter()
+                .enumerate()
+                .filter(|&(_, &b)| b == b'\n')
+                .map(|(i, _)| i + 1),
+        );
+        let mut wide = Vec::new();
+        let mut pos = 0;
+        while pos < text.len() {
+            let (valid, invalid) = match std::str::from_utf8(&text[pos..]) {
+                Ok(s) => (s, 0),
+                Err(err) => {
+                    let s = std::str::from_utf8(&text[pos..pos + err.valid_up_to()]).unwrap();
+                    (s, err.error_len().unwrap_or(text.len() - pos - s.len()))
+                }
+            };
+            wide.extend(
+                valid
+                    .char_indices()
+                    .filter(|(_, c)| c.len_utf8() > 1)
+                    .map(|(i, c)| Wide {
+                        offset: pos + i,
+                        len: c.len_utf8(),
+                    }),
+            );
+            pos += valid.len() + invalid;
+        }
+        let mut excess = vec![[0, 0]];
+        for w in &wide {
+            let [cp, u16] = excess[excess.len() - 1];
+            excess.push([
+                cp + w.len - w.units(Encoding::CodePoint),
+                u16 + w.len - w.units(Encoding::Utf16),
+            ]);
+        }
+        Normalizer {
+            len: text.len(),
+            line_starts,
+            wide,
+            excess,
+        }
+    }
+
+    /// Returns the length of the text in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of lines in the text, counting the empty line after
+    /// a final newline.
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Returns the byte offsets of the start and end of a line, excluding its
+    /// trailing newline, or `None` if the text has no such line.
+    pub fn line_bounds(&self, line: usize) -> Option<(usize, usize)> {
+        let start = *self.line_starts.get(line)?;
+        let end = self
+            .line_starts
+            .get(line + 1)
+            .map_or(self.len, |next| next - 1);
+        Some((start, end))
+    }
+
+    /// Returns the bounds of the line containing the byte `offset`, which is
+    /// clamped to the text.
+    pub fn line_bounds_at(&self, offset: usize) -> (usize, usize) {
+        self.line_bounds(self.line(offset.min(self.len))).unwrap()
+    }
+
+    fn line(&self, offset: usize) -> usize {
+        self.line_starts.partition_point(|&start| start <= offset) - 1
+    }
+
+    /// Returns the index of the first wide character at or after `offset`.
+    fn wide_index(&self, offset: usize) -> usize {
+        self.wide.partition_point(|w| w.offset < offset)
+    }
+
+    fn excess(&self, index: usize, encoding: Encoding) -> usize {
+        match encoding {
+            Encoding::Utf8 => 0,
+            Encoding::CodePoint => self.excess[index][0],
+            Encoding::Utf16 => self.excess[index][1],
+        }
+    }
+
+    /// Returns the byte `offset` clamped to the text and, unless columns are
+    /// counted in bytes, rounded down to the start of its character.
+    pub fn clamp(&self, offset: usize, encoding: Encoding) -> usize {
+        let offset = offset.min(self.len);
+        if encoding == Encoding::Utf8 {
+            return offset;
+        }
+        match self.wide_index(offset + 1).checked_sub(1) {
+            Some(i) if offset < self.wide[i].offset + self.wide[i].len => self.wide[i].offset,
+            _ => offset,
+        }
+    }
+
+    /// Returns the position of the byte `offset`, clamped as by
+    /// [`Normalizer::clamp`].
+    pub fn position(&self, offset: usize, encoding: Encoding) -> Position {
+        let offset = self.clamp(offset, encoding);
+        let line = self.line(offset);
+        let start = self.line_starts[line];
+        let excess = self.excess(self.wide_index(offset), encoding)
+            - self.excess(self.wide_index(start), encoding);
+        Position::new(line, offset - start - excess)
+    }
+
+    /// Returns the byte offset of `pos`. A line past the end of the text is
+    /// clamped to the last line, and a column past the end of its line to the
+    /// line's end; a column within a character maps to the character's start.
+    pub fn offset(&self, pos: Position, encoding: Encoding) -> usize {
+        let line = pos.line.min(self.line_count() - 1);
+        let (start, end) = self.line_bounds(line).unwrap();
+        let (lo, hi) = (self.wide_index(start), self.wide_index(end));
+        // The column at which the wide character at index i starts.
+        let column = |i: usize| {
+            self.wide[i].offset - start - (self.excess(i, encoding) - self.excess(lo, encoding))
+        };
+        // Find the first wide character on the line ending after the column.
+        let (mut i, mut j) = (lo, hi);
+        while i < j {
+            let mid = i + (j - i) / 2;
+            if column(mid) + self.wide[mid].units(encoding) <= pos.column {
+                i = mid + 1;
+            } else {
+                j = mid;
+            }
+        }
+        if i < hi && column(i) <= pos.column {
+            return self.wide[i].offset;
+        }
+        (start + pos.column + self.excess(i, encoding) - self.excess(lo, encoding)).min(end)
+    }
+
+    /// Returns the point at the byte `offset`, clamped to the text, with its
+    /// 1-based line number and its column in bytes.
+    pub fn point(&self, offset: usize) -> Point {
+        let offset = offset.min(self.len);
+        let pos = self.position(offset, Encoding::Utf8);
+        let mut p = Point::new();
+        p.set_byte_offset(offset as i32);
+        p.set_line_number(pos.line as i32 + 1);
+        p.set_column_offset(pos.column as i32);
+        p
+    }
+
+    /// Returns the span between two byte offsets, clamped to the text. A span
+    /// whose end precedes its start is empty at its start.
+    pub fn span(&self, start: usize, end: usize) -> Span {
+        let mut s = Span::new();
+        s.set_start(self.point(start));
+        s.set_end(self.point(end.max(start)));
+        s
+    }
+
+    /// Returns `p` with its fields made consistent, preferring its byte
+    /// offset if set and otherwise its line number and column.
+    pub fn normalize_point(&self, p: &Point) -> Point {
+        if p.get_byte_offset() > 0 {
+            return self.point(p.get_byte_offset() as usize);
+        }
+        if p.get_line_number() <= 0 {
+            return self.point(0);
+        }
+        let pos = Position::new(
+            p.get_line_number() as usize - 1,
+            p.get_column_offset().max(0) as usize,
+        );
+        self.point(self.offset(pos, Encoding::Utf8))
+    }
+
+    /// Returns `s` with its points normalized and its end no earlier than its
+    /// start.
+    pub fn normalize_span(&self, s: &Span) -> Span {
+        let start = self.normalize_point(s.get_start());
+        let end = self.normalize_point(s.get_end());
+        self.span(
+            start.get_byte_offset() as usize,
+            end.get_byte_offset() as usize,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_columns_in_each_encoding() {
+        // "é" is two bytes and one UTF-16 unit; "𝄞" is four bytes and two.
+        let n = Normalizer::new("é𝄞x\ny\n".as_bytes());
+        assert_eq!(n.line_count(), 3);
+        for (offset, utf16, cp) in [(0, 0, 0), (2, 1, 1), (6, 3, 2), (7, 4, 3)] {
+            assert_eq!(n.position(offset, Encoding::Utf8), Position::new(0, offset));
+            assert_eq!(n.position(offset, Encoding::Utf16), Position::new(0, utf16));
+            assert_eq!(
+                n.position(offset, Encoding::CodePoint),
+                Position::new(0, cp)
+            );
+            assert_eq!(n.offset(Position::new(0, utf16), Encoding::Utf16), offset);
+            assert_eq!(n.offset(Position::new(0, cp), Encoding::CodePoint), offset);
+        }
+        // Offsets and columns within a character round down to its start.
+        assert_eq!(n.position(4, Encoding::Utf16), Position::new(0, 1));
+        assert_eq!(n.offset(Position::new(0, 2), Encoding::Utf16), 2);
+        assert_eq!(n.position(9, Encoding::Utf16), Position::new(1, 1));
+        assert_eq!(n.offset(Position::new(1, 1), Encoding::CodePoint), 9);
+
+        // Out-of-range positions are clamped.
+        assert_eq!(n.position(99, Encoding::CodePoint), Position::new(2, 0));
+        assert_eq!(n.offset(Position::new(0, 99), Encoding::Utf16), 7);
+        assert_eq!(n.offset(Position::new(9, 9), Encoding::Utf8), 10);
+        assert_eq!(n.line_bounds(1), Some((8, 9)));
+        assert_eq!(n.line_bounds(3), None);
+        assert_eq!(n.line_bounds_at(3), (0, 7));
+    }
+
+    #[test]
+    fn counts_invalid_bytes_as_characters() {
+        let n = Normalizer::new(b"a\xff\xfe\xc3\xa9b");
+        assert_eq!(n.position(5, Encoding::Utf16), Position::new(0, 4));
+        assert_eq!(n.offset(Position::new(0, 4), Encoding::CodePoint), 5);
+        assert_eq!(n.clamp(4, Encoding::CodePoint), 3);
+    }
+
+    #[test]
+    fn normalizes_points_and_spans() {
+        let n = Normalizer::new(b"ab\ncd\n");
+        let p = n.point(4);
+        assert_eq!((p.get_line_number(), p.get_column_offset()), (2, 1));
+        assert_eq!(n.point(100).get_byte_offset(), 6);
+
+        let mut p = Point::new();
+        p.set_line_number(1);
+        p.set_column_offset(10);
+        assert_eq!(n.normalize_point(&p).get_byte_offset(), 2);
+        p.set_line_number(2);
+        p.set_column_offset(1);
+        assert_eq!(n.normalize_point(&p), n.point(4));
+        assert_eq!(n.normalize_point(&Point::new()), n.point(0));
+
+        let s = n.span(5, 1);
+        assert_eq!(s.get_start(), s.get_end());
+        let mut t = Span::new();
+        t.set_start(p);
+        t.mut_end().set_byte_offset(99);
+        assert_eq!(n.normalize_span(&t), n.span(4, 6));
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Removes duplicate records from a stream of delimited records.
+//!
+//! Usage: dedup_stream [--cache_size=SIZE] < input > output
+//!
+//! Records are read from stdin and the unique ones are written to stdout.
+//! Duplicates are only detected while their hashes remain in the cache.
+
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use kythe_storage::dedup::Deduper;
+use kythe_storage::delimited::{Reader, Writer};
+use std::io::{self, BufWriter, Write};
+
+#[derive(Parser)]
+#[command(about = "Removes duplicate records from a delimited stream on stdin")]
+struct Args {
+    /// Maximum total size of the cached record hashes (e.g. 512MiB, 3GiB).
+    #[arg(long = "cache_size", default_value = "3GiB", value_parser = parse_size)]
+    cache_size: usize,
+}
+
+/// Parses a data size such as `1024`, `64KB` or `3GiB` into a number of bytes.
+fn parse_size(s: &str) -> Result<usize> {
+    let s = s.trim();
+    let split = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(s.len());
+    let (num, unit) = s.split_at(split);
+    let num: f64 = num
+        .parse()
+        .with_context(|| format!("invalid size: {s:?}"))?;
+    let scale: u64 = match unit.trim().to_ascii_lowercase().as_str() {
+        "" | "b" => 1,
+        "k" | "kb" => 1_000,
+        "m" | "mb" => 1_000_000,
+        "g" | "gb" => 1_000_000_000,
+        "t" | "tb" => 1_000_000_000_000,
+        "ki" | "kib" => 1 << 10,
+        "mi" | "mib" => 1 << 20,
+        "gi" | "gib" => 1 << 30,
+        "ti" | "tib" => 1 << 40,
+        _ => bail!("invalid size unit: {unit:?}"),
+    };
+    Ok((num * scale as f64) as usize)
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let mut deduper = Deduper::new(args.cache_size)?;
+    let mut rd = Reader::new(io::stdin().lock());
+    let mut wr = Writer::new(BufWriter::new(io::stdout().lock()));
+    while let Some(rec) = rd.next_record().context("reading stdin")? {
+        if deduper.is_unique(rec) {
+            wr.put_record(rec).context("writing stdout")?;
+        }
+    }
+    wr.into_inner().flush().context("writing stdout")?;
+    eprintln!("dedup_stream: skipped {} records", deduper.duplicates());
+    Ok(())
+}
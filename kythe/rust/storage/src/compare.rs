@@ -0,0 +1,80 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Canonical orderings of Kythe storage messages, as `kythe/go/util/compare`.
+
+use std::cmp::Ordering;
+use storage_rust_proto::{Entry, VName};
+
+/// Compares two VNames by signature, corpus, root, path, and language.
+pub fn vnames(a: &VName, b: &VName) -> Ordering {
+    a.get_signature()
+        .cmp(b.get_signature())
+        .then_with(|| a.get_corpus().cmp(b.get_corpus()))
+        .then_with(|| a.get_root().cmp(b.get_root()))
+        .then_with(|| a.get_path().cmp(b.get_path()))
+        .then_with(|| a.get_language().cmp(b.get_language()))
+}
+
+/// Compares two entries by source, edge kind, fact name, and target, ignoring
+/// their fact values. This is the order in which a GraphStore scans entries.
+pub fn entries(a: &Entry, b: &Entry) -> Ordering {
+    vnames(a.get_source(), b.get_source())
+        .then_with(|| a.get_edge_kind().cmp(b.get_edge_kind()))
+        .then_with(|| a.get_fact_name().cmp(b.get_fact_name()))
+        .then_with(|| vnames(a.get_target(), b.get_target()))
+}
+
+/// Compares two entries as [`entries`], then by their fact values.
+pub fn value_entries(a: &Entry, b: &Entry) -> Ordering {
+    entries(a, b).then_with(|| a.get_fact_value().cmp(b.get_fact_value()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vname(signature: &str, corpus: &str) -> VName {
+        let mut v = VName::new();
+        v.set_signature(signature.into());
+        v.set_corpus(corpus.into());
+        v
+    }
+
+    fn entry(source: VName, edge_kind: &str, fact_name: &str, value: &[u8]) -> Entry {
+        let mut e = Entry::new();
+        e.set_source(source);
+        e.set_edge_kind(edge_kind.into());
+        e.set_fact_name(fact_name.into());
+        e.set_fact_value(value.to_vec());
+        e
+    }
+
+    #[test]
+    fn orders_by_fields_in_sequence() {
+        assert_eq!(vnames(&vname("a", "z"), &vname("b", "a")), Ordering::Less);
+        assert_eq!(
+            vnames(&vname("a", "b"), &vname("a", "a")),
+            Ordering::Greater
+        );
+
+        let fact = entry(vname("a", ""), "", "/kythe/node/kind", b"z");
+        let edge = entry(vname("a", ""), "/kythe/edge/ref", "/", b"");
+        assert_eq!(entries(&fact, &edge), Ordering::Less);
+
+        let other = entry(vname("a", ""), "", "/kythe/node/kind", b"a");
+        assert_eq!(entries(&fact, &other), Ordering::Equal);
+        assert_eq!(value_entries(&fact, &other), Ordering::Greater);
+    }
+}
@@ -0,0 +1,237 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Removal of duplicate records from streams using a bounded cache of record
+//! hashes, as `kythe/go/util/dedup` and `kythe/go/platform/delimited/dedup`.
+//!
+//! This is a quick-and-dirty method of removing duplicates: once the cache is
+//! full, the oldest half of the known hashes is forgotten, so duplicates that
+//! are far apart in a stream may not be removed.
+
+use crate::{EntrySink, Error, Result};
+use protobuf::Message;
+use sha2::{Digest, Sha384};
+use std::collections::HashSet;
+use storage_rust_proto::Entry;
+
+/// The size of the hash used to determine uniqueness.
+pub const HASH_SIZE: usize = 48;
+
+type Hash = [u8; HASH_SIZE];
+
+/// Determines whether a record has been seen before by checking a
+/// size-limited cache of hashes.
+pub struct Deduper {
+    pri: HashSet<Hash>,
+    sec: HashSet<Hash>,
+    /// The maximum number of hashes in each half of the cache.
+    max_size: usize,
+    duplicates: u64,
+    unique: u64,
+}
+
+impl Deduper {
+    /// Returns a deduper whose cached hashes total at most `cache_size`
+    /// bytes, which must be at least `2 * HASH_SIZE`. This bounds the hashes
+    /// alone; the sets holding them take more memory than that.
+    pub fn new(cache_size: usize) -> Result<Self> {
+        let max_size = cache_size / HASH_SIZE / 2;
+        if max_size == 0 {
+            return Err(Error::InvalidArgument(format!(
+                "invalid cache size: {cache_size} (must be at least {})",
+                2 * HASH_SIZE
+            )));
+        }
+        Ok(Self {
+            pri: HashSet::new(),
+            sec: HashSet::new(),
+            max_size,
+            duplicates: 0,
+            unique: 0,
+        })
+    }
+
+    /// Reports whether `data` has not been seen before.
+    pub fn is_unique(&mut self, data: &[u8]) -> bool {
+        let hash: Hash = Sha384::digest(data).into();
+        if self.pri.contains(&hash) || self.sec.contains(&hash) {
+            self.duplicates += 1;
+            return false;
+        }
+        self.unique += 1;
+
+        if self.sec.len() == self.max_size {
+            self.pri = std::mem::take(&mut self.sec);
+        }
+        if self.pri.len() == self.max_size {
+            self.sec.insert(hash);
+        } else {
+            self.pri.insert(hash);
+        }
+        true
+    }
+
+    /// Returns the number of unique records seen so far.
+    pub fn unique(&self) -> u64 {
+        self.unique
+    }
+
+    /// Returns the number of duplicate records seen so far.
+    pub fn duplicates(&self) -> u64 {
+        self.duplicates
+    }
+}
+
+/// Reports whether `entry` has not been seen by `deduper` before.
+fn is_unique_entry(deduper: &mut Deduper, entry: &Entry) -> Result<bool> {
+    Ok(deduper.is_unique(&entry.write_to_bytes()?))
+}
+
+/// An iterator adapter that drops duplicate entries.
+pub struct DedupEntries<I> {
+    entries: I,
+    deduper: Deduper,
+}
+
+impl<I> DedupEntries<I> {
+    /// Returns the deduper, for its statistics.
+    pub fn deduper(&self) -> &Deduper {
+        &self.deduper
+    }
+}
+
+impl<I: Iterator<Item = Result<Entry>>> Iterator for DedupEntries<I> {
+    type Item = Result<Entry>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let entry = match self.entries.next()? {
+                Ok(entry) => entry,
+                Err(err) => return Some(Err(err)),
+            };
+            match is_unique_entry(&mut self.deduper, &entry) {
+                Ok(true) => return Some(Ok(entry)),
+                Ok(false) => continue,
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
+
+/// Returns an iterator over the unique entries of `entries`, caching up to
+/// `cache_size` bytes of hashes.
+pub fn dedup_entries<I>(entries: I, cache_size: usize) -> Result<DedupEntries<I::IntoIter>>
+where
+    I: IntoIterator<Item = Result<Entry>>,
+{
+    Ok(DedupEntries {
+        entries: entries.into_iter(),
+        deduper: Deduper::new(cache_size)?,
+    })
+}
+
+/// An [`EntrySink`] that only passes unique entries on to another sink.
+pub struct DedupSink<S: EntrySink> {
+    sink: S,
+    deduper: Deduper,
+}
+
+impl<S: EntrySink> DedupSink<S> {
+    /// Returns a sink writing to `sink`, caching up to `cache_size` bytes of
+    /// hashes.
+    pub fn new(sink: S, cache_size: usize) -> Result<Self> {
+        Ok(Self {
+            sink,
+            deduper: Deduper::new(cache_size)?,
+        })
+    }
+
+    /// Returns the deduper, for its statistics.
+    pub fn deduper(&self) -> &Deduper {
+        &self.deduper
+    }
+
+    /// Returns the underlying sink.
+    pub fn into_inner(self) -> S {
+        self.sink
+    }
+}
+
+impl<S: EntrySink> EntrySink for DedupSink<S> {
+    fn write_entry(&mut self, entry: Entry) -> Result<()> {
+        if is_unique_entry(&mut self.deduper, &entry)? {
+            self.sink.write_entry(entry)?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.sink.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(fact: &str) -> Entry {
+        let mut e = Entry::new();
+        e.mut_source().set_signature("s".into());
+        e.set_fact_name(fact.into());
+        e
+    }
+
+    #[test]
+    fn rejects_small_caches() {
+        assert!(Deduper::new(2 * HASH_SIZE - 1).is_err());
+        assert!(Deduper::new(2 * HASH_SIZE).is_ok());
+    }
+
+    #[test]
+    fn drops_duplicates() {
+        let mut d = Deduper::new(1 << 20).unwrap();
+        assert!(d.is_unique(b"a"));
+        assert!(d.is_unique(b"b"));
+        assert!(!d.is_unique(b"a"));
+        assert!(!d.is_unique(b"b"));
+        assert_eq!((d.unique(), d.duplicates()), (2, 2));
+    }
+
+    #[test]
+    fn forgets_old_hashes_when_full() {
+        // One hash in each half of the cache.
+        let mut d = Deduper::new(2 * HASH_SIZE).unwrap();
+        assert!(d.is_unique(b"a"));
+        assert!(d.is_unique(b"b"));
+        assert!(!d.is_unique(b"a"));
+        assert!(d.is_unique(b"c"));
+        assert!(d.is_unique(b"a"));
+    }
+
+    #[test]
+    fn entry_adapters() {
+        let input = vec![entry("/a"), entry("/b"), entry("/a"), entry("/a")];
+        let mut it = dedup_entries(input.clone().into_iter().map(Ok), 1 << 20).unwrap();
+        let got: Vec<Entry> = it.by_ref().collect::<Result<_>>().unwrap();
+        assert_eq!(got, vec![entry("/a"), entry("/b")]);
+        assert_eq!(it.deduper().duplicates(), 2);
+
+        let mut sink = DedupSink::new(Vec::new(), 1 << 20).unwrap();
+        for e in input {
+            sink.write_entry(e).unwrap();
+        }
+        assert_eq!(sink.deduper().unique(), 2);
+        assert_eq!(sink.into_inner(), vec![entry("/a"), entry("/b")]);
+    }
+}
@@ -0,0 +1,219 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A reader and writer for streams of length-delimited records, compatible
+//! with `kythe/go/platform/delimited`.
+//!
+//! Each record is written as a varint-encoded length in bytes, followed
+//! immediately by the record itself. There are no checksums or compression.
+
+use crate::{EntrySink, Error, Result};
+use protobuf::Message;
+use std::io::{self, BufRead, Read, Write};
+use std::marker::PhantomData;
+use storage_rust_proto::Entry;
+
+/// Writes length-delimited records to an underlying writer.
+pub struct Writer<W: Write> {
+    w: W,
+}
+
+impl<W: Write> Writer<W> {
+    pub fn new(w: W) -> Self {
+        Self { w }
+    }
+
+    /// Writes `record` to the stream, preceded by its length.
+    pub fn put_record(&mut self, record: &[u8]) -> Result<()> {
+        let mut len = [0u8; 10];
+        let n = encode_varint(record.len() as u64, &mut len);
+        self.w.write_all(&len[..n])?;
+        self.w.write_all(record)?;
+        Ok(())
+    }
+
+    /// Writes the wire encoding of `msg` to the stream as a single record.
+    pub fn put<M: Message>(&mut self, msg: &M) -> Result<()> {
+        self.put_record(&msg.write_to_bytes()?)
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.w
+    }
+}
+
+impl<W: Write> EntrySink for Writer<W> {
+    fn write_entry(&mut self, entry: Entry) -> Result<()> {
+        self.put(&entry)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(self.w.flush()?)
+    }
+}
+
+/// Reads length-delimited records from an underlying reader.
+pub struct Reader<R: BufRead> {
+    r: R,
+    buf: Vec<u8>,
+}
+
+impl<R: BufRead> Reader<R> {
+    pub fn new(r: R) -> Self {
+        Self { r, buf: Vec::new() }
+    }
+
+    /// Returns the next record in the stream, or `None` at a clean end of
+    /// input. The returned slice is only valid until the next call.
+    pub fn next_record(&mut self) -> Result<Option<&[u8]>> {
+        let Some(len) = self.read_len()? else {
+            return Ok(None);
+        };
+        // The buffer only grows with the bytes actually read, so a corrupt
+        // length can't force a huge allocation.
+        self.buf.clear();
+        (&mut self.r).take(len).read_to_end(&mut self.buf)?;
+        if (self.buf.len() as u64) < len {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+        }
+        Ok(Some(&self.buf))
+    }
+
+    /// Decodes the next record in the stream as a message of type `M`.
+    pub fn next_message<M: Message>(&mut self) -> Result<Option<M>> {
+        match self.next_record()? {
+            Some(record) => Ok(Some(M::parse_from_bytes(record)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns an iterator over the remaining records decoded as `M`.
+    pub fn messages<M: Message>(self) -> Messages<R, M> {
+        Messages {
+            reader: self,
+            done: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Reads a varint length prefix, returning `None` if the stream ends
+    /// before its first byte.
+    fn read_len(&mut self) -> Result<Option<u64>> {
+        let mut value = 0u64;
+        for i in 0..10 {
+            let mut byte = [0u8; 1];
+            if let Err(err) = self.r.read_exact(&mut byte) {
+                return if i == 0 && err.kind() == io::ErrorKind::UnexpectedEof {
+                    Ok(None)
+                } else {
+                    Err(err.into())
+                };
+            }
+            value |= u64::from(byte[0] & 0x7f) << (7 * i);
+            if byte[0] & 0x80 == 0 {
+                return Ok(Some(value));
+            }
+        }
+        Err(Error::Io(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "varint length overflow",
+        )))
+    }
+}
+
+/// An iterator over the messages in a delimited stream.
+pub struct Messages<R: BufRead, M> {
+    reader: Reader<R>,
+    done: bool,
+    _marker: PhantomData<M>,
+}
+
+impl<R: BufRead, M: Message> Iterator for Messages<R, M> {
+    type Item = Result<M>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let next = self.reader.next_message().transpose();
+        self.done = !matches!(next, Some(Ok(_)));
+        next
+    }
+}
+
+/// Returns an iterator over the entries in a delimited stream.
+pub fn entries<R: BufRead>(r: R) -> Messages<R, Entry> {
+    Reader::new(r).messages()
+}
+
+fn encode_varint(mut value: u64, buf: &mut [u8; 10]) -> usize {
+    let mut n = 0;
+    while value >= 0x80 {
+        buf[n] = (value as u8) | 0x80;
+        value >>= 7;
+        n += 1;
+    }
+    buf[n] = value as u8;
+    n + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_records() {
+        let records: Vec<Vec<u8>> = vec![b"".to_vec(), b"a".to_vec(), vec![7u8; 300]];
+        let mut w = Writer::new(Vec::new());
+        for r in &records {
+            w.put_record(r).unwrap();
+        }
+        let data = w.into_inner();
+        assert_eq!(&data[..3], &[0, 1, b'a']);
+        assert_eq!(&data[3..5], &[0xac, 0x02]);
+
+        let mut r = Reader::new(&data[..]);
+        for want in &records {
+            assert_eq!(r.next_record().unwrap(), Some(&want[..]));
+        }
+        assert_eq!(r.next_record().unwrap(), None);
+    }
+
+    #[test]
+    fn truncated_record_is_an_error() {
+        let mut r = Reader::new(&[5u8, b'a', b'b'][..]);
+        assert!(r.next_record().is_err());
+
+        // A length of u64::MAX followed by nothing.
+        let mut r = Reader::new(&[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01][..]);
+        assert!(r.next_record().is_err());
+    }
+
+    #[test]
+    fn round_trip_entries() {
+        let mut entry = Entry::new();
+        entry.mut_source().set_signature("sig".into());
+        entry.set_fact_name("/kythe/node/kind".into());
+        entry.set_fact_value(b"file".to_vec());
+
+        let mut w = Writer::new(Vec::new());
+        w.write_entry(entry.clone()).unwrap();
+        w.write_entry(entry.clone()).unwrap();
+        let data = w.into_inner();
+
+        let got: Vec<Entry> = entries(&data[..]).collect::<Result<_>>().unwrap();
+        assert_eq!(got, vec![entry.clone(), entry]);
+    }
+}
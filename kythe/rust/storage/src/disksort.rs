@@ -0,0 +1,404 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An external merge sort for streams of entries that do not fit in memory,
+//! as `kythe/go/util/disksort`.
+//!
+//! Entries are buffered in memory until a budget is exceeded, at which point
+//! they are sorted and written to a temporary shard file. Once all entries
+//! have been added, the shards are merged with a k-way merge in
+//! [`compare::value_entries`] order, which refines the GraphStore order of
+//! [`compare::entries`] so that duplicate entries are always adjacent.
+
+use crate::delimited::{Reader, Writer};
+use crate::{compare, EntrySink, Error, Result};
+use protobuf::Message;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::PathBuf;
+use storage_rust_proto::Entry;
+use tempfile::TempDir;
+
+/// The default maximum number of entries to keep in memory.
+pub const DEFAULT_MAX_IN_MEMORY: usize = 32000;
+
+/// The default maximum total encoded size of the entries kept in memory.
+pub const DEFAULT_MAX_BYTES_IN_MEMORY: usize = 256 << 20;
+
+const IO_BUFFER_SIZE: usize = 64 << 10;
+
+/// Options for an [`EntrySorter`].
+#[derive(Clone, Debug)]
+pub struct Options {
+    /// The directory in which the temporary shard directory is created. If
+    /// unset, the system's default temporary directory is used.
+    pub work_dir: Option<PathBuf>,
+    /// The maximum number of entries to keep in memory before writing them to
+    /// a shard.
+    pub max_in_memory: usize,
+    /// The maximum total encoded size of the entries to keep in memory before
+    /// writing them to a shard.
+    pub max_bytes_in_memory: usize,
+    /// Whether shards are compressed with Snappy.
+    pub compress_shards: bool,
+    /// Whether duplicate entries (including their fact values) are dropped.
+    pub dedup: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            work_dir: None,
+            max_in_memory: DEFAULT_MAX_IN_MEMORY,
+            max_bytes_in_memory: DEFAULT_MAX_BYTES_IN_MEMORY,
+            compress_shards: true,
+            dedup: false,
+        }
+    }
+}
+
+/// Sorts an arbitrarily large set of entries, spilling to disk as needed.
+pub struct EntrySorter {
+    opts: Options,
+    buffer: Vec<Entry>,
+    buffer_size: usize,
+    work_dir: TempDir,
+    shards: Vec<PathBuf>,
+    duplicates: u64,
+}
+
+impl EntrySorter {
+    /// Returns a sorter with the given options, creating its temporary
+    /// directory.
+    pub fn new(opts: Options) -> Result<Self> {
+        if opts.max_in_memory == 0 || opts.max_bytes_in_memory == 0 {
+            return Err(Error::InvalidArgument(
+                "memory limits must be positive".into(),
+            ));
+        }
+        let mut builder = tempfile::Builder::new();
+        builder.prefix("external.merge.sort");
+        let work_dir = match &opts.work_dir {
+            Some(dir) => builder.tempdir_in(dir)?,
+            None => builder.tempdir()?,
+        };
+        Ok(Self {
+            opts,
+            buffer: Vec::new(),
+            buffer_size: 0,
+            work_dir,
+            shards: Vec::new(),
+            duplicates: 0,
+        })
+    }
+
+    /// Adds an entry to the set being sorted.
+    pub fn add(&mut self, entry: Entry) -> Result<()> {
+        self.buffer_size += entry.compute_size() as usize;
+        self.buffer.push(entry);
+        if self.buffer.len() >= self.opts.max_in_memory
+            || self.buffer_size >= self.opts.max_bytes_in_memory
+        {
+            self.dump_shard()?;
+        }
+        Ok(())
+    }
+
+    /// Returns the number of shards written to disk so far.
+    pub fn shard_count(&self) -> usize {
+        self.shards.len()
+    }
+
+    /// Finishes adding entries and returns an iterator over them in sorted
+    /// order. The temporary directory is removed when the iterator is dropped.
+    pub fn finish(mut self) -> Result<SortedEntries> {
+        self.sort_buffer();
+        let mut sources: Vec<Source> = Vec::with_capacity(self.shards.len() + 1);
+        for path in &self.shards {
+            let f = BufReader::with_capacity(IO_BUFFER_SIZE, File::open(path)?);
+            let r: Box<dyn BufRead> = if self.opts.compress_shards {
+                Box::new(BufReader::with_capacity(
+                    IO_BUFFER_SIZE,
+                    snap::read::FrameDecoder::new(f),
+                ))
+            } else {
+                Box::new(f)
+            };
+            sources.push(Source::Shard(Reader::new(r)));
+        }
+        sources.push(Source::Memory(std::mem::take(&mut self.buffer).into_iter()));
+
+        let mut heap = BinaryHeap::with_capacity(sources.len());
+        for (index, source) in sources.iter_mut().enumerate() {
+            if let Some(entry) = source.next()? {
+                heap.push(Head { entry, index });
+            }
+        }
+        Ok(SortedEntries {
+            heap,
+            sources,
+            dedup: self.opts.dedup,
+            last: None,
+            duplicates: self.duplicates,
+            _work_dir: self.work_dir,
+        })
+    }
+
+    /// Sorts the in-memory buffer, dropping adjacent duplicates if requested.
+    fn sort_buffer(&mut self) {
+        self.buffer.sort_by(compare::value_entries);
+        if self.opts.dedup {
+            let before = self.buffer.len();
+            self.buffer.dedup();
+            self.duplicates += (before - self.buffer.len()) as u64;
+        }
+    }
+
+    fn dump_shard(&mut self) -> Result<()> {
+        self.sort_buffer();
+        let path = self
+            .work_dir
+            .path()
+            .join(format!("shard{:06}", self.shards.len()));
+        let f = BufWriter::with_capacity(IO_BUFFER_SIZE, File::create(&path)?);
+        let w: Box<dyn Write> = if self.opts.compress_shards {
+            Box::new(snap::write::FrameEncoder::new(f))
+        } else {
+            Box::new(f)
+        };
+        let mut wr = Writer::new(w);
+        for entry in self.buffer.drain(..) {
+            wr.put(&entry)?;
+        }
+        wr.flush()?;
+        self.shards.push(path);
+        self.buffer_size = 0;
+        Ok(())
+    }
+}
+
+impl EntrySink for EntrySorter {
+    fn write_entry(&mut self, entry: Entry) -> Result<()> {
+        self.add(entry)
+    }
+}
+
+/// Sorts `entries` with the given options.
+pub fn sort_entries<I>(entries: I, opts: Options) -> Result<SortedEntries>
+where
+    I: IntoIterator<Item = Result<Entry>>,
+{
+    let mut sorter = EntrySorter::new(opts)?;
+    for entry in entries {
+        sorter.add(entry?)?;
+    }
+    sorter.finish()
+}
+
+/// A sorted run of entries, either on disk or in memory.
+enum Source {
+    Shard(Reader<Box<dyn BufRead>>),
+    Memory(std::vec::IntoIter<Entry>),
+}
+
+impl Source {
+    fn next(&mut self) -> Result<Option<Entry>> {
+        match self {
+            Source::Shard(r) => r.next_message(),
+            Source::Memory(it) => Ok(it.next()),
+        }
+    }
+}
+
+/// The current head of a source in the merge heap.
+struct Head {
+    entry: Entry,
+    index: usize,
+}
+
+impl Ord for Head {
+    // Reversed, so that the max-heap yields the least entry first. Ties are
+    // broken by source index to keep the merge stable.
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare::value_entries(&other.entry, &self.entry).then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+impl PartialOrd for Head {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Head {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Head {}
+
+/// An iterator over the sorted entries of an [`EntrySorter`].
+pub struct SortedEntries {
+    heap: BinaryHeap<Head>,
+    sources: Vec<Source>,
+    dedup: bool,
+    last: Option<Entry>,
+    duplicates: u64,
+    _work_dir: TempDir,
+}
+
+impl SortedEntries {
+    /// Returns the number of duplicate entries dropped so far.
+    pub fn duplicates(&self) -> u64 {
+        self.duplicates
+    }
+
+    fn next_entry(&mut self) -> Result<Option<Entry>> {
+        loop {
+            let Some(mut head) = self.heap.pop() else {
+                return Ok(None);
+            };
+            let entry = match self.sources[head.index].next()? {
+                Some(next) => {
+                    let entry = std::mem::replace(&mut head.entry, next);
+                    self.heap.push(head);
+                    entry
+                }
+                None => head.entry,
+            };
+            if self.dedup {
+                if self.last.as_ref() == Some(&entry) {
+                    self.duplicates += 1;
+                    continue;
+                }
+                self.last = Some(entry.clone());
+            }
+            return Ok(Some(entry));
+        }
+    }
+}
+
+impl Iterator for SortedEntries {
+    type Item = Result<Entry>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_entry().transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(signature: &str, fact: &str, value: &str) -> Entry {
+        let mut e = Entry::new();
+        e.mut_source().set_signature(signature.into());
+        e.set_fact_name(fact.into());
+        e.set_fact_value(value.into());
+        e
+    }
+
+    fn input() -> Vec<Entry> {
+        let mut entries = Vec::new();
+        for i in (0..200).rev() {
+            entries.push(entry(
+                &format!("s{:03}", i % 50),
+                "/f",
+                &format!("{}", i % 3),
+            ));
+        }
+        entries
+    }
+
+    fn expected(dedup: bool) -> Vec<Entry> {
+        let mut entries = input();
+        entries.sort_by(compare::value_entries);
+        if dedup {
+            entries.dedup();
+        }
+        entries
+    }
+
+    fn sort(opts: Options) -> (Vec<Entry>, u64) {
+        let mut sorted = sort_entries(input().into_iter().map(Ok), opts).unwrap();
+        let entries = sorted.by_ref().collect::<Result<Vec<_>>>().unwrap();
+        (entries, sorted.duplicates())
+    }
+
+    #[test]
+    fn sorts_in_memory() {
+        let (got, dups) = sort(Options::default());
+        assert_eq!(got, expected(false));
+        assert_eq!(dups, 0);
+    }
+
+    #[test]
+    fn sorts_across_shards() {
+        for compress_shards in [false, true] {
+            let opts = Options {
+                max_in_memory: 7,
+                compress_shards,
+                ..Options::default()
+            };
+            let (got, _) = sort(opts);
+            assert_eq!(got, expected(false));
+        }
+
+        let mut sorter = EntrySorter::new(Options {
+            max_bytes_in_memory: 64,
+            ..Options::default()
+        })
+        .unwrap();
+        for e in input() {
+            sorter.add(e).unwrap();
+        }
+        assert!(sorter.shard_count() > 1);
+        let got = sorter
+            .finish()
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(got, expected(false));
+    }
+
+    #[test]
+    fn dedups_while_merging() {
+        let want = expected(true);
+        let opts = Options {
+            max_in_memory: 7,
+            dedup: true,
+            ..Options::default()
+        };
+        let (got, dups) = sort(opts);
+        assert_eq!(got, want);
+        assert_eq!(dups as usize, input().len() - want.len());
+    }
+
+    #[test]
+    fn removes_work_dir() {
+        let parent = tempfile::tempdir().unwrap();
+        let opts = Options {
+            work_dir: Some(parent.path().to_path_buf()),
+            max_in_memory: 7,
+            ..Options::default()
+        };
+        let sorted = sort_entries(input().into_iter().map(Ok), opts).unwrap();
+        assert_eq!(std::fs::read_dir(parent.path()).unwrap().count(), 1);
+        drop(sorted);
+        assert_eq!(std::fs::read_dir(parent.path()).unwrap().count(), 0);
+    }
+}
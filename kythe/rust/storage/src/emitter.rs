@@ -0,0 +1,241 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A typed emitter for indexers that produce Kythe graph entries.
+//!
+//! [`Emitter`] takes care of the schema conventions that are easy to get
+//! wrong when constructing an [`Entry`] by hand: edges are written with the
+//! fact name `"/"` and an empty value, fact names are checked against the
+//! storage grammar and anchors always carry both `loc/start` and `loc/end`.
+
+use crate::{EntrySink, Error, Result};
+use kythe_schema::{edges, facts, nodes, EDGE_FACT_NAME};
+use storage_rust_proto::{Entry, VName};
+
+/// Writes nodes, facts, edges and anchors to an [`EntrySink`].
+pub struct Emitter<S: EntrySink> {
+    sink: S,
+}
+
+impl<S: EntrySink> Emitter<S> {
+    pub fn new(sink: S) -> Self {
+        Self { sink }
+    }
+
+    /// Writes a single fact about `source`.
+    ///
+    /// Edge entries must be written with [`Emitter::emit_edge`], so the edge
+    /// fact name `"/"` is rejected here.
+    pub fn emit_fact(
+        &mut self,
+        source: &VName,
+        name: &str,
+        value: impl Into<Vec<u8>>,
+    ) -> Result<()> {
+        check_vname("source", source)?;
+        if name == EDGE_FACT_NAME || !facts::is_valid_name(name) {
+            return Err(Error::InvalidEntry(format!("invalid fact name {name:?}")));
+        }
+        let mut entry = Entry::new();
+        entry.set_source(source.clone());
+        entry.set_fact_name(name.to_owned());
+        entry.set_fact_value(value.into());
+        self.sink.write_entry(entry)
+    }
+
+    /// Writes the `node/kind` fact for `node`, followed by `extra` facts.
+    pub fn emit_node<'a>(
+        &mut self,
+        node: &VName,
+        kind: &str,
+        extra: impl IntoIterator<Item = (&'a str, &'a [u8])>,
+    ) -> Result<()> {
+        if kind.is_empty() {
+            return Err(Error::InvalidEntry("empty node kind".into()));
+        }
+        self.emit_fact(node, facts::NODE_KIND, kind)?;
+        for (name, value) in extra {
+            self.emit_fact(node, name, value)?;
+        }
+        Ok(())
+    }
+
+    /// Writes a `file` node with its `text` and, if given, `text/encoding`.
+    pub fn emit_file(&mut self, file: &VName, text: &[u8], encoding: Option<&str>) -> Result<()> {
+        self.emit_node(file, nodes::FILE, [(facts::TEXT, text)])?;
+        if let Some(encoding) = encoding {
+            self.emit_fact(file, facts::TEXT_ENCODING, encoding)?;
+        }
+        Ok(())
+    }
+
+    /// Writes an `anchor` node spanning the byte offsets `[start, end)`.
+    pub fn emit_anchor(&mut self, anchor: &VName, start: usize, end: usize) -> Result<()> {
+        if start > end {
+            return Err(Error::InvalidEntry(format!(
+                "anchor span {start}:{end} is inverted"
+            )));
+        }
+        self.emit_node(
+            anchor,
+            nodes::ANCHOR,
+            [
+                (facts::ANCHOR_START, start.to_string().as_bytes()),
+                (facts::ANCHOR_END, end.to_string().as_bytes()),
+            ],
+        )
+    }
+
+    /// Writes an edge of `kind` from `source` to `target`.
+    pub fn emit_edge(&mut self, source: &VName, kind: &str, target: &VName) -> Result<()> {
+        check_vname("source", source)?;
+        check_vname("target", target)?;
+        if kind.is_empty() || !kind.starts_with('/') {
+            return Err(Error::InvalidEntry(format!("invalid edge kind {kind:?}")));
+        }
+        if edges::is_reverse(kind) {
+            return Err(Error::InvalidEntry(format!(
+                "reverse edge kind {kind:?} may not be emitted"
+            )));
+        }
+        let mut entry = Entry::new();
+        entry.set_source(source.clone());
+        entry.set_edge_kind(kind.to_owned());
+        entry.set_target(target.clone());
+        entry.set_fact_name(EDGE_FACT_NAME.to_owned());
+        self.sink.write_entry(entry)
+    }
+
+    /// Writes an edge of `kind` with an ordinal suffix, e.g. `param.0`.
+    pub fn emit_ordinal_edge(
+        &mut self,
+        source: &VName,
+        kind: &str,
+        target: &VName,
+        ordinal: u32,
+    ) -> Result<()> {
+        if edges::parse_ordinal(kind).1.is_some() {
+            return Err(Error::InvalidEntry(format!(
+                "edge kind {kind:?} already has an ordinal"
+            )));
+        }
+        self.emit_edge(source, &edges::ordinal(kind, ordinal), target)
+    }
+
+    /// Flushes the underlying sink.
+    pub fn flush(&mut self) -> Result<()> {
+        self.sink.flush()
+    }
+
+    /// Returns a reference to the underlying sink.
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+
+    /// Returns the underlying sink.
+    pub fn into_inner(self) -> S {
+        self.sink
+    }
+}
+
+/// Returns the conventional VName for an anchor spanning `[start, end)` in
+/// `file`: the file's VName with the signature `@start:end`.
+pub fn anchor_vname(file: &VName, start: usize, end: usize) -> VName {
+    let mut anchor = file.clone();
+    anchor.set_signature(format!("@{start}:{end}"));
+    anchor
+}
+
+fn check_vname(role: &str, vname: &VName) -> Result<()> {
+    if vname.get_signature().is_empty()
+        && vname.get_corpus().is_empty()
+        && vname.get_root().is_empty()
+        && vname.get_path().is_empty()
+        && vname.get_language().is_empty()
+    {
+        return Err(Error::InvalidEntry(format!("empty {role} VName")));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vname(signature: &str) -> VName {
+        let mut v = VName::new();
+        v.set_corpus("corpus".into());
+        v.set_signature(signature.into());
+        v
+    }
+
+    #[test]
+    fn edges_use_the_edge_fact_name() {
+        let mut e = Emitter::new(Vec::new());
+        e.emit_edge(&vname("a"), edges::CHILD_OF, &vname("b"))
+            .unwrap();
+        e.emit_ordinal_edge(&vname("f"), edges::PARAM, &vname("p"), 1)
+            .unwrap();
+        let entries = e.into_inner();
+
+        assert_eq!(entries[0].get_fact_name(), "/");
+        assert!(entries[0].get_fact_value().is_empty());
+        assert_eq!(entries[0].get_target(), &vname("b"));
+        assert_eq!(entries[1].get_edge_kind(), "/kythe/edge/param.1");
+    }
+
+    #[test]
+    fn anchors_carry_offsets() {
+        let mut file = VName::new();
+        file.set_path("a.rs".into());
+        let anchor = anchor_vname(&file, 3, 7);
+        assert_eq!(anchor.get_signature(), "@3:7");
+        assert_eq!(anchor.get_path(), "a.rs");
+
+        let mut e = Emitter::new(Vec::new());
+        e.emit_anchor(&anchor, 3, 7).unwrap();
+        let facts: Vec<(String, Vec<u8>)> = e
+            .into_inner()
+            .into_iter()
+            .map(|mut entry| (entry.take_fact_name(), entry.take_fact_value()))
+            .collect();
+        assert_eq!(
+            facts,
+            vec![
+                (facts::NODE_KIND.to_owned(), b"anchor".to_vec()),
+                (facts::ANCHOR_START.to_owned(), b"3".to_vec()),
+                (facts::ANCHOR_END.to_owned(), b"7".to_vec()),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_entries() {
+        let mut e = Emitter::new(Vec::new());
+        assert!(e.emit_fact(&vname("a"), "/", "x").is_err());
+        assert!(e.emit_fact(&vname("a"), "kythe/text", "x").is_err());
+        assert!(e.emit_fact(&VName::new(), facts::TEXT, "x").is_err());
+        assert!(e.emit_node(&vname("a"), "", []).is_err());
+        assert!(e.emit_edge(&vname("a"), "", &vname("b")).is_err());
+        assert!(e
+            .emit_edge(&vname("a"), "%/kythe/edge/ref", &vname("b"))
+            .is_err());
+        assert!(e.emit_edge(&vname("a"), edges::REF, &VName::new()).is_err());
+        assert!(e
+            .emit_ordinal_edge(&vname("a"), "/kythe/edge/param.0", &vname("b"), 1)
+            .is_err());
+        assert!(e.emit_anchor(&vname("a"), 5, 4).is_err());
+        assert!(e.into_inner().is_empty());
+    }
+}
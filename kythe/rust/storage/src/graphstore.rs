@@ -0,0 +1,282 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The GraphStore abstraction over a collection of entries, as
+//! `kythe/go/services/graphstore`, and an in-memory implementation.
+
+use crate::{compare, EntrySink, Result};
+use std::cmp::Ordering;
+use std::ops::ControlFlow;
+use storage_rust_proto::{Entry, ReadRequest, ScanRequest, VName, WriteRequest};
+
+/// A callback receiving entries from a [`GraphStore`]; returning
+/// `ControlFlow::Break` stops the read or scan early.
+pub type EntryFn<'a> = dyn FnMut(&Entry) -> ControlFlow<()> + 'a;
+
+/// A store of entries keyed by (source, edge kind, fact name, target), in
+/// which entries are returned in [`compare::entries`] order.
+pub trait GraphStore {
+    /// Calls `f` with each entry having the request's source VName, subject to
+    /// the request's edge kind:
+    ///
+    /// | edge kind | result                                                |
+    /// |-----------|-------------------------------------------------------|
+    /// | empty     | all entries with an empty kind and target (node facts) |
+    /// | `"*"`     | all entries, regardless of kind and target             |
+    /// | a kind    | all edge entries with the given kind                   |
+    fn read(&self, req: &ReadRequest, f: &mut EntryFn) -> Result<()>;
+
+    /// Calls `f` with each entry matching the request's target, edge kind and
+    /// fact prefix. Empty request fields match any value.
+    fn scan(&self, req: &ScanRequest, f: &mut EntryFn) -> Result<()>;
+
+    /// Writes each of the request's updates as an entry with the request's
+    /// source, replacing the value of any existing entry with the same key.
+    fn write(&mut self, req: &WriteRequest) -> Result<()>;
+
+    /// Returns the entries that [`GraphStore::read`] would produce.
+    fn read_entries(&self, req: &ReadRequest) -> Result<Vec<Entry>> {
+        let mut entries = Vec::new();
+        self.read(req, &mut |e| {
+            entries.push(e.clone());
+            ControlFlow::Continue(())
+        })?;
+        Ok(entries)
+    }
+
+    /// Returns the entries that [`GraphStore::scan`] would produce.
+    fn scan_entries(&self, req: &ScanRequest) -> Result<Vec<Entry>> {
+        let mut entries = Vec::new();
+        self.scan(req, &mut |e| {
+            entries.push(e.clone());
+            ControlFlow::Continue(())
+        })?;
+        Ok(entries)
+    }
+}
+
+/// Reports whether `entry` belongs in the result set for `req`.
+pub fn entry_matches_scan(req: &ScanRequest, entry: &Entry) -> bool {
+    (!req.has_target() || compare::vnames(req.get_target(), entry.get_target()).is_eq())
+        && (req.get_edge_kind().is_empty() || entry.get_edge_kind() == req.get_edge_kind())
+        && entry.get_fact_name().starts_with(req.get_fact_prefix())
+}
+
+/// A [`GraphStore`] holding its entries in a sorted vector.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryStore {
+    entries: Vec<Entry>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of entries in the store.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Reports whether the store has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns all entries in the store in GraphStore order.
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// Inserts `entry`, replacing any entry with the same key.
+    pub fn insert(&mut self, entry: Entry) {
+        match self
+            .entries
+            .binary_search_by(|e| compare::entries(e, &entry))
+        {
+            Ok(i) => self.entries[i] = entry,
+            Err(i) => self.entries.insert(i, entry),
+        }
+    }
+
+    /// Returns the range of entries with the given source and edge kind rule.
+    fn read_range(&self, source: &VName, edge_kind: &str) -> &[Entry] {
+        let cmp = |e: &Entry| {
+            compare::vnames(e.get_source(), source).then_with(|| match edge_kind {
+                "*" => Ordering::Equal,
+                kind => e.get_edge_kind().cmp(kind),
+            })
+        };
+        let start = self.entries.partition_point(|e| cmp(e).is_lt());
+        let end = self.entries.partition_point(|e| cmp(e).is_le());
+        &self.entries[start..end]
+    }
+}
+
+impl FromIterator<Entry> for MemoryStore {
+    fn from_iter<I: IntoIterator<Item = Entry>>(iter: I) -> Self {
+        let mut entries: Vec<Entry> = iter.into_iter().collect();
+        // A stable sort keeps later writes after earlier ones with the same
+        // key, so that the last write wins as it would with insert.
+        entries.sort_by(compare::entries);
+        entries.reverse();
+        entries.dedup_by(|a, b| compare::entries(a, b).is_eq());
+        entries.reverse();
+        Self { entries }
+    }
+}
+
+impl GraphStore for MemoryStore {
+    fn read(&self, req: &ReadRequest, f: &mut EntryFn) -> Result<()> {
+        for entry in self.read_range(req.get_source(), req.get_edge_kind()) {
+            if f(entry).is_break() {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    fn scan(&self, req: &ScanRequest, f: &mut EntryFn) -> Result<()> {
+        for entry in self.entries.iter().filter(|e| entry_matches_scan(req, e)) {
+            if f(entry).is_break() {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    fn write(&mut self, req: &WriteRequest) -> Result<()> {
+        for update in req.get_update() {
+            let mut entry = Entry::new();
+            entry.set_source(req.get_source().clone());
+            entry.set_edge_kind(update.get_edge_kind().into());
+            if update.has_target() {
+                entry.set_target(update.get_target().clone());
+            }
+            entry.set_fact_name(update.get_fact_name().into());
+            entry.set_fact_value(update.get_fact_value().to_vec());
+            self.insert(entry);
+        }
+        Ok(())
+    }
+}
+
+impl EntrySink for MemoryStore {
+    fn write_entry(&mut self, entry: Entry) -> Result<()> {
+        self.insert(entry);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vname(signature: &str) -> VName {
+        let mut v = VName::new();
+        v.set_signature(signature.into());
+        v
+    }
+
+    fn fact(source: &str, name: &str, value: &str) -> Entry {
+        let mut e = Entry::new();
+        e.set_source(vname(source));
+        e.set_fact_name(name.into());
+        e.set_fact_value(value.into());
+        e
+    }
+
+    fn edge(source: &str, kind: &str, target: &str) -> Entry {
+        let mut e = Entry::new();
+        e.set_source(vname(source));
+        e.set_edge_kind(kind.into());
+        e.set_target(vname(target));
+        e.set_fact_name("/".into());
+        e
+    }
+
+    fn store() -> MemoryStore {
+        [
+            edge("a", "/kythe/edge/ref", "b"),
+            fact("a", "/kythe/node/kind", "anchor"),
+            edge("a", "/kythe/edge/childof", "f"),
+            fact("b", "/kythe/node/kind", "function"),
+            fact("a", "/kythe/node/kind", "anchor2"),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    fn read(store: &MemoryStore, source: &str, edge_kind: &str) -> Vec<Entry> {
+        let mut req = ReadRequest::new();
+        req.set_source(vname(source));
+        req.set_edge_kind(edge_kind.into());
+        store.read_entries(&req).unwrap()
+    }
+
+    #[test]
+    fn last_write_wins() {
+        let store = store();
+        assert_eq!(store.len(), 4);
+        assert_eq!(
+            read(&store, "a", ""),
+            vec![fact("a", "/kythe/node/kind", "anchor2")]
+        );
+    }
+
+    #[test]
+    fn reads_by_edge_kind() {
+        let store = store();
+        assert_eq!(
+            read(&store, "a", "/kythe/edge/ref"),
+            vec![edge("a", "/kythe/edge/ref", "b")]
+        );
+        assert_eq!(read(&store, "a", "*").len(), 3);
+        assert!(read(&store, "c", "*").is_empty());
+    }
+
+    #[test]
+    fn scans_and_writes() {
+        let mut store = store();
+        let mut req = WriteRequest::new();
+        req.set_source(vname("c"));
+        let mut update = storage_rust_proto::WriteRequest_Update::new();
+        update.set_edge_kind("/kythe/edge/ref".into());
+        update.set_target(vname("b"));
+        update.set_fact_name("/".into());
+        req.mut_update().push(update);
+        store.write(&req).unwrap();
+
+        let mut scan = ScanRequest::new();
+        scan.set_target(vname("b"));
+        let sources: Vec<String> = store
+            .scan_entries(&scan)
+            .unwrap()
+            .iter()
+            .map(|e| e.get_source().get_signature().to_string())
+            .collect();
+        assert_eq!(sources, ["a", "c"]);
+
+        let mut scan = ScanRequest::new();
+        scan.set_fact_prefix("/kythe/node/".into());
+        let mut n = 0;
+        store
+            .scan(&scan, &mut |_| {
+                n += 1;
+                ControlFlow::Break(())
+            })
+            .unwrap();
+        assert_eq!(n, 1);
+    }
+}
@@ -0,0 +1,28 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Utilities for producing and consuming streams of Kythe `Entry` messages.
+
+pub mod compare;
+pub mod dedup;
+pub mod delimited;
+pub mod disksort;
+pub mod emitter;
+pub mod graphstore;
+mod error;
+mod sink;
+
+pub use error::{Error, Result};
+pub use graphstore::{GraphStore, MemoryStore};
+pub use sink::EntrySink;
@@ -0,0 +1,54 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Result;
+use storage_rust_proto::Entry;
+
+/// A destination for a stream of entries.
+pub trait EntrySink {
+    /// Writes a single entry to the sink.
+    fn write_entry(&mut self, entry: Entry) -> Result<()>;
+
+    /// Flushes any entries buffered by the sink.
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl EntrySink for Vec<Entry> {
+    fn write_entry(&mut self, entry: Entry) -> Result<()> {
+        self.push(entry);
+        Ok(())
+    }
+}
+
+impl<S: EntrySink + ?Sized> EntrySink for &mut S {
+    fn write_entry(&mut self, entry: Entry) -> Result<()> {
+        (**self).write_entry(entry)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        (**self).flush()
+    }
+}
+
+impl<S: EntrySink + ?Sized> EntrySink for Box<S> {
+    fn write_entry(&mut self, entry: Entry) -> Result<()> {
+        (**self).write_entry(entry)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        (**self).flush()
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Converts, sorts, filters and summarizes a stream of entries.
+//!
+//! Usage: entrystream [--read_format=FMT] [--write_format=FMT] [--sort] [--unique]
+//!                    [--count | --aggregate_by_subject]
+//!                    [--edge_kind=K,... | --fact_prefix=P,...] [--source_corpus=C,...]
+//!
+//! Entries are read from stdin and written to stdout as delimited protos, JSON
+//! lines or Riegeli records. Flags shared with the Go `entrystream` have the
+//! same meaning.
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use kythe_entrystream::{aggregate_by_subject, descriptors, read, writer, Entries, Filter, Format};
+use kythe_riegeli::WriterOptions;
+use kythe_storage::disksort::{self, Options};
+use std::io::{self, BufWriter, Write};
+
+#[derive(Parser)]
+#[command(about = "Manipulates a stream of Entry messages on stdin")]
+struct Args {
+    /// Format of the input stream: delimited, json or riegeli.
+    #[arg(long = "read_format", default_value = "delimited")]
+    read_format: Format,
+    /// Format of the output stream: delimited, json or riegeli.
+    #[arg(long = "write_format", default_value = "delimited")]
+    write_format: Format,
+    /// Riegeli writer options (e.g. "brotli:6,transpose").
+    #[arg(long = "riegeli_writer_options")]
+    riegeli_writer_options: Option<WriterOptions>,
+    /// Sort the entry stream into GraphStore order.
+    #[arg(long)]
+    sort: bool,
+    /// Print only unique entries (implies --sort).
+    #[arg(long)]
+    unique: bool,
+    /// Only print the number of entries streamed.
+    #[arg(long)]
+    count: bool,
+    /// Print one JSON object per source VName, holding its facts and
+    /// outgoing edges (implies --sort).
+    #[arg(long = "aggregate_by_subject", conflicts_with = "count")]
+    aggregate_by_subject: bool,
+    /// Keep only edges of these kinds.
+    #[arg(long = "edge_kind", value_delimiter = ',')]
+    edge_kinds: Vec<String>,
+    /// Keep only entries whose fact name starts with one of these prefixes.
+    #[arg(
+        long = "fact_prefix",
+        value_delimiter = ',',
+        conflicts_with = "edge_kinds"
+    )]
+    fact_prefixes: Vec<String>,
+    /// Keep only entries whose source is in one of these corpora.
+    #[arg(long = "source_corpus", value_delimiter = ',')]
+    source_corpora: Vec<String>,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let filter = Filter::new(args.edge_kinds, args.fact_prefixes, args.source_corpora)?;
+    let d = descriptors();
+    let input = read(args.read_format, io::stdin().lock(), &d);
+    let mut entries: Entries = Box::new(input.filter(|e| match e {
+        Ok(e) => filter.keep(e),
+        Err(_) => true,
+    }));
+    if args.sort || args.unique || args.aggregate_by_subject {
+        let opts = Options {
+            dedup: args.unique,
+            ..Options::default()
+        };
+        entries = Box::new(disksort::sort_entries(entries, opts).context("sorting entries")?);
+    }
+
+    let mut out = BufWriter::new(io::stdout().lock());
+    if args.count {
+        let mut count = 0u64;
+        for entry in entries {
+            entry.context("reading stdin")?;
+            count += 1;
+        }
+        writeln!(out, "{count}")?;
+    } else if args.aggregate_by_subject {
+        aggregate_by_subject(entries, &d, &mut out).context("aggregating entries")?;
+    } else {
+        let options = args.riegeli_writer_options.unwrap_or_default();
+        let mut sink = writer(args.write_format, &mut out, &d, options);
+        for entry in entries {
+            sink.write_entry(entry.context("reading stdin")?)
+                .context("writing stdout")?;
+        }
+        sink.flush().context("writing stdout")?;
+    }
+    out.flush().context("writing stdout")
+}
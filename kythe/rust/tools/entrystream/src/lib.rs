@@ -0,0 +1,338 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion, filtering and aggregation of entry streams, as done by the
+//! `entrystream` tool.
+//!
+//! Streams are read and written as delimited protos, JSON lines or Riegeli
+//! records; see [`Format`].
+
+use kythe_protojson::Descriptors;
+use kythe_riegeli::WriterOptions;
+use kythe_storage::{delimited, EntrySink, Error, Result};
+use serde_json::{json, Map, Value};
+use std::fmt;
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+use storage_rust_proto::{Entry, VName};
+
+/// The encodings of an entry stream.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Varint-delimited wire-format protos.
+    #[default]
+    Delimited,
+    /// JSON objects, one per line.
+    Json,
+    /// Riegeli records.
+    Riegeli,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "delimited" => Ok(Format::Delimited),
+            "json" => Ok(Format::Json),
+            "riegeli" => Ok(Format::Riegeli),
+            _ => Err(Error::InvalidArgument(format!("unknown format: {s:?}"))),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Format::Delimited => "delimited",
+            Format::Json => "json",
+            Format::Riegeli => "riegeli",
+        })
+    }
+}
+
+/// A stream of entries, or of the errors encountered reading them.
+pub type Entries<'a> = Box<dyn Iterator<Item = Result<Entry>> + 'a>;
+
+/// Returns the descriptors needed to convert entries to and from JSON.
+pub fn descriptors() -> Descriptors {
+    Descriptors::new().with_file(storage_rust_proto::file_descriptor_proto())
+}
+
+/// Returns the entries read from `r` in the given format.
+pub fn read<'a>(format: Format, r: impl BufRead + 'a, d: &'a Descriptors) -> Entries<'a> {
+    match format {
+        Format::Delimited => Box::new(delimited::entries(r)),
+        Format::Json => {
+            let values = serde_json::Deserializer::from_reader(r).into_iter::<Value>();
+            Box::new(values.map(|v| {
+                let v = v.map_err(|err| Error::InvalidEntry(err.to_string()))?;
+                d.from_json(&v).map_err(json_error)
+            }))
+        }
+        Format::Riegeli => Box::new(kythe_riegeli::entries(r).map(|e| Ok(e?))),
+    }
+}
+
+/// Returns a sink writing entries to `w` in the given format. Riegeli
+/// records are written with `options`.
+pub fn writer<'a>(
+    format: Format,
+    w: impl Write + 'a,
+    d: &'a Descriptors,
+    options: WriterOptions,
+) -> Box<dyn EntrySink + 'a> {
+    match format {
+        Format::Delimited => Box::new(delimited::Writer::new(w)),
+        Format::Json => Box::new(JsonWriter { w, d }),
+        Format::Riegeli => Box::new(kythe_riegeli::Writer::new(w, options)),
+    }
+}
+
+/// Writes entries as JSON, one per line.
+struct JsonWriter<'a, W: Write> {
+    w: W,
+    d: &'a Descriptors,
+}
+
+impl<W: Write> EntrySink for JsonWriter<'_, W> {
+    fn write_entry(&mut self, entry: Entry) -> Result<()> {
+        let json = self.d.to_json(&entry).map_err(json_error)?;
+        Ok(writeln!(self.w, "{json}")?)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(self.w.flush()?)
+    }
+}
+
+fn json_error(err: kythe_protojson::Error) -> Error {
+    Error::InvalidEntry(err.to_string())
+}
+
+/// Selects entries by edge kind or fact name, and by source corpus. Empty
+/// criteria match every entry.
+#[derive(Clone, Debug, Default)]
+pub struct Filter {
+    edge_kinds: Vec<String>,
+    fact_prefixes: Vec<String>,
+    source_corpora: Vec<String>,
+}
+
+impl Filter {
+    /// Returns a filter keeping only the edges of `edge_kinds` or the entries
+    /// whose fact name starts with one of `fact_prefixes`, and whose source
+    /// is in one of `source_corpora`. Edges are named by their kind alone, so
+    /// edge kinds and fact prefixes cannot be combined.
+    pub fn new(
+        edge_kinds: Vec<String>,
+        fact_prefixes: Vec<String>,
+        source_corpora: Vec<String>,
+    ) -> Result<Self> {
+        if !edge_kinds.is_empty() && !fact_prefixes.is_empty() {
+            return Err(Error::InvalidArgument(
+                "edge kinds and fact prefixes cannot both be given".into(),
+            ));
+        }
+        Ok(Filter {
+            edge_kinds,
+            fact_prefixes,
+            source_corpora,
+        })
+    }
+
+    /// Reports whether `entry` passes the filter.
+    pub fn keep(&self, entry: &Entry) -> bool {
+        let any = |values: &[String], f: &dyn Fn(&str) -> bool| {
+            values.is_empty() || values.iter().any(|v| f(v))
+        };
+        any(&self.edge_kinds, &|k| entry.get_edge_kind() == k)
+            && any(&self.fact_prefixes, &|p| {
+                entry.get_fact_name().starts_with(p)
+            })
+            && any(&self.source_corpora, &|c| {
+                entry.get_source().get_corpus() == c
+            })
+    }
+}
+
+/// The facts and outgoing edges of one source VName.
+type Subject = (VName, Map<String, Value>, Vec<Value>);
+
+/// Writes one JSON object per source VName of the sorted `entries`, holding
+/// its facts by name and its outgoing edges in order.
+pub fn aggregate_by_subject(
+    entries: impl IntoIterator<Item = Result<Entry>>,
+    d: &Descriptors,
+    out: &mut impl Write,
+) -> Result<()> {
+    let mut subject: Option<Subject> = None;
+    for entry in entries {
+        let mut entry = entry?;
+        if subject
+            .as_ref()
+            .is_some_and(|(source, _, _)| source != entry.get_source())
+        {
+            write_subject(subject.take(), d, out)?;
+        }
+        let (_, facts, edges) =
+            subject.get_or_insert_with(|| (entry.get_source().clone(), Map::new(), Vec::new()));
+        let value = fact_value(entry.get_fact_value());
+        if entry.get_edge_kind().is_empty() {
+            facts.insert(entry.take_fact_name(), value);
+            continue;
+        }
+        let mut edge = json!({
+            "edge_kind": entry.get_edge_kind(),
+            "target": d.to_json(entry.get_target()).map_err(json_error)?,
+        });
+        if entry.get_fact_name() != "/" {
+            edge["fact_name"] = entry.get_fact_name().into();
+            edge["fact_value"] = value;
+        }
+        edges.push(edge);
+    }
+    write_subject(subject, d, out)
+}
+
+fn write_subject(subject: Option<Subject>, d: &Descriptors, out: &mut impl Write) -> Result<()> {
+    let Some((source, facts, edges)) = subject else {
+        return Ok(());
+    };
+    let json = json!({
+        "source": d.to_json(&source).map_err(json_error)?,
+        "facts": facts,
+        "edges": edges,
+    });
+    Ok(writeln!(out, "{json}")?)
+}
+
+/// Encodes a fact value as the JSON form of an Entry's `fact_value`.
+fn fact_value(value: &[u8]) -> Value {
+    use base64::Engine;
+    base64::engine::general_purpose::STANDARD
+        .encode(value)
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn vname(signature: &str, corpus: &str) -> VName {
+        let mut v = VName::new();
+        v.set_signature(signature.into());
+        v.set_corpus(corpus.into());
+        v
+    }
+
+    fn fact(source: &str, name: &str, value: &[u8]) -> Entry {
+        let mut e = Entry::new();
+        e.set_source(vname(source, "c"));
+        e.set_fact_name(name.into());
+        e.set_fact_value(value.to_vec());
+        e
+    }
+
+    fn edge(source: &str, kind: &str, target: &str) -> Entry {
+        let mut e = fact(source, "/", b"");
+        e.set_edge_kind(kind.into());
+        e.set_target(vname(target, "c"));
+        e
+    }
+
+    fn entries() -> Vec<Entry> {
+        vec![
+            fact("a", "/kythe/node/kind", b"function"),
+            fact("a", "/kythe/text", b"\xff\x00"),
+            edge("a", "/kythe/edge/childof", "b"),
+            fact("b", "/kythe/node/kind", b"record"),
+        ]
+    }
+
+    #[test]
+    fn round_trips() {
+        let d = descriptors();
+        for format in [Format::Delimited, Format::Json, Format::Riegeli] {
+            let mut data = Vec::new();
+            let mut sink = writer(format, &mut data, &d, WriterOptions::default());
+            for entry in entries() {
+                sink.write_entry(entry).unwrap();
+            }
+            sink.flush().unwrap();
+            drop(sink);
+            let got: Vec<Entry> = read(format, Cursor::new(data), &d)
+                .collect::<Result<_>>()
+                .unwrap();
+            assert_eq!(got, entries(), "{format}");
+            assert_eq!(format.to_string().parse::<Format>().unwrap(), format);
+        }
+        assert_eq!("JSON".parse::<Format>().unwrap(), Format::Json);
+        assert!("xml".parse::<Format>().is_err());
+        assert!(read(Format::Json, Cursor::new(b"{\"no\": 1}"), &d)
+            .next()
+            .unwrap()
+            .is_err());
+    }
+
+    #[test]
+    fn aggregates_by_subject() {
+        let d = descriptors();
+        let mut out = Vec::new();
+        aggregate_by_subject(entries().into_iter().map(Ok), &d, &mut out).unwrap();
+        let lines: Vec<Value> = out
+            .split(|&b| b == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| serde_json::from_slice(line).unwrap())
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                json!({
+                    "source": {"signature": "a", "corpus": "c"},
+                    "facts": {"/kythe/node/kind": "ZnVuY3Rpb24=", "/kythe/text": "/wA="},
+                    "edges": [{
+                        "edge_kind": "/kythe/edge/childof",
+                        "target": {"signature": "b", "corpus": "c"},
+                    }],
+                }),
+                json!({
+                    "source": {"signature": "b", "corpus": "c"},
+                    "facts": {"/kythe/node/kind": "cmVjb3Jk"},
+                    "edges": [],
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn filters() {
+        let kept = |filter: &Filter| -> Vec<Entry> {
+            entries().into_iter().filter(|e| filter.keep(e)).collect()
+        };
+        let all = Filter::default();
+        assert_eq!(kept(&all), entries());
+        let edges = Filter::new(vec!["/kythe/edge/childof".into()], vec![], vec![]).unwrap();
+        assert_eq!(kept(&edges), [edge("a", "/kythe/edge/childof", "b")]);
+        let kinds = Filter::new(vec![], vec!["/kythe/node/".into()], vec![]).unwrap();
+        assert_eq!(kept(&kinds).len(), 2);
+        let corpus = Filter::new(vec![], vec![], vec!["other".into()]).unwrap();
+        assert!(kept(&corpus).is_empty());
+        assert!(matches!(
+            Filter::new(vec!["/kythe/edge/childof".into()], vec!["/".into()], vec![]),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+}
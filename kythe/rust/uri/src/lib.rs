@@ -0,0 +1,351 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Parsing and formatting of Kythe URIs (tickets), mirroring
+//! `kythe/go/util/kytheuri`.
+//!
+//! A Kythe URI has the form
+//! `kythe://corpus?lang=language?path=path?root=root#signature`, with each
+//! component %-escaped as needed.
+
+use std::fmt;
+use std::str::FromStr;
+use storage_rust_proto::VName;
+use thiserror::Error;
+
+/// The URI scheme label for Kythe.
+pub const SCHEME: &str = "kythe:";
+
+/// Errors produced while parsing a Kythe URI.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ParseError {
+    #[error("invalid URI scheme")]
+    InvalidScheme,
+    #[error("invalid attribute: {0:?}")]
+    InvalidAttribute(String),
+    #[error("invalid {0}: {1}")]
+    InvalidEscape(&'static str, &'static str),
+}
+
+/// A parsed, unescaped Kythe URI.
+///
+/// URIs are ordered by lexicographic comparison of [signature, corpus, root,
+/// path, language], which is the standard order for VNames.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Uri {
+    pub signature: String,
+    pub corpus: String,
+    pub root: String,
+    pub path: String,
+    pub language: String,
+}
+
+impl Uri {
+    /// Parses and unescapes a Kythe URI. If `s` omits a scheme label, the
+    /// "kythe" scheme is assumed.
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        if s.is_empty() {
+            return Ok(Self::default());
+        }
+        let (head, fragment) = split(s, '#');
+        let head = head.strip_prefix(SCHEME).unwrap_or(head);
+        let (head, attrs) = split(head, '?');
+        let corpus = match head.strip_prefix("//") {
+            Some(corpus) => corpus,
+            None if head.is_empty() => head,
+            None => return Err(ParseError::InvalidScheme),
+        };
+
+        let mut uri = Uri {
+            signature: unescape(fragment).map_err(|e| ParseError::InvalidEscape("signature", e))?,
+            corpus: unescape(corpus).map_err(|e| ParseError::InvalidEscape("corpus label", e))?,
+            ..Default::default()
+        };
+        for attr in attrs.split('?').filter(|a| !a.is_empty()) {
+            let (name, value) = split(attr, '=');
+            if value.is_empty() {
+                return Err(ParseError::InvalidAttribute(attr.to_owned()));
+            }
+            match name {
+                "lang" => {
+                    uri.language =
+                        unescape(value).map_err(|e| ParseError::InvalidEscape("language", e))?
+                }
+                "path" => {
+                    uri.path = unescape(value).map_err(|e| ParseError::InvalidEscape("path", e))?
+                }
+                "root" => {
+                    uri.root = unescape(value).map_err(|e| ParseError::InvalidEscape("root", e))?
+                }
+                _ => return Err(ParseError::InvalidAttribute(name.to_owned())),
+            }
+        }
+        Ok(uri)
+    }
+
+    /// Returns a URI for the given VName.
+    pub fn from_vname(v: &VName) -> Self {
+        Uri {
+            signature: v.get_signature().to_owned(),
+            corpus: v.get_corpus().to_owned(),
+            root: v.get_root().to_owned(),
+            path: v.get_path().to_owned(),
+            language: v.get_language().to_owned(),
+        }
+    }
+
+    /// Converts the URI to an equivalent VName, cleaning its path.
+    pub fn to_vname(&self) -> VName {
+        let mut v = VName::new();
+        v.set_signature(self.signature.clone());
+        v.set_corpus(self.corpus.clone());
+        v.set_root(self.root.clone());
+        v.set_path(clean_path(&self.path));
+        v.set_language(self.language.clone());
+        v
+    }
+}
+
+impl fmt::Display for Uri {
+    /// Renders the URI in canonical string form.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(SCHEME)?;
+        if !self.corpus.is_empty() {
+            write!(f, "//{}", escape(&self.corpus, true))?;
+        }
+        if !self.language.is_empty() {
+            write!(f, "?lang={}", escape(&self.language, false))?;
+        }
+        let path = clean_path(&self.path);
+        if !path.is_empty() {
+            write!(f, "?path={}", escape(&path, true))?;
+        }
+        if !self.root.is_empty() {
+            write!(f, "?root={}", escape(&self.root, true))?;
+        }
+        if !self.signature.is_empty() {
+            write!(f, "#{}", escape(&self.signature, false))?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Uri {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Uri::parse(s)
+    }
+}
+
+impl From<&VName> for Uri {
+    fn from(v: &VName) -> Self {
+        Uri::from_vname(v)
+    }
+}
+
+/// Renders the given VName as a Kythe URI string.
+pub fn to_string(v: &VName) -> String {
+    Uri::from_vname(v).to_string()
+}
+
+/// Parses the given Kythe URI string as a VName.
+pub fn to_vname(s: &str) -> Result<VName, ParseError> {
+    Uri::parse(s).map(|uri| uri.to_vname())
+}
+
+/// Returns the canonical form of the given Kythe URI string.
+pub fn fix(s: &str) -> Result<String, ParseError> {
+    Uri::parse(s).map(|uri| uri.to_string())
+}
+
+/// Partitions `s` around the first occurrence of `mark`, if any.
+fn split(s: &str, mark: char) -> (&str, &str) {
+    s.split_once(mark).unwrap_or((s, ""))
+}
+
+fn should_escape(c: u8, is_path: bool) -> bool {
+    !(c.is_ascii_alphanumeric() || matches!(c, b'-' | b'.' | b'_' | b'~') || (c == b'/' && is_path))
+}
+
+fn escape(s: &str, is_path: bool) -> String {
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+    let mut out = String::with_capacity(s.len());
+    for &c in s.as_bytes() {
+        if should_escape(c, is_path) {
+            out.push('%');
+            out.push(HEX[usize::from(c >> 4)] as char);
+            out.push(HEX[usize::from(c & 0xf)] as char);
+        } else {
+            out.push(c as char);
+        }
+    }
+    out
+}
+
+fn unescape(s: &str) -> Result<String, &'static str> {
+    if !s.contains('%') {
+        return Ok(s.to_owned());
+    }
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'%' {
+            out.push(bytes[i]);
+            i += 1;
+            continue;
+        }
+        if i + 2 >= bytes.len() {
+            return Err("invalid hex escape");
+        }
+        let hex = |c: u8| (c as char).to_digit(16);
+        match (hex(bytes[i + 1]), hex(bytes[i + 2])) {
+            (Some(hi), Some(lo)) => out.push((hi << 4 | lo) as u8),
+            _ => return Err("invalid hex digit"),
+        }
+        i += 3;
+    }
+    String::from_utf8(out).map_err(|_| "invalid UTF-8")
+}
+
+/// Returns the shortest path equivalent to `path` by purely lexical
+/// processing, as Go's `path.Clean`, but leaves the empty path alone.
+pub fn clean_path(path: &str) -> String {
+    if path.is_empty() {
+        return String::new();
+    }
+    let rooted = path.starts_with('/');
+    let mut parts: Vec<&str> = Vec::new();
+    for part in path.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                if parts.last().is_some_and(|p| *p != "..") {
+                    parts.pop();
+                } else if !rooted {
+                    parts.push("..");
+                }
+            }
+            _ => parts.push(part),
+        }
+    }
+    let joined = parts.join("/");
+    match (rooted, joined.is_empty()) {
+        (true, _) => format!("/{joined}"),
+        (false, true) => ".".to_owned(),
+        (false, false) => joined,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uri(signature: &str, corpus: &str, root: &str, path: &str, language: &str) -> Uri {
+        Uri {
+            signature: signature.into(),
+            corpus: corpus.into(),
+            root: root.into(),
+            path: path.into(),
+            language: language.into(),
+        }
+    }
+
+    #[test]
+    fn parse() {
+        let tests = [
+            ("", Uri::default()),
+            ("kythe:", Uri::default()),
+            ("kythe://", Uri::default()),
+            ("kythe://..", uri("", "..", "", "", "")),
+            ("kythe://a/../b//c", uri("", "a/../b//c", "", "", "")),
+            ("#sig", uri("sig", "", "", "", "")),
+            ("kythe:#sig", uri("sig", "", "", "", "")),
+            ("//corpus/with/path", uri("", "corpus/with/path", "", "", "")),
+            ("kythe:?root=R?path=P?lang=L", uri("", "", "R", "P", "L")),
+            (
+                "kythe://bitbucket.org/creachadair/stringset?path=stringset.go?lang=go?root=blah#sig",
+                uri("sig", "bitbucket.org/creachadair/stringset", "blah", "stringset.go", "go"),
+            ),
+            (
+                "kythe://libstdc%2B%2B?lang=c%2B%2B?path=bits/basic_string.h?root=/usr/include/c%2B%2B/4.8",
+                uri("", "libstdc++", "/usr/include/c++/4.8", "bits/basic_string.h", "c++"),
+            ),
+        ];
+        for (input, want) in tests {
+            assert_eq!(Uri::parse(input), Ok(want), "parsing {input:?}");
+        }
+    }
+
+    #[test]
+    fn parse_errors() {
+        for input in [
+            "bogus",
+            "kythe:?path=",
+            "kythe:?bogus=x",
+            "#%4",
+            "#%zz",
+            "kythe://%",
+        ] {
+            assert!(Uri::parse(input).is_err(), "parsing {input:?} should fail");
+        }
+    }
+
+    #[test]
+    fn canonical_strings() {
+        let tests = [
+            ("kythe://#", "kythe:"),
+            (
+                "kythe://?lang=L?path=P?root=R#",
+                "kythe:?lang=L?path=P?root=R",
+            ),
+            ("kythe:?root=R?lang=L?path=P", "kythe:?lang=L?path=P?root=R"),
+            ("kythe://?path=%50", "kythe:?path=P"),
+            ("kythe://?path=a+b", "kythe:?path=a%2Bb"),
+            ("kythe://?path=%20", "kythe:?path=%20"),
+            ("kythe://kythe//branch", "kythe://kythe//branch"),
+            ("kythe://a?path=./d/.././c#sig", "kythe://a?path=c#sig"),
+            ("kythe://a#s/i g", "kythe://a#s%2Fi%20g"),
+        ];
+        for (input, want) in tests {
+            assert_eq!(fix(input).unwrap(), want, "fixing {input:?}");
+        }
+    }
+
+    #[test]
+    fn vname_round_trip() {
+        let v = uri("sig", "corpus", "root", "a/b/../c", "go").to_vname();
+        assert_eq!(v.get_path(), "a/c");
+        assert_eq!(
+            to_string(&v),
+            "kythe://corpus?lang=go?path=a/c?root=root#sig"
+        );
+        assert_eq!(to_vname(&to_string(&v)).unwrap(), v);
+    }
+
+    #[test]
+    fn clean() {
+        for (input, want) in [
+            ("", ""),
+            ("a/b/../c", "a/c"),
+            ("./a//b/", "a/b"),
+            ("../a", "../a"),
+            ("/../a", "/a"),
+            ("a/..", "."),
+        ] {
+            assert_eq!(clean_path(input), want, "cleaning {input:?}");
+        }
+    }
+}
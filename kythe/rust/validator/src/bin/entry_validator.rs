@@ -0,0 +1,73 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Validates a stream of delimited Kythe entries against the schema.
+//!
+//! Usage: entry_validator [--group_by=rule|source] [FILE...]
+//!
+//! Entries are read from the given files, or from stdin if none are given.
+//! The process exits with a non-zero status if any violations are found.
+
+use anyhow::{Context, Result};
+use clap::{Parser, ValueEnum};
+use kythe_storage::delimited;
+use kythe_validator::Validator;
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Clone, Copy, ValueEnum)]
+enum GroupBy {
+    Rule,
+    Source,
+}
+
+#[derive(Parser)]
+#[command(about = "Validates a stream of delimited Kythe entries against the schema")]
+struct Args {
+    /// How to group the reported violations.
+    #[arg(long = "group_by", value_enum, default_value = "rule")]
+    group_by: GroupBy,
+
+    /// Delimited entry files to validate; stdin is read if none are given.
+    files: Vec<PathBuf>,
+}
+
+fn main() -> Result<ExitCode> {
+    let args = Args::parse();
+    let mut validator = Validator::new();
+    if args.files.is_empty() {
+        for entry in delimited::entries(io::stdin().lock()) {
+            validator.add_entry(&entry.context("reading stdin")?);
+        }
+    }
+    for path in &args.files {
+        let f = File::open(path).with_context(|| format!("opening {}", path.display()))?;
+        for entry in delimited::entries(BufReader::new(f)) {
+            validator.add_entry(&entry.with_context(|| format!("reading {}", path.display()))?);
+        }
+    }
+
+    let report = validator.finish();
+    match args.group_by {
+        GroupBy::Rule => print!("{report}"),
+        GroupBy::Source => print!("{}", report.display_by_source()),
+    }
+    Ok(if report.is_ok() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
@@ -0,0 +1,551 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Schema validation for streams of Kythe entries.
+//!
+//! A [`Validator`] consumes entries one at a time (it is also an
+//! [`EntrySink`]) and, once the stream is complete, produces a [`Report`] of
+//! schema violations: malformed entries, nodes missing their kind or a fact
+//! required by their kind, unknown node and edge kinds, anchors whose offsets
+//! fall outside of their file's text, and edges whose targets are never
+//! defined.
+
+use kythe_schema::{edges, facts, nodes, EDGE_FACT_NAME};
+use kythe_storage::EntrySink;
+use kythe_uri::Uri;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use storage_rust_proto::Entry;
+
+/// A validation rule.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Rule {
+    /// An entry that does not follow the basic entry conventions.
+    MalformedEntry,
+    /// A node with facts but no `node/kind`.
+    MissingNodeKind,
+    /// A node that lacks a fact required by its kind.
+    MissingRequiredFact,
+    /// A `node/kind` that is not defined by the schema.
+    UnknownNodeKind,
+    /// A `/kythe/edge/` kind that is not defined by the schema.
+    UnknownEdgeKind,
+    /// An anchor whose `loc/start` or `loc/end` is not a valid span.
+    InvalidAnchorOffsets,
+    /// An anchor whose span extends past the end of its file's text.
+    AnchorOutOfBounds,
+    /// An anchor whose file has no `text` fact in the stream.
+    MissingFileText,
+    /// An edge whose target has no `node/kind` in the stream.
+    DanglingEdgeTarget,
+}
+
+impl Rule {
+    /// Returns a short, stable name for the rule.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Rule::MalformedEntry => "malformed-entry",
+            Rule::MissingNodeKind => "missing-node-kind",
+            Rule::MissingRequiredFact => "missing-required-fact",
+            Rule::UnknownNodeKind => "unknown-node-kind",
+            Rule::UnknownEdgeKind => "unknown-edge-kind",
+            Rule::InvalidAnchorOffsets => "invalid-anchor-offsets",
+            Rule::AnchorOutOfBounds => "anchor-out-of-bounds",
+            Rule::MissingFileText => "missing-file-text",
+            Rule::DanglingEdgeTarget => "dangling-edge-target",
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A single violation of a validation rule.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Violation {
+    pub rule: Rule,
+    /// The source VName of the offending entry or node.
+    pub source: Uri,
+    pub message: String,
+}
+
+/// The result of validating an entry stream.
+#[derive(Clone, Debug, Default)]
+pub struct Report {
+    /// The number of entries that were checked.
+    pub entries: usize,
+    violations: Vec<Violation>,
+}
+
+impl Report {
+    /// Reports whether the stream had no violations.
+    pub fn is_ok(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Returns all violations in the order they were found.
+    pub fn violations(&self) -> &[Violation] {
+        &self.violations
+    }
+
+    /// Returns the violations grouped by rule.
+    pub fn by_rule(&self) -> BTreeMap<Rule, Vec<&Violation>> {
+        let mut groups: BTreeMap<_, Vec<_>> = BTreeMap::new();
+        for v in &self.violations {
+            groups.entry(v.rule).or_default().push(v);
+        }
+        groups
+    }
+
+    /// Returns the violations grouped by source VName.
+    pub fn by_source(&self) -> BTreeMap<&Uri, Vec<&Violation>> {
+        let mut groups: BTreeMap<_, Vec<_>> = BTreeMap::new();
+        for v in &self.violations {
+            groups.entry(&v.source).or_default().push(v);
+        }
+        groups
+    }
+
+    /// Returns a writer for the report grouped by source VName.
+    pub fn display_by_source(&self) -> impl fmt::Display + '_ {
+        BySource(self)
+    }
+}
+
+impl fmt::Display for Report {
+    /// Writes the violations grouped by rule, and within each rule by source.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (rule, violations) in self.by_rule() {
+            writeln!(f, "{rule} ({} violations)", violations.len())?;
+            let mut by_source: BTreeMap<_, Vec<_>> = BTreeMap::new();
+            for v in violations {
+                by_source.entry(&v.source).or_default().push(&v.message);
+            }
+            for (source, messages) in by_source {
+                writeln!(f, "  {source}")?;
+                for message in messages {
+                    writeln!(f, "    {message}")?;
+                }
+            }
+        }
+        writeln!(
+            f,
+            "{} violations in {} entries",
+            self.violations.len(),
+            self.entries
+        )
+    }
+}
+
+struct BySource<'a>(&'a Report);
+
+impl fmt::Display for BySource<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (source, violations) in self.0.by_source() {
+            writeln!(f, "{source}")?;
+            for v in violations {
+                writeln!(f, "  {}: {}", v.rule, v.message)?;
+            }
+        }
+        writeln!(
+            f,
+            "{} violations in {} entries",
+            self.0.violations.len(),
+            self.0.entries
+        )
+    }
+}
+
+/// What is remembered about each node while the stream is consumed.
+#[derive(Default)]
+struct NodeInfo {
+    kind: Option<String>,
+    facts: HashSet<String>,
+    loc: (Option<Vec<u8>>, Option<Vec<u8>>),
+    text_len: Option<usize>,
+}
+
+/// Accumulates entries and checks them against the Kythe schema.
+pub struct Validator {
+    required: HashMap<String, Vec<String>>,
+    nodes: HashMap<Uri, NodeInfo>,
+    edges: Vec<(Uri, String, Uri)>,
+    report: Report,
+}
+
+impl Default for Validator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Validator {
+    /// Returns a validator with the default required facts: anchors need
+    /// `loc/start` and `loc/end`, files and docs need `text` and diagnostics
+    /// need `message`.
+    pub fn new() -> Self {
+        let mut v = Validator {
+            required: HashMap::new(),
+            nodes: HashMap::new(),
+            edges: Vec::new(),
+            report: Report::default(),
+        };
+        v.require_fact(nodes::ANCHOR, facts::ANCHOR_START);
+        v.require_fact(nodes::ANCHOR, facts::ANCHOR_END);
+        v.require_fact(nodes::FILE, facts::TEXT);
+        v.require_fact(nodes::DOC, facts::TEXT);
+        v.require_fact(nodes::DIAGNOSTIC, facts::MESSAGE);
+        v
+    }
+
+    /// Requires every node of `kind` to have the fact `name`.
+    pub fn require_fact(&mut self, kind: &str, name: &str) -> &mut Self {
+        self.required
+            .entry(kind.to_owned())
+            .or_default()
+            .push(name.to_owned());
+        self
+    }
+
+    /// Checks a single entry.
+    pub fn add_entry(&mut self, entry: &Entry) {
+        self.report.entries += 1;
+        let source = Uri::from_vname(entry.get_source());
+        if source == Uri::default() {
+            self.violation(
+                Rule::MalformedEntry,
+                &source,
+                "entry has an empty source".into(),
+            );
+            return;
+        }
+        if entry.get_edge_kind().is_empty() {
+            self.add_fact(source, entry);
+        } else {
+            self.add_edge(source, entry);
+        }
+    }
+
+    fn add_fact(&mut self, source: Uri, entry: &Entry) {
+        let name = entry.get_fact_name();
+        if entry.has_target() {
+            self.violation(
+                Rule::MalformedEntry,
+                &source,
+                format!("fact {name:?} has a target"),
+            );
+        }
+        if name == EDGE_FACT_NAME || !facts::is_valid_name(name) {
+            self.violation(
+                Rule::MalformedEntry,
+                &source,
+                format!("invalid fact name {name:?}"),
+            );
+            return;
+        }
+        let value = entry.get_fact_value();
+        let info = self.nodes.entry(source).or_default();
+        info.facts.insert(name.to_owned());
+        match name {
+            facts::NODE_KIND => info.kind = Some(String::from_utf8_lossy(value).into_owned()),
+            facts::ANCHOR_START => info.loc.0 = Some(value.to_vec()),
+            facts::ANCHOR_END => info.loc.1 = Some(value.to_vec()),
+            facts::TEXT => info.text_len = Some(value.len()),
+            _ => {}
+        }
+    }
+
+    fn add_edge(&mut self, source: Uri, entry: &Entry) {
+        let kind = entry.get_edge_kind();
+        if entry.get_fact_name() != EDGE_FACT_NAME || !entry.get_fact_value().is_empty() {
+            self.violation(
+                Rule::MalformedEntry,
+                &source,
+                format!("edge {kind} must have fact name \"/\" and an empty value"),
+            );
+        }
+        let target = Uri::from_vname(entry.get_target());
+        if target == Uri::default() {
+            self.violation(
+                Rule::MalformedEntry,
+                &source,
+                format!("edge {kind} has no target"),
+            );
+            return;
+        }
+        if kind.starts_with(edges::PREFIX) && !edges::is_schema_kind(kind) {
+            self.violation(
+                Rule::UnknownEdgeKind,
+                &source,
+                format!("unknown edge kind {kind}"),
+            );
+        }
+        self.edges.push((source, kind.to_owned(), target));
+    }
+
+    /// Completes validation and returns the report.
+    pub fn finish(mut self) -> Report {
+        let files: HashMap<(&str, &str, &str), Option<usize>> = self
+            .nodes
+            .iter()
+            .filter(|(_, info)| info.kind.as_deref() == Some(nodes::FILE))
+            .map(|(uri, info)| {
+                (
+                    (uri.corpus.as_str(), uri.root.as_str(), uri.path.as_str()),
+                    info.text_len,
+                )
+            })
+            .collect();
+
+        let mut found = Vec::new();
+        for (uri, info) in &self.nodes {
+            let Some(kind) = &info.kind else {
+                found.push((
+                    Rule::MissingNodeKind,
+                    uri,
+                    "node has facts but no node/kind".into(),
+                ));
+                continue;
+            };
+            if !nodes::SCHEMA_KINDS.contains(&kind.as_str()) {
+                found.push((
+                    Rule::UnknownNodeKind,
+                    uri,
+                    format!("unknown node kind {kind:?}"),
+                ));
+            }
+            for name in self.required.get(kind).into_iter().flatten() {
+                if !info.facts.contains(name) {
+                    found.push((
+                        Rule::MissingRequiredFact,
+                        uri,
+                        format!("{kind} node lacks {name}"),
+                    ));
+                }
+            }
+            if kind == nodes::ANCHOR {
+                if let Some(v) = check_anchor(uri, info, &files) {
+                    found.push(v);
+                }
+            }
+        }
+        for (source, kind, target) in &self.edges {
+            let defined = self
+                .nodes
+                .get(target)
+                .is_some_and(|info| info.kind.is_some());
+            if !defined {
+                found.push((
+                    Rule::DanglingEdgeTarget,
+                    source,
+                    format!("{kind} target {target} has no node/kind"),
+                ));
+            }
+        }
+
+        let mut violations: Vec<Violation> = found
+            .into_iter()
+            .map(|(rule, source, message)| Violation {
+                rule,
+                source: source.clone(),
+                message,
+            })
+            .collect();
+        violations
+            .sort_by(|a, b| (a.rule, &a.source, &a.message).cmp(&(b.rule, &b.source, &b.message)));
+        self.report.violations.append(&mut violations);
+        self.report
+    }
+
+    fn violation(&mut self, rule: Rule, source: &Uri, message: String) {
+        self.report.violations.push(Violation {
+            rule,
+            source: source.clone(),
+            message,
+        });
+    }
+}
+
+impl EntrySink for Validator {
+    fn write_entry(&mut self, entry: Entry) -> kythe_storage::Result<()> {
+        self.add_entry(&entry);
+        Ok(())
+    }
+}
+
+/// Checks the span of an anchor against the text of its file, which is
+/// identified by the anchor's corpus, root and path.
+fn check_anchor<'a>(
+    uri: &'a Uri,
+    info: &NodeInfo,
+    files: &HashMap<(&str, &str, &str), Option<usize>>,
+) -> Option<(Rule, &'a Uri, String)> {
+    let parse = |v: &Option<Vec<u8>>| -> Option<usize> {
+        std::str::from_utf8(v.as_ref()?).ok()?.parse().ok()
+    };
+    let (start, end) = match (&info.loc.0, &info.loc.1) {
+        (None, _) | (_, None) => return None, // reported as a missing required fact
+        (s, e) => match (parse(s), parse(e)) {
+            (Some(start), Some(end)) if start <= end => (start, end),
+            _ => {
+                let show = |v: &Option<Vec<u8>>| {
+                    String::from_utf8_lossy(v.as_deref().unwrap_or_default()).into_owned()
+                };
+                return Some((
+                    Rule::InvalidAnchorOffsets,
+                    uri,
+                    format!("invalid span {:?}:{:?}", show(s), show(e)),
+                ));
+            }
+        },
+    };
+    match files.get(&(uri.corpus.as_str(), uri.root.as_str(), uri.path.as_str())) {
+        Some(Some(len)) if end > *len => Some((
+            Rule::AnchorOutOfBounds,
+            uri,
+            format!("span {start}:{end} exceeds file length {len}"),
+        )),
+        Some(Some(_)) | Some(None) => None, // a file without text is reported on its own
+        None => Some((
+            Rule::MissingFileText,
+            uri,
+            format!("no file text for path {:?}", uri.path),
+        )),
+    }
+}
+
+/// Validates a complete stream of entries.
+pub fn validate<'a>(entries: impl IntoIterator<Item = &'a Entry>) -> Report {
+    let mut v = Validator::new();
+    for entry in entries {
+        v.add_entry(entry);
+    }
+    v.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kythe_storage::emitter::{anchor_vname, Emitter};
+    use storage_rust_proto::VName;
+
+    fn file() -> VName {
+        let mut v = VName::new();
+        v.set_corpus("c".into());
+        v.set_path("a.rs".into());
+        v
+    }
+
+    fn node(signature: &str) -> VName {
+        let mut v = file();
+        v.set_signature(signature.into());
+        v.set_language("rust".into());
+        v
+    }
+
+    fn rules(report: &Report) -> Vec<Rule> {
+        report.violations().iter().map(|v| v.rule).collect()
+    }
+
+    #[test]
+    fn well_formed_stream() {
+        let mut e = Emitter::new(Vec::new());
+        e.emit_file(&file(), b"fn foo() {}", None).unwrap();
+        let anchor = anchor_vname(&file(), 3, 6);
+        e.emit_anchor(&anchor, 3, 6).unwrap();
+        e.emit_node(&node("foo"), nodes::FUNCTION, []).unwrap();
+        e.emit_edge(&anchor, edges::DEFINES_BINDING, &node("foo"))
+            .unwrap();
+        e.emit_edge(&anchor, edges::CHILD_OF, &file()).unwrap();
+
+        let report = validate(&e.into_inner());
+        assert!(report.is_ok(), "{report}");
+        assert_eq!(report.entries, 8);
+    }
+
+    #[test]
+    fn anchors() {
+        let mut e = Emitter::new(Vec::new());
+        e.emit_file(&file(), b"short", None).unwrap();
+        e.emit_anchor(&node("@0:10"), 0, 10).unwrap();
+        e.emit_node(
+            &node("@1:2"),
+            nodes::ANCHOR,
+            [(facts::ANCHOR_START, &b"1"[..])],
+        )
+        .unwrap();
+        e.emit_node(
+            &node("@x"),
+            nodes::ANCHOR,
+            [
+                (facts::ANCHOR_START, &b"x"[..]),
+                (facts::ANCHOR_END, &b"2"[..]),
+            ],
+        )
+        .unwrap();
+        let mut other = node("@0:1");
+        other.set_path("b.rs".into());
+        e.emit_anchor(&other, 0, 1).unwrap();
+
+        let report = validate(&e.into_inner());
+        assert_eq!(
+            rules(&report),
+            vec![
+                Rule::MissingRequiredFact,
+                Rule::InvalidAnchorOffsets,
+                Rule::AnchorOutOfBounds,
+                Rule::MissingFileText
+            ]
+        );
+        assert_eq!(
+            report.violations()[0].source,
+            Uri::from_vname(&node("@1:2"))
+        );
+    }
+
+    #[test]
+    fn edges_and_kinds() {
+        let mut e = Emitter::new(Vec::new());
+        e.emit_node(&node("a"), "bogus", []).unwrap();
+        e.emit_fact(&node("b"), facts::COMPLETE, "definition")
+            .unwrap();
+        e.emit_edge(&node("a"), "/kythe/edge/bogus", &node("b"))
+            .unwrap();
+        e.emit_edge(&node("a"), "/custom/edge", &node("c")).unwrap();
+        let mut entries = e.into_inner();
+        let mut bad = entries[0].clone();
+        bad.set_fact_name("kythe/bad".into());
+        entries.push(bad);
+
+        let report = validate(&entries);
+        assert_eq!(
+            rules(&report),
+            vec![
+                Rule::UnknownEdgeKind,
+                Rule::MalformedEntry,
+                Rule::MissingNodeKind,
+                Rule::UnknownNodeKind,
+                Rule::DanglingEdgeTarget,
+                Rule::DanglingEdgeTarget,
+            ]
+        );
+        let by_rule = report.by_rule();
+        assert_eq!(by_rule[&Rule::DanglingEdgeTarget].len(), 2);
+        let by_source = report.by_source();
+        assert_eq!(by_source[&Uri::from_vname(&node("a"))].len(), 5);
+    }
+}
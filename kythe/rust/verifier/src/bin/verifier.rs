@@ -0,0 +1,79 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Verifies goal-annotated test sources against the entries emitted by an
+//! indexer.
+//!
+//! Usage: verifier [--goal_prefix=//-] FILE... < entries
+//!
+//! Entries are read as a delimited stream from stdin. The process exits with
+//! a non-zero status if any goal could not be verified.
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use kythe_storage::delimited;
+use kythe_verifier::{parse_goals, Database, DEFAULT_GOAL_PREFIX};
+use std::io;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(about = "Verifies goal-annotated sources against Kythe entries read from stdin")]
+struct Args {
+    /// The comment prefix that introduces goal lines.
+    #[arg(long = "goal_prefix", default_value = DEFAULT_GOAL_PREFIX)]
+    goal_prefix: String,
+
+    /// Print the variable assignments of a successful verification.
+    #[arg(long = "show_bindings")]
+    show_bindings: bool,
+
+    /// Annotated source files.
+    #[arg(required = true)]
+    files: Vec<PathBuf>,
+}
+
+fn main() -> Result<ExitCode> {
+    let args = Args::parse();
+    let mut goals = Vec::new();
+    for path in &args.files {
+        let text =
+            std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        goals.extend(parse_goals(
+            &path.display().to_string(),
+            &text,
+            &args.goal_prefix,
+        )?);
+    }
+
+    let mut db = Database::new();
+    for entry in delimited::entries(io::stdin().lock()) {
+        db.add_entry(&entry.context("reading entries from stdin")?);
+    }
+
+    match db.solve(&goals) {
+        Ok(solution) => {
+            if args.show_bindings {
+                for (name, value) in &solution.bindings {
+                    println!("{name}: {value}");
+                }
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Err(failure) => {
+            eprint!("{failure}");
+            Ok(ExitCode::FAILURE)
+        }
+    }
+}
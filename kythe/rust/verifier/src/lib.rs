@@ -0,0 +1,43 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A verifier for Kythe indexer tests, in the style of the C++ Kythe
+//! verifier.
+//!
+//! Test sources are annotated with goal comments such as
+//!
+//! ```text
+//! //- @foo defines/binding VarFoo
+//! //- VarFoo.node/kind variable
+//! let foo = 1;
+//! ```
+//!
+//! Each goal line is parsed into one or more [`Goal`]s. An `@token` term
+//! refers to the anchor spanning the first occurrence of `token` on the next
+//! line that is not a goal line. Capitalized identifiers are variables, which
+//! must be bound consistently across all goals; `_` matches anything. Edge
+//! kinds and fact names may omit their `/kythe/edge/` and `/kythe/` prefixes.
+//! A group of goals may be negated with `!{ goal, goal }`.
+//!
+//! The goals from all files are solved together against a [`Database`] built
+//! from the indexer's entries.
+
+mod parser;
+mod solver;
+
+pub use parser::{parse_goals, Goal, GoalKind, Origin, ParseError, Term};
+pub use solver::{Database, Failure, Solution};
+
+/// The goal comment prefix used by default.
+pub const DEFAULT_GOAL_PREFIX: &str = "//-";
@@ -0,0 +1,492 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Parsing of goal comments in annotated test sources.
+
+use kythe_uri::Uri;
+use std::fmt;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// An error in the goals of a test source.
+#[derive(Debug, Error)]
+#[error("{file}:{line}: {message}")]
+pub struct ParseError {
+    pub file: String,
+    pub line: usize,
+    pub message: String,
+}
+
+/// Where a goal was written.
+#[derive(Clone, Debug)]
+pub struct Origin {
+    pub file: String,
+    /// The 1-based line number of the goal comment.
+    pub line: usize,
+    /// The text of the goal comment, without its prefix.
+    pub text: String,
+    /// The source line that `@` terms on this goal line refer to, if any.
+    pub context: Option<String>,
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.file, self.line, self.text.trim())
+    }
+}
+
+/// A source file whose anchors are referenced by `@` terms.
+#[derive(Debug)]
+pub struct Source {
+    pub name: String,
+    pub text: String,
+}
+
+/// A term in a goal.
+#[derive(Clone, Debug)]
+pub enum Term {
+    /// A named variable, bound consistently across all goals.
+    Var(String),
+    /// `_`, which matches anything.
+    Wildcard,
+    /// A string or identifier constant.
+    Str(String),
+    /// A literal `vname(signature, corpus, root, path, language)`.
+    VName(Uri),
+    /// The anchor spanning `[start, end)` in `source`.
+    Anchor {
+        start: usize,
+        end: usize,
+        source: Arc<Source>,
+    },
+}
+
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Term::Var(name) => f.write_str(name),
+            Term::Wildcard => f.write_str("_"),
+            Term::Str(s) => write!(f, "{s:?}"),
+            Term::VName(uri) => write!(f, "{uri}"),
+            Term::Anchor { start, end, source } => {
+                write!(f, "@{}[{start}:{end}]", &source.text[*start..*end])
+            }
+        }
+    }
+}
+
+/// The statement a goal makes about the graph.
+#[derive(Clone, Debug)]
+pub enum GoalKind {
+    /// An edge of the given (fully-qualified) kind.
+    Edge {
+        source: Term,
+        kind: String,
+        target: Term,
+    },
+    /// A fact with the given (fully-qualified) name.
+    Fact {
+        node: Term,
+        name: String,
+        value: Term,
+    },
+    /// A group of goals that must not be satisfiable together.
+    Not(Vec<Goal>),
+}
+
+/// A single goal and where it came from.
+#[derive(Clone, Debug)]
+pub struct Goal {
+    pub kind: GoalKind,
+    pub origin: Arc<Origin>,
+}
+
+impl fmt::Display for Goal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            GoalKind::Edge {
+                source,
+                kind,
+                target,
+            } => write!(f, "{source} {kind} {target}"),
+            GoalKind::Fact { node, name, value } => write!(f, "{node}.{name} {value}"),
+            GoalKind::Not(goals) => {
+                f.write_str("!{")?;
+                for (i, g) in goals.iter().enumerate() {
+                    write!(f, "{}{g}", if i == 0 { " " } else { ", " })?;
+                }
+                f.write_str(" }")
+            }
+        }
+    }
+}
+
+/// Parses the goals in `text`, whose goal comments begin with `prefix`.
+pub fn parse_goals(name: &str, text: &str, prefix: &str) -> Result<Vec<Goal>, ParseError> {
+    let source = Arc::new(Source {
+        name: name.to_owned(),
+        text: text.to_owned(),
+    });
+    let mut lines = Vec::new();
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        let goal = trimmed
+            .strip_prefix(prefix)
+            .map(|g| g.trim_end_matches(['\r', '\n']));
+        lines.push((offset, line.trim_end_matches(['\r', '\n']), goal));
+        offset += line.len();
+    }
+
+    let mut goals = Vec::new();
+    for (i, (_, _, goal)) in lines.iter().enumerate() {
+        let Some(goal) = goal else { continue };
+        // The line that anchors on this goal line refer to.
+        let target = lines[i + 1..]
+            .iter()
+            .find(|(_, _, g)| g.is_none())
+            .map(|(off, line, _)| (*off, *line));
+        let origin = Arc::new(Origin {
+            file: name.to_owned(),
+            line: i + 1,
+            text: (*goal).to_owned(),
+            context: target.map(|(_, line)| line.to_owned()),
+        });
+        let err = |message: String| ParseError {
+            file: name.to_owned(),
+            line: i + 1,
+            message,
+        };
+        let tokens = lex(goal).map_err(err)?;
+        let mut p = Parser {
+            tokens: &tokens,
+            pos: 0,
+            origin: &origin,
+            source: &source,
+            target,
+        };
+        while p.pos < tokens.len() {
+            goals.push(p.goal().map_err(err)?);
+            if p.pos < tokens.len() {
+                p.expect(&Token::Comma).map_err(err)?;
+            }
+        }
+    }
+    Ok(goals)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Word(String),
+    Str(String),
+    /// `@token`, `@"string"` or `@#n token`, with an occurrence index.
+    Anchor(String, usize),
+    VName(Vec<String>),
+    /// `.name` immediately following a term.
+    Dot(String),
+    NotOpen,
+    Close,
+    Comma,
+}
+
+fn lex(s: &str) -> Result<Vec<Token>, String> {
+    const VNAME: [char; 6] = ['v', 'n', 'a', 'm', 'e', '('];
+    let chars: Vec<char> = s.chars().collect();
+    let mut i = 0;
+    let mut tokens = Vec::new();
+    let is_word = |c: char| !c.is_whitespace() && !",{}()\"".contains(c);
+    let word = |i: &mut usize, pred: &dyn Fn(char) -> bool| {
+        let start = *i;
+        while *i < chars.len() && pred(chars[*i]) {
+            *i += 1;
+        }
+        chars[start..*i].iter().collect::<String>()
+    };
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            _ if c.is_whitespace() => i += 1,
+            ',' => {
+                tokens.push(Token::Comma);
+                i += 1;
+            }
+            '}' => {
+                tokens.push(Token::Close);
+                i += 1;
+            }
+            '!' if chars.get(i + 1) == Some(&'{') => {
+                tokens.push(Token::NotOpen);
+                i += 2;
+            }
+            '"' => tokens.push(Token::Str(lex_string(&chars, &mut i)?)),
+            '@' => {
+                i += 1;
+                let mut occurrence = 0;
+                if chars.get(i) == Some(&'#') {
+                    i += 1;
+                    let n = word(&mut i, &|c| c.is_ascii_digit());
+                    occurrence = n.parse().map_err(|_| format!("invalid occurrence {n:?}"))?;
+                }
+                let tok = if chars.get(i) == Some(&'"') {
+                    lex_string(&chars, &mut i)?
+                } else {
+                    word(&mut i, &|c| c.is_alphanumeric() || c == '_')
+                };
+                if tok.is_empty() {
+                    return Err("empty anchor token".into());
+                }
+                tokens.push(Token::Anchor(tok, occurrence));
+            }
+            _ if chars[i..].starts_with(&VNAME) => {
+                i += VNAME.len();
+                let mut args = Vec::new();
+                loop {
+                    while i < chars.len() && chars[i].is_whitespace() {
+                        i += 1;
+                    }
+                    if chars.get(i) != Some(&'"') {
+                        return Err("vname arguments must be strings".into());
+                    }
+                    args.push(lex_string(&chars, &mut i)?);
+                    while i < chars.len() && chars[i].is_whitespace() {
+                        i += 1;
+                    }
+                    match chars.get(i) {
+                        Some(',') => i += 1,
+                        Some(')') => {
+                            i += 1;
+                            break;
+                        }
+                        _ => return Err("unterminated vname".into()),
+                    }
+                }
+                if args.len() != 5 {
+                    return Err(format!("vname takes 5 arguments, found {}", args.len()));
+                }
+                tokens.push(Token::VName(args));
+            }
+            _ if is_word(c) => {
+                let w = word(&mut i, &is_word);
+                match w.split_once('.') {
+                    // Only variables can be followed by a fact name; other
+                    // words (such as edge kinds) may contain dots.
+                    Some((var, fact)) if starts_var(var) => {
+                        tokens.push(Token::Word(var.to_owned()));
+                        tokens.push(Token::Dot(fact.to_owned()));
+                    }
+                    _ => tokens.push(Token::Word(w)),
+                }
+                continue;
+            }
+            _ => return Err(format!("unexpected character {c:?}")),
+        }
+        // A fact name may directly follow a quoted, anchor or vname term.
+        if matches!(
+            tokens.last(),
+            Some(Token::Str(_) | Token::Anchor(..) | Token::VName(_))
+        ) && chars.get(i) == Some(&'.')
+        {
+            i += 1;
+            tokens.push(Token::Dot(word(&mut i, &is_word)));
+        }
+    }
+    Ok(tokens)
+}
+
+fn lex_string(chars: &[char], i: &mut usize) -> Result<String, String> {
+    let mut out = String::new();
+    *i += 1; // opening quote
+    while *i < chars.len() {
+        match chars[*i] {
+            '"' => {
+                *i += 1;
+                return Ok(out);
+            }
+            '\\' if *i + 1 < chars.len() => {
+                out.push(chars[*i + 1]);
+                *i += 2;
+            }
+            c => {
+                out.push(c);
+                *i += 1;
+            }
+        }
+    }
+    Err("unterminated string".into())
+}
+
+fn starts_var(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_ascii_uppercase()) || s == "_"
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+    origin: &'a Arc<Origin>,
+    source: &'a Arc<Source>,
+    /// The offset and text of the line that anchors refer to.
+    target: Option<(usize, &'a str)>,
+}
+
+impl Parser<'_> {
+    fn next(&mut self) -> Option<&Token> {
+        let t = self.tokens.get(self.pos);
+        self.pos += 1;
+        t
+    }
+
+    fn expect(&mut self, want: &Token) -> Result<(), String> {
+        match self.next() {
+            Some(t) if t == want => Ok(()),
+            t => Err(format!("expected {want:?}, found {t:?}")),
+        }
+    }
+
+    fn goal(&mut self) -> Result<Goal, String> {
+        if self.tokens.get(self.pos) == Some(&Token::NotOpen) {
+            self.pos += 1;
+            let mut group = vec![self.goal()?];
+            loop {
+                match self.next() {
+                    Some(Token::Comma) => group.push(self.goal()?),
+                    Some(Token::Close) => break,
+                    t => {
+                        return Err(format!(
+                            "expected ',' or '}}' in negated group, found {t:?}"
+                        ))
+                    }
+                }
+            }
+            return Ok(self.make(GoalKind::Not(group)));
+        }
+
+        let subject = self.term()?;
+        if let Some(Token::Dot(fact)) = self.tokens.get(self.pos) {
+            let name = qualify(fact, "/kythe/");
+            self.pos += 1;
+            let value = self.term()?;
+            return Ok(self.make(GoalKind::Fact {
+                node: subject,
+                name,
+                value,
+            }));
+        }
+        let kind = match self.next() {
+            Some(Token::Word(kind)) => qualify(kind, "/kythe/edge/"),
+            t => return Err(format!("expected an edge kind or fact name, found {t:?}")),
+        };
+        let target = self.term()?;
+        Ok(self.make(GoalKind::Edge {
+            source: subject,
+            kind,
+            target,
+        }))
+    }
+
+    fn term(&mut self) -> Result<Term, String> {
+        let target = self.target;
+        match self.next().cloned() {
+            Some(Token::Word(w)) if w == "_" => Ok(Term::Wildcard),
+            Some(Token::Word(w)) if starts_var(&w) => Ok(Term::Var(w)),
+            Some(Token::Word(w)) | Some(Token::Str(w)) => Ok(Term::Str(w)),
+            Some(Token::VName(args)) => Ok(Term::VName(Uri {
+                signature: args[0].clone(),
+                corpus: args[1].clone(),
+                root: args[2].clone(),
+                path: args[3].clone(),
+                language: args[4].clone(),
+            })),
+            Some(Token::Anchor(tok, occurrence)) => {
+                let (offset, line) = target.ok_or_else(|| format!("no source line for @{tok}"))?;
+                let col = line
+                    .match_indices(tok.as_str())
+                    .nth(occurrence)
+                    .map(|(col, _)| col)
+                    .ok_or_else(|| {
+                        format!("@{tok} (occurrence {occurrence}) not found on {line:?}")
+                    })?;
+                Ok(Term::Anchor {
+                    start: offset + col,
+                    end: offset + col + tok.len(),
+                    source: Arc::clone(self.source),
+                })
+            }
+            t => Err(format!("expected a term, found {t:?}")),
+        }
+    }
+
+    fn make(&self, kind: GoalKind) -> Goal {
+        Goal {
+            kind,
+            origin: Arc::clone(self.origin),
+        }
+    }
+}
+
+/// Adds `prefix` to unqualified edge kinds and fact names.
+fn qualify(name: &str, prefix: &str) -> String {
+    if name.starts_with('/') {
+        name.to_owned()
+    } else {
+        format!("{prefix}{name}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "\
+//- @foo defines/binding VarFoo
+//- VarFoo.node/kind variable
+  //- @#1foo ref VarFoo, !{ @#1foo ref/call _ }
+let foo = foo;
+//- vname(\"sig\", \"\", \"\", \"\", \"rust\").complete \"a b\"
+x(foo);
+";
+
+    #[test]
+    fn parses_goals() {
+        let goals = parse_goals("test.rs", SOURCE, "//-").unwrap();
+        let rendered: Vec<String> = goals.iter().map(|g| g.to_string()).collect();
+        assert_eq!(
+            rendered,
+            vec![
+                "@foo[114:117] /kythe/edge/defines/binding VarFoo",
+                "VarFoo./kythe/node/kind \"variable\"",
+                "@foo[120:123] /kythe/edge/ref VarFoo",
+                "!{ @foo[120:123] /kythe/edge/ref/call _ }",
+                "kythe:?lang=rust#sig./kythe/complete \"a b\"",
+            ]
+        );
+        assert_eq!(goals[2].origin.line, 3);
+        assert_eq!(goals[2].origin.context.as_deref(), Some("let foo = foo;"));
+        assert_eq!(goals[4].origin.context.as_deref(), Some("x(foo);"));
+    }
+
+    #[test]
+    fn reports_errors() {
+        for (text, line) in [
+            ("//- @bar defines X\nlet foo;\n", 1),
+            ("\n//- X.node/kind\n", 2),
+            ("//- X defines\n", 1),
+            ("//- !{ X ref Y\n", 1),
+            ("//- \"unterminated\n", 1),
+            ("//- @foo ref X\n", 1),
+        ] {
+            let err = parse_goals("t", text, "//-").unwrap_err();
+            assert_eq!(err.line, line, "{text:?}: {err}");
+        }
+    }
+}
@@ -0,0 +1,490 @@
+// Copyright 2026 The Kythe Authors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Solving goals against the entries emitted by an indexer.
+
+use crate::parser::{Goal, GoalKind, Origin, Term};
+use kythe_schema::facts;
+use kythe_storage::EntrySink;
+use kythe_uri::Uri;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use storage_rust_proto::Entry;
+
+/// An index over the entries emitted by an indexer.
+#[derive(Default)]
+pub struct Database {
+    edges_by_source: HashMap<Uri, Vec<(String, Uri)>>,
+    edges_by_target: HashMap<Uri, Vec<(String, Uri)>>,
+    edges_by_kind: HashMap<String, Vec<(Uri, Uri)>>,
+    facts_by_node: HashMap<Uri, Vec<(String, Vec<u8>)>>,
+    nodes_by_fact: HashMap<(String, Vec<u8>), Vec<Uri>>,
+    facts_by_name: HashMap<String, Vec<(Uri, Vec<u8>)>>,
+    /// File VNames (with empty signature and language) keyed by their text.
+    files: HashMap<Vec<u8>, Uri>,
+}
+
+impl Database {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an entry to the database.
+    pub fn add_entry(&mut self, entry: &Entry) {
+        let source = Uri::from_vname(entry.get_source());
+        if !entry.get_edge_kind().is_empty() {
+            let kind = entry.get_edge_kind().to_owned();
+            let target = Uri::from_vname(entry.get_target());
+            self.edges_by_source
+                .entry(source.clone())
+                .or_default()
+                .push((kind.clone(), target.clone()));
+            self.edges_by_target
+                .entry(target.clone())
+                .or_default()
+                .push((kind.clone(), source.clone()));
+            self.edges_by_kind
+                .entry(kind)
+                .or_default()
+                .push((source, target));
+            return;
+        }
+        let name = entry.get_fact_name().to_owned();
+        let value = entry.get_fact_value().to_vec();
+        if name == facts::TEXT {
+            self.files.insert(value.clone(), source.clone());
+        }
+        self.facts_by_node
+            .entry(source.clone())
+            .or_default()
+            .push((name.clone(), value.clone()));
+        self.nodes_by_fact
+            .entry((name.clone(), value.clone()))
+            .or_default()
+            .push(source.clone());
+        self.facts_by_name
+            .entry(name)
+            .or_default()
+            .push((source, value));
+    }
+
+    /// Solves `goals` together, returning the first consistent assignment of
+    /// their variables or the goal that could not be satisfied.
+    pub fn solve(&self, goals: &[Goal]) -> Result<Solution, Failure> {
+        let mut vars = Vars::default();
+        let program: Vec<Step> = goals
+            .iter()
+            .flat_map(|g| self.compile(g, &mut vars))
+            .collect();
+        let mut b = Bindings {
+            values: vec![None; vars.names.len()],
+            trail: Vec::new(),
+        };
+        let mut deepest = 0;
+        if self.search(&program, 0, &mut b, &mut deepest) {
+            let bindings = vars
+                .names
+                .iter()
+                .zip(b.values)
+                .filter(|(name, _)| !name.starts_with(['_', '@']))
+                .filter_map(|(name, value)| Some((name.clone(), value?)))
+                .collect();
+            return Ok(Solution { bindings });
+        }
+        let step = &program[deepest];
+        Err(Failure {
+            goal: step.goal.to_string(),
+            origin: Arc::clone(&step.goal.origin),
+        })
+    }
+
+    /// Lowers a goal to solver steps, expanding anchors into their facts.
+    fn compile<'g>(&self, goal: &'g Goal, vars: &mut Vars) -> Vec<Step<'g>> {
+        let mut steps = Vec::new();
+        let op = match &goal.kind {
+            GoalKind::Edge {
+                source,
+                kind,
+                target,
+            } => Op::Edge {
+                source: self.lower(source, goal, vars, &mut steps),
+                kind: kind.clone(),
+                target: self.lower(target, goal, vars, &mut steps),
+            },
+            GoalKind::Fact { node, name, value } => Op::Fact {
+                node: self.lower(node, goal, vars, &mut steps),
+                name: name.clone(),
+                value: self.lower(value, goal, vars, &mut steps),
+            },
+            GoalKind::Not(group) => {
+                Op::Not(group.iter().flat_map(|g| self.compile(g, vars)).collect())
+            }
+        };
+        steps.push(Step { op, goal });
+        steps
+    }
+
+    /// Lowers a term to a pattern, adding any steps needed to constrain it.
+    fn lower<'g>(
+        &self,
+        term: &Term,
+        goal: &'g Goal,
+        vars: &mut Vars,
+        steps: &mut Vec<Step<'g>>,
+    ) -> Pattern {
+        match term {
+            Term::Var(name) => Pattern::Var(vars.named(name)),
+            Term::Wildcard => Pattern::Var(vars.fresh("_")),
+            Term::Str(s) => Pattern::Const(Value::Str(s.clone().into_bytes())),
+            Term::VName(uri) => Pattern::Const(Value::Node(uri.clone())),
+            Term::Anchor { start, end, source } => {
+                let anchor = vars.fresh("@");
+                for (name, value) in [
+                    (facts::ANCHOR_START, start.to_string()),
+                    (facts::ANCHOR_END, end.to_string()),
+                    (facts::NODE_KIND, "anchor".to_owned()),
+                ] {
+                    let op = Op::Fact {
+                        node: Pattern::Var(anchor),
+                        name: name.to_owned(),
+                        value: Pattern::Const(Value::Str(value.into_bytes())),
+                    };
+                    steps.push(Step { op, goal });
+                }
+                // Anchors must be in the file whose text the goals came from,
+                // if the indexer emitted it.
+                if let Some(file) = self.files.get(source.text.as_bytes()) {
+                    steps.push(Step {
+                        op: Op::InFile {
+                            node: anchor,
+                            file: file.clone(),
+                        },
+                        goal,
+                    });
+                }
+                Pattern::Var(anchor)
+            }
+        }
+    }
+
+    /// Searches for an assignment satisfying `program[i..]`, recording the
+    /// index of the deepest step reached in `deepest`.
+    fn search(&self, program: &[Step], i: usize, b: &mut Bindings, deepest: &mut usize) -> bool {
+        *deepest = (*deepest).max(i);
+        let Some(step) = program.get(i) else {
+            return true;
+        };
+        match &step.op {
+            Op::Edge {
+                source,
+                kind,
+                target,
+            } => {
+                let matching = |edges: Option<&Vec<(String, Uri)>>| -> Vec<Uri> {
+                    edges
+                        .into_iter()
+                        .flatten()
+                        .filter(|(k, _)| k == kind)
+                        .map(|(_, n)| n.clone())
+                        .collect()
+                };
+                let candidates: Vec<(Uri, Uri)> = match (b.resolve(source), b.resolve(target)) {
+                    (Some(Value::Str(_)), _) | (_, Some(Value::Str(_))) => return false,
+                    (Some(Value::Node(s)), _) => matching(self.edges_by_source.get(s))
+                        .into_iter()
+                        .map(|t| (s.clone(), t))
+                        .collect(),
+                    (None, Some(Value::Node(t))) => matching(self.edges_by_target.get(t))
+                        .into_iter()
+                        .map(|s| (s, t.clone()))
+                        .collect(),
+                    (None, None) => self.edges_by_kind.get(kind).cloned().unwrap_or_default(),
+                };
+                candidates.into_iter().any(|(s, t)| {
+                    b.attempt(|b| {
+                        b.unify(source, Value::Node(s))
+                            && b.unify(target, Value::Node(t))
+                            && self.search(program, i + 1, b, deepest)
+                    })
+                })
+            }
+            Op::Fact { node, name, value } => {
+                let candidates: Vec<(Uri, Vec<u8>)> = match (b.resolve(node), b.resolve(value)) {
+                    (Some(Value::Str(_)), _) | (_, Some(Value::Node(_))) => return false,
+                    (Some(Value::Node(n)), _) => self
+                        .facts_by_node
+                        .get(n)
+                        .into_iter()
+                        .flatten()
+                        .filter(|(k, _)| k == name)
+                        .map(|(_, v)| (n.clone(), v.clone()))
+                        .collect(),
+                    (None, Some(Value::Str(v))) => self
+                        .nodes_by_fact
+                        .get(&(name.clone(), v.clone()))
+                        .into_iter()
+                        .flatten()
+                        .map(|n| (n.clone(), v.clone()))
+                        .collect(),
+                    (None, None) => self.facts_by_name.get(name).cloned().unwrap_or_default(),
+                };
+                candidates.into_iter().any(|(n, v)| {
+                    b.attempt(|b| {
+                        b.unify(node, Value::Node(n))
+                            && b.unify(value, Value::Str(v))
+                            && self.search(program, i + 1, b, deepest)
+                    })
+                })
+            }
+            Op::InFile { node, file } => match &b.values[*node] {
+                Some(Value::Node(n))
+                    if (&n.corpus, &n.root, &n.path) == (&file.corpus, &file.root, &file.path) =>
+                {
+                    self.search(program, i + 1, b, deepest)
+                }
+                _ => false,
+            },
+            Op::Not(group) => {
+                let mark = b.trail.len();
+                let satisfiable = self.search(group, 0, b, &mut 0);
+                b.undo(mark);
+                !satisfiable && self.search(program, i + 1, b, deepest)
+            }
+        }
+    }
+}
+
+impl EntrySink for Database {
+    fn write_entry(&mut self, entry: Entry) -> kythe_storage::Result<()> {
+        self.add_entry(&entry);
+        Ok(())
+    }
+}
+
+/// A value bound to a variable: a node or a fact value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Value {
+    Node(Uri),
+    Str(Vec<u8>),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Node(uri) => write!(f, "{uri}"),
+            Value::Str(s) => write!(f, "{:?}", String::from_utf8_lossy(s)),
+        }
+    }
+}
+
+/// A successful assignment of the named variables in a set of goals.
+#[derive(Debug)]
+pub struct Solution {
+    pub bindings: Vec<(String, Value)>,
+}
+
+/// The goal that could not be satisfied.
+#[derive(Debug)]
+pub struct Failure {
+    /// The failing goal, with anchors resolved to their spans.
+    pub goal: String,
+    pub origin: Arc<Origin>,
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{}:{}: could not verify goal",
+            self.origin.file, self.origin.line
+        )?;
+        writeln!(f, "  written as: {}", self.origin.text.trim())?;
+        writeln!(f, "  resolved:   {}", self.goal)?;
+        if let Some(context) = &self.origin.context {
+            writeln!(f, "  on line:    {context}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct Vars {
+    names: Vec<String>,
+    named: HashMap<String, usize>,
+}
+
+impl Vars {
+    fn named(&mut self, name: &str) -> usize {
+        if let Some(&i) = self.named.get(name) {
+            return i;
+        }
+        let i = self.fresh(name);
+        self.named.insert(name.to_owned(), i);
+        i
+    }
+
+    fn fresh(&mut self, name: &str) -> usize {
+        self.names.push(name.to_owned());
+        self.names.len() - 1
+    }
+}
+
+enum Pattern {
+    Var(usize),
+    Const(Value),
+}
+
+enum Op<'g> {
+    Edge {
+        source: Pattern,
+        kind: String,
+        target: Pattern,
+    },
+    Fact {
+        node: Pattern,
+        name: String,
+        value: Pattern,
+    },
+    InFile {
+        node: usize,
+        file: Uri,
+    },
+    Not(Vec<Step<'g>>),
+}
+
+struct Step<'g> {
+    op: Op<'g>,
+    goal: &'g Goal,
+}
+
+struct Bindings {
+    values: Vec<Option<Value>>,
+    trail: Vec<usize>,
+}
+
+impl Bindings {
+    fn resolve<'a>(&'a self, p: &'a Pattern) -> Option<&'a Value> {
+        match p {
+            Pattern::Var(v) => self.values[*v].as_ref(),
+            Pattern::Const(value) => Some(value),
+        }
+    }
+
+    fn unify(&mut self, p: &Pattern, value: Value) -> bool {
+        match p {
+            Pattern::Const(c) => *c == value,
+            Pattern::Var(v) => match &self.values[*v] {
+                Some(bound) => *bound == value,
+                None => {
+                    self.values[*v] = Some(value);
+                    self.trail.push(*v);
+                    true
+                }
+            },
+        }
+    }
+
+    /// Runs `f`, undoing any bindings it made if it fails.
+    fn attempt(&mut self, f: impl FnOnce(&mut Self) -> bool) -> bool {
+        let mark = self.trail.len();
+        let ok = f(self);
+        if !ok {
+            self.undo(mark);
+        }
+        ok
+    }
+
+    fn undo(&mut self, mark: usize) {
+        for v in self.trail.drain(mark..) {
+            self.values[v] = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_goals;
+    use kythe_schema::{edges, nodes};
+    use kythe_storage::emitter::{anchor_vname, Emitter};
+    use storage_rust_proto::VName;
+
+    const SOURCE: &str = "\
+//- @foo defines/binding VarFoo
+//- VarFoo.node/kind variable
+let foo = 1;
+//- @foo ref VarFoo
+//- !{ @foo ref/call VarFoo }
+print(foo);
+";
+
+    fn database(extra_ref: bool) -> Database {
+        let mut file = VName::new();
+        file.set_path("test.rs".into());
+        let mut var = file.clone();
+        var.set_signature("foo".into());
+        var.set_language("rust".into());
+
+        let mut e = Emitter::new(Database::new());
+        e.emit_file(&file, SOURCE.as_bytes(), None).unwrap();
+        let def = SOURCE.find("let foo").unwrap() + 4;
+        let anchor = anchor_vname(&file, def, def + 3);
+        e.emit_anchor(&anchor, def, def + 3).unwrap();
+        e.emit_edge(&anchor, edges::DEFINES_BINDING, &var).unwrap();
+        e.emit_node(&var, nodes::VARIABLE, []).unwrap();
+        let r = SOURCE.find("print(foo)").unwrap() + 6;
+        let anchor = anchor_vname(&file, r, r + 3);
+        e.emit_anchor(&anchor, r, r + 3).unwrap();
+        e.emit_edge(&anchor, edges::REF, &var).unwrap();
+        if extra_ref {
+            e.emit_edge(&anchor, edges::REF_CALL, &var).unwrap();
+        }
+        e.into_inner()
+    }
+
+    #[test]
+    fn solves_goals() {
+        let goals = parse_goals("test.rs", SOURCE, "//-").unwrap();
+        let solution = database(false).solve(&goals).unwrap();
+        assert_eq!(
+            solution.bindings,
+            vec![(
+                "VarFoo".to_owned(),
+                Value::Node(Uri::parse("kythe:?lang=rust?path=test.rs#foo").unwrap())
+            )]
+        );
+    }
+
+    #[test]
+    fn reports_the_failing_goal() {
+        let goals = parse_goals("test.rs", SOURCE, "//-").unwrap();
+        let failure = database(true).solve(&goals).unwrap_err();
+        assert_eq!(failure.origin.line, 5);
+        assert_eq!(failure.origin.context.as_deref(), Some("print(foo);"));
+
+        let text = SOURCE.replace("node/kind variable", "node/kind function");
+        let goals = parse_goals("test.rs", &text, "//-").unwrap();
+        let failure = database(false).solve(&goals).unwrap_err();
+        assert_eq!(failure.origin.line, 2);
+        assert_eq!(failure.goal, "VarFoo./kythe/node/kind \"function\"");
+    }
+
+    #[test]
+    fn anchors_must_match_offsets() {
+        let text = "//- @foo defines/binding _\n  let foo = 1;\n";
+        let goals = parse_goals("other.rs", text, "//-").unwrap();
+        assert!(database(false).solve(&goals).is_err());
+    }
+}
@@ -0,0 +1,5 @@
+extern crate protobuf;
+pub mod descriptor;
+pub use descriptor::*;
+pub mod records_metadata;
+pub use records_metadata::*;
\ No newline at end of file
@@ -0,0 +1,408 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `riegeli/records/records_metadata.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RecordsMetadata {
+    // message fields
+    file_comment: ::protobuf::SingularField<::std::string::String>,
+    record_type_name: ::protobuf::SingularField<::std::string::String>,
+    pub file_descriptor: ::protobuf::RepeatedField<::protobuf::descriptor::FileDescriptorProto>,
+    record_writer_options: ::protobuf::SingularField<::std::string::String>,
+    num_records: ::std::option::Option<i64>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RecordsMetadata {
+    fn default() -> &'a RecordsMetadata {
+        <RecordsMetadata as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RecordsMetadata {
+    pub fn new() -> RecordsMetadata {
+        ::std::default::Default::default()
+    }
+
+    // optional string file_comment = 1;
+
+
+    pub fn get_file_comment(&self) -> &str {
+        match self.file_comment.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_file_comment(&mut self) {
+        self.file_comment.clear();
+    }
+
+    pub fn has_file_comment(&self) -> bool {
+        self.file_comment.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_comment(&mut self, v: ::std::string::String) {
+        self.file_comment = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_file_comment(&mut self) -> &mut ::std::string::String {
+        if self.file_comment.is_none() {
+            self.file_comment.set_default();
+        }
+        self.file_comment.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_file_comment(&mut self) -> ::std::string::String {
+        self.file_comment.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional string record_type_name = 2;
+
+
+    pub fn get_record_type_name(&self) -> &str {
+        match self.record_type_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_record_type_name(&mut self) {
+        self.record_type_name.clear();
+    }
+
+    pub fn has_record_type_name(&self) -> bool {
+        self.record_type_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_record_type_name(&mut self, v: ::std::string::String) {
+        self.record_type_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_record_type_name(&mut self) -> &mut ::std::string::String {
+        if self.record_type_name.is_none() {
+            self.record_type_name.set_default();
+        }
+        self.record_type_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_record_type_name(&mut self) -> ::std::string::String {
+        self.record_type_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // repeated .google.protobuf.FileDescriptorProto file_descriptor = 3;
+
+
+    pub fn get_file_descriptor(&self) -> &[::protobuf::descriptor::FileDescriptorProto] {
+        &self.file_descriptor
+    }
+    pub fn clear_file_descriptor(&mut self) {
+        self.file_descriptor.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_descriptor(&mut self, v: ::protobuf::RepeatedField<::protobuf::descriptor::FileDescriptorProto>) {
+        self.file_descriptor = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_file_descriptor(&mut self) -> &mut ::protobuf::RepeatedField<::protobuf::descriptor::FileDescriptorProto> {
+        &mut self.file_descriptor
+    }
+
+    // Take field
+    pub fn take_file_descriptor(&mut self) -> ::protobuf::RepeatedField<::protobuf::descriptor::FileDescriptorProto> {
+        ::std::mem::replace(&mut self.file_descriptor, ::protobuf::RepeatedField::new())
+    }
+
+    // optional string record_writer_options = 4;
+
+
+    pub fn get_record_writer_options(&self) -> &str {
+        match self.record_writer_options.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+    pub fn clear_record_writer_options(&mut self) {
+        self.record_writer_options.clear();
+    }
+
+    pub fn has_record_writer_options(&self) -> bool {
+        self.record_writer_options.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_record_writer_options(&mut self, v: ::std::string::String) {
+        self.record_writer_options = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_record_writer_options(&mut self) -> &mut ::std::string::String {
+        if self.record_writer_options.is_none() {
+            self.record_writer_options.set_default();
+        }
+        self.record_writer_options.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_record_writer_options(&mut self) -> ::std::string::String {
+        self.record_writer_options.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    // optional int64 num_records = 5;
+
+
+    pub fn get_num_records(&self) -> i64 {
+        self.num_records.unwrap_or(0)
+    }
+    pub fn clear_num_records(&mut self) {
+        self.num_records = ::std::option::Option::None;
+    }
+
+    pub fn has_num_records(&self) -> bool {
+        self.num_records.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_num_records(&mut self, v: i64) {
+        self.num_records = ::std::option::Option::Some(v);
+    }
+}
+
+impl ::protobuf::Message for RecordsMetadata {
+    fn is_initialized(&self) -> bool {
+        for v in &self.file_descriptor {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.file_comment)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.record_type_name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.file_descriptor)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.record_writer_options)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.num_records = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.file_comment.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(ref v) = self.record_type_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        for value in &self.file_descriptor {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.record_writer_options.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
+        }
+        if let Some(v) = self.num_records {
+            my_size += ::protobuf::rt::value_size(5, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.file_comment.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(ref v) = self.record_type_name.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        for v in &self.file_descriptor {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.record_writer_options.as_ref() {
+            os.write_string(4, &v)?;
+        }
+        if let Some(v) = self.num_records {
+            os.write_int64(5, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RecordsMetadata {
+        RecordsMetadata::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "file_comment",
+                |m: &RecordsMetadata| { &m.file_comment },
+                |m: &mut RecordsMetadata| { &mut m.file_comment },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "record_type_name",
+                |m: &RecordsMetadata| { &m.record_type_name },
+                |m: &mut RecordsMetadata| { &mut m.record_type_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::descriptor::FileDescriptorProto>>(
+                "file_descriptor",
+                |m: &RecordsMetadata| { &m.file_descriptor },
+                |m: &mut RecordsMetadata| { &mut m.file_descriptor },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "record_writer_options",
+                |m: &RecordsMetadata| { &m.record_writer_options },
+                |m: &mut RecordsMetadata| { &mut m.record_writer_options },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "num_records",
+                |m: &RecordsMetadata| { &m.num_records },
+                |m: &mut RecordsMetadata| { &mut m.num_records },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RecordsMetadata>(
+                "RecordsMetadata",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RecordsMetadata {
+        static instance: ::protobuf::rt::LazyV2<RecordsMetadata> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RecordsMetadata::new)
+    }
+}
+
+impl ::protobuf::Clear for RecordsMetadata {
+    fn clear(&mut self) {
+        self.file_comment.clear();
+        self.record_type_name.clear();
+        self.file_descriptor.clear();
+        self.record_writer_options.clear();
+        self.num_records = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RecordsMetadata {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RecordsMetadata {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n&riegeli/records/records_metadata.proto\x12\x07riegeli\x1a\x20google/p\
+    rotobuf/descriptor.proto\"\x8d\x02\n\x0fRecordsMetadata\x12!\n\x0cfile_c\
+    omment\x18\x01\x20\x01(\tR\x0bfileComment\x12(\n\x10record_type_name\x18\
+    \x02\x20\x01(\tR\x0erecordTypeName\x12M\n\x0ffile_descriptor\x18\x03\x20\
+    \x03(\x0b2$.google.protobuf.FileDescriptorProtoR\x0efileDescriptor\x122\
+    \n\x15record_writer_options\x18\x04\x20\x01(\tR\x13recordWriterOptions\
+    \x12\x1f\n\x0bnum_records\x18\x05\x20\x01(\x03R\nnumRecords*\t\x08\xe8\
+    \x07\x10\x80\x80\x80\x80\x02\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}